@@ -0,0 +1,116 @@
+// Renders a full-screen window of colored text (re-rendering every row each frame), reporting the
+// allocations made per frame alongside criterion's timings. Also times building frames where 50
+// overlapping windows all change, rendering them on one thread and across the worker pool.
+// This doesn't need a terminal; run it with `cargo bench --bench render`.
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use term_render::render::{App, Colorize, ColorType, Span, Window};
+
+const WIDTH: u16 = 200;
+const HEIGHT: u16 = 60;
+
+// counts every allocation, so the per-frame churn can be reported
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+// a bordered, full-screen window where every row mixes a few colors and modifiers
+fn create_app() -> App {
+    let mut app = App::new_headless(WIDTH, HEIGHT);
+    let mut window = Window::new((0, 0), 0, (WIDTH, HEIGHT));
+    window.bordered();
+    window.titled(String::from("Render Bench"));
+    let lines = (0..HEIGHT).map(|row| {
+        Span::from_tokens(vec![
+            format!("Row {:>3} ", row).colorizes(vec![ColorType::BrightWhite, ColorType::OnBlue]),
+            "with some colored text ".colorizes(vec![ColorType::Blue, ColorType::Bold]),
+            "and a little more ".colorizes(vec![ColorType::Green, ColorType::Italic]),
+            "to fill it out".colorize(ColorType::Red),
+        ])
+    }).collect();
+    window.try_update_lines(lines);
+    app.add_window(window, String::from("bench"), vec![]).unwrap();
+    app.render(None);
+    app
+}
+
+// re-rendering every row of the window
+fn render_frame(app: &mut App) {
+    app.get_window_reference_mut(String::from("bench")).update_all();
+    app.render(None);
+}
+
+fn bench_render(c: &mut Criterion) {
+    let mut app = create_app();
+    let frames = 100;
+    let start = ALLOCATIONS.load(Ordering::Relaxed);
+    for _ in 0..frames {
+        render_frame(&mut app);
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - start;
+    println!("full-screen render: {} allocations per frame", allocations / frames);
+
+    c.bench_function("full-screen render", |b| b.iter(|| render_frame(&mut app)));
+}
+
+// 50 overlapping windows of 40 rows each, at increasing depths
+fn create_windowed_app(threads: usize) -> App {
+    let mut app = App::new_headless(WIDTH, HEIGHT);
+    app.set_render_threads(threads);
+    for index in 0..50u16 {
+        let mut window = Window::new((index * 3 % 140, index % 20), index, (60, 40));
+        window.bordered();
+        window.titled(format!("Window {}", index));
+        let lines = (0..38).map(|row| {
+            Span::from_tokens(vec![
+                format!("Row {:>2} ", row).colorizes(vec![ColorType::BrightWhite, ColorType::OnBlue]),
+                "with some colored text ".colorizes(vec![ColorType::Blue, ColorType::Bold]),
+                "to fill it out".colorize(ColorType::Red),
+            ])
+        }).collect();
+        window.try_update_lines(lines);
+        app.add_window(window, format!("window {}", index), vec![]).unwrap();
+    }
+    app.render(None);
+    app
+}
+
+// re-rendering every row of every window
+fn render_windows(app: &mut App) {
+    for index in 0..50 {
+        app.get_window_reference_mut(format!("window {}", index)).update_all();
+    }
+    app.render(None);
+}
+
+fn bench_many_windows(c: &mut Criterion) {
+    let mut group = c.benchmark_group("50 windows x 40 rows");
+    for threads in [1, 4] {
+        let mut app = create_windowed_app(threads);
+        group.bench_function(format!("{} render threads", threads), |b| b.iter(|| render_windows(&mut app)));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_render, bench_many_windows);
+criterion_main!(benches);
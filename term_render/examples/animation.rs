@@ -0,0 +1,90 @@
+use term_render::widget_impls::{Easing, SizeAndPosition, WidgetBuilder};
+use term_render::{self, event_handler::KeyCode};
+use term_render::render::{Colorize, ColorType};
+use term_render::widget::Scene;
+use term_render::render::Span;
+use term_render::color;
+
+// how long the popup takes to slide in or out
+const SLIDE_DURATION: std::time::Duration = std::time::Duration::from_millis(400);
+
+// slides a popup up from below the bottom of the terminal
+fn show_popup(app: &mut term_render::App<AppData>, scene: &mut Scene<AppData>) {
+    term_render::widget_impls::StaticTextWidgetBuilder::<AppData>::builder(String::from("popup"))
+        .with_border(true)
+        .with_title(String::from("Popup"))
+        .with_renderer(vec![
+            Span::from_tokens(vec![color!("I slid in from the bottom!", BrightGreen)]),
+            Span::from_tokens(vec![color!("p: slide me back out", BrightBlack)]),
+        ])
+        .with_dynamic_position((-15, -6), (0.5, 1.0))
+        .with_size((30, 4))
+        .with_depth(1)
+        .with_parent(scene.get_widget_index(String::from("main")))
+        // starting far enough down to be completely off-screen
+        .with_entrance_animation((0, 10), SLIDE_DURATION)
+        .add_to_scene(app, scene)
+        .unwrap();
+}
+
+// slides the popup back down, removing it once it's out of sight
+fn hide_popup(app: &mut term_render::App<AppData>, scene: &mut Scene<AppData>, index: usize) {
+    if let Some(size_and_position) = scene.widget_as_dyn_mut(index).unwrap().get_size_and_position_mut() {
+        let target = SizeAndPosition::new_dynamic(size_and_position.size_offset, (-15, 4), (0.0, 0.0), (0.5, 1.0));
+        size_and_position.animate_to(target, SLIDE_DURATION, Easing::EaseIn);
+    }
+    app.set_timeout(SLIDE_DURATION, Box::new(|_data: &mut AppData, app| {
+        if let Some(scene) = &mut app.scene {
+            // pressing p again while it slides out queues a second removal, which has nothing left to remove
+            let _ = scene.remove_widget_ref(String::from("popup"), &mut app.renderer.write());
+        }
+    }));
+}
+
+// this acts as the callback that is called every frame
+fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    if app.events.read().contains_key_code(KeyCode::Escape) || data.time.elapsed().as_secs_f64() > 60.0 {
+        return Ok(true);
+    }
+    if app.events.read().contains_char('p') && let Some(mut scene) = app.scene.take() {
+        match scene.get_widget_index(String::from("popup")) {
+            Some(index) => hide_popup(app, &mut scene, index),
+            None => show_popup(app, &mut scene),
+        }
+        app.scene = Some(scene);
+    }
+
+    Ok(false)  // return true to exit the app
+}
+
+struct AppData {
+    pub time: std::time::Instant,
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        time: std::time::Instant::now(),
+    };
+    let mut scene = Scene::new();
+
+    term_render::widget_impls::StaticTextWidgetBuilder::<AppData>::builder(String::from("main"))
+        .with_border(true)
+        .with_title(String::from("Animation"))
+        .with_renderer(vec![
+            Span::from_tokens(vec![color!("p: toggle the popup, escape: exit", BrightBlack)]),
+        ])
+        .with_dynamic_position((0, 0), (0.0, 0.0))
+        .with_dynamic_size((0, 0), (1.0, 1.0))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    app.scene = Some(scene);
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
@@ -37,13 +37,13 @@ async fn main() -> tokio::io::Result<()> {
     let (widget, window) =
         term_render::widget_impls::StaticWidgetBuilder::<AppData>::builder(String::from("name"))
             .with_border(true)
-            .with_renderer(Box::new(|_size, _position| {
+            .with_renderer(Box::new(|_size, _position, _data| {
                 Some(vec![])
             }))
             .with_position((10, 10))
             .with_size((50, 10))
             .build(&app.area.read()).unwrap();
-    scene.add_widget(widget, window, &mut *app.renderer.write()).unwrap();
+    scene.add_widget(widget, window, &mut app.renderer.write()).unwrap();
     app.scene = Some(scene);
     
     // running the application with the provided callback function
@@ -51,5 +51,14 @@ async fn main() -> tokio::io::Result<()> {
         app_callback(app_instance, data)
     }).await.unwrap();
     
+    // passing --export saves the final frame as html and svg (handy for documentation), and as
+    // styled and plain text (handy for bug reports)
+    if std::env::args().any(|arg| arg == "--export") {
+        let renderer = app.renderer.read();
+        for path in ["frame.html", "frame.svg", "frame.ans", "frame.txt"] {
+            renderer.export_frame_to(path)?;
+        }
+    }
+    
     Ok(())
 }
@@ -0,0 +1,70 @@
+use term_render::widget_impls::{CanvasMode, CanvasWidgetBuilder, WidgetBuilder};
+use term_render::{self, event_handler::KeyCode};
+use term_render::widget::Scene;
+
+// the number of samples plotted across each canvas
+const SAMPLES: usize = 200;
+
+// this acts as the callback that is called every frame
+fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    if app.events.read().contains_key_code(KeyCode::Escape) || data.time.elapsed().as_secs_f64() > 60.0 {
+        return Ok(true);
+    }
+    Ok(false)  // return true to exit the app
+}
+
+struct AppData {
+    pub time: std::time::Instant,
+}
+
+impl AppData {
+    // a wave scrolling to the left over time
+    fn wave(&self, frequency: f64, function: fn(f64) -> f64) -> Vec<f64> {
+        let phase = self.time.elapsed().as_secs_f64() * 2.0;
+        (0..SAMPLES).map(|sample| function(sample as f64 / SAMPLES as f64 * std::f64::consts::TAU * frequency + phase)).collect()
+    }
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        time: std::time::Instant::now(),
+    };
+    let mut scene = Scene::new();
+
+    // a sine wave in braille (2x4 pixels per cell)
+    CanvasWidgetBuilder::<AppData>::builder(String::from("sine"))
+        .with_border(true)
+        .with_title(String::from("Sine (braille), escape: exit"))
+        .with_renderer(Box::new(|canvas, data: &mut AppData| {
+            canvas.set_color(Some((90, 200, 255)));
+            canvas.plot(&data.wave(2.0, f64::sin));
+        }))
+        .with_dynamic_position((0, 0), (0.0, 0.0))
+        .with_dynamic_size((0, 0), (1.0, 0.7))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    // a cosine sparkline in half blocks (1x2 pixels per cell)
+    CanvasWidgetBuilder::<AppData>::builder(String::from("cosine"))
+        .with_border(true)
+        .with_title(String::from("Cosine (half blocks)"))
+        .with_mode(CanvasMode::HalfBlock)
+        .with_renderer(Box::new(|canvas, data: &mut AppData| {
+            canvas.set_color(Some((255, 160, 80)));
+            canvas.plot(&data.wave(3.0, f64::cos));
+        }))
+        .with_dynamic_position((0, 0), (0.0, 0.7))
+        .with_dynamic_size((0, 0), (1.0, 0.3))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    app.scene = Some(scene);
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
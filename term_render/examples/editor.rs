@@ -0,0 +1,64 @@
+use term_render::widget_impls::{WidgetBuilder};
+use term_render::{self, event_handler::KeyCode};
+use term_render::render::{Colorize, ColorType};
+use term_render::widget::Scene;
+use term_render::render::Span;
+use term_render::color;
+
+// this acts as the callback that is called every frame
+fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    // return is used for newlines within the editor, so escape exits instead
+    if app.events.read().contains_key_code(KeyCode::Escape) {
+        return Ok(true);
+    }
+    if data.time.elapsed().as_secs_f64() > 60.0 {
+        return Ok(true);
+    }
+
+    Ok(false)  // return true to exit the app
+}
+
+struct AppData {
+    pub time: std::time::Instant,
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        time: std::time::Instant::now(),
+    };
+    let mut scene = Scene::new();
+
+    // a small 5 line editor (7 tall to make room for the border); click it to start typing
+    term_render::widget_impls::TypingWidgetBuilder::<AppData>::builder(String::from("editor"))
+        .with_border(true)
+        .with_title(String::from("Notes"))
+        .with_multiline_renderer(Box::new(|_size, _position, text, selected, _data| {
+            Some(text.lines.iter().enumerate().map(|(index, line)| {
+                if !selected || index != text.cursor.0 {
+                    return Span::from_tokens(vec![color!(line.to_string(), White)]);
+                }
+                // drawing the cursor (and any input method composition) within the current line
+                let split = line.char_indices().nth(text.cursor.1).map(|(byte, _)| byte).unwrap_or(line.len());
+                Span::from_tokens(vec![
+                    color!(line[..split].to_string(), White),
+                    color!(text.preedit.to_string(), White, Underline),
+                    color!(String::from("|"), BrightBlack),
+                    color!(line[split..].to_string(), White),
+                ])
+            }).collect())
+        }))
+        .with_position((3, 3))
+        .with_size((40, 7))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    app.scene = Some(scene);
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use term_render::widget_impls::WidgetBuilder;
+use term_render::{self, event_handler::KeyCode};
+use term_render::render::{Colorize, ColorType};
+use term_render::widget::Scene;
+use term_render::render::Span;
+use term_render::color;
+
+// this acts as the callback that is called every frame
+fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    if app.events.read().contains_key_code(KeyCode::Escape) {
+        return Ok(true);
+    }
+    if data.time.elapsed().as_secs_f64() > 60.0 {
+        return Ok(true);
+    }
+
+    Ok(false)  // return true to exit the app
+}
+
+struct AppData {
+    pub time: std::time::Instant,
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        time: std::time::Instant::now(),
+    };
+    let mut scene = Scene::new();
+
+    // a 60 column title bar, fading from orange to purple behind the text
+    let title = format!("{:^60}", "TermRender");
+    let mut title_bar = Span::gradient_background(&title, (255, 120, 40), (110, 40, 200));
+    title_bar.add_color(ColorType::Bold);
+
+    // a rainbow, spread over multiple stops
+    let rainbow = Span::gradient_multi(
+        &"=".repeat(60),
+        &[
+            (0.0, (255, 0, 0)),
+            (0.2, (255, 160, 0)),
+            (0.4, (240, 240, 0)),
+            (0.6, (0, 200, 0)),
+            (0.8, (0, 120, 255)),
+            (1.0, (160, 0, 255)),
+        ],
+    );
+
+    term_render::widget_impls::StaticTextWidgetBuilder::<AppData>::builder(String::from("splash"))
+        .with_renderer(vec![
+            title_bar,
+            rainbow,
+            Span::from_tokens(vec![]),
+            Span::gradient("A splash screen, one color per column", (120, 220, 255), (255, 255, 255)),
+            Span::from_tokens(vec![]),
+            Span::from_tokens(vec![color!("Escape to exit", BrightBlack)]),
+        ])
+        .with_position((3, 2))
+        .with_size((60, 6))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    app.scene = Some(scene);
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use term_render::render::{Image, ImageProtocol};
+use term_render::widget_impls::{ImageWidget, ImageWidgetBuilder, WidgetBuilder};
+use term_render::{self, event_handler::KeyCode};
+use term_render::widget::Scene;
+
+// run with `cargo run --example image --features images`
+// pressing `b` toggles between the detected protocol and half blocks
+
+// this acts as the callback that is called every frame
+fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    if app.events.read().contains_key_code(KeyCode::Escape) {
+        return Ok(true);
+    }
+    if app.events.read().contains_char('b') {
+        data.blocks = !data.blocks;
+        let protocol = if data.blocks {  ImageProtocol::Blocks  } else {  ImageProtocol::detect()  };
+        if let Some(scene) = &mut app.scene &&
+            let Some(widget) = scene.widget_as_mut::<ImageWidget<AppData>>("image")
+        {
+            widget.set_protocol(protocol);
+        }
+    }
+    Ok(false)  // return true to exit the app
+}
+
+struct AppData {
+    pub blocks: bool,
+}
+
+// a shaded sphere on a transparent background
+fn sphere(size: u32) -> Image {
+    let mut pixels = Vec::with_capacity((size * size * 4) as usize);
+    let radius = size as f64 / 2.0;
+    for y in 0..size {
+        for x in 0..size {
+            let (dx, dy) = ((x as f64 + 0.5 - radius) / radius, (y as f64 + 0.5 - radius) / radius);
+            let distance = dx * dx + dy * dy;
+            if distance > 1.0 {
+                pixels.extend_from_slice(&[0, 0, 0, 0]);
+                continue;
+            }
+            // lit from the top left
+            let light = ((1.0 - distance).sqrt() * 0.7 - dx * 0.3 - dy * 0.3).clamp(0.05, 1.0);
+            pixels.extend_from_slice(&[(80.0 * light) as u8, (160.0 * light) as u8, (255.0 * light) as u8, 255]);
+        }
+    }
+    Image::new(size, size, pixels).unwrap()
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {  blocks: false  };
+    let mut scene = Scene::new();
+
+    ImageWidgetBuilder::<AppData>::builder(String::from("image"))
+        .with_border(true)
+        .with_title(format!("{:?}, b: toggle blocks, escape: exit", ImageProtocol::detect()))
+        .with_image(sphere(256))
+        .with_dynamic_position((2, 1), (0.0, 0.0))
+        .with_dynamic_size((-4, -2), (1.0, 1.0))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    app.scene = Some(scene);
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
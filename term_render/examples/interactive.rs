@@ -1,18 +1,17 @@
 use term_render::widget_impls::{WidgetBuilder};
-use term_render::{self, event_handler::KeyCode};
-use term_render::render::{Colorize, ColorType};
+use term_render::render::{Colorize, ColorType, Padding};
 use term_render::widget::{Widget, Scene};
 use term_render::render::Span;
 use term_render::color;
 
 // this acts as the callback that is called every frame
 // this is the entry point and any logic needs to branch out from here
-fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+fn app_callback(_app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
     // place app logic here
-    if app.events.read().contains_key_code(KeyCode::Return) {
+    if data.quitting {
         return Ok(true);
     }
-    if data.time.elapsed().as_secs_f64() > 15.0 {
+    if data.time.elapsed().as_secs_f64() > 60.0 {
         return Ok(true);
     }
     
@@ -25,94 +24,41 @@ struct AppData {
     // just an example field to show how to use the data struct
     // in this case, the example is being used to close the app after a set amount of time has elapsed
     pub time: std::time::Instant,
+    // set by the quit dialog
+    pub quitting: bool,
 }
 
-// handles the behavior of the secondary button that spawns another popup
-fn secondary_button_behavior(
-    widget: &mut dyn Widget<AppData>,
-    _data: &mut AppData,
-    app: &mut term_render::App<AppData>,
-    scene: &mut Scene<AppData>,
-    state: &term_render::widget_impls::ButtonState,
-) {
-    let widget_index = scene.get_widget_index(widget.get_window_ref()).unwrap_or(0);
-    let mut pressed = state == &term_render::widget_impls::ButtonState::Pressed(term_render::event_handler::MouseEventType::Left);
-    if let Some(event) = &app.events.read().mouse_event {
-        pressed &= !scene.is_click_blocked(widget_index, event.position).unwrap_or(false);
-    }
-    if !pressed {  return  }
-    if widget.get_children_indexes().is_empty() {  // in the case of this scene, the only children of a widget would be the unique popup
-        // creating another popup, this time as a static textbox showing different Widget implementations and how they can be nested
-        term_render::widget_impls::StaticWidgetBuilder::<AppData>::builder(String::from("popup_final"))
-            .with_border(true)
-            .with_renderer(Box::new(|_size, _position, _data| {
-                Some(vec![Span::from_tokens(vec![color!("This is another popup!", Red)])])
-            }))
-            .with_dynamic_position((18, 7), (0.1, 0.1))
-            .with_dynamic_size((35, 14), (0.1, 0.1))
-            .with_depth(2)
-            .with_parent(scene.get_widget_index(String::from("popup")))
-            .add_to_scene(app, scene)
-            .unwrap();
-    } else {
-        scene.remove_widget_ref(String::from("popup_final"), &mut *app.renderer.write()).unwrap();
-    }
-}
-
-// handles the behavior of the main button that spawns a popup
+// handles the behavior of the main button, which asks whether to quit through a dialog
 fn base_button_behavior(
     widget: &mut dyn Widget<AppData>,
     _data: &mut AppData,
     app: &mut term_render::App<AppData>,
     scene: &mut Scene<AppData>,
 ) {
-    // if the widget was pressed, create a popup widget as its child
+    // if the widget was pressed, open the dialog (while it's open, the dialog blocks clicks to everything else)
     let widget_index = scene.get_widget_index(widget.get_window_ref()).unwrap_or(0);
     let pressed = if let Some(event) = &app.events.read().mouse_event {
         if event.event_type == term_render::event_handler::MouseEventType::Left &&
             event.state == term_render::event_handler::MouseState::Press &&
             widget.is_collided(event.position) {
-            !scene.is_click_blocked(widget_index, event.position).unwrap_or(false)
+            !scene.is_click_blocked_all(widget_index, event.position, app).unwrap_or(false)
         } else {  false  }
     } else {  false  };
     if !pressed {  return  }
-    if widget.get_children_indexes().is_empty() {  // in the case of this scene, the only children of a widget would be the unique popup
-        // creating another widget, but this time as a button type which shows how it can simplify the user end code
-        term_render::widget_impls::ButtonWidgetBuilder::<AppData>::builder(String::from("popup"))
-            .with_border(true)
-            .with_renderer(Box::new(|_size, _position, state, _data| {
-                // rendering different text colors based on the button state (this could definitely be done better with less code, but this is just an example)
-                match state {
-                    term_render::widget_impls::ButtonState::Pressed(_) => {
-                        Some(vec![Span::from_tokens(vec![color!("This is a popup!", Red)])])
-                    },
-                    term_render::widget_impls::ButtonState::Held(_) => {
-                        Some(vec![Span::from_tokens(vec![color!("This is a popup!", BrightRed)])])
-                    },
-                    term_render::widget_impls::ButtonState::Released(_) => {
-                        Some(vec![Span::from_tokens(vec![color!("This is a popup!", Yellow)])])
-                    },
-                    term_render::widget_impls::ButtonState::Hovered => {
-                        Some(vec![Span::from_tokens(vec![color!("This is a popup!", BrightWhite, Bold)])])
-                    },
-                    term_render::widget_impls::ButtonState::Normal => {
-                        Some(vec![Span::from_tokens(vec![color!("This is a popup!", White)])])
-                    },
-                }
-            }))
-            .with_update_handler(Box::new(|widget, _data, app: &mut term_render::App<AppData>, scene, state| {
-                // basic logic could be placed within the closure and/or a separate function could be called
-                secondary_button_behavior(widget, _data, app, scene, state);
-            }))
-            .with_position((15, 5))
-            .with_size((30, 12))
-            .with_depth(1)
-            .with_parent(scene.get_widget_index(String::from("button")))
-            .add_to_scene(app, scene)
-            .unwrap();
-    } else {
-        scene.remove_widget_ref(String::from("popup"), &mut *app.renderer.write()).unwrap();
-    }
+    // the dialog is centered on the terminal, takes the focus, and removes itself once a button is chosen
+    // (Left/Right/Tab move between the buttons, Return chooses one, and Escape picks "Keep going")
+    term_render::widget_impls::DialogBuilder::<AppData>::builder(String::from("quit dialog"))
+        .with_title(String::from("Quit"))
+        .with_color(ColorType::BrightRed)  // a red border (set when it's built, so it's never drawn uncolored)
+        .with_message(Span::from_tokens(vec![color!("Are you sure you want to quit?", BrightWhite, Bold)]))
+        .with_buttons(vec![String::from("Quit"), String::from("Keep going")])
+        .with_default_button(1)
+        .with_update_handler(Some(Box::new(|data: &mut AppData, choice| {
+            data.quitting = choice == 0;
+        })))
+        .with_parent(Some(widget_index))
+        .add_to_scene(app, scene)
+        .unwrap();
 }
 
 // the main function has to be async to enable the use of async tasks further down the road (mainly under the hood)
@@ -124,6 +70,7 @@ async fn main() -> tokio::io::Result<()> {
     // defining the application data (stored separately from the app instance, but linked from the instance)
     let data = AppData {
         time: std::time::Instant::now(),
+        quitting: false,
     };
     
     // creating a new scene which will have widgets attached to it
@@ -133,21 +80,28 @@ async fn main() -> tokio::io::Result<()> {
     // because this is static, I'll be using it as the root level widget
     term_render::widget_impls::StaticTextWidgetBuilder::<AppData>::builder(String::from("text"))
         .with_border(true)
+        .with_padding(Padding { left: 2, right: 2, top: 1, bottom: 1 })  // giving the text some room to breathe
         .with_renderer(vec![
             Span::from_tokens(vec![color!("This is a text!")]),
+            // clickable links (terminals without hyperlink support just show the text)
+            Span::from_tokens(vec![
+                "Repo".colorizes(vec![ColorType::Blue, ColorType::Underline]).with_link("https://github.com/AndrewDMorgan/TermRender"),
+                color!(" | "),
+                "Issues".colorize(ColorType::BrightGreen).hyperlink("https://github.com/AndrewDMorgan/TermRender/issues"),
+            ]),
         ])
         .with_dynamic_position((0, 0), (0.5, 0.5))
-        .with_size((35, 3))
+        .with_size((35, 6))
         .add_to_scene(&mut app, &mut scene)
         .unwrap();
     
     // creating a random typing field to show how it can be used
     term_render::widget_impls::TypingWidgetBuilder::<AppData>::builder(String::from("Typing box"))
         .with_border(true)
-        .with_renderer(Box::new(|_size, _position, content, selected, _data| {
-            Some(vec![Span::from_tokens(vec![color!(match !(content[0].is_empty() && content[1].is_empty()) {
-                true if selected => format!("{}|{}", content[0], content[1]),  // showing the current content with a cursor at the end
-                true => format!("{}{}", content[0], content[1]),
+        .with_renderer(Box::new(|_size, _position, content, _selection, selected, _data| {
+            Some(vec![Span::from_tokens(vec![color!(match !(content[0].is_empty() && content[1].is_empty() && content[2].is_empty()) {
+                true if selected => format!("{}{}|{}", content[0], content[1], content[2]),  // showing the current content with a cursor at the end
+                true => format!("{}{}{}", content[0], content[1], content[2]),
                 // a placeholder text when empty to indicate where to type
                 false => String::from("Type here..."),
             }, Green)])])
@@ -161,7 +115,7 @@ async fn main() -> tokio::io::Result<()> {
     term_render::widget_impls::DynamicWidgetBuilder::<AppData>::builder(String::from("button"))
         .with_border(true)
         .with_renderer(Box::new(|_size, _position, _data| {
-            Some(vec![Span::from_tokens(vec![color!("I am thing!")])])
+            Some(vec![Span::from_tokens(vec![color!("Click me to quit!")])])
         }))
         .with_position((10, 10))
         .with_size((50, 10))
@@ -177,11 +131,26 @@ async fn main() -> tokio::io::Result<()> {
     
     // attaching the scene to the main app
     app.scene = Some(scene);
-    
+
+    // passing --record <file> saves the input, and --replay <file> plays it back (handy for reproducing bugs)
+    let args: Vec<String> = std::env::args().collect();
+    for pair in args.windows(2) {
+        match pair[0].as_str() {
+            "--record" => app.record_input(&pair[1])?,
+            "--replay" => app.replay_input(&pair[1], 1.0)?,
+            _ => {},
+        }
+    }
+
     // running the application with the provided callback function
+    // the closure can capture (and mutate) local variables, rather than everything going through the app data
+    let mut frames = 0usize;
     app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        frames += 1;
         app_callback(app_instance, data)
     }).await.unwrap();
+    drop(app);  // restoring the terminal before printing
+    println!("Ran for {} frames", frames);
     
     Ok(())
 }
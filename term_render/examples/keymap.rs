@@ -0,0 +1,81 @@
+use term_render::widget_impls::{StaticWidgetBuilder, StatusBarWidgetBuilder, WidgetBuilder};
+use term_render::{self, event_handler::KeyCode, KeyChord, KeyMap};
+use term_render::render::{Colorize, ColorType};
+use term_render::widget::Scene;
+use term_render::render::Span;
+use term_render::color;
+
+// this acts as the callback that is called every frame (the key bindings have already run by now)
+fn app_callback(_app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    Ok(data.quitting || data.time.elapsed().as_secs_f64() > 60.0)  // return true to exit the app
+}
+
+struct AppData {
+    pub time: std::time::Instant,
+    pub quitting: bool,
+    pub inserting: bool,
+    pub saves: usize,
+}
+
+// the bindings (and hints) while not inserting
+fn normal_mode() -> KeyMap<AppData> {
+    KeyMap::<AppData>::new("NORMAL")
+        .with_binding(KeyChord::char('i'), "Insert", Box::new(|data, app| {
+            data.inserting = true;
+            app.attach_keymap(insert_mode());
+        }))
+        .with_binding(KeyChord::ctrl('s'), "Save", Box::new(|data, _app| data.saves += 1))
+        .with_binding(KeyChord::char('q'), "Quit", Box::new(|data, _app| data.quitting = true))
+}
+
+// the bindings (and hints) while inserting
+fn insert_mode() -> KeyMap<AppData> {
+    KeyMap::<AppData>::new("INSERT")
+        .with_binding(KeyChord::key(KeyCode::Escape), "Normal", Box::new(|data, app| {
+            data.inserting = false;
+            app.attach_keymap(normal_mode());
+        }))
+        .with_binding(KeyChord::ctrl('s'), "Save", Box::new(|data, _app| data.saves += 1))
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        time: std::time::Instant::now(),
+        quitting: false,
+        inserting: false,
+        saves: 0,
+    };
+    let mut scene = Scene::new();
+
+    StaticWidgetBuilder::<AppData>::builder(String::from("state"))
+        .with_border(true)
+        .with_renderer(Box::new(|_size, _position, data: &mut AppData| {
+            Some(vec![
+                Span::from_tokens(vec![color!(match data.inserting {
+                    true => "Inserting (Escape goes back)",
+                    false => "Press i to insert, or q to quit",
+                }, BrightWhite)]),
+                Span::from_tokens(vec![color!(format!("Saved {} times", data.saves), Green)]),
+            ])
+        }))
+        .with_position((2, 2))
+        .with_size((40, 4))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    // the status bar sits along the bottom of the terminal and follows the attached keymap
+    StatusBarWidgetBuilder::<AppData>::builder(String::from("status bar"))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+    app.attach_keymap(normal_mode());
+
+    app.scene = Some(scene);
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
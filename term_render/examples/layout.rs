@@ -0,0 +1,84 @@
+use term_render::widget_impls::WidgetBuilder;
+use term_render::layout::{Constraint, Layout};
+use term_render::{self, event_handler::KeyCode};
+use term_render::render::{Colorize, ColorType};
+use term_render::widget::Scene;
+use term_render::render::Span;
+use term_render::color;
+
+// this acts as the callback that is called every frame
+fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    if app.events.read().contains_key_code(KeyCode::Escape) {
+        return Ok(true);
+    }
+    if data.time.elapsed().as_secs_f64() > 60.0 {
+        return Ok(true);
+    }
+
+    Ok(false)  // return true to exit the app
+}
+
+struct AppData {
+    pub time: std::time::Instant,
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        time: std::time::Instant::now(),
+    };
+    let mut scene = Scene::new();
+
+    // the screen is split into the panes and a single line status bar at the bottom
+    let rows = Layout::vertical([Constraint::Fill, Constraint::Length(1)]);
+    // the sidebar takes a quarter of the width, but the main pane never shrinks below 30 columns
+    let panes = Layout::horizontal([Constraint::Percent(0.25), Constraint::Min(30)])
+        .with_gap(1)
+        .within(rows.region(0));
+
+    term_render::widget_impls::StaticTextWidgetBuilder::<AppData>::builder(String::from("sidebar"))
+        .with_border(true)
+        .with_title(String::from("Sidebar"))
+        .with_renderer(vec![
+            Span::from_tokens(vec![color!("Inbox", White)]),
+            Span::from_tokens(vec![color!("Drafts", White)]),
+            Span::from_tokens(vec![color!("Archive", White)]),
+        ])
+        .with_sap(panes.cell(0))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    // the main pane shows its own size, which follows the terminal as it's resized
+    term_render::widget_impls::DynamicWidgetBuilder::<AppData>::builder(String::from("main"))
+        .with_border(true)
+        .with_title(String::from("Main"))
+        .with_renderer(Box::new(|size, position, _data| {
+            Some(vec![
+                Span::from_tokens(vec![color!(format!("Size: {}x{}", size.0, size.1), BrightWhite)]),
+                Span::from_tokens(vec![color!(format!("Position: ({}, {})", position.0, position.1), BrightWhite)]),
+                Span::from_tokens(vec![color!("Resize the terminal to see the panes adjust", BrightBlack)]),
+            ])
+        }))
+        .with_sap(panes.cell(1))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    term_render::widget_impls::DynamicWidgetBuilder::<AppData>::builder(String::from("status"))
+        .with_renderer(Box::new(|_size, _position, data| {
+            Some(vec![Span::from_tokens(vec![
+                color!(format!(" Escape to exit | running for {}s", data.time.elapsed().as_secs()), Black, OnWhite)
+            ])])
+        }))
+        .with_sap(rows.cell(1))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    app.scene = Some(scene);
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use term_render::widget_impls::{WidgetBuilder, ListEvent};
+use term_render::{self, event_handler::KeyCode};
+use term_render::render::{Colorize, ColorType};
+use term_render::widget::Scene;
+use term_render::render::Span;
+use term_render::color;
+
+// this acts as the callback that is called every frame
+fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    // return activates the selected item, so escape exits instead
+    if app.events.read().contains_key_code(KeyCode::Escape) {
+        return Ok(true);
+    }
+    if data.time.elapsed().as_secs_f64() > 60.0 {
+        return Ok(true);
+    }
+
+    Ok(false)  // return true to exit the app
+}
+
+struct AppData {
+    pub time: std::time::Instant,
+    pub status: String,
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        time: std::time::Instant::now(),
+        status: String::from("Click the list to select it"),
+    };
+    let mut scene = Scene::new();
+
+    // a list of more items than fit, using the default (reversed) highlight
+    let items = (1..=50).map(|index| {
+        Span::from_tokens(vec![color!(format!("Item {}", index), White)])
+    }).collect();
+    term_render::widget_impls::ListWidgetBuilder::<AppData>::builder(String::from("list"))
+        .with_border(true)
+        .with_title(String::from("Items"))
+        .with_items(items)
+        .with_update_handler(Some(Box::new(|widget, data, _app, _scene, events| {
+            for event in events {
+                data.status = match event {
+                    ListEvent::SelectionChanged(index) => format!("Selected item {}", index + 1),
+                    ListEvent::Activated(index) => format!("Activated item {} of {}", index + 1, widget.get_items().len()),
+                };
+            }
+        })))
+        .with_position((3, 3))
+        .with_size((24, 12))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    // showing the last event the list reported
+    term_render::widget_impls::DynamicWidgetBuilder::<AppData>::builder(String::from("status"))
+        .with_renderer(Box::new(|_size, _position, data| {
+            Some(vec![Span::from_tokens(vec![color!(data.status.clone(), BrightBlack)])])
+        }))
+        .with_position((3, 16))
+        .with_size((32, 1))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    app.scene = Some(scene);
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
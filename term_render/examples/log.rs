@@ -0,0 +1,60 @@
+use term_render::widget_impls::WidgetBuilder;
+use term_render::{self, event_handler::KeyCode};
+use term_render::render::{Colorize, ColorType};
+use term_render::widget::Scene;
+use term_render::render::Span;
+use term_render::color;
+
+// this acts as the callback that is called every frame
+fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    if app.events.read().contains_key_code(KeyCode::Escape) || data.time.elapsed().as_secs_f64() > 60.0 {
+        return Ok(true);
+    }
+
+    Ok(false)  // return true to exit the app
+}
+
+struct AppData {
+    pub time: std::time::Instant,
+    pub lines_logged: usize,
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        time: std::time::Instant::now(),
+        lines_logged: 0,
+    };
+    let mut scene = Scene::new();
+
+    // a tail of the newest lines; scroll up (wheel, or click then PageUp) to pause it, End to follow again
+    term_render::widget_impls::LogWidgetBuilder::<AppData>::builder(String::from("log"))
+        .with_border(true)
+        .with_title(String::from("Log"))
+        .with_max_lines(5000)
+        .with_update_handler(Some(Box::new(|log, data, _app, _scene| {
+            // pretending a few hundred lines arrive every second
+            let due = (data.time.elapsed().as_secs_f64() * 300.0) as usize;
+            while data.lines_logged < due {
+                data.lines_logged += 1;
+                let line = match data.lines_logged % 7 {
+                    0 => Span::from_tokens(vec![color!("WARN ", Yellow), color!(format!("request {} was slow", data.lines_logged), White)]),
+                    _ => Span::from_tokens(vec![color!("INFO ", Green), color!(format!("handled request {}", data.lines_logged), White)]),
+                };
+                log.push_line(line);
+            }
+        })))
+        .with_dynamic_position((0, 0), (0.0, 0.0))
+        .with_dynamic_size((0, 0), (1.0, 1.0))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    app.scene = Some(scene);
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use term_render::widget_impls::{WidgetBuilder};
+use term_render::{self, event_handler::KeyCode};
+use term_render::render::{Colorize, ColorType};
+use term_render::widget::Scene;
+use term_render::render::Span;
+use term_render::color;
+
+// this acts as the callback that is called every frame
+fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    if app.events.read().contains_key_code(KeyCode::Return) {
+        return Ok(true);
+    }
+    if data.time.elapsed().as_secs_f64() > 60.0 {
+        return Ok(true);
+    }
+
+    Ok(false)  // return true to exit the app
+}
+
+struct AppData {
+    pub time: std::time::Instant,
+    pub username: String,
+    pub password: String,
+}
+
+// renders a single line field with a cursor at the end while it's selected
+fn render_field(content: &[&str; 3], selected: bool) -> Option<Vec<Span>> {
+    Some(vec![Span::from_tokens(vec![color!(match selected {
+        true => format!("{}{}|{}", content[0], content[1], content[2]),
+        false => format!("{}{}{}", content[0], content[1], content[2]),
+    }, White)])])
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        time: std::time::Instant::now(),
+        username: String::new(),
+        password: String::new(),
+    };
+    let mut scene = Scene::new();
+
+    // the placeholder shows until the field is clicked or typed into
+    term_render::widget_impls::TypingWidgetBuilder::<AppData>::builder(String::from("username"))
+        .with_border(true)
+        .with_title(String::from("Username"))
+        .with_placeholder(Span::from_tokens(vec![color!("Type your username...", BrightBlack, Dim)]))
+        .with_renderer(Box::new(|_size, _position, content, _selection, selected, _data| {
+            render_field(content, selected)
+        }))
+        // copying the typed text into the app data so the rest of the app can read it
+        .with_update_handler(Some(Box::new(|widget, data, _app, _scene| {
+            data.username = widget.typed_text.clone();
+        })))
+        .with_position((3, 3))
+        .with_size((32, 3))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    // the render function only ever sees the mask characters
+    term_render::widget_impls::TypingWidgetBuilder::<AppData>::builder(String::from("password"))
+        .with_border(true)
+        .with_title(String::from("Password"))
+        .with_placeholder(Span::from_tokens(vec![color!("Type your password...", BrightBlack, Dim)]))
+        .with_mask_char('•')
+        .with_renderer(Box::new(|_size, _position, content, _selection, selected, _data| {
+            render_field(content, selected)
+        }))
+        // the widget still holds the real password, so it can be read back like any other field
+        .with_update_handler(Some(Box::new(|widget, data, _app, _scene| {
+            data.password = widget.typed_text.clone();
+        })))
+        .with_position((3, 7))
+        .with_size((32, 3))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    // showing what the app has read from the fields
+    term_render::widget_impls::DynamicWidgetBuilder::<AppData>::builder(String::from("status"))
+        .with_renderer(Box::new(|_size, _position, data| {
+            Some(vec![
+                Span::from_tokens(vec![color!(format!("Username: {}", data.username), White)]),
+                Span::from_tokens(vec![color!(format!("Password length: {}", data.password.chars().count()), White)]),
+                Span::from_tokens(vec![color!("Press return to log in", BrightBlack)]),
+            ])
+        }))
+        .with_position((3, 11))
+        .with_size((32, 3))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    app.scene = Some(scene);
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
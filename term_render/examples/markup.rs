@@ -0,0 +1,66 @@
+use term_render::widget_impls::WidgetBuilder;
+use term_render::{self, event_handler::KeyCode};
+use term_render::widget::Scene;
+use term_render::render::Span;
+
+// the whole help screen is authored as a single markup string (see `Span::from_markup` for the tags)
+const HELP: &str = "\
+[bold underline]Keyboard shortcuts[/]
+
+  [bold cyan]Ctrl+S[/]   save the current file
+  [bold cyan]Ctrl+O[/]   open a file
+  [bold cyan]Ctrl+F[/]   search (matches are [black on_yellow] highlighted [/])
+  [bold cyan]Escape[/]   close this help
+
+[bold underline]Log levels[/]
+
+  [rgb(120,120,120)]TRACE[/]  [[verbose]] everything
+  [green]INFO[/]   normal operation
+  [yellow]WARN[/]   something looks off
+  [bold white on_red] ERROR [/] something broke
+
+[dim italic]Colors can also come from the 256-color palette: [ansi(208)]orange[/], [ansi(45)]sky[/]";
+
+// this acts as the callback that is called every frame
+fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    if app.events.read().contains_key_code(KeyCode::Escape) || data.time.elapsed().as_secs_f64() > 60.0 {
+        return Ok(true);
+    }
+
+    Ok(false)  // return true to exit the app
+}
+
+struct AppData {
+    pub time: std::time::Instant,
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    // parsing up front, so a typo in a tag is reported (with its position) before the terminal is taken over
+    let lines = HELP.lines().map(Span::from_markup).collect::<Result<Vec<Span>, _>>()
+        .unwrap_or_else(|error| panic!("Invalid help markup: {}", error));
+
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        time: std::time::Instant::now(),
+    };
+    let mut scene = Scene::new();
+
+    term_render::widget_impls::StaticTextWidgetBuilder::<AppData>::builder(String::from("help"))
+        .with_border(true)
+        .with_title(String::from("Help"))
+        .with_padding(term_render::render::Padding { left: 2, right: 2, top: 1, bottom: 1 })
+        .with_renderer(lines)
+        .with_position((3, 2))
+        .with_size((68, 19))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    app.scene = Some(scene);
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
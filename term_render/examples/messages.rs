@@ -0,0 +1,73 @@
+use term_render::widget_impls::WidgetBuilder;
+use term_render::{self, event_handler::KeyCode};
+use term_render::render::{Colorize, ColorType};
+use term_render::widget::Scene;
+use term_render::render::Span;
+use term_render::color;
+
+// the message posted by the background task
+struct Tick;
+
+// this acts as the callback that is called every frame
+fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    if app.events.read().contains_key_code(KeyCode::Escape) {
+        return Ok(true);
+    }
+    if data.time.elapsed().as_secs_f64() > 60.0 {
+        return Ok(true);
+    }
+
+    Ok(false)  // return true to exit the app
+}
+
+struct AppData {
+    pub time: std::time::Instant,
+    pub ticks: usize,
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        time: std::time::Instant::now(),
+        ticks: 0,
+    };
+    let mut scene = Scene::new();
+
+    term_render::widget_impls::DynamicWidgetBuilder::<AppData>::builder(String::from("ticks"))
+        .with_border(true)
+        .with_title(String::from("Messages"))
+        .with_renderer(Box::new(|_size, _position, data| {
+            Some(vec![
+                Span::from_tokens(vec![color!(format!("Ticks from the background task: {}", data.ticks), White)]),
+                Span::from_tokens(vec![]),
+                Span::from_tokens(vec![color!("escape: exit", BrightBlack)]),
+            ])
+        }))
+        // the widget reads the messages posted since the last frame
+        .with_update_handler(Box::new(|_widget, data, app, _scene| {
+            data.ticks += app.messages::<Tick>().len();
+        }))
+        .with_position((3, 2))
+        .with_size((45, 5))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    app.scene = Some(scene);
+
+    // a background task, posting a tick every 500ms (it stops once the app is dropped and the send fails)
+    let sender = app.message_sender::<Tick>();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_millis(500));
+        loop {
+            interval.tick().await;
+            if sender.send(Tick).is_err() {  break;  }
+        }
+    });
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
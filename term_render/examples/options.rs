@@ -0,0 +1,99 @@
+use term_render::widget_impls::{WidgetBuilder};
+use term_render::{self, event_handler::KeyCode};
+use term_render::render::{Colorize, ColorType};
+use term_render::widget::Scene;
+use term_render::render::Span;
+use term_render::color;
+
+// this acts as the callback that is called every frame
+fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    // space and return toggle the focused checkbox, so escape exits instead
+    if app.events.read().contains_key_code(KeyCode::Escape) {
+        return Ok(true);
+    }
+    if data.time.elapsed().as_secs_f64() > 60.0 {
+        return Ok(true);
+    }
+
+    Ok(false)  // return true to exit the app
+}
+
+// the options are copied in here by the widgets' update handlers whenever they change
+struct AppData {
+    pub time: std::time::Instant,
+    pub line_numbers: bool,
+    pub word_wrap: bool,
+    pub tab_size: usize,
+}
+
+const TAB_SIZES: [usize; 3] = [2, 4, 8];
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        time: std::time::Instant::now(),
+        line_numbers: true,
+        word_wrap: false,
+        tab_size: 4,
+    };
+    let mut scene = Scene::new();
+
+    term_render::widget_impls::CheckboxWidgetBuilder::<AppData>::builder(String::from("line numbers"))
+        .with_label(Span::from_tokens(vec![color!("Show line numbers", White)]))
+        .with_checked(data.line_numbers)
+        .with_update_handler(Some(Box::new(|_widget, data, _app, _scene, checked| {
+            data.line_numbers = checked;
+        })))
+        .with_position((3, 3))
+        .with_size((30, 1))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    term_render::widget_impls::CheckboxWidgetBuilder::<AppData>::builder(String::from("word wrap"))
+        .with_label(Span::from_tokens(vec![color!("Wrap long lines", White)]))
+        .with_checked(data.word_wrap)
+        .with_update_handler(Some(Box::new(|_widget, data, _app, _scene, checked| {
+            data.word_wrap = checked;
+        })))
+        .with_position((3, 4))
+        .with_size((30, 1))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    // the radio group gets a border, so its options start a row and column in
+    term_render::widget_impls::RadioGroupWidgetBuilder::<AppData>::builder(String::from("tab size"))
+        .with_border(true)
+        .with_title(String::from("Tab size"))
+        .with_options(TAB_SIZES.iter().map(|size| {
+            Span::from_tokens(vec![color!(format!("{} spaces", size), White)])
+        }).collect())
+        .with_selected(1)
+        .with_update_handler(Some(Box::new(|_widget, data, _app, _scene, selected| {
+            data.tab_size = TAB_SIZES[selected];
+        })))
+        .with_position((3, 6))
+        .with_size((30, 5))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    // showing the options as the app sees them
+    term_render::widget_impls::DynamicWidgetBuilder::<AppData>::builder(String::from("summary"))
+        .with_renderer(Box::new(|_size, _position, data| {
+            Some(vec![Span::from_tokens(vec![color!(format!(
+                "numbers: {}, wrap: {}, tab: {}", data.line_numbers, data.word_wrap, data.tab_size
+            ), BrightBlack)])])
+        }))
+        .with_position((3, 12))
+        .with_size((40, 1))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    app.scene = Some(scene);
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
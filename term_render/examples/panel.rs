@@ -0,0 +1,141 @@
+use term_render::widget_impls::{ButtonState, ButtonWidgetBuilder, DynamicWidgetBuilder, StaticTextWidgetBuilder, TypingWidgetBuilder, WidgetBuilder};
+use term_render::{self, event_handler::{KeyCode, MouseEventType, MouseState}};
+use term_render::render::{Colorize, ColorType};
+use term_render::widget::Scene;
+use term_render::render::Span;
+use term_render::color;
+
+// this acts as the callback that is called every frame
+fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    if app.events.read().contains_key_code(KeyCode::Escape) || data.time.elapsed().as_secs_f64() > 60.0 {
+        return Ok(true);
+    }
+
+    Ok(false)  // return true to exit the app
+}
+
+struct AppData {
+    pub time: std::time::Instant,
+    // where the panel was grabbed, relative to its top left corner, while it's being dragged
+    pub grab: Option<(i16, i16)>,
+    pub name: String,
+    pub status: String,
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        time: std::time::Instant::now(),
+        grab: None,
+        name: String::new(),
+        status: String::from("Drag the panel by its top border (escape to quit)"),
+    };
+    let mut scene = Scene::new();
+
+    DynamicWidgetBuilder::<AppData>::builder(String::from("status"))
+        .with_renderer(Box::new(|_size, _position, data| {
+            Some(vec![Span::from_tokens(vec![color!(data.status.clone(), BrightBlack)])])
+        }))
+        .with_position((0, 0))
+        .with_dynamic_size((0, 1), (1.0, 0.0))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    // the panel only draws its border; everything inside of it is a child positioned relative to it
+    let panel = DynamicWidgetBuilder::<AppData>::builder(String::from("panel"))
+        .with_border(true)
+        .with_renderer(Box::new(|_size, _position, _data| Some(vec![])))
+        // pressing on the top border grabs the panel, and holding the button down drags it around
+        .with_update_handler(Box::new(|widget, data, app, _scene| {
+            let Some(event) = app.events.read().mouse_event.clone() else {  return;  };
+            let Some(size_and_position) = widget.get_size_and_position_mut() else {  return;  };
+            let (size, position) = size_and_position.get_last();
+            let (x, y) = (event.position.0 as i16, event.position.1 as i16);
+            match (event.event_type, event.state, data.grab) {
+                (MouseEventType::Left, MouseState::Press, _) if event.position.1 == position.1 &&
+                    event.position.0 >= position.0 && event.position.0 < position.0 + size.0 => {
+                    data.grab = Some((x - position.0 as i16, y - position.1 as i16));
+                },
+                (MouseEventType::Left, MouseState::Hold, Some(grab)) => {
+                    // the children are repositioned (and redrawn) by the scene once the panel moves
+                    size_and_position.position_offset = ((x - grab.0).max(0), (y - grab.1).max(1));
+                },
+                (_, MouseState::Release, _) => data.grab = None,
+                _ => {},
+            }
+        }))
+        .with_position((4, 2))
+        .with_size((36, 9))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    // every child is positioned from the panel's top left corner, and clipped to it
+    StaticTextWidgetBuilder::<AppData>::builder(String::from("title"))
+        .with_renderer(vec![Span::from_tokens(vec![color!("Who's there?", BrightWhite, Bold)])])
+        .with_position((2, 1))
+        .with_size((32, 1))
+        .with_depth(1)
+        .with_parent(Some(panel))
+        .with_relative_to_parent(true)
+        .with_clip_to_parent(true)
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    TypingWidgetBuilder::<AppData>::builder(String::from("name"))
+        .with_border(true)
+        .with_title(String::from("Name"))
+        .with_placeholder(Span::from_tokens(vec![color!("Type your name...", BrightBlack, Dim)]))
+        .with_renderer(Box::new(|_size, _position, content, _selection, selected, _data| {
+            Some(vec![Span::from_tokens(vec![color!(match selected {
+                true => format!("{}{}|{}", content[0], content[1], content[2]),
+                false => format!("{}{}{}", content[0], content[1], content[2]),
+            }, White)])])
+        }))
+        .with_update_handler(Some(Box::new(|widget, data, _app, _scene| {
+            data.name = widget.typed_text.clone();
+        })))
+        .with_position((2, 2))
+        .with_size((32, 3))
+        .with_depth(1)
+        .with_parent(Some(panel))
+        .with_relative_to_parent(true)
+        .with_clip_to_parent(true)
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    for (index, label) in ["Greet", "Clear"].into_iter().enumerate() {
+        ButtonWidgetBuilder::<AppData>::builder(String::from(label))
+            .with_border(true)
+            .with_renderer(Box::new(move |_size, _position, state, _toggled, _data: &mut AppData| {
+                Some(vec![Span::from_tokens(vec![match state {
+                    ButtonState::Hovered => label.colorize(ColorType::BrightYellow),
+                    _ => color!(label),
+                }])])
+            }))
+            .with_update_handler(Box::new(move |_button, data, _app, _scene, state, _toggled| {
+                let ButtonState::Pressed(_) = state else {  return;  };
+                data.status = match index {
+                    0 if !data.name.is_empty() => format!("Hello, {}!", data.name),
+                    0 => String::from("Type a name first"),
+                    _ => String::from("Drag the panel by its top border (escape to quit)"),
+                };
+            }))
+            .with_position((2 + index as u16 * 12, 5))
+            .with_size((10, 3))
+            .with_depth(1)
+            .with_parent(Some(panel))
+            .with_relative_to_parent(true)
+            .with_clip_to_parent(true)
+            .add_to_scene(&mut app, &mut scene)
+            .unwrap();
+    }
+
+    app.scene = Some(scene);
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use term_render::widget_impls::WidgetBuilder;
+use term_render::{self, event_handler::KeyCode};
+use term_render::render::{Colorize, ColorType};
+use term_render::widget::Scene;
+use term_render::render::Span;
+use term_render::color;
+
+// this acts as the callback that is called every frame
+// it deliberately panics after a couple of seconds; the terminal should come back in a usable state
+// with the panic's message printed below the shell prompt (rather than lost on the alternate screen)
+fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    if app.events.read().contains_key_code(KeyCode::Escape) {
+        return Ok(true);
+    }
+    if data.time.elapsed().as_secs_f64() > 2.0 {
+        panic!("Something went wrong after {} frames", data.frames);
+    }
+    data.frames += 1;
+
+    Ok(false)  // return true to exit the app
+}
+
+struct AppData {
+    pub time: std::time::Instant,
+    pub frames: usize,
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        time: std::time::Instant::now(),
+        frames: 0,
+    };
+    let mut scene = Scene::new();
+
+    term_render::widget_impls::StaticTextWidgetBuilder::<AppData>::builder(String::from("warning"))
+        .with_border(true)
+        .with_title(String::from("Panic"))
+        .with_renderer(vec![
+            Span::from_tokens(vec![color!("This app panics in two seconds", Red)]),
+        ])
+        .with_position((3, 2))
+        .with_size((40, 3))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    app.scene = Some(scene);
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
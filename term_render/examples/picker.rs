@@ -0,0 +1,60 @@
+use term_render::widget_impls::{WidgetBuilder, ListEvent};
+use term_render::{self, event_handler::KeyCode};
+use term_render::render::{Colorize, ColorType};
+use term_render::widget::Scene;
+use term_render::render::Span;
+use term_render::color;
+
+// a picker drawn in 10 rows beneath the prompt (like `fzf --height`), leaving the shell's history alone
+
+struct AppData {
+    pub choices: Vec<String>,
+    pub picked: Option<String>,
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new_inline(10)?;
+    let data = AppData {
+        choices: ["apple", "banana", "cherry", "date", "elderberry", "fig", "grape", "honeydew", "kiwi", "lemon", "mango"]
+            .into_iter().map(String::from).collect(),
+        picked: None,
+    };
+    let mut scene = Scene::new();
+
+    // the list fills the region (positions are relative to its top row)
+    let items = data.choices.iter().map(|choice| {
+        Span::from_tokens(vec![color!(choice.clone(), White)])
+    }).collect();
+    let list = term_render::widget_impls::ListWidgetBuilder::<AppData>::builder(String::from("picker"))
+        .with_border(true)
+        .with_title(String::from("Pick a fruit (return to pick, escape to cancel)"))
+        .with_items(items)
+        .with_update_handler(Some(Box::new(|_widget, data: &mut AppData, _app, _scene, events| {
+            for event in events {
+                if let ListEvent::Activated(index) = event {
+                    data.picked = data.choices.get(*index).cloned();
+                }
+            }
+        })))
+        .with_position((0, 0))
+        .with_dynamic_size((0, 0), (1.0, 1.0))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+    scene.set_focus(list).unwrap();
+    app.scene = Some(scene);
+
+    let mut picked = None;
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        picked = data.picked.clone();
+        Ok::<bool, ()>(picked.is_some() || app_instance.events.read().contains_key_code(KeyCode::Escape))
+    }).await.unwrap();
+    // the region is cleared once the app is dropped, so the result prints right after the prompt
+    drop(app);
+
+    match picked {
+        Some(picked) => println!("{}", picked),
+        None => std::process::exit(1),
+    }
+    Ok(())
+}
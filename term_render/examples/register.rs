@@ -0,0 +1,69 @@
+use term_render::widget_impls::{FormBuilder, FormRow, SizeAndPosition};
+use term_render::{self, event_handler::KeyCode};
+use term_render::render::{Colorize, ColorType};
+use term_render::widget::Scene;
+use term_render::render::Span;
+use term_render::color;
+
+// a registration form built from rows; Tab moves between the fields and buttons
+
+struct AppData {
+    pub registered: Option<std::collections::HashMap<String, String>>,
+    pub cancelled: bool,
+}
+
+fn text(text: &str) -> Span {
+    Span::from_tokens(vec![color!(text, White)])
+}
+
+fn hint(text: &str) -> Span {
+    Span::from_tokens(vec![color!(text, BrightBlack, Dim)])
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        registered: None,
+        cancelled: false,
+    };
+    let mut scene = Scene::new();
+
+    // the form stays centered as the terminal is resized
+    let form = FormBuilder::<AppData>::new(String::from("register"))
+        .with_rows(vec![
+            FormRow::Label(text("Username")),
+            FormRow::Input { name: String::from("username"), placeholder: hint("Pick a username...") },
+            FormRow::Label(text("Email")),
+            FormRow::Input { name: String::from("email"), placeholder: hint("you@example.com") },
+            FormRow::Separator,
+            FormRow::Label(text("Display name (optional)")),
+            FormRow::Input { name: String::from("display name"), placeholder: hint("Shown to other users") },
+            FormRow::Separator,
+            FormRow::Buttons(vec![text("Register"), text("Cancel")]),
+        ])
+        .with_sap(SizeAndPosition::new_dynamic((40, 20), (-20, -10), (0.0, 0.0), (0.5, 0.5)))
+        .with_border(true)
+        .with_title(String::from("Create an account"))
+        .with_submit_handler(Box::new(|data: &mut AppData, button, values| {
+            match button {
+                0 => data.registered = Some(values.clone()),
+                _ => data.cancelled = true,
+            }
+        }));
+    form.add_to_scene(&mut app, &mut scene).unwrap();
+    scene.focus_next(&app.renderer.read());
+    app.scene = Some(scene);
+
+    let mut registered = None;
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        registered = data.registered.clone();
+        Ok::<bool, ()>(registered.is_some() || data.cancelled || app_instance.events.read().contains_key_code(KeyCode::Escape))
+    }).await.unwrap();
+    drop(app);
+
+    if let Some(values) = registered {
+        println!("Registered {} <{}>", values["username"], values["email"]);
+    }
+    Ok(())
+}
@@ -0,0 +1,87 @@
+// Compares the bytes written per frame by the old line based renderer (re-emitting every dirty window
+// line through the render closures) against the cell based renderer (diffing composited frames).
+// This doesn't need a terminal; run it with `cargo run --release --example render_bench`.
+use term_render::render::{Colorize, ColorType, Frame, Rect, Span, Window};
+
+const WIDTH: u16 = 200;
+const HEIGHT: u16 = 60;
+const FRAMES: usize = 100;
+
+// three overlapping windows, each updating a single line (a counter) every frame
+fn create_windows() -> Vec<Window> {
+    let mut windows = vec![];
+    for (index, (position, size)) in [((0, 0), (200, 60)), ((20, 10), (100, 30)), ((60, 25), (80, 20))].into_iter().enumerate() {
+        let mut window = Window::new(position, index as u16, size);
+        window.bordered();
+        window.titled(format!("Window {}", index));
+        let lines = (0..size.1).map(|row| {
+            Span::from_tokens(vec![
+                format!("Row {} of window {} ", row, index).colorizes(vec![ColorType::BrightWhite]),
+                "with some colored text".colorizes(vec![ColorType::Blue, ColorType::Bold]),
+            ])
+        }).collect();
+        window.try_update_lines(lines);
+        windows.push(window);
+    }
+    windows
+}
+
+fn update_windows(windows: &mut [Window], frame: usize) {
+    for (index, window) in windows.iter_mut().enumerate() {
+        window.update_line(2, Span::from_tokens(vec![
+            format!("Frame {} (window {})", frame, index).colorizes(vec![ColorType::Yellow]),
+        ]));
+    }
+}
+
+// the old renderer: every dirty line of every window, each preceded by a cursor move, drawn by depth
+fn line_based_bytes(windows: &mut [Window]) -> usize {
+    let mut calls = vec![];
+    for window in windows.iter_mut() {
+        calls.append(&mut window.get_render_closure(&Rect { width: WIDTH, height: HEIGHT }));
+    }
+    calls.sort_by_key(|call| (call.3, call.2, call.1));
+    calls.into_iter().map(|call| format!("\x1b[{};{}H", call.2 + 1, call.1 + 1).len() + call.0().len()).sum()
+}
+
+// the new renderer: compositing every window into a frame and only writing the changed cells
+fn cell_based_bytes(windows: &[Window], previous: &mut Option<Frame>) -> usize {
+    let mut frame = Frame::new(WIDTH, HEIGHT);
+    let mut sorted: Vec<&Window> = windows.iter().collect();
+    sorted.sort_by_key(|window| window.depth);
+    for window in sorted {
+        window.draw_to_frame(&mut frame);
+    }
+    let mut output = String::new();
+    frame.diff(previous.as_ref(), &mut output);
+    *previous = Some(frame);
+    output.len()
+}
+
+fn main() {
+    let mut line_windows = create_windows();
+    let mut cell_windows = create_windows();
+    let mut previous = None;
+
+    let first_line = line_based_bytes(&mut line_windows);
+    let first_cell = cell_based_bytes(&cell_windows, &mut previous);
+
+    let (mut line_total, mut cell_total) = (0, 0);
+    let start = std::time::Instant::now();
+    for frame in 0..FRAMES {
+        update_windows(&mut line_windows, frame);
+        line_total += line_based_bytes(&mut line_windows);
+    }
+    let line_time = start.elapsed();
+    let start = std::time::Instant::now();
+    for frame in 0..FRAMES {
+        update_windows(&mut cell_windows, frame);
+        cell_total += cell_based_bytes(&cell_windows, &mut previous);
+    }
+    let cell_time = start.elapsed();
+
+    println!("{}x{} terminal, three overlapping windows, {} frames", WIDTH, HEIGHT, FRAMES);
+    println!("{:<12} {:>14} {:>16} {:>14}", "renderer", "first frame", "bytes per frame", "time per frame");
+    println!("{:<12} {:>14} {:>16} {:>14?}", "line based", first_line, line_total / FRAMES, line_time / FRAMES as u32);
+    println!("{:<12} {:>14} {:>16} {:>14?}", "cell based", first_cell, cell_total / FRAMES, cell_time / FRAMES as u32);
+}
@@ -0,0 +1,88 @@
+use term_render::widget_impls::{WidgetBuilder};
+use term_render::{self, event_handler::KeyCode};
+use term_render::render::{Colorize, ColorType};
+use term_render::widget::Scene;
+use term_render::render::Span;
+use term_render::color;
+
+// the settings shown in the form (far more than fit on screen at once)
+const SETTINGS: [&str; 12] = [
+    "Username", "Email", "Display name", "Language", "Time zone", "Theme",
+    "Font size", "Tab width", "Auto save", "Backup path", "Log level", "Editor",
+];
+
+// this acts as the callback that is called every frame
+fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    if app.events.read().contains_key_code(KeyCode::Return) {
+        return Ok(true);
+    }
+    if data.time.elapsed().as_secs_f64() > 30.0 {
+        return Ok(true);
+    }
+
+    Ok(false)  // return true to exit the app
+}
+
+struct AppData {
+    pub time: std::time::Instant,
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        time: std::time::Instant::now(),
+    };
+    let mut scene = Scene::new();
+
+    // the scroll view acts as the viewport for the form; scroll with the mouse wheel, or click
+    // inside of it and use page up/down
+    let scroll_view = term_render::widget_impls::ScrollViewWidgetBuilder::<AppData>::builder(String::from("settings"))
+        .with_border(true)
+        .with_title(String::from("Settings"))
+        .with_scrollbar(true)
+        .with_position((3, 3))
+        .with_size((52, 14))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    // the children are laid out as if the view wasn't scrolled, starting at the top of the viewport
+    // (inside the border), and extending well past the bottom of it
+    for (index, setting) in SETTINGS.iter().enumerate() {
+        let y = 4 + index as u16 * 3;
+        term_render::widget_impls::StaticTextWidgetBuilder::<AppData>::builder(format!("label_{}", setting))
+            .with_renderer(vec![
+                Span::from_tokens(vec![color!(format!("{}:", setting), BrightWhite, Bold)]),
+            ])
+            .with_position((5, y + 1))
+            .with_size((15, 1))
+            .with_depth(1)
+            .with_parent(Some(scroll_view))
+            .add_to_scene(&mut app, &mut scene)
+            .unwrap();
+
+        term_render::widget_impls::TypingWidgetBuilder::<AppData>::builder(format!("field_{}", setting))
+            .with_border(true)
+            .with_renderer(Box::new(|_size, _position, content, _selection, selected, _data| {
+                Some(vec![Span::from_tokens(vec![color!(match !(content[0].is_empty() && content[1].is_empty() && content[2].is_empty()) {
+                    true if selected => format!("{}{}|{}", content[0], content[1], content[2]),
+                    true => format!("{}{}{}", content[0], content[1], content[2]),
+                    false => String::from("..."),
+                }, Green)])])
+            }))
+            .with_position((21, y))
+            .with_size((32, 3))
+            .with_depth(1)
+            .with_parent(Some(scroll_view))
+            .add_to_scene(&mut app, &mut scene)
+            .unwrap();
+    }
+
+    app.scene = Some(scene);
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
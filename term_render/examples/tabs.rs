@@ -0,0 +1,109 @@
+use term_render::widget_impls::{ButtonState, ButtonWidgetBuilder, StaticTextWidgetBuilder, TabWidgetBuilder, TypingWidgetBuilder, WidgetBuilder};
+use term_render::{self, event_handler::KeyCode};
+use term_render::render::{Colorize, ColorType};
+use term_render::widget::Scene;
+use term_render::render::Span;
+use term_render::color;
+
+// this acts as the callback that is called every frame
+fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    if app.events.read().contains_key_code(KeyCode::Escape) || data.time.elapsed().as_secs_f64() > 60.0 {
+        return Ok(true);
+    }
+
+    Ok(false)  // return true to exit the app
+}
+
+struct AppData {
+    pub time: std::time::Instant,
+    // the last button pressed on the buttons tab
+    pub last_pressed: Option<usize>,
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        time: std::time::Instant::now(),
+        last_pressed: None,
+    };
+    let mut scene = Scene::new();
+
+    // the labels are clickable, and Left/Right switch tabs once the tab bar has the focus
+    let mut builder = TabWidgetBuilder::<AppData>::builder(String::from("tabs"));
+    let text_tab = builder.add_tab(Span::from_tokens(vec![color!("Text")]));
+    let form_tab = builder.add_tab(Span::from_tokens(vec![color!("Form")]));
+    let buttons_tab = builder.add_tab(Span::from_tokens(vec![color!("Buttons")]));
+    let tabs = builder
+        .with_border(true)
+        .with_title(String::from("Tabs"))
+        .with_position((1, 1))
+        .with_size((60, 16))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    // the children are positioned like any other widget (the body starts below the label bar, at row 3)
+    let text = StaticTextWidgetBuilder::<AppData>::builder(String::from("text view"))
+        .with_renderer(vec![
+            Span::from_tokens(vec![color!("Each tab shows its own group of widgets.", BrightWhite, Bold)]),
+            Span::from_tokens(vec![color!("Widgets on the other tabs are hidden, so they can't be clicked or focused.")]),
+            Span::from_tokens(vec![color!("Press Escape to quit.", BrightBlack)]),
+        ])
+        .with_position((3, 4))
+        .with_size((56, 3))
+        .with_parent(Some(tabs))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+    scene.set_widget_tab(text, text_tab, &mut app.renderer.write()).unwrap();
+
+    // a small form; Tab moves between the fields
+    for (row, label) in ["Name", "Email"].into_iter().enumerate() {
+        let field = TypingWidgetBuilder::<AppData>::builder(format!("{} field", label))
+            .with_border(true)
+            .with_title(String::from(label))
+            .with_renderer(Box::new(|_size, _position, content, _selection, selected, _data| {
+                Some(vec![Span::from_tokens(vec![color!(match !(content[0].is_empty() && content[1].is_empty() && content[2].is_empty()) {
+                    true if selected => format!("{}{}|{}", content[0], content[1], content[2]),
+                    true => format!("{}{}{}", content[0], content[1], content[2]),
+                    false => String::from("Type here..."),
+                }, Green)])])
+            }))
+            .with_position((3, 4 + row as u16 * 3))
+            .with_size((40, 3))
+            .with_parent(Some(tabs))
+            .add_to_scene(&mut app, &mut scene)
+            .unwrap();
+        scene.set_widget_tab(field, form_tab, &mut app.renderer.write()).unwrap();
+    }
+
+    // a grid of buttons which report which one was pressed last
+    for index in 0..6 {
+        let button = ButtonWidgetBuilder::<AppData>::builder(format!("button {}", index))
+            .with_border(true)
+            .with_renderer(Box::new(move |_size, _position, state, _toggled, data: &mut AppData| {
+                let label = format!("Button {}", index + 1);
+                Some(vec![Span::from_tokens(vec![match state {
+                    ButtonState::Hovered => label.colorize(ColorType::BrightYellow),
+                    _ if data.last_pressed == Some(index) => label.colorize(ColorType::BrightGreen),
+                    _ => color!(label),
+                }])])
+            }))
+            .with_update_handler(Box::new(move |_button, data, _app, _scene, state, _toggled| {
+                if let ButtonState::Pressed(_) = state {  data.last_pressed = Some(index);  }
+            }))
+            .with_position((3 + (index % 3) as u16 * 18, 4 + (index / 3) as u16 * 3))
+            .with_size((16, 3))
+            .with_parent(Some(tabs))
+            .add_to_scene(&mut app, &mut scene)
+            .unwrap();
+        scene.set_widget_tab(button, buttons_tab, &mut app.renderer.write()).unwrap();
+    }
+
+    app.scene = Some(scene);
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
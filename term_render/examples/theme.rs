@@ -0,0 +1,90 @@
+use term_render::widget_impls::WidgetBuilder;
+use term_render::{self, event_handler::KeyCode};
+use term_render::render::{BorderStyle, Span, Theme};
+use term_render::widget::Scene;
+
+// this acts as the callback that is called every frame
+fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    if app.events.read().contains_key_code(KeyCode::Escape) {
+        return Ok(true);
+    }
+    // t toggles between the built-in themes; the borders and titles follow it automatically,
+    // while the render closures pick it up through the app data
+    if app.events.read().contains_char('t') {
+        let theme = match data.theme.get_name() {
+            "dark" => Theme::light(),
+            _ => Theme::dark(),
+        };
+        app.set_theme(theme);
+        data.theme = app.theme();
+    }
+    if data.time.elapsed().as_secs_f64() > 60.0 {
+        return Ok(true);
+    }
+
+    Ok(false)  // return true to exit the app
+}
+
+struct AppData {
+    pub time: std::time::Instant,
+    pub theme: Theme,
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        time: std::time::Instant::now(),
+        theme: app.theme(),
+    };
+    let mut scene = Scene::new();
+
+    term_render::widget_impls::DynamicWidgetBuilder::<AppData>::builder(String::from("main"))
+        .with_border(true)
+        .with_title(String::from("Themes"))
+        .with_theme_slot("accent")
+        .with_border_style(BorderStyle::Rounded)
+        .with_renderer(Box::new(|_size, _position, data| {
+            let theme = &data.theme;
+            Some(vec![
+                Span::from_tokens(vec![
+                    theme.colorize("Current theme: ", "text"),
+                    theme.colorize(theme.get_name(), "accent"),
+                ]),
+                Span::from_tokens(vec![]),
+                Span::from_tokens(vec![theme.colorize("Everything went fine", "success")]),
+                Span::from_tokens(vec![theme.colorize("Something looks off", "warning")]),
+                Span::from_tokens(vec![theme.colorize("Something went wrong", "error")]),
+                Span::from_tokens(vec![theme.colorize(" A selected item ", "selection")]),
+                Span::from_tokens(vec![]),
+                Span::from_tokens(vec![theme.colorize("t: toggle theme, escape: exit", "muted")]),
+            ])
+        }))
+        .with_position((3, 2))
+        .with_size((40, 12))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    term_render::widget_impls::DynamicWidgetBuilder::<AppData>::builder(String::from("side"))
+        .with_border(true)
+        .with_title(String::from("Side"))
+        .with_theme_slot("border")
+        .with_renderer(Box::new(|_size, _position, data| {
+            Some(vec![Span::from_tokens(vec![
+                data.theme.colorize(&format!("{}s", data.time.elapsed().as_secs()), "text"),
+            ])])
+        }))
+        .with_position((45, 2))
+        .with_size((20, 12))
+        .with_parent(scene.get_widget_index(String::from("main")))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    app.scene = Some(scene);
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use term_render::widget_impls::WidgetBuilder;
+use term_render::{self, event_handler::KeyCode};
+use term_render::render::{Colorize, ColorType};
+use term_render::widget::Scene;
+use term_render::render::Span;
+use term_render::color;
+
+// shows a toast notification, which removes itself from the scene after 3 seconds
+fn show_toast(app: &mut term_render::App<AppData>, data: &mut AppData) {
+    let Some(mut scene) = app.scene.take() else {  return;  };
+    data.toasts += 1;
+    let name = format!("toast_{}", data.toasts);
+    // stacking the toasts up from the bottom of the main window
+    let row = 14 - (data.visible_toasts as u16 % 4) * 3;
+    term_render::widget_impls::StaticTextWidgetBuilder::<AppData>::builder(name.clone())
+        .with_border(true)
+        .with_renderer(vec![
+            Span::from_tokens(vec![color!(format!("Notification #{}", data.toasts), BrightGreen)]),
+        ])
+        .with_position((34, row))
+        .with_size((24, 3))
+        .with_depth(1)
+        .with_parent(scene.get_widget_index(String::from("main")))
+        .add_to_scene(app, &mut scene)
+        .unwrap();
+    app.scene = Some(scene);
+    data.visible_toasts += 1;
+
+    app.set_timeout(std::time::Duration::from_secs(3), Box::new(move |data: &mut AppData, app| {
+        if let Some(scene) = &mut app.scene {
+            scene.remove_widget_ref(name.clone(), &mut app.renderer.write()).unwrap();
+        }
+        data.visible_toasts -= 1;
+    }));
+}
+
+// this acts as the callback that is called every frame
+fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    if app.events.read().contains_key_code(KeyCode::Escape) {
+        return Ok(true);
+    }
+    if app.events.read().contains_char('n') {
+        show_toast(app, data);
+    }
+    if data.time.elapsed().as_secs_f64() > 60.0 {
+        return Ok(true);
+    }
+
+    Ok(false)  // return true to exit the app
+}
+
+struct AppData {
+    pub time: std::time::Instant,
+    pub toasts: usize,
+    pub visible_toasts: usize,
+    pub seconds: u64,
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        time: std::time::Instant::now(),
+        toasts: 0,
+        visible_toasts: 0,
+        seconds: 0,
+    };
+    let mut scene = Scene::new();
+
+    term_render::widget_impls::DynamicWidgetBuilder::<AppData>::builder(String::from("main"))
+        .with_border(true)
+        .with_title(String::from("Timers"))
+        .with_renderer(Box::new(|_size, _position, data| {
+            Some(vec![
+                Span::from_tokens(vec![color!(format!("Seconds (from an interval): {}", data.seconds), White)]),
+                Span::from_tokens(vec![]),
+                Span::from_tokens(vec![color!("n: show a notification, escape: exit", BrightBlack)]),
+            ])
+        }))
+        .with_position((3, 2))
+        .with_size((58, 17))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    app.scene = Some(scene);
+
+    // ticking once a second, without tracking the time in the callback
+    app.set_interval(std::time::Duration::from_secs(1), Box::new(|data: &mut AppData, _app| {
+        data.seconds += 1;
+    }));
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use term_render::widget_impls::{WidgetBuilder, SizeAndPosition};
+use term_render::{self, event_handler::KeyCode};
+use term_render::render::{Colorize, ColorType};
+use term_render::widget::Scene;
+use term_render::render::Span;
+use term_render::color;
+
+// this acts as the callback that is called every frame
+fn app_callback(app: &mut term_render::App<AppData>, data: &mut AppData) -> Result<bool, ()> {
+    if app.events.read().contains_key_code(KeyCode::Escape) {
+        return Ok(true);
+    }
+    // space toggles the tooltip
+    if app.events.read().contains_char(' ') &&
+        let Some(scene) = &mut app.scene &&
+        let Some(index) = scene.get_widget_index(String::from("tooltip"))
+    {
+        let visible = scene.is_widget_visible(index);
+        scene.set_widget_visible(index, !visible, &mut app.renderer.write()).unwrap();
+    }
+    if data.time.elapsed().as_secs_f64() > 60.0 {
+        return Ok(true);
+    }
+
+    Ok(false)  // return true to exit the app
+}
+
+struct AppData {
+    pub time: std::time::Instant,
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 10)]
+async fn main() -> tokio::io::Result<()> {
+    let mut app = term_render::App::new()?;
+    let data = AppData {
+        time: std::time::Instant::now(),
+    };
+    let mut scene = Scene::new();
+
+    // a full-screen background which changes every second, so the tooltip has to composite over it
+    term_render::widget_impls::DynamicWidgetBuilder::<AppData>::builder(String::from("background"))
+        .with_renderer(Box::new(|size, _position, data| {
+            let offset = data.time.elapsed().as_secs() as usize;
+            Some((0..size.1 as usize).map(|row| {
+                let text: String = (0..size.0 as usize).map(|column| {
+                    match (row + column + offset) % 4 {
+                        0 => '/',
+                        _ => '.',
+                    }
+                }).collect();
+                Span::from_tokens(vec![color!(text, BrightBlack)])
+            }).collect())
+        }))
+        .with_sap(SizeAndPosition::new_dynamic((0, 0), (0, 0), (1.0, 1.0), (0.0, 0.0)))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+
+    // the tooltip's lines are different lengths; the background shows through after each of them
+    term_render::widget_impls::StaticTextWidgetBuilder::<AppData>::builder(String::from("tooltip"))
+        .with_renderer(vec![
+            Span::from_tokens(vec![color!(" A floating tooltip ", Black, OnYellow)]),
+            Span::from_tokens(vec![color!(" the background shows ", Black, OnYellow)]),
+            Span::from_tokens(vec![color!(" through ", Black, OnYellow)]),
+            Span::from_tokens(vec![]),
+            Span::from_tokens(vec![color!(" space: toggle, escape: exit ", Black, OnYellow)]),
+        ])
+        .with_position((6, 4))
+        .with_size((32, 5))
+        .with_depth(1)
+        .with_parent(scene.get_widget_index(String::from("background")))
+        .add_to_scene(&mut app, &mut scene)
+        .unwrap();
+    app.renderer.write().get_window_reference_mut(String::from("tooltip")).set_transparent(true);
+
+    app.scene = Some(scene);
+
+    app.run(data, |data, app_instance: &mut term_render::App<AppData>| {
+        app_callback(app_instance, data)
+    }).await.unwrap();
+
+    Ok(())
+}
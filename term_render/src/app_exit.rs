@@ -0,0 +1,178 @@
+// What Ctrl+C does while the app runs, and the callback run once the app stops (for saving state, flushing
+// files, etc.), whatever stopped it. Raw mode turns Ctrl+C into a normal key press rather than SIGINT, so the
+// main loop decides what it does; a real SIGINT (i.e. `kill -INT`) is only handled while Ctrl+C doesn't exit.
+
+use crate::event_handler::{InputEvent, KeyModifiers};
+use crate::App;
+
+/// How many times Ctrl+C has to be pressed within `CTRL_C_REPEAT_WINDOW` to exit the app while it's ignored
+/// or forwarded (a fail-safe so the app can always be stopped).
+pub const CTRL_C_REPEAT_EXIT: usize = 3;
+
+/// The window the repeated Ctrl+C presses have to land within (see `CTRL_C_REPEAT_EXIT`).
+pub const CTRL_C_REPEAT_WINDOW: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// The exit code used when a second SIGINT arrives before the app has finished stopping from the first.
+pub const SIGINT_EXIT_CODE: i32 = 130;
+
+/// The callback run once the app stops (see `App::on_exit`).
+pub type ExitCallback<C> = Box<dyn FnOnce(&mut C) + Send>;
+
+/// What Ctrl+C does while the app runs (see `App::set_ctrlc_behavior`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CtrlC {
+    /// Exits the app, unless the focused widget has a selection for it to copy.
+    #[default] Exit,
+    /// Drops the key press, so neither the app nor the widgets see it.
+    Ignore,
+    /// Leaves the key press for the app and widgets to handle (i.e. copying in an editor).
+    Forward,
+}
+
+/// Checks if the event is Ctrl+C.
+fn is_ctrlc(event: &InputEvent) -> bool {
+    matches!(event, InputEvent::Char('c', held) if held.contains(&KeyModifiers::Control))
+}
+
+impl<C> App<C> {
+    /// Sets what Ctrl+C does while the app runs. By default, it exits the app (see `CtrlC::Exit`).
+    ///
+    /// While Ctrl+C is ignored or forwarded, pressing it `CTRL_C_REPEAT_EXIT` (3) times within
+    /// `CTRL_C_REPEAT_WINDOW` (1 second) still exits, so the app can always be stopped. On unix, a SIGINT
+    /// also stops the app cleanly (running the exit callback, see `on_exit`), and a second SIGINT before it
+    /// has finished stopping ends the process immediately with `SIGINT_EXIT_CODE`.
+    pub fn set_ctrlc_behavior(&mut self, behavior: CtrlC) {
+        self.ctrlc_behavior = behavior;
+    }
+
+    /// Gets what Ctrl+C does while the app runs (see `set_ctrlc_behavior`).
+    pub fn get_ctrlc_behavior(&self) -> CtrlC {
+        self.ctrlc_behavior
+    }
+
+    /// Sets the callback run once the app stops, with the app data. It runs exactly once, right after the main
+    /// loop stops and before the rendering and event handling are shut down, whatever stopped the app: the main
+    /// callback asking to exit or returning an error, Ctrl+C, a panic, or a background task failing (i.e. a
+    /// replay finishing, see `replay_input`). The callback is used up by the run it's called in, so it has to
+    /// be set again before running the app again. A panicking exit callback is treated like any other panic
+    /// while running.
+    /// # Example
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use term_render::event_handler::KeyModifiers;
+    /// # let runtime = tokio::runtime::Builder::new_multi_thread().worker_threads(4).enable_all().build().unwrap();
+    /// let saved = std::sync::Arc::new(AtomicUsize::new(0));
+    /// let mut app = term_render::App::<u32>::new_headless(20, 3);
+    /// // saving how many frames ran
+    /// let on_exit = |saved: &std::sync::Arc<AtomicUsize>| -> term_render::ExitCallback<u32> {
+    ///     let saved = saved.clone();
+    ///     Box::new(move |frames: &mut u32| {  saved.fetch_add(*frames as usize, Ordering::Relaxed);  })
+    /// };
+    /// # runtime.block_on(async {
+    ///
+    /// // the callback asking to exit
+    /// app.on_exit(on_exit(&saved));
+    /// app.run(0, |frames, _app| {
+    ///     *frames += 1;
+    ///     Ok::<bool, ()>(*frames == 2)
+    /// }).await.unwrap();
+    /// assert_eq!(saved.load(Ordering::Relaxed), 2);
+    ///
+    /// // the callback returning an error
+    /// app.on_exit(on_exit(&saved));
+    /// let _ = app.run(0, |frames, _app| {
+    ///     *frames += 1;
+    ///     Err::<bool, &str>("failed")
+    /// }).await;
+    /// assert_eq!(saved.load(Ordering::Relaxed), 3);
+    ///
+    /// // Ctrl+C
+    /// app.on_exit(on_exit(&saved));
+    /// app.run(0, |frames, app| {
+    ///     *frames += 1;
+    ///     let mut events = app.events.write();
+    ///     events.key_modifiers.push(KeyModifiers::Control);
+    ///     events.char_events.push('c');
+    ///     Ok::<bool, ()>(false)
+    /// }).await.unwrap();
+    /// assert_eq!(saved.load(Ordering::Relaxed), 4);
+    ///
+    /// // the event handling stopping the app (an empty recording finishing its replay)
+    /// let path = std::env::temp_dir().join(format!("on_exit_{}.input", std::process::id()));
+    /// std::fs::write(&path, b"TRIN\x01\x00").unwrap();
+    /// app.replay_input(&path, 0.0).unwrap();
+    /// app.on_exit(on_exit(&saved));
+    /// app.run(0, |frames, _app| {
+    ///     *frames = 10;
+    ///     Ok::<bool, ()>(false)
+    /// }).await.unwrap();
+    /// assert_eq!(saved.load(Ordering::Relaxed), 14);
+    /// std::fs::remove_file(&path).unwrap();
+    ///
+    /// // without setting it again, nothing runs
+    /// app.run(0, |_frames, _app| Ok::<bool, ()>(true)).await.unwrap();
+    /// assert_eq!(saved.load(Ordering::Relaxed), 14);
+    /// # });
+    ///
+    /// // the callback panicking (the panic carries on once the app has stopped)
+    /// app.on_exit(on_exit(&saved));
+    /// let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| runtime.block_on(async {
+    ///     app.run(0, |frames, _app| -> Result<bool, ()> {
+    ///         *frames = 100;
+    ///         panic!("failed")
+    ///     }).await
+    /// })));
+    /// assert!(result.is_err());
+    /// assert_eq!(saved.load(Ordering::Relaxed), 114);
+    /// ```
+    pub fn on_exit(&mut self, callback: ExitCallback<C>) {
+        self.exit_callback = Some(callback);
+    }
+
+    /// Runs the exit callback (if any), catching any panic.
+    pub(crate) fn run_exit_callback(&mut self, data: &mut C) -> Result<(), Box<dyn std::any::Any + Send>> {
+        let Some(callback) = self.exit_callback.take() else {  return Ok(());  };
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| callback(data)))
+    }
+
+    /// Registers the SIGINT handlers while Ctrl+C doesn't exit (see `set_ctrlc_behavior`), returning their ids.
+    #[cfg(unix)]
+    pub(crate) fn register_interrupt_signal(&mut self) -> Vec<signal_hook::SigId> {
+        self.interrupt_requested.store(false, std::sync::atomic::Ordering::Relaxed);
+        if self.ctrlc_behavior == CtrlC::Exit {  return Vec::new();  }
+        // the shutdown has to be registered first, so it only sees the flag set by an earlier signal
+        [
+            signal_hook::flag::register_conditional_shutdown(signal_hook::consts::SIGINT, SIGINT_EXIT_CODE, self.interrupt_requested.clone()),
+            signal_hook::flag::register(signal_hook::consts::SIGINT, self.interrupt_requested.clone()),
+        ].into_iter().flatten().collect()
+    }
+
+    /// Handles this frame's Ctrl+C presses before anything sees them, dropping them if they're ignored.
+    /// Returns true if the app should exit: after a SIGINT, or when Ctrl+C was pressed enough times in a row
+    /// while ignored or forwarded.
+    pub(crate) fn handle_ctrlc(&mut self) -> bool {
+        if self.interrupt_requested.load(std::sync::atomic::Ordering::Relaxed) {  return true;  }
+        if self.ctrlc_behavior == CtrlC::Exit {  return false;  }
+        let mut events = self.events.write();
+        let presses = events.peek_events().iter().filter(|event| is_ctrlc(event)).count();
+        if presses == 0 {  return false;  }
+        if self.ctrlc_behavior == CtrlC::Ignore {
+            events.events.retain(|event| !is_ctrlc(event));
+            // the older accessors lose which events the character and modifier came from
+            let queued = events.peek_events();
+            let typed_c = queued.iter().any(|event| matches!(event, InputEvent::Char('c', _)));
+            let held_control = queued.iter().any(|event| match event {
+                InputEvent::Key(_, held) | InputEvent::Char(_, held) => held.contains(&KeyModifiers::Control),
+                _ => false,
+            });
+            if !typed_c {  events.char_events.retain(|chr| *chr != 'c');  }
+            if !held_control {  events.key_modifiers.retain(|modifier| *modifier != KeyModifiers::Control);  }
+        }
+        drop(events);
+
+        let now = std::time::Instant::now();
+        self.ctrlc_presses.retain(|pressed| now.duration_since(*pressed) < CTRL_C_REPEAT_WINDOW);
+        self.ctrlc_presses.extend(std::iter::repeat_n(now, presses));
+        self.ctrlc_presses.len() >= CTRL_C_REPEAT_EXIT
+    }
+}
@@ -0,0 +1,143 @@
+// Hooks run by the main loop at fixed points of every frame, for cross-cutting concerns (logging, auto-saving,
+// metrics) which shouldn't be tangled up with the main callback. Hooks run in the order they were added.
+
+use crate::App;
+
+/// A hook run at a point of every frame (see `App::add_pre_update_hook`). It gets the same access to the app
+/// data and app as the main callback.
+pub type HookCallback<C> = Box<dyn FnMut(&mut C, &mut App<C>) + Send>;
+
+/// Identifies a hook added to the App, so it can be removed (see `App::remove_hook`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HookId(u64);
+
+/// The points of the frame hooks run at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum HookPoint {
+    PreUpdate,
+    PostUpdate,
+    PreRender,
+}
+
+/// The hooks added to an App, by the point they run at.
+pub(crate) struct Hooks<C> {
+    hooks: [Vec<(HookId, HookCallback<C>)>; 3],
+    next_id: u64,
+    /// The hooks currently running (they're taken out of their list while they run), and which of them were
+    /// removed meanwhile.
+    running: Vec<HookId>,
+    removed: Vec<HookId>,
+}
+
+impl<C> Hooks<C> {
+    pub(crate) fn new() -> Self {
+        Hooks {
+            hooks: [Vec::new(), Vec::new(), Vec::new()],
+            next_id: 0,
+            running: Vec::new(),
+            removed: Vec::new(),
+        }
+    }
+
+    /// Adds a hook after the others at the point, returning its id.
+    fn add(&mut self, point: HookPoint, hook: HookCallback<C>) -> HookId {
+        let id = HookId(self.next_id);
+        self.next_id += 1;
+        self.hooks[point as usize].push((id, hook));
+        id
+    }
+
+    /// Removes a hook, returning false if it doesn't exist.
+    fn remove(&mut self, id: HookId) -> bool {
+        if self.running.contains(&id) {
+            // the running hooks are put back once they finish, minus the removed ones
+            if self.removed.contains(&id) {  return false;  }
+            self.removed.push(id);
+            return true;
+        }
+        let mut removed = false;
+        for hooks in &mut self.hooks {
+            let length = hooks.len();
+            hooks.retain(|(hook_id, _hook)| *hook_id != id);
+            removed |= hooks.len() != length;
+        }
+        removed
+    }
+}
+
+impl<C> App<C> {
+    /// Adds a hook run at the start of every frame, before the keymap and the main callback (the frame's
+    /// events and messages are already collected). Hooks run in the order they were added, even when there's
+    /// no scene. Returns an id which can be used to remove the hook.
+    ///
+    /// No locks are held while hooks run, so they can freely lock `renderer`, `events`, and `area` (as long as
+    /// they release them before returning). A panicking hook shuts the app down cleanly, the same as a
+    /// panicking callback.
+    /// # Example
+    /// ```
+    /// # tokio::runtime::Builder::new_multi_thread().worker_threads(4).enable_all().build().unwrap().block_on(async {
+    /// // recording the order everything runs in over three frames
+    /// let mut app = term_render::App::<Vec<String>>::new_headless(20, 5);
+    /// app.add_pre_update_hook(Box::new(|log, _app| log.push(String::from("pre update"))));
+    /// app.add_post_update_hook(Box::new(|log, _app| log.push(String::from("post update"))));
+    /// app.add_pre_render_hook(Box::new(|log, _app| log.push(String::from("pre render"))));
+    /// // a second hook at the same point runs after the first
+    /// let second = app.add_pre_update_hook(Box::new(|log, _app| log.push(String::from("pre update 2"))));
+    /// let mut frames = 0;
+    /// let mut log = Vec::new();
+    /// app.run(Vec::new(), |data: &mut Vec<String>, app| {
+    ///     frames += 1;
+    ///     data.push(format!("callback {}", frames));
+    ///     if frames == 2 {  app.remove_hook(second);  }
+    ///     if frames == 4 {  log = std::mem::take(data);  }
+    ///     Ok::<bool, ()>(frames == 4)
+    /// }).await.unwrap();
+    /// let frame = |number: usize, second: bool| {
+    ///     let mut frame = vec![String::from("pre update")];
+    ///     if second {  frame.push(String::from("pre update 2"));  }
+    ///     frame.extend([format!("callback {}", number), String::from("post update"), String::from("pre render")]);
+    ///     frame
+    /// };
+    /// assert_eq!(log[..log.len() - 2], [frame(1, true), frame(2, true), frame(3, false)].concat());
+    /// # });
+    /// ```
+    pub fn add_pre_update_hook(&mut self, hook: HookCallback<C>) -> HookId {
+        self.hooks.add(HookPoint::PreUpdate, hook)
+    }
+
+    /// Adds a hook run after the frame's update: once the main callback, the due timers, and the scene's widgets
+    /// have run (the frame's events are still available). See `add_pre_update_hook` for how hooks run.
+    pub fn add_post_update_hook(&mut self, hook: HookCallback<C>) -> HookId {
+        self.hooks.add(HookPoint::PostUpdate, hook)
+    }
+
+    /// Adds a hook run at the end of every frame, right before the renderer is signalled to draw it (the frame's
+    /// events have been cleared by then). Frames the app exits on aren't rendered, so this doesn't run for them.
+    /// See `add_pre_update_hook` for how hooks run.
+    pub fn add_pre_render_hook(&mut self, hook: HookCallback<C>) -> HookId {
+        self.hooks.add(HookPoint::PreRender, hook)
+    }
+
+    /// Removes a hook so it doesn't run again (which is safe to do from within any hook, including itself).
+    /// Returns false if the hook doesn't exist.
+    pub fn remove_hook(&mut self, id: HookId) -> bool {
+        self.hooks.remove(id)
+    }
+
+    /// Runs the hooks of a point in the order they were added. Hooks added meanwhile wait for the next frame.
+    pub(crate) fn run_hooks(&mut self, point: HookPoint, data: &mut C) {
+        let mut hooks = std::mem::take(&mut self.hooks.hooks[point as usize]);
+        if hooks.is_empty() {  return;  }
+        self.hooks.running = hooks.iter().map(|(id, _hook)| *id).collect();
+        for (id, hook) in &mut hooks {
+            // an earlier hook may have removed it
+            if self.hooks.removed.contains(id) {  continue;  }
+            hook(data, self);
+        }
+        let removed = std::mem::take(&mut self.hooks.removed);
+        self.hooks.running.clear();
+        hooks.retain(|(id, _hook)| !removed.contains(id));
+        let added = std::mem::replace(&mut self.hooks.hooks[point as usize], hooks);
+        self.hooks.hooks[point as usize].extend(added);
+    }
+}
@@ -0,0 +1,161 @@
+// Recording the raw input read from stdin, and replaying it in place of stdin (for end-to-end tests,
+// demos, and reproducing input bugs). Replayed input goes through the same parser as real input.
+//
+// The file format is a header of the magic bytes "TRIN" and a little endian u16 version, followed by
+// chunks of input: a little endian u64 offset in microseconds (since the event thread started),
+// a little endian u32 length, and then that many bytes.
+
+use std::io::{Read, Write};
+use crate::App;
+
+/// The magic bytes starting every input recording.
+const RECORDING_MAGIC: &[u8; 4] = b"TRIN";
+/// The current version of the recording format.
+const RECORDING_VERSION: u16 = 1;
+
+/// Writes every chunk of input read from stdin to a file, along with when it was read.
+pub(crate) struct InputRecorder {
+    file: std::io::BufWriter<std::fs::File>,
+    start: std::time::Instant,
+}
+
+impl InputRecorder {
+    /// Creates (or truncates) the file and writes the header.
+    fn create(path: &std::path::Path) -> std::io::Result<Self> {
+        let mut file = std::io::BufWriter::new(std::fs::File::create(path)?);
+        file.write_all(RECORDING_MAGIC)?;
+        file.write_all(&RECORDING_VERSION.to_le_bytes())?;
+        file.flush()?;
+        Ok(InputRecorder { file, start: std::time::Instant::now() })
+    }
+
+    /// Starts the clock the chunks' offsets are measured from (called once the event thread starts).
+    pub(crate) fn start(&mut self) {
+        self.start = std::time::Instant::now();
+    }
+
+    /// Writes a chunk of input. It's flushed immediately, so the recording survives a crash.
+    pub(crate) fn record(&mut self, bytes: &[u8]) -> std::io::Result<()> {
+        let offset = self.start.elapsed().as_micros() as u64;
+        self.file.write_all(&offset.to_le_bytes())?;
+        self.file.write_all(&(bytes.len() as u32).to_le_bytes())?;
+        self.file.write_all(bytes)?;
+        self.file.flush()
+    }
+}
+
+/// The next input from a replay (see `InputReplay::next_input`).
+pub(crate) enum ReplayInput<'a> {
+    /// The next chunk isn't due yet.
+    Waiting,
+    /// A chunk of input which is due.
+    Input(&'a [u8]),
+    /// Every chunk has been replayed.
+    Finished,
+}
+
+/// Input read from a recording, handed out with the same (scaled) timing it was recorded with.
+pub(crate) struct InputReplay {
+    chunks: Vec<(std::time::Duration, Vec<u8>)>,
+    next: usize,
+    speed: f32,
+    start: std::time::Instant,
+    /// Whether stdin is read once the replay finishes, rather than exiting the app.
+    pub(crate) continue_with_stdin: bool,
+}
+
+impl InputReplay {
+    /// Reads every chunk of a recording, returning an `InvalidData` error if the file isn't a recording
+    /// (or is from a newer version of the format).
+    fn load(path: &std::path::Path, speed: f32) -> std::io::Result<Self> {
+        let mut bytes = Vec::new();
+        std::fs::File::open(path)?.read_to_end(&mut bytes)?;
+        let invalid = |details: &str| std::io::Error::new(std::io::ErrorKind::InvalidData, format!("Invalid input recording: {}", details));
+
+        if bytes.len() < 6 || &bytes[..4] != RECORDING_MAGIC {
+            return Err(invalid("missing header"));
+        }
+        let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+        if version != RECORDING_VERSION {
+            return Err(invalid(&format!("unsupported version {}", version)));
+        }
+
+        let mut chunks = Vec::new();
+        let mut rest = &bytes[6..];
+        while !rest.is_empty() {
+            if rest.len() < 12 {  return Err(invalid("truncated chunk header"));  }
+            let offset = u64::from_le_bytes(rest[..8].try_into().unwrap());
+            let length = u32::from_le_bytes(rest[8..12].try_into().unwrap()) as usize;
+            if rest.len() < 12 + length {  return Err(invalid("truncated chunk"));  }
+            chunks.push((std::time::Duration::from_micros(offset), rest[12..12 + length].to_vec()));
+            rest = &rest[12 + length..];
+        }
+        Ok(InputReplay { chunks, next: 0, speed, start: std::time::Instant::now(), continue_with_stdin: false })
+    }
+
+    /// Starts the clock the chunks are replayed against (called once the event thread starts).
+    pub(crate) fn start(&mut self) {
+        self.start = std::time::Instant::now();
+    }
+
+    /// Returns the next chunk once it's due, waiting at most `timeout` for it.
+    pub(crate) fn next_input(&mut self, timeout: std::time::Duration) -> ReplayInput<'_> {
+        let Some((offset, _)) = self.chunks.get(self.next) else {  return ReplayInput::Finished;  };
+        // a speed of zero (or less) replays everything without waiting
+        let due = match self.speed > 0.0 && self.speed.is_finite() {
+            true => offset.div_f32(self.speed),
+            false => std::time::Duration::ZERO,
+        };
+        let remaining = due.saturating_sub(self.start.elapsed());
+        if remaining > timeout {
+            std::thread::sleep(timeout);
+            return ReplayInput::Waiting;
+        }
+        std::thread::sleep(remaining);
+        self.next += 1;
+        ReplayInput::Input(&self.chunks[self.next - 1].1)
+    }
+}
+
+impl<C> App<C> {
+    /// Records the raw input read from stdin to a file while the app runs, along with when each
+    /// chunk of it was read, so it can be replayed later (see `replay_input`).
+    /// The file is created (or truncated) immediately, and each chunk is written as soon as it's read.
+    /// # Example
+    /// ```no_run
+    /// # async fn example() {
+    /// let mut app = term_render::App::new().unwrap();
+    /// app.record_input("session.input").unwrap();
+    /// app.run((), |_data, _app_instance| Ok::<bool, ()>(false)).await.unwrap();
+    /// # }
+    /// ```
+    pub fn record_input<P: AsRef<std::path::Path>>(&mut self, path: P) -> std::io::Result<()> {
+        self.input_recorder = Some(InputRecorder::create(path.as_ref())?);
+        Ok(())
+    }
+
+    /// Replays input recorded with `record_input` rather than reading stdin, feeding it through the
+    /// same parser so it behaves exactly like the original input. The chunks keep their recorded timing
+    /// (so animations and double clicks behave realistically), scaled by `speed` (2.0 replays twice as fast;
+    /// zero or less replays everything without waiting).
+    /// Once the recording ends, the app exits, unless `set_replay_continues_with_stdin` was enabled.
+    /// Returns an error if the file can't be read or isn't a recording.
+    /// # Example
+    /// ```no_run
+    /// # async fn example() {
+    /// let mut app = term_render::App::new().unwrap();
+    /// app.replay_input("session.input", 1.0).unwrap();
+    /// app.run((), |_data, _app_instance| Ok::<bool, ()>(false)).await.unwrap();
+    /// # }
+    /// ```
+    pub fn replay_input<P: AsRef<std::path::Path>>(&mut self, path: P, speed: f32) -> std::io::Result<()> {
+        self.input_replay = Some(InputReplay::load(path.as_ref(), speed)?);
+        Ok(())
+    }
+
+    /// Sets whether stdin is read once a replay (see `replay_input`) finishes, handing control back to
+    /// the user, rather than exiting the app. By default, the app exits.
+    pub fn set_replay_continues_with_stdin(&mut self, continue_with_stdin: bool) {
+        self.replay_continues_with_stdin = continue_with_stdin;
+    }
+}
@@ -0,0 +1,280 @@
+// Declarative key bindings attached to the App. The matching bindings of the attached keymap are run by the
+// main loop right before the user callback, and the status bar widget shows the keymap's hints.
+
+use crate::App;
+use crate::event_handler::{KeyCode, KeyModifiers, KeyParser};
+
+/// The callback run when a key binding's chord is pressed. It gets the same access to the app data and app as the main callback.
+pub type KeyHandler<C> = Box<dyn FnMut(&mut C, &mut App<C>) + Send>;
+
+/// The key a chord is pressed with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChordKey {
+    Code(KeyCode),
+    Char(char),
+}
+
+/// A key along with the modifiers held with it, such as Ctrl+S or F1.
+///
+/// The parser collapses control characters into the letter and the Control modifier (Ctrl+S arrives
+/// as byte 19, and is reported as 's' with Control), which chords account for: `KeyChord::ctrl('s')`
+/// (or `'S'`) matches either form. Control and Option have to be held exactly when the chord asks for
+/// them, so a plain 's' doesn't fire on Ctrl+S; Shift is implied by the case of a character.
+/// # Example
+/// ```
+/// use term_render::KeyChord;
+/// use term_render::event_handler::{KeyCode, KeyModifiers, KeyParser};
+/// let mut parser = KeyParser::new();
+/// let mut vte_parser = vte::Parser::new();
+/// parser.bytes = 1;
+/// vte_parser.advance(&mut parser, &[19]);  // Ctrl+S
+/// assert!(KeyChord::ctrl('s').matches(&parser));
+/// assert!(!KeyChord::char('s').matches(&parser));
+/// assert!(!KeyChord::key(KeyCode::F1).matches(&parser));
+/// assert_eq!(KeyChord::ctrl('s').to_string(), "^S");
+/// assert_eq!(KeyChord::key(KeyCode::F1).with_modifier(KeyModifiers::Shift).to_string(), "Shift+F1");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    key: ChordKey,
+    modifiers: Vec<KeyModifiers>,
+}
+
+impl KeyChord {
+    /// A chord of a special key (such as Escape or an arrow) with no modifiers.
+    pub fn key(key: KeyCode) -> Self {
+        KeyChord { key: ChordKey::Code(key), modifiers: vec![] }
+    }
+
+    /// A chord of a character with no modifiers.
+    pub fn char(chr: char) -> Self {
+        KeyChord { key: ChordKey::Char(chr), modifiers: vec![] }
+    }
+
+    /// A chord of a character with Control held.
+    pub fn ctrl(chr: char) -> Self {
+        Self::char(chr.to_ascii_lowercase()).with_modifier(KeyModifiers::Control)
+    }
+
+    /// Adds a modifier which has to be held with the key.
+    pub fn with_modifier(mut self, modifier: KeyModifiers) -> Self {
+        if !self.modifiers.contains(&modifier) {  self.modifiers.push(modifier);  }
+        self
+    }
+
+    /// Returns the key the chord is pressed with.
+    pub fn get_key(&self) -> ChordKey {
+        self.key
+    }
+
+    /// Returns the modifiers held with the key.
+    pub fn get_modifiers(&self) -> &[KeyModifiers] {
+        &self.modifiers
+    }
+
+    /// Returns whether the chord was pressed during the current frame.
+    pub fn matches(&self, events: &KeyParser) -> bool {
+        // Control and Option have to match exactly (Shift and Command are only checked when asked for)
+        for modifier in [KeyModifiers::Control, KeyModifiers::Option] {
+            if self.modifiers.contains(&modifier) != events.contains_modifier(modifier) {  return false;  }
+        }
+        if !self.modifiers.iter().all(|modifier| events.contains_modifier(*modifier)) {  return false;  }
+        match self.key {
+            ChordKey::Code(key) => events.contains_key_code(key),
+            ChordKey::Char(chr) if self.modifiers.contains(&KeyModifiers::Control) && chr.is_ascii_alphabetic() => {
+                // the letter may arrive in either case, or as the raw control character
+                let control = ((chr.to_ascii_lowercase() as u8) & 0x1F) as char;
+                events.contains_char(chr.to_ascii_lowercase()) || events.contains_char(chr.to_ascii_uppercase()) ||
+                    events.contains_char(control)
+            },
+            ChordKey::Char(chr) => events.contains_char(chr),
+        }
+    }
+}
+
+/// Displays the chord compactly for key hints, such as "^S", "Alt+x", or "Esc".
+impl std::fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let control = self.modifiers.contains(&KeyModifiers::Control);
+        for modifier in &self.modifiers {
+            match modifier {
+                // control is written as a caret for characters (the usual terminal notation)
+                KeyModifiers::Control if matches!(self.key, ChordKey::Char(_)) => {},
+                KeyModifiers::Control => write!(f, "Ctrl+")?,
+                KeyModifiers::Option => write!(f, "Alt+")?,
+                KeyModifiers::Shift => write!(f, "Shift+")?,
+                KeyModifiers::Command => write!(f, "Cmd+")?,
+            }
+        }
+        match self.key {
+            ChordKey::Char(chr) if control => write!(f, "^{}", chr.to_ascii_uppercase()),
+            ChordKey::Char(' ') => write!(f, "Space"),
+            ChordKey::Char(chr) => write!(f, "{}", chr),
+            ChordKey::Code(key) => write!(f, "{}", match key {
+                KeyCode::Delete => "Del",
+                KeyCode::Tab => "Tab",
+                KeyCode::Left => "←",
+                KeyCode::Right => "→",
+                KeyCode::Up => "↑",
+                KeyCode::Down => "↓",
+                KeyCode::Return => "Enter",
+                KeyCode::Escape => "Esc",
+                KeyCode::PageUp => "PgUp",
+                KeyCode::PageDown => "PgDn",
+                KeyCode::Home => "Home",
+                KeyCode::End => "End",
+                KeyCode::Insert => "Ins",
+                KeyCode::F1 => "F1",
+                KeyCode::F2 => "F2",
+                KeyCode::F3 => "F3",
+                KeyCode::F4 => "F4",
+                KeyCode::F5 => "F5",
+                KeyCode::F6 => "F6",
+                KeyCode::F7 => "F7",
+                KeyCode::F8 => "F8",
+                KeyCode::F9 => "F9",
+                KeyCode::F10 => "F10",
+                KeyCode::F11 => "F11",
+                KeyCode::F12 => "F12",
+            }),
+        }
+    }
+}
+
+/// A single binding of a keymap.
+struct KeyBinding<C> {
+    chord: KeyChord,
+    name: String,
+    handler: KeyHandler<C>,
+}
+
+/// A named set of key bindings, each mapping a chord to a name (shown as a hint by `StatusBarWidget`)
+/// and a handler. Attaching a keymap to the App (see `App::attach_keymap`) runs the handlers of the
+/// chords pressed each frame, right before the main callback. Swapping keymaps at runtime (even from
+/// within a handler) switches between modes, such as normal and insert modes with different hints.
+/// # Example
+/// ```
+/// use term_render::{KeyChord, KeyMap};
+/// use term_render::event_handler::KeyCode;
+/// # struct AppData { saved: bool }
+/// let keymap = KeyMap::<AppData>::new("NORMAL")
+///     .with_binding(KeyChord::ctrl('s'), "Save", Box::new(|data, _app| data.saved = true))
+///     .with_binding(KeyChord::key(KeyCode::Escape), "Quit", Box::new(|_data, _app| {}));
+/// let hints: Vec<String> = keymap.get_hints().map(|(chord, name)| format!("{} {}", chord, name)).collect();
+/// assert_eq!(hints, vec!["^S Save", "Esc Quit"]);
+/// ```
+pub struct KeyMap<C> {
+    name: String,
+    bindings: Vec<KeyBinding<C>>,
+}
+
+impl<C> KeyMap<C> {
+    /// Creates an empty keymap. The name is shown before the hints by `StatusBarWidget` (unless it's empty).
+    pub fn new(name: &str) -> Self {
+        KeyMap { name: name.to_owned(), bindings: vec![] }
+    }
+
+    /// Binds a chord to a handler (see `bind`).
+    pub fn with_binding(mut self, chord: KeyChord, name: &str, handler: KeyHandler<C>) -> Self {
+        self.bind(chord, name, handler);
+        self
+    }
+
+    /// Binds a chord to a handler, with the name shown in its hint. Rebinding a chord replaces its
+    /// previous binding in place (keeping the order of the hints).
+    pub fn bind(&mut self, chord: KeyChord, name: &str, handler: KeyHandler<C>) {
+        let binding = KeyBinding { chord, name: name.to_owned(), handler };
+        match self.bindings.iter_mut().find(|existing| existing.chord == binding.chord) {
+            Some(existing) => *existing = binding,
+            None => self.bindings.push(binding),
+        }
+    }
+
+    /// Removes the binding of a chord, returning false if it wasn't bound.
+    pub fn unbind(&mut self, chord: &KeyChord) -> bool {
+        let length = self.bindings.len();
+        self.bindings.retain(|binding| binding.chord != *chord);
+        self.bindings.len() != length
+    }
+
+    /// Returns the keymap's name.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the chord and name of every binding, in the order they were bound.
+    pub fn get_hints(&self) -> impl Iterator<Item = (&KeyChord, &str)> {
+        self.bindings.iter().map(|binding| (&binding.chord, binding.name.as_str()))
+    }
+
+    /// Returns the number of bindings.
+    pub fn len(&self) -> usize {
+        self.bindings.len()
+    }
+
+    /// Returns whether the keymap has no bindings.
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+}
+
+impl<C> App<C> {
+    /// Attaches a keymap, whose bindings are run each frame (right before the main callback) when their
+    /// chords are pressed, replacing the keymap attached before. Returns the previous keymap, unless this
+    /// is called from one of its own handlers (in which case it's dropped, and its remaining bindings
+    /// aren't run for this frame).
+    /// # Example
+    /// ```no_run
+    /// use term_render::{KeyChord, KeyMap};
+    /// # struct AppData { inserting: bool }
+    /// fn normal_mode() -> KeyMap<AppData> {
+    ///     KeyMap::new("NORMAL").with_binding(KeyChord::char('i'), "Insert", Box::new(|data: &mut AppData, app| {
+    ///         data.inserting = true;
+    ///         app.attach_keymap(insert_mode());
+    ///     }))
+    /// }
+    /// fn insert_mode() -> KeyMap<AppData> {
+    ///     KeyMap::new("INSERT").with_binding(KeyChord::ctrl('s'), "Done", Box::new(|data: &mut AppData, app| {
+    ///         data.inserting = false;
+    ///         app.attach_keymap(normal_mode());
+    ///     }))
+    /// }
+    /// let mut app = term_render::App::<AppData>::new().unwrap();
+    /// app.attach_keymap(normal_mode());
+    /// ```
+    pub fn attach_keymap(&mut self, keymap: KeyMap<C>) -> Option<KeyMap<C>> {
+        self.keymap_swapped = true;
+        self.keymap.replace(keymap)
+    }
+
+    /// Removes the attached keymap, returning it (see `attach_keymap`).
+    pub fn detach_keymap(&mut self) -> Option<KeyMap<C>> {
+        self.keymap_swapped = true;
+        self.keymap.take()
+    }
+
+    /// Returns the attached keymap, if any. While one of its own handlers is running, this is None.
+    pub fn get_keymap(&self) -> Option<&KeyMap<C>> {
+        self.keymap.as_ref()
+    }
+
+    /// Returns the attached keymap mutably, if any (for binding or unbinding chords in place).
+    pub fn get_keymap_mut(&mut self) -> Option<&mut KeyMap<C>> {
+        self.keymap.as_mut()
+    }
+
+    /// Runs the handler of every binding in the attached keymap whose chord was pressed this frame.
+    /// If a handler swaps the keymap, the rest of the old keymap's bindings are skipped.
+    pub(crate) fn dispatch_keymap(&mut self, data: &mut C) {
+        let Some(mut keymap) = self.keymap.take() else {  return;  };
+        self.keymap_swapped = false;
+        let events = self.events.read();
+        let pressed: Vec<usize> = (0..keymap.bindings.len()).filter(|index| keymap.bindings[*index].chord.matches(&events)).collect();
+        drop(events);
+        for index in pressed {
+            (keymap.bindings[index].handler)(data, self);
+            if self.keymap_swapped {  return;  }
+        }
+        self.keymap = Some(keymap);
+    }
+}
@@ -0,0 +1,111 @@
+// Channels for posting messages into the main loop from background tasks and threads.
+// Each message type gets its own channel; whatever arrived is collected at the start of every frame,
+// where the callback and widgets can read (or take) it.
+
+use crate::App;
+
+/// A channel for a single message type, along with the messages collected for the current frame.
+struct MessageQueue<M> {
+    sender: crossbeam::channel::Sender<M>,
+    receiver: crossbeam::channel::Receiver<M>,
+    pending: Vec<M>,
+}
+
+/// Lets the queues be stored together, regardless of their message type.
+trait AnyMessageQueue: Send {
+    /// Replaces the pending messages with whatever has arrived since the last frame.
+    fn collect(&mut self);
+    fn as_any(&self) -> &dyn std::any::Any;
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any;
+}
+
+impl<M: Send + 'static> AnyMessageQueue for MessageQueue<M> {
+    fn collect(&mut self) {
+        self.pending.clear();
+        self.pending.extend(self.receiver.try_iter());
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// The message queues of an App, by message type.
+pub(crate) struct Messages {
+    queues: std::collections::HashMap<std::any::TypeId, Box<dyn AnyMessageQueue>>,
+}
+
+impl Messages {
+    pub(crate) fn new() -> Self {
+        Messages {
+            queues: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Gets the queue for the message type, creating it if it doesn't exist yet.
+    fn get_queue<M: Send + 'static>(&mut self) -> &mut MessageQueue<M> {
+        self.queues.entry(std::any::TypeId::of::<M>())
+            .or_insert_with(|| {
+                let (sender, receiver) = crossbeam::channel::unbounded();
+                Box::new(MessageQueue::<M> { sender, receiver, pending: Vec::new() })
+            })
+            .as_any_mut()
+            .downcast_mut()
+            .expect("Message queue stored under the wrong type")
+    }
+
+    /// Collects the messages which arrived since the last frame (dropping any which weren't taken).
+    pub(crate) fn collect(&mut self) {
+        for queue in self.queues.values_mut() {
+            queue.collect();
+        }
+    }
+}
+
+impl<C> App<C> {
+    /// Returns a sender for posting messages of type `M` into the main loop, such as from a spawned
+    /// task fetching data. Senders can be cloned and moved to other tasks or threads freely; every
+    /// message type has its own channel.
+    /// Messages are collected at the start of each frame and can be read by the callback and by widgets
+    /// (through `messages`), or taken with `drain_messages`. Any which are still pending at the start of the
+    /// next frame are dropped, like the frame's events.
+    /// The channel is unbounded, so sending never blocks or fails while the app exists (even before it's running);
+    /// senders which could outpace the frame rate should batch their messages.
+    /// # Example
+    /// ```no_run
+    /// # async fn example() {
+    /// let mut app = term_render::App::new().unwrap();
+    /// let sender = app.message_sender::<String>();
+    /// tokio::spawn(async move {
+    ///     // posting the result of some background work
+    ///     let _ = sender.send(String::from("Finished loading"));
+    /// });
+    /// app.run(Vec::new(), |log: &mut Vec<String>, app_instance| {
+    ///     log.extend(app_instance.drain_messages::<String>());
+    ///     Ok::<bool, ()>(false)  // return true to exit the app
+    /// }).await.unwrap();
+    /// # }
+    /// ```
+    pub fn message_sender<M: Send + 'static>(&mut self) -> crossbeam::channel::Sender<M> {
+        self.messages.get_queue::<M>().sender.clone()
+    }
+
+    /// Returns the messages of type `M` which arrived before this frame (see `message_sender`),
+    /// leaving them for the rest of the frame (i.e. for widgets after the callback).
+    pub fn messages<M: Send + 'static>(&self) -> &[M] {
+        self.messages.queues.get(&std::any::TypeId::of::<M>())
+            .and_then(|queue| queue.as_any().downcast_ref::<MessageQueue<M>>())
+            .map(|queue| queue.pending.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Takes the messages of type `M` which arrived before this frame (see `message_sender`),
+    /// so nothing later in the frame sees them (the callback runs before widgets are updated).
+    pub fn drain_messages<M: Send + 'static>(&mut self) -> Vec<M> {
+        std::mem::take(&mut self.messages.get_queue::<M>().pending)
+    }
+}
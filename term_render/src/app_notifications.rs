@@ -0,0 +1,213 @@
+// Toast notifications, stacked in a corner of the terminal above everything else (in the overlay layer, so the
+// app's depths don't matter) and removed once they time out. Notifications past the number which can be shown at
+// once wait in a queue until one expires. Each one is a window of its own rather than a widget, so they never
+// block clicks or take the focus.
+
+use crate::render::{Layer, Span, Window};
+use crate::App;
+
+/// How long a notification is shown for, unless another timeout is set (see `NotificationManager::set_timeout`).
+pub const DEFAULT_NOTIFICATION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// The prefix of the names of the windows notifications are drawn in.
+const NOTIFICATION_WINDOW: &str = "__notification";
+
+/// The corner of the terminal notifications are stacked in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Corner {
+    TopLeft,
+    #[default] TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// Identifies a notification, so it can be dismissed early (see `App::dismiss_notification`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NotificationId(u64);
+
+/// Keeps track of the notifications shown (and waiting to be shown) by an App (see `App::notify`).
+pub struct NotificationManager {
+    corner: Corner,
+    timeout: std::time::Duration,
+    max_width: u16,
+    max_shown: usize,
+    /// The notifications being shown, oldest (closest to the corner) first.
+    shown: Vec<(NotificationId, Span)>,
+    /// The notifications waiting for room to be shown.
+    queued: std::collections::VecDeque<(NotificationId, Span)>,
+    next_id: u64,
+}
+
+impl NotificationManager {
+    pub(crate) fn new() -> Self {
+        NotificationManager {
+            corner: Corner::TopRight,
+            timeout: DEFAULT_NOTIFICATION_TIMEOUT,
+            max_width: 40,
+            max_shown: 4,
+            shown: Vec::new(),
+            queued: std::collections::VecDeque::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Sets the corner notifications are stacked in (the top right by default). Notifications already
+    /// shown move there the next time the stack changes.
+    pub fn set_corner(&mut self, corner: Corner) {
+        self.corner = corner;
+    }
+
+    /// Gets the corner notifications are stacked in.
+    pub fn get_corner(&self) -> Corner {
+        self.corner
+    }
+
+    /// Sets how long each notification is shown for, counted from when it appears rather than when it was
+    /// queued. The default is `DEFAULT_NOTIFICATION_TIMEOUT` (3 seconds).
+    pub fn set_timeout(&mut self, timeout: std::time::Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Gets how long each notification is shown for.
+    pub fn get_timeout(&self) -> std::time::Duration {
+        self.timeout
+    }
+
+    /// Sets the widest a notification can be, including its border (40 by default). Longer text is cut off.
+    pub fn set_max_width(&mut self, max_width: u16) {
+        self.max_width = max_width;
+    }
+
+    /// Sets how many notifications can be shown at once (4 by default); the rest wait in a queue.
+    pub fn set_max_shown(&mut self, max_shown: usize) {
+        self.max_shown = max_shown.max(1);
+    }
+
+    /// Checks if the notification is being shown.
+    pub fn is_shown(&self, id: NotificationId) -> bool {
+        self.shown.iter().any(|(shown, _text)| *shown == id)
+    }
+
+    /// Checks if the notification is waiting to be shown.
+    pub fn is_queued(&self, id: NotificationId) -> bool {
+        self.queued.iter().any(|(queued, _text)| *queued == id)
+    }
+
+    /// Gets the number of notifications being shown.
+    pub fn shown_count(&self) -> usize {
+        self.shown.len()
+    }
+
+    fn window_name(id: NotificationId) -> String {
+        format!("{}_{}", NOTIFICATION_WINDOW, id.0)
+    }
+
+    /// Gets the (size, position) of the notification at the index of the stack.
+    fn get_region(&self, index: usize, text: &Span, area: &crate::render::Rect) -> ((u16, u16), (u16, u16)) {
+        let width = (text.size() as u16 + 2).min(self.max_width).min(area.width);
+        let offset = index as u16 * 3;
+        let x = match self.corner {
+            Corner::TopLeft | Corner::BottomLeft => 0,
+            Corner::TopRight | Corner::BottomRight => area.width - width,
+        };
+        let y = match self.corner {
+            Corner::TopLeft | Corner::TopRight => offset,
+            Corner::BottomLeft | Corner::BottomRight => area.height.saturating_sub(offset + 3),
+        };
+        ((width, 3), (x, y))
+    }
+}
+
+impl<C> App<C> {
+    /// Gets the notification manager, for configuring how notifications are shown (see `notify`).
+    pub fn notifications(&mut self) -> &mut NotificationManager {
+        &mut self.notifications
+    }
+
+    /// Shows a notification in the corner of the terminal (see `NotificationManager::set_corner`), stacked
+    /// with any others, and drawn above every window whatever its depth. It's removed after the timeout (see
+    /// `NotificationManager::set_timeout`) by a timer, so it only expires while the app is running. If there
+    /// are already as many notifications as can be shown, it waits until one expires.
+    /// # Example
+    /// ```
+    /// use term_render::render::{Span, Window};
+    /// # tokio::runtime::Builder::new_multi_thread().worker_threads(4).enable_all().build().unwrap().block_on(async {
+    /// let mut app = term_render::App::<()>::new_headless(20, 4);
+    /// // a window far deeper than the app would normally use
+    /// let mut window = Window::new((0, 1), u16::MAX, (20, 1));
+    /// window.add_line(Span::from("x".repeat(20).as_str()));
+    /// app.renderer.write().add_window(window, String::from("deep"), vec![]).unwrap();
+    ///
+    /// app.notifications().set_timeout(std::time::Duration::from_millis(50));
+    /// app.notifications().set_max_shown(1);
+    /// let saved = app.notify(Span::from("saved"));
+    /// let synced = app.notify(Span::from("synced"));
+    /// assert_eq!(app.renderer.write().snapshot(), vec!["             ┌─────┐", "xxxxxxxxxxxxx│saved│", "             └─────┘", ""]);
+    /// assert!(app.notifications().is_shown(saved) && app.notifications().is_queued(synced));
+    ///
+    /// // once the first expires, the next is shown, and then it expires too
+    /// let start = std::time::Instant::now();
+    /// let mut seen = false;
+    /// app.run((), |_data, app| {
+    ///     seen |= app.renderer.write().snapshot()[1].ends_with("│synced│");
+    ///     Ok::<bool, ()>(start.elapsed().as_millis() > 300)
+    /// }).await.unwrap();
+    /// assert!(seen);
+    /// assert_eq!(app.notifications().shown_count(), 0);
+    /// assert_eq!(app.renderer.write().snapshot()[1], "x".repeat(20));
+    /// # });
+    /// ```
+    pub fn notify(&mut self, text: Span) -> NotificationId {
+        let id = NotificationId(self.notifications.next_id);
+        self.notifications.next_id += 1;
+        self.notifications.queued.push_back((id, text));
+        self.show_queued_notifications();
+        id
+    }
+
+    /// Removes a notification before it times out (or takes it out of the queue). Returns false if it
+    /// isn't shown or queued (i.e. it already expired).
+    pub fn dismiss_notification(&mut self, id: NotificationId) -> bool {
+        let notifications = &mut self.notifications;
+        if let Some(index) = notifications.queued.iter().position(|(queued, _text)| *queued == id) {
+            notifications.queued.remove(index);
+            return true;
+        }
+        let Some(index) = notifications.shown.iter().position(|(shown, _text)| *shown == id) else {  return false;  };
+        notifications.shown.remove(index);
+        let _ = self.renderer.write().remove_window(NotificationManager::window_name(id));
+        self.show_queued_notifications();
+        true
+    }
+
+    /// Shows queued notifications while there's room, then moves every notification into place.
+    fn show_queued_notifications(&mut self) {
+        while self.notifications.shown.len() < self.notifications.max_shown {
+            let Some((id, text)) = self.notifications.queued.pop_front() else {  break;  };
+            let mut window = Window::new((0, 0), 0, (0, 3));
+            window.bordered();
+            window.set_layer(Layer::Overlay);
+            window.from_lines(vec![text.clone()]);
+            let _ = self.renderer.write().add_window(window, NotificationManager::window_name(id), vec![]);
+            self.notifications.shown.push((id, text));
+            self.set_timeout(self.notifications.timeout, Box::new(move |_data, app| {
+                app.dismiss_notification(id);
+            }));
+        }
+        self.restack_notifications();
+    }
+
+    /// Moves the shown notifications into place (i.e. after one expires, or the terminal is resized).
+    pub(crate) fn restack_notifications(&mut self) {
+        let area = self.area.read().clone();
+        let mut renderer = self.renderer.write();
+        for (index, (id, text)) in self.notifications.shown.iter().enumerate() {
+            let name = NotificationManager::window_name(*id);
+            if !renderer.contains_window(name.clone()) {  continue;  }
+            let (size, position) = self.notifications.get_region(index, text, &area);
+            let window = renderer.get_window_reference_mut(name);
+            window.resize(size);
+            window.r#move(position);
+        }
+    }
+}
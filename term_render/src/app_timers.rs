@@ -0,0 +1,171 @@
+// Timers scheduled on the App, so animations and popups don't need to track Instants in the app data.
+// Due timers are run by the main loop right after the user callback, with the same access to the data and app.
+
+use crate::App;
+
+/// The callback run when a timer is due. It gets the same access to the app data and app as the main callback.
+pub type TimerCallback<C> = Box<dyn FnMut(&mut C, &mut App<C>) + Send>;
+
+/// Identifies a timer scheduled with `App::set_timeout` or `App::set_interval`, so it can be cancelled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimerId(u64);
+
+/// A single scheduled callback.
+struct Timer<C> {
+    id: TimerId,
+    deadline: std::time::Instant,
+    /// Set for intervals, which are rescheduled after running.
+    interval: Option<std::time::Duration>,
+    callback: TimerCallback<C>,
+}
+
+/// The timers scheduled on an App.
+pub(crate) struct Timers<C> {
+    timers: Vec<Timer<C>>,
+    next_id: u64,
+    /// The timer currently running (it's taken out of the list while its callback runs),
+    /// and whether it was cancelled from within its own callback.
+    running: Option<(TimerId, bool)>,
+}
+
+impl<C> Timers<C> {
+    pub(crate) fn new() -> Self {
+        Timers {
+            timers: Vec::new(),
+            next_id: 0,
+            running: None,
+        }
+    }
+
+    /// Schedules a callback, returning its id.
+    pub(crate) fn add(&mut self, delay: std::time::Duration, interval: Option<std::time::Duration>, callback: TimerCallback<C>) -> TimerId {
+        let id = TimerId(self.next_id);
+        self.next_id += 1;
+        self.timers.push(Timer {
+            id,
+            deadline: std::time::Instant::now() + delay,
+            interval,
+            callback,
+        });
+        id
+    }
+
+    /// Removes a timer, returning false if it doesn't exist (or already ran, for a timeout).
+    pub(crate) fn cancel(&mut self, id: TimerId) -> bool {
+        if let Some((running, cancelled)) = &mut self.running && *running == id {
+            // the timer is put back once its callback returns, unless it was cancelled
+            let was_cancelled = *cancelled;
+            *cancelled = true;
+            return !was_cancelled;
+        }
+        let length = self.timers.len();
+        self.timers.retain(|timer| timer.id != id);
+        self.timers.len() != length
+    }
+
+    /// Returns whether the timer is still scheduled.
+    pub(crate) fn contains(&self, id: TimerId) -> bool {
+        match self.running {
+            Some((running, cancelled)) if running == id => !cancelled,
+            _ => self.timers.iter().any(|timer| timer.id == id),
+        }
+    }
+
+    /// Returns the ids of every timer due by `now`, in the order of their deadlines.
+    fn get_due(&self, now: std::time::Instant) -> Vec<TimerId> {
+        let mut due: Vec<&Timer<C>> = self.timers.iter().filter(|timer| timer.deadline <= now).collect();
+        due.sort_by_key(|timer| timer.deadline);
+        due.into_iter().map(|timer| timer.id).collect()
+    }
+
+    /// Takes a timer out of the list to run it.
+    fn take(&mut self, id: TimerId) -> Option<Timer<C>> {
+        let index = self.timers.iter().position(|timer| timer.id == id)?;
+        self.running = Some((id, false));
+        Some(self.timers.remove(index))
+    }
+
+    /// Puts a timer back after it ran, if it's an interval that wasn't cancelled.
+    fn finish(&mut self, mut timer: Timer<C>, now: std::time::Instant) {
+        let cancelled = matches!(self.running.take(), Some((_, true)));
+        let Some(interval) = timer.interval else {  return;  };
+        if cancelled {  return;  }
+        timer.deadline = Self::next_deadline(timer.deadline, interval, now);
+        self.timers.push(timer);
+    }
+
+    /// Computes an interval's next deadline from its previous one (rather than from when it ran), so it doesn't drift.
+    /// Ticks which were missed entirely (i.e. while suspended, or with an interval shorter than a frame) are skipped.
+    fn next_deadline(deadline: std::time::Instant, interval: std::time::Duration, now: std::time::Instant) -> std::time::Instant {
+        let next = deadline + interval;
+        if next > now || interval.is_zero() {  return next.max(now);  }
+        // keeping to the same phase, just past now
+        let behind = (now - deadline).as_nanos() % interval.as_nanos();
+        now + (interval - std::time::Duration::from_nanos(behind as u64))
+    }
+}
+
+impl<C> App<C> {
+    /// Runs the callback once, after the delay. Timers are checked every frame, right after the main
+    /// callback, so they run on the first frame after they're due (with the same access to the data, scene, and renderer).
+    /// Returns an id which can be used to cancel the timer before it runs.
+    /// # Example
+    /// ```no_run
+    /// # fn callback(data: &mut (), app: &mut term_render::App<()>) -> Result<bool, ()> {
+    /// if app.events.read().contains_char('n') {
+    ///     // showing a notification, then hiding it again after 3 seconds
+    ///     let index = app.scene.as_ref().and_then(|scene| scene.get_widget_index(String::from("toast"))).unwrap();
+    ///     app.scene.as_mut().unwrap().set_widget_visible(index, true, &mut app.renderer.write()).unwrap();
+    ///     app.set_timeout(std::time::Duration::from_secs(3), Box::new(move |_data, app| {
+    ///         if let Some(scene) = &mut app.scene {
+    ///             let _ = scene.set_widget_visible(index, false, &mut app.renderer.write());
+    ///         }
+    ///     }));
+    /// }
+    /// # Ok(false)
+    /// # }
+    /// ```
+    pub fn set_timeout(&mut self, delay: std::time::Duration, callback: TimerCallback<C>) -> TimerId {
+        self.timers.add(delay, None, callback)
+    }
+
+    /// Runs the callback repeatedly, every `interval`, until it's cancelled (which is safe to do from within the callback).
+    /// Each deadline is computed from the previous one rather than from when the callback ran, so the interval
+    /// doesn't drift with the frame rate; ticks which were missed entirely are skipped rather than run all at once.
+    /// # Example
+    /// ```no_run
+    /// # fn callback(data: &mut u32, app: &mut term_render::App<u32>) -> Result<bool, ()> {
+    /// // counting down from the current value, stopping at zero
+    /// app.set_interval(std::time::Duration::from_secs(1), Box::new(|countdown, _app| {
+    ///     *countdown = countdown.saturating_sub(1);
+    /// }));
+    /// # Ok(false)
+    /// # }
+    /// ```
+    pub fn set_interval(&mut self, interval: std::time::Duration, callback: TimerCallback<C>) -> TimerId {
+        self.timers.add(interval, Some(interval), callback)
+    }
+
+    /// Cancels a timer so it doesn't run again. Returns false if the timer doesn't exist
+    /// (including timeouts which have already run).
+    pub fn cancel_timer(&mut self, id: TimerId) -> bool {
+        self.timers.cancel(id)
+    }
+
+    /// Returns whether a timer is still scheduled (timeouts are removed once they run).
+    pub fn is_timer_active(&self, id: TimerId) -> bool {
+        self.timers.contains(id)
+    }
+
+    /// Runs every timer which is due. Timers scheduled by the callbacks (and the next ticks of intervals)
+    /// wait for the next frame, even if they're already due, so a frame always ends.
+    pub(crate) fn run_due_timers(&mut self, data: &mut C) {
+        let now = std::time::Instant::now();
+        for id in self.timers.get_due(now) {
+            // an earlier callback may have cancelled it
+            let Some(mut timer) = self.timers.take(id) else {  continue;  };
+            (timer.callback)(data, self);
+            self.timers.finish(timer, now);
+        }
+    }
+}
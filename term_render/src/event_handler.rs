@@ -9,6 +9,8 @@
 //! - `KeyCode`: Represents supported key codes (Delete, Tab, Arrow keys, Return, Escape).
 //! - `MouseEventType`: Represents mouse event types (Null, Left, Right, Middle, Down, Up).
 //! - `MouseState`: Represents mouse button states (Release, Press, Hold, Null).
+//! - `KeyEventKind`: Represents whether a key event was a press, repeat, or release.
+//! - `InputEvent`: A single key, character, mouse, scroll, or paste event, in the order it was typed.
 //!
 //! # Structs
 //! - `MouseEvent`: Stores mouse event type, position, and state.
@@ -26,6 +28,7 @@
 //!
 //! # Example
 //! ```rust
+//! # use term_render::event_handler::{KeyParser, KeyCode, KeyModifiers};
 //! let mut parser = KeyParser::new();
 //! // Feed input bytes to parser via vte::Parser
 //! // Query events:
@@ -44,7 +47,7 @@ const SCROLL_LOG_TIME: f64 = 0.75;
 /// A representation of keyboard modifier keys.
 /// Used to track the state of modifier keys during key events.
 #[repr(u8)]
-#[derive(PartialEq, Eq, Debug, Default)]
+#[derive(PartialEq, Eq, Debug, Default, Clone, Copy, Hash)]
 pub enum KeyModifiers {
     Shift,
     #[default] Command,
@@ -54,8 +57,45 @@ pub enum KeyModifiers {
 
 /// A set of special keycodes that aren't typical characters.
 /// Used to identify specific key events in terminal input.
+/// # Example
+/// ```
+/// use term_render::event_handler::{KeyCode, KeyModifiers, KeyParser};
+/// let mut parser = KeyParser::new();
+/// let mut vte_parser = vte::Parser::new();
+/// // page down (^[[6~), and then shift + F5 (^[[15;2~)
+/// for bytes in [&b"\x1b[6~"[..], b"\x1b[15;2~"] {
+///     parser.bytes = bytes.len();
+///     vte_parser.advance(&mut parser, bytes);
+/// }
+/// assert!(parser.contains_key_code(KeyCode::PageDown));
+/// assert!(parser.contains_key_code(KeyCode::F5));
+/// assert!(parser.contains_modifier(KeyModifiers::Shift));
+/// ```
+///
+/// Each of the navigation and function keys, in both their CSI (`^[[n~`) and SS3 (`^[O`) forms:
+/// ```
+/// use term_render::event_handler::{InputEvent, KeyCode, KeyParser};
+/// let keys: [(&[u8], KeyCode); 25] = [
+///     (b"\x1b[11~", KeyCode::F1), (b"\x1b[12~", KeyCode::F2), (b"\x1b[13~", KeyCode::F3),
+///     (b"\x1b[14~", KeyCode::F4), (b"\x1b[15~", KeyCode::F5), (b"\x1b[17~", KeyCode::F6),
+///     (b"\x1b[18~", KeyCode::F7), (b"\x1b[19~", KeyCode::F8), (b"\x1b[20~", KeyCode::F9),
+///     (b"\x1b[21~", KeyCode::F10), (b"\x1b[23~", KeyCode::F11), (b"\x1b[24~", KeyCode::F12),
+///     (b"\x1b[1~", KeyCode::Home), (b"\x1b[7~", KeyCode::Home), (b"\x1b[4~", KeyCode::End),
+///     (b"\x1b[8~", KeyCode::End), (b"\x1b[5~", KeyCode::PageUp), (b"\x1b[6~", KeyCode::PageDown),
+///     (b"\x1b[2~", KeyCode::Insert),
+///     (b"\x1bOP", KeyCode::F1), (b"\x1bOQ", KeyCode::F2), (b"\x1bOR", KeyCode::F3),
+///     (b"\x1bOS", KeyCode::F4), (b"\x1bOH", KeyCode::Home), (b"\x1bOF", KeyCode::End),
+/// ];
+/// let mut parser = KeyParser::new();
+/// let mut vte_parser = vte::Parser::new();
+/// for (bytes, key) in keys {
+///     parser.clear_events();
+///     parser.parse(&mut vte_parser, bytes);
+///     assert_eq!(parser.peek_events(), &[InputEvent::Key(key, vec![])], "{:?}", bytes);
+/// }
+/// ```
 #[repr(u8)]
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum KeyCode {
     Delete,
     Tab,
@@ -65,6 +105,34 @@ pub enum KeyCode {
     Down,
     Return,
     Escape,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    Insert,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+}
+
+/// Whether a key event was the initial press, a repeat from the key being held, or the key being let go.
+/// Only terminals supporting the kitty keyboard protocol (see `enable_enhanced_keyboard`) report
+/// repeats and releases; everywhere else, every key event is a press.
+#[repr(u8)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default, Hash)]
+pub enum KeyEventKind {
+    #[default] Press,
+    Repeat,
+    Release,
 }
 
 /// Different types of mouse events that can be detected.
@@ -95,6 +163,25 @@ pub struct MouseEvent {
     pub event_type: MouseEventType,
     pub position: (u16, u16),
     pub state: MouseState,
+    /// The number of consecutive left clicks on the same cell (2 for a double click, 3 for a triple click, etc.).
+    /// Presses further apart than the double click interval start over at 1; other events are 0.
+    pub click_count: u8,
+}
+
+/// A single input event. Every frame's events are queued in the order they arrived (see `KeyParser::peek_events`),
+/// so input depending on the order (like typing, or moving the cursor between characters) can be replayed exactly.
+#[derive(Debug, PartialEq, Clone)]
+pub enum InputEvent {
+    /// A special key, with the modifiers held while it was pressed.
+    Key(KeyCode, Vec <KeyModifiers>),
+    /// A typed character, with the modifiers held while it was typed.
+    Char(char, Vec <KeyModifiers>),
+    /// A mouse press, release, drag, or movement.
+    Mouse(MouseEvent),
+    /// A scroll wheel step (negative is up, and positive is down).
+    Scroll(i8),
+    /// Text pasted with bracketed paste.
+    Paste(String),
 }
 
 /// A parser for terminal input that tracks key events, modifiers, mouse events, and scroll events.
@@ -105,13 +192,127 @@ pub struct KeyParser {
     pub key_modifiers: Vec <KeyModifiers>,
     pub key_events: std::collections::HashMap <KeyCode, bool>,
     pub char_events: Vec <char>,
+    /// Every event this frame, in the order they arrived (see `peek_events` and `drain_events`).
+    /// `key_events`, `char_events`, `key_modifiers`, and `paste_event` are filled in alongside it, but
+    /// lose the order; typing "ab", moving left, and typing "c" shows up there as the same events as "abc".
+    /// # Example
+    /// ```
+    /// use term_render::event_handler::{InputEvent, KeyCode, KeyModifiers, KeyParser};
+    /// let mut parser = vte::Parser::new();
+    /// let mut events = KeyParser::new();
+    /// // each read is parsed separately (like keys typed faster than a frame)
+    /// for bytes in [&b"a"[..], b"b", b"\x1b[D", b"c", b"\x1b[1;2C", b"\x1b[200~pasted\x1b[201~"] {
+    ///     events.bytes = bytes.len();
+    ///     parser.advance(&mut events, bytes);
+    /// }
+    /// assert_eq!(events.peek_events(), &[
+    ///     InputEvent::Char('a', vec![]),
+    ///     InputEvent::Char('b', vec![]),
+    ///     InputEvent::Key(KeyCode::Left, vec![]),
+    ///     InputEvent::Char('c', vec![]),
+    ///     InputEvent::Key(KeyCode::Right, vec![KeyModifiers::Shift]),
+    ///     InputEvent::Paste(String::from("pasted")),
+    /// ]);
+    /// // the older accessors still see every event (without the order)
+    /// assert!(events.contains_key_code(KeyCode::Left) && events.contains_char('c'));
+    /// assert!(events.contains_modifier(KeyModifiers::Shift));
+    ///
+    /// // draining takes the events, leaving the queue empty
+    /// assert_eq!(events.drain_events().len(), 6);
+    /// assert!(events.peek_events().is_empty());
+    /// ```
+    pub events: Vec <InputEvent>,
     pub in_escape_seq: bool,
     pub bytes: usize,
+    /// The latest mouse event, which persists across frames (a press becomes a hold, and a release becomes null).
+    /// # Example
+    /// ```
+    /// use term_render::event_handler::{KeyParser, MouseEventType, MouseState};
+    /// let mut parser = vte::Parser::new();
+    /// let mut events = KeyParser::new();
+    /// // a left click at the 500th column (past the 223 columns the oldest encoding can report), which is
+    /// // reported 0-based like window positions
+    /// parser.advance(&mut events, b"\x1b[<0;500;3M");
+    /// let event = events.mouse_event.clone().unwrap();
+    /// assert_eq!((event.event_type, event.state, event.position), (MouseEventType::Left, MouseState::Press, (499, 2)));
+    /// parser.advance(&mut events, b"\x1b[<0;500;3m");
+    /// assert_eq!(events.mouse_event.as_ref().unwrap().state, MouseState::Release);
+    ///
+    /// // a right click, whose release isn't lost to the motion after it
+    /// events.clear_events();
+    /// parser.advance(&mut events, b"\x1b[<2;501;4M");
+    /// events.clear_events();
+    /// parser.advance(&mut events, b"\x1b[<2;501;4m\x1b[<35;502;4M");
+    /// let event = events.mouse_event.clone().unwrap();
+    /// assert_eq!((event.event_type, event.state), (MouseEventType::Right, MouseState::Release));
+    /// assert_eq!(events.last_mouse_position(), Some((501, 3)));
+    ///
+    /// // the older numeric encoding offsets the button by 32, and releases without saying which button
+    /// events.clear_events();
+    /// parser.advance(&mut events, b"\x1b[33;500;65535M");
+    /// assert_eq!(events.mouse_event.as_ref().unwrap().event_type, MouseEventType::Middle);
+    /// parser.advance(&mut events, b"\x1b[35;500;65535M");
+    /// let event = events.mouse_event.clone().unwrap();
+    /// assert_eq!((event.event_type, event.state, event.position), (MouseEventType::Middle, MouseState::Release, (499, 65534)));
+    /// ```
     pub mouse_event: Option <MouseEvent>,
     pub mouse_modifiers: Vec <KeyModifiers>,
     pub last_press: u128,
-    pub scroll_events: Vec <(std::time::SystemTime, i8)>,  // the sign is the direction
+    /// The recent scroll events, with the sign as the direction and the mouse's position when scrolling.
+    pub scroll_events: Vec <(std::time::SystemTime, i8, (u16, u16))>,
+    /// The smoothed scroll amount of every recent scroll event, wherever the mouse was (see `scroll_at`).
     pub scroll_accumulate: f64,
+    /// The in-progress (preedit) text of an input method composition, if one is active.
+    /// This isn't part of the typed text until the composition is committed, at which point
+    /// the committed characters arrive as normal char events and this is cleared.
+    pub composition: Option <String>,
+    /// The old and new terminal area if the terminal was resized this frame.
+    pub resize_event: Option <(crate::render::Rect, crate::render::Rect)>,
+    /// The kind of each key event this frame (only present for terminals reporting event types).
+    /// Released keys are recorded here without being added to `key_events`.
+    pub key_event_kinds: std::collections::HashMap <KeyCode, KeyEventKind>,
+    /// The kind of each char event this frame (only present for terminals reporting event types).
+    pub char_event_kinds: std::collections::HashMap <char, KeyEventKind>,
+    /// Set after `ESC O` (SS3), where the next character picks the key (F1-F4, home/end, or an arrow).
+    pub ss3_pending: bool,
+    /// The longest time (in milliseconds) between left presses for them to count as a multi-click.
+    pub double_click_interval: u128,
+    /// The time, position, and click count of the last left press (for counting multi-clicks).
+    pub last_click: Option <(u128, (u16, u16), u8)>,
+    /// Where the left button was pressed down, while it's still held (the start of a drag).
+    pub drag_origin: Option <(u16, u16)>,
+    /// The last position reported by any mouse event (including motion without a button held).
+    pub mouse_position: Option <(u16, u16)>,
+    /// The text pasted this frame (with bracketed paste). The pasted characters, including any
+    /// newlines or tabs, only show up here and never as char or key events.
+    pub paste_event: Option <String>,
+    /// Whether a paste is being received (between ^[[200~ and ^[[201~).
+    pub pasting: bool,
+    /// The text of the paste being received; pastes can span several reads.
+    pub paste_buffer: String,
+    /// The clipboard's contents, for the frame the terminal answered a clipboard request
+    /// (see `render::App::request_clipboard`). Terminals can take a while to answer, or never answer.
+    /// # Example
+    /// ```
+    /// let mut parser = vte::Parser::new();
+    /// let mut events = term_render::event_handler::KeyParser::new();
+    /// parser.advance(&mut events, b"\x1b]52;c;aGVsbG8gd29ybGQ=\x07");
+    /// assert_eq!(events.clipboard_content.as_deref(), Some("hello world"));
+    /// ```
+    pub clipboard_content: Option <String>,
+    /// Whether the terminal reported supporting synchronized output (DEC mode 2026), once it answers
+    /// the query sent when the terminal is set up. Terminals which don't understand the query never answer.
+    /// # Example
+    /// ```
+    /// let mut parser = vte::Parser::new();
+    /// let mut events = term_render::event_handler::KeyParser::new();
+    /// parser.advance(&mut events, b"\x1b[?2026;2$y");
+    /// assert_eq!(events.synchronized_output_support, Some(true));
+    /// parser.advance(&mut events, b"\x1b[?2026;0$y");
+    /// assert_eq!(events.synchronized_output_support, Some(false));
+    /// assert!(events.char_events.is_empty());
+    /// ```
+    pub synchronized_output_support: Option <bool>,
 }
 
 impl KeyParser {
@@ -127,9 +328,27 @@ impl KeyParser {
                 (KeyCode::Down, false),
                 (KeyCode::Return, false),
                 (KeyCode::Escape, false),
+                (KeyCode::PageUp, false),
+                (KeyCode::PageDown, false),
+                (KeyCode::Home, false),
+                (KeyCode::End, false),
+                (KeyCode::Insert, false),
+                (KeyCode::F1, false),
+                (KeyCode::F2, false),
+                (KeyCode::F3, false),
+                (KeyCode::F4, false),
+                (KeyCode::F5, false),
+                (KeyCode::F6, false),
+                (KeyCode::F7, false),
+                (KeyCode::F8, false),
+                (KeyCode::F9, false),
+                (KeyCode::F10, false),
+                (KeyCode::F11, false),
+                (KeyCode::F12, false),
             ]),
             key_modifiers: vec!(),
             char_events: vec!(),
+            events: vec!(),
             in_escape_seq: false,
             bytes: 0,
             mouse_event: None,
@@ -137,18 +356,33 @@ impl KeyParser {
             last_press: 0,
             scroll_events: vec![],
             scroll_accumulate: 0.0,
+            composition: None,
+            resize_event: None,
+            key_event_kinds: std::collections::HashMap::new(),
+            char_event_kinds: std::collections::HashMap::new(),
+            ss3_pending: false,
+            double_click_interval: 400,
+            last_click: None,
+            drag_origin: None,
+            mouse_position: None,
+            paste_event: None,
+            pasting: false,
+            paste_buffer: String::new(),
+            clipboard_content: None,
+            synchronized_output_support: None,
         }
     }
 
     // tracking a log of scroll events to average them out over a duration of time
     /// Handles a scroll event by accumulating its direction and updating the average scroll value.
-    /// This method records the time and direction of the scroll event, then calls `update_scroll
-    fn scroll (&mut self, sign: i8) {
+    /// This method records the time, direction, and position of the scroll event, then calls `update_scroll`
+    fn scroll (&mut self, sign: i8, position: (u16, u16)) {
         let time = std::time::SystemTime::now();
         if self.scroll_accumulate.is_sign_negative() != sign.is_negative(){
             self.scroll_events.clear();  // so on sign flip it doesn't do weird things
         }
-        self.scroll_events.push((time, sign));
+        self.scroll_events.push((time, sign, position));
+        self.events.push(InputEvent::Scroll(sign));
         self.update_scroll();
     }
 
@@ -157,18 +391,34 @@ impl KeyParser {
     /// applying sensitivity and time scaling to smooth out the scroll input.
     fn update_scroll(&mut self) {
         let time = std::time::SystemTime::now();
-        let mut valid = vec![];
-        let mut avg = 0.0;
-        for (other_time, other_sign) in &self.scroll_events {
-            // 0.000001 is the conversion rate from micro seconds to seconds
-            let duration = time.duration_since(*other_time).unwrap_or_default().as_secs_f64();
-            if duration < SCROLL_LOG_TIME {
-                avg += *other_sign as f64; valid.push((*other_time, *other_sign));
-            }
-        }
-        avg *= SCROLL_SENSITIVITY / SCROLL_LOG_TIME;
-        self.scroll_accumulate = avg;
-        self.scroll_events = valid;
+        self.scroll_events.retain(|(other_time, _sign, _position)| {
+            time.duration_since(*other_time).unwrap_or_default().as_secs_f64() < SCROLL_LOG_TIME
+        });
+        self.scroll_accumulate = self.scroll_at(|_position| true);
+    }
+
+    /// Gets the smoothed scroll amount (like `scroll_accumulate`) of only the recent scroll events whose
+    /// positions pass the filter, so separate areas of the screen can scroll independently
+    /// (see `Widget::scroll_delta`).
+    /// # Example
+    /// ```
+    /// use term_render::event_handler::KeyParser;
+    /// let mut parser = vte::Parser::new();
+    /// let mut events = KeyParser::new();
+    /// // scrolling down twice in the left half and once in the right half (at 0-based columns 4 and 24)
+    /// parser.advance(&mut events, b"\x1b[<65;5;2M\x1b[<65;25;2M\x1b[<65;5;3M");
+    /// let left = events.scroll_at(|(x, _y)| x < 20);
+    /// let right = events.scroll_at(|(x, _y)| x >= 20);
+    /// assert!(left > 0.0 && right > 0.0);
+    /// assert!((left - right * 2.0).abs() < 1e-9);
+    /// assert!((left + right - events.scroll_accumulate).abs() < 1e-9);
+    /// ```
+    pub fn scroll_at (&self, position_filter: impl Fn((u16, u16)) -> bool) -> f64 {
+        let total: f64 = self.scroll_events.iter()
+            .filter(|(_time, _sign, position)| position_filter(*position))
+            .map(|(_time, sign, _position)| *sign as f64)
+            .sum();
+        total * SCROLL_SENSITIVITY / SCROLL_LOG_TIME
     }
 
     /// Clears all tracked events and resets the parser state.
@@ -180,9 +430,15 @@ impl KeyParser {
     /// internally and shouldn't ever need to be manually called*
     pub fn clear_events (&mut self) {
         self.char_events.clear();
+        self.events.clear();
         self.key_modifiers.clear();
         self.mouse_modifiers.clear();
         self.key_events.clear();
+        self.resize_event = None;
+        self.paste_event = None;
+        self.clipboard_content = None;
+        self.key_event_kinds.clear();
+        self.char_event_kinds.clear();
         self.in_escape_seq = false;
         self.update_scroll();
 
@@ -205,10 +461,257 @@ impl KeyParser {
         }
     }
 
+    /// Updates the preedit text of the current input method composition (`None` ends it).
+    /// Most terminals draw the preedit themselves and only send the committed text, in which case
+    /// this is never set; for terminals (or embedding backends) which forward the composition state,
+    /// this should be called with each update so text inputs can display it at the cursor.
+    /// # Example
+    /// ```
+    /// use term_render::widget_impls::{TypingWidget, TypingWidgetBuilder, WidgetBuilder};
+    /// use term_render::widget::Scene;
+    /// use term_render::render::{Colored, Span};
+    /// let mut app = term_render::App::<()>::new_headless(12, 1);
+    /// let mut scene = Scene::new();
+    /// let field = TypingWidgetBuilder::<()>::builder(String::from("field"))
+    ///     // drawing the preedit in brackets
+    ///     .with_renderer(Box::new(|_size, _position, typed, _selection, _selected, _data| {
+    ///         Some(vec![Span::from_tokens(vec![Colored::new(format!("{}[{}]{}", typed[0], typed[1], typed[2]))])])
+    ///     }))
+    ///     .with_position((0, 0))
+    ///     .with_size((12, 1))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .unwrap();
+    /// scene.set_focus(field).unwrap();
+    /// let mut parser = vte::Parser::new();
+    /// let mut frame = |scene: &mut Scene<()>, composition: Option<&str>, keys: &[&[u8]]| {
+    ///     app.events.write().clear_events();
+    ///     app.events.write().set_composition(composition.map(String::from));
+    ///     for key in keys {
+    ///         app.events.write().parse(&mut parser, key);
+    ///     }
+    ///     scene.update_all_widgets(&mut app, &mut ()).unwrap();
+    ///     let typed_text = scene.widget_as::<TypingWidget<()>>("field").unwrap().typed_text.clone();
+    ///     (typed_text, app.renderer.write().snapshot()[0].clone())
+    /// };
+    /// assert_eq!(frame(&mut scene, None, &[b"a", b"b", b"\x1b[D"]), (String::from("ab"), String::from("a[]b")));
+    /// // the preedit is shown at the cursor without being typed, and the keys belong to the input method
+    /// assert_eq!(frame(&mut scene, Some("に"), &[]), (String::from("ab"), String::from("a[に]b")));
+    /// assert_eq!(frame(&mut scene, Some("日本"), &[b"\x1b[D"]), (String::from("ab"), String::from("a[日本]b")));
+    /// // committing the text ends the composition, inserting it once
+    /// assert_eq!(frame(&mut scene, Some("日本"), &["日本".as_bytes()]), (String::from("a日本b"), String::from("a日本[]b")));
+    /// assert!(!app.events.read().is_composing());
+    /// ```
+    pub fn set_composition (&mut self, composition: Option <String>) {
+        // an empty preedit means the composition was cancelled
+        self.composition = composition.filter(|text| !text.is_empty());
+    }
+
+    /// Checks if an input method composition is currently in progress.
+    pub fn is_composing (&self) -> bool {
+        self.composition.is_some()
+    }
+
+    /// Sets the longest time between left presses for them to count towards the click count
+    /// of a double (or triple, ...) click. The default is 400ms.
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use term_render::event_handler::KeyParser;
+    /// let mut parser = vte::Parser::new();
+    /// let mut events = KeyParser::new();
+    /// let mut click = |events: &mut KeyParser, x: u16| {
+    ///     events.clear_events();
+    ///     parser.advance(events, format!("\x1b[<0;{};5M", x).as_bytes());
+    ///     let count = events.mouse_event.as_ref().unwrap().click_count;
+    ///     parser.advance(events, format!("\x1b[<0;{};5m", x).as_bytes());
+    ///     count
+    /// };
+    /// events.set_double_click_interval(Duration::from_secs(60));
+    /// // a triple click keeps counting rather than starting over
+    /// assert_eq!([click(&mut events, 5), click(&mut events, 5), click(&mut events, 5)], [1, 2, 3]);
+    /// // a press on another cell starts over
+    /// assert_eq!([click(&mut events, 6), click(&mut events, 6)], [1, 2]);
+    /// // and so does waiting longer than the interval
+    /// events.set_double_click_interval(Duration::from_millis(20));
+    /// std::thread::sleep(Duration::from_millis(40));
+    /// assert_eq!(click(&mut events, 6), 1);
+    /// ```
+    pub fn set_double_click_interval (&mut self, interval: std::time::Duration) {
+        self.double_click_interval = interval.as_millis();
+    }
+
+    /// Gets where the current drag started (where the left button was pressed), or None if
+    /// the left button isn't held. This persists across frames until the button is released.
+    pub fn drag_origin (&self) -> Option <(u16, u16)> {
+        self.drag_origin
+    }
+
+    /// Gets how far the mouse has moved since the left button was pressed, or None if it isn't held.
+    /// Drags further than an i16 can hold (only possible on enormous terminals) saturate.
+    /// # Example
+    /// ```
+    /// use term_render::event_handler::KeyParser;
+    /// let mut parser = vte::Parser::new();
+    /// let mut events = KeyParser::new();
+    /// assert_eq!(events.drag_delta(), None);
+    /// parser.advance(&mut events, b"\x1b[<0;10;10M");  // pressed at (9, 9)
+    /// parser.advance(&mut events, b"\x1b[<32;7;15M");  // dragged to (6, 14)
+    /// assert_eq!(events.drag_delta(), Some((-3, 5)));
+    ///
+    /// parser.advance(&mut events, b"\x1b[<32;50000;1M");
+    /// assert_eq!(events.drag_delta(), Some((i16::MAX, -9)));
+    /// parser.advance(&mut events, b"\x1b[<0;50000;1m");
+    /// assert_eq!(events.drag_delta(), None);
+    ///
+    /// parser.advance(&mut events, b"\x1b[<0;50000;1M");
+    /// parser.advance(&mut events, b"\x1b[<32;1;1M");
+    /// assert_eq!(events.drag_delta(), Some((i16::MIN, 0)));
+    /// ```
+    pub fn drag_delta (&self) -> Option <(i16, i16)> {
+        let origin = self.drag_origin?;
+        let position = self.mouse_position?;
+        let delta = |from: u16, to: u16| (to as i32 - from as i32).clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+        Some((delta(origin.0, position.0), delta(origin.1, position.1)))
+    }
+
+    /// Gets the last known position of the mouse. Motion events are reported even without
+    /// a button held (all motion tracking is enabled by `enable_mouse_capture`), so this can be used for hovering.
+    pub fn last_mouse_position (&self) -> Option <(u16, u16)> {
+        self.mouse_position
+    }
+
+    /// Gets the text pasted this frame, if any (see `paste_event`).
+    pub fn get_paste (&self) -> Option <&str> {
+        self.paste_event.as_deref()
+    }
+
+    /// Returns the old and new terminal area if the terminal was resized this frame.
+    pub fn was_resized (&self) -> Option <(crate::render::Rect, crate::render::Rect)> {
+        self.resize_event.clone()
+    }
+
+    /// Gets whether the key was pressed, repeated, or released this frame. On terminals which don't report
+    /// event types, any recorded key is a press; None means the key had no event this frame. With several
+    /// events for the same key in a frame, the last one's kind is kept.
+    /// # Example
+    /// ```
+    /// use term_render::event_handler::{KeyCode, KeyEventKind, KeyParser};
+    /// let mut parser = vte::Parser::new();
+    /// let mut events = KeyParser::new();
+    /// parser.advance(&mut events, b"\x1b[A");
+    /// assert_eq!(events.key_event_kind(KeyCode::Up), Some(KeyEventKind::Press));
+    /// // held down, repeating within the same frame
+    /// parser.advance(&mut events, b"\x1b[1;1:2A");
+    /// assert_eq!(events.key_event_kind(KeyCode::Up), Some(KeyEventKind::Repeat));
+    /// parser.advance(&mut events, b"\x1b[1;1:3A");
+    /// assert_eq!(events.key_event_kind(KeyCode::Up), Some(KeyEventKind::Release));
+    /// assert!(events.contains_key_code(KeyCode::Up));  // it was still pressed this frame
+    ///
+    /// // only released, so it wasn't pressed
+    /// events.clear_events();
+    /// parser.advance(&mut events, b"\x1b[1;1:3B");
+    /// assert_eq!(events.key_event_kind(KeyCode::Down), Some(KeyEventKind::Release));
+    /// assert!(!events.contains_key_code(KeyCode::Down));
+    /// ```
+    pub fn key_event_kind (&self, key: KeyCode) -> Option <KeyEventKind> {
+        if let Some(kind) = self.key_event_kinds.get(&key) {  return Some(*kind);  }
+        match self.contains_key_code(key) {
+            true => Some(KeyEventKind::Press),
+            false => None,
+        }
+    }
+
+    /// The same as `key_event_kind` but for characters.
+    /// # Example
+    /// ```
+    /// use term_render::event_handler::{InputEvent, KeyCode, KeyEventKind, KeyModifiers, KeyParser};
+    /// let mut parser = vte::Parser::new();
+    /// let mut events = KeyParser::new();
+    /// // kitty's `CSI code ; modifiers:event-type u` keys: a press, a repeat, and then the release
+    /// events.parse(&mut parser, b"\x1b[97u");
+    /// assert_eq!(events.char_event_kind('a'), Some(KeyEventKind::Press));
+    /// events.parse(&mut parser, b"\x1b[97;1:2u");
+    /// assert_eq!(events.char_event_kinds.get(&'a'), Some(&KeyEventKind::Repeat));
+    /// events.parse(&mut parser, b"\x1b[97;1:3u");
+    /// assert_eq!(events.char_event_kind('a'), Some(KeyEventKind::Release));
+    /// // the release isn't typed again
+    /// assert_eq!(events.peek_events(), &[InputEvent::Char('a', vec![]), InputEvent::Char('a', vec![])]);
+    ///
+    /// // ctrl + a, then shift + enter released
+    /// events.clear_events();
+    /// events.parse(&mut parser, b"\x1b[97;5u");
+    /// events.parse(&mut parser, b"\x1b[13;2:3u");
+    /// assert_eq!(events.peek_events(), &[InputEvent::Char('a', vec![KeyModifiers::Control])]);
+    /// assert_eq!(events.char_event_kind('a'), Some(KeyEventKind::Press));
+    /// assert_eq!(events.key_event_kinds.get(&KeyCode::Return), Some(&KeyEventKind::Release));
+    /// assert!(!events.contains_key_code(KeyCode::Return));
+    ///
+    /// // shift + enter pressed
+    /// events.clear_events();
+    /// events.parse(&mut parser, b"\x1b[13;2u");
+    /// assert_eq!(events.peek_events(), &[InputEvent::Key(KeyCode::Return, vec![KeyModifiers::Shift])]);
+    /// assert_eq!(events.key_event_kind(KeyCode::Return), Some(KeyEventKind::Press));
+    /// ```
+    pub fn char_event_kind (&self, chr: char) -> Option <KeyEventKind> {
+        if let Some(kind) = self.char_event_kinds.get(&chr) {  return Some(*kind);  }
+        match self.contains_char(chr) {
+            true => Some(KeyEventKind::Press),
+            false => None,
+        }
+    }
+
+    /// Gets every event this frame in the order they arrived, without removing them (see `events`).
+    pub fn peek_events (&self) -> &[InputEvent] {
+        &self.events
+    }
+
+    /// Takes every event this frame in the order they arrived, leaving the queue empty (see `events`).
+    /// The older accessors, like `contains_key_code`, aren't affected.
+    pub fn drain_events (&mut self) -> Vec <InputEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Records a key press (queueing it, and marking it as pressed in `key_events`).
+    pub fn press_key (&mut self, key: KeyCode) {
+        self.key_events.insert(key, true);
+        self.events.push(InputEvent::Key(key, vec![]));
+    }
+
+    // records a typed character (queueing it, and adding it to `char_events`)
+    fn push_char (&mut self, chr: char) {
+        self.char_events.push(chr);
+        self.events.push(InputEvent::Char(chr, vec![]));
+    }
+
+    // the number of queued events and modifiers before a sequence is parsed
+    fn mark_events (&self) -> (usize, usize) {
+        (self.events.len(), self.key_modifiers.len())
+    }
+
+    // records a character typed with Alt/Option held (an escape followed by the character)
+    fn push_alt_char (&mut self, chr: char) {
+        let mark = self.mark_events();
+        self.push_char(chr);
+        self.key_modifiers.push(KeyModifiers::Option);
+        self.attach_modifiers(mark);
+    }
+
+    // gives the keys and characters queued by a sequence the modifiers it added
+    // (sequences add their modifiers before or after the key, depending on the sequence)
+    fn attach_modifiers (&mut self, (queued, modifiers): (usize, usize)) {
+        let Some(added) = self.key_modifiers.get(modifiers..).filter(|added| !added.is_empty()) else {  return;  };
+        for event in self.events.iter_mut().skip(queued) {
+            if let InputEvent::Key(_, held) | InputEvent::Char(_, held) = event {
+                held.extend_from_slice(added);
+            }
+        }
+    }
+
     /// Checks if a specific character event has been recorded.
     /// Returns `true` if the character is present in the recorded events, otherwise `false
     pub fn contains_char (&self, chr: char) -> bool {
-        self.char_events.contains(&chr)
+        self.events.iter().any(|event| matches!(event, InputEvent::Char(other, _) if *other == chr)) ||
+            self.char_events.contains(&chr)
     }
 
     /// Checks if a specific key modifier is currently active.
@@ -226,53 +729,91 @@ impl KeyParser {
     /// Checks if a specific key code event has been recorded.
     /// Returns `true` if the key code is present in the recorded events, otherwise `false
     pub fn contains_key_code (&self, key: KeyCode) -> bool {
-        *self.key_events.get(&key).unwrap_or(&false)
+        self.events.iter().any(|event| matches!(event, InputEvent::Key(other, _) if *other == key)) ||
+            *self.key_events.get(&key).unwrap_or(&false)
     }
 
     /// Handles mouse escape codes by parsing the provided numbers and character.
     /// This method extracts the button type, position, and modifiers from the escape code,
     /// then updates the mouse event state accordingly.
     /// It supports different mouse buttons, scroll events, and modifier keys.
-    fn handle_mouse_escape_codes (&mut self, numbers: &[u16], c: char) {
-        if let Some([byte, x, y]) = numbers.get(0..3) {
-            let button = byte & 0b11; // Mask lowest 2 bits (button type)
-            //println!("button: {}, numbers: {:?}", button, numbers);
+    /// Two encodings are understood: SGR (`^[[<b;x;yM`, or `m` for a release), which is what
+    /// `enable_mouse_capture` asks for, and the older numeric form (`^[[b;x;yM`, with the button
+    /// offset by 32 and a release reported as button 3). Coordinates go up to `u16::MAX` in both.
+    fn handle_mouse_escape_codes (&mut self, numbers: &[u16], intermediates: &[u8], c: char) {
+        let sgr = match intermediates {
+            b"<" => true,
+            [] if c == 'M' => false,
+            _ => return,  // some other sequence ending in m/M
+        };
+        let Some(&[code, x, y]) = numbers.get(0..3) else {  return;  };
+        // the terminal reports 1-based positions, while windows and widgets are positioned from (0, 0)
+        let (x, y) = (x.saturating_sub(1), y.saturating_sub(1));
+        let byte = match sgr {
+            true => code,
+            false => code.saturating_sub(32),
+        };
+        let button = byte & 0b11; // Mask lowest 2 bits (button type)
+        let is_motion = (byte & 32) != 0;
+        let is_scroll = (byte & 64) != 0;
+        let release = match sgr {
+            true => c == 'm',
+            false => button == 3 && !is_motion && !is_scroll,
+        };
 
-            // adding key press modifiers
-            if (byte & 32) != 0 {
-                self.key_modifiers.push(KeyModifiers::Shift);
+        // adding the modifiers held during the mouse event
+        for (bit, modifier) in [(4, KeyModifiers::Shift), (8, KeyModifiers::Option), (16, KeyModifiers::Control)] {
+            if (byte & bit) != 0 && !self.mouse_modifiers.contains(&modifier) {
+                self.mouse_modifiers.push(modifier);
             }
-            if (byte & 64) != 0 {
-                self.key_modifiers.push(KeyModifiers::Option);
-            }
-            if (byte & 128) != 0 {
-                self.key_modifiers.push(KeyModifiers::Control);
-            }
-
-            //println!("Code: {:?} / {}", numbers, c);
+        }
 
-            let is_scroll = (byte & 64) != 0;
-            let event_type = match (is_scroll, button) {
-                (true, 0) => {
-                    self.scroll(-1i8);
-                    MouseEventType::Up
-                },
-                (true, 1) => {
-                    self.scroll(1i8);
-                    MouseEventType::Down
-                },
-                (false, 0) => MouseEventType::Left,
-                (false, 1) => MouseEventType::Middle,
-                (false, 2) => MouseEventType::Right,
-                _ => MouseEventType::Null
-            };
+        // the button being held (if any), which a release without a button belongs to
+        let held = self.mouse_event.as_ref()
+            .filter(|event| matches!(event.state, MouseState::Press | MouseState::Hold))
+            .map(|event| event.event_type.clone())
+            .filter(|event_type| matches!(event_type, MouseEventType::Left | MouseEventType::Middle | MouseEventType::Right));
+        let event_type = match (is_scroll, button) {
+            (true, 0) => {
+                self.scroll(-1i8, (x, y));
+                MouseEventType::Up
+            },
+            (true, 1) => {
+                self.scroll(1i8, (x, y));
+                MouseEventType::Down
+            },
+            (true, _) => MouseEventType::Null,  // horizontal scrolling
+            (false, 0) if byte & 128 == 0 => MouseEventType::Left,
+            (false, 1) if byte & 128 == 0 => MouseEventType::Middle,
+            (false, 2) if byte & 128 == 0 => MouseEventType::Right,
+            // the older encoding doesn't say which button was released
+            (false, 3) if release => held.clone().unwrap_or(MouseEventType::Null),
+            _ => MouseEventType::Null,  // moving without a button held (or the extra buttons)
+        };
 
-            if matches!(event_type, MouseEventType::Left) && numbers[0] == 4 {
-                self.mouse_modifiers.push(KeyModifiers::Shift);
+        // tracking the position and drags (these persist across frames, unlike the event itself)
+        self.mouse_position = Some((x, y));
+        match (&event_type, release) {
+            (MouseEventType::Left, false) if !is_motion => self.drag_origin = Some((x, y)),
+            (MouseEventType::Left | MouseEventType::Null, true) => self.drag_origin = None,
+            _ => {},
+        }
+        if is_motion && !release {
+            // moving with a button down continues the current press rather than starting a new one
+            if let Some(event) = &mut self.mouse_event && held.as_ref() == Some(&event_type) {
+                event.position = (x, y);
+                let event = event.clone();
+                self.events.push(InputEvent::Mouse(event));
+                return;
             }
-
-            self.calculate_mouse_event_code(event_type, (*x, *y), c);
+            // a press or release which hasn't been seen yet (i.e. a right click) isn't replaced by a move
+            if event_type == MouseEventType::Null && self.mouse_event.as_ref()
+                .is_some_and(|event| event.event_type != MouseEventType::Null &&
+                    matches!(event.state, MouseState::Press | MouseState::Release))
+            {  return;  }
         }
+
+        self.calculate_mouse_event_code(event_type, (x, y), if release {  'm'  } else {  'M'  });
     }
 
     /// Updates the mouse event state based on the provided event type, position, and character.
@@ -285,18 +826,43 @@ impl KeyParser {
         (x, y): (u16, u16),
         c: char
     ) {
-        if let Some(event) = &mut self.mouse_event {
-            if matches!(event_type, MouseEventType::Left) &&
-                event.position != (x, y) &&
-                matches!(event.state, MouseState::Hold) &&
-                c == 'M'
-            {
-                event.position = (x, y);
-                return;
-            }
+        // moving to another cell between clicks starts the count over
+        if let Some((_, position, _)) = self.last_click && position != (x, y) {
+            self.last_click = None;
         }
 
-        self.mouse_event = Some(MouseEvent {
+        if let Some(event) = &mut self.mouse_event &&
+            matches!(event_type, MouseEventType::Left) &&
+            event.position != (x, y) &&
+            matches!(event.state, MouseState::Hold) &&
+            c == 'M'
+        {
+            event.position = (x, y);
+            let event = event.clone();
+            self.events.push(InputEvent::Mouse(event));
+            return;
+        }
+
+        let click_count = match (&event_type, c) {
+            (MouseEventType::Left, 'M') => {
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::SystemTime::UNIX_EPOCH)
+                    .map(|time| time.as_millis())
+                    .unwrap_or(0);
+                let count = match self.last_click {
+                    Some((time, _, count)) if now.saturating_sub(time) <= self.double_click_interval => count.saturating_add(1),
+                    _ => 1,
+                };
+                self.last_click = Some((now, (x, y), count));
+                count
+            },
+            // the release belongs to the same click
+            (MouseEventType::Left, 'm') => self.last_click.map(|click| click.2).unwrap_or(0),
+            _ => 0,
+        };
+
+        let event = MouseEvent {
+            click_count,
             event_type,
             position: (x, y),
             state: {
@@ -306,7 +872,12 @@ impl KeyParser {
                     _ => MouseState::Null,
                 }
             },
-        });
+        };
+        // scrolling is queued as its own event
+        if !matches!(event.event_type, MouseEventType::Down | MouseEventType::Up) {
+            self.events.push(InputEvent::Mouse(event.clone()));
+        }
+        self.mouse_event = Some(event);
     }
 
     /// Handles custom escape codes by parsing the provided numbers.
@@ -322,94 +893,94 @@ impl KeyParser {
     fn handle_custom_escape_codes (&mut self, numbers: &[u16]) {
         match numbers[1] {
             2 => {
-                self.key_events.insert(KeyCode::Delete, true);
+                self.press_key(KeyCode::Delete);
                 self.key_modifiers.push(KeyModifiers::Shift);
             }
             3 => {
-                self.key_events.insert(KeyCode::Delete, true);
+                self.press_key(KeyCode::Delete);
                 self.key_modifiers.push(KeyModifiers::Option);
             }
             4 => {
-                self.key_events.insert(KeyCode::Left, true);
+                self.press_key(KeyCode::Left);
                 self.key_modifiers.push(KeyModifiers::Command);
             }
             5 => {
-                self.key_events.insert(KeyCode::Right, true);
+                self.press_key(KeyCode::Right);
                 self.key_modifiers.push(KeyModifiers::Command);
             }
             6 => {
-                self.key_events.insert(KeyCode::Up, true);
+                self.press_key(KeyCode::Up);
                 self.key_modifiers.push(KeyModifiers::Command);
             }
             7 => {
-                self.key_events.insert(KeyCode::Down, true);
+                self.press_key(KeyCode::Down);
                 self.key_modifiers.push(KeyModifiers::Command);
             }
             8 => {
-                self.key_events.insert(KeyCode::Delete, true);
+                self.press_key(KeyCode::Delete);
                 self.key_modifiers.push(KeyModifiers::Option);
                 self.key_modifiers.push(KeyModifiers::Shift);
             }
             9 => {
-                self.key_events.insert(KeyCode::Delete, true);
+                self.press_key(KeyCode::Delete);
                 self.key_modifiers.push(KeyModifiers::Command);
             }
             10 => {
-                self.key_events.insert(KeyCode::Delete, true);
+                self.press_key(KeyCode::Delete);
                 self.key_modifiers.push(KeyModifiers::Command);
                 self.key_modifiers.push(KeyModifiers::Shift);
             }
             11 => {
                 self.key_modifiers.push(KeyModifiers::Command);
-                self.char_events.push('s');  // command + s
+                self.push_char('s');  // command + s
             }
             12 => {  // lrud
-                self.key_events.insert(KeyCode::Left, true);
+                self.press_key(KeyCode::Left);
                 self.key_modifiers.push(KeyModifiers::Command);
                 self.key_modifiers.push(KeyModifiers::Shift);
             }
             13 => {
-                self.key_events.insert(KeyCode::Right, true);
+                self.press_key(KeyCode::Right);
                 self.key_modifiers.push(KeyModifiers::Command);
                 self.key_modifiers.push(KeyModifiers::Shift);
             }
             14 => {
-                self.key_events.insert(KeyCode::Up, true);
+                self.press_key(KeyCode::Up);
                 self.key_modifiers.push(KeyModifiers::Command);
                 self.key_modifiers.push(KeyModifiers::Shift);
             }
             15 => {
-                self.key_events.insert(KeyCode::Down, true);
+                self.press_key(KeyCode::Down);
                 self.key_modifiers.push(KeyModifiers::Command);
                 self.key_modifiers.push(KeyModifiers::Shift);
             }
             16 => {
                 self.key_modifiers.push(KeyModifiers::Command);
-                self.char_events.push('c');
+                self.push_char('c');
             }
             17 => {
                 self.key_modifiers.push(KeyModifiers::Command);
-                self.char_events.push('v');
+                self.push_char('v');
             }
             18 => {
                 self.key_modifiers.push(KeyModifiers::Command);
-                self.char_events.push('x');
+                self.push_char('x');
             }
             19 => {
                 self.key_modifiers.push(KeyModifiers::Command);
-                self.char_events.push('f');
+                self.push_char('f');
             }
             20 => {
                 self.key_modifiers.push(KeyModifiers::Command);
-                self.char_events.push('z');
+                self.push_char('z');
             }
             21 => {
                 self.key_modifiers.push(KeyModifiers::Command);
                 self.key_modifiers.push(KeyModifiers::Shift);
-                self.char_events.push('z');
+                self.push_char('z');
             }
             22 => {
-                self.key_events.insert(KeyCode::Tab, true);
+                self.press_key(KeyCode::Tab);
                 self.key_modifiers.push(KeyModifiers::Option);
             }
             _ => {}
@@ -424,33 +995,109 @@ impl KeyParser {
         match c {
             'D' => {
                 self.key_modifiers.push(KeyModifiers::Control);
-                self.key_events.insert(KeyCode::Left, true);
+                self.press_key(KeyCode::Left);
             },
             'C' => {
                 self.key_modifiers.push(KeyModifiers::Control);
-                self.key_events.insert(KeyCode::Right, true);
+                self.press_key(KeyCode::Right);
             },
             'A' => {
                 self.key_modifiers.push(KeyModifiers::Control);
-                self.key_events.insert(KeyCode::Up, true);
+                self.press_key(KeyCode::Up);
             },
             'B' => {
                 self.key_modifiers.push(KeyModifiers::Control);
-                self.key_events.insert(KeyCode::Down, true);
+                self.press_key(KeyCode::Down);
             },
             _ => {}  // control + arrows
         }
     }
 
     /// Handles standard escape codes by parsing the provided numbers and character.
+    // xterm style modifiers (also used by the kitty protocol) are encoded as 1 + a bit field (shift, alt, ctrl, super, ...)
+    fn handle_modifier_param (&mut self, modifiers: u16) {
+        let bits = modifiers.saturating_sub(1);
+        if bits & 1 != 0 {  self.key_modifiers.push(KeyModifiers::Shift);  }
+        if bits & 2 != 0 {  self.key_modifiers.push(KeyModifiers::Option);  }
+        if bits & 4 != 0 {  self.key_modifiers.push(KeyModifiers::Control);  }
+        if bits & 8 != 0 {  self.key_modifiers.push(KeyModifiers::Command);  }
+    }
+
+    // handles the kitty protocol's `CSI key-code ; modifiers:event-type u` form
+    fn handle_kitty_key (&mut self, params: &[Vec <u16>], kind: KeyEventKind) {
+        let key_code = params.first().and_then(|param| param.first()).copied().unwrap_or(0);
+        let modifiers = params.get(1).and_then(|param| param.first()).copied().unwrap_or(1);
+        let key = match key_code {
+            13 => Some(KeyCode::Return),
+            9 => Some(KeyCode::Tab),
+            127 | 8 => Some(KeyCode::Delete),
+            27 => Some(KeyCode::Escape),
+            _ => None,
+        };
+        if let Some(key) = key {
+            self.key_event_kinds.insert(key, kind);
+            if kind == KeyEventKind::Release {  return;  }
+            self.press_key(key);
+            self.handle_modifier_param(modifiers);
+            return;
+        }
+
+        // anything else is a unicode code point (kitty's private use area keys are ignored)
+        let Some(chr) = char::from_u32(key_code as u32) else {  return;  };
+        if chr.is_control() || (0xE000..=0xF8FF).contains(&(key_code as u32)) {  return;  }
+        self.char_event_kinds.insert(chr, kind);
+        if kind == KeyEventKind::Release {  return;  }
+        self.push_char(chr);
+        self.handle_modifier_param(modifiers);
+    }
+
     fn handle_standard_escape_codes (&mut self, numbers: &Vec <u16>, c: char) {
         match c as u8 {
+            0x7E => {  // ~ (the vt style keys; ^[[5~, ^[[15;2~, etc.)
+                let key = match numbers.first() {
+                    Some(1 | 7) => Some(KeyCode::Home),
+                    Some(2) => Some(KeyCode::Insert),
+                    Some(4 | 8) => Some(KeyCode::End),
+                    Some(5) => Some(KeyCode::PageUp),
+                    Some(6) => Some(KeyCode::PageDown),
+                    Some(11) => Some(KeyCode::F1),
+                    Some(12) => Some(KeyCode::F2),
+                    Some(13) => Some(KeyCode::F3),
+                    Some(14) => Some(KeyCode::F4),
+                    Some(15) => Some(KeyCode::F5),
+                    Some(17) => Some(KeyCode::F6),
+                    Some(18) => Some(KeyCode::F7),
+                    Some(19) => Some(KeyCode::F8),
+                    Some(20) => Some(KeyCode::F9),
+                    Some(21) => Some(KeyCode::F10),
+                    Some(23) => Some(KeyCode::F11),
+                    Some(24) => Some(KeyCode::F12),
+                    _ => None,
+                };
+                if let Some(key) = key {
+                    self.press_key(key);
+                    if let Some(modifiers) = numbers.get(1) {  self.handle_modifier_param(*modifiers);  }
+                }
+            },
+            // ^[[H and ^[[F (xterm home/end), and ^[[1;2P for modified F1-F4
+            0x48 | 0x46 | 0x50..=0x53 => {
+                let key = match c {
+                    'H' => KeyCode::Home,
+                    'F' => KeyCode::End,
+                    'P' => KeyCode::F1,
+                    'Q' => KeyCode::F2,
+                    'R' => KeyCode::F3,
+                    _ => KeyCode::F4,
+                };
+                self.press_key(key);
+                if let Some(modifiers) = numbers.get(1) {  self.handle_modifier_param(*modifiers);  }
+            },
             0x5A => {
-                self.key_events.insert(KeyCode::Tab, true);
+                self.press_key(KeyCode::Tab);
                 self.key_modifiers.push(KeyModifiers::Shift);
             },
             0x44 => {
-                self.key_events.insert(KeyCode::Left, true);
+                self.press_key(KeyCode::Left);
                 if *numbers == [1, 3] {
                     self.key_modifiers.push(KeyModifiers::Option);
                 } else if *numbers == [1, 2] {
@@ -461,7 +1108,7 @@ impl KeyParser {
                 }
             },
             0x43 => {
-                self.key_events.insert(KeyCode::Right, true);
+                self.press_key(KeyCode::Right);
                 if *numbers == [1, 3] {
                     self.key_modifiers.push(KeyModifiers::Option);
                 } else if *numbers == [1, 2] {
@@ -472,7 +1119,7 @@ impl KeyParser {
                 }
             },
             0x41 => {
-                self.key_events.insert(KeyCode::Up, true);
+                self.press_key(KeyCode::Up);
                 if *numbers == [1, 3] {
                     self.key_modifiers.push(KeyModifiers::Option);
                 } else if *numbers == [1, 2] {
@@ -483,7 +1130,7 @@ impl KeyParser {
                 }
             },
             0x42 => {
-                self.key_events.insert(KeyCode::Down, true);
+                self.press_key(KeyCode::Down);
                 if *numbers == [1, 3] {
                     self.key_modifiers.push(KeyModifiers::Option);
                 } else if *numbers == [1, 2] {
@@ -502,16 +1149,84 @@ impl KeyParser {
 /// Enables mouse capture in the terminal by sending the appropriate escape codes.
 pub fn enable_mouse_capture() {
     let mut stdout = std::io::stdout();
-    let _ = stdout.write_all(b"echo -e \"\x1B[?1006h\"");
+    let _ = stdout.write_all(b"\x1B[?1006h"); // Report events in the SGR encoding (no 223 column limit)
     let _ = stdout.write_all(b"\x1B[?1000h"); // Enable basic mouse mode
     let _ = stdout.write_all(b"\x1B[?1003h"); // Enable all motion events
 }
 
+// whether the kitty keyboard protocol was requested (so it's only popped if it was pushed)
+static ENHANCED_KEYBOARD: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+/// Opts into the kitty keyboard protocol (progressive enhancement), which lets the terminal report
+/// key repeats and releases (see `KeyParser::key_event_kind`). Terminals which don't support the
+/// protocol ignore the request, and input continues to be parsed as normal.
+/// This is disabled again when the renderer shuts down.
+pub fn enable_enhanced_keyboard() {
+    let mut stdout = std::io::stdout();
+    // 1 = disambiguate escape codes, 2 = report event types
+    let _ = stdout.write_all(b"\x1B[>3u");
+    let _ = stdout.flush();
+    ENHANCED_KEYBOARD.store(true, std::sync::atomic::Ordering::Relaxed);
+}
+
+/// Returns whether the kitty keyboard protocol was requested through `enable_enhanced_keyboard`.
+pub fn is_enhanced_keyboard_enabled() -> bool {
+    ENHANCED_KEYBOARD.load(std::sync::atomic::Ordering::Relaxed)
+}
+
+/// Pops the kitty keyboard protocol flags pushed by `enable_enhanced_keyboard` (does nothing if it wasn't enabled).
+pub fn disable_enhanced_keyboard() {
+    if !ENHANCED_KEYBOARD.swap(false, std::sync::atomic::Ordering::Relaxed) {  return;  }
+    let mut stdout = std::io::stdout();
+    let _ = stdout.write_all(b"\x1B[<u");
+    let _ = stdout.flush();
+}
+
+// the console modes of the input and output from before `enable_virtual_terminal` (so they can be restored)
+#[cfg(windows)]
+static CONSOLE_MODES: parking_lot::Mutex<Option<(u32, u32)>> = parking_lot::const_mutex(None);
+
+/// Switches the Windows console into virtual terminal mode, so keys and mouse events arrive as the same
+/// escape sequences unix terminals send (which `KeyParser` already handles) and escape codes written to
+/// stdout are interpreted. Quick edit is also disabled, so clicks go to the app rather than selecting text.
+/// This should be called after entering raw mode. The previous modes are kept for `disable_virtual_terminal`.
+#[cfg(windows)]
+pub fn enable_virtual_terminal() -> std::io::Result<()> {
+    use crossterm_winapi::{ConsoleMode, Handle};
+    const ENABLE_PROCESSED_OUTPUT: u32 = 0x0001;
+    const ENABLE_VIRTUAL_TERMINAL_PROCESSING: u32 = 0x0004;
+    const ENABLE_QUICK_EDIT_MODE: u32 = 0x0040;
+    const ENABLE_EXTENDED_FLAGS: u32 = 0x0080;
+    const ENABLE_VIRTUAL_TERMINAL_INPUT: u32 = 0x0200;
+
+    let input = ConsoleMode::from(Handle::current_in_handle()?);
+    let output = ConsoleMode::from(Handle::current_out_handle()?);
+    let modes = (input.mode()?, output.mode()?);
+    // the modes from the first call are the original ones (i.e. when resuming after a suspend)
+    CONSOLE_MODES.lock().get_or_insert(modes);
+    input.set_mode((modes.0 | ENABLE_VIRTUAL_TERMINAL_INPUT | ENABLE_EXTENDED_FLAGS) & !ENABLE_QUICK_EDIT_MODE)?;
+    output.set_mode(modes.1 | ENABLE_PROCESSED_OUTPUT | ENABLE_VIRTUAL_TERMINAL_PROCESSING)
+}
+
+/// Restores the console modes from before `enable_virtual_terminal` (does nothing if it wasn't enabled).
+#[cfg(windows)]
+pub fn disable_virtual_terminal() {
+    use crossterm_winapi::{ConsoleMode, Handle};
+    let Some((input, output)) = CONSOLE_MODES.lock().take() else {  return;  };
+    if let Ok(handle) = Handle::current_in_handle() {
+        let _ = ConsoleMode::from(handle).set_mode(input);
+    }
+    if let Ok(handle) = Handle::current_out_handle() {
+        let _ = ConsoleMode::from(handle).set_mode(output);
+    }
+}
+
 /// Disables mouse capture in the terminal by sending the appropriate escape codes.
 pub fn disable_mouse_capture() {
     let mut stdout = std::io::stdout();
     let _ = stdout.write_all(b"\x1B[?1000l"); // Disable mouse mode
     let _ = stdout.write_all(b"\x1B[?1003l"); // Disable motion events
+    let _ = stdout.write_all(b"\x1B[?1006l"); // Disable the SGR encoding
 }
 
 impl KeyParser {
@@ -523,6 +1238,95 @@ impl KeyParser {
             .expect("Time went backwards...")
             .as_millis();
     }
+
+    /// Parses a single read of terminal input. Alt (or Option) + a character arrives as an escape followed by
+    /// the character, so an escape is only the escape key when nothing follows it within the same read, or when
+    /// it's doubled up (escape + escape). Alt + `P`, `X`, `^`, `_` and `]` would otherwise be read as the start
+    /// of a DCS, SOS, PM, APC or OSC string (swallowing everything typed after it), so those pairs are handled
+    /// here too; an escape and `]` followed by a digit is left as an OSC reply (such as a clipboard paste).
+    /// # Example
+    /// ```
+    /// use term_render::event_handler::{InputEvent, KeyCode, KeyModifiers, KeyParser};
+    /// let mut parser = vte::Parser::new();
+    /// let mut events = KeyParser::new();
+    /// // alt + a
+    /// events.parse(&mut parser, b"\x1ba");
+    /// assert_eq!(events.peek_events(), &[InputEvent::Char('a', vec![KeyModifiers::Option])]);
+    /// assert!(!events.contains_key_code(KeyCode::Escape));
+    ///
+    /// // escape on its own
+    /// events.clear_events();
+    /// events.parse(&mut parser, b"\x1b");
+    /// assert_eq!(events.peek_events(), &[InputEvent::Key(KeyCode::Escape, vec![])]);
+    ///
+    /// // escape and then a, quickly but in separate reads, are two separate keys
+    /// events.clear_events();
+    /// events.parse(&mut parser, b"\x1b");
+    /// events.parse(&mut parser, b"a");
+    /// assert_eq!(events.peek_events(), &[InputEvent::Key(KeyCode::Escape, vec![]), InputEvent::Char('a', vec![])]);
+    ///
+    /// // a doubled escape is a single escape, and escape sequences are still keys
+    /// events.clear_events();
+    /// events.parse(&mut parser, b"\x1b\x1b");
+    /// events.parse(&mut parser, b"\x1b[A");
+    /// assert_eq!(events.peek_events(), &[InputEvent::Key(KeyCode::Escape, vec![]), InputEvent::Key(KeyCode::Up, vec![])]);
+    ///
+    /// // alt + the characters which introduce terminal strings, and typing afterwards isn't swallowed
+    /// for chr in ['P', 'X', '^', '_', ']'] {
+    ///     events.clear_events();
+    ///     events.parse(&mut parser, format!("\x1b{}", chr).as_bytes());
+    ///     events.parse(&mut parser, b"x");
+    ///     assert_eq!(events.peek_events(), &[InputEvent::Char(chr, vec![KeyModifiers::Option]), InputEvent::Char('x', vec![])]);
+    /// }
+    ///
+    /// // `ESC O` starts an SS3 key when its key follows in the same read, but alone it's alt + O
+    /// events.clear_events();
+    /// events.parse(&mut parser, b"\x1bOP");
+    /// assert_eq!(events.peek_events(), &[InputEvent::Key(KeyCode::F1, vec![])]);
+    /// events.clear_events();
+    /// events.parse(&mut parser, b"\x1bO");
+    /// events.parse(&mut parser, b"P");
+    /// assert_eq!(events.peek_events(), &[InputEvent::Char('O', vec![KeyModifiers::Option]), InputEvent::Char('P', vec![])]);
+    /// ```
+    pub fn parse (&mut self, parser: &mut vte::Parser, bytes: &[u8]) {
+        self.bytes = bytes.len();
+        let mut rest = bytes;
+        while let Some(index) = Self::find_split_escape(rest) {
+            parser.advance(self, &rest[..index]);
+            self.set_press_time();
+            match rest[index + 1] {
+                0x1B => self.press_key(KeyCode::Escape),
+                byte => self.esc_dispatch(&[], false, byte),
+            }
+            rest = &rest[index + 2..];
+        }
+        // a trailing escape isn't handed to the parser, which would wait for the rest of a sequence
+        match rest.split_last() {
+            Some((0x1B, before)) => {
+                parser.advance(self, before);
+                self.set_press_time();
+                self.press_key(KeyCode::Escape);
+            },
+            _ => parser.advance(self, rest),
+        }
+        // SS3 arrives in the same read as its key, so an `ESC O` ending the read is Alt + O
+        if self.ss3_pending && rest.ends_with(&[0x1B, b'O']) {
+            self.ss3_pending = false;
+            self.set_press_time();
+            self.push_alt_char('O');
+        }
+    }
+
+    /// Finds the next escape which `parse` handles itself rather than handing to vte: a doubled escape,
+    /// or Alt + a character which vte would take as the start of a terminal string.
+    fn find_split_escape (bytes: &[u8]) -> Option <usize> {
+        (0..bytes.len().saturating_sub(1)).find(|&index| bytes[index] == 0x1B && match bytes[index + 1] {
+            0x1B | b'P' | b'X' | b'^' | b'_' => true,
+            // OSC replies start with their number (e.g. `^[]52;c;...` for a paste)
+            b']' => !bytes.get(index + 2).is_some_and(u8::is_ascii_digit),
+            _ => false,
+        })
+    }
 }
 
 impl Perform for KeyParser {
@@ -533,13 +1337,37 @@ impl Perform for KeyParser {
     #[inline(always)]
     fn print(&mut self, chr: char) {
         //println!("char {}: '{}'", chr as u8, chr);
-        if self.in_escape_seq || self.bytes > 1 {
-            match chr as u8 {
-                17 => {
-                    self.char_events.push('w');
-                    self.key_modifiers.push(KeyModifiers::Option);
-                },
-                _ => {}
+        if self.pasting {
+            self.paste_buffer.push(chr);
+            return;
+        }
+        if self.ss3_pending {
+            self.ss3_pending = false;
+            self.set_press_time();
+            let key = match chr {
+                'P' => Some(KeyCode::F1),
+                'Q' => Some(KeyCode::F2),
+                'R' => Some(KeyCode::F3),
+                'S' => Some(KeyCode::F4),
+                'H' => Some(KeyCode::Home),
+                'F' => Some(KeyCode::End),
+                'A' => Some(KeyCode::Up),
+                'B' => Some(KeyCode::Down),
+                'C' => Some(KeyCode::Right),
+                'D' => Some(KeyCode::Left),
+                _ => None,
+            };
+            if let Some(key) = key {  self.press_key(key);  }
+            return;
+        }
+        // multi-byte reads are normally escape codes, but committed text from an input
+        // method (or any other non-ascii characters) arrive as a single multi-byte read
+        if self.in_escape_seq || (self.bytes > 1 && chr.is_ascii()) {
+            if chr as u8 == 17 {
+                let mark = self.mark_events();
+                self.push_char('w');
+                self.key_modifiers.push(KeyModifiers::Option);
+                self.attach_modifiers(mark);
             }
 
             return;
@@ -547,12 +1375,14 @@ impl Perform for KeyParser {
         self.set_press_time();
 
         if chr as u8 == 0x7F {
-            self.key_events.insert(KeyCode::Delete, true);
+            self.press_key(KeyCode::Delete);
             return;
         }
-        if !(chr.is_ascii_graphic() || chr.is_whitespace()) {  return;  }
+        if chr.is_control() || !(chr.is_ascii_graphic() || chr.is_whitespace() || !chr.is_ascii()) {  return;  }
         //println!("char {}: '{}'", chr as u8, chr);
-        self.char_events.push(chr);
+        // committed text ends the composition (the preedit is replaced rather than inserted twice)
+        self.composition = None;
+        self.push_char(chr);
     }
 
     /// Handles a control character input.
@@ -563,6 +1393,17 @@ impl Perform for KeyParser {
     fn execute(&mut self, byte: u8) {
         self.set_press_time();
 
+        // control characters within a paste are part of the text (not key presses)
+        if self.pasting {
+            match byte {
+                0x0D => self.paste_buffer.push('\r'),
+                0x0A => self.paste_buffer.push('\n'),
+                0x09 => self.paste_buffer.push('\t'),
+                _ => {},
+            }
+            return;
+        }
+
         // control + ...
         // 3 = c; 22 = v; 26 = z; 6 = f; 1 = a; 24 = x; 19 = s; 21 = u; r = 18
         // left ^[[1;5D right ^[[1;5C up ^[[1;5A down ^[[1;5B
@@ -570,96 +1411,204 @@ impl Perform for KeyParser {
         // control + key and control + shift + key don't send unique
         // escape codes for some odd reason
 
+        let mark = self.mark_events();
         match byte {
             0x1B => {
                 self.in_escape_seq = true;
             },
             0x0D => {  // return aka \n
-                self.key_events.insert(KeyCode::Return, true);
+                self.press_key(KeyCode::Return);
             },
             0x09 => {
-                self.key_events.insert(KeyCode::Tab, true);
+                self.press_key(KeyCode::Tab);
             },// 3 = c; 22 = v; 26 = z; 6 = f; 1 = a; 24 = x; 19 = s; 21 = u; r = 18
             3 => {
                 self.key_modifiers.push(KeyModifiers::Control);
-                self.char_events.push('c');
+                self.push_char('c');
             },
             22 => {
                 self.key_modifiers.push(KeyModifiers::Control);
-                self.char_events.push('v');
+                self.push_char('v');
             },
             26 => {
                 self.key_modifiers.push(KeyModifiers::Control);
-                self.char_events.push('z');
+                self.push_char('z');
             },
             6 => {
                 self.key_modifiers.push(KeyModifiers::Control);
-                self.char_events.push('f');
+                self.push_char('f');
             },
             1 => {
                 self.key_modifiers.push(KeyModifiers::Control);
-                self.char_events.push('a');
+                self.push_char('a');
             },
             24 => {
                 self.key_modifiers.push(KeyModifiers::Control);
-                self.char_events.push('x');
+                self.push_char('x');
             },
             19 => {
                 self.key_modifiers.push(KeyModifiers::Control);
-                self.char_events.push('s');
+                self.push_char('s');
             },
             21 => {
                 self.key_modifiers.push(KeyModifiers::Control);
-                self.char_events.push('u');
+                self.push_char('u');
             },
             18 => {
                 self.key_modifiers.push(KeyModifiers::Control);
-                self.char_events.push('r');
+                self.push_char('r');
             },
             0x08 => {
                 self.key_modifiers.push(KeyModifiers::Control);
-                self.key_events.insert(KeyCode::Delete, true);
+                self.press_key(KeyCode::Delete);
             },
             10 => {
-                self.char_events.push('a');
+                self.push_char('a');
                 self.key_modifiers.push(KeyModifiers::Control);
             },
             _ => {},
         }
+        self.attach_modifiers(mark);
         //println!("byte {}: '{}'", byte, byte as char);
     }
 
+    /// Handles an OSC (Operating System Command) sequence.
+    /// The only one currently used is the terminal's answer to a clipboard request (OSC 52), which
+    /// carries the clipboard's contents as base64 (see `clipboard_content`).
+    fn osc_dispatch(&mut self, params: &[&[u8]], _bell_terminated: bool) {
+        self.in_escape_seq = false;
+        if self.pasting {  return;  }
+        // 52 ; selection ; data (a `?` is a request, which shouldn't be echoed back, but just in case)
+        if let [b"52", _, data] = params && *data != b"?" &&
+            let Some(bytes) = crate::render_clipboard::base64_decode(data)
+        {
+            self.clipboard_content = Some(String::from_utf8_lossy(&bytes).into_owned());
+        }
+    }
+
+    /// Handles an escape sequence which isn't a CSI sequence.
+    /// `ESC O` (SS3) prefixes F1-F4 (and the arrows/home/end in application cursor mode); the key itself
+    /// arrives as the following character (in the same read, otherwise it was Alt + O). Any other character
+    /// after an escape is that character with Alt/Option held (see `parse`).
+    #[inline(always)]
+    fn esc_dispatch(&mut self, intermediates: &[u8], _ignore: bool, byte: u8) {
+        self.in_escape_seq = false;
+        if self.pasting || !intermediates.is_empty() {  return;  }
+        match byte {
+            b'O' => self.ss3_pending = true,
+            0x21..=0x7E => {
+                self.set_press_time();
+                self.push_alt_char(byte as char);
+            },
+            _ => {},
+        }
+    }
+
     /// Handles a CSI (Control Sequence Introducer) escape sequence.
     /// This method processes the parameters and final character of the escape sequence,
     /// updating the parser state accordingly. It supports mouse events, custom escape codes,
     /// control + arrow keys, and standard escape codes.
     /// The method resets the escape sequence flag and updates the last key press time.
     #[inline(always)]
-    fn csi_dispatch(&mut self, params: &vte::Params, _: &[u8], _: bool, c: char) {
+    fn csi_dispatch(&mut self, params: &vte::Params, intermediates: &[u8], _: bool, c: char) {
         self.in_escape_seq = false;  // resetting the escape sequence
-        self.set_press_time();
 
         let numbers: Vec <u16> = params.iter().map(|p| p[0]).collect();
 
+        // a mode report (DECRPM, `^[[?2026;1$y`) answering a query rather than a key press
+        if c == 'y' && intermediates.contains(&b'$') && !self.pasting {
+            if numbers.first() == Some(&2026) {
+                // 1 and 2 are set and reset, 3 is permanently set (0 isn't recognized, and 4 is permanently reset)
+                self.synchronized_output_support = Some(matches!(numbers.get(1), Some(1..=3)));
+            }
+            return;
+        }
+        self.set_press_time();
+
+        // bracketed paste; everything between the start and end markers is text
+        if c == '~' && numbers.first() == Some(&200) {
+            self.pasting = true;
+            self.paste_buffer.clear();
+            return;
+        }
+        if c == '~' && numbers.first() == Some(&201) {
+            self.pasting = false;
+            // normalizing the line endings (terminals usually send carriage returns)
+            let text = std::mem::take(&mut self.paste_buffer).replace("\r\n", "\n").replace('\r', "\n");
+            // multiple pastes in one frame are joined
+            self.events.push(InputEvent::Paste(text.clone()));
+            match &mut self.paste_event {
+                Some(paste) => paste.push_str(&text),
+                None => self.paste_event = Some(text),
+            }
+            return;
+        }
+        if self.pasting {  return;  }
+
         // mouse handling
         if c == 'M' || c == 'm' {
-            self.handle_mouse_escape_codes(&numbers, c);
+            self.handle_mouse_escape_codes(&numbers, intermediates, c);
+            return;
+        }
+
+        // the kitty protocol attaches the event type as a sub-parameter of the modifiers (press if absent)
+        let sub_params: Vec <Vec <u16>> = params.iter().map(|p| p.to_vec()).collect();
+        let kind = match sub_params.get(1).and_then(|param| param.get(1)) {
+            Some(2) => KeyEventKind::Repeat,
+            Some(3) => KeyEventKind::Release,
+            _ => KeyEventKind::Press,
+        };
+        let mark = self.mark_events();
+        if c == 'u' {
+            self.handle_kitty_key(&sub_params, kind);
+            self.attach_modifiers(mark);
             return;
         }
+        // a release shouldn't show up as the key being pressed
+        let previous_keys = self.key_events.clone();
+        let (previous_events, previous_modifiers) = mark;
+        self.dispatch_key_escape_codes(&numbers, c);
+        if kind == KeyEventKind::Press && sub_params.get(1).is_none_or(|param| param.len() < 2) {
+            self.attach_modifiers(mark);
+            return;
+        }
+        let dispatched: Vec <KeyCode> = self.events[previous_events..].iter()
+            .filter_map(|event| match event {
+                InputEvent::Key(key, _) => Some(*key),
+                _ => None,
+            })
+            .collect();
+        for key in dispatched {
+            self.key_event_kinds.insert(key, kind);
+            // a key pressed earlier in the frame still counts as pressed
+            if kind == KeyEventKind::Release && !previous_keys.get(&key).copied().unwrap_or(false) {
+                self.key_events.insert(key, false);
+            }
+        }
+        if kind == KeyEventKind::Release {
+            self.key_modifiers.truncate(previous_modifiers);
+            self.events.truncate(previous_events);
+        }
+        self.attach_modifiers(mark);
+    }
+}
 
+impl KeyParser {
+    // dispatches the non-mouse and non-kitty escape codes
+    fn dispatch_key_escape_codes (&mut self, numbers: &Vec <u16>, c: char) {
         //for number in &numbers {println!("{}", number);}
         if c == '~' && numbers.len() == 2 && numbers[0] == 3 {  // this section is for custom escape codes
-            self.handle_custom_escape_codes(&numbers);
-        } else if numbers.len() == 2 && numbers[0] == 1 && numbers[1] == 5 {
+            self.handle_custom_escape_codes(numbers);
+        } else if numbers.len() == 2 && numbers[0] == 1 && numbers[1] == 5 && matches!(c, 'A'..='D') {
             // control + ...
             // 3 = c; 22 = v; 26 = z; 6 = f; 1 = a; 24 = x; 19 = s; 21 = u; r = 18
             // left ^[[1;5D right ^[[1;5C up ^[[1;5A down ^[[1;5B
             // control u and control r and necessary for undo and redo bc/
             // control + key and control + shift + key don't send unique
             // escape codes for some odd reason
-            self.handle_control_arrows(&numbers, c);
+            self.handle_control_arrows(numbers, c);
         } else {  // this checks existing escape codes of 1 parameter/ending code (they don't end with ~)
-            self.handle_standard_escape_codes(&numbers, c);
+            self.handle_standard_escape_codes(numbers, c);
         }
     }
 }
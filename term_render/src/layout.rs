@@ -0,0 +1,305 @@
+use crate::render::Rect;
+use crate::widget::Region;
+use crate::widget_impls::SizeAndPosition;
+
+/// A function which computes a region (size, position) from the terminal's current area.
+/// These are produced by `Layout::region` and `Grid::region`, and can be used to nest layouts
+/// (see `Layout::within`) or to position widgets (see `SizeAndPosition::new_computed`).
+pub type RegionFunction = std::rc::Rc<dyn Fn(&Rect) -> Region>;
+
+/// A constraint on the length of a single cell along a layout's direction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Constraint {
+    /// A fixed number of cells.
+    Length(u16),
+    /// A percentage of the available space in the range [0, 1].
+    /// Percentages are shrunk first when there isn't enough space for every constraint.
+    Percent(f32),
+    /// At least the given number of cells, growing to share any leftover space (like `Fill`).
+    /// The minimum wins over percentages when the terminal is too small for both.
+    Min(u16),
+    /// Whatever space is left over, split evenly between all `Fill` and `Min` constraints.
+    Fill,
+}
+
+/// The direction a layout splits its area in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// Cells are placed left to right.
+    Horizontal,
+    /// Cells are placed top to bottom.
+    Vertical,
+}
+
+/// Splits an area into cells along a single direction based on a list of constraints.
+/// Layouts can be nested by splitting one of the resulting regions again (see `within`), and the
+/// cells can be handed directly to widget builders through `cell` (i.e. `.with_sap(layout.cell(0))`),
+/// in which case they're recomputed from the terminal's area every frame.
+/// # Example:
+/// ```
+/// use term_render::layout::{Constraint, Layout};
+/// use term_render::render::Rect;
+/// let layout = Layout::vertical([Constraint::Length(3), Constraint::Fill, Constraint::Length(1)]);
+/// let regions = layout.split(&Rect { width: 80, height: 24 });
+/// // regions are (size, position), and positions start at (0, 0) in the top left corner
+/// assert_eq!(regions, vec![((80, 3), (0, 0)), ((80, 20), (0, 3)), ((80, 1), (0, 23))]);
+/// ```
+#[derive(Clone)]
+pub struct Layout {
+    direction: Direction,
+    constraints: Vec<Constraint>,
+    margin: u16,
+    gap: u16,
+    parent: Option<RegionFunction>,
+}
+
+impl Layout {
+    /// Creates a layout splitting its area in the given direction.
+    pub fn new(direction: Direction, constraints: impl Into<Vec<Constraint>>) -> Self {
+        Layout {
+            direction,
+            constraints: constraints.into(),
+            margin: 0,
+            gap: 0,
+            parent: None,
+        }
+    }
+
+    /// Creates a layout which stacks its cells top to bottom.
+    pub fn vertical(constraints: impl Into<Vec<Constraint>>) -> Self {
+        Self::new(Direction::Vertical, constraints)
+    }
+
+    /// Creates a layout which places its cells left to right.
+    pub fn horizontal(constraints: impl Into<Vec<Constraint>>) -> Self {
+        Self::new(Direction::Horizontal, constraints)
+    }
+
+    /// Sets the number of cells left empty around every side of the area before it's split.
+    pub fn with_margin(mut self, margin: u16) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Sets the number of cells left empty between neighbouring cells.
+    pub fn with_gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Makes the layout split a region computed from the terminal's area (i.e. a cell of another
+    /// layout through `Layout::region`) rather than the whole terminal.
+    /// # Example:
+    /// ```
+    /// use term_render::layout::{Constraint, Layout};
+    /// use term_render::render::Rect;
+    /// let rows = Layout::vertical([Constraint::Fill, Constraint::Length(1)]);
+    /// let columns = Layout::horizontal([Constraint::Length(20), Constraint::Fill]).within(rows.region(0));
+    /// let regions = columns.split(&Rect { width: 80, height: 24 });
+    /// assert_eq!(regions, vec![((20, 23), (0, 0)), ((60, 23), (20, 0))]);
+    /// ```
+    pub fn within(mut self, parent: RegionFunction) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    /// Splits the terminal's area (or the parent region, see `within`) into one region (size, position)
+    /// per constraint.
+    pub fn split(&self, area: &Rect) -> Vec<Region> {
+        let region = match &self.parent {
+            Some(parent) => parent(area),
+            None => ((area.width, area.height), (0, 0)),
+        };
+        self.split_region(region)
+    }
+
+    /// Splits the given region (size, position) into one region per constraint.
+    pub fn split_region(&self, region: Region) -> Vec<Region> {
+        let ((width, height), (x, y)) = shrink_region(region, self.margin);
+        let available = match self.direction {
+            Direction::Horizontal => width,
+            Direction::Vertical => height,
+        };
+        let mut offset = 0u16;
+        Self::resolve(&self.constraints, available, self.gap).into_iter().map(|length| {
+            let cell = match self.direction {
+                Direction::Horizontal => ((length, height), (x.saturating_add(offset), y)),
+                Direction::Vertical => ((width, length), (x, y.saturating_add(offset))),
+            };
+            offset = offset.saturating_add(length).saturating_add(self.gap);
+            cell
+        }).collect()
+    }
+
+    /// Returns a function computing the region of the cell at the given index from the terminal's area.
+    /// An index without a matching constraint produces an empty region.
+    pub fn region(&self, index: usize) -> RegionFunction {
+        let layout = self.clone();
+        std::rc::Rc::new(move |area| layout.split(area).get(index).copied().unwrap_or(((0, 0), (0, 0))))
+    }
+
+    /// Creates a `SizeAndPosition` which follows the cell at the given index as the terminal is resized.
+    /// This can be passed directly to a widget builder's `with_sap`.
+    pub fn cell(&self, index: usize) -> SizeAndPosition {
+        SizeAndPosition::new_computed(self.region(index))
+    }
+
+    /// Resolves the constraints into lengths for the given available space, leaving `gap` cells between
+    /// each of them. Lengths and minimums are given their space first, then percentages (which are
+    /// shrunk if they don't fit), and finally any leftover space is split between `Fill` and `Min`.
+    /// If the fixed lengths alone don't fit, the later ones are cut short.
+    /// # Example:
+    /// ```
+    /// use term_render::layout::{Constraint, Layout};
+    /// let constraints = [Constraint::Percent(0.5), Constraint::Min(30)];
+    /// assert_eq!(Layout::resolve(&constraints, 100, 0), vec![50, 50]);
+    /// // on a tiny terminal, the minimum wins over the percentage
+    /// assert_eq!(Layout::resolve(&constraints, 40, 0), vec![10, 30]);
+    /// ```
+    pub fn resolve(constraints: &[Constraint], available: u16, gap: u16) -> Vec<u16> {
+        let gaps = gap.saturating_mul(constraints.len().saturating_sub(1) as u16);
+        let space = available.saturating_sub(gaps);
+
+        // the fixed lengths and minimums come first
+        let mut remaining = space;
+        let mut lengths: Vec<u16> = constraints.iter().map(|constraint| match constraint {
+            Constraint::Length(length) | Constraint::Min(length) => {
+                let length = (*length).min(remaining);
+                remaining -= length;
+                length
+            },
+            Constraint::Percent(_) | Constraint::Fill => 0,
+        }).collect();
+
+        // then the percentages, scaled down to whatever is left if they're too large
+        let wanted: Vec<u16> = constraints.iter().map(|constraint| match constraint {
+            Constraint::Percent(percent) => (space as f32 * percent.clamp(0.0, 1.0)).round() as u16,
+            _ => 0,
+        }).collect();
+        let total_wanted: u32 = wanted.iter().map(|length| *length as u32).sum();
+        let scale = match total_wanted > remaining as u32 {
+            true => remaining as f32 / total_wanted as f32,
+            false => 1.0,
+        };
+        let mut percent_total = 0;
+        for (length, wanted) in lengths.iter_mut().zip(&wanted) {
+            if *wanted == 0 {  continue;  }
+            *length = ((*wanted as f32 * scale) as u16).min(remaining - percent_total);
+            percent_total += *length;
+        }
+        remaining -= percent_total;
+
+        // finally, splitting the leftover space between the fills (the first ones get any extra cells)
+        let fills: Vec<usize> = constraints.iter().enumerate()
+            .filter(|(_, constraint)| matches!(constraint, Constraint::Fill | Constraint::Min(_)))
+            .map(|(index, _)| index)
+            .collect();
+        if !fills.is_empty() {
+            let share = remaining / fills.len() as u16;
+            let extra = remaining % fills.len() as u16;
+            for (i, index) in fills.into_iter().enumerate() {
+                lengths[index] += share + (i < extra as usize) as u16;
+            }
+        } lengths
+    }
+}
+
+/// Splits an area into a grid of cells, with one set of constraints for the rows and another for
+/// the columns. Cells are returned in row-major order (every column of the first row, then the second...).
+/// # Example:
+/// ```
+/// use term_render::layout::{Constraint, Grid};
+/// use term_render::render::Rect;
+/// let grid = Grid::new([Constraint::Fill, Constraint::Fill], [Constraint::Length(10), Constraint::Fill])
+///     .with_gap(1);
+/// let cells = grid.split(&Rect { width: 31, height: 9 });
+/// assert_eq!(cells[0], ((10, 4), (0, 0)));
+/// assert_eq!(cells[3], ((20, 4), (11, 5)));
+/// ```
+#[derive(Clone)]
+pub struct Grid {
+    rows: Vec<Constraint>,
+    columns: Vec<Constraint>,
+    margin: u16,
+    gap: u16,
+    parent: Option<RegionFunction>,
+}
+
+impl Grid {
+    /// Creates a grid with the given row and column constraints.
+    pub fn new(rows: impl Into<Vec<Constraint>>, columns: impl Into<Vec<Constraint>>) -> Self {
+        Grid {
+            rows: rows.into(),
+            columns: columns.into(),
+            margin: 0,
+            gap: 0,
+            parent: None,
+        }
+    }
+
+    /// Sets the number of cells left empty around every side of the area before it's split.
+    pub fn with_margin(mut self, margin: u16) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Sets the number of cells left empty between neighbouring rows and columns.
+    pub fn with_gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Makes the grid split a region computed from the terminal's area rather than the whole terminal
+    /// (see `Layout::within`).
+    pub fn within(mut self, parent: RegionFunction) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    /// Splits the terminal's area (or the parent region) into the grid's cells, in row-major order.
+    pub fn split(&self, area: &Rect) -> Vec<Region> {
+        let region = match &self.parent {
+            Some(parent) => parent(area),
+            None => ((area.width, area.height), (0, 0)),
+        };
+        self.split_region(region)
+    }
+
+    /// Splits the given region (size, position) into the grid's cells, in row-major order.
+    pub fn split_region(&self, region: Region) -> Vec<Region> {
+        let columns = Layout::horizontal(self.columns.clone()).with_gap(self.gap);
+        Layout::vertical(self.rows.clone())
+            .with_margin(self.margin)
+            .with_gap(self.gap)
+            .split_region(region)
+            .into_iter()
+            .flat_map(|row| columns.split_region(row))
+            .collect()
+    }
+
+    /// Returns a function computing the region of the cell at the given row and column from the
+    /// terminal's area. A cell outside of the grid produces an empty region.
+    pub fn region(&self, row: usize, column: usize) -> RegionFunction {
+        let grid = self.clone();
+        let index = match column < grid.columns.len() {
+            true => row.saturating_mul(grid.columns.len()).saturating_add(column),
+            false => usize::MAX,
+        };
+        std::rc::Rc::new(move |area| grid.split(area).get(index).copied().unwrap_or(((0, 0), (0, 0))))
+    }
+
+    /// Creates a `SizeAndPosition` which follows the cell at the given row and column as the terminal
+    /// is resized. This can be passed directly to a widget builder's `with_sap`.
+    pub fn cell(&self, row: usize, column: usize) -> SizeAndPosition {
+        SizeAndPosition::new_computed(self.region(row, column))
+    }
+}
+
+/// Removes the margin from every side of a region (size, position).
+fn shrink_region(region: Region, margin: u16) -> Region {
+    let ((width, height), (x, y)) = region;
+    (
+        (width.saturating_sub(margin.saturating_mul(2)), height.saturating_sub(margin.saturating_mul(2))),
+        (x.saturating_add(margin), y.saturating_add(margin)),
+    )
+}
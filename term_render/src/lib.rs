@@ -1,4 +1,3 @@
-use std::io::Read;
 /// Handling terminal events (key presses, mouse events, etc.)
 /// This module provides functionality to parse and manage terminal input events.
 /// The actual polling of events is handled externally.
@@ -16,15 +15,60 @@ pub mod widget;
 /// This module provides ready-to-use widget implementations and builders for common UI components.
 /// It simplifies the process of creating and configuring widgets by providing default behaviors and properties.
 pub mod widget_impls;
+/// Declarative layouts (splitting the terminal into rows, columns, and grids of cells)
+/// This module computes sizes and positions from constraints, which widgets can follow as the terminal is resized.
+/// Layouts can be nested by splitting the cells of other layouts.
+pub mod layout;
+mod app_exit;
+mod app_hooks;
+mod app_input;
+mod app_keymap;
+mod app_messages;
+mod app_notifications;
+mod app_timers;
+mod render_ansi;
+mod render_capabilities;
+mod render_clipboard;
+mod render_error;
+mod render_frame;
+#[cfg(feature = "images")]
+mod render_image;
+mod render_markup;
+mod render_pool;
+mod render_resize;
+mod render_theme;
+mod scene_layout;
+mod scene_tooltip;
+mod widget_animation;
 mod widget_button;
+mod widget_canvas;
+mod widget_checkbox;
+mod widget_context_menu;
+mod widget_dialog;
 mod widget_dynamic;
+mod widget_form;
+#[cfg(feature = "images")]
+mod widget_image;
+mod widget_list;
+mod widget_log;
+mod widget_radio;
+mod widget_scroll_view;
+mod widget_separator;
 mod widget_static;
 mod widget_static_text;
+mod widget_status_bar;
+mod widget_tab;
+mod widget_table;
 mod widget_typing;
 
 use crate::event_handler::KeyModifiers;
 pub use term_render_macros::*;  // re-exporting the macros for easier use
 pub use render::Colorize;  // making sure the colorize trait is included
+pub use app_timers::{TimerCallback, TimerId};
+pub use app_exit::{CtrlC, ExitCallback, CTRL_C_REPEAT_EXIT, CTRL_C_REPEAT_WINDOW, SIGINT_EXIT_CODE};
+pub use app_hooks::{HookCallback, HookId};
+pub use app_keymap::{ChordKey, KeyChord, KeyHandler, KeyMap};
+pub use app_notifications::{Corner, NotificationId, NotificationManager, DEFAULT_NOTIFICATION_TIMEOUT};
 
 // writing this out gets really verbose really quickly
 
@@ -36,30 +80,88 @@ pub use render::Colorize;  // making sure the colorize trait is included
 /// This is also paired with the `send_sync!` macro to simplify the creation of such types.
 pub type SendSync<T> = std::sync::Arc<parking_lot::RwLock<T>>;
 
+/// How long the event thread waits for input before checking if the app is exiting.
+const INPUT_POLL_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(10);
+/// How long shutting down waits for the event thread to stop before leaving it behind.
+const EVENT_THREAD_SHUTDOWN_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// Where the event thread reads its input from, and where it's recorded to (see `App::record_input`).
+struct EventInput {
+    recorder: Option<app_input::InputRecorder>,
+    replay: Option<app_input::InputReplay>,
+}
+
 /// Represents an internal error within the App struct/workings of lib.rs.
 /// This is used to propagate errors from background tasks (rendering and event handling)
 /// back to the main application loop, allowing for graceful shutdown and error reporting.
 /// This error type is distinct from user-defined errors that may arise from the callback function.
 #[derive(Debug)]
-pub struct AppErr {
-    details: String,
+pub enum AppError {
+    /// The update callback returned an error (formatted with its `Debug` implementation).
+    Callback(String),
+    /// Updating the scene's widgets failed.
+    Widget(widget::WidgetError),
+    /// The renderer failed (such as the render sync channel closing).
+    Render(render::RenderError),
+    /// Reading the input failed.
+    Input(std::io::Error),
+    /// Resuming after suspending the process failed.
+    Suspend(std::io::Error),
+    /// The rendering task failed to finish (such as by panicking).
+    RenderTask(tokio::task::JoinError),
+    /// Any other error, described by the message (see `AppError::new`).
+    Other(String),
 }
 
-impl std::fmt::Display for AppErr {
+impl std::fmt::Display for AppError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "AppErr: {}", self.details)
+        match self {
+            AppError::Callback(details) => write!(f, "error in the update callback: {}", details),
+            AppError::Widget(error) => write!(f, "failed to update the scene's widgets: {}", error),
+            AppError::Render(error) => write!(f, "{}", error),
+            AppError::Input(error) => write!(f, "failed to read the input: {}", error),
+            AppError::Suspend(error) => write!(f, "failed to resume after suspending: {}", error),
+            AppError::RenderTask(error) => write!(f, "error in the rendering task: {}", error),
+            AppError::Other(details) => write!(f, "{}", details),
+        }
+    }
+}
+
+impl std::error::Error for AppError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AppError::Widget(error) => Some(error),
+            AppError::Render(error) => Some(error),
+            AppError::Input(error) | AppError::Suspend(error) => Some(error),
+            AppError::RenderTask(error) => Some(error),
+            AppError::Callback(_) | AppError::Other(_) => None,
+        }
+    }
+}
+
+impl From<widget::WidgetError> for AppError {
+    fn from(error: widget::WidgetError) -> Self {
+        AppError::Widget(error)
     }
 }
 
-impl AppErr {
-    /// Create a new AppErr with the given details.
-    /// The internal details are very limited and as such the creator
-    /// should pre-format the details to be as informative as possible.
+impl From<render::RenderError> for AppError {
+    fn from(error: render::RenderError) -> Self {
+        AppError::Render(error)
+    }
+}
+
+impl AppError {
+    /// Create a new `AppError::Other` with the given details.
+    /// The creator should pre-format the details to be as informative as possible.
     pub fn new(details: &str) -> Self {
-        AppErr { details: details.to_string() }
+        AppError::Other(details.to_string())
     }
 }
 
+/// The old name of `AppError`, kept so existing code continues to compile.
+pub type AppErr = AppError;
+
 /// The main application struct that combines rendering and event handling.
 /// This will handle the background work, leaving the user to focus on the application logic.
 /// The generic parameter C represents the application data type, which can be any type defined by the user.
@@ -91,23 +193,286 @@ pub struct App<C> {
     /// The scene graph is responsible for updating and rendering the widgets based on the current state
     /// and events.
     pub scene: Option<widget::Scene<C>>,
+    /// The target duration of each iteration of the main loop (zero runs as fast as possible).
+    tick_rate: std::time::Duration,
+    /// The measured duration of the last frame, including the time spent waiting for the next tick.
+    last_frame_duration: std::time::Duration,
+    /// The area as of the last frame (used for detecting resizes between frames).
+    previous_area: render::Rect,
+    /// Set while the terminal is handed back (see `suspend_with`), so the event thread stops reading stdin.
+    suspended: SendSync<bool>,
+    /// Whether Ctrl+Z suspends the process (see `set_suspend_on_ctrl_z`).
+    suspend_on_ctrl_z: bool,
+    /// Set when a SIGTSTP arrives (i.e. `kill -TSTP`), so the process is suspended cleanly on the next frame.
+    stop_requested: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    /// What Ctrl+C does while running (see `set_ctrlc_behavior`).
+    ctrlc_behavior: app_exit::CtrlC,
+    /// When Ctrl+C was recently pressed while it doesn't exit (for the repeated press fail-safe).
+    ctrlc_presses: Vec<std::time::Instant>,
+    /// Set when a SIGINT arrives while Ctrl+C doesn't exit, so the app stops cleanly on the next frame.
+    interrupt_requested: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    /// The callback run once the app stops (see `on_exit`).
+    exit_callback: Option<app_exit::ExitCallback<C>>,
+    /// The timers scheduled with `set_timeout` and `set_interval`.
+    timers: app_timers::Timers<C>,
+    /// The notifications shown in the corner of the terminal (see `notify`).
+    notifications: app_notifications::NotificationManager,
+    /// The hooks run at fixed points of every frame (see `add_pre_update_hook`).
+    hooks: app_hooks::Hooks<C>,
+    /// The key bindings run each frame before the callback (see `attach_keymap`).
+    keymap: Option<app_keymap::KeyMap<C>>,
+    /// Set when the keymap is attached or detached, so a handler swapping it stops the dispatch.
+    keymap_swapped: bool,
+    /// The channels for posting messages into the main loop (see `message_sender`).
+    messages: app_messages::Messages,
+    /// Where the input read from stdin is recorded to, if anywhere (see `record_input`).
+    input_recorder: Option<app_input::InputRecorder>,
+    /// The recorded input read in place of stdin, if any (see `replay_input`).
+    input_replay: Option<app_input::InputReplay>,
+    /// Whether stdin is read once a replay finishes, rather than exiting.
+    replay_continues_with_stdin: bool,
 }
 
 impl<C> App<C> {
     /// Create a new instance of the App struct.
     /// This initializes the renderer and event handler.
     pub fn new() -> std::io::Result<Self> {
-        let renderer = send_sync!(render::App::new()?);
-        renderer.write().render(None);
+        let renderer = render::App::new()?;
+        let size = renderer.get_terminal_size()?;
+        Ok(Self::with_renderer(renderer, size))
+    }
+    
+    /// Create an App which draws into `height` rows beneath the cursor rather than taking over the whole screen
+    /// (see `render::App::new_inline`). Widgets are positioned relative to the top of those rows.
+    pub fn new_inline(height: u16) -> std::io::Result<Self> {
+        let mut renderer = render::App::new_inline(height)?;
+        let size = renderer.poll_terminal_size()?;
+        Ok(Self::with_renderer(renderer, size))
+    }
+    
+    /// Create an App whose renderer composites into memory rather than the terminal (see
+    /// `render::App::new_headless`), for testing widgets without a real terminal.
+    /// Rather than calling `run`, tests drive the scene directly: setting events on `events`, calling
+    /// `Scene::update_all_widgets`, and checking the renderer's `snapshot`.
+    /// # Example
+    /// ```
+    /// use term_render::widget_impls::{StaticTextWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::{Colored, Span};
+    /// let mut app = term_render::App::<()>::new_headless(20, 4);
+    /// let mut scene = term_render::widget::Scene::new();
+    /// StaticTextWidgetBuilder::<()>::builder(String::from("text"))
+    ///     .with_border(true)
+    ///     .with_renderer(vec![Span::from_tokens(vec![Colored::new(String::from("Hi"))])])
+    ///     .with_position((0, 0))
+    ///     .with_size((6, 3))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .unwrap();
+    /// scene.update_all_widgets(&mut app, &mut ()).unwrap();
+    /// assert_eq!(app.renderer.write().snapshot(), vec!["┌────┐", "│Hi  │", "└────┘", ""]);
+    /// ```
+    pub fn new_headless(width: u16, height: u16) -> Self {
+        Self::with_renderer(render::App::new_headless(width, height), (width, height))
+    }
+    
+    /// Creates the App around the renderer, with the given terminal size.
+    fn with_renderer(mut renderer: render::App, (width, height): (u16, u16)) -> Self {
+        renderer.render(None);
+        let renderer = send_sync!(renderer);
         let events = send_sync!(event_handler::KeyParser::new());
-        let (width, height) = renderer.read().get_terminal_size()?;
         
-        Ok(Self {
+        Self {
             renderer,
             events,
             area: send_sync!(render::Rect { width, height }),
             exit: send_sync!(false),
             scene: None,
+            tick_rate: std::time::Duration::from_millis(10),
+            last_frame_duration: std::time::Duration::ZERO,
+            previous_area: render::Rect { width, height },
+            suspended: send_sync!(false),
+            suspend_on_ctrl_z: true,
+            stop_requested: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            ctrlc_behavior: app_exit::CtrlC::Exit,
+            ctrlc_presses: Vec::new(),
+            interrupt_requested: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            exit_callback: None,
+            timers: app_timers::Timers::new(),
+            notifications: app_notifications::NotificationManager::new(),
+            hooks: app_hooks::Hooks::new(),
+            keymap: None,
+            keymap_swapped: false,
+            messages: app_messages::Messages::new(),
+            input_recorder: None,
+            input_replay: None,
+            replay_continues_with_stdin: false,
+        }
+    }
+    
+    /// Sets the target number of frames (iterations of the main loop, including the callback
+    /// and scene updates) per second. A value of zero (or less) runs the loop as fast as possible.
+    /// By default, the app runs at 100 fps.
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// # struct AppData;
+    /// # tokio::runtime::Builder::new_multi_thread().worker_threads(4).enable_all().build().unwrap().block_on(async {
+    /// let mut app = term_render::App::<AppData>::new_headless(20, 3);
+    /// app.set_target_fps(50.0);
+    /// assert_eq!(app.get_tick_rate(), Duration::from_millis(20));
+    /// let mut frame_durations = vec![];
+    /// app.run(AppData, |_data, app_instance| {
+    ///     frame_durations.push(app_instance.last_frame_duration());
+    ///     Ok::<bool, ()>(frame_durations.len() == 5)
+    /// }).await.unwrap();
+    /// // every frame (after the first) waited out the rest of its tick
+    /// assert!(frame_durations[1..].iter().all(|duration| *duration >= Duration::from_millis(19)));
+    /// assert!(app.get_fps() <= 52.0);
+    ///
+    /// // and zero runs as fast as possible
+    /// app.set_target_fps(0.0);
+    /// assert_eq!(app.get_tick_rate(), Duration::ZERO);
+    /// # });
+    /// ```
+    pub fn set_target_fps(&mut self, fps: f64) {
+        self.tick_rate = match fps > 0.0 {
+            true => std::time::Duration::from_secs_f64(1.0 / fps),
+            false => std::time::Duration::ZERO,
+        };
+    }
+    
+    /// Sets the target duration of each frame directly. A duration of zero runs the loop as fast as possible.
+    /// After each frame, the loop only waits for whatever time remains, so slow callbacks don't
+    /// add onto the tick rate.
+    pub fn set_tick_rate(&mut self, tick_rate: std::time::Duration) {
+        self.tick_rate = tick_rate;
+    }
+    
+    /// Returns the target duration of each frame.
+    pub fn get_tick_rate(&self) -> std::time::Duration {
+        self.tick_rate
+    }
+    
+    /// Returns how long the last frame actually took (from the start of one frame to the start of
+    /// the next). This is the delta time for animations that shouldn't depend on the frame rate.
+    pub fn last_frame_duration(&self) -> std::time::Duration {
+        self.last_frame_duration
+    }
+    
+    /// Returns the old and new area if the terminal was resized since the previous frame.
+    /// This is only set for the single frame where the change was noticed (widgets can
+    /// check the same thing through `KeyParser::was_resized`).
+    /// # Example
+    /// ```
+    /// use term_render::render::Rect;
+    /// # struct AppData;
+    /// # tokio::runtime::Builder::new_multi_thread().worker_threads(4).enable_all().build().unwrap().block_on(async {
+    /// let mut app = term_render::App::<AppData>::new_headless(20, 3);
+    /// app.renderer.write().set_resize_debounce(std::time::Duration::ZERO);
+    /// let mut frame = 0;
+    /// let mut resizes = vec![];
+    /// app.run(AppData, |_data, app_instance| {
+    ///     frame += 1;
+    ///     if let Some(resize) = app_instance.was_resized() {
+    ///         // widgets see it through the events too
+    ///         assert_eq!(app_instance.events.read().was_resized(), Some(resize.clone()));
+    ///         resizes.push((frame, resize));
+    ///     }
+    ///     if frame == 5 {
+    ///         app_instance.renderer.write().set_headless_size(30, 5);
+    ///     }
+    ///     Ok::<bool, ()>(frame == 20)
+    /// }).await.unwrap();
+    /// // set for the one frame after the size changed, and cleared on the next
+    /// assert_eq!(resizes.len(), 1);
+    /// let (resize_frame, (old, new)) = resizes[0].clone();
+    /// assert!(resize_frame > 5);
+    /// assert_eq!((old, new), (Rect { width: 20, height: 3 }, Rect { width: 30, height: 5 }));
+    /// # });
+    /// ```
+    pub fn was_resized(&self) -> Option<(render::Rect, render::Rect)> {
+        self.events.read().resize_event.clone()
+    }
+    
+    /// Returns the measured frame rate based on the last frame's duration (0 before the first frame).
+    pub fn get_fps(&self) -> f64 {
+        match self.last_frame_duration.is_zero() {
+            true => 0.0,
+            false => 1.0 / self.last_frame_duration.as_secs_f64(),
+        }
+    }
+    
+    /// Returns a copy of the renderer's current theme, for render closures which style their
+    /// content by theme slot (i.e. stored in the app data and used with `Theme::colorize`).
+    pub fn theme(&self) -> render::Theme {
+        self.renderer.read().get_theme().clone()
+    }
+    
+    /// Sets the renderer's theme, recoloring the borders and titles of widgets with a theme slot
+    /// (see `WidgetBuilder::with_theme_slot`) and re-rendering every window.
+    pub fn set_theme(&mut self, theme: render::Theme) {
+        self.renderer.write().set_theme(theme);
+    }
+
+    /// Turns color (and the other text modifiers) on or off, overriding the NO_COLOR environment variable
+    /// (see `render::set_color_enabled`). Color can't be turned on for a dumb terminal.
+    pub fn set_color_enabled(&mut self, enabled: bool) {
+        render::set_color_enabled(enabled && !self.renderer.read().is_dumb());
+    }
+
+    /// Turns reduced motion on or off, stopping blinking and animations (see `render::set_reduced_motion`).
+    pub fn set_reduced_motion(&mut self, reduced: bool) {
+        render::set_reduced_motion(reduced);
+    }
+
+    /// Sets whether Ctrl+Z suspends the process (on unix), as it would for other terminal programs.
+    /// Raw mode stops the terminal from sending the stop signal itself, so the app does it instead,
+    /// handing the terminal back to the shell and taking it again once continued (i.e. with `fg`).
+    /// By default, this is enabled; apps using Ctrl+Z for something else (like undo) can disable it.
+    pub fn set_suspend_on_ctrl_z(&mut self, suspend: bool) {
+        self.suspend_on_ctrl_z = suspend;
+    }
+    
+    /// Temporarily hands the terminal back to run the closure, such as to open `$EDITOR` or run another
+    /// command, then takes it back and redraws every widget. While suspended, nothing is drawn and no
+    /// input is read (so the other program gets all of it).
+    /// Returns the closure's result, or an error if the terminal couldn't be set up again.
+    /// # Example
+    /// ```no_run
+    /// # fn callback(data: &mut (), app: &mut term_render::App<()>) -> Result<bool, ()> {
+    /// if app.events.read().contains_char('e') {
+    ///     let status = app.suspend_with(|| {
+    ///         std::process::Command::new(std::env::var("EDITOR").unwrap_or(String::from("vi"))).status()
+    ///     }).expect("Failed to restore the terminal");
+    /// }
+    /// # Ok(false)
+    /// # }
+    /// ```
+    pub fn suspend_with<F: FnOnce() -> R, R>(&mut self, f: F) -> std::io::Result<R> {
+        *self.suspended.write() = true;
+        // the event thread only checks between its waits for input, so this makes sure it has stopped reading
+        std::thread::sleep(INPUT_POLL_TIMEOUT * 2);
+        self.renderer.write().suspend_terminal();
+        
+        let result = f();
+        
+        let resumed = self.renderer.write().resume_terminal();
+        *self.suspended.write() = false;
+        // anything read before suspending is stale by now
+        self.events.write().clear_events();
+        if let Some(scene) = &mut self.scene {
+            scene.force_update_all_widgets(&mut self.renderer.write());
+        }
+        resumed.map(|_| result)
+    }
+    
+    /// Suspends the process like Ctrl+Z normally would, handing the terminal back to the shell.
+    /// Once the process is continued (i.e. with `fg`), the terminal is taken back and every widget is redrawn.
+    #[cfg(unix)]
+    pub fn suspend_process(&mut self) -> std::io::Result<()> {
+        self.suspend_with(|| {
+            // SIGTSTP is handled by the app while running, so the process is stopped with SIGSTOP (which can't be)
+            // SAFETY: raising a signal has no memory safety requirements
+            unsafe {  libc::raise(libc::SIGSTOP);  }
         })
     }
     
@@ -117,32 +482,126 @@ impl<C> App<C> {
     /// The callback function should return a `Result<bool, T>`, where the bool indicates whether to
     /// exit the application, and T is the error type.
     /// If the callback function returns an error, the application will exit and propagate the error.
-    /// The application will also exit if Ctrl+C is detected (a fail-safe to ensure the application can be stopped).
+    /// By default, the application will also exit if Ctrl+C is detected (a fail-safe to ensure the application can be
+    /// stopped), unless the focused widget has a selection for it to copy (see `set_ctrlc_behavior`).
+    /// Whatever stops the app, the exit callback is run with the data first (see `on_exit`).
+    /// If the callback (or anything else while the app is running) panics, the app shuts down, the terminal
+    /// is restored, and then the panic continues with its message printed where it can be seen.
+    /// Errors are likewise only printed once the terminal is back to normal.
+    /// While running, the panic hook is replaced (the previous one is put back afterwards).
+    /// On unix, SIGTSTP (and Ctrl+Z, see `set_suspend_on_ctrl_z`) suspends the app cleanly (see `suspend_process`).
     /// # Parameters
     /// - data: The application data to be passed to the callback function.
-    /// - update_call_back: The callback function to be called every frame.
+    /// - update_call_back: The callback function to be called every frame. This can be a plain function
+    ///   or a closure capturing (and mutating) its environment.
+    ///
+    /// For callbacks which need to await something each frame, see `run_async`.
+    ///
+    /// The app's scene (if any) is updated every frame after the callback, with the same data, so widgets
+    /// can read and change it too.
     /// # Example
     /// ```
+    /// use term_render::widget_impls::{DynamicWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::{Colored, Span};
+    /// # tokio::runtime::Builder::new_multi_thread().worker_threads(4).enable_all().build().unwrap().block_on(async {
     /// struct Data {
     ///     pub counter: u32,
     ///     // other fields...
     /// }
     /// let data = Data { counter: 0 };
-    /// let mut app = term_render::App::new().unwrap();
+    /// let mut app = term_render::App::<Data>::new_headless(20, 3);  // or App::new() for the real terminal
+    /// // a widget drawing the counter from the application data
+    /// let mut scene = term_render::widget::Scene::new();
+    /// DynamicWidgetBuilder::<Data>::builder(String::from("counter"))
+    ///     .with_renderer(Box::new(|_size, _position, data: &mut Data| {
+    ///         Some(vec![Span::from_tokens(vec![Colored::new(format!("count: {}", data.counter))])])
+    ///     }))
+    ///     .with_position((0, 0))
+    ///     .with_size((20, 1))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .unwrap();
+    /// app.scene = Some(scene);
+    /// let mut shown = String::new();
     /// app.run(data, |data, app_instance| {
     ///     // the application data can be mutated here to track state
     ///     data.counter += 1;
-    ///     // application logic here
-    ///     Ok(false)  // return true to exit the app
+    ///     // the scene was last updated on the previous frame
+    ///     shown = app_instance.renderer.write().snapshot()[0].clone();
+    ///     Ok::<bool, ()>(data.counter == 3)  // return true to exit the app
+    /// }).await.unwrap();
+    /// assert_eq!(shown, "count: 2");
+    /// # });
+    /// ```
+    ///
+    /// A panic in the callback comes back out of `run` with its message, once the app has shut down:
+    /// ```
+    /// # struct AppData;
+    /// # let runtime = tokio::runtime::Builder::new_multi_thread().worker_threads(4).enable_all().build().unwrap();
+    /// let mut app = term_render::App::<AppData>::new_headless(20, 3);
+    /// let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+    ///     runtime.block_on(app.run(AppData, |_data, _app| -> Result<bool, ()> {
+    ///         panic!("something broke");
+    ///     }))
+    /// }));
+    /// let payload = result.unwrap_err();
+    /// assert_eq!(payload.downcast_ref::<&str>(), Some(&"something broke"));
+    /// // the app was shut down cleanly before the panic was passed on, so it can be run again
+    /// runtime.block_on(app.run(AppData, |_data, _app| Ok::<bool, ()>(true))).unwrap();
+    /// ```
+    ///
+    /// The event thread is stopped (rather than left waiting on input) before `run` returns, so apps
+    /// can be run one after another in the same process:
+    /// ```
+    /// # struct AppData;
+    /// # tokio::runtime::Builder::new_multi_thread().worker_threads(4).enable_all().build().unwrap().block_on(async {
+    /// for _ in 0..2 {
+    ///     let mut app = term_render::App::<AppData>::new_headless(20, 3);
+    ///     let mut exited = None;
+    ///     app.run(AppData, |_data, _app| {
+    ///         exited = Some(std::time::Instant::now());
+    ///         Ok::<bool, ()>(true)
+    ///     }).await.unwrap();
+    ///     // shutting down doesn't wait out the 100ms left for a stuck event thread
+    ///     assert!(exited.unwrap().elapsed() < std::time::Duration::from_millis(100));
+    /// }
+    /// # });
+    /// ```
+    pub async fn run<T, F>(&mut self, data: C, mut update_call_back: F) -> Result<(), T>
+        where T: Sized + std::fmt::Debug, F: FnMut(&mut C, &mut App<C>) -> Result<bool, T>
+    {
+        self.run_async(data, async |data: &mut C, app: &mut App<C>| update_call_back(data, app)).await
+    }
+    
+    /// Run the application with an asynchronous callback, which is awaited every frame (such as to
+    /// do network IO without blocking the loop). Rendering and event handling carry on in the
+    /// background while it's awaited; the next frame starts once the callback finishes.
+    /// Otherwise, this behaves the same as `run`.
+    /// # Example
+    /// ```no_run
+    /// # async fn example() {
+    /// // captured from the environment by the callback
+    /// let status_path = std::path::PathBuf::from("status.txt");
+    /// let mut last_check = std::time::Instant::now();
+    /// let mut app = term_render::App::new().unwrap();
+    /// app.run_async(String::new(), async |status, _app_instance| {
+    ///     // reloading the status every second without blocking the renderer
+    ///     if last_check.elapsed().as_secs() >= 1 {
+    ///         last_check = std::time::Instant::now();
+    ///         *status = tokio::fs::read_to_string(&status_path).await.unwrap_or_default();
+    ///     }
+    ///     Ok::<bool, ()>(false)  // return true to exit the app
     /// }).await.unwrap();
+    /// # }
     /// ```
-    pub async fn run<T: Sized + std::fmt::Debug>(&mut self, data: C, update_call_back: fn(&mut C, &mut App<C>) -> Result<bool, T>) -> Result<(), T> {
+    pub async fn run_async<T, F>(&mut self, mut data: C, update_call_back: F) -> Result<(), T>
+        where T: Sized + std::fmt::Debug, F: AsyncFnMut(&mut C, &mut App<C>) -> Result<bool, T>
+    {
         self.renderer.write().render(None);
         // it seems that adding a back wall seems to fix the initialization rendering bug? Odd, but works
         // no idea why it's only a problem here but in past projects it never was
-        let mut window = render::Window::new((1, 1), 0, (self.area.read().width, self.area.read().height));
+        let mut window = render::Window::new((0, 0), 0, (self.area.read().width, self.area.read().height));
         window.hide();
-        self.renderer.write().add_window(window, String::from("null_window_back_wall_unique"), vec![]);
+        let _ = self.renderer.write().add_window(window, String::from("null_window_back_wall_unique"), vec![]);  // already added if the app ran before
         self.renderer.write().render(None);
 
         let terminal_size_change = send_sync!(true);
@@ -152,47 +611,91 @@ impl<C> App<C> {
         let (sender, receiver) = crossbeam::channel::bounded(10);
         let area_clone = self.area.clone();
         let exit_clone = self.exit.clone();
-        let render_handle: tokio::task::JoinHandle<Result<(), AppErr>> = tokio::spawn( async move {
+        let render_handle: tokio::task::JoinHandle<Result<(), AppError>> = tokio::spawn( async move {
             Self::render((renderer_clone, receiver), area_clone, exit_clone, terminal_size_change_clone).await?;
             Ok(())
         });
-        let exit_clone = self.exit.clone();
-        let events_clone = self.events.clone();
-        let events_handle = tokio::spawn( async move {
-            Self::handle_events(exit_clone, events_clone).await;
-        });
-        match self.running_loop(data, update_call_back, sender, terminal_size_change).await {
-            Err(e) => {
-                println!("Error in running loop: {:?}", e);
-            },
-            Ok(_) => {},
+        let input = EventInput {
+            recorder: self.input_recorder.take(),
+            replay: self.input_replay.take().map(|mut replay| {
+                replay.continue_with_stdin = self.replay_continues_with_stdin;
+                replay
+            }),
+        };
+        let events_handle = Self::handle_events(self.exit.clone(), self.suspended.clone(), self.events.clone(), input);
+        // stop signals are deferred to the main loop, so the terminal can be handed back first
+        #[cfg(unix)]
+        let stop_signal = signal_hook::flag::register(signal_hook::consts::SIGTSTP, self.stop_requested.clone()).ok();
+        #[cfg(unix)]
+        let interrupt_signals = self.register_interrupt_signal();
+        // anything printed now would be lost on the alternate screen, so messages wait until the terminal is restored
+        let messages = send_sync!(Vec::<String>::new());
+        let previous_hook = Self::capture_panics(self.exit.clone(), messages.clone());
+        let mut panic_payload = None;
+        if let Err(e) = self.running_loop(&mut data, update_call_back, sender, terminal_size_change, &mut panic_payload).await {
+            messages.write().push(format!("Error in running loop: {:?}", e));
+        }
+        // the exit callback runs whatever stopped the loop, before the tasks are shut down
+        if let Err(payload) = self.run_exit_callback(&mut data) {
+            panic_payload.get_or_insert(payload);
         }
         
         //println!("Checking for errors");
         let mut error = false;
         *self.exit.write() = true;  // signal the tasks to exit
-        match events_handle.await {
-            Ok(_) => {},
-            Err(e) => {
-                println!("Error in event handling task: {:?}", e);
-                error = true;
-            },
+        // the event thread checks the exit flag between short waits for input, so it should stop almost immediately
+        let deadline = std::time::Instant::now() + EVENT_THREAD_SHUTDOWN_TIMEOUT;
+        while !events_handle.is_finished() && std::time::Instant::now() < deadline {
+            tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+        }
+        // without a way to wait on stdin (i.e. on windows), the thread is left blocked until the next input
+        if events_handle.is_finished() {
+            match events_handle.join() {
+                Ok(Ok(())) => {},
+                Ok(Err(e)) => {
+                    messages.write().push(format!("App Error in event handling thread: {:?}", e));
+                    error = true;
+                },
+                Err(e) => {
+                    messages.write().push(format!("Error in event handling thread: {:?}", e));
+                    error = true;
+                },
+            }
         }
         match render_handle.await {
             Ok(Err(e)) => {
-                println!("App Error in rendering task: {:?}", e);
+                messages.write().push(format!("App Error in rendering task: {:?}", e));
                 error = true;
             },
             Ok(_) => {},
             Err(e) => {
-                println!("Error in rendering task: {:?}", e);
+                messages.write().push(format!("Error in rendering task: {:?}", e));
                 error = true;
             },
         }
+        std::panic::set_hook(previous_hook);
+        #[cfg(unix)]
+        if let Some(stop_signal) = stop_signal {
+            signal_hook::low_level::unregister(stop_signal);
+        }
+        #[cfg(unix)]
+        for signal in interrupt_signals {
+            signal_hook::low_level::unregister(signal);
+        }
 
+        let messages = std::mem::take(&mut *messages.write());
+        if error || panic_payload.is_some() || !messages.is_empty() {
+            self.renderer.write().restore_terminal();
+            for message in &messages {
+                eprintln!("{}", message);
+            }
+        }
+        if let Some(payload) = panic_payload {
+            // the message was already printed above
+            std::panic::resume_unwind(payload);
+        }
         if error {
             // panicking since the user type isn't known at compile time and can't be easily returned internally
-            // panicking is necessary to ensure the app during drop doesn't clear the screen
             panic!("An error occurred during execution, see above for details.");
         }
 
@@ -209,9 +712,33 @@ impl<C> App<C> {
         Ok(())
     }
     
+    /// Awaits the future, catching any panic while it's polled (like `std::panic::catch_unwind`).
+    async fn catch_unwind<F: std::future::Future>(future: F) -> Result<F::Output, Box<dyn std::any::Any + Send>> {
+        let mut future = std::pin::pin!(future);
+        std::future::poll_fn(move |context| {
+            match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| future.as_mut().poll(context))) {
+                Ok(std::task::Poll::Ready(output)) => std::task::Poll::Ready(Ok(output)),
+                Ok(std::task::Poll::Pending) => std::task::Poll::Pending,
+                Err(payload) => std::task::Poll::Ready(Err(payload)),
+            }
+        }).await
+    }
+    
+    /// Replaces the panic hook with one that records the panic's message (rather than printing it
+    /// onto the alternate screen) and signals the app to exit. Returns the previous hook.
+    fn capture_panics(exit: SendSync<bool>, messages: SendSync<Vec<String>>) -> Box<dyn Fn(&std::panic::PanicHookInfo<'_>) + Sync + Send> {
+        let previous_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let thread = std::thread::current();
+            messages.write().push(format!("thread '{}' {}", thread.name().unwrap_or("<unnamed>"), info));
+            *exit.write() = true;  // signal the tasks to exit
+        }));
+        previous_hook
+    }
+    
     /// The main loop for the application.
     /// This loop continuously calls the update callback function and checks for exit conditions.
-    /// If the callback function returns true or if Ctrl+C is detected, the loop exits.
+    /// If the callback function returns true or if Ctrl+C is detected (see `set_ctrlc_behavior`), the loop exits.
     /// If the callback function returns an error, the loop exits and the error is propagated.
     /// This function purely handles the logic of the application, while rendering and event handling are managed in separate tasks.
     /// # Parameters
@@ -219,48 +746,121 @@ impl<C> App<C> {
     /// - update_call_back: The callback function to be called every frame.
     /// - sender: A channel sender to signal the rendering task to update.
     /// - terminal_size_change: A flag to indicate if the terminal size has changed.
+    /// - panic_payload: Set to the payload if the callback or a widget panics (the loop exits).
     /// # Returns
-    /// - Result<(), AppErr>: Returns Ok(()) if the loop exits normally, or an AppErr if an error occurs.
-    async fn running_loop<T: Sized + std::fmt::Debug>(&mut self,
-                                                         mut data: C,
-                                                         update_call_back: fn(&mut C, &mut App<C>) -> Result<bool, T>,
+    /// - Result<(), AppError>: Returns Ok(()) if the loop exits normally, or an AppError if an error occurs.
+    async fn running_loop<T: Sized + std::fmt::Debug, F: AsyncFnMut(&mut C, &mut App<C>) -> Result<bool, T>>(&mut self,
+                                                         data: &mut C,
+                                                         mut update_call_back: F,
                                                          sender: crossbeam::channel::Sender<bool>,
-                                                         terminal_size_change: SendSync<bool>
-    ) -> Result<(), AppErr> {
+                                                         terminal_size_change: SendSync<bool>,
+                                                         panic_payload: &mut Option<Box<dyn std::any::Any + Send>>
+    ) -> Result<(), AppError> {
+        let mut frame_start = std::time::Instant::now();
         loop {
-            // quick sleep to keep the events up-to-date enough
-            tokio::time::sleep(tokio::time::Duration::from_secs_f64(0.01)).await;
-            let result = update_call_back(&mut data, self);
+            // waiting out the rest of the tick (the events keep getting parsed in the background)
+            let remaining = self.tick_rate.saturating_sub(frame_start.elapsed());
+            if remaining.is_zero() {
+                tokio::task::yield_now().await;
+            } else {
+                tokio::time::sleep(remaining).await;
+            }
+            self.last_frame_duration = frame_start.elapsed();
+            frame_start = std::time::Instant::now();
+            
+            // noting any resize for this frame (it's cleared with the rest of the events)
+            let area = self.area.read().clone();
+            if area != self.previous_area {
+                let old_area = std::mem::replace(&mut self.previous_area, area.clone());
+                self.events.write().resize_event = Some((old_area, area));
+                self.restack_notifications();
+            }
+            
+            // collecting the messages posted since the last frame (see message_sender)
+            self.messages.collect();
+            
+            // dropping an ignored Ctrl+C before anything sees it (see set_ctrlc_behavior)
+            if self.handle_ctrlc() {  break;  }
+            
+            // the terminal's answer to the synchronized output query (see render::App::set_synchronized_output)
+            let synchronized_support = self.events.read().synchronized_output_support;
+            if let Some(supported) = synchronized_support {
+                self.events.write().synchronized_output_support = None;
+                self.renderer.write().set_synchronized_support(supported);
+            }
+            
+            // running the hooks at the start of the frame (see add_pre_update_hook)
+            if let Err(payload) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| self.run_hooks(app_hooks::HookPoint::PreUpdate, data))) {
+                *panic_payload = Some(payload);
+                break;
+            }
+            
+            // running the handlers of the attached keymap's pressed chords (see attach_keymap)
+            if let Err(payload) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| self.dispatch_keymap(data))) {
+                *panic_payload = Some(payload);
+                break;
+            }
+            
+            // panics are caught so the terminal can be restored before the panic carries on
+            let result = match Self::catch_unwind(update_call_back(data, self)).await {
+                Ok(result) => result,
+                Err(payload) => {
+                    *panic_payload = Some(payload);
+                    break;
+                },
+            };
             match result {
                 Ok(should_exit) => {
                     let events_read = self.events.read();
                     // making sure there is some safety in case the user messed up something
-                    if should_exit || (events_read.contains_modifier(KeyModifiers::Control) && events_read.contains_char('c')) {  break;  }
+                    // (unless Ctrl+C is copying the focused widget's selection)
+                    let copying = self.scene.as_ref().is_some_and(|scene| scene.focused_has_selection());
+                    let ctrlc = self.ctrlc_behavior == app_exit::CtrlC::Exit &&
+                        events_read.contains_modifier(KeyModifiers::Control) && events_read.contains_char('c');
+                    if should_exit || (ctrlc && !copying) {  break;  }
+                    drop(events_read);
+                    self.handle_suspend_requests()?;
                 },
                 Err(e) => {
-                    println!("Error in update callback: {:?}", e);
                     *self.exit.write() = true;  // signal the tasks to exit
-                    break;
+                    return Err(AppError::Callback(format!("{:?}", e)));
                 },
             }
             
+            // running any timers which are due (see set_timeout)
+            if let Err(payload) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| self.run_due_timers(data))) {
+                *panic_payload = Some(payload);
+                break;
+            }
+            
             // updating the scene
             if let Some(mut scene) = self.scene.take() {
                 // updating all widgets' states based on the events and their rendered windows
-                match scene.update_all_widgets(self, &mut data) {
-                    Err(e) => {
+                let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| scene.update_all_widgets(self, data)));
+                match result {
+                    Err(payload) => {
+                        *panic_payload = Some(payload);
+                        break;
+                    },
+                    Ok(Err(e)) => {
                         *self.exit.write() = true;  // signal the tasks to exit
-                        return Err(AppErr::new(&format!("Failed to update widgets in scene: {:?}", e)));
+                        return Err(AppError::Widget(e));
                     },
                     _ => {},
                 }
                 
                 if *terminal_size_change.read() {
-                    scene.force_update_all_widgets(&mut *self.renderer.write());
+                    scene.force_update_all_widgets(&mut self.renderer.write());
                 }
                 self.scene = Some(scene);
             }
             
+            // running the hooks after the update (see add_post_update_hook)
+            if let Err(payload) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| self.run_hooks(app_hooks::HookPoint::PostUpdate, data))) {
+                *panic_payload = Some(payload);
+                break;
+            }
+            
             // updating the back wall
             let mut render_write = self.renderer.write();
             let win = render_write.get_window_reference_mut(String::from("null_window_back_wall_unique"));
@@ -272,12 +872,18 @@ impl<C> App<C> {
             // if any background processes throw an error, exit will be set to true (otherwise, only this loop should set exit to true)
             if *self.exit.read() {  break;  }
             
+            // running the hooks before the frame is rendered (see add_pre_render_hook)
+            if let Err(payload) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| self.run_hooks(app_hooks::HookPoint::PreRender, data))) {
+                *panic_payload = Some(payload);
+                break;
+            }
+            
             // updating the render (keeping it in sync)
             if !sender.is_full() {
                 match sender.send(true) {
                     Ok(_) => {},
-                    Err(e) => {
-                       return Err(AppErr::new(&format!("Failed to send render sync on channel: {:?}", e)));
+                    Err(_) => {
+                       return Err(AppError::Render(render::RenderError::ChannelClosed));
                     }
                 }
             }
@@ -286,48 +892,139 @@ impl<C> App<C> {
         Ok(())
     }
     
-    /// Handle a single event from stdin.
-    /// This function reads from stdin, parses the input, and updates the event handler.
-    fn event_handling(parser: &mut vte::Parser, buffer: &mut [u8; 128], stdin: &mut std::io::Stdin, events: &SendSync<event_handler::KeyParser>) {
-        let result = stdin.read(buffer);
-        if let Ok(n) = result {
-            events.write().bytes = n;
-            if n == 1 && buffer[0] == 0x1B {
-                events.write().key_events.insert(event_handler::KeyCode::Escape, true);
-            } else {
-                parser.advance(&mut *events.write(), &buffer[..n]);
-            }
+    /// Suspends the process if Ctrl+Z was pressed (see `set_suspend_on_ctrl_z`) or a SIGTSTP arrived.
+    #[cfg(unix)]
+    fn handle_suspend_requests(&mut self) -> Result<(), AppError> {
+        let events = self.events.read();
+        let ctrl_z = self.suspend_on_ctrl_z && events.contains_modifier(KeyModifiers::Control) && events.contains_char('z');
+        drop(events);
+        if !ctrl_z && !self.stop_requested.swap(false, std::sync::atomic::Ordering::Relaxed) {  return Ok(());  }
+        self.suspend_process().map_err(|e| {
+            *self.exit.write() = true;  // signal the tasks to exit
+            AppError::Suspend(e)
+        })
+    }
+    
+    /// Suspending isn't supported outside of unix.
+    #[cfg(not(unix))]
+    fn handle_suspend_requests(&mut self) -> Result<(), AppError> {
+        Ok(())
+    }
+    
+    /// Handle a single event from stdin (or the replay, see `replay_input`).
+    /// This function waits briefly for input, parses it, and updates the event handler (recording the input if enabled).
+    /// Errors from reading are returned (interruptions aren't errors; they're retried on the next call).
+    /// Once a replay finishes, this returns an `UnexpectedEof` error, unless it continues with stdin.
+    fn event_handling(parser: &mut vte::Parser, buffer: &mut [u8; 128], events: &SendSync<event_handler::KeyParser>, input: &mut EventInput) -> std::io::Result<()> {
+        let bytes = match &mut input.replay {
+            Some(replay) => match (replay.continue_with_stdin, replay.next_input(INPUT_POLL_TIMEOUT)) {
+                (_, app_input::ReplayInput::Waiting) => return Ok(()),
+                (_, app_input::ReplayInput::Input(bytes)) => bytes,
+                (true, app_input::ReplayInput::Finished) => {
+                    input.replay = None;
+                    return Ok(());
+                },
+                (false, app_input::ReplayInput::Finished) => return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof)),
+            },
+            None => {
+                let n = Self::read_input(buffer, INPUT_POLL_TIMEOUT)?;
+                &buffer[..n]
+            },
+        };
+        if bytes.is_empty() {  return Ok(());  }
+        if let Some(recorder) = &mut input.recorder {
+            recorder.record(bytes)?;
         }
+        events.write().parse(parser, bytes);
+        Ok(())
     }
     
-    // event handling task (runs a loop that reads from stdin and parses the input)
-    // if this panics, it will set exit to true (signaling the app to shut down) and the main loop will exit
+    /// Reads whatever input is waiting on stdin, waiting at most `timeout` for some to arrive.
+    /// Returns the number of bytes read (0 if nothing arrived in time), or an `UnexpectedEof` error once stdin is closed.
+    /// Stdin is read directly rather than through `std::io::Stdin`, whose buffering would hide
+    /// input that was already read from the wait.
+    #[cfg(unix)]
+    fn read_input(buffer: &mut [u8], timeout: std::time::Duration) -> std::io::Result<usize> {
+        let mut poll_fd = libc::pollfd { fd: libc::STDIN_FILENO, events: libc::POLLIN, revents: 0 };
+        // SAFETY: the pointer is to a single valid pollfd, matching the count of 1
+        let ready = unsafe {  libc::poll(&mut poll_fd, 1, timeout.as_millis() as libc::c_int)  };
+        let read = match ready {
+            0 => return Ok(0),
+            // SAFETY: the pointer and length come from the same valid, mutable slice
+            1.. => unsafe {  libc::read(libc::STDIN_FILENO, buffer.as_mut_ptr() as *mut libc::c_void, buffer.len())  },
+            _ => -1,  // the poll's error is picked up below
+        };
+        match read {
+            0 => Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof)),
+            n if n > 0 => Ok(n as usize),
+            _ => {
+                let error = std::io::Error::last_os_error();
+                match error.kind() {
+                    std::io::ErrorKind::Interrupted | std::io::ErrorKind::WouldBlock => Ok(0),
+                    _ => Err(error),
+                }
+            },
+        }
+    }
+    
+    /// Reads whatever input is waiting on stdin. Without a way to wait with a timeout, this blocks until input arrives.
+    /// On windows, the console is in virtual terminal mode (see `event_handler::enable_virtual_terminal`),
+    /// so the input is made of the same escape sequences as on unix.
+    #[cfg(not(unix))]
+    fn read_input(buffer: &mut [u8], _timeout: std::time::Duration) -> std::io::Result<usize> {
+        use std::io::{IsTerminal, Read};
+        let mut stdin = std::io::stdin();
+        match stdin.read(buffer) {
+            // the windows console reads Ctrl+Z as the end of the input, but the console is still open
+            Ok(0) if stdin.is_terminal() => Ok(0),
+            Ok(0) => Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof)),
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => Ok(0),
+            result => result,
+        }
+    }
+    
+    // event handling thread (runs a loop that reads from stdin and parses the input)
+    // if reading fails, it will set exit to true (signaling the app to shut down) and the main loop will exit
     
     /// Handle events in a separate thread.
-    /// This function spawns a new thread that continuously reads from stdin and processes events.
-    async fn handle_events(exit: SendSync<bool>, events: SendSync<event_handler::KeyParser>) {
-        let events = events.clone();
-        let exit_clone = exit.clone();
-        // can't manually clean it up...
-        let _result_handle = std::thread::spawn(
-            move || {
-                let events = events;
-                let mut parser = vte::Parser::new();
-                let mut buffer = [0; 128];
-                let mut stdin = std::io::stdin();
-                loop {
-                    Self::event_handling(&mut parser, &mut buffer, &mut stdin, &events);
-                    if *exit_clone.read() { break; }
+    /// This function spawns a new thread that continuously reads from stdin and processes events
+    /// until the exit flag is set (pausing while the app is suspended). The thread returns an error if stdin can't be read; once stdin
+    /// is closed, it stops quietly (no more events can arrive). When a replay finishes (see `replay_input`),
+    /// the app exits, unless it continues with stdin.
+    fn handle_events(exit: SendSync<bool>, suspended: SendSync<bool>, events: SendSync<event_handler::KeyParser>, mut input: EventInput) -> std::thread::JoinHandle<Result<(), AppError>> {
+        std::thread::spawn(move || {
+            let mut parser = vte::Parser::new();
+            let mut buffer = [0; 128];
+            // the recorded timings are relative to when the events start being read
+            if let Some(recorder) = &mut input.recorder {  recorder.start();  }
+            if let Some(replay) = &mut input.replay {  replay.start();  }
+            while !*exit.read() {
+                // the input belongs to whatever is using the terminal while suspended
+                if *suspended.read() {
+                    std::thread::sleep(INPUT_POLL_TIMEOUT);
+                    continue;
                 }
-            }
-        );
+                match Self::event_handling(&mut parser, &mut buffer, &events, &mut input) {
+                    Ok(()) => {},
+                    Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof && input.replay.is_some() => {
+                        *exit.write() = true;  // signal the app to exit (the replay is over)
+                        break;
+                    },
+                    Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
+                    Err(e) => {
+                        *exit.write() = true;  // signal the app to exit
+                        return Err(AppError::Input(e));
+                    },
+                }
+            } Ok(())
+        })
     }
     
     /// Handles rendering for a single frame.
-    async fn render_handling(renderer: &SendSync<render::App>, area: &SendSync<render::Rect>, terminal_size_change: &SendSync<bool>) -> Result<(), AppErr> {
-        let ar = match renderer.read().get_terminal_size() {
+    async fn render_handling(renderer: &SendSync<render::App>, area: &SendSync<render::Rect>, terminal_size_change: &SendSync<bool>) -> Result<(), AppError> {
+        let ar = match renderer.write().poll_terminal_size() {
             Err(e) => {
-                return Err(AppErr::new(&format!("Failed to get terminal size: {:?}", e)));
+                return Err(AppError::Render(render::RenderError::TerminalSize(e)));
             },
             Ok(size) => size,
         };
@@ -350,16 +1047,16 @@ impl<C> App<C> {
                                area: SendSync<render::Rect>,
                                exit: SendSync<bool>,
                                terminal_size_change: SendSync<bool>
-    ) -> Result<(), AppErr> {
+    ) -> Result<(), AppError> {
         let exit_clone = exit.clone();
-        let result_handle: tokio::task::JoinHandle<Result<(), AppErr>> = tokio::spawn(async move {
+        let result_handle: tokio::task::JoinHandle<Result<(), AppError>> = tokio::spawn(async move {
             loop {
                 Self::render_handling(&renderer.0, &area, &terminal_size_change).await?;
                 if *exit_clone.read() {  break;  }
                 match renderer.1.recv() {
                     // the if is necessary to prevent errors whenever exiting (this would wait for a non-existent signal)
                     // no real errors or important ones should be sent in that tiny period of time
-                    Err(e) if !*exit_clone.read() => { return Err(AppErr::new(&format!("Failed to receive render sync on channel: {:?}", e))); }  // channel disconnected, exit the loop
+                    Err(_) if !*exit_clone.read() => { return Err(AppError::Render(render::RenderError::ChannelClosed)); }  // channel disconnected, exit the loop
                     _ => {},
                 }
             } Ok(())
@@ -372,8 +1069,8 @@ impl<C> App<C> {
             },
             Ok(_) => {},
             Err(e) => {
-                println!("Error in rendering: {:?}", e);
                 *exit.write() = true;  // signal the tasks to exit
+                return Err(AppError::RenderTask(e));
             },
         } Ok(())
     }
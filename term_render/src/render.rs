@@ -5,6 +5,25 @@ use std::io::Write;
 // literally just for enabling or disabling mouse support and stuff
 use crate::event_handler;
 use crossbeam;
+use unicode_width::UnicodeWidthChar;
+
+// the inline markup parser (styled_text, styled_lines, etc.)
+pub use crate::render_markup::*;
+// cell based frames for inspecting and exporting what was rendered
+pub use crate::render_frame::*;
+// named color palettes shared across widgets
+pub use crate::render_theme::*;
+// the renderer's errors
+pub use crate::render_error::*;
+// turning off color (NO_COLOR, dumb terminals) and motion
+pub use crate::render_capabilities::*;
+// debouncing the terminal's size while it's being resized
+pub use crate::render_resize::{DEFAULT_RESIZE_DEBOUNCE, LARGE_RESIZE};
+// copying and pasting through the terminal (OSC 52)
+pub use crate::render_clipboard::MAX_CLIPBOARD_BYTES;
+// raster images through the terminal's graphics protocols
+#[cfg(feature = "images")]
+pub use crate::render_image::*;
 
 
 // static color/mod pairs for default ascii/ansi codes
@@ -19,6 +38,10 @@ use crossbeam;
 pub static CLEAR: &str = "\x1b[0m";
 pub static SHOW_CURSOR: &str = "\x1b[?25h";
 pub static HIDE_CURSOR: &str = "\x1b[?25l";
+/// Begins a synchronized update (DEC mode 2026); the terminal holds off presenting until it ends.
+pub static BEGIN_SYNCHRONIZED_UPDATE: &str = "\x1b[?2026h";
+/// Ends a synchronized update, presenting everything written since it began at once.
+pub static END_SYNCHRONIZED_UPDATE: &str = "\x1b[?2026l";
 
 // * color, modifiers, is_background
 pub static EMPTY_MODIFIER_REFERENCE: &[&str] = &[];  // making a default static type is annoying
@@ -43,25 +66,28 @@ pub static BRIGHT_CYAN:    (Option <&str>, &[&str], bool) = (Some("96"), &[], fa
 pub static BRIGHT_WHITE:   (Option <&str>, &[&str], bool) = (Some("97"), &[], false );
 pub static BRIGHT_DEFAULT: (Option <&str>, &[&str], bool) = (Some("99"), &[], false );
 
-pub static ON_BLACK:   (Option <&str>, &[&str], bool) = (Some("100"), &[], true );
-pub static ON_RED:     (Option <&str>, &[&str], bool) = (Some("101"), &[], true );
-pub static ON_GREEN:   (Option <&str>, &[&str], bool) = (Some("102"), &[], true );
-pub static ON_YELLOW:  (Option <&str>, &[&str], bool) = (Some("103"), &[], true );
-pub static ON_BLUE:    (Option <&str>, &[&str], bool) = (Some("104"), &[], true );
-pub static ON_MAGENTA: (Option <&str>, &[&str], bool) = (Some("105"), &[], true );
-pub static ON_CYAN:    (Option <&str>, &[&str], bool) = (Some("106"), &[], true );
-pub static ON_WHITE:   (Option <&str>, &[&str], bool) = (Some("107"), &[], true );
-pub static ON_DEFAULT: (Option <&str>, &[&str], bool) = (Some("109"), &[], true );
-
-pub static ON_BRIGHT_BLACK:   (Option <&str>, &[&str], bool) = (Some("40"), &[], true );
-pub static ON_BRIGHT_RED:     (Option <&str>, &[&str], bool) = (Some("41"), &[], true );
-pub static ON_BRIGHT_GREEN:   (Option <&str>, &[&str], bool) = (Some("42"), &[], true );
-pub static ON_BRIGHT_YELLOW:  (Option <&str>, &[&str], bool) = (Some("43"), &[], true );
-pub static ON_BRIGHT_BLUE:    (Option <&str>, &[&str], bool) = (Some("44"), &[], true );
-pub static ON_BRIGHT_MAGENTA: (Option <&str>, &[&str], bool) = (Some("45"), &[], true );
-pub static ON_BRIGHT_CYAN:    (Option <&str>, &[&str], bool) = (Some("46"), &[], true );
-pub static ON_BRIGHT_WHITE:   (Option <&str>, &[&str], bool) = (Some("47"), &[], true );
-pub static ON_BRIGHT_DEFAULT: (Option <&str>, &[&str], bool) = (Some("49"), &[], true );
+// backgrounds follow the foregrounds: 40-47 (and 49 for the default) are the normal colors, and
+// 100-107 the bright ones. Before 0.2.0 these constants had the two sets swapped (the dark mode table
+// swapped them back, but light mode rendered OnRed as bright red, OnWhite as black, etc.)
+pub static ON_BLACK:   (Option <&str>, &[&str], bool) = (Some("40"), &[], true );
+pub static ON_RED:     (Option <&str>, &[&str], bool) = (Some("41"), &[], true );
+pub static ON_GREEN:   (Option <&str>, &[&str], bool) = (Some("42"), &[], true );
+pub static ON_YELLOW:  (Option <&str>, &[&str], bool) = (Some("43"), &[], true );
+pub static ON_BLUE:    (Option <&str>, &[&str], bool) = (Some("44"), &[], true );
+pub static ON_MAGENTA: (Option <&str>, &[&str], bool) = (Some("45"), &[], true );
+pub static ON_CYAN:    (Option <&str>, &[&str], bool) = (Some("46"), &[], true );
+pub static ON_WHITE:   (Option <&str>, &[&str], bool) = (Some("47"), &[], true );
+pub static ON_DEFAULT: (Option <&str>, &[&str], bool) = (Some("49"), &[], true );
+
+pub static ON_BRIGHT_BLACK:   (Option <&str>, &[&str], bool) = (Some("100"), &[], true );
+pub static ON_BRIGHT_RED:     (Option <&str>, &[&str], bool) = (Some("101"), &[], true );
+pub static ON_BRIGHT_GREEN:   (Option <&str>, &[&str], bool) = (Some("102"), &[], true );
+pub static ON_BRIGHT_YELLOW:  (Option <&str>, &[&str], bool) = (Some("103"), &[], true );
+pub static ON_BRIGHT_BLUE:    (Option <&str>, &[&str], bool) = (Some("104"), &[], true );
+pub static ON_BRIGHT_MAGENTA: (Option <&str>, &[&str], bool) = (Some("105"), &[], true );
+pub static ON_BRIGHT_CYAN:    (Option <&str>, &[&str], bool) = (Some("106"), &[], true );
+pub static ON_BRIGHT_WHITE:   (Option <&str>, &[&str], bool) = (Some("107"), &[], true );
+pub static ON_BRIGHT_DEFAULT: (Option <&str>, &[&str], bool) = (Some("109"), &[], true );
 
 pub static BOLD:      (Option <&str>, &[&str], bool) = (None    , &["1"], false);
 pub static DIM:       (Option <&str>, &[&str], bool) = (None    , &["2"], false);
@@ -73,7 +99,28 @@ pub static HIDE:      (Option <&str>, &[&str], bool) = (None    , &["8"], false)
 
 
 // manages the global state for light/dark modes (handles basic colors switching around)
-// no support for RGB/custom color codes, only the default variants
+/// The global light/dark mode. In light mode, the basic colors are swapped around (i.e. black and
+/// white) and RGB colors are passed through the RGB adjustment (see `ColorMode::set_rgb_adjustment`).
+/// Colors are resolved when they're added to text, so switching modes re-renders every window, and any
+/// text generated afterward (such as by widget renderers) uses the new mode.
+/// # Example:
+/// ```standalone_crate
+/// use term_render::render::{ColorMode, ColorType, RgbAdjust};
+/// let code = |color: ColorType| color.get_color().unwrap_into_tuple().0.unwrap();
+/// assert_eq!(code(ColorType::Rgb(200, 100, 50)), "38;2;200;100;50");
+///
+/// // light mode inverts the lightness while keeping the hue
+/// ColorMode::to_light();
+/// assert_eq!(ColorMode::get(), ColorMode::Light);
+/// assert_eq!(code(ColorType::Rgb(200, 100, 50)), "38;2;205;105;55");
+/// assert_eq!(code(ColorType::Rgb(205, 105, 55)), "38;2;200;100;50");
+///
+/// ColorMode::set_rgb_adjustment(RgbAdjust::None);
+/// assert_eq!(code(ColorType::OnRGB(200, 100, 50)), "48;2;200;100;50");
+///
+/// ColorMode::to_dark();
+/// assert_eq!(code(ColorType::Rgb(200, 100, 50)), "38;2;200;100;50");
+/// ```
 #[derive(Clone, PartialEq, Eq, Debug, Default, Hash, Copy)]
 pub enum ColorMode {
     #[default] Dark,
@@ -81,18 +128,101 @@ pub enum ColorMode {
 }
 
 impl ColorMode {
+    /// Switches to light mode, re-rendering every window on the next frame.
     pub fn to_light () {
-        unsafe {COLOR_MODE = ColorMode::Light};
+        ColorMode::set(ColorMode::Light);
     }
 
+    /// Switches to dark mode (the default), re-rendering every window on the next frame.
     pub fn to_dark () {
-        unsafe {COLOR_MODE = ColorMode::Dark};
+        ColorMode::set(ColorMode::Dark);
+    }
+
+    fn set (mode: ColorMode) {
+        let light = mode == ColorMode::Light;
+        if COLOR_MODE.swap(light, std::sync::atomic::Ordering::Relaxed) != light {
+            COLOR_MODE_GENERATION.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        }
+    }
+
+    /// Gets the current color mode.
+    pub fn get () -> ColorMode {
+        match COLOR_MODE.load(std::sync::atomic::Ordering::Relaxed) {
+            true => ColorMode::Light,
+            false => ColorMode::Dark,
+        }
+    }
+
+    /// Sets how RGB colors (foreground and background) are adjusted in light mode.
+    /// The default is `RgbAdjust::Invert`. Dark mode always uses RGB colors as they are.
+    pub fn set_rgb_adjustment (adjustment: RgbAdjust) {
+        *RGB_ADJUSTMENT.write() = adjustment;
+        if ColorMode::get() == ColorMode::Light {
+            COLOR_MODE_GENERATION.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        }
+    }
+
+    /// Gets how RGB colors are adjusted in light mode.
+    pub fn get_rgb_adjustment () -> RgbAdjust {
+        *RGB_ADJUSTMENT.read()
+    }
+
+    // incremented whenever the colors change, so the renderer knows to redraw every window
+    fn get_generation () -> usize {
+        COLOR_MODE_GENERATION.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    // redraws every window on the next frame (i.e. once color is turned off, see `set_color_enabled`)
+    pub(crate) fn bump_generation () {
+        COLOR_MODE_GENERATION.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    }
+}
+
+/// How RGB colors are adjusted in light mode.
+#[derive(Clone, Debug, Default, Copy)]
+pub enum RgbAdjust {
+    /// The colors are left as they are.
+    None,
+    /// The lightness is inverted while the hue and saturation are kept, so dark colors become
+    /// light and vice versa (i.e. `Rgb(20, 20, 20)` becomes `Rgb(235, 235, 235)`).
+    /// Inverting twice gives back the original color.
+    #[default] Invert,
+    /// A custom function mapping (r, g, b) to the adjusted color.
+    Custom (fn (u8, u8, u8) -> (u8, u8, u8)),
+}
+
+impl RgbAdjust {
+    /// Applies the adjustment to the given color.
+    /// # Example:
+    /// ```
+    /// use term_render::render::RgbAdjust;
+    /// assert_eq!(RgbAdjust::Invert.apply((200, 100, 50)), (205, 105, 55));
+    /// assert_eq!(RgbAdjust::Invert.apply((0, 0, 0)), (255, 255, 255));
+    /// assert_eq!(RgbAdjust::None.apply((200, 100, 50)), (200, 100, 50));
+    /// assert_eq!(RgbAdjust::Custom(|r, g, b| (b, g, r)).apply((200, 100, 50)), (50, 100, 200));
+    /// ```
+    pub fn apply (&self, color: (u8, u8, u8)) -> (u8, u8, u8) {
+        match self {
+            RgbAdjust::None => color,
+            RgbAdjust::Invert => {
+                // the lightness (in hsl) is the average of the largest and smallest components, so shifting
+                // every component by the same amount flips it without changing the hue or saturation
+                let (r, g, b) = color;
+                let shift = 255 - r.max(g).max(b) as i16 - r.min(g).min(b) as i16;
+                let adjust = |component: u8| (component as i16 + shift) as u8;
+                (adjust(r), adjust(g), adjust(b))
+            },
+            RgbAdjust::Custom(function) => function(color.0, color.1, color.2),
+        }
     }
 }
 
 // hopefully this will let full usage of colors while not worrying too much about light/dark mode
 // -- (basic but limited automatic support; not everything will look perfect by default)
-static mut COLOR_MODE: ColorMode = ColorMode::Dark;
+// (atomics and a lock rather than a static mut, as colors are resolved from multiple threads)
+static COLOR_MODE: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+static COLOR_MODE_GENERATION: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+static RGB_ADJUSTMENT: parking_lot::RwLock <RgbAdjust> = parking_lot::const_rwlock(RgbAdjust::Invert);
 
 
 // Different base ascii text modifiers (static constants)
@@ -102,6 +232,24 @@ static mut COLOR_MODE: ColorMode = ColorMode::Dark;
 /// However, to convert a `ColorType` into `UniqueColor`, additional
 /// logic is necessary compared to directly using `UniqueColor`.
 /// This additional overhead is minimal and generally unnoticeable in most applications.
+///
+/// Backgrounds use the same codes as their foregrounds shifted by ten (`OnRed` is 41, and
+/// `OnBrightRed` 101). Light mode maps bright colors onto the normal ones and swaps black and white.
+/// # Example:
+/// ```standalone_crate
+/// use term_render::render::{ColorMode, ColorType};
+/// let code = |color: ColorType| color.get_color().unwrap_into_tuple().0.unwrap();
+/// assert_eq!([code(ColorType::Red), code(ColorType::OnRed)], ["31", "41"]);
+/// assert_eq!([code(ColorType::BrightRed), code(ColorType::OnBrightRed)], ["91", "101"]);
+/// assert_eq!(code(ColorType::OnDefault), "49");
+///
+/// ColorMode::to_light();
+/// assert_eq!([code(ColorType::Red), code(ColorType::OnRed)], ["31", "41"]);
+/// assert_eq!([code(ColorType::BrightRed), code(ColorType::OnBrightRed)], ["31", "41"]);
+/// assert_eq!([code(ColorType::White), code(ColorType::OnWhite)], ["90", "100"]);
+/// assert_eq!([code(ColorType::Black), code(ColorType::OnBlack)], ["97", "107"]);
+/// ColorMode::to_dark();
+/// ```
 #[derive(Clone, Debug, Eq, PartialEq, Default, Hash, Copy)]
 pub enum ColorType {
     Black,
@@ -205,13 +353,54 @@ impl UniqueColor {
 impl ColorType {
     // Converts the color type into a unique color (static or dynamic)
     pub fn get_color (&self) -> UniqueColor {
-        if unsafe { COLOR_MODE } == ColorMode::Dark {
+        // colors the terminal can't show are swapped for the nearest ones it can
+        let depth = get_color_depth();
+        if depth != ColorDepth::TrueColor && let Some(color) = self.get_downconverted_color(depth) {
+            return color;
+        }
+        if ColorMode::get() == ColorMode::Dark {
             self.get_dark_color()
         } else {
             self.get_light_color()
         }
     }
     
+    // the nearest color at a lower color depth, or None if the color can be shown as it is (i.e. the
+    // modifiers, or the base colors with 16 or more colors)
+    fn get_downconverted_color (&self, depth: ColorDepth) -> Option <UniqueColor> {
+        let adjust = |rgb: (u8, u8, u8)| match ColorMode::get() {
+            ColorMode::Dark => rgb,
+            ColorMode::Light => ColorMode::get_rgb_adjustment().apply(rgb),
+        };
+        let (index, background) = match *self {
+            ColorType::Rgb(r, g, b) => (rgb_to_ansi256(adjust((r, g, b))), false),
+            ColorType::OnRGB (r, g, b) => (rgb_to_ansi256(adjust((r, g, b))), true),
+            ColorType::Ansi(index) => (index, false),
+            ColorType::OnANSI (index) => (index, true),
+            // the base colors are dropped without any colors, while the modifiers stay
+            color if depth == ColorDepth::Mono => {
+                let (code, _mods, background) = match ColorMode::get() {
+                    ColorMode::Dark => color.get_dark_color(),
+                    ColorMode::Light => color.get_light_color(),
+                }.unwrap_into_tuple();
+                return code.map(|_code| UniqueColor::Static((None, EMPTY_MODIFIER_REFERENCE, background)));
+            },
+            _ => return None,
+        };
+        let code = match depth {
+            ColorDepth::Mono => return Some(UniqueColor::Static((None, EMPTY_MODIFIER_REFERENCE, background))),
+            ColorDepth::Ansi256 => format!("{};5;{}", if background {  48  } else {  38  }, index),
+            _ => {
+                let base = ansi256_to_ansi16(index) as u16;
+                match base < 8 {
+                    true => base + if background {  40  } else {  30  },
+                    false => base - 8 + if background {  100  } else {  90  },
+                }.to_string()
+            },
+        };
+        Some(UniqueColor::Dynamic((Some(code), EMPTY_MODIFIER_REFERENCE, background)))
+    }
+
     fn get_light_color (&self) -> UniqueColor {
         match self {
             ColorType::Black =>   { UniqueColor::Static(BRIGHT_WHITE) },
@@ -234,7 +423,7 @@ impl ColorType {
             ColorType::BrightWhite =>   { UniqueColor::Static(BLACK) },
             ColorType::BrightDefault => { UniqueColor::Static(DEFAULT) },
 
-            ColorType::OnBlack => { UniqueColor::Static(ON_WHITE) },
+            ColorType::OnBlack => { UniqueColor::Static(ON_BRIGHT_WHITE) },
             ColorType::OnRed => { UniqueColor::Static(ON_RED) },
             ColorType::OnGreen => { UniqueColor::Static(ON_GREEN) },
             ColorType::OnYellow => { UniqueColor::Static(ON_YELLOW) },
@@ -242,9 +431,9 @@ impl ColorType {
             ColorType::OnMagenta => { UniqueColor::Static(ON_MAGENTA) },
             ColorType::OnCyan => { UniqueColor::Static(ON_CYAN) },
             ColorType::OnWhite => { UniqueColor::Static(ON_BRIGHT_BLACK) },
-            ColorType::OnDefault => { UniqueColor::Static(ON_BRIGHT_DEFAULT) },
+            ColorType::OnDefault => { UniqueColor::Static(ON_DEFAULT) },
 
-            ColorType::OnBrightBlack => { UniqueColor::Static(ON_BRIGHT_WHITE) },
+            ColorType::OnBrightBlack => { UniqueColor::Static(ON_WHITE) },
             ColorType::OnBrightRed => { UniqueColor::Static(ON_RED) },
             ColorType::OnBrightGreen => { UniqueColor::Static(ON_GREEN) },
             ColorType::OnBrightYellow => { UniqueColor::Static(ON_YELLOW) },
@@ -254,20 +443,13 @@ impl ColorType {
             ColorType::OnBrightWhite => { UniqueColor::Static(ON_BLACK) },
             ColorType::OnBrightDefault => { UniqueColor::Static(ON_DEFAULT) },
 
-            // 24-bit? I think so but make sure it works
+            // 24-bit colors go through the (configurable) light mode adjustment
             ColorType::Rgb(r, g, b) => {
-                let (mut rn, mut gn, mut bn) = (*r, *g, *b);
-                if rn > 128 {  rn -= 128;  }
-                if gn > 128 {  gn -= 128;  }
-                if bn > 128 {  bn -= 128;  }
+                let (rn, gn, bn) = ColorMode::get_rgb_adjustment().apply((*r, *g, *b));
                 UniqueColor::Dynamic((Some(format!("38;2;{};{};{}", rn, gn, bn)), EMPTY_MODIFIER_REFERENCE, false))
             },
-            // background 24-bit? Make sure that's right
             ColorType::OnRGB (r, g, b) => {
-                let (mut rn, mut gn, mut bn) = (*r, *g, *b);
-                if rn > 128 {  rn -= 128;  }
-                if gn > 128 {  gn -= 128;  }
-                if bn > 128 {  bn -= 128;  }
+                let (rn, gn, bn) = ColorMode::get_rgb_adjustment().apply((*r, *g, *b));
                 UniqueColor::Dynamic((Some(format!("48;2;{};{};{}", rn, gn, bn)), EMPTY_MODIFIER_REFERENCE, true))
             },
             ColorType::Ansi(index) => {
@@ -309,24 +491,24 @@ impl ColorType {
             ColorType::BrightWhite => { UniqueColor::Static(BRIGHT_WHITE) },
             ColorType::BrightDefault => { UniqueColor::Static(BRIGHT_DEFAULT) },
 
-            ColorType::OnBlack => { UniqueColor::Static(ON_BRIGHT_BLACK) },
-            ColorType::OnRed => { UniqueColor::Static(ON_BRIGHT_RED) },
-            ColorType::OnGreen => { UniqueColor::Static(ON_BRIGHT_GREEN) },
-            ColorType::OnYellow => { UniqueColor::Static(ON_BRIGHT_YELLOW) },
-            ColorType::OnBlue => { UniqueColor::Static(ON_BRIGHT_BLUE) },
-            ColorType::OnMagenta => { UniqueColor::Static(ON_BRIGHT_MAGENTA) },
-            ColorType::OnCyan => { UniqueColor::Static(ON_BRIGHT_CYAN) },
-            ColorType::OnWhite => { UniqueColor::Static(ON_BRIGHT_WHITE) },
+            ColorType::OnBlack => { UniqueColor::Static(ON_BLACK) },
+            ColorType::OnRed => { UniqueColor::Static(ON_RED) },
+            ColorType::OnGreen => { UniqueColor::Static(ON_GREEN) },
+            ColorType::OnYellow => { UniqueColor::Static(ON_YELLOW) },
+            ColorType::OnBlue => { UniqueColor::Static(ON_BLUE) },
+            ColorType::OnMagenta => { UniqueColor::Static(ON_MAGENTA) },
+            ColorType::OnCyan => { UniqueColor::Static(ON_CYAN) },
+            ColorType::OnWhite => { UniqueColor::Static(ON_WHITE) },
             ColorType::OnDefault => { UniqueColor::Static(ON_DEFAULT) },
 
-            ColorType::OnBrightBlack => { UniqueColor::Static(ON_BLACK) },
-            ColorType::OnBrightRed => { UniqueColor::Static(ON_RED) },
-            ColorType::OnBrightGreen => { UniqueColor::Static(ON_GREEN) },
-            ColorType::OnBrightYellow => { UniqueColor::Static(ON_YELLOW) },
-            ColorType::OnBrightBlue => { UniqueColor::Static(ON_BLUE) },
-            ColorType::OnBrightMagenta => { UniqueColor::Static(ON_MAGENTA) },
-            ColorType::OnBrightCyan => { UniqueColor::Static(ON_CYAN) },
-            ColorType::OnBrightWhite => { UniqueColor::Static(ON_WHITE) },
+            ColorType::OnBrightBlack => { UniqueColor::Static(ON_BRIGHT_BLACK) },
+            ColorType::OnBrightRed => { UniqueColor::Static(ON_BRIGHT_RED) },
+            ColorType::OnBrightGreen => { UniqueColor::Static(ON_BRIGHT_GREEN) },
+            ColorType::OnBrightYellow => { UniqueColor::Static(ON_BRIGHT_YELLOW) },
+            ColorType::OnBrightBlue => { UniqueColor::Static(ON_BRIGHT_BLUE) },
+            ColorType::OnBrightMagenta => { UniqueColor::Static(ON_BRIGHT_MAGENTA) },
+            ColorType::OnBrightCyan => { UniqueColor::Static(ON_BRIGHT_CYAN) },
+            ColorType::OnBrightWhite => { UniqueColor::Static(ON_BRIGHT_WHITE) },
             ColorType::OnBrightDefault => { UniqueColor::Static(ON_BRIGHT_DEFAULT) },
 
             // 24-bit? I think so but make sure it works
@@ -353,8 +535,44 @@ impl ColorType {
             ColorType::Hide => { UniqueColor::Static(HIDE) },
         }
     }
+
+    // Maps a stored escape code fragment (color or modifier) back to the color type which produces it
+    // in the current color mode. RGB and ANSI colors are parsed directly (so their light mode
+    // adjustment isn't undone). Returns None for fragments no color type produces.
+    fn from_escape_code (code: &str, background: bool) -> Option <ColorType> {
+        let parse = |prefix: &str| -> Option <Vec <u8>> {
+            code.strip_prefix(prefix)?.split(';').map(|part| part.parse::<u8>().ok()).collect()
+        };
+        match (background, parse("38;2;"), parse("48;2;"), parse("38;5;"), parse("48;5;")) {
+            (false, Some(rgb), ..) if rgb.len() == 3 => return Some(ColorType::Rgb(rgb[0], rgb[1], rgb[2])),
+            (true, _, Some(rgb), ..) if rgb.len() == 3 => return Some(ColorType::OnRGB(rgb[0], rgb[1], rgb[2])),
+            (false, _, _, Some(index), _) if index.len() == 1 => return Some(ColorType::Ansi(index[0])),
+            (true, _, _, _, Some(index)) if index.len() == 1 => return Some(ColorType::OnANSI(index[0])),
+            _ => {},
+        }
+        NAMED_COLOR_TYPES.iter().copied().find(|color| {
+            let (color_code, mods, is_background) = color.get_color().unwrap_into_tuple();
+            match color_code {
+                Some(color_code) => is_background == background && color_code == code,
+                None => mods.len() == 1 && mods[0] == code,
+            }
+        })
+    }
 }
 
+// every color type without parameters (used for mapping escape codes back to their color types)
+const NAMED_COLOR_TYPES: [ColorType; 43] = [
+    ColorType::Black, ColorType::Red, ColorType::Green, ColorType::Yellow, ColorType::Blue,
+    ColorType::Magenta, ColorType::Cyan, ColorType::White, ColorType::Default,
+    ColorType::BrightBlack, ColorType::BrightRed, ColorType::BrightGreen, ColorType::BrightYellow, ColorType::BrightBlue,
+    ColorType::BrightMagenta, ColorType::BrightCyan, ColorType::BrightWhite, ColorType::BrightDefault,
+    ColorType::OnBlack, ColorType::OnRed, ColorType::OnGreen, ColorType::OnYellow, ColorType::OnBlue,
+    ColorType::OnMagenta, ColorType::OnCyan, ColorType::OnWhite, ColorType::OnDefault,
+    ColorType::OnBrightBlack, ColorType::OnBrightRed, ColorType::OnBrightGreen, ColorType::OnBrightYellow, ColorType::OnBrightBlue,
+    ColorType::OnBrightMagenta, ColorType::OnBrightCyan, ColorType::OnBrightWhite, ColorType::OnBrightDefault,
+    ColorType::Bold, ColorType::Dim, ColorType::Italic, ColorType::Underline, ColorType::Blink, ColorType::Reverse, ColorType::Hide,
+];
+
 // Color setters for standard primitives
 
 /// Converts the given instance into the type `Colored` based on a provided
@@ -365,6 +583,8 @@ impl ColorType {
 /// calling of these functions.
 /// For example, the following are equivalent:
 /// ```
+/// # use term_render::render::{Colorize, ColorType};
+/// # use term_render::color;
 /// "Hello, world!".colorizes(vec![]);
 /// "Hello, world!".colorize(ColorType::Red);
 /// "Hello, world!".colorizes(vec![ColorType::Red, ColorType::Bold]);
@@ -380,6 +600,11 @@ pub trait Colorize {
 
     // adds a single modifier/color
     fn colorize (&self, colors: ColorType) -> Colored;
+
+    /// Turns the text into a clickable hyperlink to the url (see `Colored::with_link`), without changing its colors.
+    fn hyperlink (&self, url: &str) -> Colored {
+        self.colorizes(vec![]).with_link(url)
+    }
 }
 
 impl Colorize for &str {
@@ -414,6 +639,7 @@ pub struct Colored {
     mods: Vec <String>,
     color: Option <String>,
     bg_color: Option <String>,
+    link: Option <String>,
 }
 
 impl Colorize for Colored {
@@ -438,9 +664,37 @@ impl Colored {
             mods: vec![],
             color: None,
             bg_color: None,
+            link: None,
         }
     }
 
+    /// Turns the text into a clickable hyperlink to the url (through OSC 8), keeping its colors.
+    /// Terminals without hyperlink support show the plain text instead.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{Colorize, ColorType, Colored};
+    /// let link = "issues".colorize(ColorType::Blue).with_link("https://example.com/issues");
+    /// assert_eq!(link.get_link(), Some("https://example.com/issues"));
+    /// assert_eq!(link.get_size(), 6);  // the link markers aren't visible
+    /// // splitting the text keeps the link on both halves
+    /// let (left, right) = link.split(3);
+    /// assert_eq!((left.get_link(), right.get_link()), (link.get_link(), link.get_link()));
+    /// ```
+    pub fn with_link (mut self, url: &str) -> Colored {
+        self.link = Some(url.to_owned());
+        self
+    }
+
+    /// Gets the url the text links to, if it's a hyperlink (see `with_link`).
+    pub fn get_link (&self) -> Option <&str> {
+        self.link.as_deref()
+    }
+
+    /// Sets (or removes, with None) the url the text links to (see `with_link`).
+    pub fn set_link (&mut self, url: Option <&str>) {
+        self.link = url.map(String::from);
+    }
+
     /// returns the left and right halves as unique Colored instances with the
     /// same modifiers, background color, and main color still applied.
     pub fn split (&self, mid_point: usize) -> (Colored, Colored) {
@@ -450,35 +704,168 @@ impl Colored {
                 mods: self.mods.clone(),
                 color: self.color.clone(),
                 bg_color: self.bg_color.clone(),
+                link: self.link.clone(),
             },
             Colored {
                 text: self.text[mid_point..].to_string(),
                 mods: self.mods.clone(),
                 color: self.color.clone(),
                 bg_color: self.bg_color.clone(),
+                link: self.link.clone(),
             }
         )
     }
     
+    // gets the characters in the given range (by char index rather than byte index) keeping the styling
+    fn slice_chars (&self, start: usize, end: usize) -> Colored {
+        let byte_index = |index: usize| self.text.char_indices().nth(index).map(|(byte, _)| byte).unwrap_or(self.text.len());
+        let (_, right) = self.split(byte_index(start));
+        let (middle, _) = right.split(byte_index(end) - byte_index(start));
+        middle
+    }
+
+    // gets the characters within the first `columns` terminal columns, keeping the styling
+    // a wide character split by the boundary is replaced by a space so the result is exactly `columns` wide
+    fn slice_columns (&self, columns: usize) -> Colored {
+        let mut width = 0;
+        let mut end = self.text.len();
+        for (byte, chr) in self.text.char_indices() {
+            let chr_width = chr.width().unwrap_or(0);
+            if width + chr_width > columns {
+                end = byte;
+                break;
+            }
+            width += chr_width;
+        }
+        let (mut left, _) = self.split(end);
+        left.text.push_str(&" ".repeat(columns.saturating_sub(width)));
+        left
+    }
+
     /// Checks if the Colored instance has no colors or modifiers applied.
     pub fn is_uncolored (&self) -> bool {
         self.mods.is_empty() && self.color.is_none() && self.bg_color.is_none()
     }
 
     /// Checks if the given color type is contained within the current Colored instance.
+    /// The colors have to match exactly (i.e. `Rgb(1, 2, 3)` doesn't contain `Red`).
+    /// # Example:
+    /// ```
+    /// use term_render::render::{Colorize, ColorType};
+    /// let colored = "text".colorizes(vec![ColorType::Black, ColorType::Bold]);  // black is "30"
+    /// assert!(colored.contains(&ColorType::Black));
+    /// assert!(colored.contains(&ColorType::Bold));
+    /// assert!(!colored.contains(&ColorType::Italic));  // italic is "3"
+    /// ```
     pub fn contains (&self, color: &ColorType) -> bool {
         let col = color.get_color().unwrap_into_tuple();
-        if col.0 == self.bg_color && col.2 {  return true;  }
-        if let Some(self_color) = &self.color {
-            if let Some(other_col) = col.0 {
-                if self_color.contains(&other_col) {  return true;  }
-            }
+        if let Some(code) = col.0 {
+            let current = match col.2 {
+                true => &self.bg_color,
+                false => &self.color,
+            };
+            if current.as_ref() == Some(&code) {  return true;  }
         }
         for modifier in col.1 {
             if self.mods.contains(&modifier) {  return true;  }
         } false
     }
 
+    /// Removes the given color, background, or modifier if it's applied.
+    /// Returns true if anything was removed.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{Colorize, ColorType};
+    /// let mut colored = "text".colorizes(vec![ColorType::Red, ColorType::Bold, ColorType::Italic]);
+    /// assert!(colored.remove_color(ColorType::Bold));
+    /// assert!(!colored.remove_color(ColorType::Bold));
+    /// assert_eq!(colored.mods(), vec![ColorType::Italic]);
+    /// assert!(colored.remove_color(ColorType::Red));
+    /// assert_eq!(colored.fg(), None);
+    /// ```
+    pub fn remove_color (&mut self, color: ColorType) -> bool {
+        if !self.contains(&color) {  return false;  }
+        let (code, mods, background) = color.get_color().unwrap_into_tuple();
+        if code.is_some() {
+            match background {
+                true => self.bg_color = None,
+                false => self.color = None,
+            }
+        }
+        self.mods.retain(|modifier| !mods.contains(modifier));
+        true
+    }
+
+    /// Removes every modifier (bold, italic, etc.), keeping the colors.
+    pub fn clear_mods (&mut self) {
+        self.mods.clear();
+    }
+
+    /// Removes the foreground color, keeping the background and modifiers.
+    pub fn clear_fg (&mut self) {
+        self.color = None;
+    }
+
+    /// Removes the background color, keeping the foreground and modifiers.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{Colorize, ColorType};
+    /// let mut colored = "text".colorizes(vec![ColorType::Green, ColorType::OnRGB(10, 20, 30)]);
+    /// colored.clear_bg();
+    /// assert_eq!((colored.fg(), colored.bg()), (Some(ColorType::Green), None));
+    /// colored.clear_fg();
+    /// assert!(colored.is_uncolored());
+    /// ```
+    pub fn clear_bg (&mut self) {
+        self.bg_color = None;
+    }
+
+    /// Sets (or clears, with None) the foreground color, replacing any existing one.
+    /// Backgrounds and modifiers aren't foreground colors, so they're ignored.
+    pub fn set_fg (&mut self, color: Option <ColorType>) {
+        let Some(color) = color else {
+            self.color = None;
+            return;
+        };
+        let (code, _mods, background) = color.get_color().unwrap_into_tuple();
+        if !background && code.is_some() {  self.color = code;  }
+    }
+
+    /// Sets (or clears, with None) the background color, replacing any existing one.
+    /// Foreground colors and modifiers aren't backgrounds, so they're ignored.
+    pub fn set_bg (&mut self, color: Option <ColorType>) {
+        let Some(color) = color else {
+            self.bg_color = None;
+            return;
+        };
+        let (code, _mods, background) = color.get_color().unwrap_into_tuple();
+        if background && code.is_some() {  self.bg_color = code;  }
+    }
+
+    /// Gets the foreground color, if there is one and it maps back to a `ColorType`.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{Colorize, ColorType};
+    /// let mut colored = "text".colorizes(vec![ColorType::Rgb(200, 100, 50), ColorType::OnANSI(17)]);
+    /// assert_eq!(colored.fg(), Some(ColorType::Rgb(200, 100, 50)));
+    /// assert_eq!(colored.bg(), Some(ColorType::OnANSI(17)));
+    /// colored.set_fg(Some(ColorType::BrightCyan));
+    /// assert_eq!(colored.fg(), Some(ColorType::BrightCyan));
+    /// ```
+    pub fn fg (&self) -> Option <ColorType> {
+        ColorType::from_escape_code(self.color.as_ref()?, false)
+    }
+
+    /// Gets the background color, if there is one and it maps back to a `ColorType`.
+    pub fn bg (&self) -> Option <ColorType> {
+        ColorType::from_escape_code(self.bg_color.as_ref()?, true)
+    }
+
+    /// Gets the modifiers (bold, italic, etc.) which map back to a `ColorType`, in the order they were added.
+    pub fn mods (&self) -> Vec <ColorType> {
+        self.mods.iter().filter_map(|modifier| ColorType::from_escape_code(modifier, false)).collect()
+    }
+
     /// Changes the underlying text for the Colored text token.
     pub fn change_text (&mut self, text: String) {
         self.text = text;
@@ -499,21 +886,35 @@ impl Colored {
     /// Adds a `UniqueColor` to the current `Colored` instance.
     /// This function updates the color, background color, and modifiers
     /// of the `Colored` instance based on the provided `UniqueColor`.
-    /// If the `UniqueColor` specifies a background color, it will overwrite
-    /// the existing background color. If it specifies a text color, it will
-    /// overwrite the existing text color only if it is not `None`.
-    /// Modifiers are appended to the existing list of modifiers.
+    /// If the `UniqueColor` specifies a color (background or text), it overwrites the existing one;
+    /// a `None` color leaves the existing one alone (use `clear_fg` or `clear_bg` to remove them).
+    /// Modifiers are appended to the existing list of modifiers (unless they're already applied,
+    /// so repeatedly colorizing the same text doesn't keep growing its escape codes).
     /// Typically, other member functions such as `add_color` allow for
     /// the adding a `ColorType`, which is a higher level abstraction
     /// that is converted into a `UniqueColor` internally.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{Colorize, ColorType, UniqueColor, EMPTY_MODIFIER_REFERENCE};
+    /// let mut colored = "text".colorizes(vec![ColorType::Red, ColorType::OnRGB(10, 20, 30)]);
+    /// // a background flagged modifier without a color keeps the existing background
+    /// colored.add_unique(UniqueColor::Dynamic((None, &["1"], true)));
+    /// assert_eq!(colored.bg(), Some(ColorType::OnRGB(10, 20, 30)));
+    /// assert_eq!(colored.mods(), vec![ColorType::Bold]);
+    /// colored.add_unique(UniqueColor::Dynamic((None, EMPTY_MODIFIER_REFERENCE, false)));
+    /// assert_eq!(colored.fg(), Some(ColorType::Red));
+    /// ```
     pub fn add_unique (&mut self, unique_color: UniqueColor) {
         let (color, mods, background) = unique_color.unwrap_into_tuple();
-        if background {  self.bg_color = color;  }
-        else if let Some(col) = color {
-            // making sure to not overwrite the existing color if this is None
-            self.color = Some(col);
+        // making sure to not overwrite the existing colors if this is None
+        if let Some(col) = color {
+            match background {
+                true => self.bg_color = Some(col),
+                false => self.color = Some(col),
+            }
         }
         for modifier in mods {
+            if self.mods.contains(&modifier) {  continue;  }
             self.mods.push(modifier);
         }
     }
@@ -527,6 +928,7 @@ impl Colored {
             mods: colored.mods.clone(),
             color: colored.color.clone(),
             bg_color: colored.bg_color.clone(),
+            link: colored.link.clone(),
         };
         for color in colors {
             colored.add_color(color);
@@ -556,47 +958,68 @@ impl Colored {
         } colored
     }
 
-    /// Applies the specified colors to the specified text and returns the resulting string.
+    /// Applies the specified colors (and hyperlink, if any) to the specified text and returns the resulting string.
     /// The `last_color` parameter is used to optimize the output by avoiding redundant color escape codes.
     /// Returns the generated text and the character count of the text (not including escape codes).
+    /// This allocates a new string each call; `push_text` writes into an existing one instead.
     pub fn get_text (&self, last_color: &mut String) -> (String, usize) {
         let mut text = String::new();
+        let size = self.push_text(last_color, &mut text);
+        (text, size)
+    }
 
-        let col = match &self.color {
-            Some(colr) => colr,
-            _ => &String::new()
-        };
-
-        let bg_col = match &self.bg_color {
-            Some(colr) => colr,
-            _ => &String::new()
-        };
-
-        let color = match
-            (self.bg_color.is_some(), self.color.is_some(), !self.mods.is_empty())
-        {
-            (true, true, true) => format!("\x1b[0;{};{};{}m", col, bg_col, self.mods.join(";")),
-            (true, true, false) => format!("\x1b[0;{};{}m", col, bg_col),
-            (false, true, true) => format!("\x1b[0;{};{}m", col, self.mods.join(";")),
-            (false, true, false) => format!("\x1b[0;{}m", col),
-            (true, false, true) => format!("\x1b[0;{};{}m", bg_col, self.mods.join(";")),
-            (true, false, false) => format!("\x1b[0;{}m", bg_col),
-            (false, false, _) => String::from("\x1b[0m"),
-        };
-
-        if color != *last_color {
-            //text.push_str(CLEAR);
-            text.push_str(&color);
-            *last_color = color;
+    /// Appends the colored text (and hyperlink, if any) to the end of `text`, reusing its buffer rather
+    /// than allocating (see `get_text`). The color's escape code is skipped if it matches `last_color`.
+    /// Returns the character count of the text (not including escape codes).
+    /// # Example:
+    /// ```
+    /// use term_render::render::{Colorize, ColorType};
+    /// let mut last_color = String::new();
+    /// let mut text = String::new();
+    /// let size = "hi".colorize(ColorType::Red).push_text(&mut last_color, &mut text);
+    /// "!".colorize(ColorType::Red).push_text(&mut last_color, &mut text);
+    /// assert_eq!(size, 2);
+    /// assert_eq!(text, "\x1b[0;31mhi!");  // the repeated color isn't written twice
+    /// ```
+    pub fn push_text (&self, last_color: &mut String, text: &mut String) -> usize {
+        // without color, the text goes out as it is (still taking up the same space)
+        if !is_color_enabled() {
+            text.push_str(&self.text);
+            return get_text_width(&self.text);
+        }
+        // writing the escape code in place, and taking it back off if it's the same as the last one
+        let start = text.len();
+        text.push_str("\x1b[0");
+        let reduced_motion = is_reduced_motion();
+        for code in self.color.iter().chain(&self.bg_color).chain(&self.mods) {
+            if reduced_motion && code == BLINK.1[0] {  continue;  }
+            text.push(';');
+            text.push_str(code);
+        }
+        text.push('m');
+        if text[start..] == **last_color {
+            text.truncate(start);
+        } else {
+            last_color.clear();
+            last_color.push_str(&text[start..]);
         }
 
-        text.push_str(&self.text);
-        (text, self.text.chars().count())
+        // hyperlinks wrap just this text, so they never leak into the next token
+        match &self.link {
+            Some(url) => {
+                push_hyperlink(text, Some(url));
+                text.push_str(&self.text);
+                push_hyperlink(text, None);
+            },
+            None => text.push_str(&self.text),
+        }
+        get_text_width(&self.text)
     }
 
-    /// Gets the total character count of the word.
+    /// Gets the number of terminal columns the text takes up (wide characters take two,
+    /// and zero width characters such as combining marks take none).
     pub fn get_size (&self) -> usize {
-        self.text.chars().count()
+        get_text_width(&self.text)
     }
 }
 
@@ -617,7 +1040,7 @@ impl Span {
         }
     }
 
-    /// Gets the total character count of the span (not including escape codes).
+    /// Gets the number of terminal columns the span takes up (not including escape codes).
     pub fn size (&self) -> usize {
         let mut size = 0;
         for colored in &self.line {
@@ -626,20 +1049,178 @@ impl Span {
         size
     }
 
+    /// Appends a `Colored` segment to the end of the span.
+    pub fn push (&mut self, colored: Colored) {
+        self.line.push(colored);
+    }
+
+    /// Appends the segments of another span to the end of this one.
+    pub fn append (&mut self, other: Span) {
+        self.line.extend(other.line);
+    }
+
+    /// Clamps the span to a maximum number of terminal columns, keeping the styling of each segment
+    /// (the `Span` equivalent of clamping a rendered line to its visible UTF-8 characters).
+    /// A wide character cut in half by the boundary is replaced with a space.
+    pub fn clamp (&self, max_length: usize) -> Span {
+        let mut remaining = max_length;
+        let mut line = vec![];
+        for colored in &self.line {
+            if remaining == 0 {  break;  }
+            let size = colored.get_size();
+            if size <= remaining {
+                line.push(colored.clone());
+            } else {
+                line.push(colored.slice_columns(remaining));
+            }
+            remaining = remaining.saturating_sub(size);
+        }
+        Span { line }
+    }
+
+    /// Adds a `ColorType` to every `Colored` segment of the span (such as to highlight a whole line).
+    pub fn add_color (&mut self, color: ColorType) {
+        for colored in &mut self.line {
+            colored.add_color(color);
+        }
+    }
+
     /// Joins the colored segments into a single string, applying necessary color codes.
-    /// Returns the combined string and its total character count (the actual character count, not
-    /// including the characters consumed by escape codes).
+    /// Returns the combined string and its total width in terminal columns (not including the
+    /// characters consumed by escape codes).
+    /// This allocates a new string each call; `join_into` writes into an existing one instead.
     pub fn join (&self) -> (String, usize) {
-        //let mut lastColored = vec![];
-        let mut last_colored = String::new();
-        let mut total = String::new();
+        // roughly sized for the text and an escape code per segment, so it rarely needs to grow
+        let mut total = String::with_capacity(self.line.iter().map(|colored| colored.text.len() + 16).sum());
+        let total_size = self.join_into(&mut total);
+        (total, total_size)
+    }
+
+    /// Appends the joined segments to the end of `text` (see `join`), reusing its buffer rather than allocating.
+    /// Returns the width of the span in terminal columns.
+    /// # Example:
+    /// ```
+    /// use term_render::render::Span;
+    /// let mut text = String::from("> ");
+    /// let size = Span::from("hello").join_into(&mut text);
+    /// assert_eq!(size, 5);
+    /// assert_eq!(text, "> \x1b[0mhello");
+    /// ```
+    pub fn join_into (&self, text: &mut String) -> usize {
+        let mut last_colored = String::with_capacity(16);
         let mut total_size = 0;
         for colored in &self.line {
-            let (text, size) = colored.get_text(&mut last_colored);
-            total.push_str(&text);
-            total_size += size;
+            total_size += colored.push_text(&mut last_colored, text);
+        } total_size
+    }
+
+    /// Creates a span of a single segment of text with one color or modifier applied.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{ColorType, Span};
+    /// let warning = Span::styled("careful", ColorType::Yellow) + Span::from(" (this can't be undone)");
+    /// assert_eq!(warning.size(), 30);
+    /// assert_eq!(warning.get_tokens().len(), 2);
+    /// ```
+    pub fn styled (text: &str, color: ColorType) -> Span {
+        Span::from_tokens(vec![Colored::get_from_color_types_str(text, vec![color])])
+    }
+}
+
+/// Concatenates two spans, keeping the styling of every segment.
+/// # Example:
+/// ```
+/// use term_render::render::{Colorize, ColorType, Span};
+/// let mut line = Span::from("Status: ") + Span::styled("ok", ColorType::Green);
+/// line += Span::from(" (3 checks)");
+/// line.push(" done".colorize(ColorType::Bold));
+/// assert_eq!(line.size(), 26);
+/// assert_eq!(line.get_tokens().len(), 4);
+/// ```
+impl std::ops::Add for Span {
+    type Output = Span;
+
+    fn add (mut self, other: Span) -> Span {
+        self.append(other);
+        self
+    }
+}
+
+/// Appends the segments of another span (see `Span::append`).
+impl std::ops::AddAssign for Span {
+    fn add_assign (&mut self, other: Span) {
+        self.append(other);
+    }
+}
+
+/// A span of a single segment.
+/// # Example:
+/// ```
+/// use term_render::render::{Colorize, ColorType, Span};
+/// let span: Span = "hello".colorize(ColorType::Red).into();
+/// assert_eq!(span.get_tokens().len(), 1);
+/// ```
+impl From <Colored> for Span {
+    fn from (colored: Colored) -> Span {
+        Span::from_tokens(vec![colored])
+    }
+}
+
+/// A span of a single uncolored segment.
+/// # Example:
+/// ```
+/// use term_render::render::Span;
+/// let span = Span::from("plain text");
+/// assert_eq!(span.size(), 10);
+/// assert!(span.get_tokens()[0].is_uncolored());
+/// ```
+impl From <&str> for Span {
+    fn from (text: &str) -> Span {
+        Span::from_tokens(vec![Colored::new(text.to_owned())])
+    }
+}
+
+/// An uncolored segment of text (the same as `Colored::new`).
+/// # Example:
+/// ```
+/// use term_render::render::Colored;
+/// let colored: Colored = format!("{} items", 3).into();
+/// assert_eq!(colored.get_size(), 7);
+/// ```
+impl From <String> for Colored {
+    fn from (text: String) -> Colored {
+        Colored::new(text)
+    }
+}
+
+/// Collects segments into a span, in order.
+/// # Example:
+/// ```
+/// use term_render::render::{Colorize, ColorType, Span};
+/// let span: Span = ["a", "b", "c"].iter().map(|text| text.colorize(ColorType::Blue)).collect();
+/// assert_eq!(span.size(), 3);
+/// ```
+impl FromIterator <Colored> for Span {
+    fn from_iter <I: IntoIterator <Item = Colored>> (iter: I) -> Span {
+        Span::from_tokens(iter.into_iter().collect())
+    }
+}
+
+/// Writes the span with the escape codes of every segment (unlike `join`, each segment's codes are
+/// written out even when they match the previous segment's), followed by a reset.
+/// # Example:
+/// ```
+/// use term_render::render::{ColorType, Span};
+/// let span = Span::from("a") + Span::styled("b", ColorType::Bold);
+/// assert_eq!(span.to_string(), "\x1b[0ma\x1b[0;1mb\x1b[0m");
+/// ```
+impl std::fmt::Display for Span {
+    fn fmt (&self, f: &mut std::fmt::Formatter <'_>) -> std::fmt::Result {
+        for colored in &self.line {
+            write!(f, "{}", colored.get_text(&mut String::new()).0)?;
         }
-        (total, total_size)
+        if self.line.is_empty() {  return Ok(());  }
+        write!(f, "{}", CLEAR)
     }
 }
 
@@ -650,102 +1231,1146 @@ impl Span {
 // This allows a separation/abstraction for individual sections
 // This also allows for a cached window to be reused if temporarily closed
 
-/// A window is a block or section within the terminal space, similar to a paragraph in Ratatui.
-/// Multiple windows can be rendered at once, and each window can contain its own text or logic.
-/// Each widow can be moved, resized, hidden, shown, and colored independently.
-/// Additionally, each window handles its own rendering logic, caching, and other behaviors.
-#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
-pub struct Window {
-    pub position: (u16, u16),
-    pub depth: u16,
-    pub size: (u16, u16),
-    updated: Vec <bool>,
-    was_updated: bool,
+/// The group of windows a window is drawn with (see `Window::set_layer`). Every window in a higher layer is
+/// drawn over every window in a lower layer, whatever their depths; depths only order the windows within a layer.
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash, Copy, PartialOrd, Ord)]
+pub enum Layer {
+    /// Beneath every other window (i.e. a backdrop).
+    Background,
+    /// Where windows go unless told otherwise.
+    #[default] Normal,
+    /// Above every other window (i.e. notifications).
+    Overlay,
+}
 
-    // (Span, cached render, num visible chars)
-    lines: Vec <(Span, String, usize)>,
+/// The horizontal alignment of the lines within a window.
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash, Copy)]
+pub enum Alignment {
+    #[default] Left,
+    Center,
+    Right,
+}
 
-    bordered: bool,
-    title: (Span, usize),
-    color: Colored,
-    pub hidden: bool,
+/// The glyphs a border is drawn with.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Copy)]
+pub struct BorderChars {
+    pub top_left: char,
+    pub top: char,
+    pub top_right: char,
+    pub left: char,
+    pub right: char,
+    pub bottom_left: char,
+    pub bottom: char,
+    pub bottom_right: char,
 }
 
-/// A type representing a closure that returns a String when called.
-/// The type is Send so that it can be sent into a background thread for rendering.
-/// The render closures are used to handle the rendering of windows in a background thread.
-type RenderClosure = Vec <(Box <dyn FnOnce () -> String + Send>, u16, u16, u16)>;
+/// The style of a window's border.
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash, Copy)]
+pub enum BorderStyle {
+    /// `┌─┐` single lines with square corners.
+    #[default] Plain,
+    /// `╭─╮` single lines with rounded corners.
+    Rounded,
+    /// `╔═╗` double lines.
+    Double,
+    /// `┏━┓` heavy lines.
+    Thick,
+    /// `+-+` plain ascii, for terminals and fonts without box drawing characters.
+    Ascii,
+    /// Any set of glyphs.
+    Custom (BorderChars),
+}
 
-impl Window {
-    /// Creates a new window at the given position with the given size.
-    /// The position is given as (x, y) coordinates.
-    /// The size is given as (width, height).
-    /// The depth is used to determine the rendering order of windows.
-    /// Windows with a higher depth are rendered on top of windows with a lower depth.
-    pub fn new (position: (u16, u16), depth: u16, size: (u16, u16)) -> Self {
-        Window {
-            position,
-            depth,
-            size,
-            updated: vec![false; size.1 as usize],
-            was_updated: false,
-            lines: vec![],
-            bordered: false,
-            title: (Span::default(), 0),
-            color: Colored::new(String::new()),  // format!("\x1b[38;2;{};{};{}m", 125, 125, 0),//String::new(),
-            hidden: false,
+impl BorderStyle {
+    /// Gets the glyphs the style is drawn with.
+    pub fn get_chars (&self) -> BorderChars {
+        let chars = |corners: [char; 4], horizontal: char, vertical: char| BorderChars {
+            top_left: corners[0],
+            top: horizontal,
+            top_right: corners[1],
+            left: vertical,
+            right: vertical,
+            bottom_left: corners[2],
+            bottom: horizontal,
+            bottom_right: corners[3],
+        };
+        match self {
+            BorderStyle::Plain => chars(['┌', '┐', '└', '┘'], '─', '│'),
+            BorderStyle::Rounded => chars(['╭', '╮', '╰', '╯'], '─', '│'),
+            BorderStyle::Double => chars(['╔', '╗', '╚', '╝'], '═', '║'),
+            BorderStyle::Thick => chars(['┏', '┓', '┗', '┛'], '━', '┃'),
+            BorderStyle::Ascii => chars(['+', '+', '+', '+'], '-', '|'),
+            BorderStyle::Custom(chars) => *chars,
         }
     }
+}
 
-    /// Hides the window. Returns true if the window was visible before.
-    /// Returns false if the window was already hidden.
-    /// Additionally, this will only mark the window to update if it was visible before.
-    pub fn hide (&mut self) -> bool {
-        if self.hidden {  return false;  }
-        self.hidden = true;
-        self.update_all();
-        true
+/// Which sides of a window have a border, as a set of flags which can be combined with `|`
+/// (i.e. `BorderSides::TOP | BorderSides::BOTTOM`). By default, every side is drawn.
+/// The title is drawn in the top border, so it's hidden without one.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Copy)]
+pub struct BorderSides (u8);
+
+impl BorderSides {
+    pub const NONE: BorderSides = BorderSides(0);
+    pub const TOP: BorderSides = BorderSides(1);
+    pub const BOTTOM: BorderSides = BorderSides(2);
+    pub const LEFT: BorderSides = BorderSides(4);
+    pub const RIGHT: BorderSides = BorderSides(8);
+    pub const ALL: BorderSides = BorderSides(15);
+
+    /// Checks if every side in `other` is also in this set.
+    pub fn contains (&self, other: BorderSides) -> bool {
+        self.0 & other.0 == other.0
     }
 
-    /// Makes the window visible. Returns true if the window was hidden before.
-    /// Returns false if the window was already visible.
-    /// Additionally, this will only mark the window to update if it was hidden before.
-    pub fn show(&mut self) -> bool {
-        if !self.hidden {  return false;  }
-        self.hidden = false;
-        self.update_all();
-        true
+    /// Checks if no sides are set.
+    pub fn is_empty (&self) -> bool {
+        self.0 == 0
     }
+}
 
-    /// Tries to move the window to a new position.
-    /// The window is only updated if the position is different from before.
-    /// If the position is the same as before, nothing happens and the window is not marked
-    /// for an update.
-    pub fn r#move (&mut self, new_position: (u16, u16)) {
-        if new_position == self.position {  return;  }
-        self.position = new_position;
-        self.update_all();
+impl Default for BorderSides {
+    fn default () -> Self {
+        BorderSides::ALL
     }
+}
 
-    /// Applies multiple colors to the window.
-    /// This will always mark the window to update.
-    pub fn colorizes (&mut self, colors: Vec <ColorType>) {
-        for color in colors {
-            self.color.add_color(color);
-        } self.update_all();
+impl std::ops::BitOr for BorderSides {
+    type Output = BorderSides;
+    fn bitor (self, other: BorderSides) -> BorderSides {
+        BorderSides(self.0 | other.0)
     }
+}
 
-    /// Adds a color to the window.
-    /// This will always mark the window to update.
-    pub fn colorize (&mut self, color: ColorType) {
-        self.color.add_color(color);
-        self.update_all();
+/// The border glyphs on the left and right of a row of a window (None where that side has no border).
+pub type RowBorder = (Option <char>, Option <char>);
+
+/// The blank space between a window's border (or edge) and its content, in cells.
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash, Copy)]
+pub struct Padding {
+    pub left: u16,
+    pub right: u16,
+    pub top: u16,
+    pub bottom: u16,
+}
+
+impl Padding {
+    /// Creates padding with the same amount on every side.
+    pub fn uniform (amount: u16) -> Self {
+        Padding { left: amount, right: amount, top: amount, bottom: amount }
     }
+}
 
-    /// Tries to add a color to the window.
-    /// Returns true if the color was added.
-    /// Returns false if the color was already present.
-    /// Additionally, this will only mark the window to update if the color was added.
+/// Which way a window's scrollbar runs (see `ScrollbarConfig`).
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash, Copy)]
+pub enum ScrollbarOrientation {
+    /// Down the last interior column, following the scroll offset through the lines.
+    #[default] Vertical,
+    /// Along the last interior row, showing how much of the widest line fits (windows don't scroll
+    /// sideways, so the thumb always starts at the left).
+    Horizontal,
+}
+
+/// The look of a window's scrollbar (see `Window::set_scrollbar`).
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Copy)]
+pub struct ScrollbarConfig {
+    pub orientation: ScrollbarOrientation,
+    /// The glyph drawn along the length of the bar.
+    pub track_char: char,
+    /// The glyph drawn for the part of the bar showing the visible content.
+    pub thumb_char: char,
+    /// Whether the bar is left blank while all the content fits (its cells stay reserved either way).
+    pub auto_hide: bool,
+}
+
+impl Default for ScrollbarConfig {
+    fn default () -> Self {
+        ScrollbarConfig {
+            orientation: ScrollbarOrientation::Vertical,
+            track_char: '│',
+            thumb_char: '█',
+            auto_hide: true,
+        }
+    }
+}
+
+/// How lines wider than a window are handled.
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash, Copy)]
+pub enum WrapMode {
+    /// Lines are cut off at the edge of the window.
+    #[default] None,
+    /// Lines are broken at spaces where possible, falling back to character breaks for long words.
+    Word,
+    /// Lines are broken at exactly the width of the window.
+    Char,
+}
+
+impl Span {
+    /// Splits the span into rows no wider than the given width (in terminal columns), keeping the
+    /// styling of each `Colored` segment on every fragment. An empty span produces a single empty row.
+    /// A character wider than the row is given a row of its own.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{Colored, Span, WrapMode};
+    /// let rows = Span::from_tokens(vec![Colored::new(String::from("ab cd"))]).wrap(3, WrapMode::Word);
+    /// assert_eq!(rows.iter().map(Span::size).collect::<Vec<_>>(), vec![2, 2]);
+    /// // each character is two columns wide, so none of them fit in a single column
+    /// for mode in [WrapMode::Word, WrapMode::Char] {
+    ///     let rows = Span::from_tokens(vec![Colored::new(String::from("日本語"))]).wrap(1, mode);
+    ///     assert_eq!(rows.iter().map(Span::size).collect::<Vec<_>>(), vec![2, 2, 2]);
+    /// }
+    /// ```
+    pub fn wrap (&self, width: usize, mode: WrapMode) -> Vec <Span> {
+        let text: Vec <char> = self.line.iter().flat_map(|colored| colored.text.chars()).collect();
+        if mode == WrapMode::None || width == 0 || self.size() <= width {
+            return vec![self.clone()];
+        }
+        let widths: Vec <usize> = text.iter().map(|chr| chr.width().unwrap_or(0)).collect();
+
+        // finding the (start, end) char range of each row
+        let mut ranges = vec![];
+        let mut start = 0;
+        while start < text.len() {
+            // the end is the first character which doesn't fit (wide characters aren't split between rows)
+            let mut columns = 0;
+            let mut end = start;
+            while end < text.len() && columns + widths[end] <= width {
+                columns += widths[end];
+                end += 1;
+            }
+            if end == text.len() {
+                ranges.push((start, end));
+                break;
+            }
+            let end = end.max(start + 1);  // a character wider than the row still has to go somewhere
+            // the space right after a full row is also a valid break
+            let space = match mode {
+                WrapMode::Word => (start + 1..=end.min(text.len() - 1)).rev().find(|index| text[*index] == ' '),
+                _ => None,
+            };
+            match space {
+                Some(space) => {
+                    ranges.push((start, space));
+                    start = space + 1;  // the space itself is dropped
+                },
+                None => {
+                    ranges.push((start, end));
+                    start = end;
+                },
+            }
+        }
+
+        ranges.into_iter().map(|(row_start, row_end)| {
+            let mut tokens = vec![];
+            let mut offset = 0;
+            for colored in &self.line {
+                let size = colored.text.chars().count();
+                let (token_start, token_end) = (offset, offset + size);
+                offset = token_end;
+                if token_end <= row_start || token_start >= row_end {  continue;  }
+                tokens.push(colored.slice_chars(
+                    row_start.saturating_sub(token_start),
+                    usize::min(row_end, token_end) - token_start
+                ));
+            } Span::from_tokens(tokens)
+        }).collect()
+    }
+}
+
+impl Span {
+    /// Creates a span with an `Rgb` color linearly interpolated from `from` to `to` across the text.
+    /// The colors are spread over the text's terminal columns (so wide characters take two steps), and
+    /// neighbouring characters with the same color share a single `Colored` token.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{Colored, ColorType, Span};
+    /// let span = Span::gradient("abc", (0, 0, 0), (200, 100, 0));
+    /// assert_eq!(span.size(), 3);
+    /// let colors: Vec<Option<ColorType>> = span.get_tokens().iter().map(Colored::fg).collect();
+    /// assert_eq!(colors, vec![
+    ///     Some(ColorType::Rgb(0, 0, 0)), Some(ColorType::Rgb(100, 50, 0)), Some(ColorType::Rgb(200, 100, 0)),
+    /// ]);
+    /// // a flat gradient collapses into a single token
+    /// assert_eq!(Span::gradient("hello", (9, 9, 9), (9, 9, 9)).get_tokens().len(), 1);
+    /// ```
+    pub fn gradient (text: &str, from: (u8, u8, u8), to: (u8, u8, u8)) -> Span {
+        Span::gradient_multi(text, &[(0.0, from), (1.0, to)])
+    }
+
+    /// Creates a span with an `Rgb` color interpolated between multiple stops across the text.
+    /// Each stop is a position in the range [0, 1] (the start to the end of the text) and a color;
+    /// text before the first stop or after the last one uses that stop's color. Without any stops,
+    /// the text is left uncolored.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{Colored, ColorType, Span};
+    /// let span = Span::gradient_multi("abcde", &[(0.0, (255, 0, 0)), (0.5, (0, 255, 0)), (1.0, (0, 0, 255))]);
+    /// assert_eq!(span.get_tokens()[2].fg(), Some(ColorType::Rgb(0, 255, 0)));
+    /// ```
+    pub fn gradient_multi (text: &str, stops: &[(f32, (u8, u8, u8))]) -> Span {
+        Span::gradient_tokens(text, stops, false)
+    }
+
+    /// Creates a span with an `OnRGB` background interpolated from `from` to `to` across the text
+    /// (the background version of `gradient`).
+    pub fn gradient_background (text: &str, from: (u8, u8, u8), to: (u8, u8, u8)) -> Span {
+        Span::gradient_multi_background(text, &[(0.0, from), (1.0, to)])
+    }
+
+    /// Creates a span with an `OnRGB` background interpolated between multiple stops across the text
+    /// (the background version of `gradient_multi`).
+    pub fn gradient_multi_background (text: &str, stops: &[(f32, (u8, u8, u8))]) -> Span {
+        Span::gradient_tokens(text, stops, true)
+    }
+
+    /// Gets the colored tokens making up the span.
+    pub fn get_tokens (&self) -> &[Colored] {
+        &self.line
+    }
+
+    fn gradient_tokens (text: &str, stops: &[(f32, (u8, u8, u8))], background: bool) -> Span {
+        if stops.is_empty() {  return Span::from_tokens(vec![Colored::new(text.to_owned())]);  }
+        let mut stops = stops.to_vec();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let width = get_text_width(text);
+
+        let mut tokens: Vec <(String, (u8, u8, u8))> = vec![];
+        let mut column = 0;
+        for chr in text.chars() {
+            let chr_width = chr.width().unwrap_or(0);
+            // zero width characters (i.e. combining marks) stay with the character before them
+            if chr_width == 0 && let Some(last) = tokens.last_mut() {
+                last.0.push(chr);
+                continue;
+            }
+            let position = match width > 1 {
+                true => column as f32 / (width - 1) as f32,
+                false => 0.0,
+            };
+            let color = Span::get_gradient_color(&stops, position);
+            column += chr_width;
+            match tokens.last_mut() {
+                Some(last) if last.1 == color => last.0.push(chr),
+                _ => tokens.push((chr.to_string(), color)),
+            }
+        }
+
+        Span::from_tokens(tokens.into_iter().map(|(text, (r, g, b))| {
+            let color = match background {
+                true => ColorType::OnRGB(r, g, b),
+                false => ColorType::Rgb(r, g, b),
+            };
+            text.colorize(color)
+        }).collect())
+    }
+
+    // the color at the given position along the (sorted) stops
+    fn get_gradient_color (stops: &[(f32, (u8, u8, u8))], position: f32) -> (u8, u8, u8) {
+        let Some(end) = stops.iter().position(|stop| stop.0 >= position) else {
+            return stops[stops.len() - 1].1;
+        };
+        if end == 0 {  return stops[0].1;  }
+        let (start, end) = (stops[end - 1], stops[end]);
+        let t = match end.0 > start.0 {
+            true => (position - start.0) / (end.0 - start.0),
+            false => 1.0,
+        };
+        let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        (lerp(start.1.0, end.1.0), lerp(start.1.1, end.1.1), lerp(start.1.2, end.1.2))
+    }
+}
+
+/// Alternating background colors applied beneath the content of a window's rows (zebra striping).
+/// The stripes are anchored to logical rows: the first line of the window's content is logical row
+/// `start_row` (scrolling the window doesn't shift the stripes), so widgets that only hand the window
+/// their visible lines should pass their own scroll offset to keep the stripes from shifting. The first `header_rows` content lines (i.e. sticky headers) aren't striped
+/// and don't count towards the phase.
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+pub struct RowStriping {
+    pub even: Option <ColorType>,
+    pub odd: Option <ColorType>,
+    pub start_row: usize,
+    pub header_rows: usize,
+}
+
+impl RowStriping {
+    /// Gets the background escape code for the given content line of the window, if it's striped.
+    pub fn get_background (&self, line: usize) -> Option <String> {
+        if line < self.header_rows {  return None;  }
+        let logical_row = self.start_row + line - self.header_rows;
+        let color = match logical_row % 2 {
+            0 => self.even?,
+            _ => self.odd?,
+        };
+        // only backgrounds make sense as stripes
+        let (code, _mods, is_background) = color.get_color().unwrap_into_tuple();
+        if !is_background {  return None;  }
+        Some(format!("\x1b[{}m", code?))
+    }
+}
+
+/// A window is a block or section within the terminal space, similar to a paragraph in Ratatui.
+/// Multiple windows can be rendered at once, and each window can contain its own text or logic.
+/// Each widow can be moved, resized, hidden, shown, and colored independently.
+/// Additionally, each window handles its own rendering logic, caching, and other behaviors.
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+pub struct Window {
+    pub position: (u16, u16),
+    pub depth: u16,
+    // the group of windows it's drawn with, before the depth is considered (see `set_layer`)
+    layer: Layer,
+    pub size: (u16, u16),
+    updated: Vec <bool>,
+    was_updated: bool,
+
+    // (Span, cached render and num visible chars) the cache is dropped whenever the span is replaced
+    lines: Vec <(Span, Option <(String, usize)>)>,
+    // the number of lines joined into the cache so far (see `get_join_count`)
+    joins: usize,
+    // whether the window moved (or its clip changed) since it was last drawn, which only needs the
+    // rows redrawn in their new place rather than re-rendered
+    moved: bool,
+
+    bordered: bool,
+    title: (Span, usize),
+    title_alignment: Alignment,
+    bottom_title: (Span, usize),
+    bottom_title_alignment: Alignment,
+    color: Colored,
+    pub hidden: bool,
+    // the visible region (position, size); anything outside of it isn't drawn
+    clip: Option <((u16, u16), (u16, u16))>,
+    striping: Option <RowStriping>,
+    // the index of the first line shown in the window (the lines above it are scrolled out of view)
+    scroll_offset: usize,
+    wrap_mode: WrapMode,
+    // the visual rows when wrapping is enabled (the scroll offset and updated rows refer to these)
+    wrapped: Vec <Span>,
+    wrap_outdated: bool,
+    alignment: Alignment,
+    // padding cells are skipped rather than painted, so lower windows show through them
+    transparent: bool,
+    // the rows from the last time the window was composited into a frame (see `App::render`)
+    rendered_rows: Vec <String>,
+    // the theme slot the border and title are colored with (see `Theme`)
+    theme_slot: Option <String>,
+    border_style: BorderStyle,
+    border_sides: BorderSides,
+    padding: Padding,
+    scrollbar: Option <ScrollbarConfig>,
+    // the scrollbar's cells (None where it's blank) as of the last render, to find the rows which changed
+    scrollbar_cells: Vec <Option <char>>,
+    // the image placed over the content area, and the protocol it's shown with (see `set_image`)
+    #[cfg(feature = "images")]
+    image: Option <(Image, ImageProtocol)>,
+}
+
+/// A type representing a closure that returns a String when called.
+/// The type is Send so that it can be sent into a background thread for rendering.
+/// The render closures are used to handle the rendering of windows in a background thread.
+type RenderClosure = Vec <(Box <dyn FnOnce () -> String + Send>, u16, u16, u16)>;
+
+// decides whether a window matches, given its keywords
+type WindowKey = Box <dyn Fn (&Vec <String>) -> bool>;
+
+impl Window {
+    /// Creates a new window at the given position with the given size.
+    /// The position is given as (x, y) coordinates, starting from (0, 0) in the top left corner of the
+    /// terminal (they're only converted to the terminal's 1-based cursor addressing when drawn).
+    /// The size is given as (width, height).
+    /// The depth is used to determine the rendering order of windows.
+    /// Windows with a higher depth are rendered on top of windows with a lower depth.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{App, Span, Window};
+    /// let mut app = App::new_headless(3, 2);
+    /// let mut window = Window::new((0, 0), 0, (1, 1));
+    /// window.add_line(Span::from("x"));
+    /// app.add_window(window, String::from("corner"), vec![]).unwrap();
+    /// assert_eq!(app.snapshot(), vec!["x", ""]);
+    /// // the top left cell is the terminal's row 1, column 1
+    /// let mut output = String::new();
+    /// app.screenshot().diff(None, &mut output);
+    /// assert!(output.starts_with("\x1b[1;1H"));
+    /// assert_eq!(app.screenshot().get(0, 0).unwrap().chr, 'x');
+    /// ```
+    pub fn new (position: (u16, u16), depth: u16, size: (u16, u16)) -> Self {
+        Window {
+            position,
+            depth,
+            layer: Layer::Normal,
+            size,
+            updated: vec![false; size.1 as usize],
+            was_updated: false,
+            lines: vec![],
+            joins: 0,
+            moved: false,
+            bordered: false,
+            title: (Span::default(), 0),
+            title_alignment: Alignment::Center,
+            bottom_title: (Span::default(), 0),
+            bottom_title_alignment: Alignment::Center,
+            color: Colored::new(String::new()),  // format!("\x1b[38;2;{};{};{}m", 125, 125, 0),//String::new(),
+            hidden: false,
+            clip: None,
+            striping: None,
+            scroll_offset: 0,
+            wrap_mode: WrapMode::None,
+            wrapped: vec![],
+            wrap_outdated: false,
+            alignment: Alignment::Left,
+            transparent: false,
+            rendered_rows: vec![],
+            theme_slot: None,
+            border_style: BorderStyle::Plain,
+            border_sides: BorderSides::ALL,
+            padding: Padding::default(),
+            scrollbar: None,
+            scrollbar_cells: vec![],
+            #[cfg(feature = "images")]
+            image: None,
+        }
+    }
+
+    /// Hides the window. Returns true if the window was visible before.
+    /// Returns false if the window was already hidden.
+    /// Additionally, this will only mark the window to update if it was visible before.
+    pub fn hide (&mut self) -> bool {
+        if self.hidden {  return false;  }
+        self.hidden = true;
+        self.update_all();
+        true
+    }
+
+    /// Makes the window visible. Returns true if the window was hidden before.
+    /// Returns false if the window was already visible.
+    /// Additionally, this will only mark the window to update if it was hidden before.
+    pub fn show(&mut self) -> bool {
+        if !self.hidden {  return false;  }
+        self.hidden = false;
+        self.update_all();
+        true
+    }
+
+    /// Sets whether the window is transparent. A transparent window doesn't paint its padding
+    /// (the cells after the end of each line, the alignment padding before it, and empty lines),
+    /// so whatever is below it at a lower depth shows through. The borders and text are still drawn.
+    /// Rows with a striped background (see `set_row_striping`) are always fully painted.
+    pub fn set_transparent (&mut self, transparent: bool) {
+        if self.transparent == transparent {  return;  }
+        self.transparent = transparent;
+        self.update_all();
+    }
+
+    /// Returns whether the window is transparent (see `set_transparent`).
+    pub fn is_transparent (&self) -> bool {
+        self.transparent
+    }
+
+    /// Sets the layer the window is drawn in (`Layer::Normal` by default). Windows are drawn in order of their
+    /// layer, then their depth, and then the order they were added, so a window in the overlay layer is drawn
+    /// over every normal window regardless of the depths the app uses.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{App, Layer, Span, Window};
+    /// let mut app = App::new_headless(5, 1);
+    /// let mut toast = Window::new((0, 0), 0, (5, 1));
+    /// toast.add_line(Span::from("toast"));
+    /// toast.set_layer(Layer::Overlay);
+    /// app.add_window(toast, String::from("toast"), vec![]).unwrap();
+    /// // added later and much deeper, but still beneath the overlay
+    /// let mut popup = Window::new((0, 0), u16::MAX, (5, 1));
+    /// popup.add_line(Span::from("popup"));
+    /// app.add_window(popup, String::from("popup"), vec![]).unwrap();
+    /// assert_eq!(app.snapshot(), vec!["toast"]);
+    ///
+    /// app.get_window_reference_mut(String::from("toast")).set_layer(Layer::Background);
+    /// assert_eq!(app.snapshot(), vec!["popup"]);
+    /// ```
+    pub fn set_layer (&mut self, layer: Layer) {
+        if self.layer == layer {  return;  }
+        self.layer = layer;
+        self.update_all();
+    }
+
+    /// Gets the layer the window is drawn in (see `set_layer`).
+    pub fn get_layer (&self) -> Layer {
+        self.layer
+    }
+
+    /// Gets the key windows are drawn in order of: the layer, and then the depth within it. Windows with
+    /// the same key are drawn in the order they were added.
+    pub fn get_stacking (&self) -> (Layer, u16) {
+        (self.layer, self.depth)
+    }
+
+    /// Re-renders the cached rows (see `get_full_render`) if the window changed since they were
+    /// last rendered, then marks the window as rendered.
+    fn refresh_rendered_rows (&mut self) {
+        if !self.needs_render() {  return;  }
+        if self.wrap_mode == WrapMode::None {
+            for index in self.scroll_offset..self.scroll_offset + self.get_content_height() {
+                self.cache_line(index);
+            }
+        }
+        let mut rows = std::mem::take(&mut self.rendered_rows);
+        self.push_full_render(&mut rows);
+        self.rendered_rows = rows;
+        self.mark_rendered();
+    }
+
+    // joins the line into its cache if it isn't already there, returning the cached string and its width
+    fn cache_line (&mut self, index: usize) -> Option <&(String, usize)> {
+        let line = self.lines.get_mut(index)?;
+        if line.1.is_none() {
+            line.1 = Some(line.0.join());
+            self.joins += 1;
+        }
+        line.1.as_ref()
+    }
+
+    /// Returns the number of lines the window has joined (turned from `Span`s into styled strings) so far.
+    /// Each line is only joined again once its span changes, so re-rendering an unchanged window (such as
+    /// when it moves or is shown again) reuses the joined strings. Lines of wrapped windows aren't counted.
+    /// # Example
+    /// ```
+    /// use term_render::render::{App, Span, Window};
+    /// let mut app = App::new_headless(60, 20);
+    /// let mut window = Window::new((0, 0), 0, (30, 10));
+    /// window.bordered();
+    /// window.try_update_lines((0..8).map(|row| Span::from(format!("row {}", row).as_str())).collect());
+    /// app.add_window(window, String::from("panel"), vec![]).unwrap();
+    /// app.render(None);
+    /// assert_eq!(app.get_window_reference(String::from("panel")).get_join_count(), 8);
+    ///
+    /// // dragging the window across the screen only redraws the cached rows
+    /// for step in 1..=20 {
+    ///     app.get_window_reference_mut(String::from("panel")).r#move((step, step / 4));
+    ///     app.render(None);
+    /// }
+    /// assert_eq!(app.snapshot()[6].trim(), "│row 0                       │");
+    /// assert_eq!(app.get_window_reference(String::from("panel")).get_join_count(), 8);
+    /// ```
+    pub fn get_join_count (&self) -> usize {
+        self.joins
+    }
+
+    // brings the wrapped lines and scrollbar up to date, returning whether any rows need re-rendering
+    fn needs_render (&mut self) -> bool {
+        self.refresh_wrapped();
+        self.refresh_scrollbar();
+        !self.was_updated
+    }
+
+    /// Marks every row of the window as rendered.
+    fn mark_rendered (&mut self) {
+        self.was_updated = true;
+        self.updated.iter_mut().for_each(|updated| *updated = true);
+    }
+
+    /// Renders every row of the window (see `get_full_render`) into the frame at the window's position,
+    /// applying the clip region. Hidden windows aren't drawn.
+    pub fn draw_to_frame (&self, frame: &mut Frame) {
+        self.draw_rows(&self.get_full_render(), frame);
+    }
+
+    /// Writes the given rows of the window (as produced by `get_full_render`) into the frame,
+    /// applying the clip region.
+    fn draw_rows (&self, rows: &[String], frame: &mut Frame) {
+        let mut clipped = String::new();  // reused for every row
+        for (row, text) in rows.iter().enumerate() {
+            let y = self.position.1.saturating_add(row as u16);
+            let Some((x, skip, take)) = self.clip_row(self.position.0, y, self.clip) else {  continue;  };
+            clipped.clear();
+            Window::push_clipped_line(&mut clipped, text, skip, take);
+            frame.write_str(x, y, &clipped);
+        }
+    }
+
+    /// Gets the number of rows available for content (the height minus any border and padding).
+    pub fn get_content_height (&self) -> usize {
+        let (top, bottom, _left, _right) = self.get_content_insets();
+        (self.size.1 as usize).saturating_sub(top + bottom)
+    }
+
+    /// Gets the number of columns available for content (the width minus any border and padding).
+    pub fn get_content_width (&self) -> usize {
+        let (_top, _bottom, left, right) = self.get_content_insets();
+        (self.size.0 as usize).saturating_sub(left + right)
+    }
+
+    // the number of cells between each edge and the content (top, bottom, left, right), counting the padding
+    // and the scrollbar
+    fn get_content_insets (&self) -> (usize, usize, usize, usize) {
+        let (top, bottom, left, right) = self.get_border_insets();
+        let (bar_bottom, bar_right) = match self.scrollbar.map(|config| config.orientation) {
+            Some(ScrollbarOrientation::Vertical) => (0, 1),
+            Some(ScrollbarOrientation::Horizontal) => (1, 0),
+            None => (0, 0),
+        };
+        (
+            top + self.padding.top as usize,
+            bottom + bar_bottom + self.padding.bottom as usize,
+            left + self.padding.left as usize,
+            right + bar_right + self.padding.right as usize,
+        )
+    }
+
+    // the number of cells the border takes up on each side (top, bottom, left, right)
+    fn get_border_insets (&self) -> (usize, usize, usize, usize) {
+        let side = |side: BorderSides| (self.bordered && self.border_sides.contains(side)) as usize;
+        (side(BorderSides::TOP), side(BorderSides::BOTTOM), side(BorderSides::LEFT), side(BorderSides::RIGHT))
+    }
+
+    // the border glyphs on either side of the content rows
+    fn get_row_border (&self) -> RowBorder {
+        let (_top, _bottom, left, right) = self.get_border_insets();
+        let chars = self.border_style.get_chars();
+        ((left == 1).then_some(chars.left), (right == 1).then_some(chars.right))
+    }
+
+    /// Gets the index of the first visible line.
+    pub fn get_scroll_offset (&self) -> usize {
+        self.scroll_offset
+    }
+
+    /// Gets the largest scroll offset which still fills the window (0 if all the lines fit).
+    /// When wrapping, this is in visual rows rather than lines.
+    pub fn get_max_scroll (&self) -> usize {
+        self.get_row_count().saturating_sub(self.get_content_height())
+    }
+
+    /// Sets the horizontal alignment of every line within the window. The padding is based on
+    /// the visible size of each line (so escape codes don't offset it) and is recalculated
+    /// whenever the window is resized.
+    pub fn set_alignment (&mut self, alignment: Alignment) {
+        if alignment == self.alignment {  return;  }
+        self.alignment = alignment;
+        self.update_all();
+    }
+
+    /// Returns the horizontal alignment of the window's lines.
+    pub fn get_alignment (&self) -> Alignment {
+        self.alignment
+    }
+
+    /// Pads the start of a rendered line so it's aligned within the given width.
+    /// Lines which already fill the width are left as is.
+    pub fn align_row (render_text: (String, usize), alignment: Alignment, width: usize) -> (String, usize) {
+        let padding = match alignment {
+            Alignment::Left => 0,
+            Alignment::Center => width.saturating_sub(render_text.1) / 2,
+            Alignment::Right => width.saturating_sub(render_text.1),
+        };
+        if padding == 0 {  return render_text;  }
+        let mut text = String::with_capacity(padding + render_text.0.len());
+        Window::push_spaces(&mut text, padding);
+        text.push_str(&render_text.0);
+        (text, render_text.1 + padding)
+    }
+
+    /// Sets how lines wider than the window are handled (see `WrapMode`).
+    /// # Example:
+    /// ```
+    /// use term_render::render::{App, CellColor, ColorType, Colorize, Frame, Span, Window, WrapMode};
+    /// let mut app = App::new_headless(30, 4);
+    /// let mut window = Window::new((0, 0), 0, (30, 4));
+    /// window.set_wrap_mode(WrapMode::Word);
+    /// window.add_line(Span::from_tokens(vec![
+    ///     "The quick brown fox jumps over ".colorize(ColorType::Red),
+    ///     "the lazy dog, and then naps in the sun all afternoon".colorize(ColorType::Green),
+    /// ]));
+    /// // one long token falls back to breaking anywhere
+    /// window.add_line(Span::from("abcdefghijklmnopqrstuvwxyz0123456789"));
+    /// app.add_window(window, String::from("text"), vec![]).unwrap();
+    /// assert_eq!(app.snapshot(), vec![
+    ///     "The quick brown fox jumps over",
+    ///     "the lazy dog, and then naps in",
+    ///     "the sun all afternoon",
+    ///     "abcdefghijklmnopqrstuvwxyz0123",
+    /// ]);
+    /// // each fragment keeps the color of the part of the line it came from
+    /// let mut frame = Frame::new(30, 4);
+    /// for (y, line) in app.snapshot_styled().iter().enumerate() {
+    ///     frame.write_str(0, y as u16, line);
+    /// }
+    /// assert_eq!(frame.get(0, 0).unwrap().style.fg, Some(CellColor::Indexed(1)));
+    /// assert_eq!(frame.get(0, 1).unwrap().style.fg, Some(CellColor::Indexed(2)));
+    /// assert_eq!(frame.get(20, 2).unwrap().style.fg, Some(CellColor::Indexed(2)));
+    /// ```
+    pub fn set_wrap_mode (&mut self, mode: WrapMode) {
+        if mode == self.wrap_mode {  return;  }
+        self.wrap_mode = mode;
+        self.wrapped.clear();
+        self.update_all();
+        self.refresh_wrapped();
+        self.scroll_offset = usize::min(self.scroll_offset, self.get_max_scroll());
+    }
+
+    /// Enables (word) wrapping, or disables it so long lines are cut off.
+    pub fn set_wrap (&mut self, wrap: bool) {
+        self.set_wrap_mode(match wrap {
+            true => WrapMode::Word,
+            false => WrapMode::None,
+        });
+    }
+
+    /// Returns the current wrap mode.
+    pub fn get_wrap_mode (&self) -> WrapMode {
+        self.wrap_mode
+    }
+
+    /// Gets the number of visual rows the content takes up (the number of lines unless wrapping).
+    pub fn get_row_count (&self) -> usize {
+        match self.wrap_mode {
+            WrapMode::None => self.lines.len(),
+            _ if self.wrap_outdated => self.compute_wrapped().len(),
+            _ => self.wrapped.len(),
+        }
+    }
+
+    // gets the content shown on the given visual row (before scrolling)
+    fn get_row_span (&self, row: usize) -> Option <&Span> {
+        match self.wrap_mode {
+            WrapMode::None => self.lines.get(row).map(|line| &line.0),
+            _ => self.wrapped.get(row),
+        }
+    }
+
+    fn compute_wrapped (&self) -> Vec <Span> {
+        let width = self.get_content_width();
+        self.lines.iter().flat_map(|line| line.0.wrap(width, self.wrap_mode)).collect()
+    }
+
+    // re-wraps the lines, marking the visible rows whose content shifted or changed
+    fn refresh_wrapped (&mut self) {
+        if self.wrap_mode == WrapMode::None {
+            self.wrap_outdated = false;
+            return;
+        }
+        if !self.wrap_outdated && !self.wrapped.is_empty() {  return;  }
+        self.wrap_outdated = false;
+        let wrapped = self.compute_wrapped();
+        // fewer rows could leave the old offset past the end
+        let offset = usize::min(self.scroll_offset, wrapped.len().saturating_sub(self.get_content_height()));
+        let top = self.get_content_insets().0;
+        for row in 0..self.get_content_height() {
+            if self.wrapped.get(self.scroll_offset + row) == wrapped.get(offset + row) {  continue;  }
+            self.updated[row + top] = false;
+            self.was_updated = false;
+        }
+        self.scroll_offset = offset;
+        self.wrapped = wrapped;
+    }
+
+    /// Scrolls the content so the given line is the first visible one, clamped to `get_max_scroll`.
+    /// The border and title stay in place. Only rows whose content actually changed are marked
+    /// for re-rendering, so scrolling doesn't force a full redraw.
+    pub fn set_scroll_offset (&mut self, offset: usize) {
+        self.refresh_wrapped();
+        let offset = usize::min(offset, self.get_max_scroll());
+        if offset == self.scroll_offset {  return;  }
+        let old_offset = self.scroll_offset;
+        self.scroll_offset = offset;
+
+        let top = self.get_content_insets().0;
+        for row in 0..self.get_content_height() {
+            let old_line = self.get_row_span(old_offset + row);
+            let new_line = self.get_row_span(offset + row);
+            let stripe_changed = self.striping.as_ref().is_some_and(|striping| {
+                striping.get_background(old_offset + row) != striping.get_background(offset + row)
+            });
+            if old_line == new_line && !stripe_changed {  continue;  }
+            self.updated[row + top] = false;
+            self.was_updated = false;
+        }
+    }
+
+    /// Scrolls the content by the given number of lines (negative values scroll up), clamping at either end.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{App, Rect, Span, Window};
+    /// let mut app = App::new_headless(8, 4);
+    /// let mut window = Window::new((0, 0), 0, (8, 4));
+    /// window.bordered();
+    /// for index in 0..10_000 {
+    ///     window.add_line(Span::from(format!("{}", index).as_str()));
+    /// }
+    /// app.add_window(window, String::from("log"), vec![]).unwrap();
+    /// // the border stays put while the lines inside it move
+    /// app.get_window_reference_mut(String::from("log")).scroll_by(5);
+    /// assert_eq!(app.snapshot(), vec!["┌──────┐", "│5     │", "│6     │", "└──────┘"]);
+    /// // going past either end stops at the last (or first) full window of lines
+    /// app.get_window_reference_mut(String::from("log")).scroll_by(i32::MAX);
+    /// assert_eq!(app.get_window_reference_mut(String::from("log")).get_scroll_offset(), 9_998);
+    /// assert_eq!(app.snapshot()[2], "│9999  │");
+    /// app.get_window_reference_mut(String::from("log")).scroll_by(-20_000);
+    /// assert_eq!(app.snapshot()[1], "│0     │");
+    ///
+    /// // only the rows whose content changed are rendered again
+    /// let area = Rect { width: 8, height: 4 };
+    /// let mut window = Window::new((0, 0), 0, (8, 3));
+    /// for text in ["same", "same", "same", "new"] {
+    ///     window.add_line(Span::from(text));
+    /// }
+    /// assert_eq!(window.get_render_closure(&area).len(), 3);
+    /// window.scroll_by(1);
+    /// let rows = window.get_render_closure(&area);
+    /// assert_eq!(rows.len(), 1);
+    /// assert_eq!(rows[0].2, 2);  // the bottom row
+    /// ```
+    pub fn scroll_by (&mut self, delta: i32) {
+        let offset = (self.scroll_offset as i64 + delta as i64).max(0) as usize;
+        self.set_scroll_offset(offset);
+    }
+
+    // marks the row showing the given line as needing to be re-rendered (if it's visible)
+    fn mark_line_updated (&mut self, line: usize) {
+        if self.wrap_mode != WrapMode::None {
+            // a line can span several rows, and changing its length shifts everything after it
+            self.wrap_outdated = true;
+            self.was_updated = false;
+            return;
+        }
+        if line < self.scroll_offset {  return;  }
+        let row = line - self.scroll_offset;
+        if row >= self.get_content_height() {  return;  }
+        let top = self.get_content_insets().0;
+        self.updated[row + top] = false;
+        self.was_updated = false;
+    }
+
+    /// Applies alternating backgrounds beneath the content of each row (see `RowStriping`).
+    /// The stripe only shows where the content doesn't set its own background, so explicit
+    /// backgrounds (such as a selection highlight) are drawn on top of it, and foreground colors
+    /// and modifiers are left untouched. Backgrounds which are `None` leave those rows unstriped.
+    /// The window is only marked for an update if the striping changed.
+    pub fn set_row_striping (&mut self, style_even: Option <ColorType>, style_odd: Option <ColorType>, start_row: usize) {
+        let header_rows = self.striping.as_ref().map(|striping| striping.header_rows).unwrap_or(0);
+        self.set_striping(Some(RowStriping { even: style_even, odd: style_odd, start_row, header_rows }));
+    }
+
+    /// Sets the number of leading content lines which are excluded from the striping (i.e. headers).
+    /// This does nothing if striping isn't enabled.
+    pub fn set_striping_header_rows (&mut self, header_rows: usize) {
+        if let Some(mut striping) = self.striping.clone() {
+            striping.header_rows = header_rows;
+            self.set_striping(Some(striping));
+        }
+    }
+
+    /// Sets or removes (`None`) the row striping directly.
+    pub fn set_striping (&mut self, striping: Option <RowStriping>) {
+        if striping == self.striping {  return;  }
+        self.striping = striping;
+        self.update_all();
+    }
+
+    /// Returns the current row striping, if any.
+    pub fn get_striping (&self) -> Option <&RowStriping> {
+        self.striping.as_ref()
+    }
+
+    /// Layers a background beneath a rendered line, padding it out to the given width so the
+    /// background covers the whole row. The background is re-applied after every escape code
+    /// which doesn't set its own background (including resets), so the content's colors win.
+    pub fn apply_row_background (render_text: (String, usize), background: &str, width: usize) -> (String, usize) {
+        let (mut line, mut size) = render_text;
+        if size < width {
+            line.push_str(CLEAR);
+            Window::push_spaces(&mut line, width - size);
+            size = width;
+        }
+
+        let mut text = String::from(background);
+        let mut content_background = false;
+        let mut chars = line.chars().peekable();
+        let mut sequence = String::with_capacity(32);
+        while let Some(chr) = chars.next() {
+            if chr != '\x1b' {
+                text.push(chr);
+                continue;
+            }
+            crate::render_frame::read_escape_sequence_into(&mut chars, &mut sequence);
+            text.push_str(&sequence);
+            if !(sequence.starts_with("\x1b[") && sequence.ends_with('m')) {  continue;  }
+
+            // tracking whether the content currently has its own background
+            let params = sequence.trim_start_matches("\x1b[").trim_end_matches('m');
+            let mut codes = params.split(';').map(|code| code.parse::<u16>().unwrap_or(0));
+            while let Some(code) = codes.next() {
+                match code {
+                    0 | 49 | 109 => content_background = false,
+                    40..=47 | 100..=107 => content_background = true,
+                    48 => {
+                        content_background = true;
+                        // skipping the extended color arguments
+                        match codes.next() {
+                            Some(5) => {  codes.next();  },
+                            Some(2) => {  codes.nth(2);  },
+                            _ => {},
+                        }
+                    },
+                    38 => {
+                        match codes.next() {
+                            Some(5) => {  codes.next();  },
+                            Some(2) => {  codes.nth(2);  },
+                            _ => {},
+                        }
+                    },
+                    _ => {},
+                }
+            }
+            if !content_background {  text.push_str(background);  }
+        } (text, size)
+    }
+
+    /// Restricts drawing to the given region (position, size) in terminal coordinates.
+    /// Rows and columns of the window outside the region aren't drawn, and a window
+    /// entirely outside the region produces no draw calls at all. `None` removes the clip.
+    /// The window is only marked for an update if the clip changed.
+    pub fn set_clip (&mut self, clip: Option <((u16, u16), (u16, u16))>) {
+        if clip == self.clip {  return;  }
+        self.clip = clip;
+        self.moved = true;  // the rows themselves don't change
+    }
+
+    /// Returns the region (position, size) the window is clipped to, if any.
+    pub fn get_clip (&self) -> Option <((u16, u16), (u16, u16))> {
+        self.clip
+    }
+
+    /// Calculates the visible part of a row of the window starting at the given terminal position,
+    /// within the clip region (position, size). Returns the starting column along with the number of
+    /// characters to skip and keep, or None if the row is entirely clipped.
+    fn clip_row (&self, x: u16, y: u16, clip: Option <((u16, u16), (u16, u16))>) -> Option <(u16, usize, usize)> {
+        let Some((clip_position, clip_size)) = clip else {
+            return Some((x, 0, self.size.0 as usize));
+        };
+        if y < clip_position.1 || y >= clip_position.1.saturating_add(clip_size.1) {  return None;  }
+        let start = x.max(clip_position.0);
+        let end = x.saturating_add(self.size.0).min(clip_position.0.saturating_add(clip_size.0));
+        if start >= end {  return None;  }
+        Some((start, (start - x) as usize, (end - start) as usize))
+    }
+
+    /// Slices a rendered line to the visible columns in the range skip..skip + take,
+    /// keeping all the escape codes so the styling stays intact.
+    /// Cursor forward sequences (from transparent windows) are counted as the cells they skip over.
+    pub fn clip_line (text: &str, skip: usize, take: usize) -> String {
+        let mut clipped = String::new();
+        Window::push_clipped_line(&mut clipped, text, skip, take);
+        clipped
+    }
+
+    // the same as `clip_line`, but appending to an existing string rather than allocating
+    fn push_clipped_line (clipped: &mut String, text: &str, skip: usize, take: usize) {
+        let mut visible = 0;
+        let mut chars = text.chars();
+        let mut sequence = String::with_capacity(32);
+        while let Some(chr) = chars.next() {
+            if chr == '\x1b' {
+                crate::render_frame::read_escape_sequence_into(&mut chars, &mut sequence);
+                match sequence.strip_prefix("\x1b[").and_then(|params| params.strip_suffix('C')) {
+                    Some(count) => {
+                        // only the part of the skipped cells within the visible range is kept
+                        let count = count.parse::<usize>().unwrap_or(1);
+                        let start = visible.max(skip);
+                        let end = (visible + count).min(skip + take);
+                        if end > start {  Window::push_cursor_forward(clipped, end - start);  }
+                        visible += count;
+                    },
+                    None => clipped.push_str(&sequence),
+                }
+                continue;
+            }
+            // a wide character cut in half by either edge is replaced by spaces for its visible half
+            let width = chr.width().unwrap_or(0);
+            // (zero width characters belong to the character before them)
+            let (start, end) = (visible.max(skip), (visible + width).min(skip + take));
+            let inside = match width {
+                0 => visible > skip && visible <= skip + take,
+                _ => start == visible && end == visible + width,
+            };
+            if inside {  clipped.push(chr);  }
+            else if end > start {  Window::push_spaces(clipped, end - start);  }
+            visible += width;
+        }
+    }
+
+    /// Pushes an escape sequence moving the cursor forward the given number of cells (`CSI n C`),
+    /// skipping over them without painting anything. Nothing is pushed for zero cells.
+    pub fn push_cursor_forward (text: &mut String, cells: usize) {
+        if cells == 0 {  return;  }
+        text.push_str("\x1b[");
+        text.push_str(&cells.to_string());
+        text.push('C');
+    }
+
+    /// Pushes the given number of spaces, copying them from a static run of spaces rather than
+    /// allocating a new string (as `" ".repeat(count)` would).
+    pub fn push_spaces (text: &mut String, count: usize) {
+        const SPACES: &str = "                                                                ";
+        let mut remaining = count;
+        while remaining > 0 {
+            let chunk = remaining.min(SPACES.len());
+            text.push_str(&SPACES[..chunk]);
+            remaining -= chunk;
+        }
+    }
+
+    /// Applies the clip region to a set of render closures, dropping or slicing them as needed.
+    fn clip_closures (&self, render_closures: RenderClosure, area: &Rect) -> RenderClosure {
+        let clip = self.get_draw_region(area);
+        let width = self.size.0 as usize;
+        render_closures.into_iter().filter_map(|(closure, x, y, depth)| {
+            let (start, skip, take) = self.clip_row(x, y, Some(clip))?;
+            if skip == 0 && take == width {  return Some((closure, x, y, depth));  }
+            let clipped: Box <dyn FnOnce () -> String + Send> = Box::new(move || {
+                Window::clip_line(&closure(), skip, take)
+            });
+            Some((clipped, start, y, depth))
+        }).collect()
+    }
+
+    // the region (position, size) the window's rows are drawn within: its clip region, limited to the
+    // terminal's area (so rows running past the right edge don't wrap onto the next row)
+    fn get_draw_region (&self, area: &Rect) -> ((u16, u16), (u16, u16)) {
+        let (position, size) = self.clip.unwrap_or(((0, 0), (area.width, area.height)));
+        let end = (position.0.saturating_add(size.0).min(area.width), position.1.saturating_add(size.1).min(area.height));
+        (position, (end.0.saturating_sub(position.0), end.1.saturating_sub(position.1)))
+    }
+
+    /// Tries to move the window to a new position.
+    /// The window is only updated if the position is different from before.
+    /// If the position is the same as before, nothing happens and the window is not marked
+    /// for an update.
+    pub fn r#move (&mut self, new_position: (u16, u16)) {
+        if new_position == self.position {  return;  }
+        self.position = new_position;
+        self.moved = true;  // the rows are redrawn in the new place without being re-rendered
+    }
+
+    /// Applies multiple colors to the window.
+    /// This will always mark the window to update.
+    pub fn colorizes (&mut self, colors: Vec <ColorType>) {
+        for color in colors {
+            self.color.add_color(color);
+        } self.update_all();
+    }
+
+    /// Adds a color to the window.
+    /// This will always mark the window to update.
+    pub fn colorize (&mut self, color: ColorType) {
+        self.color.add_color(color);
+        self.update_all();
+    }
+
+    /// Tries to add a color to the window.
+    /// Returns true if the color was added.
+    /// Returns false if the color was already present.
+    /// Additionally, this will only mark the window to update if the color was added.
     pub fn try_colorize (&mut self, color: ColorType) -> bool {
         if self.color.contains(&color) {  return false;  }
         self.color.add_color(color);
@@ -753,6 +2378,11 @@ impl Window {
         true
     }
 
+    /// Checks if the window is painted with the color or modifier.
+    pub fn has_color (&self, color: &ColorType) -> bool {
+        self.color.contains(color)
+    }
+
     /// Clears all colors and modifiers from the window.
     /// Returns true if any colors or modifiers were cleared.
     /// Returns false if the window was already uncolored.
@@ -764,10 +2394,342 @@ impl Window {
         true
     }
 
+    /// Sets the theme slot the border and title are colored with (see `Theme`), or None to keep the
+    /// window's own colors. The slot's colors are applied when the window is added to the `App`
+    /// and whenever the app's theme changes.
+    pub fn set_theme_slot (&mut self, slot: Option <String>) {
+        self.theme_slot = slot;
+    }
+
+    /// Gets the theme slot the border and title are colored with, if any.
+    pub fn get_theme_slot (&self) -> Option <&String> {
+        self.theme_slot.as_ref()
+    }
+
+    /// Colors the border and title with the window's theme slot from the given theme.
+    /// Nothing changes if the window doesn't have a slot, or the theme doesn't provide it.
+    pub fn apply_theme (&mut self, theme: &Theme) {
+        let Some(colors) = self.theme_slot.as_ref().and_then(|slot| theme.get_slot(slot)) else {  return;  };
+        self.color = Colored::get_from_color_types_str("", colors.to_vec());
+        for title in [&mut self.title.0, &mut self.bottom_title.0] {
+            let tokens = title.line.iter().map(|token| {
+                Colored::get_from_color_types_str(&token.text, colors.to_vec())
+            }).collect();
+            *title = Span::from_tokens(tokens);
+        }
+        self.update_all();
+    }
+
     // Adds a border around the window/block
     /// Adds a border around the window.
     pub fn bordered (&mut self) {
         self.bordered = true;
+        self.wrap_outdated = self.wrap_mode != WrapMode::None;
+    }
+
+    /// Adds or removes the border around the window.
+    pub fn set_bordered (&mut self, bordered: bool) {
+        if self.bordered == bordered {  return;  }
+        self.bordered = bordered;
+        self.wrap_outdated = self.wrap_mode != WrapMode::None;
+        self.update_all();
+    }
+
+    /// Returns whether the window has a border.
+    pub fn is_bordered (&self) -> bool {
+        self.bordered
+    }
+
+    /// Sets the glyphs the border is drawn with (plain lines by default).
+    /// This only has an effect once the window is bordered.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{BorderChars, BorderStyle, Span, Window};
+    /// // dropping the escape codes to get the visible text of each row
+    /// let visible = |row: &String| {
+    ///     let mut in_escape = false;
+    ///     row.chars().filter(|chr| {
+    ///         if *chr == '\x1b' {  in_escape = true;  }
+    ///         else if in_escape {  in_escape = !chr.is_ascii_alphabetic();  return false;  }
+    ///         !in_escape
+    ///     }).collect::<String>()
+    /// };
+    /// let custom = BorderChars {
+    ///     top_left: '*', top: '~', top_right: '*', left: ':',
+    ///     right: ':', bottom_left: '*', bottom: '~', bottom_right: '*',
+    /// };
+    /// for (style, first, last) in [
+    ///     (BorderStyle::Plain, "┌─Hi─┐", "└────┘"),
+    ///     (BorderStyle::Rounded, "╭─Hi─╮", "╰────╯"),
+    ///     (BorderStyle::Double, "╔═Hi═╗", "╚════╝"),
+    ///     (BorderStyle::Thick, "┏━Hi━┓", "┗━━━━┛"),
+    ///     (BorderStyle::Ascii, "+-Hi-+", "+----+"),
+    ///     (BorderStyle::Custom(custom), "*~Hi~*", "*~~~~*"),
+    /// ] {
+    ///     let mut window = Window::new((0, 0), 0, (6, 3));
+    ///     window.bordered();
+    ///     window.titled(String::from("Hi"));
+    ///     window.set_border_style(style);
+    ///     let rows: Vec<String> = window.get_full_render().iter().map(visible).collect();
+    ///     assert_eq!(rows[0], first);
+    ///     assert_eq!(rows[1].chars().next(), Some(style.get_chars().left));
+    ///     assert_eq!(rows[2], last);
+    /// }
+    /// ```
+    pub fn set_border_style (&mut self, style: BorderStyle) {
+        if style == self.border_style {  return;  }
+        self.border_style = style;
+        self.update_all();
+    }
+
+    /// Gets the style the border is drawn with.
+    pub fn get_border_style (&self) -> BorderStyle {
+        self.border_style
+    }
+
+    /// Sets which sides of the window have a border (every side by default), such as only
+    /// `BorderSides::BOTTOM` for a separator. This only has an effect once the window is bordered.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{BorderSides, Window};
+    /// let mut window = Window::new((0, 0), 0, (6, 3));
+    /// window.bordered();
+    /// window.set_border_sides(BorderSides::TOP | BorderSides::LEFT);
+    /// let rows = window.get_full_render();
+    /// assert_eq!(rows.len(), 3);  // the bottom row holds content rather than a border
+    /// assert!(rows[0].contains('┌') && !rows[0].contains('┐'));  // no right border, so no corner
+    /// assert!(rows[2].contains('│') && !rows[2].contains('└'));
+    /// assert_eq!(window.get_content_height(), 2);
+    /// assert_eq!(window.get_content_width(), 5);
+    /// ```
+    pub fn set_border_sides (&mut self, sides: BorderSides) {
+        if sides == self.border_sides {  return;  }
+        self.border_sides = sides;
+        self.update_all();
+    }
+
+    /// Gets which sides of the window have a border.
+    pub fn get_border_sides (&self) -> BorderSides {
+        self.border_sides
+    }
+
+    /// Sets the blank space between the border (or edge, when borderless) and the content.
+    /// The padding takes away from the content area, so fewer lines are visible at once
+    /// (the rest can still be scrolled into view).
+    /// # Example:
+    /// ```
+    /// use term_render::render::{Colorize, Padding, Span, Window};
+    /// let mut window = Window::new((0, 0), 0, (8, 5));
+    /// window.bordered();
+    /// window.set_padding(Padding { left: 2, right: 1, top: 1, bottom: 0 });
+    /// window.from_lines(vec![
+    ///     Span::from_tokens(vec!["Hello".colorizes(vec![])]),
+    ///     Span::from_tokens(vec!["World".colorizes(vec![])]),
+    ///     Span::from_tokens(vec!["Again".colorizes(vec![])]),
+    /// ]);
+    /// assert_eq!((window.get_content_width(), window.get_content_height()), (3, 2));
+    /// // dropping the escape codes to get the visible text of each row
+    /// let visible = |row: &String| {
+    ///     let mut in_escape = false;
+    ///     row.chars().filter(|chr| {
+    ///         if *chr == '\x1b' {  in_escape = true;  }
+    ///         else if in_escape {  in_escape = !chr.is_ascii_alphabetic();  return false;  }
+    ///         !in_escape
+    ///     }).collect::<String>()
+    /// };
+    /// let rows: Vec<String> = window.get_full_render().iter().map(visible).collect();
+    /// assert_eq!(rows[1], "│      │");
+    /// assert_eq!(rows[2], "│  Hel │");
+    /// assert_eq!(rows[3], "│  Wor │");
+    /// // the third line is below the padded content area, but can be scrolled to
+    /// window.set_scroll_offset(1);
+    /// let rows: Vec<String> = window.get_full_render().iter().map(visible).collect();
+    /// assert_eq!(rows[3], "│  Aga │");
+    /// ```
+    pub fn set_padding (&mut self, padding: Padding) {
+        if padding == self.padding {  return;  }
+        self.padding = padding;
+        self.update_all();
+        self.refresh_wrapped();
+        self.scroll_offset = usize::min(self.scroll_offset, self.get_max_scroll());
+    }
+
+    /// Gets the blank space between the border (or edge) and the content.
+    pub fn get_padding (&self) -> Padding {
+        self.padding
+    }
+
+    /// Adds a scrollbar along the last interior column (or row, when horizontal), just inside the border.
+    /// The bar takes its cells away from the content, so lines are clamped and padded to the narrower
+    /// width. The thumb's size and position follow the number of rows, the rows which fit, and the scroll offset.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{Colorize, ScrollbarConfig, Span, Window};
+    /// let mut window = Window::new((0, 0), 0, (6, 10));
+    /// window.try_update_lines((0..100).map(|index| Span::from_tokens(vec![format!("{}", index).colorizes(vec![])])).collect());
+    /// window.set_scrollbar(ScrollbarConfig::default());
+    /// assert_eq!(window.get_content_width(), 5);
+    /// // dropping the escape codes to get the visible text of each row
+    /// let visible = |row: &String| {
+    ///     let mut in_escape = false;
+    ///     row.chars().filter(|chr| {
+    ///         if *chr == '\x1b' {  in_escape = true;  }
+    ///         else if in_escape {  in_escape = !chr.is_ascii_alphabetic();  return false;  }
+    ///         !in_escape
+    ///     }).collect::<String>()
+    /// };
+    /// // a tenth of the lines fit, so the thumb is a single row moving down the track
+    /// for (offset, thumb_row) in [(0, 0), (45, 5), (90, 9)] {
+    ///     window.set_scroll_offset(offset);
+    ///     assert_eq!(window.get_scrollbar_thumb(), Some((thumb_row, 1)));
+    ///     let rows: Vec<String> = window.get_full_render().iter().map(visible).collect();
+    ///     for (row, text) in rows.iter().enumerate() {
+    ///         let bar = if row == thumb_row {  '█'  } else {  '│'  };
+    ///         assert_eq!(*text, format!("{:<5}{}", offset + row, bar));
+    ///     }
+    /// }
+    ///
+    /// // with a border, the bar sits just inside it
+    /// window.bordered();
+    /// window.set_scroll_offset(0);
+    /// let rows: Vec<String> = window.get_full_render().iter().map(visible).collect();
+    /// assert_eq!(rows[1], "│0  █│");
+    /// assert_eq!(rows[2], "│1  ││");
+    /// ```
+    pub fn set_scrollbar (&mut self, config: ScrollbarConfig) {
+        if self.scrollbar == Some(config) {  return;  }
+        self.set_scrollbar_config(Some(config));
+    }
+
+    /// Removes the scrollbar, giving its cells back to the content.
+    pub fn remove_scrollbar (&mut self) {
+        if self.scrollbar.is_none() {  return;  }
+        self.set_scrollbar_config(None);
+    }
+
+    // swaps the scrollbar, re-fitting the content to the new width and height
+    fn set_scrollbar_config (&mut self, config: Option <ScrollbarConfig>) {
+        self.scrollbar = config;
+        self.scrollbar_cells.clear();
+        self.wrap_outdated = true;
+        self.refresh_wrapped();
+        self.scroll_offset = usize::min(self.scroll_offset, self.get_max_scroll());
+        self.update_all();
+    }
+
+    /// Gets the scrollbar's configuration, if the window has one.
+    pub fn get_scrollbar (&self) -> Option <ScrollbarConfig> {
+        self.scrollbar
+    }
+
+    // the scrollbar's length, and how far the content extends along it: (total, visible, offset)
+    fn get_scrollbar_extent (&self) -> Option <(usize, usize, usize, usize)> {
+        let config = self.scrollbar?;
+        let (top, bottom, left, right) = self.get_border_insets();
+        Some(match config.orientation {
+            ScrollbarOrientation::Vertical => (
+                (self.size.1 as usize).saturating_sub(top + bottom),
+                self.get_row_count(),
+                self.get_content_height(),
+                self.scroll_offset,
+            ),
+            ScrollbarOrientation::Horizontal => (
+                (self.size.0 as usize).saturating_sub(left + right),
+                self.lines.iter().map(|line| line.0.size()).max().unwrap_or(0),
+                self.get_content_width(),
+                0,
+            ),
+        })
+    }
+
+    /// Gets where the scrollbar's thumb starts along the track (0 being the top or left of the interior)
+    /// and how many cells long it is. Returns None without a scrollbar, or while it's hidden because
+    /// everything fits (see `ScrollbarConfig::auto_hide`).
+    pub fn get_scrollbar_thumb (&self) -> Option <(usize, usize)> {
+        let (track, total, visible, offset) = self.get_scrollbar_extent()?;
+        if track == 0 || (total <= visible && self.scrollbar.is_some_and(|config| config.auto_hide)) {  return None;  }
+        if total <= visible {  return Some((0, track));  }
+        let length = ((track * visible) as f32 / total as f32).round().clamp(1.0, track as f32) as usize;
+        let max_offset = total - visible;
+        let start = ((offset.min(max_offset) as f32 / max_offset as f32) * (track - length) as f32).round() as usize;
+        Some((start, length))
+    }
+
+    // the glyph of each cell along the scrollbar (None where it's blank)
+    fn get_scrollbar_cells (&self) -> Vec <Option <char>> {
+        let (Some(config), Some((track, ..))) = (self.scrollbar, self.get_scrollbar_extent()) else {  return vec![];  };
+        let Some((start, length)) = self.get_scrollbar_thumb() else {  return vec![None; track];  };
+        (0..track).map(|cell| Some(match (start..start + length).contains(&cell) {
+            true => config.thumb_char,
+            false => config.track_char,
+        })).collect()
+    }
+
+    // marks the rows whose scrollbar cells changed since the last render (i.e. after scrolling or adding lines)
+    fn refresh_scrollbar (&mut self) {
+        let Some(config) = self.scrollbar else {  return;  };
+        let cells = self.get_scrollbar_cells();
+        if cells == self.scrollbar_cells {  return;  }
+        let (top, bottom, _left, _right) = self.get_border_insets();
+        match config.orientation {
+            ScrollbarOrientation::Vertical => {
+                for (cell, glyph) in cells.iter().enumerate() {
+                    if self.scrollbar_cells.get(cell) == Some(glyph) {  continue;  }
+                    if let Some(updated) = self.updated.get_mut(top + cell) {  *updated = false;  }
+                }
+            },
+            ScrollbarOrientation::Horizontal => {
+                let row = (self.size.1 as usize).saturating_sub(bottom + 1);
+                if let Some(updated) = self.updated.get_mut(row) {  *updated = false;  }
+            },
+        }
+        self.was_updated = false;
+        self.scrollbar_cells = cells;
+    }
+
+    /// Checks whether the given terminal position is on the scrollbar, returning how far along the track
+    /// it is (0.0 at the top or left, 1.0 at the bottom or right). Widgets can pass the fraction to
+    /// `scroll_to_fraction` when the bar is clicked or dragged. Returns None without a (visible) scrollbar.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{Colorize, ScrollbarConfig, Span, Window};
+    /// let mut window = Window::new((3, 2), 0, (6, 11));
+    /// window.try_update_lines((0..100).map(|index| Span::from_tokens(vec![format!("{}", index).colorizes(vec![])])).collect());
+    /// window.set_scrollbar(ScrollbarConfig::default());
+    /// assert_eq!(window.scrollbar_hit((8, 2)), Some(0.0));
+    /// assert_eq!(window.scrollbar_hit((8, 7)), Some(0.5));
+    /// assert_eq!(window.scrollbar_hit((7, 7)), None);  // the content, rather than the bar
+    /// window.scroll_to_fraction(0.5);
+    /// assert_eq!(window.get_scroll_offset(), 45);
+    /// ```
+    pub fn scrollbar_hit (&self, position: (u16, u16)) -> Option <f32> {
+        let config = self.scrollbar?;
+        self.get_scrollbar_thumb()?;
+        let (track, ..) = self.get_scrollbar_extent()?;
+        let (top, bottom, left, right) = self.get_border_insets();
+        let (x, y) = (position.0 as usize, position.1 as usize);
+        let (window_x, window_y) = (self.position.0 as usize, self.position.1 as usize);
+        let along = match config.orientation {
+            ScrollbarOrientation::Vertical => {
+                if x + 1 + right != window_x + self.size.0 as usize || y < window_y + top {  return None;  }
+                y - window_y - top
+            },
+            ScrollbarOrientation::Horizontal => {
+                if y + 1 + bottom != window_y + self.size.1 as usize || x < window_x + left {  return None;  }
+                x - window_x - left
+            },
+        };
+        if along >= track {  return None;  }
+        Some(match track {
+            1 => 0.0,
+            _ => along as f32 / (track - 1) as f32,
+        })
+    }
+
+    /// Scrolls to the given fraction of the way through the content (0.0 being the top, and 1.0 the bottom).
+    pub fn scroll_to_fraction (&mut self, fraction: f32) {
+        let offset = (fraction.clamp(0.0, 1.0) * self.get_max_scroll() as f32).round() as usize;
+        self.set_scroll_offset(offset);
     }
 
     // Sets/updates the title of the window/block
@@ -776,7 +2738,7 @@ impl Window {
         self.title = (
             Span::from_tokens(
             vec![title.colorizes(vec![])]),
-            title.chars().count()
+            get_text_width(&title)
         );
         self.was_updated = false;
         self.updated[0] = false;
@@ -788,6 +2750,11 @@ impl Window {
         self.title.1 != 0
     }
 
+    /// Returns the text of the title (without any coloring), which is empty if no title is set.
+    pub fn get_title_text (&self) -> String {
+        self.title.0.get_tokens().iter().map(|token| token.text.as_str()).collect()
+    }
+
     /// Provides a title with color and modifiers.
     pub fn titled_colored (&mut self, title: Span) {
         let size = title.size();
@@ -796,6 +2763,68 @@ impl Window {
         self.updated[0] = false;
     }
 
+    /// Sets where the title sits along the top border (centered by default).
+    pub fn set_title_alignment (&mut self, alignment: Alignment) {
+        if alignment == self.title_alignment {  return;  }
+        self.title_alignment = alignment;
+        self.was_updated = false;
+        self.updated[0] = false;
+    }
+
+    /// Gets where the title sits along the top border.
+    pub fn get_title_alignment (&self) -> Alignment {
+        self.title_alignment
+    }
+
+    /// Sets a secondary title drawn in the bottom border, such as a hint like "press q to quit".
+    /// Like the main title, it's only visible when the window is bordered.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{Alignment, Colorize, Span, Window};
+    /// let mut window = Window::new((0, 0), 0, (12, 3));
+    /// window.bordered();
+    /// window.titled(String::from("Top"));
+    /// window.set_title_alignment(Alignment::Left);
+    /// window.bottom_titled(Span::from_tokens(vec!["q: quit".colorizes(vec![])]));
+    /// window.set_bottom_title_alignment(Alignment::Right);
+    /// // dropping the escape codes to get the visible text of each row
+    /// let visible = |row: &String| {
+    ///     let mut in_escape = false;
+    ///     row.chars().filter(|chr| {
+    ///         if *chr == '\x1b' {  in_escape = true;  }
+    ///         else if in_escape {  in_escape = !chr.is_ascii_alphabetic();  return false;  }
+    ///         !in_escape
+    ///     }).collect::<String>()
+    /// };
+    /// let rows: Vec<String> = window.get_full_render().iter().map(visible).collect();
+    /// assert_eq!(rows[0], "┌─Top──────┐");
+    /// assert_eq!(rows[2], "└──q: quit─┘");
+    ///
+    /// // titles wider than the window are cut off rather than pushing out the corners
+    /// window.titled(String::from("A title much wider than the window"));
+    /// let rows: Vec<String> = window.get_full_render().iter().map(visible).collect();
+    /// assert_eq!(rows[0], "┌A title mu┐");
+    /// ```
+    pub fn bottom_titled (&mut self, title: Span) {
+        let size = title.size();
+        self.bottom_title = (title, size);
+        self.was_updated = false;
+        if let Some(updated) = self.updated.last_mut() {  *updated = false;  }
+    }
+
+    /// Sets where the bottom title sits along the bottom border (centered by default).
+    pub fn set_bottom_title_alignment (&mut self, alignment: Alignment) {
+        if alignment == self.bottom_title_alignment {  return;  }
+        self.bottom_title_alignment = alignment;
+        self.was_updated = false;
+        if let Some(updated) = self.updated.last_mut() {  *updated = false;  }
+    }
+
+    /// Gets where the bottom title sits along the bottom border.
+    pub fn get_bottom_title_alignment (&self) -> Alignment {
+        self.bottom_title_alignment
+    }
+
     /// Tries to resize the window. Returns true if the size was changed.
     /// Returns false if the size is the same as before.
     /// Additionally, this will only mark the window to update if the size was changed.
@@ -806,95 +2835,231 @@ impl Window {
             std::cmp::max(changed.1, 0)
         );
         self.updated = vec![false; self.size.1 as usize];
+        self.wrap_outdated = true;
+        self.refresh_wrapped();
+        // a taller window can show more lines, so the offset may no longer be reachable
+        self.scroll_offset = usize::min(self.scroll_offset, self.get_max_scroll());
         self.update_all();
         true
     }
     
     // Clamps a string to a maximum length of visible UTF-8 characters while preserving escape codes
-    /// Clamps a string a maximum length of visible UTF-8 characters while preserving ANSI escape codes.
-    /// This function iterates through the characters of the input string, counting only the visible characters
-    /// and ignoring the characters that are part of ANSI escape codes. When the count of visible characters
-    /// exceeds the specified maximum length, the function stops adding characters to the output string.
+    /// Clamps a string a maximum length of visible UTF-8 characters while preserving ANSI escape codes,
+    /// appending the result to `accumulative` (so rows can be built without allocating intermediate strings).
+    /// This function iterates through the characters of the input string, counting the terminal columns of
+    /// the visible characters and ignoring the characters that are part of ANSI escape codes. When the
+    /// columns would exceed the specified maximum length, the function stops adding characters to the output
+    /// string (padding with a space if a wide character would have been cut in half).
     /// The resulting string contains the original text up to the maximum visible length,
     /// along with any necessary ANSI escape codes to maintain the original formatting.
     /// Note: This function assumes that the input string is valid UTF-8 and that ANSI escape codes
-    /// are well-formed (i.e., they start with `\x1b` and end with `m`).
-    fn clamp_string_visible_utf_8 (text: &str, max_length: usize) -> String {
-        let mut accumulative: String = String::new();
-
+    /// are well-formed (i.e., they start with `\x1b` and end with `m`, or a terminator for hyperlinks).
+    fn push_clamped_visible_utf_8 (accumulative: &mut String, text: &str, max_length: usize) {
         let mut visible = 0;
-        let mut in_escape = false;
-        for chr in text.chars() {
+        let mut linked = false;
+        let mut chars = text.chars();
+        let mut sequence = String::with_capacity(32);
+        while let Some(chr) = chars.next() {
             if chr == '\x1b' {
-                in_escape = true;
-            } else if in_escape {
-                if chr == 'm' {
-                    in_escape = false;
-                }
-            } else {
-                visible += 1;
-                if visible > max_length {  break;  }
+                // (hyperlinks end with a terminator rather than a letter, see `read_escape_sequence`)
+                crate::render_frame::read_escape_sequence_into(&mut chars, &mut sequence);
+                if let Some(url) = crate::render_frame::parse_hyperlink(&sequence) {  linked = url.is_some();  }
+                accumulative.push_str(&sequence);
+                continue;
             }
+            // a wide character which would cross the boundary is replaced with a space
+            let width = chr.width().unwrap_or(0);
+            if visible + width > max_length {
+                Window::push_spaces(accumulative, max_length - visible);
+                break;
+            }
+            visible += width;
             accumulative.push(chr);
         }
-
-        accumulative
+        // a hyperlink cut off by the clamp would otherwise carry on into the border
+        if linked {  push_hyperlink(accumulative, None);  }
     }
 
     /// Gets the raw string for a given line index.
     /// This function handles the rendering of a single line of the window,
     /// including borders and padding as necessary.
-    /// The function takes in the color for the border, whether the window is bordered,
-    /// the text to render, and the size of the window.
+    /// The function takes in the color for the border, the border glyphs on either side (if any),
+    /// the padding on either side (left, right), the text to render, and the size of the window.
     /// It returns the fully formatted string for that line, including any ANSI escape codes.
     /// Note: This function does not handle cursor movement or positioning.
     /// That is handled externally when rendering the window.
     /// This function is called from within the closure provided by `get_render_closure`.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{App, CellColor, ColorType, Frame, Span, Window};
+    /// // two bordered windows side by side, each with its own background
+    /// let mut app = App::new_headless(12, 3);
+    /// for (name, x, background) in [("left", 0, ColorType::OnBlue), ("right", 6, ColorType::OnRed)] {
+    ///     let mut window = Window::new((x, 0), 0, (6, 3));
+    ///     window.bordered();
+    ///     window.colorize(background);
+    ///     window.add_line(Span::from("hi"));
+    ///     app.add_window(window, name.to_owned(), vec![]).unwrap();
+    /// }
+    /// let mut frame = Frame::new(12, 3);
+    /// for (y, line) in app.snapshot_styled().iter().enumerate() {
+    ///     frame.write_str(0, y as u16, line);
+    /// }
+    /// // every cell (borders, text, and padding) carries its own window's background
+    /// for y in 0..3 {
+    ///     for x in 0..12 {
+    ///         let expected = if x < 6 {  CellColor::Indexed(4)  } else {  CellColor::Indexed(1)  };
+    ///         assert_eq!(frame.get(x, y).unwrap().style.bg, Some(expected), "cell ({}, {})", x, y);
+    ///     }
+    /// }
+    /// ```
     pub fn render_window_slice (color: (String, usize),
-                              bordered: bool,
+                              border: RowBorder,
+                              padding: (usize, usize),
                               render_text: (String, usize),
                               size: (u16, u16)
     ) -> String {
-        let mut text = String::new();
+        // sized up front, so the row is built without growing the string
+        let mut text = String::with_capacity(color.0.len() * 2 + render_text.0.len() + size.0 as usize + CLEAR.len() + 8);
 
         //let line = &self.lines[index - 1];//self.lines[0..self.size.1 as usize - borderSize][0];
-        let border_size = match bordered {
-            true => 2, false => 0
-        };
-        let line_text = Window::clamp_string_visible_utf_8(
-            &render_text.0, size.0 as usize - border_size
-        );
-        let line_size = std::cmp::min(render_text.1, size.0 as usize - border_size);
+        let border_size = border.0.is_some() as usize + border.1.is_some() as usize;
+        let outer_width = (size.0 as usize).saturating_sub(border_size);
+        let padding = (padding.0.min(outer_width), padding.1.min(outer_width.saturating_sub(padding.0)));
+        let width = outer_width - padding.0 - padding.1;
+        let line_size = std::cmp::min(render_text.1, width);
+
+        // the borders and padding are drawn in the window's color (so a colored background covers
+        // the full width), and the style is only reset once the whole slice is done
+        text.push_str(&color.0);
+        if let Some(left) = border.0 {  text.push(left);  }
+        Window::push_spaces(&mut text, padding.0);
+        Window::push_clamped_visible_utf_8(&mut text, &render_text.0, width);
+        text.push_str(&color.0);  // switching back from the content's style
+        Window::push_spaces(&mut text, width - line_size + padding.1);
+        if let Some(right) = border.1 {  text.push(right);  }
+        text.push_str(CLEAR);
+        text
+    }
 
-        // handling the side borders
-        if bordered {
+    /// Gets the raw string for a given line index of a transparent window (see `render_window_slice`).
+    /// Rather than padding the line with spaces, the cursor is moved forward over the `leading` cells
+    /// before the text and the cells after it, so nothing is painted over the windows below.
+    pub fn render_transparent_window_slice (color: (String, usize),
+                                            border: RowBorder,
+                                            padding: (usize, usize),
+                                            render_text: (String, usize),
+                                            leading: usize,
+                                            size: (u16, u16)
+    ) -> String {
+        let mut text = String::new();
+        let border_size = border.0.is_some() as usize + border.1.is_some() as usize;
+        let outer_width = (size.0 as usize).saturating_sub(border_size);
+        let padding = (padding.0.min(outer_width), padding.1.min(outer_width.saturating_sub(padding.0)));
+        let width = outer_width - padding.0 - padding.1;
+        let leading = leading.min(width);
+        let line_size = std::cmp::min(render_text.1, width - leading);
+
+        if let Some(left) = border.0 {
             text.push_str(&color.0);
-            text.push('│');
+            text.push(left);
             text.push_str(CLEAR);
-            text.push_str(&line_text);
+        }
+        Window::push_cursor_forward(&mut text, padding.0 + leading);
+        if line_size > 0 {
+            Window::push_clamped_visible_utf_8(&mut text, &render_text.0, width - leading);
             text.push_str(CLEAR);
-            let padding = (size.0 as usize - 2) - line_size;
-            text.push_str(&" ".repeat(padding));
+        }
+        if let Some(right) = border.1 {
+            // the trailing cells only need skipping to reach the right border
+            Window::push_cursor_forward(&mut text, width - leading - line_size + padding.1);
             text.push_str(&color.0);
-            text.push('│');
+            text.push(right);
             text.push_str(CLEAR);
-        } else {
-            text.push_str(&line_text);
-            text.push_str(CLEAR);  // making sure the following are blank
-            let padding = (size.0 as usize) - line_size;
-            text.push_str(&" ".repeat(padding));
         } text
     }
 
+    // gets the background layered beneath a row: its stripe if it's striped, otherwise the window's own
+    // background (if any). Transparent windows only get stripes, so the windows below still show through.
+    fn get_row_background (&self, line_index: Option <usize>) -> Option <String> {
+        let stripe = line_index.and_then(|line_index| {
+            self.striping.as_ref().and_then(|striping| striping.get_background(line_index))
+        });
+        if stripe.is_some() || self.transparent {  return stripe;  }
+        self.color.bg_color.as_ref().map(|code| format!("\x1b[{}m", code))
+    }
+
+    /// Renders a single row of text within the window (aligned, striped, bordered, and padded as needed).
+    /// The edges are the border glyphs and the padding on either side.
+    fn render_row (color: (String, usize),
+                   (border, padding): (RowBorder, (usize, usize)),
+                   render_text: (String, usize),
+                   alignment: Alignment,
+                   background: Option <String>,
+                   transparent: bool,
+                   size: (u16, u16)
+    ) -> String {
+        let width = (size.0 as usize)
+            .saturating_sub(border.0.is_some() as usize + border.1.is_some() as usize)
+            .saturating_sub(padding.0 + padding.1);
+        if transparent && background.is_none() {
+            let leading = Window::align_row((String::new(), render_text.1), alignment, width).1 - render_text.1;
+            return Window::render_transparent_window_slice(color, border, padding, render_text, leading, size);
+        }
+        let render_text = Window::align_row(render_text, alignment, width);
+        let render_text = match background {
+            Some(background) => Window::apply_row_background(render_text, &background, width),
+            None => render_text,
+        };
+        Window::render_window_slice(color, border, padding, render_text, size)
+    }
+
+    // beside a vertical scrollbar, the bar's cell takes the place of the right border in a row one column
+    // narrower (the border is added back after it, see `push_scrollbar_border`)
+    fn get_scrollbar_row_layout (border: RowBorder, size: (u16, u16), cell: Option <Option <char>>) -> (RowBorder, (u16, u16)) {
+        match cell {
+            Some(cell) => ((border.0, Some(cell.unwrap_or(' '))), (size.0.saturating_sub(border.1.is_some() as u16), size.1)),
+            None => (border, size),
+        }
+    }
+
+    // adds the right border back after a row rendered beside a vertical scrollbar
+    fn push_scrollbar_border (text: &mut String, color: &str, border: RowBorder, cell: Option <Option <char>>) {
+        if cell.is_none() {  return;  }
+        if let Some(right) = border.1 {
+            text.push_str(color);
+            text.push(right);
+            text.push_str(CLEAR);
+        }
+    }
+
+    /// Renders the row of a horizontal scrollbar: the bar's cells (blank where None) between the side borders.
+    fn render_horizontal_scrollbar (color: &str, border: RowBorder, cells: &[Option <char>]) -> String {
+        let mut text = String::from(color);
+        if let Some(left) = border.0 {  text.push(left);  }
+        text.extend(cells.iter().map(|cell| cell.unwrap_or(' ')));
+        if let Some(right) = border.1 {  text.push(right);  }
+        text.push_str(CLEAR);
+        text
+    }
+
+    // blanks the rows a hidden window covered, at the window's own depth so they're drawn in the same order the
+    // window was (rather than under, or racing with, the windows at depth 0); the windows beneath need to redraw
+    // themselves over it (see `update_all`)
     fn handle_hidden_closure (&mut self, mut render_closures: RenderClosure) -> RenderClosure {
         self.was_updated = true;
+        if self.transparent {
+            // nothing was painted over the windows below, so there's nothing to clear (they get re-rendered)
+            self.updated.iter_mut().for_each(|updated| *updated = true);
+            return render_closures;
+        }
         for i in 0..self.updated.len() {
             if self.updated[i] {  continue;  }
             self.updated[i] = true;
             let width = self.size.0;
             render_closures.push((Box::new(move || {
                 " ".repeat(width as usize)
-            }), self.position.0, self.position.1 + i as u16, 0));  // the depth is 0, right?
+            }), self.position.0, self.position.1 + i as u16, self.depth));
         }
         render_closures
     }
@@ -915,12 +3080,64 @@ impl Window {
     /// for rendering in the terminal.
     /// Note: The closures capture the necessary variables by value to ensure
     /// they can be executed independently in a background thread.
-    pub fn get_render_closure (&mut self) -> RenderClosure {
+    /// `App::render` no longer uses these (it composites every window into a cell based `Frame` and
+    /// only writes the cells which changed, so hiding a window reveals whatever is beneath it), but they
+    /// remain for drawing windows line by line manually. The blank rows of a hidden window have its depth.
+    /// # Example
+    /// ```
+    /// use term_render::render::{App, Rect, Span, Window};
+    /// let mut app = App::new_headless(12, 3);
+    /// let mut background = Window::new((0, 0), 0, (12, 3));
+    /// background.from_lines(vec![Span::from("background"); 3]);
+    /// app.add_window(background, String::from("background"), vec![]).unwrap();
+    /// let mut popup = Window::new((2, 1), 10, (6, 1));
+    /// popup.from_lines(vec![Span::from("popup")]);
+    /// app.add_window(popup, String::from("popup"), vec![]).unwrap();
+    /// assert_eq!(app.snapshot()[1], "bapopup nd");
+    ///
+    /// // the next frame after hiding the popup shows the background intact
+    /// app.get_window_reference_mut(String::from("popup")).hide();
+    /// assert_eq!(app.snapshot(), vec!["background"; 3]);
+    ///
+    /// // when drawing line by line, the popup's blank row keeps its depth (so it's ordered like the popup was)
+    /// let mut popup = Window::new((2, 1), 10, (6, 1));
+    /// popup.get_render_closure(&Rect { width: 12, height: 3 });
+    /// popup.hide();
+    /// let rows = popup.get_render_closure(&Rect { width: 12, height: 3 });
+    /// assert_eq!(rows.into_iter().map(|(closure, x, y, depth)| (closure(), x, y, depth)).collect::<Vec<_>>(),
+    ///            vec![(String::from("      "), 2, 1, 10)]);
+    /// ```
+    /// The rows are clipped to the terminal's area (along with the window's clip region), so a window
+    /// partially off-screen only draws its visible part rather than wrapping onto the following rows.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{Rect, Span, Window};
+    /// let area = Rect { width: 30, height: 5 };
+    /// // a 40 wide window with only 10 columns and 3 rows on-screen
+    /// let mut window = Window::new((20, 2), 0, (40, 6));
+    /// window.bordered();
+    /// window.add_line(Span::from("some text which runs well past the right edge of the terminal"));
+    /// let rows = window.get_render_closure(&area);
+    /// assert_eq!(rows.len(), 3);  // nothing is drawn for the rows below the terminal
+    /// for (closure, x, y, _) in rows {
+    ///     assert!(x == 20 && y < area.height);
+    ///     assert_eq!(Span::from_ansi(&closure()).size(), 10);
+    /// }
+    /// ```
+    pub fn get_render_closure (&mut self, area: &Rect) -> RenderClosure {
+        self.refresh_wrapped();
+        self.refresh_scrollbar();
+        // the rows of a moved window are emitted again at the new position (from the cached lines)
+        if std::mem::take(&mut self.moved) {
+            self.updated.iter_mut().for_each(|updated| *updated = false);
+            self.was_updated = false;
+        }
         if self.was_updated {  return vec![];  }  // no re-rendering is needed
 
         let mut render_closures: RenderClosure = vec![];
         if self.hidden {
-            return self.handle_hidden_closure(render_closures);
+            let render_closures = self.handle_hidden_closure(render_closures);
+            return self.clip_closures(render_closures, area);
         }
 
         // these will need to be sorted by row, and the cursor movement is handled externally (the u16 pair)
@@ -928,76 +3145,252 @@ impl Window {
         self.was_updated = true;
 
         // make sure to not call UpdateRender when using closures
-        let bordered_size = {
-            if self.bordered {  1  }
-            else {  0  }
-        };
+        let (top, bottom, _left, _right) = self.get_border_insets();
+        let border = self.get_row_border();
+        let padding = (self.padding.left as usize, self.padding.right as usize);
+        let content = self.get_content_insets().0..self.get_content_insets().0 + self.get_content_height();
+        let bar_cells = self.scrollbar_cells.clone();
+        let orientation = self.scrollbar.map(|config| config.orientation);
         let mut updated = false;
-        for index in bordered_size..self.size.1 as usize - bordered_size {
+        for index in top..(self.size.1 as usize).saturating_sub(bottom) {
             if self.updated[index] {  continue;  }
             self.updated[index] = true;
             updated = true;
 
+            if orientation == Some(ScrollbarOrientation::Horizontal) && index + 1 + bottom == self.size.1 as usize {
+                let color = border_color.0.clone();
+                let cells = bar_cells.clone();
+                let closure = move || {  Window::render_horizontal_scrollbar(&color, border, &cells)  };
+                render_closures.push((Box::new(closure), self.position.0, self.position.1 + index as u16, self.depth + 1));
+                continue;
+            }
+            let bar_cell = (orientation == Some(ScrollbarOrientation::Vertical)).then(|| bar_cells.get(index - top).copied().flatten());
+
+            // the padding rows above and below the content are left blank (without a line)
+            let line_index = content.contains(&index).then(|| index - content.start + self.scroll_offset);
             let (text, size);
-            if index - bordered_size < self.lines.len() {
-                (text, size) = self.lines[index - bordered_size].0.join();
-                self.lines[index - bordered_size].1 = text.clone();
-                self.lines[index - bordered_size].2 = size;
-            } else {
-                (text, size) = (String::new(), 0);
+            match line_index {
+                Some(line_index) if self.wrap_mode != WrapMode::None => {
+                    (text, size) = self.wrapped.get(line_index).map(|span| span.join()).unwrap_or_default();
+                },
+                Some(line_index) if line_index < self.lines.len() => {
+                    // unchanged lines reuse the string they were joined into last time
+                    (text, size) = self.cache_line(line_index).cloned().unwrap_or_default();
+                },
+                _ => (text, size) = (String::new(), 0),
             }
 
             // creating the closure
             let color = border_color.clone();
             let window_size = self.size;  // idk a better way to do this other than cloning
-            let bordered = self.bordered;
+            let background = self.get_row_background(line_index);
+
+            let alignment = self.alignment;
+            let transparent = self.transparent;
 
             let closure = move || {
-                Window::render_window_slice(color, bordered, (text, size), window_size)
+                let (row_border, row_size) = Window::get_scrollbar_row_layout(border, window_size, bar_cell);
+                let mut row = Window::render_row(color.clone(), (row_border, padding), (text, size), alignment, background, transparent, row_size);
+                Window::push_scrollbar_border(&mut row, &color.0, border, bar_cell);
+                row
             };
             render_closures.push((Box::new(closure), self.position.0, self.position.1 + index as u16, self.depth + 1));
         }
 
-        if updated && self.bordered {
+        let chars = self.border_style.get_chars();
+        let sides = self.border_sides;
+        if updated && bottom == 1 {
             self.updated[self.size.1 as usize - 1] = true;
-            self.updated[0] = true;
 
             // adding the top and bottom lines to the closures
             let color = border_color.clone();
             let window_size = self.size.0;  // idk a better way to do this other than cloning
-            let closure = move || {  // top
-                let mut text = String::new();
-                text.push_str(&color.0);
-                text.push('└');
-                text.push_str(&"─".repeat(window_size as usize - 2));
-                text.push('┘');
-                text.push_str(CLEAR);
-                text
+            let title = self.bottom_title.clone();
+            let alignment = self.bottom_title_alignment;
+            let closure = move || {  // bottom
+                Window::render_bottom_border(&color.0, window_size, &title, alignment, &chars, sides)
             };
             render_closures.push((Box::new(closure), self.position.0, self.position.1 + self.size.1 - 1, self.depth + 1));
+        }
+        if updated && top == 1 {
+            self.updated[0] = true;
+            let color = border_color;  // consuming border color here
+            let window_size = self.size.0;  // idk a better way to do this other than cloning
+            let title = self.title.clone();
+            let alignment = self.title_alignment;
+            let closure = move || {
+                Window::render_top_border(&color.0, window_size, &title, alignment, &chars, sides)
+            };
+            render_closures.push((Box::new(closure), self.position.0, self.position.1, self.depth + 1));
+        }
+
+        self.clip_closures(render_closures, area)
+    }
+
+    /// Renders the top border line of a window, including the title at the given alignment.
+    /// The color is the escape code for the border (usually gathered through `Colored::get_text`).
+    /// The corners are only drawn where the sides have a border (otherwise the line runs to the edge).
+    pub fn render_top_border (color: &str,
+                              width: u16,
+                              title: &(Span, usize),
+                              alignment: Alignment,
+                              chars: &BorderChars,
+                              sides: BorderSides
+    ) -> String {
+        let left = if sides.contains(BorderSides::LEFT) {  chars.top_left  } else {  chars.top  };
+        let right = if sides.contains(BorderSides::RIGHT) {  chars.top_right  } else {  chars.top  };
+        Window::render_titled_border(color, width, title, alignment, (left, chars.top, right))
+    }
+
+    /// Renders the bottom border line of a window, including the bottom title (if any) at the given alignment.
+    /// The corners are only drawn where the sides have a border (otherwise the line runs to the edge).
+    pub fn render_bottom_border (color: &str,
+                                 width: u16,
+                                 title: &(Span, usize),
+                                 alignment: Alignment,
+                                 chars: &BorderChars,
+                                 sides: BorderSides
+    ) -> String {
+        let left = if sides.contains(BorderSides::LEFT) {  chars.bottom_left  } else {  chars.bottom  };
+        let right = if sides.contains(BorderSides::RIGHT) {  chars.bottom_right  } else {  chars.bottom  };
+        Window::render_titled_border(color, width, title, alignment, (left, chars.bottom, right))
+    }
+
+    // renders a horizontal border (left corner, fill, right corner) with a title placed along it
+    fn render_titled_border (color: &str,
+                             width: u16,
+                             title: &(Span, usize),
+                             alignment: Alignment,
+                             glyphs: (char, char, char)
+    ) -> String {
+        let mut text = String::new();
+        let horizontal = glyphs.1.to_string();
+        let inner = (width as usize).saturating_sub(2);
+        // a title wider than the window is cut off rather than pushing the corner out
+        let title_width = title.1.min(inner);
+        let spare = inner - title_width;
+        // titles at the edges keep a single segment between them and the corner (when there's room)
+        let leading = match alignment {
+            Alignment::Left => spare.min(1),
+            Alignment::Center => (width as usize / 2).saturating_sub(title_width / 2 + 1).min(spare),
+            Alignment::Right => spare - spare.min(1),
+        };
+        text.push_str(color);
+        text.push(glyphs.0);
+        text.push_str(&horizontal.repeat(leading));
+        text.push_str(CLEAR);
+        match title_width < title.1 {
+            true => text.push_str(&title.0.clamp(title_width).join().0),
+            false => text.push_str(&title.0.join().0),
+        }
+        text.push_str(color);
+        text.push_str(&horizontal.repeat(spare - leading));
+        text.push(glyphs.2);
+        text.push_str(CLEAR);
+        text
+    }
+
+    /// Renders every row of the window, regardless of which lines are marked as updated.
+    /// Unlike `get_render_closure`, this doesn't touch any of the dirty tracking, so it can
+    /// be used to inspect what the window currently looks like (i.e. for exporting a frame).
+    /// Returns the rows from top to bottom in the same format as the render closures would produce.
+    /// Hidden windows return no rows.
+    /// Lines are clamped and padded by their width in terminal columns, so wide characters (i.e. CJK
+    /// and emoji) keep the borders aligned; a wide character cut off by the edge becomes a space.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{get_text_width, Colorize, ColorType, Span, Window};
+    /// let mut window = Window::new((0, 0), 0, (11, 5));
+    /// window.bordered();
+    /// window.try_update_lines(vec![
+    ///     Span::from_tokens(vec!["日本語テスト".colorizes(vec![ColorType::Red])]),
+    ///     Span::from_tokens(vec!["👋🌍✨ hi".colorizes(vec![])]),
+    ///     Span::from_tokens(vec!["cafe\u{301}".colorizes(vec![])]),  // a combining accent
+    /// ]);
+    /// // dropping the escape codes to get the visible text of each row
+    /// let visible = |row: &String| {
+    ///     let mut in_escape = false;
+    ///     row.chars().filter(|chr| {
+    ///         if *chr == '\x1b' {  in_escape = true;  }
+    ///         else if in_escape {  in_escape = !chr.is_ascii_alphabetic();  return false;  }
+    ///         !in_escape
+    ///     }).collect::<String>()
+    /// };
+    /// let rows: Vec<String> = window.get_full_render().iter().map(visible).collect();
+    /// assert_eq!(rows[1], "│日本語テ │");  // the fifth character would have been cut in half
+    /// assert_eq!(rows[2], "│👋🌍✨ hi│");
+    /// assert_eq!(rows[3], "│cafe\u{301}     │");
+    /// assert!(rows.iter().all(|row| get_text_width(row) == 11));
+    /// ```
+    pub fn get_full_render (&self) -> Vec <String> {
+        let mut rows = vec![];
+        self.push_full_render(&mut rows);
+        rows
+    }
 
-            // bottom
-            let color = border_color;  // consuming border color here
-            let window_size = self.size.0;  // idk a better way to do this other than cloning
-            let title = self.title.clone();
-            let closure = move || {
-                let mut text = String::new();
-                text.push_str(&color.0);
-                text.push('┌');
-                let half = window_size / 2 - title.1 as u16 / 2 - 1;
-                text.push_str(&"─".repeat(half as usize));
-                text.push_str(CLEAR);
-                text.push_str(&title.0.join().0);
-                text.push_str(&color.0);
-                text.push_str(&"─".repeat(window_size as usize - 2 - half as usize - title.1));
-                text.push('┐');
-                text.push_str(CLEAR);
-                text
+    // renders every row into the given vector (see `get_full_render`), clearing it first so its
+    // allocation can be kept between renders
+    fn push_full_render (&self, rows: &mut Vec <String>) {
+        rows.clear();
+        if self.hidden || self.size.0 == 0 || self.size.1 == 0 {  return;  }
+        let border_color = self.color.get_text(&mut String::new());
+        let (top, bottom, _left, _right) = self.get_border_insets();
+        let border = self.get_row_border();
+        let chars = self.border_style.get_chars();
+
+        // the cached rows could be stale if lines changed since the last render
+        let rewrapped = match self.wrap_mode != WrapMode::None && self.wrap_outdated {
+            true => Some(self.compute_wrapped()),
+            false => None,
+        };
+        let wrapped = match self.wrap_mode {
+            WrapMode::None => None,
+            _ => Some(rewrapped.as_ref().unwrap_or(&self.wrapped)),
+        };
+
+        if top == 1 {
+            rows.push(Window::render_top_border(
+                &border_color.0, self.size.0, &self.title, self.title_alignment, &chars, self.border_sides
+            ));
+        }
+        let padding = (self.padding.left as usize, self.padding.right as usize);
+        let content = self.get_content_insets().0..self.get_content_insets().0 + self.get_content_height();
+        let bar_cells = self.get_scrollbar_cells();
+        let orientation = self.scrollbar.map(|config| config.orientation);
+        for index in top..(self.size.1 as usize).saturating_sub(bottom) {
+            if orientation == Some(ScrollbarOrientation::Horizontal) && index + 1 + bottom == self.size.1 as usize {
+                rows.push(Window::render_horizontal_scrollbar(&border_color.0, border, &bar_cells));
+                continue;
+            }
+            let bar_cell = (orientation == Some(ScrollbarOrientation::Vertical)).then(|| bar_cells.get(index - top).copied().flatten());
+            let (row_border, row_size) = Window::get_scrollbar_row_layout(border, self.size, bar_cell);
+            // the padding rows above and below the content are left blank
+            let (render_text, background) = match content.contains(&index) {
+                true => {
+                    let line_index = index - content.start + self.scroll_offset;
+                    let render_text = match &wrapped {
+                        Some(wrapped) => wrapped.get(line_index).map(|span| span.join()).unwrap_or_default(),
+                        None => match self.lines.get(line_index) {
+                            Some((_span, Some(cached))) => cached.clone(),
+                            Some((span, None)) => span.join(),
+                            None => (String::new(), 0),
+                        },
+                    };
+                    (render_text, self.get_row_background(Some(line_index)))
+                },
+                false => ((String::new(), 0), self.get_row_background(None)),
             };
-            render_closures.push((Box::new(closure), self.position.0, self.position.1, self.depth + 1));
+            let mut row = Window::render_row(
+                border_color.clone(), (row_border, padding), render_text, self.alignment, background, self.transparent, row_size
+            );
+            Window::push_scrollbar_border(&mut row, &border_color.0, border, bar_cell);
+            rows.push(row);
+        }
+        if bottom == 1 {
+            rows.push(Window::render_bottom_border(
+                &border_color.0, self.size.0, &self.bottom_title, self.bottom_title_alignment, &chars, self.border_sides
+            ));
         }
-
-        render_closures
     }
 
     // Gets the rendered text for the individual window
@@ -1010,80 +3403,10 @@ impl Window {
     /// This is mostly deprecated in favor of using `get_render_closure` for better performance
     /// and to allow for background rendering in a separate thread. This is no longer internally used,
     /// so any applications of it would need to be a manual call for a reason beyond the base rendering.
+    /// This now returns the same rows as `get_full_render`.
     pub fn get_render_deprecated(&self) -> Vec<String> {
-        let mut text = vec![String::new()];
-        let color = self.color.get_text(&mut String::new());
-
-        // handling the top border
-        let border_size =
-            if self.bordered {
-                let mut line_size = 1;
-                text[0].push_str(&color.0);
-                text[0].push('┌');
-                let split_size = (self.size.0 - 2) / 2 - self.title.1 as u16 / 2;
-                line_size += split_size;
-                text[0].push_str(&"─".repeat(split_size as usize));
-                line_size += self.title.1 as u16;
-                text[0].push_str(&self.title.0.join().0);
-                //let lineSize = text[0].len();
-                text[0].push_str(&"─".repeat(
-                    (self.size.0 as usize).saturating_sub(1 + line_size as usize)
-                ));
-                text[0].push('┐');
-                text[0].push_str(CLEAR);
-                //text[0].push('\n');  // fix this
-                text.push(String::new());
-                2
-            }
-            else {  0  };
-        let bordered = border_size / 2;
-        for index in bordered..self.size.1 as usize - bordered {
-            let line_text;
-            let line_size;
-            if index <= self.lines.len() {
-                let line = &self.lines[index - 1];//self.lines[0..self.size.1 as usize - borderSize][0];
-                line_text = Window::clamp_string_visible_utf_8(
-                    &line.1, self.size.0 as usize - border_size
-                );
-                line_size = std::cmp::min(self.lines[index - 1].2, self.size.0 as usize - border_size);
-            } else {
-                line_text = String::new();
-                line_size = 0;
-            }
-
-            // handling the side borders
-            if self.bordered {
-                text[index].push_str(&color.0);
-                text[index].push('│');
-                text[index].push_str(CLEAR);
-                text[index].push_str(&line_text);
-                text[index].push_str(CLEAR);
-                let padding = (self.size.0 as usize - 2) - line_size;
-                text[index].push_str(&" ".repeat(padding));
-                text[index].push_str(&color.0);
-                text[index].push('│');
-                text[index].push_str(CLEAR);
-            } else {
-                text[index].push_str(&line_text);
-                let padding = (self.size.0 as usize) - line_size;
-                text[index].push_str(&" ".repeat(padding));
-            }
-            text.push(String::new());
-        }
-
-        // handling the bottom border
-        let last_index = text.len() - 1;
-        if self.bordered {
-            text[last_index].push_str(&color.0);
-            text[last_index].push('└');
-            text[last_index].push_str(&"─".repeat(self.size.0 as usize - 2));
-            text[last_index].push('┘');
-            text[last_index].push_str(CLEAR);
-        } else {
-            // removing the last \n
-            text.pop();
-        }
-        text
+        // this used to be a separate (and out of date) copy of the rendering logic
+        self.get_full_render()
     }
 
     // Replaces a single line with an updated version
@@ -1093,17 +3416,34 @@ impl Window {
     /// The updated line is marked as needing to be re-rendered.
     pub fn update_line (&mut self, index: usize, span: Span) {
         if index >= self.lines.len() {  return;  }
-        self.lines[index] = (span, String::new(), 0);
-        self.updated[index] = false;
-        self.was_updated = false;
+        self.lines[index] = (span, None);
+        self.mark_line_updated(index);
     }
 
     // Appends a single line to the window
     /// Appends a new line to the window, and marks it as needing to be updated.
     pub fn add_line (&mut self, span: Span) {
-        self.lines.push((span, String::new(), 0));
-        self.updated.push(false);
-        self.was_updated = false;
+        self.lines.push((span, None));
+        self.mark_line_updated(self.lines.len() - 1);
+    }
+
+    /// Removes the line at the given index, shifting the following lines up.
+    /// Every row from the removed line down is re-rendered (the last one becomes blank).
+    /// If the index is out of bounds, the function does nothing.
+    pub fn remove_line (&mut self, index: usize) {
+        if index >= self.lines.len() {  return;  }
+        self.lines.remove(index);
+        for line in index..=self.lines.len() {
+            self.mark_line_updated(line);
+        }
+        self.scroll_offset = usize::min(self.scroll_offset, self.get_max_scroll());
+    }
+
+    /// Removes every line from the window. The rows they occupied are re-rendered as blanks.
+    pub fn clear_lines (&mut self) {
+        self.lines.clear();
+        self.scroll_offset = 0;
+        self.update_all();
     }
 
     // Takes a vector of type Span
@@ -1115,16 +3455,12 @@ impl Window {
     /// marking each line as needing an update.
     pub fn from_lines (&mut self, lines: Vec <Span>) {
         self.lines.clear();// self.updated.clear();
-        let mut index = {
-            if self.bordered {  1  }
-            else {  0  }
-        };
         for span in lines {
-            self.lines.push((span, String::new(), 0));
-            self.updated[index] = false;
-            self.was_updated = false;
-            index += 1;
+            self.lines.push((span, None));
         }
+        // the previous lines could've been longer, so every row is re-drawn
+        self.scroll_offset = usize::min(self.scroll_offset, self.get_max_scroll());
+        self.update_all();
     }
 
     // checks to see if any lines need to be updated
@@ -1133,28 +3469,44 @@ impl Window {
     /// the window is fully updated and all lines are replaced.
     /// If the number of lines is the same, only the lines that have changed are updated.
     /// The function returns true if any lines were updated, and false otherwise.
+    /// When there are fewer lines than before, the rows which are no longer used are rendered as blanks.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{Colorize, Span, Window};
+    /// let lines = |count: usize| (0..count).map(|index| {
+    ///     Span::from_tokens(vec![format!("Line {}", index).colorizes(vec![])])
+    /// }).collect::<Vec<Span>>();
+    /// let mut window = Window::new((0, 0), 0, (8, 10));
+    /// window.try_update_lines(lines(10));
+    /// let area = term_render::render::Rect { width: 20, height: 20 };
+    /// let _ = window.get_render_closure(&area);  // rendering everything once
+    /// assert!(window.try_update_lines(lines(3)));
+    /// let rows: Vec<(String, u16)> = window.get_render_closure(&area).into_iter().map(|(closure, _, y, _)| (closure(), y)).collect();
+    /// // dropping the escape codes to get the visible text of each row
+    /// let visible = |row: &String| row.replace("\x1b[0m", "");
+    /// for row in 3..=9 {
+    ///     let (text, _) = rows.iter().find(|(_, y)| *y == row).expect("the stale row wasn't re-rendered");
+    ///     assert_eq!(visible(text), " ".repeat(8));
+    /// }
+    /// ```
     pub fn try_update_lines (&mut self, mut lines: Vec <Span>) -> bool {
         if lines.len() != self.lines.len() {
             self.update_all();  // making sure every line gets updated (incase it was shrunk)
             self.was_updated = false;
             self.lines.clear();
-            for (index, span) in lines.into_iter().enumerate() {
-                if index >= self.updated.len() {  break;  }
-                self.lines.push((span, String::new(), 0));
+            for span in lines {
+                // lines past the bottom are kept so they can be scrolled into view
+                self.lines.push((span, None));
             }
+            self.scroll_offset = usize::min(self.scroll_offset, self.get_max_scroll());
             return true;
         }
         let mut index = lines.len();
-        let bordered = {
-            if self.bordered {  1  }
-            else {  0  }
-        };
         while let Some(span) = lines.pop() {
             index -= 1;  // the pop already subtracted one
             if self.lines[index].0 != span {
-                self.lines[index] = (span, String::new(), 0);
-                self.updated[index + bordered] = false;  // it was as easy as adding a plus 1....... me sad
-                self.was_updated = false;
+                self.lines[index] = (span, None);
+                self.mark_line_updated(index);
             }
         } self.was_updated
     }
@@ -1171,6 +3523,15 @@ impl Window {
             *line = false;
         }
         self.was_updated = false;
+        self.wrap_outdated = self.wrap_mode != WrapMode::None;
+    }
+
+    /// Drops the joined strings cached for each line (see `get_join_count`), which keep the escape codes
+    /// chosen when they were joined; they're rejoined when next rendered (i.e. once color is turned back on).
+    fn invalidate_line_cache (&mut self) {
+        for line in self.lines.iter_mut() {
+            line.1 = None;
+        }
     }
 
     /// Suppresses updates for all lines in the window.
@@ -1181,6 +3542,52 @@ impl Window {
         }
         self.was_updated = true;
     }
+
+    /// Places an image over the top left of the content area (scaled to fit it, see `Image::fit`), shown
+    /// through the given protocol after the window's cells are drawn. The window's lines are still drawn
+    /// beneath it (`Image::to_half_blocks` gives the usual fallback). The image is only shown while the
+    /// whole of it is visible: if it's scrolled, clipped, off screen, or a window above overlaps it, the
+    /// lines are shown instead. `Blocks` (or None) removes the image.
+    #[cfg(feature = "images")]
+    pub fn set_image (&mut self, image: Option <(Image, ImageProtocol)>) {
+        let image = image.filter(|(_image, protocol)| *protocol != ImageProtocol::Blocks);
+        if image == self.image {  return;  }
+        self.image = image;
+        self.update_all();
+    }
+
+    /// Gets the image placed over the content area and its protocol, if any (see `set_image`).
+    #[cfg(feature = "images")]
+    pub fn get_image (&self) -> Option <&(Image, ImageProtocol)> {
+        self.image.as_ref()
+    }
+
+    // where the image sits (position, size), if the whole of it is on screen, unscrolled, and unclipped
+    #[cfg(feature = "images")]
+    fn get_image_region (&self, area: &Rect) -> Option <((u16, u16), (u16, u16))> {
+        let (image, _protocol) = self.image.as_ref()?;
+        if self.hidden || self.scroll_offset != 0 {  return None;  }
+        let (top, _bottom, left, _right) = self.get_content_insets();
+        let size = image.fit(self.get_content_width() as u16, self.get_content_height() as u16);
+        let position = (self.position.0.checked_add(left as u16)?, self.position.1.checked_add(top as u16)?);
+        let (right, bottom) = (position.0 as u32 + size.0 as u32, position.1 as u32 + size.1 as u32);
+        if size.0 == 0 || size.1 == 0 || right > area.width as u32 || bottom > area.height as u32 {  return None;  }
+        if let Some((clip_position, clip_size)) = self.clip {
+            let inside = position.0 >= clip_position.0 && position.1 >= clip_position.1 &&
+                right <= clip_position.0 as u32 + clip_size.0 as u32 && bottom <= clip_position.1 as u32 + clip_size.1 as u32;
+            if !inside {  return None;  }
+        }
+        Some((position, size))
+    }
+
+    // whether any part of the window covers the region (position, size)
+    #[cfg(feature = "images")]
+    fn overlaps (&self, (position, size): ((u16, u16), (u16, u16))) -> bool {
+        let (x, y, width, height) = (self.position.0 as u32, self.position.1 as u32, self.size.0 as u32, self.size.1 as u32);
+        !self.hidden && width > 0 && height > 0 &&
+            x < position.0 as u32 + size.0 as u32 && (position.0 as u32) < x + width &&
+            y < position.1 as u32 + size.1 as u32 && (position.1 as u32) < y + height
+    }
 }
 
 
@@ -1194,6 +3601,32 @@ pub struct Rect {
     pub height: u16,
 }
 
+/// The shape of the terminal's cursor while it's shown (see `App::set_cursor_style`).
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash, Copy)]
+pub enum CursorStyle {
+    #[default] Block,
+    Underline,
+    Bar,
+}
+
+impl CursorStyle {
+    /// Gets the DECSCUSR escape code which switches the terminal's cursor to this style.
+    /// # Example:
+    /// ```
+    /// use term_render::render::CursorStyle;
+    /// assert_eq!(CursorStyle::Block.to_escape_code(true), "\x1b[1 q");
+    /// assert_eq!(CursorStyle::Bar.to_escape_code(false), "\x1b[6 q");
+    /// ```
+    pub fn to_escape_code (&self, blinking: bool) -> String {
+        let code = match self {
+            CursorStyle::Block => 1,
+            CursorStyle::Underline => 3,
+            CursorStyle::Bar => 5,
+        } + (!blinking || is_reduced_motion()) as u8;  // the steady versions follow the blinking ones
+        format!("\x1b[{} q", code)
+    }
+}
+
 // the main application. It stores and handles the active windows
 // It also handles rendering the cumulative sum of the windows
 /// The main application for rendering and managing windows in the terminal.
@@ -1208,11 +3641,55 @@ pub struct App {
     window_references: std::collections::HashMap <String, usize>,
     change_window_layout: bool,
     updated: bool,
-    render_handle: Option <std::thread::JoinHandle <()>>,
+    // finishes once the last frame has been written to the terminal
+    render_handle: Option <crossbeam::channel::Receiver <()>>,
+    // the workers windows are re-rendered on and frames are written from (see `set_render_threads`)
+    pool: crate::render_pool::RenderPool,
     buffer: std::sync::Arc <parking_lot::RwLock <String>>,
     reset_windows: bool,
     concluded_receiver: Option<crossbeam::channel::Receiver <()>>,
     pub concluded_sender: Option<crossbeam::channel::Sender <()>>,
+    // text drawn on top of every window (position, pre-colored text)
+    overlay: Vec <((u16, u16), String)>,
+    overlay_updated: bool,
+    // where the (hidden) cursor is left after rendering, so input method candidate windows appear there
+    ime_position: Option <(u16, u16)>,
+    ime_updated: bool,
+    // where the terminal's cursor is shown, if anywhere (see `set_cursor`)
+    cursor: Option <(u16, u16)>,
+    // the cursor's shape and whether it blinks, if it was changed (see `set_cursor_style`)
+    cursor_style: Option <(CursorStyle, bool)>,
+    cursor_updated: bool,
+    // escape codes written before the next frame (i.e. clipboard requests), so they don't interleave with one
+    pending_output: String,
+    // whether frames are wrapped in synchronized updates, if it was set (see `set_synchronized_output`)
+    synchronized_output: Option <bool>,
+    // whether the terminal reported supporting synchronized updates, once it answers
+    synchronized_support: Option <bool>,
+    // what the terminal is currently showing, which the next frame is diffed against (None redraws everything)
+    previous_frame: Option <Frame>,
+    // the order windows are composited in, kept between frames to reuse its allocation
+    draw_order: Vec <usize>,
+    // the color mode generation the windows were last rendered with (switching modes redraws everything)
+    color_mode_generation: usize,
+    theme: Theme,
+    // whether the terminal was already returned to its normal state (see `restore_terminal`)
+    terminal_restored: bool,
+    // while suspended, whether the enhanced keyboard should be re-enabled when resuming (see `suspend_terminal`)
+    suspended: Option <bool>,
+    // the fake terminal size when rendering into memory rather than the terminal (see `new_headless`)
+    headless: Option <Rect>,
+    // the first row and height of the region rendered to when rendering inline (see `new_inline`)
+    inline: Option <(u16, u16)>,
+    // whether the terminal is dumb, so frames are written out as plain text (see `is_dumb_terminal`)
+    dumb: bool,
+    // the terminal's size, which is only used once it stops changing (see `set_resize_debounce`)
+    resize: crate::render_resize::ResizeDebouncer,
+    // the images shown as of the last frame written, and the ones the next frame will show (see `Window::set_image`)
+    #[cfg(feature = "images")]
+    placed_images: Vec <crate::render_image::ImagePlacement>,
+    #[cfg(feature = "images")]
+    image_placements: Vec <crate::render_image::ImagePlacement>,
 }
 
 /// Cleans up the terminal state when the App instance is dropped.
@@ -1222,6 +3699,7 @@ pub struct App {
 /// such as hidden cursors or altered screen buffers.
 impl Drop for App {
     fn drop (&mut self) {
+        if self.terminal_restored || self.suspended.is_some() || self.headless.is_some() {  return;  }
         // should prevent clearing the screen if an error was thrown
         let error = if let Some(receiver) = self.concluded_receiver.take() {
             if receiver.try_recv().is_err() {
@@ -1233,19 +3711,7 @@ impl Drop for App {
                 false
             }
         } else {  false  };
-        if !error {  print!("\x1B[?1049l");  }
-        
-        event_handler::disable_mouse_capture();
-        crossterm::terminal::disable_raw_mode().unwrap();
-
-        print!("{SHOW_CURSOR}");  // showing the cursor
-
-        // clearing the screen
-        print!("\x1B[0m");
-        print!("\x1B[2K\x1B[E");
-
-        // I don't really care if an error is thrown at this point
-        let _ = std::io::stdout().flush();
+        self.reset_terminal(!error);
     }
 }
 
@@ -1255,34 +3721,255 @@ impl App {
     /// and sets up the alternate screen buffer. It also clears the terminal screen
     /// and hides the cursor. The function returns a Result containing the new App instance
     /// or an error if any of the terminal operations fail.
+    ///
+    /// Color is turned off if the NO_COLOR environment variable is set (see `set_color_enabled`). On a dumb
+    /// terminal (see `is_dumb_terminal`), only raw mode is enabled (no alternate screen or mouse capture),
+    /// and every frame which changed is written out in full as plain text.
     pub fn new () -> std::io::Result<Self> {  // 1049h
-        event_handler::enable_mouse_capture();
-        crossterm::terminal::enable_raw_mode()?;
-        
-        print!("\x1B[0m");
-        print!("\x1B[2K\x1B[E");
-        
-        print!("\x1B7");
-        print!("\x1B[?1049h");
-        print!("\x1B[?25l");
-        
-        let mut stdout = std::io::stdout();
-        crossterm::execute!(stdout, crossterm::terminal::Clear(crossterm::terminal::ClearType::All))?;
-        
+        let dumb = crate::render_capabilities::detect_capabilities();
+        App::setup_terminal(dumb)?;
+        let mut app = App::with_headless(None);
+        app.dumb = dumb;
+        app.resize.watch_signal();
+        Ok(app)
+    }
+
+    /// Creates a renderer which draws into `height` rows beneath the cursor rather than taking over the whole
+    /// screen (like a picker shown below the shell's prompt). The terminal is scrolled if there isn't room, and
+    /// the alternate screen isn't used, so the shell's history stays intact. Raw mode and mouse capture are
+    /// still enabled.
+    ///
+    /// Window positions are relative to the top of the region, and the area is the terminal's width by
+    /// `height` (or fewer rows if the terminal is shorter). When the app is dropped (or the terminal restored),
+    /// the region is cleared and the cursor is left where it started, so the next output follows the prompt.
+    /// A dumb terminal can't be drawn to in place, so this falls back to `new`.
+    /// # Example:
+    /// ```no_run
+    /// use term_render::render::{App, Span, Window};
+    /// let mut app = App::new_inline(3).unwrap();
+    /// assert_eq!(app.poll_terminal_size().unwrap().1, 3);
+    /// let mut window = Window::new((0, 0), 0, (20, 3));
+    /// window.bordered();
+    /// window.add_line(Span::from("inline"));
+    /// app.add_window(window, String::from("window"), vec![]).unwrap();
+    /// app.render(None);
+    /// ```
+    pub fn new_inline (height: u16) -> std::io::Result<Self> {
+        if crate::render_capabilities::detect_capabilities() {  return App::new();  }
+        let origin = App::setup_inline(height)?;
+        let mut app = App::with_headless(None);
+        app.inline = Some((origin, height));
+        app.resize.watch_signal();
+        Ok(app)
+    }
+
+    /// Returns the first row and the height of the region rendered to, if rendering inline (see `new_inline`).
+    pub fn get_inline_region (&self) -> Option <(u16, u16)> {
+        self.inline
+    }
+
+    /// Creates a renderer which composites into memory rather than the terminal, for testing what windows
+    /// and widgets render without a real terminal (see `snapshot`). The terminal is never touched:
+    /// nothing is written to stdout, raw mode is left alone, and `get_terminal_size` returns the given size.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{App, Span, Window};
+    /// let mut app = App::new_headless(20, 5);
+    /// let mut window = Window::new((1, 1), 0, (12, 3));
+    /// window.bordered();
+    /// window.titled(String::from("Title"));
+    /// window.try_update_lines(vec![Span::from_tokens(vec![term_render::render::Colored::new(String::from("Hello"))])]);
+    /// app.add_window(window, String::from("window"), vec![]).unwrap();
+    /// assert_eq!(app.snapshot(), vec![
+    ///     "",
+    ///     " ┌───Title──┐",
+    ///     " │Hello     │",
+    ///     " └──────────┘",
+    ///     "",
+    /// ]);
+    /// ```
+    pub fn new_headless (width: u16, height: u16) -> Self {
+        App::with_headless(Some(Rect { width, height }))
+    }
 
+    // the renderer's initial state, without setting up the terminal
+    fn with_headless (headless: Option <Rect>) -> Self {
         let (sender, receiver) = crossbeam::channel::unbounded();
-        Ok(App {
+        App {
             area: Rect::default(),
             active_windows: vec![],
             window_references: std::collections::HashMap::new(),
             change_window_layout: true,
             updated: true,
             render_handle: None,
+            pool: crate::render_pool::RenderPool::default(),
             buffer: std::sync::Arc::new(parking_lot::RwLock::new(String::new())),
             reset_windows: false,
             concluded_receiver: Some(receiver),
             concluded_sender: Some(sender),
-        })
+            overlay: vec![],
+            overlay_updated: false,
+            ime_position: None,
+            ime_updated: false,
+            cursor: None,
+            cursor_style: None,
+            cursor_updated: false,
+            pending_output: String::new(),
+            synchronized_output: None,
+            synchronized_support: None,
+            previous_frame: None,
+            draw_order: vec![],
+            color_mode_generation: ColorMode::get_generation(),
+            theme: Theme::default(),
+            terminal_restored: false,
+            suspended: None,
+            inline: None,
+            dumb: false,
+            resize: crate::render_resize::ResizeDebouncer::new(match headless {
+                Some(_) => std::time::Duration::ZERO,
+                None => DEFAULT_RESIZE_DEBOUNCE,
+            }),
+            headless,
+            #[cfg(feature = "images")]
+            placed_images: vec![],
+            #[cfg(feature = "images")]
+            image_placements: vec![],
+        }
+    }
+
+    // raw mode, mouse capture, and the alternate screen (undone by `reset_terminal`); dumb terminals only get raw mode
+    fn setup_terminal (dumb: bool) -> std::io::Result<()> {
+        crossterm::terminal::enable_raw_mode()?;
+        if dumb {  return Ok(());  }
+        // the console has to be in virtual terminal mode before the escape codes (including mouse capture) are understood
+        #[cfg(windows)]
+        event_handler::enable_virtual_terminal()?;
+        event_handler::enable_mouse_capture();
+        
+        print!("\x1B[0m");
+        print!("\x1B[2K\x1B[E");
+        
+        print!("\x1B7");
+        print!("\x1B[?1049h");
+        print!("\x1B[?25l");
+        print!("\x1B[?2004h");  // bracketed paste (pastes are wrapped in ^[[200~ and ^[[201~)
+        print!("\x1B[?2026$p");  // asking whether synchronized output is supported (see `set_synchronized_output`)
+        
+        let mut stdout = std::io::stdout();
+        crossterm::execute!(stdout, crossterm::terminal::Clear(crossterm::terminal::ClearType::All))?;
+        Ok(())
+    }
+
+    // raw mode and mouse capture, with `height` rows reserved beneath the cursor rather than the alternate
+    // screen (undone by `reset_terminal`); returns the row the reserved region starts on
+    fn setup_inline (height: u16) -> std::io::Result<u16> {
+        #[cfg(windows)]
+        event_handler::enable_virtual_terminal()?;
+        // making room (scrolling the terminal if the cursor is near the bottom) before raw mode stops newlines
+        // from returning to the start of the line
+        let mut stdout = std::io::stdout();
+        write!(stdout, "\r\x1B[0m{}", "\n".repeat(height.saturating_sub(1) as usize))?;
+        if height > 1 {  write!(stdout, "\x1B[{}A", height - 1)?;  }
+        stdout.flush()?;
+        crossterm::terminal::enable_raw_mode()?;
+        // (asked before mouse capture, so no mouse events are mixed in with the answer)
+        let (_column, row) = crossterm::cursor::position()?;
+        event_handler::enable_mouse_capture();
+
+        print!("\x1B[?25l");
+        print!("\x1B[?2004h");  // bracketed paste (pastes are wrapped in ^[[200~ and ^[[201~)
+        print!("\x1B[?2026$p");  // asking whether synchronized output is supported (see `set_synchronized_output`)
+        Ok(row)
+    }
+
+    // clears the rows of the inline region (see `new_inline`)
+    fn clear_inline_region (&self) {
+        let Some((origin, height)) = self.inline else {  return;  };
+        for row in origin..origin.saturating_add(height) {
+            print!("\x1B[{};1H\x1B[2K", row + 1);
+        }
+    }
+
+    /// Temporarily hands the terminal back (leaving the alternate screen and raw mode, and showing the cursor),
+    /// such as before stopping the process or running another program in the terminal.
+    /// Nothing is drawn until `resume_terminal` is called. Suspending twice does nothing.
+    pub fn suspend_terminal (&mut self) {
+        if self.suspended.is_some() || self.terminal_restored || self.headless.is_some() {  return;  }
+        self.suspended = Some(event_handler::is_enhanced_keyboard_enabled());
+        self.reset_terminal(true);
+    }
+
+    /// Takes the terminal back after `suspend_terminal`, re-entering raw mode and the alternate screen
+    /// (or reserving the inline region again beneath the cursor, see `new_inline`).
+    /// Every window is redrawn (whatever was on the screen in the meantime is unknown).
+    pub fn resume_terminal (&mut self) -> std::io::Result<()> {
+        let Some(enhanced_keyboard) = self.suspended.take() else {  return Ok(());  };
+        match self.inline {
+            Some((_origin, height)) => self.inline = Some((App::setup_inline(height)?, height)),
+            None => App::setup_terminal(self.dumb)?,
+        }
+        if enhanced_keyboard {  event_handler::enable_enhanced_keyboard();  }
+        self.reset_windows = true;
+        self.cursor_updated = true;  // the cursor's style was reset while suspended
+        self.previous_frame = None;
+        Ok(())
+    }
+
+    /// Returns whether the terminal is currently suspended (see `suspend_terminal`).
+    pub fn is_suspended (&self) -> bool {
+        self.suspended.is_some()
+    }
+
+    /// Returns the terminal to its normal state: leaving the alternate screen, exiting raw mode,
+    /// and showing the cursor. This happens automatically when the App is dropped, but can be done early
+    /// so anything printed afterwards (such as errors reported while shutting down) is visible.
+    /// Nothing is drawn after restoring, and restoring more than once does nothing.
+    pub fn restore_terminal (&mut self) {
+        if self.terminal_restored {  return;  }
+        self.terminal_restored = true;
+        // a suspended terminal was already handed back
+        if self.suspended.is_none() {  self.reset_terminal(true);  }
+    }
+
+    // undoes everything `setup_terminal` did (the alternate screen is kept when an error should stay visible)
+    fn reset_terminal (&mut self, leave_alternate_screen: bool) {
+        // the last frame could still be being written
+        if let Some(handle) = self.render_handle.take() {
+            let _ = handle.recv();
+        }
+        if self.headless.is_some() {  return;  }
+        if self.dumb {
+            let _ = crossterm::terminal::disable_raw_mode();
+            return;
+        }
+        match self.inline {
+            // clearing the region and returning to where it started (or leaving it be below an error)
+            Some((origin, _height)) if leave_alternate_screen => {
+                self.clear_inline_region();
+                print!("\x1B[{};1H", origin + 1);
+            },
+            Some((origin, height)) => print!("\x1B[{};1H", origin.saturating_add(height) + 1),
+            None if leave_alternate_screen => print!("\x1B[?1049l"),
+            None => {},
+        }
+
+        event_handler::disable_mouse_capture();
+        event_handler::disable_enhanced_keyboard();
+        print!("\x1B[?2004l");  // disabling bracketed paste
+        // this can run while unwinding, where panicking again would abort
+        let _ = crossterm::terminal::disable_raw_mode();
+        #[cfg(windows)]
+        event_handler::disable_virtual_terminal();
+
+        if self.cursor_style.is_some() {  print!("\x1b[0 q");  }  // the terminal's default cursor style
+        print!("{SHOW_CURSOR}");  // showing the cursor
+
+        // clearing the screen
+        print!("\x1B[0m");
+        if self.inline.is_none() {  print!("\x1B[2K\x1B[E");  }
+
+        // I don't really care if an error is thrown at this point
+        let _ = std::io::stdout().flush();
     }
 
     /// Checks if a window with the given name exists.
@@ -1325,7 +4012,91 @@ impl App {
     /// Gets the current terminal size as (width, height).
     /// This function returns a Result containing the terminal size or an error if it fails to retrieve it.
     pub fn get_terminal_size (&self) -> Result <(u16, u16), std::io::Error> {
-        crossterm::terminal::size()
+        match &self.headless {
+            Some(size) => Ok((size.width, size.height)),
+            None => crossterm::terminal::size(),
+        }
+    }
+
+    /// Gets the terminal size to render at, as (width, height). While the terminal is being resized, its new size
+    /// is only used once it's stayed the same for the debounce interval (see `set_resize_debounce`), or right away
+    /// if either dimension changed by at least `LARGE_RESIZE` cells. On unix, the size is only read from the
+    /// terminal again after a SIGWINCH, rather than on every call (see `get_size_queries`).
+    /// # Example
+    /// ```
+    /// use term_render::render::App;
+    /// let mut app = App::new_headless(40, 10);
+    /// app.set_resize_debounce(std::time::Duration::from_millis(50));
+    /// assert_eq!(app.poll_terminal_size().unwrap(), (40, 10));
+    /// // small changes wait for the size to settle
+    /// app.set_headless_size(42, 11);
+    /// assert_eq!(app.poll_terminal_size().unwrap(), (40, 10));
+    /// app.set_headless_size(43, 11);
+    /// assert_eq!(app.poll_terminal_size().unwrap(), (40, 10));
+    /// std::thread::sleep(std::time::Duration::from_millis(60));
+    /// assert_eq!(app.poll_terminal_size().unwrap(), (43, 11));
+    /// // while large ones are used right away
+    /// app.set_headless_size(100, 30);
+    /// assert_eq!(app.poll_terminal_size().unwrap(), (100, 30));
+    /// ```
+    /// When rendering inline (see `new_inline`), this is the terminal's width by the height of the region.
+    pub fn poll_terminal_size (&mut self) -> Result <(u16, u16), std::io::Error> {
+        let headless = self.headless.as_ref().map(|size| (size.width, size.height));
+        let inline = self.inline;
+        self.resize.poll(|| {
+            let size = match headless {
+                Some(size) => size,
+                None => crossterm::terminal::size()?,
+            };
+            Ok(match inline {
+                Some((origin, height)) => (size.0, height.min(size.1.saturating_sub(origin))),
+                None => size,
+            })
+        })
+    }
+
+    /// Sets how long a new terminal size has to stay the same before it's used (see `poll_terminal_size`).
+    /// The default is `DEFAULT_RESIZE_DEBOUNCE` (100ms), or zero for headless renderers.
+    pub fn set_resize_debounce (&mut self, debounce: std::time::Duration) {
+        self.resize.set_debounce(debounce);
+    }
+
+    /// Gets how long a new terminal size has to stay the same before it's used (see `set_resize_debounce`).
+    pub fn get_resize_debounce (&self) -> std::time::Duration {
+        self.resize.get_debounce()
+    }
+
+    /// Gets the number of times `poll_terminal_size` read the terminal's size (i.e. to check it isn't read
+    /// every frame).
+    pub fn get_size_queries (&self) -> usize {
+        self.resize.get_queries()
+    }
+
+    /// Returns whether the renderer composites into memory rather than the terminal (see `new_headless`).
+    pub fn is_headless (&self) -> bool {
+        self.headless.is_some()
+    }
+
+    /// Changes the fake terminal size of a headless renderer (i.e. to test resizing), which
+    /// takes effect on the next render. This does nothing when rendering to the terminal.
+    pub fn set_headless_size (&mut self, width: u16, height: u16) {
+        if let Some(size) = &mut self.headless {
+            *size = Rect { width, height };
+        }
+    }
+
+    /// Renders any pending changes, then returns the text of each row of the frame (without any
+    /// escape codes, and with trailing spaces trimmed). This is mainly meant for snapshot testing
+    /// with a headless renderer (see `new_headless`).
+    pub fn snapshot (&mut self) -> Vec <String> {
+        self.render(None);
+        self.previous_frame.as_ref().map(|frame| frame.to_lines()).unwrap_or_default()
+    }
+
+    /// The same as `snapshot`, but keeping the colors as SGR escape codes (see `Frame::to_styled_lines`).
+    pub fn snapshot_styled (&mut self) -> Vec <String> {
+        self.render(None);
+        self.previous_frame.as_ref().map(|frame| frame.to_styled_lines()).unwrap_or_default()
     }
 
     // Gets the current window size and position
@@ -1340,36 +4111,50 @@ impl App {
     /// The window is identified by a unique name and can be associated with keywords for searching or
     /// categorization. If the window is not hidden, it will trigger a layout change.
     /// The function updates the internal state to reflect the addition of the new window.
-    pub fn add_window (&mut self, window: Window, name: String, keywords: Vec <String>) {
+    /// Windows with a theme slot are colored by the current theme.
+    /// Returns `Err(RenderError::DuplicateWindow)` (without adding anything) if a window with the name already exists.
+    /// # Example
+    /// ```
+    /// use term_render::render::{App, RenderError, Window};
+    /// let mut app = App::new_headless(10, 3);
+    /// app.add_window(Window::new((0, 0), 0, (2, 1)), String::from("a"), vec![]).unwrap();
+    /// app.add_window(Window::new((0, 0), 0, (3, 1)), String::from("b"), vec![]).unwrap();
+    /// assert!(matches!(app.add_window(Window::new((0, 0), 0, (4, 1)), String::from("a"), vec![]), Err(RenderError::DuplicateWindow(_))));
+    /// // removing and re-adding a name keeps every name pointing at its own window
+    /// app.remove_window(String::from("a")).unwrap();
+    /// app.add_window(Window::new((0, 0), 0, (5, 1)), String::from("a"), vec![]).unwrap();
+    /// app.add_window(Window::new((0, 0), 0, (6, 1)), String::from("c"), vec![]).unwrap();
+    /// assert_eq!(app.get_window_reference(String::from("a")).size, (5, 1));
+    /// assert_eq!(app.get_window_reference(String::from("b")).size, (3, 1));
+    /// assert_eq!(app.get_window_reference(String::from("c")).size, (6, 1));
+    /// ```
+    pub fn add_window (&mut self, mut window: Window, name: String, keywords: Vec <String>) -> Result <(), RenderError> {
+        if self.window_references.contains_key(&name) {  return Err(RenderError::DuplicateWindow(name));  }
+        window.apply_theme(&self.theme);
         if !window.hidden {  self.change_window_layout = true;  }  // if the window is hidden, it shouldn't change anything
-        self.window_references.insert(name, self.window_references.len());
+        self.window_references.insert(name, self.active_windows.len());
         self.active_windows.push((window, keywords));
         //self.updated = true;
+        Ok(())
     }
 
     // Pops an active window.
     // Returns Ok(window) if the index is valid, or Err if out of bounds
     /// Removes a window by its name.
     /// Returns `Ok(Window)` if the window was found and removed successfully,
-    /// or `Err(String)` if no window with the specified name exists.
+    /// or `Err(RenderError::WindowNotFound)` if no window with the specified name exists.
     /// This function also updates the internal state to reflect the removal,
     /// including adjusting the indices of remaining windows and marking the layout
     /// as changed.
-    pub fn remove_window (&mut self, name: String) -> Result <Window, String> {
+    pub fn remove_window (&mut self, name: String) -> Result <Window, RenderError> {
         self.change_window_layout = true;
         self.reset_windows = true;
         //self.updated = true;
 
-        if !self.window_references.contains_key(&name) {
-            return Err(format!("No window named '{}' found", name));
-        }
         // the None case would produce a value too large, so it should throw the expected error
         let index = *self.window_references.get(&name).unwrap_or(&usize::MAX);
         if index >= self.active_windows.len() {
-            return Err(format!(
-                "Invalid index; Accessed at {}, but the size is {}",
-                index, self.active_windows.len()
-            ));
+            return Err(RenderError::WindowNotFound(name));
         }
 
         // updating the references list
@@ -1396,19 +4181,15 @@ impl App {
         std::ops::Range<usize>: Iterator<Item = usize>
     {
         let mut visible = 0;
-        let mut in_escape = false;
+        let mut linked = false;
         let mut slice = String::new();
-        for chr in text.chars() {
+        let mut chars = text.chars();
+        while let Some(chr) = chars.next() {
             if chr == '\x1b' {
-                in_escape = true;
-
-                // making sure to keep the initial escape codes
-                slice.push(chr);
-            } else if in_escape {
-                in_escape = chr != 'm';
-
                 // making sure to keep the initial escape codes
-                slice.push(chr);
+                let sequence = read_escape_sequence(&mut chars);
+                if let Some(url) = crate::render_frame::parse_hyperlink(&sequence) {  linked = url.is_some();  }
+                slice.push_str(&sequence);
             } else {
                 visible += 1;
                 if visible >= range.start {
@@ -1417,10 +4198,13 @@ impl App {
                         slice.push(chr);
                         continue;
                     }
-                    return slice;  // no need to continue
+                    break;  // no need to continue
                 }
             }
-        } slice
+        }
+        // closing any hyperlink the end of the range cut off
+        if linked {  push_hyperlink(&mut slice, None);  }
+        slice
     }
 
     /// Handles any changes needed for rendering the windows, such as resizing or resetting.
@@ -1431,12 +4215,25 @@ impl App {
         let handle = self.render_handle.take();
         if let Some(handle) = handle {
             // if an error was thrown, I don't care anymore
-            let _ = handle.join();
+            let _ = handle.recv();
         }
 
         self.buffer.write().clear();
-        if size.0 != self.area.width || size.1 != self.area.height || self.reset_windows {
+        let color_mode_generation = ColorMode::get_generation();
+        let resized = size.0 != self.area.width || size.1 != self.area.height;
+        // when the terminal only grew, the new cells can just be painted (the whole frame is redrawn anyway),
+        // rather than clearing the screen, which flashes while the terminal is being resized
+        let grew = resized && size.0 >= self.area.width && size.1 >= self.area.height && self.previous_frame.is_some();
+        if resized || self.reset_windows || color_mode_generation != self.color_mode_generation {
+            let clear = !grew || self.reset_windows || color_mode_generation != self.color_mode_generation;
+            if color_mode_generation != self.color_mode_generation {
+                // hidden windows too, since they'd otherwise be shown with the old escape codes
+                for window in &mut self.active_windows {
+                    window.0.invalidate_line_cache();
+                }
+            }
             self.reset_windows = false;
+            self.color_mode_generation = color_mode_generation;
             *self.buffer.write() = String::with_capacity((size.0 * size.1 * 3) as usize);
 
             // making sure the windows get updated
@@ -1447,7 +4244,13 @@ impl App {
             }
             
             // replace with an actual clear..... this doesn't work (it just shifts the screen--or does it???)
-            print!("\x1b[2J\x1b[H");  // re-clearing the screen (everything will need to update)
+            if self.headless.is_none() && !self.dumb && clear {
+                match self.inline {
+                    Some(_) => self.clear_inline_region(),
+                    None => print!("\x1b[2J\x1b[H"),  // re-clearing the screen (everything will need to update)
+                }
+            }
+            self.previous_frame = None;
         }
     }
 
@@ -1455,12 +4258,19 @@ impl App {
     // It also clears the screen from previous writing
     /// Renders all the active windows to the console.
     /// Optionally, the terminal size can be provided to avoid recalculating it.
-    /// Returns the number of draw calls made during the render (useful for debugging
-    /// lazy rendering and other optimizations).
+    /// The windows are composited by depth into a cell based `Frame`, which is diffed against the
+    /// previous frame so only the cells which changed are written to the terminal.
+    /// Returns the number of cursor moves made during the render, which is roughly the number of
+    /// changed runs of cells (useful for debugging lazy rendering and other optimizations).
     pub fn render (&mut self, terminal_size: Option <(u16, u16)>) -> usize {
+        // drawing now would garble the normal screen
+        if self.terminal_restored || self.suspended.is_some() {  return 0;  }
         // incase the size is needed and thus calculated elsewhere (to prevent recalculation which is slow)
         // (aka I'm too lazy to update the code I already made.....)
-        let size = terminal_size.unwrap_or(self.get_terminal_size().unwrap());
+        let size = match terminal_size {
+            Some(size) => size,
+            None => self.poll_terminal_size().unwrap(),
+        };
         self.handle_render_window_changes(&size);
 
         self.area = Rect {
@@ -1472,65 +4282,186 @@ impl App {
         // this should reduce CPU usage by a fair bit and allow a fast refresh rate if needed
         let mut updated = false;
         for window in &self.active_windows {
-            if window.0.was_updated {  continue;  }
+            if window.0.was_updated && !window.0.moved {  continue;  }
             updated = true;
             break;
         }
-        if !updated {  return 0;  }
+        if !updated && !self.overlay_updated && !self.ime_updated && !self.cursor_updated &&
+            self.pending_output.is_empty() && self.previous_frame.is_some() {  return 0;  }
+        self.overlay_updated = false;
+        self.ime_updated = false;
+        let cursor_updated = std::mem::take(&mut self.cursor_updated);
         
-        // stores the draw calls
-        let mut draw_calls = vec![];
-
-        // going through the sorted windows
-        for window in &mut self.active_windows {
-            //let window = &mut self.activeWindows[*index];
-            draw_calls.append(&mut window.0.get_render_closure());
-        }
-
-        let num_calls = draw_calls.len();
-
+        let frame = self.compose_frame();
+        if self.dumb {  return self.write_plain_frame(frame);  }
         let size = (self.area.width, self.area.height);
-        let buffer = self.buffer.clone();
-        //println!("Num calls: {}", drawCalls.len());
-        self.render_handle = Some(std::thread::spawn(move || {
-            // the buffer for the render string
-
-            // sorting the calls by action row (and left to right for same row calls)
-            // drawCall.3 is the depth; higher numbers will be rendered last thus being on top (each depth is a unique layer)
-            draw_calls.sort_by_key(|draw_call| draw_call.2 as usize * size.0 as usize + draw_call.1 as usize + draw_call.3 as usize * size.0 as usize * size.1 as usize);
-
-            // iterating through the calls (consuming drawCalls)
-            let write_buffer = &mut *buffer.write();
-            for call in draw_calls {
-                // moving the cursor into position
-                // ESC[{line};{column}H
-                write_buffer.push_str("\x1b[");
-                App::push_u16(write_buffer, call.2);
-                write_buffer.push(';');
-                App::push_u16(write_buffer, call.1);
-                write_buffer.push('H');
-
-                let output = call.0();
-                write_buffer.push_str(&output);
+        let ime_position = self.ime_position;
+        let cursor = self.cursor;
+
+        let write_buffer = &mut *self.buffer.write();
+        write_buffer.push_str(&std::mem::take(&mut self.pending_output));
+        // the cursor would be seen jumping around while the frame is drawn
+        if cursor.is_some() || cursor_updated {  write_buffer.push_str(HIDE_CURSOR);  }
+        if cursor_updated && let Some((style, blinking)) = self.cursor_style {
+            write_buffer.push_str(&style.to_escape_code(blinking));
+        }
+        let frame_start = write_buffer.len();
+        let row_offset = self.inline.map_or(0, |(origin, _height)| origin);
+        let num_moves = frame.diff_at(self.previous_frame.as_ref(), write_buffer, row_offset);
+        // images go over the cells they cover, once those are written
+        #[cfg(feature = "images")]
+        {
+            if self.previous_frame.is_none() {
+                // everything was redrawn, so the images need placing again (and kitty's removing)
+                crate::render_image::ImagePlacement::push_updates(&std::mem::take(&mut self.placed_images), &[], write_buffer);
             }
+            crate::render_image::ImagePlacement::push_updates(&self.placed_images, &self.image_placements, write_buffer);
+            self.placed_images = std::mem::take(&mut self.image_placements);
+        }
+        self.previous_frame = Some(frame);
+        // presenting the frame at once, rather than as it's written (only when something was drawn)
+        if num_moves > 0 && self.is_synchronized_output() {
+            write_buffer.insert_str(frame_start, BEGIN_SYNCHRONIZED_UPDATE);
+            write_buffer.push_str(END_SYNCHRONIZED_UPDATE);
+        }
+        // leaving the cursor where it's shown, or where text is being typed (the terminal places the
+        // ime candidates there), or otherwise in the bottom right corner
+        // (positions are 0-based, while the terminal's cursor addressing is 1-based)
+        let position = cursor.or(ime_position).map(|position| (position.0.saturating_add(1), position.1.saturating_add(1))).unwrap_or(size);
+        write_buffer.push_str("\x1b[");
+        App::push_u16(write_buffer, position.1.saturating_add(row_offset));
+        write_buffer.push(';');
+        App::push_u16(write_buffer, position.0);
+        write_buffer.push('H');
+        if cursor.is_some() {  write_buffer.push_str(SHOW_CURSOR);  }
+
+        // writing to the terminal in the background (it's joined at the start of the next render)
+        if self.headless.is_some() {  return num_moves;  }
+        let buffer = self.buffer.clone();
+        self.render_handle = Some(self.pool.execute(move || {
+            let mut out = std::io::stdout().lock();
+            out.write_all(buffer.read().as_bytes()).unwrap();
+            out.flush().unwrap();
+        }));
 
-            // moving the cursor to the bottom right
-            write_buffer.push_str("\x1b[");
-            App::push_u16(write_buffer, size.1);
-            write_buffer.push(';');
-            App::push_u16(write_buffer, size.0);
-            write_buffer.push_str("H ");
+        num_moves
+    }
 
-            // rendering the buffer
+    // dumb terminals can't move the cursor, so each frame which changed is written out in full (returning
+    // 1 if it was, as the frame starts on a new line rather than moving the cursor)
+    fn write_plain_frame (&mut self, frame: Frame) -> usize {
+        self.pending_output.clear();  // (escape codes would only show up as garbage)
+        if self.previous_frame.as_ref() == Some(&frame) {  return 0;  }
+        {
+            let write_buffer = &mut *self.buffer.write();
+            for line in frame.to_lines() {
+                write_buffer.push_str(&line);
+                write_buffer.push_str("\r\n");
+            }
+        }
+        self.previous_frame = Some(frame);
+        let buffer = self.buffer.clone();
+        self.render_handle = Some(self.pool.execute(move || {
             let mut out = std::io::stdout().lock();
-            out.write_all(write_buffer.as_bytes()).unwrap();
+            out.write_all(buffer.read().as_bytes()).unwrap();
             out.flush().unwrap();
         }));
+        1
+    }
+
+    /// Overrides how many colors are written (see `ColorDepth`), which is otherwise detected from the
+    /// environment when the renderer is created (see `ColorDepth::detect`). Every window is re-rendered
+    /// on the next frame. This is global, applying to any other renderer as well.
+    pub fn set_color_depth (&mut self, depth: ColorDepth) {
+        set_color_depth(depth);
+    }
+
+    /// Gets how many colors are written (see `set_color_depth`).
+    pub fn get_color_depth (&self) -> ColorDepth {
+        get_color_depth()
+    }
+
+    /// Returns whether the terminal is dumb, so frames are written out as plain text (see `new`).
+    pub fn is_dumb (&self) -> bool {
+        self.dumb
+    }
+
+    /// Composites every visible window (by depth) and the overlay into a frame the size of the area.
+    /// Only windows which changed since the last frame re-render their rows; the rest reuse them.
+    fn compose_frame (&mut self) -> Frame {
+        let mut frame = Frame::new(self.area.width, self.area.height);
+        let mut order = std::mem::take(&mut self.draw_order);
+        order.clear();
+        order.extend(0..self.active_windows.len());
+        order.sort_by_key(|index| self.active_windows[*index].0.get_stacking());  // stable, so equal depths keep their order
+
+        // the windows which changed are re-rendered in parallel (each into its own rows), then
+        // drawn in order so the deeper windows still end up on top
+        let mut changed = vec![];
+        for &index in &order {
+            let window = &mut self.active_windows[index].0;
+            if window.hidden {  window.mark_rendered();  }
+            else if window.needs_render() {  changed.push((index, std::mem::take(window)));  }
+        }
+        let rendered = self.pool.map(changed, |(index, mut window)| {
+            window.refresh_rendered_rows();
+            (index, window)
+        });
+        for (index, window) in rendered {
+            self.active_windows[index].0 = window;
+        }
+        for &index in &order {
+            let window = &mut self.active_windows[index].0;
+            window.moved = false;
+            if window.hidden {  continue;  }
+            window.draw_rows(&window.rendered_rows, &mut frame);
+        }
+        #[cfg(feature = "images")]
+        self.place_images(&order, &mut frame);
+        self.draw_order = order;
+        for (position, text) in &self.overlay {
+            frame.write_str(position.0, position.1, text);
+        } frame
+    }
 
-        num_calls
+    // finds where each window's image goes (skipping the ones a window above overlaps), reserving the
+    // frame's cells beneath them so they aren't drawn over the images
+    #[cfg(feature = "images")]
+    fn place_images (&mut self, order: &[usize], frame: &mut Frame) {
+        self.image_placements.clear();
+        for (depth, &index) in order.iter().enumerate() {
+            let window = &self.active_windows[index].0;
+            let Some(region) = window.get_image_region(&self.area) else {  continue;  };
+            if order[depth + 1..].iter().any(|above| self.active_windows[*above].0.overlaps(region)) {  continue;  }
+            let Some((image, protocol)) = window.image.clone() else {  continue;  };
+            let placement = crate::render_image::ImagePlacement::new(image, protocol, region.0, region.1);
+            frame.reserve(region.0, region.1, placement.key);
+            self.image_placements.push(placement);
+        }
+    }
 
-        //let elapsed = start.elapsed();
-        //panic!("Render thread completed in {:?}", elapsed);
+    /// Gets the regions (position, size) of the images shown as of the last render (see `Window::set_image`).
+    /// Images which are hidden (i.e. because a window above overlaps them) aren't included.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{App, Image, ImageProtocol, Window};
+    /// let mut app = App::new_headless(20, 10);
+    /// let mut window = Window::new((2, 1), 0, (10, 6));
+    /// window.bordered();
+    /// window.set_image(Some((Image::new(4, 4, vec![255; 64]).unwrap(), ImageProtocol::Kitty)));
+    /// app.add_window(window, String::from("image"), vec![]).unwrap();
+    /// app.render(None);
+    /// // a square image covers twice as many columns as rows
+    /// assert_eq!(app.get_image_regions(), vec![((3, 2), (8, 4))]);
+    ///
+    /// // a window on top hides it
+    /// app.add_window(Window::new((10, 5), 1, (4, 4)), String::from("above"), vec![]).unwrap();
+    /// app.render(None);
+    /// assert!(app.get_image_regions().is_empty());
+    /// ```
+    #[cfg(feature = "images")]
+    pub fn get_image_regions (&self) -> Vec <((u16, u16), (u16, u16))> {
+        self.placed_images.iter().map(|placement| (placement.position, placement.size)).collect()
     }
 
     /// Takes an u16 value and pushes the text form of it in an efficient manner.
@@ -1561,7 +4492,8 @@ impl App {
     }
 
     /// Prunes all windows which contain one of the specified keywords.
-    /// Returns the number of windows pruned.
+    /// Returns the number of windows pruned. Windows belonging to widgets should be removed through
+    /// `widget::Scene::remove_widgets_by_keyword` instead, which removes the widgets along with them.
     pub fn prune_by_keywords (&mut self, keywords: Vec <String>) -> usize {
         let mut pruned = vec![];
         for (index, window) in self.active_windows.iter().enumerate() {
@@ -1607,7 +4539,7 @@ impl App {
     /// Prunes all windows based on a given key (closure).
     /// Returns the number of windows pruned.
     /// If the closure returns true, the element is pruned. If it returns false it's kept.
-    pub fn prune_by_key (&mut self, key: Box <dyn Fn (&Vec <String>) -> bool>) -> usize {
+    pub fn prune_by_key (&mut self, key: WindowKey) -> usize {
         let mut pruned = vec![];
         for (index, window) in self.active_windows.iter().enumerate() {
             if key(&window.1) {
@@ -1658,7 +4590,7 @@ impl App {
     /// Gets the names to all windows which satisfy the given key (closure).
     /// If the closure returns true, the name is provided. Otherwise, it's
     /// considered unrelated.
-    pub fn get_windows_by_key (&self, key: Box <dyn Fn (&Vec <String>) -> bool>) -> Vec <&String> {
+    pub fn get_windows_by_key (&self, key: WindowKey) -> Vec <&String> {
         let mut names = vec![];
         for name in &self.window_references {
             if key(&self.active_windows[*name.1].1) {
@@ -1674,6 +4606,205 @@ impl App {
         self.active_windows[window_index].1.contains(keyword)
     }
 
+    /// Sets the theme, recoloring every window with a theme slot and re-rendering all of them.
+    pub fn set_theme (&mut self, theme: Theme) {
+        self.theme = theme;
+        for (window, _keywords) in &mut self.active_windows {
+            window.apply_theme(&self.theme);
+            window.update_all();
+        }
+    }
+
+    /// Gets the current theme.
+    pub fn get_theme (&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Sets the overlay, which is drawn above every window regardless of depth.
+    /// Each entry is a (0-based) position and a pre-colored string (i.e. from `Span::join`) drawn starting there.
+    /// This is intended for debugging visualizations (such as the scene's hit-test overlay) rather
+    /// than regular content, as the overlay doesn't participate in the window layout at all.
+    /// Setting an identical overlay is a no-op, so this can be safely called every frame.
+    pub fn set_overlay (&mut self, overlay: Vec <((u16, u16), String)>) {
+        if overlay == self.overlay {  return;  }
+        self.overlay = overlay;
+        self.overlay_updated = true;
+    }
+
+    /// Removes the overlay (see `set_overlay`).
+    pub fn clear_overlay (&mut self) {
+        self.set_overlay(vec![]);
+    }
+
+    /// Returns the current overlay (see `set_overlay`).
+    pub fn get_overlay (&self) -> &Vec <((u16, u16), String)> {
+        &self.overlay
+    }
+
+    /// Sets where the cursor is left after each render (0-based, like window positions), or None for the default.
+    /// The cursor stays hidden, but terminals position input method candidate windows relative to it,
+    /// so text inputs set this to their insertion point while selected.
+    pub fn set_ime_position (&mut self, position: Option <(u16, u16)>) {
+        if position == self.ime_position {  return;  }
+        self.ime_position = position;
+        self.ime_updated = true;
+    }
+
+    /// Returns the position the cursor is left at after rendering (see `set_ime_position`).
+    pub fn get_ime_position (&self) -> Option <(u16, u16)> {
+        self.ime_position
+    }
+
+    /// Shows the terminal's cursor at the given cell (0-based, like window positions) after each render,
+    /// or hides it again with None. Unlike a cursor drawn as part of a window's text, the real
+    /// cursor blinks and is tracked by screen readers. The cursor is hidden by default.
+    /// Widgets can request the cursor instead (see `Widget::cursor_position`).
+    pub fn set_cursor (&mut self, position: Option <(u16, u16)>) {
+        if position == self.cursor {  return;  }
+        self.cursor = position;
+        self.cursor_updated = true;
+    }
+
+    /// Returns where the terminal's cursor is shown, if anywhere (see `set_cursor`).
+    pub fn get_cursor (&self) -> Option <(u16, u16)> {
+        self.cursor
+    }
+
+    /// Sets the shape of the terminal's cursor and whether it blinks, for while it's shown (see `set_cursor`).
+    /// The terminal's default style is put back when the terminal is restored.
+    pub fn set_cursor_style (&mut self, style: CursorStyle, blinking: bool) {
+        if self.cursor_style == Some((style, blinking)) {  return;  }
+        self.cursor_style = Some((style, blinking));
+        self.cursor_updated = true;
+    }
+
+    /// Returns the cursor's shape and whether it blinks, or None if the terminal's default is used.
+    pub fn get_cursor_style (&self) -> Option <(CursorStyle, bool)> {
+        self.cursor_style
+    }
+
+    /// Copies text to the terminal's clipboard (through OSC 52, so it also works over ssh).
+    /// The request is written with the next render. Returns false without copying anything if the
+    /// text is over `MAX_CLIPBOARD_BYTES`, as some terminals drop larger requests.
+    /// Terminals without OSC 52 support (or with it disabled) silently ignore the request.
+    /// # Example
+    /// ```
+    /// let mut app = term_render::render::App::new_headless(20, 5);
+    /// assert!(app.set_clipboard("copied text"));
+    /// assert!(!app.set_clipboard(&"a".repeat(term_render::render::MAX_CLIPBOARD_BYTES + 1)));
+    /// ```
+    pub fn set_clipboard (&mut self, text: &str) -> bool {
+        let Some(code) = crate::render_clipboard::copy_escape_code(text) else {  return false;  };
+        self.pending_output.push_str(&code);
+        true
+    }
+
+    /// Asks the terminal for the clipboard's contents, which arrive asynchronously (usually a frame
+    /// or two later) in `KeyParser::clipboard_content`. Terminals which don't allow reading the
+    /// clipboard never reply, so nothing should wait on the answer.
+    pub fn request_clipboard (&mut self) {
+        self.pending_output.push_str(crate::render_clipboard::PASTE_REQUEST_CODE);
+    }
+
+    /// Sets whether each frame is wrapped in a synchronized update (DEC mode 2026), so the terminal
+    /// presents the whole frame at once rather than showing it half drawn (which shows up as tearing or
+    /// flicker when lots of windows change at once). Frames where nothing changed aren't wrapped.
+    /// By default, this is enabled unless the terminal reports not supporting it (it's queried when the
+    /// terminal is set up). Terminals which support it (such as kitty, iTerm2, WezTerm, and foot) hold the
+    /// frame until it's complete; others ignore the unknown mode and draw the frame as it arrives, as before.
+    /// # Example
+    /// ```
+    /// let mut app = term_render::render::App::new_headless(20, 5);
+    /// assert!(app.is_synchronized_output());
+    /// app.set_synchronized_output(false);
+    /// assert!(!app.is_synchronized_output());
+    /// ```
+    pub fn set_synchronized_output (&mut self, enabled: bool) {
+        self.synchronized_output = Some(enabled);
+    }
+
+    /// Returns whether frames are wrapped in synchronized updates (see `set_synchronized_output`).
+    pub fn is_synchronized_output (&self) -> bool {
+        self.synchronized_output.unwrap_or(self.synchronized_support != Some(false))
+    }
+
+    /// Sets the number of worker threads the renderer uses. When several windows change in the same frame,
+    /// they're re-rendered in parallel across the workers (and then drawn in depth order, as usual), and each
+    /// frame is written to the terminal from a worker in the background. With a single thread, windows are
+    /// re-rendered on the calling thread. The workers live as long as the renderer (they're joined when it's
+    /// dropped), and aren't started until they're first needed. Zero is treated as a single thread.
+    /// By default, the number of available cores is used, up to 4.
+    /// # Example
+    /// ```
+    /// use term_render::render::{App, Span, Window};
+    /// let mut app = App::new_headless(20, 4);
+    /// app.set_render_threads(2);
+    /// assert_eq!(app.get_render_threads(), 2);
+    /// for (index, text) in ["one", "two", "three", "four"].into_iter().enumerate() {
+    ///     let mut window = Window::new((0, index as u16), index as u16, (20, 1));
+    ///     window.add_line(Span::from(text));
+    ///     app.add_window(window, text.to_owned(), vec![]).unwrap();
+    /// }
+    /// app.render(None);
+    /// assert_eq!(app.snapshot()[2].trim_end(), "three");
+    /// ```
+    pub fn set_render_threads (&mut self, threads: usize) {
+        // the last frame could still be being written by the current workers
+        if let Some(handle) = self.render_handle.take() {
+            let _ = handle.recv();
+        }
+        self.pool.set_threads(threads);
+    }
+
+    /// Gets the number of worker threads the renderer uses (see `set_render_threads`).
+    pub fn get_render_threads (&self) -> usize {
+        self.pool.get_threads()
+    }
+
+    // records the terminal's answer to whether it supports synchronized updates
+    pub(crate) fn set_synchronized_support (&mut self, supported: bool) {
+        self.synchronized_support = Some(supported);
+    }
+
+    /// Composites all the visible windows into a cell based `Frame` the size of the last rendered area.
+    /// Windows are drawn in order of depth the same way `render` composites them, so the frame
+    /// represents what the terminal is showing (or would show after the next render).
+    /// Unlike the frames being rendered, this doesn't include the overlay.
+    pub fn screenshot (&self) -> Frame {
+        let mut frame = Frame::new(self.area.width, self.area.height);
+        let mut windows: Vec <&Window> = self.active_windows.iter().map(|window| &window.0).collect();
+        windows.sort_by_key(|window| window.get_stacking());  // stable, so equal depths keep their order
+        for window in windows {
+            window.draw_to_frame(&mut frame);
+        } frame
+    }
+
+    /// Exports the current frame in the given format (i.e. html or svg for documentation, or plain text
+    /// for bug reports and golden tests). This is a shorthand for `screenshot().export(format)`, so
+    /// overlapping windows are resolved into the grid the terminal would show.
+    /// # Example
+    /// ```
+    /// use term_render::render::{App, ExportFormat, Span, Window};
+    /// let mut app = App::new_headless(8, 3);
+    /// // the higher window is added first, but still covers the lower one where they overlap
+    /// let mut high = Window::new((3, 1), 1, (4, 1));
+    /// high.add_line(Span::from("high"));
+    /// app.add_window(high, String::from("high"), vec![]).unwrap();
+    /// let mut low = Window::new((0, 0), 0, (6, 2));
+    /// low.from_lines(vec![Span::from("lowest"), Span::from("lower!")]);
+    /// app.add_window(low, String::from("low"), vec![]).unwrap();
+    /// app.render(None);
+    /// assert_eq!(app.export_frame(ExportFormat::Plain), "lowest\nlowhigh\n\n");
+    /// ```
+    pub fn export_frame (&self, format: ExportFormat) -> String {
+        self.screenshot().export(format)
+    }
+
+    /// Writes the current frame to a file, in the format its extension implies (see `ExportFormat::from_path`).
+    pub fn export_frame_to (&self, path: impl AsRef <std::path::Path>) -> std::io::Result <()> {
+        std::fs::write(path.as_ref(), self.export_frame(ExportFormat::from_path(path.as_ref())))
+    }
+
     /// Returns whether the window layout has changed since the last call to render.
     pub fn changed_window_layout (&self) -> bool {
         self.change_window_layout
@@ -0,0 +1,117 @@
+// Parsing strings which already contain escape codes (i.e. the output of a syntax highlighter or
+// `git diff --color`) into Spans. The SGR codes are resolved into each token's colors, so the text
+// within the tokens is plain and all of the width logic works on what's actually visible.
+
+use crate::render::{CellColor, CellStyle, Colored, ColorType, Span, read_escape_sequence};
+
+/// The foreground colors matching the 16 base palette indexes (normal, then bright).
+static FOREGROUNDS: [ColorType; 16] = [
+    ColorType::Black, ColorType::Red, ColorType::Green, ColorType::Yellow,
+    ColorType::Blue, ColorType::Magenta, ColorType::Cyan, ColorType::White,
+    ColorType::BrightBlack, ColorType::BrightRed, ColorType::BrightGreen, ColorType::BrightYellow,
+    ColorType::BrightBlue, ColorType::BrightMagenta, ColorType::BrightCyan, ColorType::BrightWhite,
+];
+
+/// The background colors matching the 16 base palette indexes (normal, then bright).
+static BACKGROUNDS: [ColorType; 16] = [
+    ColorType::OnBlack, ColorType::OnRed, ColorType::OnGreen, ColorType::OnYellow,
+    ColorType::OnBlue, ColorType::OnMagenta, ColorType::OnCyan, ColorType::OnWhite,
+    ColorType::OnBrightBlack, ColorType::OnBrightRed, ColorType::OnBrightGreen, ColorType::OnBrightYellow,
+    ColorType::OnBrightBlue, ColorType::OnBrightMagenta, ColorType::OnBrightCyan, ColorType::OnBrightWhite,
+];
+
+impl CellStyle {
+    /// Converts the style into the equivalent colors and modifiers (the hyperlink isn't included).
+    pub fn to_color_types (&self) -> Vec <ColorType> {
+        let mut colors = vec![];
+        let modifiers = [
+            (self.bold, ColorType::Bold), (self.dim, ColorType::Dim), (self.italic, ColorType::Italic),
+            (self.underline, ColorType::Underline), (self.blink, ColorType::Blink),
+            (self.reverse, ColorType::Reverse), (self.hidden, ColorType::Hide),
+        ];
+        for (enabled, modifier) in modifiers {
+            if enabled {  colors.push(modifier);  }
+        }
+        match self.fg {
+            None => {},
+            Some(CellColor::Indexed(index @ 0..=15)) => colors.push(FOREGROUNDS[index as usize]),
+            Some(CellColor::Indexed(index)) => colors.push(ColorType::Ansi(index)),
+            Some(CellColor::Rgb(r, g, b)) => colors.push(ColorType::Rgb(r, g, b)),
+        }
+        match self.bg {
+            None => {},
+            Some(CellColor::Indexed(index @ 0..=15)) => colors.push(BACKGROUNDS[index as usize]),
+            Some(CellColor::Indexed(index)) => colors.push(ColorType::OnANSI(index)),
+            Some(CellColor::Rgb(r, g, b)) => colors.push(ColorType::OnRGB(r, g, b)),
+        } colors
+    }
+}
+
+impl Span {
+    /// Parses a string containing SGR escape codes into a span, with one `Colored` token per run of
+    /// identically styled text. Resets, the modifiers, the 16 base colors (30-37, 90-97, 40-47, and 100-107),
+    /// and the 256-color and 24-bit colors (38;5, 38;2, 48;5, and 48;2) are understood. Hyperlinks (OSC 8)
+    /// are kept, while every other escape code (i.e. cursor movement or window titles) is stripped.
+    /// Each token only contains visible text, so the span's size and clamping are exact.
+    /// # Example
+    /// ```
+    /// use term_render::render::{Colored, ColorType, Frame, Span};
+    /// let original = "\x1b[1;31merror\x1b[0m: \x1b[38;5;208mexpected\x1b[39m \x1b[48;2;0;51;102m`;`\x1b[m\x1b[2K!";
+    /// let span = Span::from_ansi(original);
+    /// assert_eq!(span.size(), 20);
+    /// assert_eq!(span.get_tokens(), &[
+    ///     Colored::get_from_color_types_str("error", vec![ColorType::Bold, ColorType::Red]),
+    ///     Colored::get_from_color_types_str(": ", vec![]),
+    ///     Colored::get_from_color_types_str("expected", vec![ColorType::Ansi(208)]),
+    ///     Colored::get_from_color_types_str(" ", vec![]),
+    ///     Colored::get_from_color_types_str("`;`", vec![ColorType::OnRGB(0, 51, 102)]),
+    ///     Colored::get_from_color_types_str("!", vec![]),
+    /// ]);
+    ///
+    /// // rendering the span gives the same cells (text and styles) as the original string
+    /// for original in [
+    ///     original,
+    ///     "\x1b[32m+ added\x1b[0m \x1b[9;2;4mstruck\x1b[24m \x1b[7;94;103mswapped\x1b[27m",
+    ///     "plain \x1b]0;window title\x07text \x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\ \x1b[1A",
+    /// ] {
+    ///     let (mut expected, mut rendered) = (Frame::new(40, 1), Frame::new(40, 1));
+    ///     expected.write_str(0, 0, original);
+    ///     rendered.write_str(0, 0, &Span::from_ansi(original).join().0);
+    ///     assert_eq!(rendered.to_styled_lines(), expected.to_styled_lines());
+    /// }
+    /// ```
+    pub fn from_ansi (text: &str) -> Span {
+        let mut tokens = vec![];
+        let mut style = CellStyle::default();
+        // the style of the text accumulated so far, so style changes without any text between them are free
+        let mut run_style = CellStyle::default();
+        let mut current = String::new();
+        let mut chars = text.chars();
+        while let Some(chr) = chars.next() {
+            if chr == '\x1b' {
+                let sequence = read_escape_sequence(&mut chars);
+                if let Some(params) = sequence.strip_prefix("\x1b[").and_then(|sequence| sequence.strip_suffix('m')) {
+                    style.apply_sgr(params);
+                } else if let Some(url) = crate::render_frame::parse_hyperlink(&sequence) {
+                    style.link = url.map(|url| url.into());
+                }  // anything else is dropped
+                continue;
+            }
+            if style != run_style {
+                Self::push_ansi_run(&mut tokens, &mut current, &run_style);
+                run_style = style.clone();
+            }
+            current.push(chr);
+        }
+        Self::push_ansi_run(&mut tokens, &mut current, &run_style);
+        Span::from_tokens(tokens)
+    }
+
+    /// Pushes the accumulated text (if any) as a token with the given style.
+    fn push_ansi_run (tokens: &mut Vec <Colored>, text: &mut String, style: &CellStyle) {
+        if text.is_empty() {  return;  }
+        let mut token = Colored::get_from_color_types_str(&std::mem::take(text), style.to_color_types());
+        token.set_link(style.link.as_deref());
+        tokens.push(token);
+    }
+}
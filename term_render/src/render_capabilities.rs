@@ -0,0 +1,231 @@
+// What the terminal (and the user) can handle. NO_COLOR (https://no-color.org) and terminals without color
+// support turn off every color and text modifier, a dumb terminal (`TERM=dumb`, or no TERM at all on unix)
+// also can't move its cursor so frames are written out as plain text, and the reduced motion flag stops
+// anything from blinking or animating. Terminals with fewer colors get the nearest ones they can show (see
+// `ColorDepth`). These are global, as colors are resolved wherever text is generated.
+
+/// How many colors the terminal can show. Colors beyond it are swapped for the nearest ones it can show
+/// when they're resolved (see `ColorType::get_color`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ColorDepth {
+    /// No colors at all, only the text modifiers (bold, underline, etc.).
+    Mono,
+    /// The 16 base colors, whose exact look depends on the terminal's theme.
+    Ansi16,
+    /// The 256 color palette (the base colors, a 6x6x6 color cube, and a grayscale ramp).
+    Ansi256,
+    /// Any 24-bit color (the default, until the terminal is detected).
+    #[default] TrueColor,
+}
+
+impl ColorDepth {
+    /// Works out the terminal's color depth from the environment: COLORTERM set to `truecolor` or `24bit`
+    /// means 24-bit colors, a TERM containing `256color` means the 256 color palette, a dumb terminal has
+    /// no colors, and anything else is assumed to have the 16 base colors. The Windows console (which has no
+    /// TERM) supports 24-bit colors.
+    pub fn detect () -> ColorDepth {
+        if is_dumb_terminal() {  return ColorDepth::Mono;  }
+        let color_term = std::env::var("COLORTERM").unwrap_or_default();
+        if color_term == "truecolor" || color_term == "24bit" {  return ColorDepth::TrueColor;  }
+        match std::env::var("TERM") {
+            Ok(term) if term.contains("256color") => ColorDepth::Ansi256,
+            Ok(_) => ColorDepth::Ansi16,
+            Err(_) => ColorDepth::TrueColor,
+        }
+    }
+}
+
+/// Sets how many colors are written (see `ColorDepth`), re-rendering every window on the next frame. It's
+/// detected when the renderer is created (see `ColorDepth::detect`), and can be overridden afterwards
+/// (see `render::App::set_color_depth`).
+/// # Example:
+/// ```standalone_crate
+/// use term_render::render::{self, ColorDepth, ColorType};
+/// let code = |color: ColorType| color.get_color().unwrap_into_tuple().0;
+/// render::set_color_depth(ColorDepth::Ansi256);
+/// assert_eq!(code(ColorType::Rgb(255, 128, 0)), Some(String::from("38;5;208")));
+/// assert_eq!(code(ColorType::OnRGB(30, 30, 30)), Some(String::from("48;5;234")));
+/// assert_eq!(code(ColorType::Ansi(208)), Some(String::from("38;5;208")));
+/// render::set_color_depth(ColorDepth::Ansi16);
+/// assert_eq!(code(ColorType::Rgb(255, 128, 0)), Some(String::from("33")));
+/// assert_eq!(code(ColorType::OnANSI(21)), Some(String::from("44")));
+/// assert_eq!(code(ColorType::Red), Some(String::from("31")));
+/// render::set_color_depth(ColorDepth::Mono);
+/// assert_eq!(code(ColorType::Rgb(255, 128, 0)), None);
+/// assert_eq!(code(ColorType::Red), None);
+/// assert_eq!(ColorType::Bold.get_color().unwrap_into_tuple().1, vec![String::from("1")]);
+/// render::set_color_depth(ColorDepth::TrueColor);
+/// assert_eq!(code(ColorType::Rgb(255, 128, 0)), Some(String::from("38;2;255;128;0")));
+/// ```
+pub fn set_color_depth (depth: ColorDepth) {
+    let depth = depth as u8;
+    if COLOR_DEPTH.swap(depth, std::sync::atomic::Ordering::Relaxed) != depth {
+        crate::render::ColorMode::bump_generation();
+    }
+}
+
+/// Gets how many colors are written (see `set_color_depth`).
+pub fn get_color_depth () -> ColorDepth {
+    match COLOR_DEPTH.load(std::sync::atomic::Ordering::Relaxed) {
+        0 => ColorDepth::Mono,
+        1 => ColorDepth::Ansi16,
+        2 => ColorDepth::Ansi256,
+        _ => ColorDepth::TrueColor,
+    }
+}
+
+/// Gets the nearest color of the 256 color palette, out of the 6x6x6 color cube and the grayscale ramp (the
+/// base 16 colors are left out, as they look different on every terminal).
+/// # Example:
+/// ```
+/// use term_render::render::rgb_to_ansi256;
+/// assert_eq!(rgb_to_ansi256((0, 0, 0)), 16);
+/// assert_eq!(rgb_to_ansi256((255, 255, 255)), 231);
+/// assert_eq!(rgb_to_ansi256((255, 0, 0)), 196);
+/// assert_eq!(rgb_to_ansi256((255, 128, 0)), 208);
+/// assert_eq!(rgb_to_ansi256((100, 150, 200)), 68);
+/// assert_eq!(rgb_to_ansi256((128, 128, 128)), 244);
+/// assert_eq!(rgb_to_ansi256((30, 30, 30)), 234);
+/// ```
+pub fn rgb_to_ansi256 (rgb: (u8, u8, u8)) -> u8 {
+    // the nearest level of the cube for each channel, and the nearest step of the ramp for the average
+    let levels = CUBE_LEVELS.get_or_init(|| std::array::from_fn(|value| {
+        (0..6u8).min_by_key(|level| (CUBE_STEPS[*level as usize] as i32 - value as i32).abs()).unwrap_or(0)
+    }));
+    let cube = 16 + 36 * levels[rgb.0 as usize] + 6 * levels[rgb.1 as usize] + levels[rgb.2 as usize];
+    let average = (rgb.0 as u16 + rgb.1 as u16 + rgb.2 as u16) / 3;
+    let gray = 232 + ((average.saturating_sub(3) / 10).min(23)) as u8;
+    match color_distance(rgb, crate::render::ansi_256_to_rgb(gray)) < color_distance(rgb, crate::render::ansi_256_to_rgb(cube)) {
+        true => gray,
+        false => cube,
+    }
+}
+
+/// Gets the nearest of the 16 base colors to a color of the 256 color palette (the base colors map to themselves).
+/// # Example:
+/// ```
+/// use term_render::render::ansi256_to_ansi16;
+/// assert_eq!(ansi256_to_ansi16(4), 4);
+/// assert_eq!(ansi256_to_ansi16(196), 9);
+/// assert_eq!(ansi256_to_ansi16(208), 3);  // orange is closest to (the xterm) yellow
+/// assert_eq!(ansi256_to_ansi16(21), 4);
+/// assert_eq!(ansi256_to_ansi16(28), 2);
+/// assert_eq!(ansi256_to_ansi16(244), 8);
+/// assert_eq!(ansi256_to_ansi16(234), 0);
+/// assert_eq!(ansi256_to_ansi16(231), 15);
+/// ```
+pub fn ansi256_to_ansi16 (index: u8) -> u8 {
+    let table = BASE_COLORS.get_or_init(|| std::array::from_fn(|index| match index {
+        0..=15 => index as u8,
+        _ => {
+            let rgb = crate::render::ansi_256_to_rgb(index as u8);
+            (0..16u8).min_by_key(|base| color_distance(rgb, crate::render::ansi_256_to_rgb(*base))).unwrap_or(0)
+        },
+    }));
+    table[index as usize]
+}
+
+/// The "redmean" approximation of how different two colors look (cheaper than a proper color space, but
+/// much closer to how they're seen than the plain distance between the rgb values).
+fn color_distance (a: (u8, u8, u8), b: (u8, u8, u8)) -> i32 {
+    let mean_red = (a.0 as i32 + b.0 as i32) / 2;
+    let (red, green, blue) = (a.0 as i32 - b.0 as i32, a.1 as i32 - b.1 as i32, a.2 as i32 - b.2 as i32);
+    (((512 + mean_red) * red * red) >> 8) + 4 * green * green + (((767 - mean_red) * blue * blue) >> 8)
+}
+
+/// Turns color (and every other SGR text modifier, along with hyperlinks) on or off for all output. While
+/// it's off, text is written without any escape codes, but takes up the same space as it would with them, so
+/// layouts don't change. It starts off if the NO_COLOR environment variable is set (and not empty), or the
+/// terminal is dumb, once the renderer is created (see `render::App::new`). Every window is redrawn on the
+/// next frame.
+/// # Example:
+/// ```standalone_crate
+/// use term_render::render::{self, App, Colorize, ColorType, Span, Window};
+/// render::set_color_enabled(false);
+/// let colored = "hi".colorize(ColorType::Rgb(200, 100, 50)).colorize(ColorType::Bold).with_link("https://example.com");
+/// let (text, size) = colored.get_text(&mut String::new());
+/// assert_eq!((text.as_str(), size), ("hi", 2));
+///
+/// let window = || {
+///     let mut window = Window::new((0, 0), 0, (12, 3));
+///     window.bordered();
+///     window.colorize(ColorType::Red);
+///     window.add_line(Span::from_tokens(vec![colored.clone(), "!".colorize(ColorType::OnBlue)]));
+///     window
+/// };
+/// let mut app = App::new_headless(12, 3);
+/// app.add_window(window(), String::from("window"), vec![]).unwrap();
+/// let snapshot = app.snapshot_styled();
+/// assert!(snapshot.iter().all(|line| !line.contains('\x1b')));
+/// assert_eq!(snapshot[1], "│hi!       │");
+///
+/// // turning it back on redraws everything in color, including the lines which were already drawn
+/// render::set_color_enabled(true);
+/// let mut fresh = App::new_headless(12, 3);
+/// fresh.add_window(window(), String::from("window"), vec![]).unwrap();
+/// assert!(app.snapshot_styled()[1].contains("\x1b[0;1;38;2;200;100;50mhi"));
+/// assert_eq!(app.snapshot_styled(), fresh.snapshot_styled());
+/// ```
+pub fn set_color_enabled (enabled: bool) {
+    if COLOR_ENABLED.swap(enabled, std::sync::atomic::Ordering::Relaxed) != enabled {
+        crate::render::ColorMode::bump_generation();
+    }
+}
+
+/// Checks if color is enabled (see `set_color_enabled`).
+pub fn is_color_enabled () -> bool {
+    COLOR_ENABLED.load(std::sync::atomic::Ordering::Relaxed)
+}
+
+/// Turns reduced motion on or off, for users who find movement distracting. While it's on, the blink
+/// modifier (and blinking cursors) are written as steady, and animations (see
+/// `SizeAndPosition::animate_to`) jump straight to their end. Anything else which animates over time
+/// should check `is_reduced_motion` and show its final (or a still) state instead.
+/// # Example:
+/// ```standalone_crate
+/// use term_render::render::{self, Colorize, ColorType};
+/// render::set_reduced_motion(true);
+/// assert_eq!("hi".colorize(ColorType::Blink).colorize(ColorType::Red).get_text(&mut String::new()).0, "\x1b[0;31mhi");
+/// render::set_reduced_motion(false);
+/// assert_eq!("hi".colorize(ColorType::Blink).colorize(ColorType::Red).get_text(&mut String::new()).0, "\x1b[0;31;5mhi");
+/// ```
+pub fn set_reduced_motion (reduced: bool) {
+    if REDUCED_MOTION.swap(reduced, std::sync::atomic::Ordering::Relaxed) != reduced {
+        crate::render::ColorMode::bump_generation();
+    }
+}
+
+/// Checks if reduced motion is on (see `set_reduced_motion`).
+pub fn is_reduced_motion () -> bool {
+    REDUCED_MOTION.load(std::sync::atomic::Ordering::Relaxed)
+}
+
+/// Checks if the terminal is dumb (`TERM=dumb`, or TERM missing on unix, where there's no terminfo entry
+/// to go by), meaning it can't handle escape codes at all.
+pub fn is_dumb_terminal () -> bool {
+    match std::env::var_os("TERM") {
+        Some(term) => term.is_empty() || term == "dumb",
+        None => cfg!(unix),
+    }
+}
+
+/// Turns color off if the environment asks for it (NO_COLOR) or the terminal is dumb, and detects the color
+/// depth (see `ColorDepth::detect`), returning whether the terminal is dumb.
+pub(crate) fn detect_capabilities () -> bool {
+    set_color_depth(ColorDepth::detect());
+    let dumb = is_dumb_terminal();
+    if dumb || std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+        set_color_enabled(false);
+    } dumb
+}
+
+static COLOR_ENABLED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(true);
+static REDUCED_MOTION: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+static COLOR_DEPTH: std::sync::atomic::AtomicU8 = std::sync::atomic::AtomicU8::new(ColorDepth::TrueColor as u8);
+
+// the values of each channel in the 6x6x6 color cube
+const CUBE_STEPS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+// built the first time they're needed: the nearest cube level for each channel value, and the nearest
+// base color for each of the 256 palette colors
+static CUBE_LEVELS: std::sync::OnceLock <[u8; 256]> = std::sync::OnceLock::new();
+static BASE_COLORS: std::sync::OnceLock <[u8; 256]> = std::sync::OnceLock::new();
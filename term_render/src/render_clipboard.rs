@@ -0,0 +1,61 @@
+// Clipboard access through OSC 52, which works over ssh (the terminal owns the clipboard, not the host).
+// Copying writes `ESC ] 52 ; c ; <base64> BEL`, and pasting writes the same code with `?` in place
+// of the data, which the terminal may answer with the clipboard's contents (see `KeyParser::clipboard_content`).
+
+/// The largest text (in bytes) which can be copied. Some terminals cap OSC 52 codes at around 100kB
+/// of base64 (xterm's default, for one), which is a little under 75kB before encoding.
+pub const MAX_CLIPBOARD_BYTES: usize = 74_994;
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes bytes as (padded) standard base64.
+pub(crate) fn base64_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = (chunk[0] as u32) << 16 |
+            (chunk.get(1).copied().unwrap_or(0) as u32) << 8 |
+            chunk.get(2).copied().unwrap_or(0) as u32;
+        for index in 0..4 {
+            match index <= chunk.len() {
+                true => encoded.push(BASE64_ALPHABET[(group >> (18 - index * 6)) as usize & 0x3F] as char),
+                false => encoded.push('='),
+            }
+        }
+    } encoded
+}
+
+/// Decodes standard base64, with or without padding (whitespace is skipped).
+/// Returns None if the text contains anything else.
+pub(crate) fn base64_decode(text: &[u8]) -> Option<Vec<u8>> {
+    let mut decoded = Vec::with_capacity(text.len() / 4 * 3);
+    let mut group = 0u32;
+    let mut bits = 0;
+    for byte in text {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => break,
+            byte if byte.is_ascii_whitespace() => continue,
+            _ => return None,
+        };
+        group = group << 6 | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            decoded.push((group >> bits) as u8);
+        }
+    }
+    Some(decoded)
+}
+
+/// Builds the code copying text to the clipboard, or None if the text is over `MAX_CLIPBOARD_BYTES`.
+pub(crate) fn copy_escape_code(text: &str) -> Option<String> {
+    if text.len() > MAX_CLIPBOARD_BYTES {  return None;  }
+    Some(format!("\x1b]52;c;{}\x07", base64_encode(text.as_bytes())))
+}
+
+/// The code asking the terminal for the clipboard's contents.
+pub(crate) static PASTE_REQUEST_CODE: &str = "\x1b]52;c;?\x07";
@@ -0,0 +1,53 @@
+// The errors raised by the renderer. Each kind of failure is its own variant, so callers can match on
+// them rather than comparing messages.
+
+/// An error from the renderer (see `render::App`).
+/// # Example
+/// ```
+/// use term_render::render::{App, RenderError};
+/// let mut renderer = App::new_headless(10, 3);
+/// match renderer.remove_window(String::from("missing")) {
+///     Err(RenderError::WindowNotFound(name)) => assert_eq!(name, "missing"),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive (Debug)]
+pub enum RenderError {
+    /// The terminal's size couldn't be read.
+    TerminalSize (std::io::Error),
+    /// The channel syncing frames between the app's loop and the rendering task closed unexpectedly.
+    ChannelClosed,
+    /// There's no window with the given name.
+    WindowNotFound (String),
+    /// A window with the given name already exists.
+    DuplicateWindow (String),
+    /// Writing to the terminal (or setting it up) failed.
+    Io (std::io::Error),
+}
+
+impl std::fmt::Display for RenderError {
+    fn fmt (&self, f: &mut std::fmt::Formatter <'_>) -> std::fmt::Result {
+        match self {
+            RenderError::TerminalSize(error) => write!(f, "failed to get the terminal's size: {}", error),
+            RenderError::ChannelClosed => write!(f, "the render sync channel closed unexpectedly"),
+            RenderError::WindowNotFound(name) => write!(f, "no window named '{}' exists", name),
+            RenderError::DuplicateWindow(name) => write!(f, "a window named '{}' already exists", name),
+            RenderError::Io(error) => write!(f, "failed to write to the terminal: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for RenderError {
+    fn source (&self) -> Option <&(dyn std::error::Error + 'static)> {
+        match self {
+            RenderError::TerminalSize(error) | RenderError::Io(error) => Some(error),
+            RenderError::ChannelClosed | RenderError::WindowNotFound(_) | RenderError::DuplicateWindow(_) => None,
+        }
+    }
+}
+
+impl From <std::io::Error> for RenderError {
+    fn from (error: std::io::Error) -> Self {
+        RenderError::Io(error)
+    }
+}
@@ -0,0 +1,688 @@
+// A cell based representation of a composed frame.
+// Windows render themselves as cursor-addressed strings; a Frame resolves those strings
+// (escape codes and all) into a grid of styled cells. The renderer composites every window
+// into a frame and diffs it against the last one, and frames can also be exported.
+
+use unicode_width::UnicodeWidthChar;
+
+/// The canonical rgb values for the 16 base ansi colors (the xterm defaults).
+/// The first 8 are the normal colors, and the last 8 are the bright variants.
+pub static ANSI_RGB_TABLE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// The default foreground and background used when a cell doesn't specify a color.
+pub static DEFAULT_FG_RGB: (u8, u8, u8) = (229, 229, 229);
+pub static DEFAULT_BG_RGB: (u8, u8, u8) = (0, 0, 0);
+
+/// Converts an index in the 256 color palette into its rgb value.
+/// 0-15 are the base colors, 16-231 are the 6x6x6 color cube, and 232-255 are the grayscale ramp.
+pub fn ansi_256_to_rgb (index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI_RGB_TABLE[index as usize],
+        16..=231 => {
+            let levels = [0u8, 95, 135, 175, 215, 255];
+            let cube = index - 16;
+            (levels[(cube / 36) as usize], levels[((cube / 6) % 6) as usize], levels[(cube % 6) as usize])
+        },
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        },
+    }
+}
+
+/// Gets the number of terminal columns plain (unescaped) text takes up.
+/// Wide characters (i.e. CJK) take two columns, and zero width characters take none.
+pub fn get_text_width (text: &str) -> usize {
+    text.chars().map(|chr| chr.width().unwrap_or(0)).sum()
+}
+
+/// Reads the rest of an escape sequence whose `\x1b` was just read, returning the whole sequence
+/// (including the `\x1b`). CSI sequences (`\x1b[`) end at their final letter (or `~`), OSC sequences
+/// (`\x1b]`, i.e. hyperlinks) end at a BEL or ST (`\x1b\\`), and anything else is a single character.
+/// # Example:
+/// ```
+/// use term_render::render::read_escape_sequence;
+/// let mut chars = "[0;31mred".chars();
+/// assert_eq!(read_escape_sequence(&mut chars), "\x1b[0;31m");
+/// assert_eq!(chars.as_str(), "red");
+/// // the url of a hyperlink can contain letters, so it only ends at the terminator
+/// let mut chars = "]8;;https://example.com\x1b\\link".chars();
+/// assert_eq!(read_escape_sequence(&mut chars), "\x1b]8;;https://example.com\x1b\\");
+/// assert_eq!(chars.as_str(), "link");
+/// ```
+pub fn read_escape_sequence (chars: &mut impl Iterator <Item = char>) -> String {
+    let mut sequence = String::new();
+    read_escape_sequence_into(chars, &mut sequence);
+    sequence
+}
+
+// reads an escape sequence (see `read_escape_sequence`) into the given string, replacing its contents
+// (so a single buffer can be reused for every sequence of a row)
+pub(crate) fn read_escape_sequence_into (chars: &mut impl Iterator <Item = char>, sequence: &mut String) {
+    sequence.clear();
+    sequence.push('\x1b');
+    let Some(kind) = chars.next() else {  return;  };
+    sequence.push(kind);
+    match kind {
+        '[' => {
+            for chr in chars.by_ref() {
+                sequence.push(chr);
+                if chr.is_ascii_alphabetic() || chr == '~' {  break;  }
+            }
+        },
+        ']' => {
+            while let Some(chr) = chars.next() {
+                sequence.push(chr);
+                if chr == '\x07' {  break;  }
+                if chr == '\x1b' {
+                    // the string terminator is `\x1b\\`
+                    if let Some(next) = chars.next() {  sequence.push(next);  }
+                    break;
+                }
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Gets the url a hyperlink escape sequence (OSC 8) opens, `Some(None)` if it closes the
+/// current hyperlink, or None if the sequence isn't a hyperlink.
+pub(crate) fn parse_hyperlink (sequence: &str) -> Option <Option <String>> {
+    let body = sequence.strip_prefix("\x1b]8;")?;
+    let body = body.strip_suffix('\x07').or_else(|| body.strip_suffix("\x1b\\")).unwrap_or(body);
+    // the parameters (i.e. `id=...`) come before the url
+    let (_params, url) = body.split_once(';')?;
+    match url.is_empty() {
+        true => Some(None),
+        false => Some(Some(url.to_owned())),
+    }
+}
+
+/// Pushes the escape sequence (OSC 8) opening a hyperlink to the url, or closing the current one with None.
+/// Terminals without hyperlink support ignore it, leaving the plain text.
+/// # Example:
+/// ```
+/// use term_render::render::{push_hyperlink, Frame};
+/// let mut text = String::new();
+/// push_hyperlink(&mut text, Some("https://example.com"));
+/// text.push_str("hi");
+/// push_hyperlink(&mut text, None);
+/// let mut frame = Frame::new(5, 1);
+/// frame.write_str(0, 0, &text);
+/// assert_eq!(frame.get(1, 0).unwrap().style.link.as_deref(), Some("https://example.com"));
+/// assert_eq!(frame.get(2, 0).unwrap().style.link, None);
+/// assert_eq!(frame.to_lines(), vec!["hi"]);
+/// ```
+pub fn push_hyperlink (output: &mut String, url: Option <&str>) {
+    output.push_str("\x1b]8;;");
+    if let Some(url) = url {  output.push_str(url);  }
+    output.push_str("\x1b\\");
+}
+
+/// A resolved color for a single cell.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Copy)]
+pub enum CellColor {
+    /// One of the 256 palette colors (0-15 being the base ansi colors).
+    Indexed (u8),
+    /// A 24-bit color.
+    Rgb (u8, u8, u8),
+}
+
+impl CellColor {
+    /// Gets the rgb value for the color.
+    pub fn to_rgb (&self) -> (u8, u8, u8) {
+        match self {
+            CellColor::Indexed(index) => ansi_256_to_rgb(*index),
+            CellColor::Rgb(r, g, b) => (*r, *g, *b),
+        }
+    }
+}
+
+/// The style of a single cell, resolved from the SGR escape codes that preceded it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct CellStyle {
+    pub fg: Option <CellColor>,
+    pub bg: Option <CellColor>,
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub blink: bool,
+    pub reverse: bool,
+    pub hidden: bool,
+    /// The url of the hyperlink (OSC 8) the cell belongs to, if any.
+    pub link: Option <std::sync::Arc <str>>,
+}
+
+impl CellStyle {
+    /// Applies the parameters of a single SGR (`\x1b[...m`) escape code to the style.
+    /// Hyperlinks aren't part of the SGR state, so a reset keeps the link.
+    pub fn apply_sgr (&mut self, params: &str) {
+        // (read as they're applied, so no buffer of codes needs allocating)
+        let mut codes = params.split(';').map(|code| code.parse::<u16>().unwrap_or(0)).peekable();
+        while let Some(code) = codes.next() {
+            match code {
+                0 => *self = CellStyle {  link: self.link.take(),  ..CellStyle::default()  },
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                5 => self.blink = true,
+                7 => self.reverse = true,
+                8 => self.hidden = true,
+                code @ 30..=37 => self.fg = Some(CellColor::Indexed((code - 30) as u8)),
+                code @ 90..=97 => self.fg = Some(CellColor::Indexed((code - 90 + 8) as u8)),
+                code @ 40..=47 => self.bg = Some(CellColor::Indexed((code - 40) as u8)),
+                code @ 100..=107 => self.bg = Some(CellColor::Indexed((code - 100 + 8) as u8)),
+                39 | 99 => self.fg = None,
+                49 | 109 => self.bg = None,
+                code @ (38 | 48) => {
+                    let color = match codes.peek() {
+                        Some(5) => {
+                            codes.next();
+                            codes.next().map(|value| CellColor::Indexed(value as u8))
+                        },
+                        Some(2) => {
+                            codes.next();
+                            let mut channel = || codes.next().unwrap_or(0) as u8;
+                            Some(CellColor::Rgb(channel(), channel(), channel()))
+                        },
+                        _ => None,
+                    };
+                    if code == 38 {  self.fg = color;  }
+                    else {  self.bg = color;  }
+                },
+                _ => {},
+            }
+        }
+    }
+
+    /// Gets the SGR escape code which switches the terminal to this style (starting from a reset,
+    /// so nothing from the previous style carries over). The hyperlink isn't included (see `push_hyperlink`).
+    pub fn to_sgr (&self) -> String {
+        let mut sgr = String::new();
+        self.push_sgr(&mut sgr);
+        sgr
+    }
+
+    /// Appends the SGR escape code for this style (see `to_sgr`) to the output, without allocating.
+    pub fn push_sgr (&self, sgr: &mut String) {
+        use std::fmt::Write;
+        sgr.push_str("\x1b[0");
+        let flags = [
+            (self.bold, ";1"), (self.dim, ";2"), (self.italic, ";3"), (self.underline, ";4"),
+            (self.blink && !crate::render::is_reduced_motion(), ";5"), (self.reverse, ";7"), (self.hidden, ";8"),
+        ];
+        for (enabled, code) in flags {
+            if enabled {  sgr.push_str(code);  }
+        }
+        // (writing to a string can't fail)
+        for (color, base, bright, extended) in [(self.fg, 30, 90, 38), (self.bg, 40, 100, 48)] {
+            let _ = match color {
+                None => Ok(()),
+                Some(CellColor::Indexed(index @ 0..=7)) => write!(sgr, ";{}", base + index as u16),
+                Some(CellColor::Indexed(index @ 8..=15)) => write!(sgr, ";{}", bright + index as u16 - 8),
+                Some(CellColor::Indexed(index)) => write!(sgr, ";{};5;{}", extended, index),
+                Some(CellColor::Rgb(r, g, b)) => write!(sgr, ";{};2;{};{};{}", extended, r, g, b),
+            };
+        }
+        sgr.push('m');
+    }
+
+    /// Returns the (foreground, background) rgb values after applying defaults and reverse video.
+    pub fn resolved_rgb (&self) -> ((u8, u8, u8), (u8, u8, u8)) {
+        let fg = self.fg.map(|color| color.to_rgb()).unwrap_or(DEFAULT_FG_RGB);
+        let bg = self.bg.map(|color| color.to_rgb()).unwrap_or(DEFAULT_BG_RGB);
+        if self.reverse {  (bg, fg)  }
+        else {  (fg, bg)  }
+    }
+}
+
+/// A single cell within a `Frame`.
+/// Double width characters occupy their own cell plus the following one, which is marked
+/// as a continuation (and holds no character of its own).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Cell {
+    pub chr: char,
+    pub style: CellStyle,
+    pub continuation: bool,
+}
+
+impl Default for Cell {
+    fn default () -> Self {
+        Cell { chr: ' ', style: CellStyle::default(), continuation: false }
+    }
+}
+
+/// The different formats a frame can be exported as.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Copy)]
+pub enum ExportFormat {
+    /// A `<pre>` block with inline-styled spans.
+    Html,
+    /// A standalone svg image with a rect per background cell and a text element per character.
+    Svg,
+    /// One line per row, styled with SGR escape codes (see `Frame::to_styled_lines`), which `cat`
+    /// shows as the terminal did.
+    Ansi,
+    /// One line per row of plain text (see `Frame::to_lines`).
+    Plain,
+}
+
+impl ExportFormat {
+    /// Picks the format for a file by its extension: `.html`/`.htm`, `.svg`, `.ans`/`.ansi`, and
+    /// plain text for anything else.
+    /// # Example:
+    /// ```
+    /// use term_render::render::ExportFormat;
+    /// assert_eq!(ExportFormat::from_path(std::path::Path::new("bug/frame.ans")), ExportFormat::Ansi);
+    /// assert_eq!(ExportFormat::from_path(std::path::Path::new("frame.txt")), ExportFormat::Plain);
+    /// ```
+    pub fn from_path (path: &std::path::Path) -> ExportFormat {
+        let extension = path.extension().and_then(|extension| extension.to_str()).unwrap_or("").to_ascii_lowercase();
+        match extension.as_str() {
+            "html" | "htm" => ExportFormat::Html,
+            "svg" => ExportFormat::Svg,
+            "ans" | "ansi" => ExportFormat::Ansi,
+            _ => ExportFormat::Plain,
+        }
+    }
+}
+
+/// A grid of styled cells representing a composed frame.
+/// Frames are built by writing the same cursor-addressed strings the renderer produces
+/// (see `render::App::screenshot`), so what's exported is what the terminal would show.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct Frame {
+    pub width: u16,
+    pub height: u16,
+    cells: Vec <Cell>,
+    // the key of the graphic covering each cell, or 0 (empty until something is reserved; see `reserve`)
+    reserved: Vec <u64>,
+}
+
+// the approximate size of a monospace cell when exporting to svg
+const SVG_CELL_WIDTH: usize = 9;
+const SVG_CELL_HEIGHT: usize = 18;
+const FONT_STACK: &str = "ui-monospace, 'SFMono-Regular', Menlo, Consolas, 'DejaVu Sans Mono', monospace";
+
+impl Frame {
+    /// Creates a blank frame of the given size.
+    pub fn new (width: u16, height: u16) -> Self {
+        Frame {
+            width,
+            height,
+            cells: vec![Cell::default(); width as usize * height as usize],
+            reserved: vec![],
+        }
+    }
+
+    /// Marks a region of cells (position, size) as covered by a graphic (such as an image) identified by
+    /// a nonzero key. When diffing, the cells are skipped if the previous frame had the same graphic
+    /// there, as the terminal is showing the graphic rather than the cells. Where the graphic changed
+    /// or went away, the cells are written again so nothing of the old graphic is left behind.
+    /// # Example:
+    /// ```
+    /// use term_render::render::Frame;
+    /// let mut previous = Frame::new(4, 1);
+    /// previous.reserve((0, 0), (2, 1), 7);
+    /// let mut frame = Frame::new(4, 1);
+    /// frame.write_str(0, 0, "abcd");
+    /// frame.reserve((0, 0), (2, 1), 7);
+    /// let mut output = String::new();
+    /// frame.diff(Some(&previous), &mut output);
+    /// assert_eq!(output, "\x1b[1;3H\x1b[0mcd\x1b[0m");
+    /// // once the graphic is gone, its cells are redrawn even though they didn't change
+    /// let mut output = String::new();
+    /// Frame::new(4, 1).diff(Some(&previous), &mut output);
+    /// assert_eq!(output, "\x1b[1;1H\x1b[0m  \x1b[0m");
+    /// ```
+    pub fn reserve (&mut self, position: (u16, u16), size: (u16, u16), key: u64) {
+        if self.reserved.is_empty() {  self.reserved = vec![0; self.cells.len()];  }
+        for y in position.1..position.1.saturating_add(size.1).min(self.height) {
+            for x in position.0..position.0.saturating_add(size.0).min(self.width) {
+                self.reserved[y as usize * self.width as usize + x as usize] = key;
+            }
+        }
+    }
+
+    /// Gets the key of the graphic covering the cell (see `reserve`), or 0 if there isn't one.
+    pub fn get_reserved (&self, x: u16, y: u16) -> u64 {
+        if x >= self.width || y >= self.height {  return 0;  }
+        self.reserved.get(y as usize * self.width as usize + x as usize).copied().unwrap_or(0)
+    }
+
+    /// Gets the cell at the given (0-based) column and row, if it's within the frame.
+    pub fn get (&self, x: u16, y: u16) -> Option <&Cell> {
+        if x >= self.width || y >= self.height {  return None;  }
+        self.cells.get(y as usize * self.width as usize + x as usize)
+    }
+
+    fn get_mut (&mut self, x: u16, y: u16) -> Option <&mut Cell> {
+        if x >= self.width || y >= self.height {  return None;  }
+        self.cells.get_mut(y as usize * self.width as usize + x as usize)
+    }
+
+    /// Writes a string containing SGR escape codes (and hyperlinks) starting at the given (0-based) column and row.
+    /// The style is reset at the start of the string, the same as how each draw call is emitted.
+    /// Text running off the right edge is clipped rather than wrapped.
+    pub fn write_str (&mut self, x: u16, y: u16, text: &str) {
+        let mut style = CellStyle::default();
+        let mut column = x as usize;
+        let mut chars = text.chars().peekable();
+        let mut sequence = String::with_capacity(32);
+        while let Some(chr) = chars.next() {
+            if chr == '\x1b' {
+                read_escape_sequence_into(&mut chars, &mut sequence);
+                if let Some(url) = parse_hyperlink(&sequence) {
+                    style.link = url.map(std::sync::Arc::from);
+                    continue;
+                }
+                // gathering the parameters of the escape code
+                let Some(params) = sequence.strip_prefix("\x1b[") else {  continue;  };
+                let Some(last) = params.chars().next_back() else {  continue;  };
+                let params = &params[..params.len() - last.len_utf8()];
+                if last == 'm' {  style.apply_sgr(params);  }
+                // cursor forward (from transparent windows) skips over cells without painting them
+                if last == 'C' {  column += params.parse::<usize>().unwrap_or(1);  }
+                continue;
+            }
+            let width = chr.width().unwrap_or(0);
+            if width == 0 {  continue;  }  // control characters and combining marks
+            if column + width > self.width as usize {  break;  }
+            if let Some(cell) = self.get_mut(column as u16, y) {
+                *cell = Cell { chr, style: style.clone(), continuation: false };
+            }
+            if width == 2 && let Some(cell) = self.get_mut(column as u16 + 1, y) {
+                *cell = Cell { chr: ' ', style: style.clone(), continuation: true };
+            }
+            column += width;
+        }
+    }
+
+    /// Writes the escape codes needed to turn the previous frame (what the terminal currently shows)
+    /// into this one, returning the number of cursor moves. Only the cells which changed are written:
+    /// the cursor is only moved when a changed cell isn't directly after the last one written, and
+    /// the style is only switched when it differs from the last cell written.
+    /// If there's no previous frame (or its size is different), every cell is written.
+    /// # Example:
+    /// ```
+    /// use term_render::render::Frame;
+    /// let mut previous = Frame::new(10, 2);
+    /// previous.write_str(0, 0, "hello");
+    /// let mut frame = previous.clone();
+    /// frame.write_str(0, 1, "\x1b[31mhi");
+    /// let mut output = String::new();
+    /// assert_eq!(frame.diff(Some(&previous), &mut output), 1);
+    /// assert_eq!(output, "\x1b[2;1H\x1b[0;31mhi\x1b[0m");
+    /// ```
+    pub fn diff (&self, previous: Option <&Frame>, output: &mut String) -> usize {
+        self.diff_at(previous, output, 0)
+    }
+
+    /// The same as `diff`, but with the frame drawn starting at the given row of the terminal (rather than
+    /// the top), such as for rendering inline beneath the shell's prompt (see `App::new_inline`).
+    /// # Example:
+    /// ```
+    /// use term_render::render::Frame;
+    /// let mut frame = Frame::new(4, 2);
+    /// frame.write_str(0, 1, "hi");
+    /// let mut output = String::new();
+    /// frame.diff_at(Some(&Frame::new(4, 2)), &mut output, 20);
+    /// assert_eq!(output, "\x1b[22;1H\x1b[0mhi\x1b[0m");
+    /// ```
+    pub fn diff_at (&self, previous: Option <&Frame>, output: &mut String, row_offset: u16) -> usize {
+        use std::fmt::Write;
+        let previous = previous.filter(|previous| previous.width == self.width && previous.height == self.height);
+        let mut style: Option <&CellStyle> = None;
+        let mut link: Option <&str> = None;
+        let mut cursor: Option <(u16, u16)> = None;
+        let mut moves = 0;
+        // without color, only the text is written (see `set_color_enabled`)
+        let color_enabled = crate::render::is_color_enabled();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let Some(cell) = self.get(x, y) else {  continue;  };
+                if cell.continuation {  continue;  }
+                let width = cell.chr.width().unwrap_or(1).max(1) as u16;
+                let changed = match previous {
+                    None => true,
+                    // the terminal is showing the same graphic over the cell, rather than the cell itself
+                    Some(previous) if self.get_reserved(x, y) != 0 && previous.get_reserved(x, y) == self.get_reserved(x, y) => false,
+                    // a graphic which changed or went away has to be covered up
+                    Some(previous) if previous.get_reserved(x, y) != 0 => true,
+                    // a wide character also needs its continuation cell to match
+                    Some(previous) => (x..x.saturating_add(width)).any(|column| previous.get(column, y) != self.get(column, y)),
+                };
+                if !changed {  continue;  }
+
+                // terminal coordinates are 1-based
+                if cursor != Some((x, y)) {
+                    let _ = write!(output, "\x1b[{};{}H", y.saturating_add(row_offset) + 1, x + 1);
+                    moves += 1;
+                }
+                if color_enabled && style != Some(&cell.style) {
+                    if cell.style.link.as_deref() != link {
+                        link = cell.style.link.as_deref();
+                        push_hyperlink(output, link);
+                    }
+                    cell.style.push_sgr(output);
+                    style = Some(&cell.style);
+                }
+                output.push(cell.chr);
+                cursor = Some((x.saturating_add(width), y));
+            }
+        }
+        if link.is_some() {  push_hyperlink(output, None);  }
+        if style.is_some() {  output.push_str("\x1b[0m");  }
+        moves
+    }
+
+    /// Escapes the characters which have special meaning in html/xml.
+    fn escape_xml (chr: char, output: &mut String) {
+        match chr {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            _ => output.push(chr),
+        }
+    }
+
+    fn css_rgb (color: (u8, u8, u8)) -> String {
+        format!("#{:02x}{:02x}{:02x}", color.0, color.1, color.2)
+    }
+
+    /// Converts the frame into a `<pre>` block of inline styled spans.
+    /// Runs of cells sharing a style are merged into a single span.
+    /// # Example:
+    /// ```
+    /// use term_render::render::Frame;
+    /// let mut frame = Frame::new(4, 1);
+    /// frame.write_str(0, 0, "\x1b[0;1;31;44mab\x1b[0;42m日");
+    /// assert_eq!(frame.to_html(), concat!(
+    ///     "<pre style=\"font-family: ui-monospace, 'SFMono-Regular', Menlo, Consolas, 'DejaVu Sans Mono', monospace; ",
+    ///     "background: #000000; color: #e5e5e5; line-height: 1.2;\">",
+    ///     "<span style=\"color: #cd0000; background: #0000ee; font-weight: bold;\">ab</span>",
+    ///     "<span style=\"color: #e5e5e5; background: #00cd00;\">日</span>\n",
+    ///     "</pre>",
+    /// ));
+    /// ```
+    pub fn to_html (&self) -> String {
+        let mut html = format!(
+            "<pre style=\"font-family: {}; background: {}; color: {}; line-height: 1.2;\">",
+            FONT_STACK, Frame::css_rgb(DEFAULT_BG_RGB), Frame::css_rgb(DEFAULT_FG_RGB)
+        );
+        for y in 0..self.height {
+            let mut current: Option <&CellStyle> = None;
+            for x in 0..self.width {
+                let Some(cell) = self.get(x, y) else {  continue;  };
+                if cell.continuation {  continue;  }  // the wide character already spans this cell
+                if current != Some(&cell.style) {
+                    if current.is_some() {  html.push_str("</span>");  }
+                    html.push_str(&format!("<span style=\"{}\">", Frame::css_style(&cell.style)));
+                    current = Some(&cell.style);
+                }
+                if cell.style.hidden {  html.push(' ');  }
+                else {  Frame::escape_xml(cell.chr, &mut html);  }
+            }
+            if current.is_some() {  html.push_str("</span>");  }
+            html.push('\n');
+        }
+        html.push_str("</pre>");
+        html
+    }
+
+    fn css_style (style: &CellStyle) -> String {
+        let (fg, bg) = style.resolved_rgb();
+        let mut css = format!("color: {}; background: {};", Frame::css_rgb(fg), Frame::css_rgb(bg));
+        if style.bold {  css.push_str(" font-weight: bold;");  }
+        if style.dim {  css.push_str(" opacity: 0.6;");  }
+        if style.italic {  css.push_str(" font-style: italic;");  }
+        if style.underline {  css.push_str(" text-decoration: underline;");  }
+        css
+    }
+
+    /// Converts the frame into a standalone svg image. Each cell with a non-default background
+    /// gets a rect (double width characters span two cells), and each character gets a text element.
+    /// # Example:
+    /// ```
+    /// use term_render::render::Frame;
+    /// let mut frame = Frame::new(4, 1);
+    /// frame.write_str(0, 0, "\x1b[0;1;31;44mab\x1b[0;42m日");
+    /// assert_eq!(frame.to_svg(), concat!(
+    ///     "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"36\" height=\"18\" viewBox=\"0 0 36 18\">\n",
+    ///     "<rect width=\"100%\" height=\"100%\" fill=\"#000000\"/>\n",
+    ///     "<g font-family=\"ui-monospace, 'SFMono-Regular', Menlo, Consolas, 'DejaVu Sans Mono', monospace\" font-size=\"13\">\n",
+    ///     "<rect x=\"0\" y=\"0\" width=\"9\" height=\"18\" fill=\"#0000ee\"/>\n",
+    ///     "<text x=\"0\" y=\"13\" fill=\"#cd0000\" font-weight=\"bold\">a</text>\n",
+    ///     "<rect x=\"9\" y=\"0\" width=\"9\" height=\"18\" fill=\"#0000ee\"/>\n",
+    ///     "<text x=\"9\" y=\"13\" fill=\"#cd0000\" font-weight=\"bold\">b</text>\n",
+    ///     "<rect x=\"18\" y=\"0\" width=\"18\" height=\"18\" fill=\"#00cd00\"/>\n",
+    ///     "<text x=\"18\" y=\"13\" fill=\"#e5e5e5\">日</text>\n",
+    ///     "</g>\n</svg>\n",
+    /// ));
+    /// ```
+    pub fn to_svg (&self) -> String {
+        let (pixel_width, pixel_height) = (self.width as usize * SVG_CELL_WIDTH, self.height as usize * SVG_CELL_HEIGHT);
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\">\n",
+            pixel_width, pixel_height
+        );
+        svg.push_str(&format!(
+            "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n<g font-family=\"{}\" font-size=\"{}\">\n",
+            Frame::css_rgb(DEFAULT_BG_RGB), FONT_STACK.replace('"', "'"), SVG_CELL_HEIGHT * 3 / 4
+        ));
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let Some(cell) = self.get(x, y) else {  continue;  };
+                if cell.continuation {  continue;  }
+                let span = if cell.chr.width().unwrap_or(1) == 2 {  2  } else {  1  };
+                let (fg, bg) = cell.style.resolved_rgb();
+                let (px, py) = (x as usize * SVG_CELL_WIDTH, y as usize * SVG_CELL_HEIGHT);
+                if bg != DEFAULT_BG_RGB {
+                    svg.push_str(&format!(
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\n",
+                        px, py, SVG_CELL_WIDTH * span, SVG_CELL_HEIGHT, Frame::css_rgb(bg)
+                    ));
+                }
+                if cell.chr == ' ' || cell.style.hidden {  continue;  }
+                let mut attributes = format!("fill=\"{}\"", Frame::css_rgb(fg));
+                if cell.style.bold {  attributes.push_str(" font-weight=\"bold\"");  }
+                if cell.style.italic {  attributes.push_str(" font-style=\"italic\"");  }
+                if cell.style.underline {  attributes.push_str(" text-decoration=\"underline\"");  }
+                if cell.style.dim {  attributes.push_str(" opacity=\"0.6\"");  }
+                let mut chr = String::new();
+                Frame::escape_xml(cell.chr, &mut chr);
+                svg.push_str(&format!(
+                    "<text x=\"{}\" y=\"{}\" {}>{}</text>\n",
+                    px, py + SVG_CELL_HEIGHT * 3 / 4, attributes, chr
+                ));
+            }
+        }
+        svg.push_str("</g>\n</svg>\n");
+        svg
+    }
+
+    /// Exports the frame in the given format. The line based formats end every row (including the
+    /// last) with a newline.
+    pub fn export (&self, format: ExportFormat) -> String {
+        let join = |lines: Vec <String>| lines.into_iter().fold(String::new(), |mut text, line| {
+            text.push_str(&line);
+            text.push('\n');
+            text
+        });
+        match format {
+            ExportFormat::Html => self.to_html(),
+            ExportFormat::Svg => self.to_svg(),
+            ExportFormat::Ansi => join(self.to_styled_lines()),
+            ExportFormat::Plain => join(self.to_lines()),
+        }
+    }
+
+    /// Gets the text of each row (without any styling), with trailing spaces trimmed.
+    /// # Example:
+    /// ```
+    /// use term_render::render::Frame;
+    /// let mut frame = Frame::new(10, 2);
+    /// frame.write_str(2, 1, "\x1b[31mhi");
+    /// assert_eq!(frame.to_lines(), vec!["", "  hi"]);
+    /// ```
+    pub fn to_lines (&self) -> Vec <String> {
+        (0..self.height).map(|y| {
+            let line: String = (0..self.width)
+                .filter_map(|x| self.get(x, y))
+                .filter(|cell| !cell.continuation)
+                .map(|cell| cell.chr)
+                .collect();
+            line.trim_end_matches(' ').to_owned()
+        }).collect()
+    }
+
+    /// Gets each row with its styling as SGR escape codes, switching styles only where they change
+    /// (each row starts unstyled and ends with a reset). Trailing unstyled spaces are trimmed.
+    /// # Example:
+    /// ```
+    /// use term_render::render::Frame;
+    /// let mut frame = Frame::new(10, 1);
+    /// frame.write_str(2, 0, "\x1b[31mhi");
+    /// assert_eq!(frame.to_styled_lines(), vec!["  \x1b[0;31mhi\x1b[0m"]);
+    /// ```
+    pub fn to_styled_lines (&self) -> Vec <String> {
+        let blank = Cell::default();
+        (0..self.height).map(|y| {
+            let cells: Vec <&Cell> = (0..self.width).filter_map(|x| self.get(x, y)).collect();
+            let length = cells.iter().rposition(|cell| **cell != blank).map_or(0, |index| index + 1);
+            let mut line = String::new();
+            let mut style = &blank.style;
+            for cell in &cells[..length] {
+                if cell.continuation {  continue;  }
+                if &cell.style != style {
+                    if cell.style.link != style.link {  push_hyperlink(&mut line, cell.style.link.as_deref());  }
+                    line.push_str(&cell.style.to_sgr());
+                    style = &cell.style;
+                }
+                line.push(cell.chr);
+            }
+            if style.link.is_some() {  push_hyperlink(&mut line, None);  }
+            if style != &blank.style {  line.push_str("\x1b[0m");  }
+            line
+        }).collect()
+    }
+}
@@ -0,0 +1,482 @@
+// Raster images shown through the terminal's graphics protocols (the `images` feature).
+// A window can carry an image (see `Window::set_image`), which the renderer places over the window's
+// content area after the frame's cells are written. The cells under a placed image are reserved in the
+// frame (see `Frame::reserve`), so they aren't redrawn over the image until it moves or goes away.
+// Windows always render the image as half blocks too, which is what's shown when there's no protocol.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+// the next id given to an image (ids are never reused, so they can stand in for comparing the pixels)
+static NEXT_IMAGE_ID: AtomicU32 = AtomicU32::new(1);
+
+// the foreground and background colors of a half block cell
+type CellColors = (Option <(u8, u8, u8)>, Option <(u8, u8, u8)>);
+
+/// The size (in pixels) assumed for a cell when the terminal doesn't report it.
+pub static DEFAULT_CELL_PIXELS: (u16, u16) = (10, 20);
+
+/// The protocol used to show images in the terminal.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ImageProtocol {
+    /// The kitty graphics protocol (kitty, ghostty, and others).
+    Kitty,
+    /// iTerm2's inline images (iTerm2, WezTerm, mintty, and others).
+    Iterm2,
+    /// Sixel graphics (foot, mlterm, and xterm when built with it).
+    Sixel,
+    /// No protocol; the image is drawn with half block characters (two pixels per cell).
+    Blocks,
+}
+
+impl ImageProtocol {
+    /// Guesses the protocol the terminal supports from the environment variables it sets,
+    /// falling back to `Blocks` if it isn't recognized (see `detect_from`).
+    pub fn detect () -> ImageProtocol {
+        ImageProtocol::detect_from(|name| std::env::var(name).ok())
+    }
+
+    /// Guesses the protocol the terminal supports, given a lookup for environment variables.
+    /// Inside tmux, `Blocks` is always used, as tmux doesn't pass the protocols through by default.
+    /// # Example:
+    /// ```
+    /// use term_render::render::ImageProtocol;
+    /// let environment = |variables: &'static [(&'static str, &'static str)]| move |name: &str| {
+    ///     variables.iter().find(|(key, _value)| *key == name).map(|(_key, value)| value.to_string())
+    /// };
+    /// assert_eq!(ImageProtocol::detect_from(environment(&[("TERM", "xterm-kitty")])), ImageProtocol::Kitty);
+    /// assert_eq!(ImageProtocol::detect_from(environment(&[("TERM_PROGRAM", "WezTerm")])), ImageProtocol::Iterm2);
+    /// assert_eq!(ImageProtocol::detect_from(environment(&[("TERM", "foot")])), ImageProtocol::Sixel);
+    /// assert_eq!(ImageProtocol::detect_from(environment(&[("TERM", "xterm-256color")])), ImageProtocol::Blocks);
+    /// assert_eq!(ImageProtocol::detect_from(environment(&[("TERM", "xterm-kitty"), ("TMUX", "1")])), ImageProtocol::Blocks);
+    /// ```
+    pub fn detect_from (lookup: impl Fn (&str) -> Option <String>) -> ImageProtocol {
+        if lookup("TMUX").is_some() {  return ImageProtocol::Blocks;  }
+        let term = lookup("TERM").unwrap_or_default();
+        let program = lookup("TERM_PROGRAM").unwrap_or_default();
+        if lookup("KITTY_WINDOW_ID").is_some() || term.contains("kitty") || term.contains("ghostty") || program == "ghostty" {
+            return ImageProtocol::Kitty;
+        }
+        if matches!(program.as_str(), "iTerm.app" | "WezTerm" | "mintty") || lookup("LC_TERMINAL").as_deref() == Some("iTerm2") {
+            return ImageProtocol::Iterm2;
+        }
+        if term.starts_with("foot") || term.contains("mlterm") {
+            return ImageProtocol::Sixel;
+        }
+        ImageProtocol::Blocks
+    }
+}
+
+/// An RGBA image (8 bits per channel, row by row). Images are cheap to clone, as the pixels are shared.
+/// Each image gets a unique id when it's created, which is what images are compared and hashed by
+/// (the pixels can't be changed afterward, so two images with the same id always match).
+#[derive(Clone, Debug)]
+pub struct Image {
+    id: u32,
+    width: u32,
+    height: u32,
+    pixels: std::sync::Arc <[u8]>,
+}
+
+impl PartialEq for Image {
+    fn eq (&self, other: &Image) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Image {}
+
+impl std::hash::Hash for Image {
+    fn hash <H: std::hash::Hasher> (&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Image {
+    /// Creates an image from RGBA pixels (four bytes per pixel, row by row).
+    /// Returns an error if the image is empty or the number of bytes doesn't match the size.
+    /// # Example:
+    /// ```
+    /// use term_render::render::Image;
+    /// let image = Image::new(2, 1, vec![255, 0, 0, 255, 0, 0, 255, 255]).unwrap();
+    /// assert_eq!(image.get_pixel(1, 0), Some((0, 0, 255, 255)));
+    /// assert!(Image::new(2, 2, vec![0; 4]).is_err());
+    /// ```
+    pub fn new (width: u32, height: u32, pixels: Vec <u8>) -> Result <Image, String> {
+        if width == 0 || height == 0 {
+            return Err(String::from("An image can't have a width or height of zero."));
+        }
+        if pixels.len() as u64 != width as u64 * height as u64 * 4 {
+            return Err(format!("Expected {} bytes of RGBA pixels for a {}x{} image, but got {}.",
+                width as u64 * height as u64 * 4, width, height, pixels.len()));
+        }
+        Ok(Image {
+            id: NEXT_IMAGE_ID.fetch_add(1, Ordering::Relaxed),
+            width,
+            height,
+            pixels: pixels.into(),
+        })
+    }
+
+    /// Gets the image's unique id.
+    pub fn get_id (&self) -> u32 {
+        self.id
+    }
+
+    /// Gets the size of the image in pixels (width, height).
+    pub fn get_size (&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Gets the RGBA pixels, row by row.
+    pub fn get_pixels (&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Gets the (red, green, blue, alpha) value of a pixel, if it's within the image.
+    pub fn get_pixel (&self, x: u32, y: u32) -> Option <(u8, u8, u8, u8)> {
+        if x >= self.width || y >= self.height {  return None;  }
+        let index = (y as usize * self.width as usize + x as usize) * 4;
+        Some((self.pixels[index], self.pixels[index + 1], self.pixels[index + 2], self.pixels[index + 3]))
+    }
+
+    /// Gets the number of cells (columns, rows) the image covers when scaled to fit within the given
+    /// number of cells, keeping its aspect ratio. Cells are assumed to be twice as tall as they are wide.
+    /// # Example:
+    /// ```
+    /// use term_render::render::Image;
+    /// let image = Image::new(40, 20, vec![0; 40 * 20 * 4]).unwrap();
+    /// assert_eq!(image.fit(20, 20), (20, 5));
+    /// assert_eq!(image.fit(80, 5), (20, 5));
+    /// ```
+    pub fn fit (&self, columns: u16, rows: u16) -> (u16, u16) {
+        if columns == 0 || rows == 0 {  return (0, 0);  }
+        // each cell is two square pixels tall
+        let (width, height) = (self.width as f64, self.height as f64);
+        let fitted_rows = (columns as f64 * height / width / 2.0).round().max(1.0);
+        if fitted_rows <= rows as f64 {
+            return (columns, fitted_rows as u16);
+        }
+        let fitted_columns = (rows as f64 * 2.0 * width / height).round().clamp(1.0, columns as f64);
+        (fitted_columns as u16, rows)
+    }
+
+    /// Scales the image to the given size (in pixels), returning the RGBA pixels. Each pixel takes the
+    /// nearest one from the image.
+    pub fn resample (&self, width: u32, height: u32) -> Vec <u8> {
+        let mut pixels = Vec::with_capacity(width as usize * height as usize * 4);
+        for y in 0..height {
+            let source_y = (y as u64 * self.height as u64 / height.max(1) as u64) as u32;
+            for x in 0..width {
+                let source_x = (x as u64 * self.width as u64 / width.max(1) as u64) as u32;
+                let (r, g, b, a) = self.get_pixel(source_x, source_y).unwrap_or_default();
+                pixels.extend_from_slice(&[r, g, b, a]);
+            }
+        }
+        pixels
+    }
+
+    /// Draws the image with half block characters, scaled to the given number of cells (see `fit`).
+    /// Each cell shows two pixels: the upper one as the foreground of a `▀` and the lower one as its
+    /// background. Mostly transparent pixels are left blank.
+    /// # Example:
+    /// ```
+    /// use term_render::render::Image;
+    /// // a red pixel above a blue one
+    /// let image = Image::new(1, 2, vec![255, 0, 0, 255, 0, 0, 255, 255]).unwrap();
+    /// let rows = image.to_half_blocks(1, 1);
+    /// assert_eq!(rows[0].join().0, "\x1b[0;38;2;255;0;0;48;2;0;0;255m▀");
+    /// ```
+    pub fn to_half_blocks (&self, columns: u16, rows: u16) -> Vec <crate::render::Span> {
+        let pixels = self.resample(columns as u32, rows as u32 * 2);
+        let pixel = |x: usize, y: usize| {
+            let index = (y * columns as usize + x) * 4;
+            // (mostly) transparent pixels are left blank
+            if pixels[index + 3] < 128 {  return None;  }
+            Some((pixels[index], pixels[index + 1], pixels[index + 2]))
+        };
+        (0..rows as usize).map(|row| {
+            let mut span = crate::render::Span::default();
+            let mut text = String::new();
+            let mut style = None;
+            for column in 0..columns as usize {
+                let (chr, cell_style) = match (pixel(column, row * 2), pixel(column, row * 2 + 1)) {
+                    (None, None) => (' ', (None, None)),
+                    (Some(top), None) => ('▀', (Some(top), None)),
+                    (None, Some(bottom)) => ('▄', (Some(bottom), None)),
+                    (Some(top), Some(bottom)) => ('▀', (Some(top), Some(bottom))),
+                };
+                if style != Some(cell_style) && !text.is_empty() {
+                    span.push(Image::get_colored(std::mem::take(&mut text), style.unwrap_or_default()));
+                }
+                style = Some(cell_style);
+                text.push(chr);
+            }
+            if !text.is_empty() {  span.push(Image::get_colored(text, style.unwrap_or_default()));  }
+            span
+        }).collect()
+    }
+
+    fn get_colored (text: String, (foreground, background): CellColors) -> crate::render::Colored {
+        let mut colored = crate::render::Colored::new(text);
+        if let Some((r, g, b)) = foreground {  colored.add_color(crate::render::ColorType::Rgb(r, g, b));  }
+        if let Some((r, g, b)) = background {  colored.add_color(crate::render::ColorType::OnRGB(r, g, b));  }
+        colored
+    }
+
+    /// Gets the escape sequence showing the image through the protocol, scaled to cover the given number
+    /// of cells from the cursor's position (the cursor is left where it was). `cell_pixels` is the size of
+    /// a cell in pixels, which sixel images are scaled by (the other protocols scale the image themselves).
+    /// `id` is the kitty image id, which the image can later be removed with (see `kitty_delete`).
+    /// `Blocks` has no escape sequence, so an empty string is returned for it.
+    /// # Example:
+    /// ```
+    /// use term_render::render::{Image, ImageProtocol};
+    /// let image = Image::new(1, 1, vec![255, 255, 255, 255]).unwrap();
+    /// assert_eq!(image.encode(ImageProtocol::Kitty, 2, 1, (10, 20), 7), "\x1b_Ga=T,f=32,s=1,v=1,c=2,r=1,i=7,q=2,C=1,m=0;/////w==\x1b\\");
+    /// assert!(image.encode(ImageProtocol::Iterm2, 2, 1, (10, 20), 7).starts_with("\x1b]1337;File=inline=1;"));
+    /// assert!(image.encode(ImageProtocol::Sixel, 2, 1, (10, 20), 7).starts_with("\x1bP0;1;0q\"1;1;20;20#"));
+    /// assert_eq!(image.encode(ImageProtocol::Blocks, 2, 1, (10, 20), 7), "");
+    /// ```
+    pub fn encode (&self, protocol: ImageProtocol, columns: u16, rows: u16, cell_pixels: (u16, u16), id: u32) -> String {
+        match protocol {
+            ImageProtocol::Kitty => self.encode_kitty(columns, rows, id),
+            ImageProtocol::Iterm2 => self.encode_iterm2(columns, rows),
+            ImageProtocol::Sixel => {
+                let width = columns as u32 * cell_pixels.0.max(1) as u32;
+                let height = rows as u32 * cell_pixels.1.max(1) as u32;
+                encode_sixel(&self.resample(width, height), width, height)
+            },
+            ImageProtocol::Blocks => String::new(),
+        }
+    }
+
+    // the raw pixels, sent in chunks (kitty limits each escape sequence to 4096 bytes of data)
+    fn encode_kitty (&self, columns: u16, rows: u16, id: u32) -> String {
+        let data = encode_base64(&self.pixels);
+        let chunks: Vec <&[u8]> = data.as_bytes().chunks(4096).collect();
+        let mut output = String::with_capacity(data.len() + chunks.len() * 16 + 64);
+        for (index, chunk) in chunks.iter().enumerate() {
+            let more = (index + 1 < chunks.len()) as u8;
+            output.push_str("\x1b_G");
+            if index == 0 {
+                output.push_str(&format!("a=T,f=32,s={},v={},c={},r={},i={},q=2,C=1,", self.width, self.height, columns, rows, id));
+            }
+            output.push_str(&format!("m={};", more));
+            // (base64 is always ascii)
+            output.push_str(std::str::from_utf8(chunk).unwrap_or_default());
+            output.push_str("\x1b\\");
+        }
+        output
+    }
+
+    // the image as a png file
+    fn encode_iterm2 (&self, columns: u16, rows: u16) -> String {
+        let png = encode_png(&self.pixels, self.width, self.height);
+        format!("\x1b]1337;File=inline=1;size={};width={};height={};preserveAspectRatio=0:{}\x07",
+            png.len(), columns, rows, encode_base64(&png))
+    }
+}
+
+/// Gets the escape sequence removing a kitty image (and freeing its data) by its id.
+pub fn kitty_delete (id: u32) -> String {
+    format!("\x1b_Ga=d,d=I,i={},q=2\x1b\\", id)
+}
+
+/// Gets the size of a cell in pixels, as reported by the terminal (or `DEFAULT_CELL_PIXELS` if it isn't).
+pub fn get_cell_pixels () -> (u16, u16) {
+    let Ok(size) = crossterm::terminal::window_size() else {  return DEFAULT_CELL_PIXELS;  };
+    if size.width == 0 || size.height == 0 || size.columns == 0 || size.rows == 0 {  return DEFAULT_CELL_PIXELS;  }
+    (size.width / size.columns, size.height / size.rows)
+}
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+// standard base64 with padding
+fn encode_base64 (bytes: &[u8]) -> String {
+    let mut output = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let value = (chunk[0] as u32) << 16 | (*chunk.get(1).unwrap_or(&0) as u32) << 8 | *chunk.get(2).unwrap_or(&0) as u32;
+        for index in 0..4 {
+            if index <= chunk.len() {
+                output.push(BASE64_ALPHABET[(value >> (18 - index * 6)) as usize & 63] as char);
+            } else {  output.push('=');  }
+        }
+    }
+    output
+}
+
+// a png with the pixels stored uncompressed (terminals decode it once, so the size barely matters)
+fn encode_png (pixels: &[u8], width: u32, height: u32) -> Vec <u8> {
+    // each row starts with its filter type (0 being none)
+    let row_bytes = width as usize * 4;
+    let mut raw = Vec::with_capacity((row_bytes + 1) * height as usize);
+    for row in pixels.chunks(row_bytes) {
+        raw.push(0);
+        raw.extend_from_slice(row);
+    }
+    // a zlib stream of stored (uncompressed) deflate blocks
+    let mut zlib = vec![0x78, 0x01];
+    let blocks: Vec <&[u8]> = raw.chunks(65535).collect();
+    for (index, block) in blocks.iter().enumerate() {
+        zlib.push((index + 1 == blocks.len()) as u8);
+        zlib.extend_from_slice(&(block.len() as u16).to_le_bytes());
+        zlib.extend_from_slice(&(!(block.len() as u16)).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    zlib.extend_from_slice(&adler32(&raw).to_be_bytes());
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    header.extend_from_slice(&[8, 6, 0, 0, 0]);  // 8 bits per channel, RGBA, no interlacing
+
+    let mut png = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+    for (kind, data) in [(b"IHDR", &header[..]), (b"IDAT", &zlib[..]), (b"IEND", &[][..])] {
+        png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        let start = png.len();
+        png.extend_from_slice(kind);
+        png.extend_from_slice(data);
+        let crc = crc32(&png[start..]);
+        png.extend_from_slice(&crc.to_be_bytes());
+    }
+    png
+}
+
+fn adler32 (bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in bytes {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    b << 16 | a
+}
+
+fn crc32 (bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {  0xedb88320 ^ (crc >> 1)  } else {  crc >> 1  };
+        }
+    }
+    !crc
+}
+
+// the 6x6x6 color cube a sixel pixel's color is rounded to (or None if it's mostly transparent)
+fn sixel_color (pixel: &[u8]) -> Option <usize> {
+    if pixel[3] < 128 {  return None;  }
+    let level = |channel: u8| (channel as usize * 5 + 127) / 255;
+    Some(level(pixel[0]) * 36 + level(pixel[1]) * 6 + level(pixel[2]))
+}
+
+// sixel graphics, with the colors rounded to a 6x6x6 cube (transparent pixels are left unpainted)
+fn encode_sixel (pixels: &[u8], width: u32, height: u32) -> String {
+    use std::fmt::Write;
+    let (width, height) = (width as usize, height as usize);
+    let colors: Vec <Option <usize>> = pixels.chunks(4).map(sixel_color).collect();
+    let mut used = [false; 216];
+    for color in colors.iter().flatten() {  used[*color] = true;  }
+
+    // (writing to a string can't fail)
+    let mut output = String::new();
+    let _ = write!(output, "\x1bP0;1;0q\"1;1;{};{}", width, height);
+    for (color, _used) in used.iter().enumerate().filter(|(_color, used)| **used) {
+        let percent = |level: usize| level * 100 / 5;
+        let _ = write!(output, "#{};2;{};{};{}", color, percent(color / 36), percent(color / 6 % 6), percent(color % 6));
+    }
+    // each band covers six rows of pixels, painted one color at a time
+    for band in (0..height).step_by(6) {
+        let mut band_colors = [false; 216];
+        for y in band..(band + 6).min(height) {
+            for color in colors[y * width..(y + 1) * width].iter().flatten() {  band_colors[*color] = true;  }
+        }
+        let mut first = true;
+        for (color, _used) in band_colors.iter().enumerate().filter(|(_color, used)| **used) {
+            if !first {  output.push('$');  }  // back to the start of the band
+            first = false;
+            let _ = write!(output, "#{}", color);
+            let mut run: Option <(char, usize)> = None;
+            for x in 0..width {
+                let mut bits = 0;
+                for row in 0..6 {
+                    let y = band + row;
+                    if y < height && colors[y * width + x] == Some(color) {  bits |= 1 << row;  }
+                }
+                let chr = (63 + bits) as u8 as char;
+                match &mut run {
+                    Some((run_chr, count)) if *run_chr == chr => *count += 1,
+                    _ => {
+                        if let Some(run) = run {  push_sixel_run(&mut output, run);  }
+                        run = Some((chr, 1));
+                    },
+                }
+            }
+            if let Some(run) = run {  push_sixel_run(&mut output, run);  }
+        }
+        output.push('-');
+    }
+    output.push_str("\x1b\\");
+    output
+}
+
+// repeated sixels are run length encoded (`!count` followed by the sixel)
+fn push_sixel_run (output: &mut String, (chr, count): (char, usize)) {
+    if count > 3 {
+        output.push('!');
+        output.push_str(&count.to_string());
+        output.push(chr);
+    } else {
+        for _ in 0..count {  output.push(chr);  }
+    }
+}
+
+/// An image placed on the screen by the renderer (see `Window::set_image`).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub(crate) struct ImagePlacement {
+    // identifies the image along with where it's placed (nonzero; used to reserve the frame's cells)
+    pub(crate) key: u64,
+    pub(crate) image: Image,
+    pub(crate) protocol: ImageProtocol,
+    pub(crate) position: (u16, u16),
+    pub(crate) size: (u16, u16),
+}
+
+impl ImagePlacement {
+    pub(crate) fn new (image: Image, protocol: ImageProtocol, position: (u16, u16), size: (u16, u16)) -> Self {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        (image.id, protocol, position, size).hash(&mut hasher);
+        ImagePlacement {  key: hasher.finish() | 1, image, protocol, position, size  }
+    }
+
+    // the kitty id of the placement (nonzero, and unique to where the image is)
+    fn get_kitty_id (&self) -> u32 {
+        (self.key as u32) | 1
+    }
+
+    /// Writes the escape codes updating the images on the screen from the previous placements to the
+    /// current ones, after the frame's cells were written. Kitty images live above the cells, so the ones
+    /// which moved or went away are deleted, while the others are overwritten by the cells themselves.
+    /// Images which are already on the screen (in the same place) aren't sent again.
+    pub(crate) fn push_updates (previous: &[ImagePlacement], current: &[ImagePlacement], output: &mut String) {
+        for placement in previous {
+            if placement.protocol != ImageProtocol::Kitty || current.iter().any(|other| other.key == placement.key) {  continue;  }
+            output.push_str(&kitty_delete(placement.get_kitty_id()));
+        }
+        let mut cell_pixels = None;
+        for placement in current {
+            if previous.iter().any(|other| other.key == placement.key) {  continue;  }
+            // the terminal is only asked for its cell size when a sixel is actually drawn
+            let pixels = match placement.protocol {
+                ImageProtocol::Sixel => *cell_pixels.get_or_insert_with(get_cell_pixels),
+                _ => DEFAULT_CELL_PIXELS,
+            };
+            output.push_str("\x1b[");
+            crate::render::App::push_u16(output, placement.position.1.saturating_add(1));
+            output.push(';');
+            crate::render::App::push_u16(output, placement.position.0.saturating_add(1));
+            output.push('H');
+            output.push_str(&placement.image.encode(placement.protocol, placement.size.0, placement.size.1, pixels, placement.get_kitty_id()));
+        }
+    }
+}
@@ -0,0 +1,456 @@
+// A lightweight inline markup for authoring styled text without chaining `color!` calls.
+// "Normal [red]warning[/red] and [bold blue]link[/] here"
+// Tags are whitespace separated lists of style names which get pushed onto a stack,
+// closers pop the stack, and the text between is emitted as Colored tokens.
+
+use crate::render::{Colored, ColorType, Span};
+
+/// An error produced by the strict markup parsers (`Span::from_markup`, `styled_text_strict`, and `styled_lines_strict`).
+/// The position is the byte offset into the original string where the offending tag starts,
+/// which should make it easy to find typos while developing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkupError {
+    pub position: usize,
+    pub details: String,
+}
+
+impl std::fmt::Display for MarkupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MarkupError at byte {}: {}", self.position, self.details)
+    }
+}
+
+impl MarkupError {
+    fn new (position: usize, details: String) -> Self {
+        MarkupError { position, details }
+    }
+}
+
+/// A single entry in the style stack; the raw tag text and its style names are kept so named closers
+/// (i.e. `[/red]` or `[/bold red]`) can find the matching opener.
+struct StyleFrame {
+    tag: String,
+    names: Vec <String>,
+    colors: Vec <ColorType>,
+}
+
+impl StyleFrame {
+    /// Whether a named closer matches this tag, either as a whole or by any one of its style names.
+    fn is_closed_by (&self, closing: &str) -> bool {
+        let normalize = |name: &str| name.to_ascii_lowercase().replace('_', "-");
+        let closing = normalize(closing);
+        normalize(&self.tag) == closing || self.names.iter().any(|name| normalize(name) == closing)
+    }
+}
+
+/// Parses a markup string into a single `Span`. Any newlines are kept as literal text;
+/// use `styled_lines` for multi-line content.
+/// Supported tags (multiple can be combined within one tag separated by spaces):
+/// * named colors: `black`, `red`, `green`, `yellow`, `blue`, `magenta`, `cyan`, `white`, `default`
+/// * bright variants: `bright-red`, `bright-blue`, ...
+/// * backgrounds: `on-red`, `on-bright-red`, `on-#003366`, `on-rgb(0,51,102)`, ...
+/// * modifiers: `bold`, `dim`, `italic`, `underline`, `blink`, `reverse`, `hide`
+/// * hex rgb: `#ff8800` or `#f80`
+/// * decimal rgb: `rgb(255,136,0)`, and 256-color palette indexes: `ansi(208)`
+///
+/// Underscores can be used in place of dashes (i.e. `on_blue` or `bright_red`).
+///
+/// `[/]` pops the most recent tag, `[/name]` pops back to the latest tag containing that style name,
+/// and `[[` is a literal `[`. Unknown or malformed tags are passed through as literal text, so user
+/// content never panics.
+/// # Example
+/// ```
+/// use term_render::render::{styled_text, Colored, ColorType};
+/// let token = |text: &str, colors: Vec<ColorType>| Colored::get_from_color_types_str(text, colors);
+///
+/// let span = styled_text("Normal [red]warning[/red] and [bold blue]link[/] here");
+/// assert_eq!(span.size(), 28);  // "Normal warning and link here"
+///
+/// // nested tags stack, and a named closer pops back to the tag with that style (closing any inside it)
+/// assert_eq!(styled_text("[bold blue]a [red]b[/blue] c").get_tokens(), &[
+///     token("a ", vec![ColorType::Bold, ColorType::Blue]),
+///     token("b", vec![ColorType::Bold, ColorType::Blue, ColorType::Red]),
+///     token(" c", vec![]),
+/// ]);
+///
+/// // mismatched closers, empty tags, and unknown tags are kept as text, and [[ is an escaped bracket
+/// assert_eq!(styled_text("[red]x[/blue][] [blurple]y [[z]").get_tokens(), &[
+///     token("x[/blue][] [blurple]y [z]", vec![ColorType::Red]),
+/// ]);
+/// ```
+pub fn styled_text (text: &str) -> Span {
+    // the lenient parser never produces an error
+    parse_markup(text, false).unwrap_or_default().into_iter().next().unwrap_or_default()
+}
+
+/// Parses a markup string into one `Span` per line. Styles which are still open at the end
+/// of a line carry over onto the following lines.
+/// # Example
+/// ```
+/// use term_render::render::{styled_lines, Colored, ColorType};
+/// let token = |text: &str, colors: Vec<ColorType>| Colored::get_from_color_types_str(text, colors);
+///
+/// let lines = styled_lines("[green]one\ntwo[/] three\n[[four]");
+/// assert_eq!(lines.len(), 3);
+/// assert_eq!(lines[0].get_tokens(), &[token("one", vec![ColorType::Green])]);
+/// assert_eq!(lines[1].get_tokens(), &[token("two", vec![ColorType::Green]), token(" three", vec![])]);
+/// assert_eq!(lines[2].get_tokens(), &[token("[four]", vec![])]);
+/// ```
+pub fn styled_lines (text: &str) -> Vec <Span> {
+    parse_markup(text, true).unwrap_or_default()
+}
+
+/// The strict version of `styled_text`. Instead of passing unknown tags, empty tags, mismatched closers,
+/// or unclosed tags through as text, an error is returned with the byte position of the problem.
+/// # Example
+/// ```
+/// use term_render::render::styled_text_strict;
+/// assert!(styled_text_strict("[bold blue]a [[b][/blue]").is_ok());
+///
+/// let error = styled_text_strict("ok [red]x[/blue]").unwrap_err();
+/// assert_eq!((error.position, error.details.as_str()), (9, "Closing tag 'blue' doesn't match any open tag"));
+/// assert_eq!(styled_text_strict("a[]").unwrap_err().position, 1);
+/// assert_eq!(styled_text_strict("a [blurple]b").unwrap_err().details, "Unknown style 'blurple'");
+/// assert_eq!(styled_text_strict("a [red]b").unwrap_err().position, 2);  // never closed
+/// ```
+pub fn styled_text_strict (text: &str) -> Result <Span, MarkupError> {
+    parse_markup_strict(text, false).map(|lines| lines.into_iter().next().unwrap_or_default())
+}
+
+/// The strict version of `styled_lines`. See `styled_text_strict` for what is considered an error.
+/// # Example
+/// ```
+/// use term_render::render::styled_lines_strict;
+/// assert_eq!(styled_lines_strict("[red]one\ntwo[/]").unwrap().len(), 2);
+/// // the position counts bytes through the whole string, not just the line
+/// let error = styled_lines_strict("one\ntwo [/]").unwrap_err();
+/// assert_eq!((error.position, error.details.as_str()), (8, "Closing tag without an open tag"));
+/// ```
+pub fn styled_lines_strict (text: &str) -> Result <Vec <Span>, MarkupError> {
+    parse_markup_strict(text, true)
+}
+
+impl Span {
+    /// Parses a markup string (see `styled_text` for the supported tags) into a `Span`, returning an
+    /// error with the byte offset of the first unknown, empty, or mismatched tag. Unlike `styled_text_strict`,
+    /// tags which are still open at the end of the string are fine (their style simply runs to the end).
+    /// Adjacent text with the same style is merged into a single `Colored` token.
+    /// # Example
+    /// ```
+    /// use term_render::render::{Colored, ColorType, Span};
+    /// let token = |text: &str, colors: Vec<ColorType>| Colored::get_from_color_types_str(text, colors);
+    ///
+    /// // nested tags stack their styles, and [/] pops the most recent one
+    /// let span = Span::from_markup("[bold red]Error:[/] details [on_blue]in [yellow]here[/][/]").unwrap();
+    /// assert_eq!(span.get_tokens(), &[
+    ///     token("Error:", vec![ColorType::Bold, ColorType::Red]),
+    ///     token(" details ", vec![]),
+    ///     token("in ", vec![ColorType::OnBlue]),
+    ///     token("here", vec![ColorType::OnBlue, ColorType::Yellow]),
+    /// ]);
+    ///
+    /// // combined tags, rgb and palette colors, and escaped brackets
+    /// let span = Span::from_markup("[bold yellow on_black]a[/][rgb(255, 0, 0)]b[/][ansi(196)]c[/] [[d]").unwrap();
+    /// assert_eq!(span.get_tokens(), &[
+    ///     token("a", vec![ColorType::Bold, ColorType::Yellow, ColorType::OnBlack]),
+    ///     token("b", vec![ColorType::Rgb(255, 0, 0)]),
+    ///     token("c", vec![ColorType::Ansi(196)]),
+    ///     token(" [d]", vec![]),
+    /// ]);
+    /// assert_eq!(Span::from_markup("[on_rgb(0,51,102)]x").unwrap().get_tokens(), &[token("x", vec![ColorType::OnRGB(0, 51, 102)])]);
+    ///
+    /// // runs with the same style are merged, and tags left open at the end are fine
+    /// let span = Span::from_markup("[green]one[/][green] two[/][green][/] [bold]three").unwrap();
+    /// assert_eq!(span.get_tokens(), &[
+    ///     token("one two", vec![ColorType::Green]),
+    ///     token(" ", vec![]),
+    ///     token("three", vec![ColorType::Bold]),
+    /// ]);
+    ///
+    /// // errors point at the offending tag
+    /// let error = Span::from_markup("fine [blurple]not fine").unwrap_err();
+    /// assert_eq!((error.position, error.details.as_str()), (5, "Unknown style 'blurple'"));
+    /// assert_eq!(Span::from_markup("[rgb(256,0,0)]x").unwrap_err().details, "Unknown style 'rgb(256,0,0)'");
+    /// assert_eq!(Span::from_markup("[red]x[/blue]").unwrap_err().position, 6);
+    /// assert_eq!(Span::from_markup("x[/]").unwrap_err().position, 1);
+    /// assert_eq!(Span::from_markup("[red").unwrap_err().details, "Unterminated tag");
+    /// ```
+    pub fn from_markup (text: &str) -> Result <Span, MarkupError> {
+        let mut parser = MarkupParser::new(text, true, false);
+        parser.allow_unclosed = true;
+        parser.parse().map(|lines| lines.into_iter().next().unwrap_or_default())
+    }
+}
+
+fn parse_markup (text: &str, split_lines: bool) -> Result <Vec <Span>, MarkupError> {
+    MarkupParser::new(text, false, split_lines).parse()
+}
+
+fn parse_markup_strict (text: &str, split_lines: bool) -> Result <Vec <Span>, MarkupError> {
+    MarkupParser::new(text, true, split_lines).parse()
+}
+
+/// Splits a tag into its style names at whitespace, keeping the arguments of `rgb(...)`/`ansi(...)` together.
+fn split_style_names (tag: &str) -> Vec <String> {
+    let mut names = vec![];
+    let mut current = String::new();
+    let mut depth = 0usize;
+    for chr in tag.chars() {
+        match chr {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            chr if chr.is_whitespace() => {
+                if depth == 0 && !current.is_empty() {  names.push(std::mem::take(&mut current));  }
+                continue;  // spaces within the arguments are dropped
+            },
+            _ => {},
+        }
+        current.push(chr);
+    }
+    if !current.is_empty() {  names.push(current);  }
+    names
+}
+
+/// Converts a single style name into its `ColorType`, returning None for unknown names.
+fn parse_style_name (name: &str) -> Option <ColorType> {
+    let lowered = name.to_ascii_lowercase().replace('_', "-");
+    if let Some(background) = lowered.strip_prefix("on-") {
+        return match parse_style_name(background)? {
+            ColorType::Black => Some(ColorType::OnBlack),
+            ColorType::Red => Some(ColorType::OnRed),
+            ColorType::Green => Some(ColorType::OnGreen),
+            ColorType::Yellow => Some(ColorType::OnYellow),
+            ColorType::Blue => Some(ColorType::OnBlue),
+            ColorType::Magenta => Some(ColorType::OnMagenta),
+            ColorType::Cyan => Some(ColorType::OnCyan),
+            ColorType::White => Some(ColorType::OnWhite),
+            ColorType::Default => Some(ColorType::OnDefault),
+            ColorType::BrightBlack => Some(ColorType::OnBrightBlack),
+            ColorType::BrightRed => Some(ColorType::OnBrightRed),
+            ColorType::BrightGreen => Some(ColorType::OnBrightGreen),
+            ColorType::BrightYellow => Some(ColorType::OnBrightYellow),
+            ColorType::BrightBlue => Some(ColorType::OnBrightBlue),
+            ColorType::BrightMagenta => Some(ColorType::OnBrightMagenta),
+            ColorType::BrightCyan => Some(ColorType::OnBrightCyan),
+            ColorType::BrightWhite => Some(ColorType::OnBrightWhite),
+            ColorType::BrightDefault => Some(ColorType::OnBrightDefault),
+            ColorType::Rgb(r, g, b) => Some(ColorType::OnRGB(r, g, b)),
+            ColorType::Ansi(index) => Some(ColorType::OnANSI(index)),
+            _ => None,  // modifiers can't be backgrounds
+        };
+    }
+    if let Some(hex) = lowered.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+    if let Some(arguments) = lowered.strip_prefix("rgb(").and_then(|rest| rest.strip_suffix(')')) {
+        let channels: Vec <u8> = arguments.split(',').map(|channel| channel.trim().parse().ok()).collect::<Option <_>>()?;
+        let [r, g, b] = channels[..] else {  return None;  };
+        return Some(ColorType::Rgb(r, g, b));
+    }
+    if let Some(index) = lowered.strip_prefix("ansi(").and_then(|rest| rest.strip_suffix(')')) {
+        return index.trim().parse().ok().map(ColorType::Ansi);
+    }
+    match lowered.as_str() {
+        "black" => Some(ColorType::Black),
+        "red" => Some(ColorType::Red),
+        "green" => Some(ColorType::Green),
+        "yellow" => Some(ColorType::Yellow),
+        "blue" => Some(ColorType::Blue),
+        "magenta" => Some(ColorType::Magenta),
+        "cyan" => Some(ColorType::Cyan),
+        "white" => Some(ColorType::White),
+        "default" => Some(ColorType::Default),
+
+        "bright-black" => Some(ColorType::BrightBlack),
+        "bright-red" => Some(ColorType::BrightRed),
+        "bright-green" => Some(ColorType::BrightGreen),
+        "bright-yellow" => Some(ColorType::BrightYellow),
+        "bright-blue" => Some(ColorType::BrightBlue),
+        "bright-magenta" => Some(ColorType::BrightMagenta),
+        "bright-cyan" => Some(ColorType::BrightCyan),
+        "bright-white" => Some(ColorType::BrightWhite),
+        "bright-default" => Some(ColorType::BrightDefault),
+
+        "bold" => Some(ColorType::Bold),
+        "dim" => Some(ColorType::Dim),
+        "italic" => Some(ColorType::Italic),
+        "underline" => Some(ColorType::Underline),
+        "blink" => Some(ColorType::Blink),
+        "reverse" => Some(ColorType::Reverse),
+        "hide" => Some(ColorType::Hide),
+        _ => None,
+    }
+}
+
+/// Parses `rrggbb` or `rgb` hex digits into an rgb color.
+fn parse_hex (hex: &str) -> Option <ColorType> {
+    if !hex.chars().all(|chr| chr.is_ascii_hexdigit()) {  return None;  }
+    match hex.len() {
+        6 => Some(ColorType::Rgb(
+            u8::from_str_radix(&hex[0..2], 16).ok()?,
+            u8::from_str_radix(&hex[2..4], 16).ok()?,
+            u8::from_str_radix(&hex[4..6], 16).ok()?,
+        )),
+        3 => {
+            // #f80 is shorthand for #ff8800
+            let digit = |index: usize| u8::from_str_radix(&hex[index..index + 1], 16).map(|v| v * 17);
+            Some(ColorType::Rgb(digit(0).ok()?, digit(1).ok()?, digit(2).ok()?))
+        },
+        _ => None,
+    }
+}
+
+/// Handles the state of a single parse (the style stack, accumulated text, and finished lines).
+struct MarkupParser<'a> {
+    text: &'a str,
+    strict: bool,
+    split_lines: bool,
+    /// Whether tags left open at the end are accepted in strict mode (see `Span::from_markup`).
+    allow_unclosed: bool,
+    stack: Vec <StyleFrame>,
+    /// The byte position of each open tag (used for reporting unclosed tags).
+    open_positions: Vec <usize>,
+    current_text: String,
+    /// The runs of text on the current line along with their styles (merged into tokens once the line ends).
+    current_line: Vec <(String, Vec <ColorType>)>,
+    lines: Vec <Span>,
+}
+
+impl<'a> MarkupParser<'a> {
+    fn new (text: &'a str, strict: bool, split_lines: bool) -> Self {
+        MarkupParser {
+            text,
+            strict,
+            split_lines,
+            allow_unclosed: false,
+            stack: vec![],
+            open_positions: vec![],
+            current_text: String::new(),
+            current_line: vec![],
+            lines: vec![],
+        }
+    }
+
+    /// Pushes the accumulated text as a run using the current style stack, extending the previous run
+    /// if it has the same style.
+    fn flush_text (&mut self) {
+        if self.current_text.is_empty() {  return;  }
+        let colors: Vec <ColorType> = self.stack.iter().flat_map(|frame| frame.colors.iter().copied()).collect();
+        let text = std::mem::take(&mut self.current_text);
+        match self.current_line.last_mut() {
+            Some((last_text, last_colors)) if *last_colors == colors => last_text.push_str(&text),
+            _ => self.current_line.push((text, colors)),
+        }
+    }
+
+    fn flush_line (&mut self) {
+        self.flush_text();
+        let tokens = std::mem::take(&mut self.current_line).into_iter()
+            .map(|(text, colors)| Colored::get_from_color_types_str(&text, colors))
+            .collect();
+        self.lines.push(Span::from_tokens(tokens));
+    }
+
+    /// Either reports an error (strict mode) or keeps the raw tag as literal text.
+    fn reject_tag (&mut self, position: usize, raw: &str, details: String) -> Result <(), MarkupError> {
+        if self.strict {
+            return Err(MarkupError::new(position, details));
+        }
+        self.current_text.push_str(raw);
+        Ok(())
+    }
+
+    fn parse (mut self) -> Result <Vec <Span>, MarkupError> {
+        let text = self.text;
+        let mut index = 0;
+        while index < text.len() {
+            let rest = &text[index..];
+            if rest.starts_with("[[") {
+                self.current_text.push('[');
+                index += 2;
+                continue;
+            }
+            if rest.starts_with('[') {
+                let Some(end) = rest.find(']') else {
+                    // no closing bracket; the rest of the string is literal
+                    if self.strict {
+                        return Err(MarkupError::new(index, String::from("Unterminated tag")));
+                    }
+                    self.current_text.push('[');
+                    index += 1;
+                    continue;
+                };
+                let raw = &rest[..=end];
+                let inner = &rest[1..end];
+                if inner.contains('\n') || inner.contains('[') {
+                    // a stray bracket within normal text
+                    if self.strict {
+                        return Err(MarkupError::new(index, String::from("Unterminated tag")));
+                    }
+                    self.current_text.push('[');
+                    index += 1;
+                    continue;
+                }
+                self.handle_tag(index, raw, inner)?;
+                index += raw.len();
+                continue;
+            }
+
+            let chr = rest.chars().next().unwrap_or_default();
+            if chr == '\n' && self.split_lines {
+                self.flush_line();
+            } else {
+                self.current_text.push(chr);
+            }
+            index += chr.len_utf8();
+        }
+
+        if let Some(position) = self.open_positions.last() && self.strict && !self.allow_unclosed {
+            return Err(MarkupError::new(*position, format!("Unclosed tag '{}'", self.stack.last().map(|frame| frame.tag.as_str()).unwrap_or_default())));
+        }
+        self.flush_line();
+        Ok(self.lines)
+    }
+
+    fn handle_tag (&mut self, position: usize, raw: &str, inner: &str) -> Result <(), MarkupError> {
+        let tag = inner.trim();
+        if tag.is_empty() {
+            return self.reject_tag(position, raw, String::from("Empty tag"));
+        }
+
+        if let Some(closing) = tag.strip_prefix('/') {
+            let closing = closing.trim();
+            if closing.is_empty() {
+                // [/] pops whatever was opened last
+                if self.stack.is_empty() {
+                    return self.reject_tag(position, raw, String::from("Closing tag without an open tag"));
+                }
+                self.flush_text();
+                self.stack.pop();
+                self.open_positions.pop();
+                return Ok(());
+            }
+            // named closers pop back to the matching opener
+            let Some(frame_index) = self.stack.iter().rposition(|frame| frame.is_closed_by(closing)) else {
+                return self.reject_tag(position, raw, format!("Closing tag '{}' doesn't match any open tag", closing));
+            };
+            self.flush_text();
+            self.stack.truncate(frame_index);
+            self.open_positions.truncate(frame_index);
+            return Ok(());
+        }
+
+        let names = split_style_names(tag);
+        let mut colors = vec![];
+        for name in &names {
+            match parse_style_name(name) {
+                Some(color) => colors.push(color),
+                None => return self.reject_tag(position, raw, format!("Unknown style '{}'", name)),
+            }
+        }
+        self.flush_text();
+        self.stack.push(StyleFrame { tag: tag.to_string(), names, colors });
+        self.open_positions.push(position);
+        Ok(())
+    }
+}
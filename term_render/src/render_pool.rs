@@ -0,0 +1,124 @@
+// A small pool of worker threads owned by the renderer, living as long as it does (rather than spawning
+// a thread for every frame). Windows which changed are re-rendered on the workers in parallel, and each
+// finished frame is written to the terminal on one of them in the background (see `render::App::render`).
+
+type Job = Box <dyn FnOnce () + Send>;
+
+/// The most workers used by default. Rendering rows is quick, so more threads mostly add overhead.
+const MAX_DEFAULT_THREADS: usize = 4;
+
+pub(crate) struct RenderPool {
+    threads: usize,
+    sender: Option <crossbeam::channel::Sender <Job>>,
+    workers: Vec <std::thread::JoinHandle <()>>,
+}
+
+impl RenderPool {
+    /// Creates a pool with the given number of workers (at least one). The threads aren't spawned
+    /// until the first job is run, so renderers which never need them (i.e. headless ones) don't pay for them.
+    pub(crate) fn new (threads: usize) -> Self {
+        RenderPool {
+            threads: threads.max(1),
+            sender: None,
+            workers: vec![],
+        }
+    }
+
+    /// Gets the number of workers in the pool.
+    pub(crate) fn get_threads (&self) -> usize {
+        self.threads
+    }
+
+    /// Changes the number of workers, joining the current ones (new ones are spawned when next needed).
+    pub(crate) fn set_threads (&mut self, threads: usize) {
+        if threads.max(1) == self.threads {  return;  }
+        self.shutdown();
+        self.threads = threads.max(1);
+    }
+
+    // spawns the workers if they aren't running yet, returning the queue they take jobs from
+    fn get_sender (&mut self) -> &crossbeam::channel::Sender <Job> {
+        self.sender.get_or_insert_with(|| {
+            let (sender, receiver) = crossbeam::channel::unbounded::<Job>();
+            for _ in 0..self.threads {
+                let receiver = receiver.clone();
+                self.workers.push(std::thread::spawn(move || {
+                    // the loop ends once the pool drops its sender
+                    for job in receiver {
+                        // a panicking job shouldn't take the worker down with it
+                        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(job));
+                    }
+                }));
+            }
+            sender
+        })
+    }
+
+    /// Runs the job on one of the workers, returning a receiver which is sent to (or disconnected,
+    /// if the job panicked) once it finishes.
+    pub(crate) fn execute (&mut self, job: impl FnOnce () + Send + 'static) -> crossbeam::channel::Receiver <()> {
+        let (done_sender, done_receiver) = crossbeam::channel::bounded(1);
+        let _ = self.get_sender().send(Box::new(move || {
+            job();
+            let _ = done_sender.send(());
+        }));
+        done_receiver
+    }
+
+    /// Applies the function to every item across the workers, returning the results in the same order
+    /// as the items. With a single worker (or item), everything runs on the calling thread instead.
+    /// Panics if the function panicked on a worker.
+    pub(crate) fn map <T: Send + 'static, R: Send + 'static> (&mut self, items: Vec <T>, function: fn (T) -> R) -> Vec <R> {
+        if self.threads == 1 || items.len() < 2 {
+            return items.into_iter().map(function).collect();
+        }
+        let count = items.len();
+        let (result_sender, result_receiver) = crossbeam::channel::bounded(count);
+        let sender = self.get_sender();
+        for (index, item) in items.into_iter().enumerate() {
+            let result_sender = result_sender.clone();
+            let _ = sender.send(Box::new(move || {
+                let _ = result_sender.send((index, function(item)));
+            }));
+        }
+        drop(result_sender);
+
+        let mut results: Vec <Option <R>> = (0..count).map(|_| None).collect();
+        for _ in 0..count {
+            let (index, result) = result_receiver.recv().expect("A render worker panicked.");
+            results[index] = Some(result);
+        }
+        results.into_iter().flatten().collect()
+    }
+
+    // stops the workers, waiting for any queued jobs (such as writing the last frame) to finish
+    fn shutdown (&mut self) {
+        self.sender = None;  // the workers stop once the queue is empty and disconnected
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl Default for RenderPool {
+    fn default () -> Self {
+        let threads = std::thread::available_parallelism().map_or(1, |threads| threads.get());
+        RenderPool::new(threads.min(MAX_DEFAULT_THREADS))
+    }
+}
+
+impl std::fmt::Debug for RenderPool {
+    fn fmt (&self, f: &mut std::fmt::Formatter <'_>) -> std::fmt::Result {
+        f.debug_struct("RenderPool")
+            .field("threads", &self.threads)
+            .field("running", &self.sender.is_some())
+            .finish()
+    }
+}
+
+/// Joins the workers, so the process exits cleanly once the renderer is dropped.
+impl Drop for RenderPool {
+    fn drop (&mut self) {
+        self.shutdown();
+    }
+}
@@ -0,0 +1,120 @@
+// Debouncing the terminal's size. Dragging a terminal's corner resizes it many times a second, and every new
+// size redraws the whole screen, so a new size is only used once it's been stable for a moment (or jumped by a
+// lot at once, like maximizing). On unix, the size is only read again once a SIGWINCH says it changed, rather
+// than every frame (see `render::App::poll_terminal_size`).
+
+/// How long a new terminal size has to stay the same before it's used, unless another interval is set
+/// (see `render::App::set_resize_debounce`). Headless renderers don't debounce by default.
+pub const DEFAULT_RESIZE_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// Resizes changing either dimension by at least this many cells are used right away.
+pub const LARGE_RESIZE: u16 = 20;
+
+pub(crate) struct ResizeDebouncer {
+    /// How long a new size has to be stable before it's used.
+    debounce: std::time::Duration,
+    /// The size in use.
+    applied: Option <(u16, u16)>,
+    /// The size last read, and since when it's been that size.
+    observed: Option <((u16, u16), std::time::Instant)>,
+    /// Set by SIGWINCH whenever the size needs reading again.
+    changed: std::sync::Arc <std::sync::atomic::AtomicBool>,
+    /// The registered SIGWINCH handler, if any (without one, the size is read every time).
+    #[cfg(unix)]
+    signal: Option <signal_hook::SigId>,
+    /// The number of times the size was read.
+    queries: usize,
+}
+
+impl ResizeDebouncer {
+    pub(crate) fn new (debounce: std::time::Duration) -> Self {
+        ResizeDebouncer {
+            debounce,
+            applied: None,
+            observed: None,
+            changed: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(true)),
+            #[cfg(unix)]
+            signal: None,
+            queries: 0,
+        }
+    }
+
+    /// Only reads the size again after a SIGWINCH (falling back to reading it every time if the
+    /// handler can't be registered). This does nothing outside of unix.
+    pub(crate) fn watch_signal (&mut self) {
+        #[cfg(unix)]
+        if self.signal.is_none() {
+            self.signal = signal_hook::flag::register(signal_hook::consts::SIGWINCH, self.changed.clone()).ok();
+        }
+    }
+
+    // whether the size could have changed since it was last read
+    fn is_stale (&self) -> bool {
+        #[cfg(unix)]
+        let watched = self.signal.is_some();
+        #[cfg(not(unix))]
+        let watched = false;
+        !watched || self.changed.swap(false, std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Gets the size to render at, reading it with `query` if it could have changed. A new size is used once
+    /// it's stayed the same for the debounce interval, or right away if it's a large change.
+    pub(crate) fn poll (&mut self, query: impl FnOnce () -> std::io::Result <(u16, u16)>) -> std::io::Result <(u16, u16)> {
+        let (observed, since) = match self.observed {
+            Some(observed) if !self.is_stale() => observed,
+            previous => {
+                let size = query()?;
+                self.queries += 1;
+                let observed = match previous {
+                    Some((previous, since)) if previous == size => (previous, since),
+                    _ => (size, std::time::Instant::now()),
+                };
+                self.observed = Some(observed);
+                observed
+            },
+        };
+        let applied = *self.applied.get_or_insert(observed);
+        let large = applied.0.abs_diff(observed.0) >= LARGE_RESIZE || applied.1.abs_diff(observed.1) >= LARGE_RESIZE;
+        if large || since.elapsed() >= self.debounce {
+            self.applied = Some(observed);
+        }
+        Ok(self.applied.unwrap_or(observed))
+    }
+
+    pub(crate) fn get_debounce (&self) -> std::time::Duration {
+        self.debounce
+    }
+
+    pub(crate) fn set_debounce (&mut self, debounce: std::time::Duration) {
+        self.debounce = debounce;
+    }
+
+    pub(crate) fn get_queries (&self) -> usize {
+        self.queries
+    }
+}
+
+impl Default for ResizeDebouncer {
+    fn default () -> Self {
+        ResizeDebouncer::new(DEFAULT_RESIZE_DEBOUNCE)
+    }
+}
+
+impl std::fmt::Debug for ResizeDebouncer {
+    fn fmt (&self, f: &mut std::fmt::Formatter <'_>) -> std::fmt::Result {
+        f.debug_struct("ResizeDebouncer")
+            .field("debounce", &self.debounce)
+            .field("applied", &self.applied)
+            .field("queries", &self.queries)
+            .finish()
+    }
+}
+
+impl Drop for ResizeDebouncer {
+    fn drop (&mut self) {
+        #[cfg(unix)]
+        if let Some(signal) = self.signal.take() {
+            signal_hook::low_level::unregister(signal);
+        }
+    }
+}
@@ -0,0 +1,100 @@
+// Named color palettes, so restyling an app doesn't mean touching every render closure.
+// A theme maps semantic slots ("border", "accent", "error"...) to the colors they're drawn with;
+// windows can follow a slot for their border and title, and render closures can colorize text by slot.
+
+use crate::render::{Colored, ColorType, Colorize};
+
+/// A named palette mapping semantic slots (such as `"border"`, `"accent"`, or `"error"`) to colors.
+/// The theme is stored on `render::App` (see `App::set_theme`); windows with a theme slot (set through a
+/// builder's `with_theme_slot`) have their border and title drawn with that slot's colors, and render
+/// closures can use `colorize` to style text by slot rather than hardcoding colors.
+/// The built-in themes (`dark` and `light`) provide every slot in `Theme::SLOTS`.
+/// # Example:
+/// ```
+/// use term_render::render::{ColorType, Theme};
+/// let theme = Theme::dark().with_slot("accent", vec![ColorType::Magenta, ColorType::Bold]);
+/// assert_eq!(theme.get_slot("accent"), Some(&[ColorType::Magenta, ColorType::Bold][..]));
+/// assert!(theme.colorize("Hello", "accent").contains(&ColorType::Magenta));
+/// // missing slots leave the text uncolored
+/// assert!(theme.colorize("Hello", "missing").is_uncolored());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Theme {
+    name: String,
+    slots: std::collections::HashMap <String, Vec <ColorType>>,
+}
+
+impl Default for Theme {
+    fn default () -> Self {
+        Theme::dark()
+    }
+}
+
+impl Theme {
+    /// The slots provided by the built-in themes.
+    pub const SLOTS: [&'static str; 9] = [
+        "border", "title", "text", "accent", "selection", "error", "warning", "success", "muted",
+    ];
+
+    /// Creates an empty theme with the given name.
+    pub fn new (name: &str) -> Self {
+        Theme {
+            name: name.to_owned(),
+            slots: std::collections::HashMap::new(),
+        }
+    }
+
+    /// The default theme, meant for terminals with a dark background.
+    pub fn dark () -> Self {
+        Theme::new("dark")
+            .with_slot("border", vec![ColorType::BrightBlack])
+            .with_slot("title", vec![ColorType::BrightWhite, ColorType::Bold])
+            .with_slot("text", vec![ColorType::White])
+            .with_slot("accent", vec![ColorType::BrightCyan])
+            .with_slot("selection", vec![ColorType::Black, ColorType::OnCyan])
+            .with_slot("error", vec![ColorType::BrightRed, ColorType::Bold])
+            .with_slot("warning", vec![ColorType::BrightYellow])
+            .with_slot("success", vec![ColorType::BrightGreen])
+            .with_slot("muted", vec![ColorType::BrightBlack])
+    }
+
+    /// A theme meant for terminals with a light background.
+    pub fn light () -> Self {
+        Theme::new("light")
+            .with_slot("border", vec![ColorType::Black])
+            .with_slot("title", vec![ColorType::Black, ColorType::Bold])
+            .with_slot("text", vec![ColorType::Black])
+            .with_slot("accent", vec![ColorType::Blue])
+            .with_slot("selection", vec![ColorType::BrightWhite, ColorType::OnBlue])
+            .with_slot("error", vec![ColorType::Red, ColorType::Bold])
+            .with_slot("warning", vec![ColorType::Magenta])
+            .with_slot("success", vec![ColorType::Green])
+            .with_slot("muted", vec![ColorType::BrightBlack])
+    }
+
+    /// Sets the colors of a slot, returning the theme (for chaining).
+    pub fn with_slot (mut self, slot: &str, colors: Vec <ColorType>) -> Self {
+        self.set_slot(slot, colors);
+        self
+    }
+
+    /// Sets the colors of a slot, replacing any existing ones.
+    pub fn set_slot (&mut self, slot: &str, colors: Vec <ColorType>) {
+        self.slots.insert(slot.to_owned(), colors);
+    }
+
+    /// Gets the colors of a slot, if the theme has it.
+    pub fn get_slot (&self, slot: &str) -> Option <&[ColorType]> {
+        self.slots.get(slot).map(|colors| colors.as_slice())
+    }
+
+    /// Gets the name of the theme.
+    pub fn get_name (&self) -> &str {
+        &self.name
+    }
+
+    /// Colors the text with the given slot's colors (or leaves it uncolored if the theme doesn't have the slot).
+    pub fn colorize (&self, text: &str, slot: &str) -> Colored {
+        text.colorizes(self.get_slot(slot).map(|colors| colors.to_vec()).unwrap_or_default())
+    }
+}
@@ -0,0 +1,173 @@
+// Layout-only persistence for scenes. A widget's content comes from closures (which can't be saved),
+// but its geometry, depth, border, title, parent, and visibility can, so an app can restore the
+// user's arrangement of panes across sessions. With the `serde` feature enabled, `SceneLayout` can
+// be written in any format serde supports (i.e. JSON or TOML).
+
+use crate::render as term_render;
+use crate::widget::{Scene, WidgetError};
+use crate::widget_impls::{Anchor, SizeAndPosition};
+
+/// The saved layout of a single widget (see `SceneLayout`).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WidgetLayout {
+    /// Fixed size adjustment (width, height) in characters (see `SizeAndPosition::size_offset`).
+    pub size_offset: (i16, i16),
+    /// Fixed position adjustment (x, y) in characters (see `SizeAndPosition::position_offset`).
+    pub position_offset: (i16, i16),
+    /// Size as a percentage of the terminal area (see `SizeAndPosition::size_area_percent`).
+    pub size_area_percent: (f32, f32),
+    /// Position as a percentage of the terminal area (see `SizeAndPosition::position_area_percent`).
+    pub position_area_percent: (f32, f32),
+    /// The point of the widget placed at its position.
+    pub anchor: Anchor,
+    /// The smallest size (width, height) the widget can have.
+    pub min_size: Option<(u16, u16)>,
+    /// The largest size (width, height) the widget can have.
+    pub max_size: Option<(u16, u16)>,
+    /// The depth of the widget's window.
+    pub depth: u16,
+    /// Whether the widget's window has a border.
+    pub bordered: bool,
+    /// The text of the window's title, if it has one.
+    pub title: Option<String>,
+    /// The name of the parent widget, or `None` for the root widget.
+    pub parent: Option<String>,
+    /// Whether the widget is visible (see `Scene::set_widget_visible`).
+    pub visible: bool,
+}
+
+impl WidgetLayout {
+    /// Copies the geometry into a widget's size and position configuration.
+    /// Anything which can't be saved (i.e. computed regions and the cached size) is left alone.
+    fn apply_geometry(&self, size_and_position: &mut SizeAndPosition) {
+        size_and_position.size_offset = self.size_offset;
+        size_and_position.position_offset = self.position_offset;
+        size_and_position.size_area_percent = self.size_area_percent;
+        size_and_position.position_area_percent = self.position_area_percent;
+        size_and_position.anchor = self.anchor;
+        size_and_position.min_size = self.min_size;
+        size_and_position.max_size = self.max_size;
+    }
+}
+
+/// The layout of every widget in a scene, keyed by the widgets' names (see `Scene::export_layout`
+/// and `Scene::apply_layout`). Only the arrangement is saved; the widgets themselves (and their
+/// content) still have to be built by the app before the layout is applied.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SceneLayout {
+    /// The layout of each widget by its name.
+    pub widgets: std::collections::BTreeMap<String, WidgetLayout>,
+}
+
+impl<C> Scene<C> {
+    /// Captures the layout of every widget in the scene: its size and position configuration,
+    /// depth, border, title, parent, and visibility. Widgets without a window or without a
+    /// size and position configuration (see `Widget::get_size_and_position`) are skipped.
+    /// # Example
+    /// ```
+    /// use term_render::widget::Scene;
+    /// use term_render::widget_impls::{StaticWidgetBuilder, WidgetBuilder};
+    ///
+    /// let build = |app: &mut term_render::App<()>, scene: &mut Scene<()>| {
+    ///     StaticWidgetBuilder::<()>::builder(String::from("pane"))
+    ///         .with_position((2, 2))
+    ///         .with_size((10, 4))
+    ///         .with_renderer(Box::new(|_size, _position, _data| None))
+    ///         .add_to_scene(app, scene)
+    ///         .unwrap();
+    /// };
+    ///
+    /// // the user rearranges the pane during one session
+    /// let mut app = term_render::App::<()>::new_headless(40, 20);
+    /// let mut scene = Scene::new();
+    /// build(&mut app, &mut scene);
+    /// let index = scene.get_widget_index(String::from("pane")).unwrap();
+    /// scene.widget_as_dyn_mut(index).unwrap().get_size_and_position_mut().unwrap().position_offset = (20, 8);
+    /// app.renderer.write().get_window_reference_mut(String::from("pane")).set_bordered(true);
+    /// let layout = scene.export_layout(&app.renderer.read());
+    /// assert_eq!(layout.widgets["pane"].position_offset, (20, 8));
+    ///
+    /// // and the next session rebuilds the scene and restores the arrangement
+    /// let mut app = term_render::App::<()>::new_headless(40, 20);
+    /// let mut scene = Scene::new();
+    /// build(&mut app, &mut scene);
+    /// scene.apply_layout(&layout, &mut app.renderer.write()).unwrap();
+    /// assert_eq!(scene.export_layout(&app.renderer.read()), layout);
+    /// ```
+    pub fn export_layout(&self, renderer: &term_render::App) -> SceneLayout {
+        let mut layout = SceneLayout::default();
+        for index in 0..self.widgets.len() {
+            let Some(widget) = self.widgets.index(index) else {  continue;  };
+            let Some(size_and_position) = widget.get_size_and_position() else {  continue;  };
+            let name = widget.get_window_ref();
+            if !renderer.contains_window(name.clone()) {  continue;  }
+            let window = renderer.get_window_reference(name.clone());
+            let parent = widget.get_parent_index()
+                .and_then(|parent| self.widgets.index(parent))
+                .map(|parent| parent.get_window_ref());
+            layout.widgets.insert(name, WidgetLayout {
+                size_offset: size_and_position.size_offset,
+                position_offset: size_and_position.position_offset,
+                size_area_percent: size_and_position.size_area_percent,
+                position_area_percent: size_and_position.position_area_percent,
+                anchor: size_and_position.anchor,
+                min_size: size_and_position.min_size,
+                max_size: size_and_position.max_size,
+                depth: window.depth,
+                bordered: window.is_bordered(),
+                title: window.has_title().then(|| window.get_title_text()),
+                parent,
+                visible: self.is_widget_visible(index),
+            });
+        } layout
+    }
+
+    /// Applies a layout captured by `export_layout`, matching widgets by name. Names which no longer
+    /// exist in the scene are ignored, and widgets missing from the layout are left untouched.
+    /// A widget is only moved to its saved parent if that parent still exists.
+    /// Returns an error if a widget can't be reparented (i.e. it would create a cycle).
+    pub fn apply_layout(&mut self, layout: &SceneLayout, renderer: &mut term_render::App) -> Result<(), WidgetError> {
+        let mut shown = vec![];
+        let mut hidden = vec![];
+        for (name, widget_layout) in &layout.widgets {
+            let Some(index) = self.get_widget_index(name.clone()) else {  continue;  };
+            let Some(widget) = self.widgets.index_mut(index) else {  continue;  };
+            if let Some(size_and_position) = widget.get_size_and_position_mut() {
+                widget_layout.apply_geometry(size_and_position);
+            }
+
+            if renderer.contains_window(name.clone()) {
+                let window = renderer.get_window_reference_mut(name.clone());
+                window.depth = widget_layout.depth;
+                window.set_bordered(widget_layout.bordered);
+                window.titled(widget_layout.title.clone().unwrap_or_default());
+                window.update_all();
+            }
+
+            // if the parent no longer exists, the widget stays where it is
+            let parent = match &widget_layout.parent {
+                Some(parent) => self.get_widget_index(parent.clone()).map(Some),
+                None => Some(None),
+            };
+            if let Some(parent) = parent &&
+                self.widgets.index(index).and_then(|widget| widget.get_parent_index()) != parent {
+                self.reparent(index, parent)?;
+            }
+            match widget_layout.visible {
+                true => shown.push(index),
+                false => hidden.push(index),
+            }
+        }
+        renderer.update_window_layout_order();
+
+        // showing a widget shows its whole subtree, so the hidden widgets are hidden afterwards
+        for index in shown {
+            if !self.is_widget_visible(index) {  self.set_widget_visible(index, true, renderer)?;  }
+        }
+        for index in hidden {
+            if self.is_widget_visible(index) {  self.set_widget_visible(index, false, renderer)?;  }
+        } Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+// Tooltips shown once the mouse rests over a widget. The scene owns their whole lifecycle: it tracks how long
+// the mouse has been still, and draws the tooltip in a window of its own (rather than a widget, so it never
+// blocks clicks or takes the focus). Widgets only have to provide the text (see `WidgetBuilder::with_tooltip`).
+
+use crate::render::{self as term_render, Span};
+use crate::widget::Scene;
+use crate::App;
+
+/// How long the mouse has to rest over a widget before its tooltip appears, unless another delay is set
+/// (see `Scene::set_tooltip_delay`).
+pub const DEFAULT_TOOLTIP_DELAY: std::time::Duration = std::time::Duration::from_millis(600);
+
+/// The depth tooltips are drawn at, so they sit above everything else (including context menus).
+pub const TOOLTIP_DEPTH: u16 = 300;
+
+/// The name of the window tooltips are drawn in.
+const TOOLTIP_WINDOW: &str = "__tooltip";
+
+/// A closure producing a tooltip's text from the app data, so it can reflect the app's state.
+pub type TooltipFunction<C> = Box<dyn Fn(&mut C) -> Span>;
+
+/// The text shown in a widget's tooltip (see `WidgetBuilder::with_tooltip`). Both `Span`s and
+/// `TooltipFunction`s convert into tooltips.
+pub enum Tooltip<C> {
+    /// Fixed text.
+    Text(Span),
+    /// Text produced from the app data each time the tooltip appears.
+    Dynamic(TooltipFunction<C>),
+}
+
+impl<C> Tooltip<C> {
+    /// Gets the text the tooltip shows.
+    pub fn get_text(&self, data: &mut C) -> Span {
+        match self {
+            Tooltip::Text(text) => text.clone(),
+            Tooltip::Dynamic(function) => function(data),
+        }
+    }
+}
+
+impl<C> From<Span> for Tooltip<C> {
+    fn from(text: Span) -> Self {
+        Tooltip::Text(text)
+    }
+}
+
+impl<C> From<TooltipFunction<C>> for Tooltip<C> {
+    fn from(function: TooltipFunction<C>) -> Self {
+        Tooltip::Dynamic(function)
+    }
+}
+
+/// Tracks the mouse for the scene's tooltip.
+pub(crate) struct TooltipState {
+    /// How long the mouse has to rest before the tooltip appears.
+    delay: std::time::Duration,
+    /// Where the mouse is resting, and since when.
+    resting: Option<((u16, u16), std::time::Instant)>,
+    /// Whether the tooltip's window is shown.
+    shown: bool,
+}
+
+impl TooltipState {
+    pub(crate) fn new() -> Self {
+        TooltipState {
+            delay: DEFAULT_TOOLTIP_DELAY,
+            resting: None,
+            shown: false,
+        }
+    }
+}
+
+impl<C> Scene<C> {
+    /// Sets how long the mouse has to rest over a widget (without moving, clicking, or any key being pressed)
+    /// before its tooltip appears. The default is `DEFAULT_TOOLTIP_DELAY` (600ms).
+    /// # Example
+    /// ```
+    /// use term_render::widget_impls::{ButtonWidgetBuilder, WidgetBuilder};
+    /// use term_render::event_handler::{MouseEvent, MouseEventType, MouseState};
+    /// use term_render::render::{Colored, Span};
+    /// use term_render::widget::Scene;
+    /// let mut app = term_render::App::<()>::new_headless(30, 5);
+    /// let mut scene = Scene::new();
+    /// scene.set_tooltip_delay(std::time::Duration::from_millis(50));
+    /// ButtonWidgetBuilder::<()>::builder(String::from("save"))
+    ///     .with_tooltip(Span::from_tokens(vec![Colored::new(String::from("Saves the file"))]))
+    ///     .with_position((0, 0))
+    ///     .with_size((30, 5))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .unwrap();
+    /// // runs a frame, moving the mouse to the given position (if any)
+    /// let mut frame = |scene: &mut Scene<()>, position: Option<(u16, u16)>| {
+    ///     if let Some(position) = position {
+    ///         let mut events = app.events.write();
+    ///         events.mouse_position = Some(position);
+    ///         events.mouse_event = Some(MouseEvent { event_type: MouseEventType::Null, position, state: MouseState::Hold, click_count: 0 });
+    ///     }
+    ///     scene.update_all_widgets(&mut app, &mut ()).unwrap();
+    ///     app.events.write().clear_events();
+    ///     app.renderer.write().snapshot()
+    /// };
+    ///
+    /// // the tooltip only appears once the mouse has rested over the button for the delay
+    /// frame(&mut scene, Some((4, 1)));
+    /// assert!(!scene.is_tooltip_shown());
+    /// assert_eq!(frame(&mut scene, None)[2], "");
+    /// std::thread::sleep(std::time::Duration::from_millis(60));
+    /// // it's drawn beside the cursor
+    /// assert_eq!(frame(&mut scene, None)[2], "     Saves the file");
+    /// assert!(scene.is_tooltip_shown());
+    ///
+    /// // moving the mouse hides it, and near the bottom right corner, it flips to stay within the terminal
+    /// frame(&mut scene, Some((25, 4)));
+    /// assert!(!scene.is_tooltip_shown());
+    /// std::thread::sleep(std::time::Duration::from_millis(60));
+    /// assert_eq!(frame(&mut scene, None)[3], "                Saves the file");
+    /// ```
+    pub fn set_tooltip_delay(&mut self, delay: std::time::Duration) {
+        self.tooltip.delay = delay;
+    }
+
+    /// Gets how long the mouse has to rest over a widget before its tooltip appears (see `set_tooltip_delay`).
+    pub fn get_tooltip_delay(&self) -> std::time::Duration {
+        self.tooltip.delay
+    }
+
+    /// Returns whether a tooltip is currently shown.
+    pub fn is_tooltip_shown(&self) -> bool {
+        self.tooltip.shown
+    }
+
+    /// Hides the tooltip (if it's shown).
+    fn hide_tooltip(&mut self, app: &mut term_render::App) {
+        if !std::mem::take(&mut self.tooltip.shown) {  return;  }
+        let _ = app.remove_window(String::from(TOOLTIP_WINDOW));
+    }
+
+    /// Shows or hides the tooltip based on where the mouse is resting and for how long. Any movement, click,
+    /// or key press hides it and restarts the delay. Once the delay passes, the tooltip of the widget which
+    /// would receive a click there (or its closest ancestor with a tooltip) is shown beside the cursor.
+    pub(crate) fn update_tooltip(&mut self, app: &mut App<C>, data: &mut C) {
+        let events = app.events.read();
+        let position = events.last_mouse_position();
+        let interacted = events.key_events.values().any(|pressed| *pressed) || !events.char_events.is_empty() ||
+            events.mouse_event.as_ref().is_some_and(|event| event.event_type != crate::event_handler::MouseEventType::Null);
+        drop(events);
+        match (position, self.tooltip.resting) {
+            (Some(position), Some((resting, _since))) if position == resting && !interacted => {},
+            _ => {
+                self.hide_tooltip(&mut app.renderer.write());
+                self.tooltip.resting = position.map(|position| (position, std::time::Instant::now()));
+                return;
+            },
+        }
+        let Some((position, since)) = self.tooltip.resting else {  return;  };
+        if self.tooltip.shown || since.elapsed() < self.tooltip.delay {  return;  }
+
+        let mut index = self.hit_test(position, app);
+        let tooltip = loop {
+            let Some(widget) = index.and_then(|index| self.widgets.index(index)) else {  return;  };
+            if let Some(tooltip) = widget.get_tooltip() {  break tooltip;  }
+            index = widget.get_parent_index();
+        };
+        let text = tooltip.get_text(data);
+        let area = app.area.read().clone();
+        let size = ((text.size() as u16).min(area.width), 1);
+        if size.0 == 0 {  return;  }
+        // below and to the right of the cursor, unless that would leave the terminal
+        let mut tooltip_position = (position.0 + 1, position.1 + 1);
+        if tooltip_position.0 + size.0 > area.width {  tooltip_position.0 = area.width - size.0;  }
+        if tooltip_position.1 >= area.height {  tooltip_position.1 = position.1.saturating_sub(1);  }
+        let mut window = term_render::Window::new(tooltip_position, TOOLTIP_DEPTH, size);
+        window.from_lines(vec![text]);
+        let _ = app.renderer.write().add_window(window, String::from(TOOLTIP_WINDOW), vec![]);  // removed whenever it's hidden
+        self.tooltip.shown = true;
+    }
+}
@@ -2,6 +2,8 @@
 
 // handles widgets and all between
 use crate::{render as term_render, render, App};
+pub use crate::scene_layout::{SceneLayout, WidgetLayout};
+pub use crate::scene_tooltip::{Tooltip, TooltipFunction, DEFAULT_TOOLTIP_DELAY, TOOLTIP_DEPTH};
 
 // I don't like the all unsafe, but I don't see an easy way around it without
 // complicating the API and usage significantly.
@@ -120,6 +122,103 @@ impl<C> Widget<C> for WidgetEventQueuer<C> {
     fn is_collided(&self, position: (u16, u16)) -> bool {
         unsafe {  (*self.owner).is_collided(position)  }
     }
+    
+    /// Gets the smoothed scroll amount of the mouse wheel events over the widget.
+    fn scroll_delta(&self, events: &crate::event_handler::KeyParser) -> f64 {
+        unsafe {  (*self.owner).scroll_delta(events)  }
+    }
+    
+    /// Returns the offset applied to the positions of this widget's children.
+    fn get_child_offset(&self) -> (i16, i16) {
+        unsafe {  (*self.owner).get_child_offset()  }
+    }
+    
+    /// Returns the region (size, position) this widget's children are clipped to, if any.
+    fn get_child_clip(&self) -> Option<Region> {
+        unsafe {  (*self.owner).get_child_clip()  }
+    }
+    
+    /// Sets the offset and clip region inherited from this widget's parents.
+    fn set_parent_transform(&mut self, offset: (i16, i16), clip: Option<Region>) {
+        unsafe {  (*self.owner).set_parent_transform(offset, clip);  }
+    }
+    
+    /// Adjusts the child offset so the given region (size, position) is visible.
+    fn scroll_child_into_view(&mut self, rect: Region) -> bool {
+        unsafe {  (*self.owner).scroll_child_into_view(rect)  }
+    }
+    
+    /// Returns the owning widget as `Any` (not the event queuer itself).
+    fn as_any(&self) -> &dyn std::any::Any {
+        unsafe {  (*self.owner).as_any()  }
+    }
+    
+    /// Returns the owning widget as a mutable `Any` (not the event queuer itself).
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        unsafe {  (*self.owner).as_any_mut()  }
+    }
+    
+    /// Returns whether the widget can receive keyboard focus.
+    fn focusable(&self) -> bool {
+        unsafe {  (*self.owner).focusable()  }
+    }
+    
+    /// Returns whether the widget needs `update_with_events` called each update.
+    fn consumes_events(&self) -> bool {
+        unsafe {  (*self.owner).consumes_events()  }
+    }
+    
+    /// Tells the widget whether it has the scene's keyboard focus.
+    fn set_focused(&mut self, focused: bool) {
+        unsafe {  (*self.owner).set_focused(focused);  }
+    }
+    
+    /// Returns where the widget wants the terminal's cursor shown, if anywhere.
+    fn cursor_position(&self) -> Option<(u16, u16)> {
+        unsafe {  (*self.owner).cursor_position()  }
+    }
+    
+    /// Returns whether the widget has a selection which can be copied.
+    fn has_selection(&self) -> bool {
+        unsafe {  (*self.owner).has_selection()  }
+    }
+    
+    /// Returns the widget's size and position configuration, if it has one.
+    fn get_size_and_position(&self) -> Option<&crate::widget_impls::SizeAndPosition> {
+        unsafe {  (*self.owner).get_size_and_position()  }
+    }
+    
+    /// Returns the widget's size and position configuration mutably, if it has one.
+    fn get_size_and_position_mut(&mut self) -> Option<&mut crate::widget_impls::SizeAndPosition> {
+        unsafe {  (*self.owner).get_size_and_position_mut()  }
+    }
+    
+    /// Returns the keywords the widget's window is tagged with.
+    fn get_keywords(&self) -> &[String] {
+        unsafe {  (*self.owner).get_keywords()  }
+    }
+    
+    /// Returns the tooltip shown while the mouse rests over the widget, if any.
+    fn get_tooltip(&self) -> Option<&Tooltip<C>> {
+        unsafe {  (*self.owner).get_tooltip()  }
+    }
+}
+
+/// A rectangular region given as (size, position), matching the order used by `SizeAndPosition`.
+pub type Region = ((u16, u16), (u16, u16));
+
+/// Pairs of widget indexes and the depths of their windows.
+type WidgetDepths = Vec<(usize, u16)>;
+
+/// A closure run as a widget is mounted or unmounted (see `Widget::on_mount`), given the app data and the app.
+pub type LifecycleHook<C> = Box<dyn FnMut(&mut C, &mut App<C>)>;
+
+/// A lifecycle hook waiting for the scene to have the app and data (see `Scene::run_lifecycle_hooks`).
+enum Lifecycle<C> {
+    /// The widget at the index was added.
+    Mount(usize),
+    /// The widget was removed (and is kept until its hook runs).
+    Unmount(Box<dyn Widget<C>>),
 }
 
 /// Core trait defining the interface for all UI widgets in the scene graph.
@@ -163,34 +262,223 @@ pub trait Widget<T> {
     
     /// Checks if a given position collides with the widget's area.
     fn is_collided(&self, position: (u16, u16)) -> bool;
+    
+    /// Gets the smoothed scroll amount of only the recent mouse wheel events over this widget
+    /// (see `KeyParser::scroll_at`), so scrolling over one widget doesn't scroll another.
+    /// # Example
+    /// ```
+    /// use term_render::widget_impls::{ListWidget, ListWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::{Colored, Span};
+    /// use term_render::widget::Scene;
+    /// let mut app = term_render::App::<()>::new_headless(20, 5);
+    /// let mut scene = Scene::new();
+    /// let items: Vec<Span> = (0..30).map(|i| Span::from_tokens(vec![Colored::new(i.to_string())])).collect();
+    /// for (name, x) in [("left", 0), ("right", 10)] {
+    ///     ListWidgetBuilder::<()>::builder(String::from(name))
+    ///         .with_items(items.clone())
+    ///         .with_position((x, 0))
+    ///         .with_size((10, 5))
+    ///         .add_to_scene(&mut app, &mut scene)
+    ///         .unwrap();
+    /// }
+    /// scene.update_all_widgets(&mut app, &mut ()).unwrap();
+    /// let mut parser = vte::Parser::new();
+    ///
+    /// // scrolling down over the left list twice and the right list once, interleaved
+    /// parser.advance(&mut *app.events.write(), b"\x1b[<65;3;2M\x1b[<65;13;2M\x1b[<65;3;4M");
+    /// let events = app.events.read();
+    /// let delta = |name: &str| scene.widget_as_ref(scene.get_widget_index(String::from(name)).unwrap()).unwrap().scroll_delta(&events);
+    /// let (left, right) = (delta("left"), delta("right"));
+    /// assert!(right > 0.0 && (left - right * 2.0).abs() < 1e-9);
+    /// drop(events);
+    ///
+    /// // only the list under the wheel scrolls
+    /// app.events.write().scroll_events.clear();
+    /// parser.advance(&mut *app.events.write(), b"\x1b[<65;3;2M");
+    /// scene.update_all_widgets(&mut app, &mut ()).unwrap();
+    /// assert_eq!(scene.widget_as_mut::<ListWidget<()>>("left").unwrap().get_scroll_offset(), 1);
+    /// assert_eq!(scene.widget_as_mut::<ListWidget<()>>("right").unwrap().get_scroll_offset(), 0);
+    /// ```
+    fn scroll_delta(&self, events: &crate::event_handler::KeyParser) -> f64 {
+        events.scroll_at(|position| self.is_collided(position))
+    }
+    
+    /// Returns the offset applied to the positions of this widget's children (i.e. a scroll offset).
+    /// The offsets of all ancestors are summed by the scene and passed to `set_parent_transform`.
+    /// By default, children aren't offset.
+    fn get_child_offset(&self) -> (i16, i16) {  (0, 0)  }
+    
+    /// Returns the region (size, position) this widget's children are clipped to, or `None` if
+    /// they aren't clipped. The regions of all ancestors are intersected by the scene.
+    fn get_child_clip(&self) -> Option<Region> {  None  }
+    
+    /// Sets the offset and clip region (size, position) inherited from this widget's ancestors.
+    /// This is called by the scene before every update. Widgets should add the offset to their
+    /// resolved position and ignore positions outside the clip when hit-testing (`SizeAndPosition`
+    /// handles both through its `parent_offset` and `clip` fields).
+    /// The default implementation ignores the transform.
+    fn set_parent_transform(&mut self, _offset: (i16, i16), _clip: Option<Region>) {}
+    
+    /// Requests that the child offset is adjusted so the given region (size, position), in terminal
+    /// coordinates, is visible. Returns true if the offset changed.
+    /// This is called on every ancestor by `Scene::scroll_into_view`. By default, nothing happens.
+    fn scroll_child_into_view(&mut self, _rect: Region) -> bool {  false  }
+    
+    /// Returns whether the widget can receive keyboard focus (through clicking it or Tab/Shift+Tab).
+    /// By default, widgets aren't focusable.
+    fn focusable(&self) -> bool {  false  }
+    
+    /// Returns whether the widget needs `update_with_events` called on every update. Widgets which
+    /// never react to events can return false, letting `Scene::update_all_widgets` skip straight to
+    /// rendering them. By default, widgets are assumed to consume events.
+    fn consumes_events(&self) -> bool {  true  }
+    
+    /// Called once after the widget is added to the scene, for starting anything it owns (timers,
+    /// background tasks, file watchers, etc.). Adding a widget doesn't give the scene the app data, so
+    /// this runs at the start of the next `Scene::update_all_widgets` (or `Scene::run_lifecycle_hooks`).
+    /// By default, nothing happens.
+    fn on_mount(&mut self, _app: &mut App<T>, _data: &mut T) {}
+    
+    /// Called once after the widget is removed from the scene (whether directly, as the child of a removed
+    /// widget, or through `Scene::remove_widgets_by_keyword`), for stopping anything started in `on_mount`.
+    /// Children are unmounted before their parents. Like `on_mount`, this runs once the scene next has the
+    /// app data, by which point the widget's window is gone. A widget removed before it was mounted is
+    /// never mounted or unmounted. By default, nothing happens.
+    fn on_unmount(&mut self, _app: &mut App<T>, _data: &mut T) {}
+    
+    /// Returns the widget as `Any`, allowing it to be downcast to its concrete type (see `Scene::widget_as`).
+    /// Implementations should simply return `self`.
+    fn as_any(&self) -> &dyn std::any::Any;
+    
+    /// Returns the widget as a mutable `Any`, allowing it to be downcast to its concrete type
+    /// (see `Scene::widget_as_mut`). Implementations should simply return `self`.
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any;
+    
+    /// Tells the widget whether it currently has the scene's keyboard focus. This is called by the
+    /// scene before every update, so interactive widgets should only respond to the keyboard while focused.
+    /// The default implementation ignores the focus.
+    fn set_focused(&mut self, _focused: bool) {}
+    
+    /// Returns where the widget wants the terminal's cursor shown (0-based, like widget positions), such as a
+    /// text input's insertion point while it's selected. After updating, the scene shows the cursor at the
+    /// first position reported (see `render::App::set_cursor`), hiding it again once no widget reports one.
+    /// By default, widgets don't show the cursor.
+    fn cursor_position(&self) -> Option<(u16, u16)> {  None  }
+    
+    /// Returns whether the widget has a selection which can be copied (i.e. selected text).
+    /// While the focused widget has one, Ctrl+C copies rather than exiting the app.
+    /// By default, widgets don't have selections.
+    fn has_selection(&self) -> bool {  false  }
+    
+    /// Returns the widget's size and position configuration, letting the scene read its geometry
+    /// (see `Scene::export_layout`). By default, widgets don't expose one.
+    fn get_size_and_position(&self) -> Option<&crate::widget_impls::SizeAndPosition> {  None  }
+    
+    /// Returns the widget's size and position configuration mutably, letting the scene change its
+    /// geometry (see `Scene::apply_layout`). By default, widgets don't expose one.
+    fn get_size_and_position_mut(&mut self) -> Option<&mut crate::widget_impls::SizeAndPosition> {  None  }
+    
+    /// Returns the keywords the widget's window is tagged with, which are passed through to
+    /// `render::App::add_window` (see `Scene::widgets_by_keyword`). By default, widgets have none.
+    fn get_keywords(&self) -> &[String] {  &[]  }
+    
+    /// Returns the tooltip the scene shows once the mouse rests over the widget (see
+    /// `WidgetBuilder::with_tooltip` and `Scene::set_tooltip_delay`). By default, widgets have none.
+    fn get_tooltip(&self) -> Option<&Tooltip<T>> {  None  }
 }
 
-/// Error type for widget operations, containing descriptive error messages.
-/// Used throughout the widget system for consistent error handling.
+/// Error type for widget operations (and building widgets; see `WidgetBuilderError`).
+/// Each kind of failure is its own variant, so callers can match on them rather than comparing messages.
+/// # Example
+/// ```
+/// use term_render::widget::{Scene, WidgetError};
+/// let mut app = term_render::App::<()>::new_headless(10, 3);
+/// let mut scene = Scene::<()>::new();
+/// match scene.remove_widget_ref(String::from("missing"), &mut app.renderer.write()) {
+///     Err(WidgetError::NameNotFound(name)) => assert_eq!(name, "missing"),
+///     _ => unreachable!(),
+/// }
+/// assert!(matches!(scene.set_focus(3), Err(WidgetError::IndexOutOfBounds { index: 3, len: 0 })));
+/// ```
 #[derive(Debug)]
-pub struct WidgetErr {
-    details: String,
+pub enum WidgetError {
+    /// The index is past the end of the scene's widgets.
+    IndexOutOfBounds { index: usize, len: usize },
+    /// The widget which was at the index has been removed.
+    RemovedWidget(usize),
+    /// There's no widget with the given name.
+    NameNotFound(String),
+    /// A widget (or window) with the given name already exists (see `Scene::generate_unique_name`).
+    DuplicateName(String),
+    /// The scene already has a parentless (root) widget.
+    DuplicateRoot,
+    /// A widget can't be parented to itself or one of its descendants.
+    CyclicParent { index: usize, parent: usize },
+    /// The widget isn't in its parent's list of children.
+    ChildNotFound { parent: usize, child: usize },
+    /// The widget has no parent, but the operation needs one.
+    NoParent(usize),
+    /// The widget isn't of the type the operation needs (i.e. a `TabWidget`).
+    WrongWidgetType { index: usize, expected: &'static str },
+    /// The widget can't take the focus.
+    NotFocusable(usize),
+    /// A widget can't be built (or placed) with a zero size.
+    InvalidGeometry { size: (u16, u16), position: (u16, u16) },
+    /// The renderer failed (i.e. the widget's window doesn't exist).
+    Render(crate::render::RenderError),
+    /// Any other error, described by the message (see `WidgetError::new`).
+    Other(String),
 }
 
-impl std::fmt::Display for WidgetErr {
+/// The name `WidgetError` had before it became an enum.
+pub type WidgetErr = WidgetError;
+
+impl std::fmt::Display for WidgetError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "WidgetErr: {}", self.details)
+        match self {
+            WidgetError::IndexOutOfBounds { index, len } => write!(f, "widget index {} is out of bounds (the length is {})", index, len),
+            WidgetError::RemovedWidget(index) => write!(f, "the widget at index {} was removed", index),
+            WidgetError::NameNotFound(name) => write!(f, "no widget named '{}' exists", name),
+            WidgetError::DuplicateName(name) => write!(f, "a widget or window named '{}' already exists", name),
+            WidgetError::DuplicateRoot => write!(f, "the scene already has a root widget"),
+            WidgetError::CyclicParent { index, parent } => write!(f, "widget {} can't be parented to {}, itself or one of its children", index, parent),
+            WidgetError::ChildNotFound { parent, child } => write!(f, "widget {} isn't a child of widget {}", child, parent),
+            WidgetError::NoParent(index) => write!(f, "widget {} has no parent", index),
+            WidgetError::WrongWidgetType { index, expected } => write!(f, "widget {} isn't a {}", index, expected),
+            WidgetError::NotFocusable(index) => write!(f, "widget {} isn't focusable", index),
+            WidgetError::InvalidGeometry { size, position } => write!(f, "a widget can't have a size of {:?} (at {:?})", size, position),
+            WidgetError::Render(error) => write!(f, "{}", error),
+            WidgetError::Other(details) => write!(f, "{}", details),
+        }
+    }
+}
+
+impl std::error::Error for WidgetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WidgetError::Render(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<crate::render::RenderError> for WidgetError {
+    fn from(error: crate::render::RenderError) -> Self {
+        WidgetError::Render(error)
     }
 }
 
-impl WidgetErr {
-    /// Creates a new `WidgetErr` with the given details. Currently,
-    /// this is a very simple struct format, so details will already need
-    /// to be coalesced into a single string.
+impl WidgetError {
+    /// Creates a `WidgetError::Other` with the given details (for errors without a variant of their own).
     pub fn new(details: &str) -> Self {
-        WidgetErr { details: details.to_string() }
+        WidgetError::Other(details.to_string())
     }
 }
 
 /// A specialized vector that maintains stable indices after item removal.
 /// Uses a free-list of reserved positions to allow O(1) insertion/removal
 /// while preserving indices of existing elements - crucial for UI scene graphs.
-struct PositionReservedVector<C, T: Widget<C> + ?Sized> {
+pub(crate) struct PositionReservedVector<C, T: Widget<C> + ?Sized> {
     /// The underlying vector storing widgets or None for reserved positions.
     pub vector: Vec<Option<Box<T>>>,
     /// List of indices that have been removed and can be reused.
@@ -203,11 +491,11 @@ struct PositionReservedVector<C, T: Widget<C> + ?Sized> {
 impl <C, T: ?Sized + Widget<C>> PositionReservedVector<C, T> {
     /// Helper method to get a raw pointer for trait objects
     /// This uses unsafe code but is necessary for the event queuer system
-    fn get_widget_raw_ptr(widget: &Box<T>) -> *mut dyn Widget<C> {
+    fn get_widget_raw_ptr(widget: &T) -> *mut dyn Widget<C> {
         unsafe {
             // We use std::ptr::addr_of! to get the address without creating an intermediate reference
             // This works because Box guarantees the pointer is valid
-            let box_ptr = widget.as_ref() as *const T;
+            let box_ptr = widget as *const T;
             std::mem::transmute_copy::<*const T, *mut dyn Widget<C>>(&box_ptr)
         }
     }
@@ -261,12 +549,9 @@ impl <C, T: ?Sized + Widget<C>> PositionReservedVector<C, T> {
     /// Removes an item at the given index, replacing it with `None`, leaving the indices intact.
     /// Marks the position as reserved for future reuse.
     /// Returns the removed item or an error if index is invalid.
-    pub fn remove(&mut self, index: usize) -> Result<Box<T>, WidgetErr> {
-        if index >= self.vector.len() {
-            return Err(WidgetErr::new("Index out of bounds"));
-        }
-        if self.reserved_positions.contains(&index) {
-            return Err(WidgetErr::new("Index is reserved and cannot be removed"));
+    pub fn remove(&mut self, index: usize) -> Result<Box<T>, WidgetError> {
+        if index >= self.vector.len() || self.reserved_positions.contains(&index) {
+            return Err(self.missing(index));
         }
         
         let item = self.vector.remove(index);
@@ -275,7 +560,7 @@ impl <C, T: ?Sized + Widget<C>> PositionReservedVector<C, T> {
         
         Ok(match item {
             Some(i) => i,
-            None => return Err(WidgetErr::new("Invalid widget index - 1")),
+            None => return Err(WidgetError::RemovedWidget(index)),
         })
     }
     
@@ -303,11 +588,11 @@ impl <C, T: ?Sized + Widget<C>> PositionReservedVector<C, T> {
     
     /// Returns a reference to the item at the given index, if it exists.
     /// If the index is out of bounds *or* reserved, returns None.
-    pub fn index(&self, index: usize) -> Option<&Box<T>> {
+    pub fn index(&self, index: usize) -> Option<&T> {
         if index >= self.vector.len() {
             return None;
         }
-        self.vector[index].as_ref()
+        self.vector[index].as_deref()
     }
     
     /// Returns a mutable reference to the item at the given index, if it exists.
@@ -318,13 +603,60 @@ impl <C, T: ?Sized + Widget<C>> PositionReservedVector<C, T> {
         }
         self.vector[index].as_mut()
     }
+    
+    /// Gets the error describing why there's no item at the given index.
+    pub fn missing(&self, index: usize) -> WidgetError {
+        match index >= self.vector.len() {
+            true => WidgetError::IndexOutOfBounds { index, len: self.vector.len() },
+            false => WidgetError::RemovedWidget(index),
+        }
+    }
+    
+    /// Like `index`, but returns an error (see `missing`) rather than `None`.
+    pub fn get(&self, index: usize) -> Result<&T, WidgetError> {
+        self.index(index).ok_or_else(|| self.missing(index))
+    }
+    
+    /// Like `index_mut`, but returns an error (see `missing`) rather than `None`.
+    pub fn get_mut(&mut self, index: usize) -> Result<&mut Box<T>, WidgetError> {
+        let missing = self.missing(index);
+        self.index_mut(index).ok_or(missing)
+    }
 }
 
 /// Manages a collection of widgets and their hierarchical relationships.
 /// Handles rendering coordination, event propagation, and widget lifecycle.
 pub struct Scene<C> {
     /// All widgets in the scene
-    widgets: PositionReservedVector<C, dyn Widget<C>>,
+    pub(crate) widgets: PositionReservedVector<C, dyn Widget<C>>,
+    /// Whether the hit-test debugging overlay is being drawn.
+    hit_test_overlay: bool,
+    /// The index of the widget with keyboard focus, if any.
+    focused: Option<usize>,
+    /// The widgets Tab visits first, in order (see `set_tab_order`).
+    tab_order: Vec<usize>,
+    /// The index of each widget by its name (kept in sync as widgets are added and removed).
+    names: std::collections::HashMap<String, usize>,
+    /// The indexes of widgets which have been hidden (see `set_widget_visible`).
+    hidden: std::collections::HashSet<usize>,
+    /// Whether a widget showed the terminal's cursor last update (see `Widget::cursor_position`).
+    showing_cursor: bool,
+    /// The index of the modal widget, if any (see `set_modal`).
+    modal: Option<usize>,
+    /// The indexes of widgets waiting to be removed once the scene finishes updating (see `queue_removal`).
+    pending_removals: Vec<usize>,
+    /// The widgets waiting to be mounted or unmounted, in order (see `run_lifecycle_hooks`).
+    lifecycle: Vec<Lifecycle<C>>,
+    /// The index of the open context menu, if any (see `open_context_menu`).
+    context_menu: Option<usize>,
+    /// Where the mouse is resting and whether the tooltip is shown (see `set_tooltip_delay`).
+    pub(crate) tooltip: crate::scene_tooltip::TooltipState,
+}
+
+impl<C> Default for Scene<C> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<C> Scene<C> {
@@ -338,63 +670,146 @@ impl<C> Scene<C> {
                 event_queuer: None,
                 _phantom: std::marker::PhantomData,
             },
+            hit_test_overlay: false,
+            focused: None,
+            tab_order: Vec::new(),
+            names: std::collections::HashMap::new(),
+            hidden: std::collections::HashSet::new(),
+            showing_cursor: false,
+            modal: None,
+            pending_removals: Vec::new(),
+            lifecycle: Vec::new(),
+            context_menu: None,
+            tooltip: crate::scene_tooltip::TooltipState::new(),
         }
     }
 
     /// Finds the index of a widget by its window reference name.
     /// Returns `Some(index)` if found, otherwise returns `None`.
     pub fn get_widget_index(&self, widget_name: String) -> Option<usize> {
-        if widget_name == self.widgets.taken.as_ref().unwrap_or(&(String::from(""), 0)).0 {
-            return Some(self.widgets.taken.as_ref().unwrap().1);
-        }
-        for i in 0..self.widgets.len() {
-            if let Some(widget) = self.widgets.index(i) {
-                if widget.get_window_ref() == widget_name {
-                    return Some(i);
-                }
-            }
-        } None
+        self.names.get(&widget_name).copied()
     }
     
     /// Returns a reference to the widget at the given index.
     /// Returns an error if the index is out of bounds.
-    pub fn widget_as_ref(&self, index: usize) -> Result<&Box<dyn Widget<C>>, WidgetErr> {
-        self.widgets.index(index).ok_or(WidgetErr::new("Index out of bounds"))
+    pub fn widget_as_ref(&self, index: usize) -> Result<&dyn Widget<C>, WidgetError> {
+        self.widgets.index(index).ok_or_else(|| self.widgets.missing(index))
     }
     
     /// Returns a mutable reference to the widget at the given index.
     /// Returns an error if the index is out of bounds.
-    pub fn widget_as_mut(&mut self, index: usize) -> Result<&mut Box<dyn Widget<C>>, WidgetErr> {
-        self.widgets.index_mut(index).ok_or(WidgetErr::new("Index out of bounds"))
+    pub fn widget_as_dyn_mut(&mut self, index: usize) -> Result<&mut Box<dyn Widget<C>>, WidgetError> {
+        self.widgets.get_mut(index)
+    }
+    
+    /// Gets the widget with the given name as its concrete type, allowing access to fields and methods
+    /// which aren't part of the `Widget` trait. Returns `None` if there's no widget with the name, or
+    /// if it isn't a `W`.
+    /// # Example:
+    /// ```no_run
+    /// use term_render::widget_impls::TypingWidget;
+    /// # struct AppData;
+    /// # let scene = term_render::widget::Scene::<AppData>::new();
+    /// if let Some(search) = scene.widget_as::<TypingWidget<AppData>>("search") {
+    ///     println!("Searching for {}", search.typed_text);
+    /// }
+    /// ```
+    pub fn widget_as<W: Widget<C> + 'static>(&self, name: &str) -> Option<&W> {
+        let index = *self.names.get(name)?;
+        self.widgets.index(index)?.as_any().downcast_ref::<W>()
+    }
+    
+    /// Gets the widget with the given name as its concrete type, mutably (see `widget_as`).
+    /// Returns `None` if there's no widget with the name, or if it isn't a `W`.
+    /// # Example:
+    /// ```no_run
+    /// use term_render::widget_impls::TypingWidget;
+    /// # struct AppData;
+    /// # let mut scene = term_render::widget::Scene::<AppData>::new();
+    /// if let Some(search) = scene.widget_as_mut::<TypingWidget<AppData>>("search") {
+    ///     search.set_text("");
+    /// }
+    /// ```
+    pub fn widget_as_mut<W: Widget<C> + 'static>(&mut self, name: &str) -> Option<&mut W> {
+        let index = *self.names.get(name)?;
+        self.widgets.index_mut(index)?.as_any_mut().downcast_mut::<W>()
     }
     
     // whenever a widget is updated, all its parents need to be updated as well
     /// Adds a widget to the scene and registers its window with the renderer.
     /// Establishes parent-child relationships and handles root node assignment.
-    /// Returns the index where the widget was placed.
-    pub fn add_widget(&mut self, widget: Box<dyn Widget<C>>, window: term_render::Window, app: &mut term_render::App) -> Result<usize, WidgetErr> {
-        app.add_window(window, widget.get_window_ref(), vec![]);
+    /// Returns the index where the widget was placed, or `WidgetError::DuplicateName` if a widget (or window)
+    /// with the same name already exists (in which case nothing is added).
+    /// # Example
+    /// ```
+    /// use term_render::widget_impls::{StaticWidgetBuilder, WidgetBuilder};
+    /// use term_render::widget::{Scene, WidgetError};
+    /// let mut app = term_render::App::<()>::new_headless(20, 5);
+    /// let mut scene = Scene::new();
+    /// let add = |name: &str, app: &mut term_render::App<()>, scene: &mut Scene<()>| {
+    ///     StaticWidgetBuilder::<()>::builder(String::from(name))
+    ///         .with_position((0, 0))
+    ///         .with_size((5, 1))
+    ///         .add_to_scene(app, scene)
+    /// };
+    /// let first = add("panel", &mut app, &mut scene).unwrap();
+    /// assert!(matches!(add("panel", &mut app, &mut scene), Err(WidgetError::DuplicateName(name)) if name == "panel"));
+    /// assert_eq!(scene.get_widget_index(String::from("panel")), Some(first));
+    ///
+    /// // the name can be reused once the widget is removed
+    /// let other = add("other", &mut app, &mut scene).unwrap();
+    /// scene.remove_widget(first, &mut app.renderer.write()).unwrap();
+    /// let again = add("panel", &mut app, &mut scene).unwrap();
+    /// assert_eq!(scene.get_widget_index(String::from("panel")), Some(again));
+    /// assert_eq!(scene.get_widget_index(String::from("other")), Some(other));
+    /// let renderer = app.renderer.read();
+    /// assert_eq!(renderer.get_window_reference(String::from("panel")).size, (5, 1));
+    /// assert!(renderer.contains_window(String::from("other")));
+    /// drop(renderer);
+    ///
+    /// // or a unique name can be generated
+    /// let name = scene.generate_unique_name("panel");
+    /// assert_eq!(name, "panel 1");
+    /// add(&name, &mut app, &mut scene).unwrap();
+    /// assert_eq!(scene.generate_unique_name("panel"), "panel 2");
+    /// ```
+    pub fn add_widget(&mut self, widget: Box<dyn Widget<C>>, window: term_render::Window, app: &mut term_render::App) -> Result<usize, WidgetError> {
+        let name = widget.get_window_ref();
+        if self.names.contains_key(&name) || app.contains_window(name.clone()) {
+            return Err(WidgetError::DuplicateName(name));
+        }
+        let hidden = window.hidden;
+        app.add_window(window, name.clone(), widget.get_keywords().to_vec())?;
         
         //let index = self.widgets.len();
         let parent_index = widget.get_parent_index();
         let index = self.widgets.push(widget);
+        self.names.insert(name, index);
+        // widgets built hidden are skipped until they're shown (see `set_widget_visible`)
+        if hidden {  self.hidden.insert(index);  }
+        self.lifecycle.push(Lifecycle::Mount(index));
         
         // adding the optional parent-child relationship (only the root node can be parentless)
         if let Some(parent_index) = &parent_index {
             // Fix the syntax - use proper error handling
-            match self.widgets.index_mut(*parent_index) {
-                Some(parent_widget) => parent_widget.add_child_index(index),
-                None => return Err(WidgetErr::new("Invalid widget index - 2")),
-            }
+            self.widgets.get_mut(*parent_index)?.add_child_index(index);
         }
         
         Ok(index)
     }
     
+    /// Generates a name no widget in the scene has yet, by numbering the prefix (`"prefix 1"`, `"prefix 2"`, ...),
+    /// for when a widget's name doesn't matter (see `add_widget`).
+    pub fn generate_unique_name(&self, prefix: &str) -> String {
+        (1..).map(|number| format!("{} {}", prefix, number))
+            .find(|name| !self.names.contains_key(name))
+            .unwrap_or_default()
+    }
+    
     /// A wrapper around `remove_widget` that takes a widget reference name instead of an index.
     /// This is to reduce the boilerplate of getting the index first often through a chain of functions.
-    pub fn remove_widget_ref(&mut self, r#ref: String, app: &mut term_render::App) -> Result<(), WidgetErr> {
-        let index = self.get_widget_index(r#ref).ok_or(WidgetErr::new("Widget not found"))?;
+    pub fn remove_widget_ref(&mut self, r#ref: String, app: &mut term_render::App) -> Result<(), WidgetError> {
+        let index = self.get_widget_index(r#ref.clone()).ok_or(WidgetError::NameNotFound(r#ref))?;
         self.remove_widget(index, app)
     }
     
@@ -403,13 +818,9 @@ impl<C> Scene<C> {
     /// If a parent is removed, all its children are also removed.
     /// Returns an error if the index is out of bounds or reserved (look at
     /// `PositionReservedVector::reserved_positions` for more information on reservations).
-    pub fn remove_widget(&mut self, index: usize, app: &mut term_render::App) -> Result<(), WidgetErr> {
+    pub fn remove_widget(&mut self, index: usize, app: &mut term_render::App) -> Result<(), WidgetError> {
         // checking if it's out of range, or a reserved index
-        if index >= self.widgets.len() || self.widgets.index(index).is_none() {
-            return Err(WidgetErr::new("Index out of bounds"));
-        }
-        
-        app.remove_window(self.widgets.index(index).unwrap().get_window_ref()).unwrap();
+        app.remove_window(self.widgets.get(index)?.get_window_ref())?;
         
         // updating the parents windows
         if self.widgets.index(index).unwrap().get_parent_index().is_some() {
@@ -417,34 +828,289 @@ impl<C> Scene<C> {
         }
         
         // remove from parent's children list
-        if let Some(parent_index) = match self.widgets.index(index){
-            Some(w) => w,
-            None => return Err(WidgetErr::new("Invalid widget index - 10")),
-        }.get_parent_index() {
-            let parent_widget = match self.widgets.index_mut(parent_index) {
-                Some(w) => w,
-                None => return Err(WidgetErr::new("Invalid widget index - 4")),
-            };
-            let child_index_location = parent_widget.get_children_indexes().iter().position(|&i| i == index).ok_or(WidgetErr::new("Child index not found in parent"))?;
+        if let Some(parent_index) = self.widgets.get(index)?.get_parent_index() {
+            let parent_widget = self.widgets.get_mut(parent_index)?;
+            let child_index_location = parent_widget.get_children_indexes().iter().position(|&i| i == index)
+                .ok_or(WidgetError::ChildNotFound { parent: parent_index, child: index })?;
             parent_widget.remove_child_index(child_index_location);
         }
         
         // remove all children recursively
-        let children = match self.widgets.index(index){
-            Some(w) => w,
-            None => return Err(WidgetErr::new("Invalid widget index - 5")),
-        }.get_children_indexes();
+        let children = self.widgets.get(index)?.get_children_indexes();
         for &child_index in &children {
-            match self.widgets.index_mut(child_index) {
-                Some(child_widget) => child_widget.set_parent_index(None),
-                None => return Err(WidgetErr::new("Invalid child widget index - 82")),
-            }
+            self.widgets.get_mut(child_index)?.set_parent_index(None);
             self.remove_widget(child_index, app)?;
         }
         
-        // finally, remove the widget itself
-        self.widgets.remove(index)?;
+        // finally, remove the widget itself (its index could be reused, so it can't keep the focus)
+        if self.focused == Some(index) {  self.focused = None;  }
+        if self.modal == Some(index) {  self.modal = None;  }
+        if self.context_menu == Some(index) {  self.context_menu = None;  }
+        self.tab_order.retain(|ordered| *ordered != index);
+        let name = self.widgets.index(index).map(|widget| widget.get_window_ref()).unwrap_or_default();
+        if self.names.get(&name) == Some(&index) {  self.names.remove(&name);  }
+        self.hidden.remove(&index);
+        let widget = self.widgets.remove(index)?;
+        // a widget which was never mounted doesn't need unmounting
+        let mounting = self.lifecycle.iter().position(|event| matches!(event, Lifecycle::Mount(mounting) if *mounting == index));
+        match mounting {
+            Some(position) => {  self.lifecycle.remove(position);  },
+            None => self.lifecycle.push(Lifecycle::Unmount(widget)),
+        }
+        
+        Ok(())
+    }
+    
+    /// Runs the lifecycle hooks of the widgets added and removed since they last ran, in the order it
+    /// happened (see `Widget::on_mount` and `Widget::on_unmount`). This happens at the start and end of
+    /// every `update_all_widgets`, so it only needs calling to run them sooner.
+    /// # Example
+    /// ```
+    /// use term_render::widget::Scene;
+    /// use term_render::widget_impls::{DynamicWidgetBuilder, WidgetBuilder};
+    ///
+    /// let mut app = term_render::App::<Vec<String>>::new_headless(40, 10);
+    /// let mut scene = Scene::new();
+    /// let add = |app: &mut term_render::App<Vec<String>>, scene: &mut Scene<Vec<String>>, name: &str, parent: Option<usize>| {
+    ///     let (mounted, unmounted) = (name.to_owned(), name.to_owned());
+    ///     DynamicWidgetBuilder::<Vec<String>>::builder(String::from(name))
+    ///         .with_size((5, 1))
+    ///         .with_parent(parent)
+    ///         .with_keywords(vec![String::from(if name == "root" {  "root"  } else {  "popup"  })])
+    ///         .with_on_mount(Box::new(move |log: &mut Vec<String>, _app| log.push(format!("mount {}", mounted))))
+    ///         .with_on_unmount(Box::new(move |log: &mut Vec<String>, _app| log.push(format!("unmount {}", unmounted))))
+    ///         .add_to_scene(app, scene)
+    ///         .unwrap()
+    /// };
+    /// let root = add(&mut app, &mut scene, "root", None);
+    /// let popup = add(&mut app, &mut scene, "popup", Some(root));
+    /// let button = add(&mut app, &mut scene, "button", Some(popup));
+    /// add(&mut app, &mut scene, "label", Some(button));
+    ///
+    /// let mut log = vec![];
+    /// scene.run_lifecycle_hooks(&mut app, &mut log);
+    /// assert_eq!(log, ["mount root", "mount popup", "mount button", "mount label"]);
+    ///
+    /// // removing the popup (tagged along with its children) unmounts its subtree once, children first
+    /// log.clear();
+    /// assert_eq!(scene.remove_widgets_by_keyword(vec![String::from("popup")], &mut app.renderer.write()).unwrap(), 3);
+    /// scene.update_all_widgets(&mut app, &mut log).unwrap();
+    /// scene.update_all_widgets(&mut app, &mut log).unwrap();
+    /// assert_eq!(log, ["unmount label", "unmount button", "unmount popup"]);
+    ///
+    /// // a widget removed before it's mounted is never mounted or unmounted
+    /// log.clear();
+    /// let popup = add(&mut app, &mut scene, "popup", Some(root));
+    /// scene.remove_widget(popup, &mut app.renderer.write()).unwrap();
+    /// scene.run_lifecycle_hooks(&mut app, &mut log);
+    /// assert!(log.is_empty());
+    /// ```
+    pub fn run_lifecycle_hooks(&mut self, app: &mut App<C>, data: &mut C) {
+        for event in std::mem::take(&mut self.lifecycle) {
+            match event {
+                Lifecycle::Mount(index) => {
+                    if let Ok(widget) = self.widgets.get_mut(index) {  widget.on_mount(app, data);  }
+                },
+                Lifecycle::Unmount(mut widget) => widget.on_unmount(app, data),
+            }
+        }
+    }
+    
+    /// Returns the indexes of the widgets tagged with any of the given keywords (see
+    /// `WidgetBuilder::with_keywords`), in the order they're stored in the scene.
+    pub fn widgets_by_keyword(&self, keywords: Vec<String>) -> Vec<usize> {
+        (0..self.widgets.len()).filter(|&index| {
+            self.widgets.index(index).is_some_and(|widget| {
+                widget.get_keywords().iter().any(|keyword| keywords.contains(keyword))
+            })
+        }).collect()
+    }
+    
+    /// Removes every widget tagged with any of the given keywords, along with all of their children
+    /// (tagged or not), exactly like `remove_widget`. Unlike `render::App::prune_by_keywords`, which only
+    /// removes the windows, this keeps the widgets and windows in sync.
+    /// Returns the total number of widgets removed.
+    /// # Example
+    /// ```
+    /// use term_render::widget::Scene;
+    /// use term_render::widget_impls::{StaticWidgetBuilder, WidgetBuilder};
+    ///
+    /// let mut app = term_render::App::<()>::new_headless(60, 20);
+    /// let mut scene = Scene::new();
+    /// let mut add = |scene: &mut Scene<()>, name: &str, parent: Option<usize>, keywords: Vec<String>| {
+    ///     StaticWidgetBuilder::<()>::builder(String::from(name))
+    ///         .with_position((2, 2))
+    ///         .with_size((10, 4))
+    ///         .with_renderer(Box::new(|_size, _position, _data| None))
+    ///         .with_parent(parent)
+    ///         .with_keywords(keywords)
+    ///         .add_to_scene(&mut app, scene)
+    ///         .unwrap()
+    /// };
+    /// let root = add(&mut scene, "root", None, vec![]);
+    /// let sidebar = add(&mut scene, "sidebar", Some(root), vec![]);
+    /// // a dialog whose children are only partly tagged
+    /// let dialog = add(&mut scene, "dialog", Some(root), vec![String::from("dialog")]);
+    /// add(&mut scene, "dialog ok", Some(dialog), vec![String::from("dialog")]);
+    /// add(&mut scene, "dialog message", Some(dialog), vec![]);
+    /// assert_eq!(app.renderer.read().get_windows_by_keywords_non_ref(vec![String::from("dialog")]).len(), 2);
+    ///
+    /// assert_eq!(scene.widgets_by_keyword(vec![String::from("dialog")]).len(), 2);
+    /// assert_eq!(scene.remove_widgets_by_keyword(vec![String::from("dialog")], &mut app.renderer.write()).unwrap(), 3);
+    ///
+    /// // the widgets and their windows are gone, while the rest of the tree is untouched
+    /// assert!(scene.widgets_by_keyword(vec![String::from("dialog")]).is_empty());
+    /// assert!(scene.get_widget_index(String::from("dialog message")).is_none());
+    /// assert!(!app.renderer.read().contains_window(String::from("dialog message")));
+    /// assert_eq!(scene.get_widget_index(String::from("root")), Some(root));
+    /// assert_eq!(scene.widget_as_ref(root).unwrap().get_children_indexes(), vec![sidebar]);
+    /// scene.update_all_widgets(&mut app, &mut ()).unwrap();
+    /// ```
+    pub fn remove_widgets_by_keyword(&mut self, keywords: Vec<String>, app: &mut term_render::App) -> Result<usize, WidgetError> {
+        let mut removed = 0;
+        for index in self.widgets_by_keyword(keywords) {
+            // tagged widgets inside an already removed subtree are gone by now
+            if self.widgets.index(index).is_none() {  continue;  }
+            removed += self.get_subtree_indexes(index).len();
+            self.remove_widget(index, app)?;
+        } Ok(removed)
+    }
+
+    /// Moves the widget at the given index (along with its children) to a new parent.
+    /// Both the old and new parents' child lists are updated. Returns an error if either index is
+    /// invalid, if the new parent is the widget itself or one of its descendants (which would create
+    /// a cycle), or if the widget would become a second parentless (root) widget.
+    pub fn reparent(&mut self, child_index: usize, new_parent: Option<usize>) -> Result<(), WidgetError> {
+        let old_parent = self.widgets.get(child_index)?.get_parent_index();
+        if old_parent == new_parent {  return Ok(());  }
+        match new_parent {
+            Some(parent_index) => {
+                self.widgets.get(parent_index)?;
+                if self.get_subtree_indexes(child_index).contains(&parent_index) {
+                    return Err(WidgetError::CyclicParent { index: child_index, parent: parent_index });
+                }
+            },
+            None => {
+                let has_root = (0..self.widgets.len()).any(|index| index != child_index &&
+                    self.widgets.index(index).is_some_and(|widget| widget.get_parent_index().is_none())
+                );
+                if has_root {  return Err(WidgetError::DuplicateRoot);  }
+            },
+        }
+        
+        if let Some(parent_index) = old_parent {
+            let parent_widget = self.widgets.get_mut(parent_index)?;
+            let child_index_location = parent_widget.get_children_indexes().iter().position(|&i| i == child_index)
+                .ok_or(WidgetError::ChildNotFound { parent: parent_index, child: child_index })?;
+            parent_widget.remove_child_index(child_index_location);
+        }
+        if let Some(parent_index) = new_parent {
+            self.widgets.get_mut(parent_index)?.add_child_index(child_index);
+        }
+        self.widgets.get_mut(child_index)?.set_parent_index(new_parent);
+        Ok(())
+    }
+    
+    /// Gathers the index of the given widget along with all of its descendants (the widget is first).
+    fn get_subtree_indexes(&self, index: usize) -> Vec<usize> {
+        let mut indexes = vec![index];
+        let mut i = 0;
+        while let Some(current) = indexes.get(i) {
+            if let Some(widget) = self.widgets.index(*current) {
+                indexes.extend(widget.get_children_indexes());
+            } i += 1;
+        } indexes
+    }
+    
+    /// Splits the depths of every widget's window into those within the given widget's subtree and
+    /// those outside of it, as (index, depth) pairs. Widgets without a window are skipped.
+    fn partition_depths(&self, index: usize, app: &term_render::App) -> (WidgetDepths, WidgetDepths) {
+        let subtree = self.get_subtree_indexes(index);
+        let (mut inside, mut outside) = (vec![], vec![]);
+        for i in 0..self.widgets.len() {
+            let Some(widget) = self.widgets.index(i) else {  continue;  };
+            let name = widget.get_window_ref();
+            if !app.contains_window(name.clone()) {  continue;  }
+            let depth = app.get_window_reference(name).depth;
+            if subtree.contains(&i) {  inside.push((i, depth));  }
+            else {  outside.push((i, depth));  }
+        } (inside, outside)
+    }
+    
+    /// Sets the depths of the given widgets' windows and marks them (and the layout) for re-rendering.
+    fn apply_depths(&self, depths: WidgetDepths, app: &mut term_render::App) {
+        for (index, depth) in depths {
+            let Some(widget) = self.widgets.index(index) else {  continue;  };
+            let window = app.get_window_reference_mut(widget.get_window_ref());
+            window.depth = depth;
+            window.update_all();
+        }
+        app.update_window_layout_order();
+    }
+    
+    /// Raises the widget at the given index, along with all of its descendants, above every other
+    /// widget in the scene (i.e. for clicking on a window to bring it to the front).
+    /// The relative depths within the subtree are kept. Returns an error if the index is invalid.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{StaticTextWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::{Colorize, Span};
+    /// # struct AppData;
+    /// let mut app = term_render::App::<AppData>::new_headless(10, 3);
+    /// let mut scene = term_render::widget::Scene::new();
+    /// let desk = StaticTextWidgetBuilder::<AppData>::builder(String::from("desk"))
+    ///     .with_position((0, 0)).with_size((10, 3))
+    ///     .add_to_scene(&mut app, &mut scene).unwrap();
+    /// // two overlapping windows, with "back" starting out below "front"
+    /// let mut windows = vec![];
+    /// for (name, depth) in [("back", 1), ("front", 2)] {
+    ///     windows.push(StaticTextWidgetBuilder::<AppData>::builder(String::from(name))
+    ///         .with_renderer(vec![Span::from_tokens(vec![term_render::color!(name)])])
+    ///         .with_position((0, 0)).with_size((5, 1)).with_depth(depth).with_parent(Some(desk))
+    ///         .add_to_scene(&mut app, &mut scene).unwrap());
+    /// }
+    /// let area = term_render::render::Rect { width: 10, height: 3 };
+    /// for index in &windows {
+    ///     scene.update_widget_renderer(*index, &mut app.renderer.write(), &area, &mut AppData).unwrap();
+    /// }
+    /// assert_eq!(app.renderer.write().snapshot()[0], "front");
+    ///
+    /// scene.bring_to_front(windows[0], &mut app.renderer.write()).unwrap();
+    /// assert_eq!(app.renderer.write().snapshot()[0], "back");
+    /// ```
+    pub fn bring_to_front(&mut self, index: usize, app: &mut term_render::App) -> Result<(), WidgetError> {
+        self.widgets.get(index)?;
+        let (inside, outside) = self.partition_depths(index, app);
+        let Some(min_inside) = inside.iter().map(|(_, depth)| *depth).min() else {  return Ok(());  };
+        // a gap of two keeps the borders (drawn a depth above their window) from tying with the content
+        let base = outside.iter().map(|(_, depth)| *depth).max().map_or(0, |depth| depth.saturating_add(2));
+        let depths = inside.into_iter().map(|(i, depth)| (i, (depth - min_inside).saturating_add(base))).collect();
+        self.apply_depths(depths, app);
+        Ok(())
+    }
+    
+    /// Lowers the widget at the given index, along with all of its descendants, below every other
+    /// widget in the scene. The relative depths within the subtree are kept, and if there isn't room
+    /// below the other widgets, they're raised to make room. Returns an error if the index is invalid.
+    pub fn send_to_back(&mut self, index: usize, app: &mut term_render::App) -> Result<(), WidgetError> {
+        self.widgets.get(index)?;
+        let (inside, outside) = self.partition_depths(index, app);
+        let Some(min_inside) = inside.iter().map(|(_, depth)| *depth).min() else {  return Ok(());  };
+        let max_inside = inside.iter().map(|(_, depth)| *depth).max().unwrap_or(min_inside);
+        let Some(min_outside) = outside.iter().map(|(_, depth)| *depth).min() else {  return Ok(());  };
         
+        // the subtree needs its span of depths plus a gap of two below everything else
+        let needed = (max_inside - min_inside).saturating_add(2);
+        let mut depths: WidgetDepths = vec![];
+        if min_outside >= needed {
+            let base = min_outside - needed;
+            depths.extend(inside.into_iter().map(|(i, depth)| (i, depth - min_inside + base)));
+        } else {
+            let raise = needed - min_outside;
+            depths.extend(inside.into_iter().map(|(i, depth)| (i, depth - min_inside)));
+            depths.extend(outside.into_iter().map(|(i, depth)| (i, depth.saturating_add(raise))));
+        }
+        self.apply_depths(depths, app);
         Ok(())
     }
     
@@ -452,38 +1118,293 @@ impl<C> Scene<C> {
     /// Processes events first, then updates visual representation for each widget.
     /// If a widget's content changes, its parents are also updated to reflect the change.
     /// This ensures the entire scene graph remains consistent and up-to-date.
-    pub fn update_all_widgets(&mut self, app_main: &mut App<C>, data: &mut C) -> Result<(), WidgetErr> {
-        for i in 0..self.widgets.len() {  // the if let skips reserved indices
-            if self.widgets.index(i).is_none() {  continue;  }
+    pub fn update_all_widgets(&mut self, app_main: &mut App<C>, data: &mut C) -> Result<(), WidgetError> {
+        self.run_lifecycle_hooks(app_main, data);
+        self.update_focus(app_main);
+        let mut cursor = None;
+        // parents go before their children, so children positioned relative to them see where they are this frame
+        for i in self.get_update_order() {
+            if self.widgets.index(i).is_none() || self.hidden.contains(&i) {  continue;  }
             
             self.widgets.set_mut_widget_ptr(i);
             let mut widget = match self.widgets.event_queuer.take() {
-                None => return Err(WidgetErr::new("Failed to gather the event queuer")),
+                None => return Err(WidgetError::new("Failed to gather the event queuer")),
                 Some(ptr) => ptr,
             };
             //self.widgets.replace(i, Some(widget_safe));  // put the widget back
             
-            widget.update_with_events(data, app_main, self);
+            let (offset, clip) = self.get_parent_transform(i);
+            let clip = self.apply_parent_rect(i, &mut widget, clip, &app_main.renderer.read());
+            widget.set_parent_transform(offset, clip);
+            widget.set_focused(self.focused == Some(i));
+            if widget.consumes_events() {
+                widget.update_with_events(data, app_main, self);
+            }
+            cursor = cursor.or(widget.cursor_position());
             let window = widget.get_window_ref();
-            if widget.update_render(app_main.renderer.write().get_window_reference_mut(window), &*app_main.area.read(), data) && widget.get_parent_index().is_some() {
-                // if the widget changed, update all its children
-                self.update_children(i, &mut *app_main.renderer.write())?;
+            let mut renderer = app_main.renderer.write();
+            let window = renderer.get_window_reference_mut(window);
+            let rect = (window.size, window.position);
+            // animating widgets move every frame, so they're treated as updated until they arrive
+            let updated = widget.update_render(window, &app_main.area.read(), data) ||
+                widget.get_size_and_position().is_some_and(|size_and_position| size_and_position.is_animating());
+            window.set_clip(clip.map(|(size, position)| (position, size)));
+            let moved = rect != (window.size, window.position);
+            drop(renderer);
+            if (updated && widget.get_parent_index().is_some()) || moved {
+                // if the widget changed (or moved, taking any relatively positioned children with it), update all its children
+                self.update_children(i, &mut app_main.renderer.write())?;
             }
+        }
+        
+        // only touching the cursor when a widget requested it (or did last frame), so it can be set directly otherwise
+        if cursor.is_some() || self.showing_cursor {
+            app_main.renderer.write().set_cursor(cursor);
+            self.showing_cursor = cursor.is_some();
+        }
+        
+        // widgets which removed themselves (or others) while updating
+        for index in std::mem::take(&mut self.pending_removals) {
+            if self.widgets.index(index).is_some() {
+                self.remove_widget(index, &mut app_main.renderer.write())?;
+            }
+        }
+        // (including those just removed, and any widgets added while updating)
+        self.run_lifecycle_hooks(app_main, data);
+        
+        self.update_tooltip(app_main, data);
+        if self.hit_test_overlay {
+            self.update_hit_test_overlay(app_main);
         } Ok(())
     }
+    
+    /// Queues the widget at the given index (along with its children) to be removed once the scene
+    /// finishes updating (see `update_all_widgets`). A widget can't be removed while it's being updated,
+    /// so this is how a widget removes itself (i.e. a dialog closing once a button is chosen).
+    pub fn queue_removal(&mut self, index: usize) {
+        if !self.pending_removals.contains(&index) {
+            self.pending_removals.push(index);
+        }
+    }
+    
+    /// Makes the widget at the given index modal, or clears the modal widget with `None`. While a
+    /// visible modal widget exists, only it and its descendants can be clicked (every other widget is
+    /// treated as blocked; see `is_click_blocked_all`) or focused, so widgets beneath it don't react
+    /// to the mouse or keyboard. If the focus is outside of the modal widget, it moves to the first
+    /// focusable widget within it. Removing the modal widget clears it.
+    pub fn set_modal(&mut self, index: Option<usize>) {
+        self.modal = index;
+        let Some(index) = index else {  return;  };
+        if self.focused.is_some_and(|focused| focused == index || self.is_ancestor(index, focused)) {  return;  }
+        self.focused = self.get_subtree_indexes(index).into_iter().find(|index| {
+            !self.hidden.contains(index) && self.widgets.index(*index).is_some_and(|widget| widget.focusable())
+        });
+    }
+    
+    /// Opens a context menu listing the items at the given position (usually where the mouse was right
+    /// clicked; see `ContextMenu`), closing the one already open, if any. The menu is sized to its longest
+    /// item, and flips to the left (and/or up) when it would overflow the terminal. Choosing an item runs
+    /// its action, and the menu closes itself once an item is chosen, on Escape, or on a click outside of it.
+    /// Returns the menu's index, or an error if there are no items.
+    pub fn open_context_menu(&mut self, position: (u16, u16), items: Vec<crate::widget_impls::ContextMenuItem<C>>, app: &mut App<C>) -> Result<usize, WidgetError> where C: 'static {
+        self.open_shared_context_menu(position, std::rc::Rc::new(std::cell::RefCell::new(items)), app)
+    }
+    
+    /// Opens a context menu whose items are shared with whatever opened it (see `open_context_menu`).
+    pub(crate) fn open_shared_context_menu(&mut self, position: (u16, u16), items: crate::widget_impls::SharedContextMenuItems<C>, app: &mut App<C>) -> Result<usize, WidgetError> where C: 'static {
+        let (menu, window) = crate::widget_impls::ContextMenu::new(position, items, &app.area.read())?;
+        self.close_context_menu(&mut app.renderer.write());
+        let index = self.add_widget(Box::new(menu), window, &mut app.renderer.write())?;
+        self.context_menu = Some(index);
+        Ok(index)
+    }
+    
+    /// Returns the index of the open context menu, if any (see `open_context_menu`).
+    pub fn get_context_menu(&self) -> Option<usize> {
+        self.context_menu
+    }
+    
+    /// Closes the open context menu (if any) without running any of its items. It's hidden straight away,
+    /// and removed once the scene finishes updating (see `queue_removal`).
+    pub fn close_context_menu(&mut self, app: &mut term_render::App) {
+        let Some(index) = self.context_menu.take() else {  return;  };
+        if self.widgets.index(index).is_none() {  return;  }
+        let _ = self.set_widget_visible(index, false, app);
+        self.queue_removal(index);
+    }
+    
+    /// Stops tracking the context menu at the given index as the open one (it's closing itself).
+    pub(crate) fn forget_context_menu(&mut self, index: usize) {
+        if self.context_menu == Some(index) {  self.context_menu = None;  }
+    }
+    
+    /// Returns the index of the modal widget, if any (see `set_modal`).
+    pub fn get_modal(&self) -> Option<usize> {
+        self.modal
+    }
+    
+    /// Returns the modal widget if it's still in the scene and visible.
+    fn get_active_modal(&self) -> Option<usize> {
+        self.modal.filter(|index| self.widgets.index(*index).is_some() && !self.hidden.contains(index))
+    }
+    
+    /// Checks whether the widget at the given index can be interacted with, which is always true
+    /// unless there's a modal widget it isn't a part of.
+    fn is_within_modal(&self, index: usize) -> bool {
+        self.get_active_modal().is_none_or(|modal| modal == index || self.is_ancestor(modal, index))
+    }
 
+    /// Shows or hides the widget at the given index along with all of its children (recursively).
+    /// Every window in the subtree is hidden/shown, and hidden widgets are skipped while updating the
+    /// scene, so they don't process events, can't be clicked or focused, and don't block clicks for
+    /// other widgets. Showing a subtree again forces it to fully re-render.
+    /// Returns an error if the index (or one of its children) is invalid.
+    pub fn set_widget_visible(&mut self, index: usize, visible: bool, app: &mut term_render::App) -> Result<(), WidgetError> {
+        let widget = self.widgets.get(index)?;
+        let window = widget.get_window_ref();
+        let children = widget.get_children_indexes();
+        if app.contains_window(window.clone()) {
+            let window = app.get_window_reference_mut(window);
+            if visible {
+                window.show();
+                window.update_all();
+            } else {
+                window.hide();
+            }
+        }
+        if visible {
+            self.hidden.remove(&index);
+        } else {
+            self.hidden.insert(index);
+            if self.focused == Some(index) {  self.focused = None;  }
+        }
+        for child in children {
+            self.set_widget_visible(child, visible, app)?;
+        } Ok(())
+    }
+    
+    /// Returns whether the widget at the given index is visible (see `set_widget_visible`).
+    /// Invalid indexes are treated as not visible.
+    pub fn is_widget_visible(&self, index: usize) -> bool {
+        self.widgets.index(index).is_some() && !self.hidden.contains(&index)
+    }
+    
+    /// Returns the index of the widget with keyboard focus, if any.
+    pub fn focused_widget(&self) -> Option<usize> {
+        self.focused
+    }
+    
+    /// Returns whether the widget with keyboard focus has a selection (see `Widget::has_selection`).
+    pub fn focused_has_selection(&self) -> bool {
+        self.focused.and_then(|index| self.widgets.index(index)).is_some_and(|widget| widget.has_selection())
+    }
+    
+    /// Gives the widget at the given index keyboard focus.
+    /// Returns an error if the index is out of bounds or the widget isn't focusable.
+    pub fn set_focus(&mut self, index: usize) -> Result<(), WidgetError> {
+        let widget = self.widgets.get(index)?;
+        if !widget.focusable() {  return Err(WidgetError::NotFocusable(index));  }
+        self.focused = Some(index);
+        Ok(())
+    }
+    
+    /// Removes keyboard focus from whichever widget has it.
+    pub fn clear_focus(&mut self) {
+        self.focused = None;
+    }
+    
+    /// Moves the focus to the next focusable (and visible) widget in the scene, wrapping around
+    /// to the first one. Returns the newly focused widget, if there is one.
+    pub fn focus_next(&mut self, app: &term_render::App) -> Option<usize> {
+        self.cycle_focus(app, false)
+    }
+    
+    /// Moves the focus to the previous focusable (and visible) widget in the scene, wrapping around
+    /// to the last one. Returns the newly focused widget, if there is one.
+    pub fn focus_previous(&mut self, app: &term_render::App) -> Option<usize> {
+        self.cycle_focus(app, true)
+    }
+    
+    /// Sets the order Tab moves the focus through widgets in. The listed widgets are visited first, in
+    /// the given order, followed by any other focusable widgets in the order they're stored in the scene.
+    /// Removed widgets drop out of the order. By default, the order is empty.
+    /// # Example
+    /// ```
+    /// use term_render::widget_impls::{TypingWidgetBuilder, WidgetBuilder};
+    /// use term_render::widget::Scene;
+    /// let mut app = term_render::App::<()>::new_headless(20, 9);
+    /// let mut scene = Scene::new();
+    /// let fields: Vec<usize> = (0..3).map(|row| {
+    ///     TypingWidgetBuilder::<()>::builder(format!("field {}", row))
+    ///         .with_position((0, row * 3))
+    ///         .with_size((20, 3))
+    ///         .add_to_scene(&mut app, &mut scene)
+    ///         .unwrap()
+    /// }).collect();
+    /// scene.update_all_widgets(&mut app, &mut ()).unwrap();
+    ///
+    /// // the last field comes first, then the rest in the order they were added
+    /// scene.set_tab_order(vec![fields[2]]);
+    /// let renderer = app.renderer.read();
+    /// let order: Vec<Option<usize>> = (0..4).map(|_| scene.focus_next(&renderer)).collect();
+    /// assert_eq!(order, vec![Some(fields[2]), Some(fields[0]), Some(fields[1]), Some(fields[2])]);
+    /// ```
+    pub fn set_tab_order(&mut self, order: Vec<usize>) {
+        self.tab_order = order;
+    }
+
+    /// Gets the widgets Tab visits first, in order (see `set_tab_order`).
+    pub fn get_tab_order(&self) -> &[usize] {
+        &self.tab_order
+    }
+    
+    /// Steps the focus through the focusable widgets in the tab order (see `set_tab_order`), followed
+    /// by the order they're stored in the scene.
+    fn cycle_focus(&mut self, app: &term_render::App, reverse: bool) -> Option<usize> {
+        let mut candidates: Vec<usize> = (0..self.widgets.len()).filter(|index| {
+            self.widgets.index(*index).is_some_and(|widget| {
+                let name = widget.get_window_ref();
+                widget.focusable() && app.contains_window(name.clone()) && !app.get_window_reference(name).hidden
+            }) && !self.hidden.contains(index) && self.is_within_modal(*index)
+        }).collect();
+        candidates.sort_by_key(|index| self.tab_order.iter().position(|ordered| ordered == index).unwrap_or(usize::MAX));
+        if reverse {  candidates.reverse();  }
+        let next = match self.focused.and_then(|focused| candidates.iter().position(|index| *index == focused)) {
+            Some(position) => candidates.get(position + 1).or(candidates.first()),
+            None => candidates.first(),
+        }.copied();
+        self.focused = next;
+        next
+    }
+    
+    /// Moves the focus based on this frame's events: Tab/Shift+Tab cycle through the focusable widgets,
+    /// and a left click focuses the widget which receives it (see `hit_test`), or clears the focus if
+    /// that widget isn't focusable.
+    fn update_focus(&mut self, app_main: &App<C>) {
+        let events = app_main.events.read();
+        let click = events.mouse_event.as_ref().filter(|event| {
+            event.event_type == crate::event_handler::MouseEventType::Left &&
+                event.state == crate::event_handler::MouseState::Press
+        }).map(|event| event.position);
+        let tab = events.contains_key_code(crate::event_handler::KeyCode::Tab);
+        let shifted = events.contains_modifier(crate::event_handler::KeyModifiers::Shift);
+        drop(events);
+        
+        if let Some(position) = click {
+            self.focused = self.hit_test(position, app_main)
+                .filter(|index| self.widgets.index(*index).is_some_and(|widget| widget.focusable()));
+        }
+        if tab {
+            self.cycle_focus(&app_main.renderer.read(), shifted);
+        }
+    }
+    
     /// Recursively updates all child widgets of the widget at the given index.
     /// Ensures visual consistency when parent widgets change.
-    fn update_children(&mut self, index: usize, app: &mut term_render::App) -> Result<(), WidgetErr> {
-        let children = match self.widgets.index(index){
-            Some(w) => w,
-            None => return Err(WidgetErr::new("Invalid widget index - 42")),
-        }.get_children_indexes();
+    fn update_children(&mut self, index: usize, app: &mut term_render::App) -> Result<(), WidgetError> {
+        let children = self.widgets.get(index)?.get_children_indexes();
         for &child_index in &children {
-            let widget = match self.widgets.index_mut(child_index) {
-                Some(w) => w,
-                None => return Err(WidgetErr::new("Invalid widget index - 13")),
-            };
+            let widget = self.widgets.get_mut(child_index)?;
             app.get_window_reference_mut(widget.get_window_ref()).update_all();
             self.update_children(child_index, app)?;
         } Ok(())
@@ -503,25 +1424,24 @@ impl<C> Scene<C> {
     
     /// Updates a specific widget and its rendering.
     /// Also triggers updates to parent widgets to maintain consistency if the window is updated.
-    pub fn update_widget(&mut self, index: usize, app_main: &mut App<C>, area: &term_render::Rect, data: &mut C) -> Result<(), WidgetErr> {
-        if index >= self.widgets.len() || self.widgets.index(index).is_none() {
-            return Err(WidgetErr::new("Index out of bounds"));
-        }
+    pub fn update_widget(&mut self, index: usize, app_main: &mut App<C>, area: &term_render::Rect, data: &mut C) -> Result<(), WidgetError> {
+        self.widgets.get(index)?;
+        if self.hidden.contains(&index) {  return Ok(());  }
         
-        let mut widget = match self.widgets.take(index) {
-            Some(w) => w,
-            None => return Err(WidgetErr::new("Invalid widget index - 6")),
-        };
+        let (offset, clip) = self.get_parent_transform(index);
+        let mut widget = self.widgets.take(index).ok_or(WidgetError::RemovedWidget(index))?;
+        let clip = self.apply_parent_rect(index, &mut *widget, clip, &app_main.renderer.read());
+        widget.set_parent_transform(offset, clip);
+        widget.set_focused(self.focused == Some(index));
         widget.update_with_events(data, app_main, self);
         self.widgets.replace(index, Some(widget));  // put the widget back
-        let widget =match self.widgets.index_mut(index) {
-            Some(w) => w,
-            None => return Err(WidgetErr::new("Invalid widget index - 12")),
-        };
+        let widget = self.widgets.get_mut(index)?;
         let renderer = &mut *app_main.renderer.write();
         let window = renderer.get_window_reference_mut(widget.get_window_ref());
-        if widget.update_render(window, area, data) && widget.get_parent_index().is_some() {
-            self.update_parents(index, &mut *app_main.renderer.write())?;
+        let updated = widget.update_render(window, area, data);
+        window.set_clip(clip.map(|(size, position)| (position, size)));
+        if updated && widget.get_parent_index().is_some() {
+            self.update_parents(index, renderer)?;
         }
         
         Ok(())
@@ -529,13 +1449,14 @@ impl<C> Scene<C> {
     
     /// Updates only the rendering of a specific widget without processing events.
     /// Useful for visual-only changes that don't affect widget state.
-    pub fn update_widget_renderer(&mut self, index: usize, app: &mut term_render::App, area: &term_render::Rect, data: &mut C) -> Result<(), WidgetErr> {
-        let widget = match self.widgets.index_mut(index) {
-            Some(w) => w,
-            None => return Err(WidgetErr::new("Invalid widget index - 7")),
-        };
+    pub fn update_widget_renderer(&mut self, index: usize, app: &mut term_render::App, area: &term_render::Rect, data: &mut C) -> Result<(), WidgetError> {
+        let (offset, clip) = self.get_parent_transform(index);
+        let widget = self.widgets.get_mut(index)?;
+        widget.set_parent_transform(offset, clip);
         let window = app.get_window_reference_mut(widget.get_window_ref());
-        if widget.update_render(window, area, data) && widget.get_parent_index().is_some() {
+        let updated = widget.update_render(window, area, data);
+        window.set_clip(clip.map(|(size, position)| (position, size)));
+        if updated && widget.get_parent_index().is_some() {
             self.update_parents(index, app)?;
         }
         Ok(())
@@ -543,15 +1464,9 @@ impl<C> Scene<C> {
     
     /// Recursively updates all parent widgets of the widget at the given index.
     /// Ensures visual consistency when child widgets change.
-    fn update_parents(&mut self, index: usize, app: &mut term_render::App) -> Result<(), WidgetErr> {
-        if let Some(parent_index) = match self.widgets.index(index){
-            Some(w) => w,
-            None => return Err(WidgetErr::new("Invalid widget index - 8")),
-        }.get_parent_index() {
-            let widget = match self.widgets.index_mut(parent_index) {
-                Some(w) => w,
-                None => return Err(WidgetErr::new("Invalid widget index - 9")),
-            };
+    fn update_parents(&mut self, index: usize, app: &mut term_render::App) -> Result<(), WidgetError> {
+        if let Some(parent_index) = self.widgets.get(index)?.get_parent_index() {
+            let widget = self.widgets.get_mut(parent_index)?;
             app.get_window_reference_mut(widget.get_window_ref()).update_all();
             self.update_parents(parent_index, app)?;
         } Ok(())
@@ -560,41 +1475,307 @@ impl<C> Scene<C> {
     /// Checks if a click at the given position is blocked by any other widgets in the scene.
     /// Returns `Some(true)` if blocked, `Some(false)` if not blocked, or `None` if the index is invalid.
     /// This is useful for determining if a click event should be processed by the widget or ignored due to overlap.
+    ///
+    /// A widget is only blocked by widgets whose windows are drawn over its own: those at a higher depth,
+    /// or (at an equal depth) its descendants and widgets added after it. Hidden widgets and widgets
+    /// whose windows are hidden never block clicks. While there's a modal widget (see `set_modal`), every
+    /// widget outside of it is blocked everywhere. This is the check the built-in widgets use.
     /// This is different from `is_click_blocked` which only checks child widgets.
+    /// # Example
+    /// ```
+    /// use term_render::widget::{Scene, Widget};
+    /// use term_render::widget_impls::{StaticWidgetBuilder, WidgetBuilder};
+    ///
+    /// let mut app = term_render::App::<()>::new_headless(40, 20);
+    /// let mut scene = Scene::new();
+    /// // three overlapping widgets, added from the highest depth to the lowest
+    /// for (name, depth) in [("top", 3), ("middle", 2), ("bottom", 1)] {
+    ///     StaticWidgetBuilder::<()>::builder(String::from(name))
+    ///         .with_position((5, 5))
+    ///         .with_size((10, 5))
+    ///         .with_depth(depth)
+    ///         .with_renderer(Box::new(|_size, _position, _data| None))
+    ///         .add_to_scene(&mut app, &mut scene)
+    ///         .unwrap();
+    /// }
+    /// let (top, middle, bottom) = (
+    ///     scene.get_widget_index(String::from("top")).unwrap(),
+    ///     scene.get_widget_index(String::from("middle")).unwrap(),
+    ///     scene.get_widget_index(String::from("bottom")).unwrap(),
+    /// );
+    ///
+    /// // the highest depth wins, even though it was added first
+    /// assert_eq!(scene.is_click_blocked_all(top, (8, 7), &app), Some(false));
+    /// assert_eq!(scene.is_click_blocked_all(middle, (8, 7), &app), Some(true));
+    /// assert_eq!(scene.hit_test((8, 7), &app), Some(top));
+    ///
+    /// // a hidden window no longer swallows the click
+    /// app.renderer.write().get_window_reference_mut(String::from("top")).hidden = true;
+    /// assert_eq!(scene.is_click_blocked_all(middle, (8, 7), &app), Some(false));
+    /// assert_eq!(scene.is_click_blocked_all(bottom, (8, 7), &app), Some(true));
+    /// assert_eq!(scene.hit_test((8, 7), &app), Some(middle));
+    /// ```
     pub fn is_click_blocked_all(&self, index: usize, position: (u16, u16), app: &App<C>) -> Option<bool> {
-        let base_depth = app.renderer.read().get_window_reference(match self.widgets.index(index) {
-            Some(w) => w,
-            None => return None,
-        }.get_window_ref()).depth;
-        for i in 0..self.widgets.len() {
-            if i == index {  continue;  }
-            if let Some(widget) = self.widgets.index(i) {
-                if widget.is_collided(position) &&
-                    base_depth < app.renderer.read().get_window_reference(widget.get_window_ref()).depth
-                {
-                    return Some(true)
-                }
-            }
-        } Some(false)  // None means bad index, false means it's not blocked
+        self.is_blocked_by(index, position, &app.renderer.read(), 0..self.widgets.len())
     }
     
-    /// Checks if a click at the given position is blocked by any child widgets of the widget at the given index.
+    /// Checks if a click at the given position is blocked by any child widgets of the widget at the given index
+    /// (recursively). Children follow the same rules as `is_click_blocked_all`, so a child at a lower depth
+    /// (drawn beneath the widget) or with a hidden window doesn't block the click.
     /// Returns `Some(true)` if blocked, `Some(false)` if not blocked, or `None` if the index is invalid.
     /// This is useful for determining if a click event should be processed by the widget or ignored due to overlap.
-    pub fn is_click_blocked(&self, index: usize, position: (u16, u16)) -> Option<bool> {
-        let children = &self.widgets.index(index)?.get_children_indexes();
-        for child in children {
-            let widget = self.widgets.index(*child)?;
-            if widget.is_collided(position) {
-                return Some(true);
+    pub fn is_click_blocked(&self, index: usize, position: (u16, u16), app: &App<C>) -> Option<bool> {
+        self.widgets.index(index)?;
+        let descendants = self.get_subtree_indexes(index).into_iter().skip(1);
+        self.is_blocked_by(index, position, &app.renderer.read(), descendants)
+    }
+    
+    /// The shared check behind `is_click_blocked` and `is_click_blocked_all`: whether any of the candidate
+    /// widgets covers the given position while being drawn over the widget at the given index.
+    /// Returns `None` if the index is invalid (or its window doesn't exist).
+    fn is_blocked_by(&self, index: usize, position: (u16, u16), renderer: &term_render::App, candidates: impl IntoIterator<Item = usize>) -> Option<bool> {
+        let name = self.widgets.index(index)?.get_window_ref();
+        if !renderer.contains_window(name.clone()) {  return None;  }
+        // everything outside of a modal widget is covered by it
+        if !self.is_within_modal(index) {  return Some(true);  }
+        let base_depth = renderer.get_window_reference(name).get_stacking();
+        for i in candidates {
+            if i == index || self.hidden.contains(&i) {  continue;  }
+            let Some(widget) = self.widgets.index(i) else {  continue;  };
+            let name = widget.get_window_ref();
+            if !renderer.contains_window(name.clone()) {  continue;  }
+            let window = renderer.get_window_reference(name);
+            if window.hidden || !widget.is_collided(position) {  continue;  }
+            let blocks = match window.get_stacking().cmp(&base_depth) {
+                std::cmp::Ordering::Greater => true,
+                std::cmp::Ordering::Less => false,
+                // equal depths are drawn in the order they were added, so it comes down to the scene graph
+                std::cmp::Ordering::Equal => self.is_ancestor(index, i) || (i > index && !self.is_ancestor(i, index)),
+            };
+            if blocks {  return Some(true);  }
+        } Some(false)  // None means bad index, false means it's not blocked
+    }
+    
+    /// Checks whether the widget at `ancestor` is a (possibly indirect) parent of the widget at `index`.
+    fn is_ancestor(&self, ancestor: usize, index: usize) -> bool {
+        let mut parent_index = self.widgets.index(index).and_then(|widget| widget.get_parent_index());
+        while let Some(parent) = parent_index {
+            if parent == ancestor {  return true;  }
+            parent_index = self.widgets.index(parent).and_then(|widget| widget.get_parent_index());
+        } false
+    }
+    
+    /// Gathers the offset and clip region (size, position) a widget inherits from its ancestors.
+    /// Offsets are summed, and clip regions are intersected (an empty intersection has a size of zero).
+    pub fn get_parent_transform(&self, index: usize) -> ((i16, i16), Option<Region>) {
+        let mut offset = (0i16, 0i16);
+        let mut clip: Option<Region> = None;
+        let mut parent_index = self.widgets.index(index).and_then(|widget| widget.get_parent_index());
+        while let Some(parent) = parent_index.and_then(|parent| self.widgets.index(parent)) {
+            let child_offset = parent.get_child_offset();
+            offset = (offset.0.saturating_add(child_offset.0), offset.1.saturating_add(child_offset.1));
+            if let Some(parent_clip) = parent.get_child_clip() {
+                clip = Some(match clip {
+                    None => parent_clip,
+                    Some(clip) => Self::intersect_regions(clip, parent_clip),
+                });
+            }
+            parent_index = parent.get_parent_index();
+        } (offset, clip)
+    }
+    
+    /// Gets the order widgets are updated in: by index, except that a widget's parent always comes before it
+    /// (i.e. after reparenting a widget under one added later), so the scene graph is walked top-down.
+    fn get_update_order(&self) -> Vec <usize> {
+        let mut order = Vec::with_capacity(self.widgets.len());
+        let mut visited = vec![false; self.widgets.len()];
+        for index in 0..self.widgets.len() {
+            // gathering the ancestors which haven't been visited yet, then adding them from the root down
+            let mut chain = vec![];
+            let mut next = Some(index);
+            while let Some(current) = next.filter(|current| *current < visited.len() && !visited[*current]) {
+                visited[current] = true;
+                chain.push(current);
+                next = self.widgets.index(current).and_then(|widget| widget.get_parent_index());
             }
-            // recursively check children
-            if let Some(blocked) = self.is_click_blocked(*child, position) {
-                if blocked {
-                    return Some(true);
+            order.extend(chain.into_iter().rev());
+        } order
+    }
+    
+    /// Hands a widget positioned relative to its parent (see `PositionMode::RelativeToParent`) the region
+    /// (size, position) of its parent's window, and narrows the clip to that region if the widget is clipped
+    /// to its parent. Returns the clip the widget ends up with.
+    fn apply_parent_rect(&self, index: usize, widget: &mut dyn Widget<C>, clip: Option<Region>, app: &term_render::App) -> Option<Region> {
+        let Some(size_and_position) = widget.get_size_and_position_mut() else {  return clip;  };
+        let parent_rect = self.widgets.index(index)
+            .and_then(|widget| widget.get_parent_index())
+            .and_then(|parent| self.widgets.index(parent))
+            .map(|parent| parent.get_window_ref())
+            .filter(|window| app.contains_window(window.clone()))
+            .map(|window| {
+                let window = app.get_window_reference(window);
+                (window.size, window.position)
+            });
+        size_and_position.parent_rect = parent_rect;
+        match (size_and_position.clip_to_parent, parent_rect) {
+            (true, Some(rect)) => Some(clip.map_or(rect, |clip| Self::intersect_regions(clip, rect))),
+            _ => clip,
+        }
+    }
+    
+    /// Intersects two regions given as (size, position).
+    fn intersect_regions(a: Region, b: Region) -> Region {
+        let x = a.1.0.max(b.1.0);
+        let y = a.1.1.max(b.1.1);
+        let right = a.1.0.saturating_add(a.0.0).min(b.1.0.saturating_add(b.0.0));
+        let bottom = a.1.1.saturating_add(a.0.1).min(b.1.1.saturating_add(b.0.1));
+        ((right.saturating_sub(x), bottom.saturating_sub(y)), (x, y))
+    }
+    
+    /// Scrolls every ancestor of the widget at the given index (i.e. `ScrollViewWidget`s) so the
+    /// widget's window is visible. Returns true if any of the offsets changed.
+    /// Returns an error if the index is out of bounds.
+    pub fn scroll_into_view(&mut self, index: usize, app: &mut term_render::App) -> Result<bool, WidgetError> {
+        let widget = self.widgets.get(index)?;
+        let name = widget.get_window_ref();
+        if !app.contains_window(name.clone()) {  return Err(crate::render::RenderError::WindowNotFound(name).into());  }
+        let window = app.get_window_reference(name);
+        let mut rect = (window.size, window.position);
+        
+        let mut changed = false;
+        let mut parent_index = widget.get_parent_index();
+        while let Some(parent) = parent_index {
+            let parent_widget = self.widgets.get_mut(parent)?;
+            let old_offset = parent_widget.get_child_offset();
+            if parent_widget.scroll_child_into_view(rect) {
+                changed = true;
+                // the rect moves along with the scroll for the next ancestor up
+                let new_offset = parent_widget.get_child_offset();
+                let x = (rect.1.0 as i32 + new_offset.0 as i32 - old_offset.0 as i32).clamp(0, u16::MAX as i32) as u16;
+                let y = (rect.1.1 as i32 + new_offset.1 as i32 - old_offset.1 as i32).clamp(0, u16::MAX as i32) as u16;
+                rect.1 = (x, y);
+            }
+            parent_index = parent_widget.get_parent_index();
+        } Ok(changed)
+    }
+    
+    /// Finds the widget which would receive a click at the given position.
+    /// This uses the same checks the built-in widgets use (`is_collided` and `is_click_blocked_all`),
+    /// so the result reflects what actually happens when clicking. Widgets with hidden windows are skipped.
+    /// If multiple widgets would somehow accept the click, the deepest one is returned.
+    /// Returns `None` if no widget would receive the click.
+    pub fn hit_test(&self, position: (u16, u16), app: &App<C>) -> Option<usize> {
+        let mut hit: Option<(usize, (term_render::Layer, u16))> = None;
+        for i in 0..self.widgets.len() {
+            let Some(widget) = self.widgets.index(i) else {  continue;  };
+            if self.hidden.contains(&i) {  continue;  }
+            if !widget.is_collided(position) || self.is_click_blocked_all(i, position, app).unwrap_or(false) {  continue;  }
+            let name = widget.get_window_ref();
+            let renderer = app.renderer.read();
+            if !renderer.contains_window(name.clone()) {  continue;  }
+            let window = renderer.get_window_reference(name);
+            if window.hidden {  continue;  }
+            let depth = window.get_stacking();
+            if hit.is_none_or(|(_, hit_depth)| depth >= hit_depth) {
+                hit = Some((i, depth));
+            }
+        } hit.map(|(index, _)| index)
+    }
+    
+    /// Enables or disables the hit-test debugging overlay.
+    /// While enabled, every widget's bounds are tinted on top of the rendered windows:
+    /// green cells would deliver a click to a widget, and red hatched cells are where a widget's
+    /// bounds are blocked by a widget drawn over it. A readout in the bottom left corner
+    /// shows the widget under the mouse along with its rect and depth.
+    /// This is purely for debugging why clicks do or don't register.
+    pub fn set_hit_test_overlay(&mut self, enabled: bool, app: &mut term_render::App) {
+        self.hit_test_overlay = enabled;
+        if !enabled {  app.clear_overlay();  }
+    }
+    
+    /// Toggles the hit-test debugging overlay (see `set_hit_test_overlay`).
+    /// This is convenient to bind to a key while developing.
+    pub fn toggle_hit_test_overlay(&mut self, app: &mut term_render::App) {
+        self.set_hit_test_overlay(!self.hit_test_overlay, app);
+    }
+    
+    /// Returns whether the hit-test debugging overlay is enabled.
+    pub fn is_hit_test_overlay_enabled(&self) -> bool {
+        self.hit_test_overlay
+    }
+    
+    /// Rebuilds the hit-test overlay from the current widgets, windows, and mouse position.
+    fn update_hit_test_overlay(&self, app_main: &App<C>) {
+        // none of the locks can be held while checking for blocking (it reads the renderer itself)
+        let frame = app_main.renderer.read().screenshot();
+        let mut rects = vec![];
+        for i in 0..self.widgets.len() {
+            let Some(widget) = self.widgets.index(i) else {  continue;  };
+            let renderer = app_main.renderer.read();
+            let name = widget.get_window_ref();
+            if !renderer.contains_window(name.clone()) {  continue;  }
+            let window = renderer.get_window_reference(name);
+            if window.hidden {  continue;  }
+            rects.push((i, window.position, window.size));
+        }
+        
+        // classifying each cell covered by a widget; true means at least one widget there is blocked
+        let mut cells: std::collections::HashMap<(u16, u16), bool> = std::collections::HashMap::new();
+        for (index, position, size) in rects {
+            for y in position.1..position.1.saturating_add(size.1) {
+                for x in position.0..position.0.saturating_add(size.0) {
+                    let widget = match self.widgets.index(index) {
+                        Some(w) => w,
+                        None => continue,
+                    };
+                    if !widget.is_collided((x, y)) {  continue;  }
+                    let blocked = self.is_click_blocked_all(index, (x, y), app_main).unwrap_or(false);
+                    *cells.entry((x, y)).or_insert(false) |= blocked;
                 }
             }
-        } Some(false)  // None means bad index, false means it's not blocked
+        }
+        
+        let mut overlay = vec![];
+        for ((x, y), blocked) in cells {
+            let chr = frame.get(x, y).map(|cell| cell.chr).unwrap_or(' ');
+            let colored = if blocked {
+                let chr = if chr == ' ' && (x + y) % 2 == 0 {  '╱'  } else {  chr  };
+                term_render::Colored::get_from_color_types_str(&chr.to_string(), vec![term_render::ColorType::BrightWhite, term_render::ColorType::OnRed])
+            } else {
+                term_render::Colored::get_from_color_types_str(&chr.to_string(), vec![term_render::ColorType::Black, term_render::ColorType::OnGreen])
+            };
+            overlay.push(((x, y), term_render::Span::from_tokens(vec![colored]).join().0));
+        }
+        
+        // the readout for whatever is under the mouse
+        let mouse = app_main.events.read().mouse_event.as_ref().map(|event| event.position);
+        let readout = match mouse {
+            None => String::from(" hit-test: no mouse position yet"),
+            Some(position) => match self.hit_test(position, app_main) {
+                None => format!(" hit-test ({}, {}): no widget", position.0, position.1),
+                Some(index) => {
+                    let name = self.widgets.index(index).map(|widget| widget.get_window_ref()).unwrap_or_default();
+                    let renderer = app_main.renderer.read();
+                    let window = renderer.get_window_reference(name.clone());
+                    format!(
+                        " hit-test ({}, {}): '{}' at ({}, {}) size {}x{} depth {}",
+                        position.0, position.1, name, window.position.0, window.position.1,
+                        window.size.0, window.size.1, window.depth
+                    )
+                },
+            },
+        };
+        let area = app_main.area.read().clone();
+        let legend = " green: clickable  red: blocked";
+        let width = (area.width as usize).min(readout.chars().count().max(legend.len()) + 1);
+        for (row, text) in [legend.to_string(), readout].iter().enumerate() {
+            // padding to a fixed width so shorter readouts fully cover the old ones
+            let text: String = format!("{:<width$}", text, width = width).chars().take(width).collect();
+            let colored = term_render::Colored::get_from_color_types_str(&text, vec![term_render::ColorType::BrightWhite, term_render::ColorType::OnBlue]);
+            let y = area.height.saturating_sub(2 - row as u16);
+            overlay.push(((0, y), term_render::Span::from_tokens(vec![colored]).join().0));
+        }
+        overlay.sort_by_key(|(position, _)| (position.1, position.0));
+        app_main.renderer.write().set_overlay(overlay);
     }
 }
-
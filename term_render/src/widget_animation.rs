@@ -0,0 +1,113 @@
+/// The curve an animation's progress follows over its duration (see `SizeAndPosition::animate_to`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// Moves at a constant speed.
+    Linear,
+    /// Starts slowly and speeds up.
+    EaseIn,
+    /// Starts quickly and slows down as it arrives (the default).
+    #[default]
+    EaseOut,
+    /// Starts and ends slowly, moving fastest halfway through.
+    EaseInOut,
+    /// Slows down as it arrives, overshooting the target slightly before settling back onto it.
+    EaseOutBack,
+}
+
+impl Easing {
+    /// Maps the linear progress (in the range [0, 1]) to the eased progress.
+    /// The result starts at 0 and ends at 1, but `EaseOutBack` goes slightly past 1 on the way.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::Easing;
+    /// assert_eq!(Easing::Linear.apply(0.25), 0.25);
+    /// assert!(Easing::EaseOut.apply(0.25) > 0.25);
+    /// assert!(Easing::EaseIn.apply(0.25) < 0.25);
+    /// assert!((0..=10).any(|step| Easing::EaseOutBack.apply(step as f32 / 10.0) > 1.0));
+    /// for easing in [Easing::Linear, Easing::EaseIn, Easing::EaseOut, Easing::EaseInOut, Easing::EaseOutBack] {
+    ///     assert!(easing.apply(0.0).abs() < 1e-6 && (easing.apply(1.0) - 1.0).abs() < 1e-6);
+    /// }
+    /// ```
+    pub fn apply(&self, progress: f32) -> f32 {
+        let t = progress.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => match t < 0.5 {
+                true => 4.0 * t * t * t,
+                false => 1.0 - (2.0 - 2.0 * t).powi(3) / 2.0,
+            },
+            Easing::EaseOutBack => {
+                const OVERSHOOT: f32 = 1.70158;
+                1.0 + (OVERSHOOT + 1.0) * (t - 1.0).powi(3) + OVERSHOOT * (t - 1.0).powi(2)
+            },
+        }
+    }
+}
+
+/// An in-flight transition of a `SizeAndPosition` (see `SizeAndPosition::animate_to`).
+/// The start is stored relative to the target, so the target can keep changing (i.e. through the
+/// builder) while the animation still arrives at wherever it ends up.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Animation {
+    /// The start minus the target for each of the offsets and percentages.
+    size_offset: (f32, f32),
+    position_offset: (f32, f32),
+    size_area_percent: (f32, f32),
+    position_area_percent: (f32, f32),
+    duration: std::time::Duration,
+    easing: Easing,
+    /// When the animation started; it starts the first time the size and position are calculated.
+    start: Option<std::time::Instant>,
+}
+
+/// The offsets and percentages which are interpolated: (size offset, position offset, size percent, position percent).
+pub(crate) type Geometry = ((f32, f32), (f32, f32), (f32, f32), (f32, f32));
+
+impl Animation {
+    /// Creates an animation from the given start to the given target geometry.
+    pub(crate) fn new(start: Geometry, target: Geometry, duration: std::time::Duration, easing: Easing) -> Self {
+        let difference = |a: (f32, f32), b: (f32, f32)| (a.0 - b.0, a.1 - b.1);
+        Animation {
+            size_offset: difference(start.0, target.0),
+            position_offset: difference(start.1, target.1),
+            size_area_percent: difference(start.2, target.2),
+            position_area_percent: difference(start.3, target.3),
+            duration,
+            easing,
+            start: None,
+        }
+    }
+
+    /// Starts the clock if it hasn't been started yet.
+    pub(crate) fn start(&mut self) {
+        self.start.get_or_insert_with(std::time::Instant::now);
+    }
+
+    /// Returns the linear progress in the range [0, 1]; animations which haven't started are at 0.
+    /// With reduced motion on (see `render::set_reduced_motion`), every animation is already finished.
+    pub fn get_progress(&self) -> f32 {
+        if crate::render::is_reduced_motion() {  return 1.0;  }
+        let Some(start) = self.start else {  return 0.0;  };
+        if self.duration.is_zero() {  return 1.0;  }
+        (start.elapsed().as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+    }
+
+    /// Returns whether the animation has reached its target.
+    pub fn is_finished(&self) -> bool {
+        self.get_progress() >= 1.0
+    }
+
+    /// Returns the geometry at the current point of the animation, given the target geometry.
+    pub(crate) fn interpolate(&self, target: Geometry) -> Geometry {
+        let remaining = 1.0 - self.easing.apply(self.get_progress());
+        let lerp = |target: (f32, f32), difference: (f32, f32)| (target.0 + difference.0 * remaining, target.1 + difference.1 * remaining);
+        (
+            lerp(target.0, self.size_offset),
+            lerp(target.1, self.position_offset),
+            lerp(target.2, self.size_area_percent),
+            lerp(target.3, self.position_area_percent),
+        )
+    }
+}
@@ -3,13 +3,16 @@
 use crate::widget_impls::*;
 use crate::widget::*;
 
-type RenderFunction<C> = Box<dyn Fn((u16, u16), (u16, u16), &ButtonState, &mut C) -> Option<Vec<crate::render::Span>>>;
+type RenderFunction<C> = Box<dyn Fn((u16, u16), (u16, u16), &ButtonState, bool, &mut C) -> Option<Vec<crate::render::Span>>>;
+/// The closure called as a button updates, getting its interaction state and whether it's toggled on.
+type UpdateHandler<C> = Box<dyn Fn(&mut ButtonWidget<C>, &mut C, &mut crate::App<C>, &mut Scene<C>, &ButtonState, bool)>;
 
 /// Represents the various interaction states of a button widget.
 /// `Pressed`, `Held`, and `Released` include mouse event types
 /// to specify which mouse button triggered the state change.
 /// This should allow for more complex interactions,
 /// such as right-click context menus or middle-click actions.
+/// A disabled button (see `ButtonWidget::set_enabled`) is always `Disabled`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ButtonState {
     Normal,
@@ -17,6 +20,7 @@ pub enum ButtonState {
     Pressed(crate::event_handler::MouseEventType),
     Held(crate::event_handler::MouseEventType),
     Released(crate::event_handler::MouseEventType),
+    Disabled,
 }
 
 /// Builder for creating ButtonWidget instances with a fluent interface.
@@ -33,6 +37,16 @@ pub struct ButtonWidgetBuilder<C> {
     border: bool,
     /// The title of the widget, if any.
     title: Option<String>,
+    /// The theme slot coloring the border and title, if any.
+    theme_slot: Option<String>,
+    /// The glyphs the border is drawn with.
+    border_style: crate::render::BorderStyle,
+    /// The blank space between the border (or edge) and the content.
+    padding: crate::render::Padding,
+    /// Where the title sits along the top border.
+    title_alignment: crate::render::Alignment,
+    /// The title drawn in the bottom border and its alignment, if any.
+    bottom_title: Option<(String, crate::render::Alignment)>,
     /// The size and position configuration for the widget.
     pub size_and_position: SizeAndPosition,
     /// The custom render function for the widget, if any.
@@ -40,37 +54,74 @@ pub struct ButtonWidgetBuilder<C> {
 
     /// Optional update handler closure for the button widget.
     /// This closure is called during event updates and receives references to the widget,
-    /// application data, the app instance, the scene, and the current button state.
+    /// application data, the app instance, the scene, the current button state, and whether it's toggled on.
     /// By default, there is no update handler, meaning the widget won't respond to events.
-    update_handler: Option<Box<dyn Fn(&mut ButtonWidget<C>, &mut C, &mut crate::App<C>, &mut Scene<C>, &ButtonState)>>,
+    update_handler: Option<UpdateHandler<C>>,
     /// The index of the parent widget in the scene graph, if any.
     parent: Option<usize>,
+    /// Whether Return/Space press the button while it's hovered (not just while it's focused).
+    keyboard_activation: bool,
+    /// The items of the context menu right clicking the button opens, if any.
+    context_menu: Option<SharedContextMenuItems<C>>,
+    /// Whether left clicking the button flips it on and off.
+    toggle: bool,
+    /// Whether the button responds to the mouse and keyboard.
+    enabled: bool,
     
+    /// The keywords the widget's window is tagged with.
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+    /// The colors and modifiers the whole window is painted with (i.e. the border's color).
+    colors: Vec<crate::render::ColorType>,
+    /// Whether the window starts hidden.
+    hidden: bool,
+    /// The layer the window is drawn in.
+    layer: crate::render::Layer,
+
+    /// The closure run once the widget is added to the scene, if any (see `Widget::on_mount`).
+    on_mount: Option<LifecycleHook<C>>,
+    /// The closure run once the widget is removed from the scene, if any (see `Widget::on_unmount`).
+    on_unmount: Option<LifecycleHook<C>>,
+
     __phantom: std::marker::PhantomData<C>,
 }
 
 /// Implementations for the methods in `WidgetBuilder`.
 impl<C: 'static> WidgetBuilder<C> for ButtonWidgetBuilder<C> {
     /// Constructs a `ButtonWidget`, an implementor of `Widget`, given the parameters.
-    /// Validates that size and position are non-zero before creating the widget.
+    /// Validates that the size is non-zero before creating the widget.
     /// The method takes in a reference to the terminal's current area/size.
     /// # Example:
     /// ```
     /// use term_render::widget_impls::{ButtonWidgetBuilder, WidgetBuilder};
     /// use term_render::render::Rect;
+    /// # struct AppData;
     /// let (widget, window) = ButtonWidgetBuilder::<AppData>::builder(String::new())
-    ///     .build(&Rect::default())  // replace &Rect with the actual terminal size (such as `&app.area.read()`)
+    ///     .with_size((20, 10))
+    ///     .build(&Rect { width: 80, height: 24 })  // replace &Rect with the actual terminal size (such as `&app.area.read()`)
     ///     .expect("Invalid widget position or size.");
     /// ```
     fn build(mut self, display_area: &crate::render::Rect) -> Result<(Box<dyn Widget<C>>, crate::render::Window), WidgetBuilderError> {
-        let (position, size) = self.size_and_position.get_size_and_position(display_area);
-        if size.0 == 0 || size.1 == 0 || position.0 == 0 || position.1 == 0 {
-            return Err(WidgetBuilderError { details: String::from("Position and/or size cannot be zero when building a new widget or window.") })
+        let (size, position) = self.size_and_position.get_size_and_position(display_area);
+        if size.0 == 0 || size.1 == 0 {
+            return Err(WidgetBuilderError::InvalidGeometry { size, position })
         }
         let depth = self.depth.as_ref().unwrap_or(&0u16);
         let mut window = crate::render::Window::new(position, *depth, size);
         if self.border {  window.bordered();  }
+        window.set_theme_slot(self.theme_slot.clone());
+        window.set_border_style(self.border_style);
+        window.set_padding(self.padding);
         if let Some(title) = &self.title {  window.titled(title.clone());  }
+        window.colorizes(std::mem::take(&mut self.colors));
+        if self.hidden {  window.hide();  }
+        window.set_layer(self.layer);
+        window.set_title_alignment(self.title_alignment);
+        if let Some((title, alignment)) = &self.bottom_title {
+            window.bottom_titled(crate::render::Span::from_tokens(vec![crate::render::Colored::new(title.clone())]));
+            window.set_bottom_title_alignment(*alignment);
+        }
         Ok((Box::new(ButtonWidget::<C> {
             children: vec![],
             name: self.name,
@@ -79,6 +130,18 @@ impl<C: 'static> WidgetBuilder<C> for ButtonWidgetBuilder<C> {
             render_function: self.render_function,
             update_handler: self.update_handler,
             button_state: std::rc::Rc::new(ButtonState::Normal),
+            click_count: 0,
+            focused: false,
+            keyboard_press: false,
+            keyboard_activation: self.keyboard_activation,
+            context_menu: self.context_menu,
+            toggle: self.toggle,
+            toggled: false,
+            enabled: self.enabled,
+            keywords: self.keywords,
+            tooltip: self.tooltip,
+            on_mount: self.on_mount,
+            on_unmount: self.on_unmount,
             __phantom: std::marker::PhantomData,
         }), window))
     }
@@ -137,20 +200,22 @@ impl<C: 'static> WidgetBuilder<C> for ButtonWidgetBuilder<C> {
     /// Sets the rendering closure that generates content for the widget.
     /// The closure receives size and position parameters and returns an optional vector of type `Span`.
     /// By default, there is no renderer, leaving the widget empty (apart from stylization like a border or title).
-    /// The closure is a boxed closure that takes in `(size: (u16, u16), position: (u16, u16))`. This closure
+    /// The closure is a boxed closure that takes in `(size: (u16, u16), position: (u16, u16), state: &ButtonState,
+    /// toggled: bool, data: &mut C)` (see `ButtonWidgetBuilder::with_toggle` for `toggled`). This closure
     /// can capture local context to allow for easier dynamic variations between widgets with minimal boilerplate.
     /// # Example:
     /// ```
     /// use term_render::widget_impls::{ButtonWidgetBuilder, WidgetBuilder};
     /// use term_render::render::Rect;
+    /// # struct AppData;
     ///
     /// // the closure can capture local variables to reduce boilerplate
-    /// let closure = Box::new(move |size, position, button_state| {
-    ///     None  // this will leave the widget un-updated
-    /// });
     /// let (widget, window) = ButtonWidgetBuilder::<AppData>::builder(String::new())
-    ///     .with_renderer(closure)
-    ///     .build(&Rect::default())
+    ///     .with_renderer(Box::new(move |size, position, button_state: &_, toggled, data: &mut AppData| {
+    ///         None  // this will leave the widget un-updated
+    ///     }))
+    ///     .with_size((20, 10))
+    ///     .build(&Rect { width: 80, height: 24 })
     ///     .unwrap();
     /// ```
     fn with_renderer(mut self, renderer: Self::RendererType) -> Self {
@@ -164,6 +229,7 @@ impl<C: 'static> WidgetBuilder<C> for ButtonWidgetBuilder<C> {
     /// # Example:
     /// ```
     /// use term_render::widget_impls::{ButtonWidgetBuilder, WidgetBuilder};
+    /// # struct AppData;
     /// let builder = ButtonWidgetBuilder::<AppData>::builder(String::from("Widget Name"));
     /// ```
     fn builder(name: String) -> Self {
@@ -174,8 +240,24 @@ impl<C: 'static> WidgetBuilder<C> for ButtonWidgetBuilder<C> {
             render_function: None,
             border: false,
             title: None,
+            theme_slot: None,
+            border_style: crate::render::BorderStyle::Plain,
+            padding: crate::render::Padding::default(),
+            title_alignment: crate::render::Alignment::Center,
+            bottom_title: None,
             update_handler: None,
             parent: None,
+            keyboard_activation: false,
+            context_menu: None,
+            toggle: false,
+            enabled: true,
+            keywords: vec![],
+            tooltip: None,
+            colors: vec![],
+            hidden: false,
+            layer: crate::render::Layer::Normal,
+            on_mount: None,
+            on_unmount: None,
             __phantom: std::marker::PhantomData,
         }
     }
@@ -186,10 +268,80 @@ impl<C: 'static> WidgetBuilder<C> for ButtonWidgetBuilder<C> {
         self
     }
 
+    /// Sets which point of the widget is placed at its computed position.
+    fn with_anchor(mut self, anchor: Anchor) -> Self {
+        self.size_and_position.anchor = anchor;
+        self
+    }
+
+    /// Sets the smallest size the widget can shrink to.
+    fn with_min_size(mut self, min_size: (u16, u16)) -> Self {
+        self.size_and_position.min_size = Some(min_size);
+        self
+    }
+
+    /// Sets the largest size the widget can grow to.
+    fn with_max_size(mut self, max_size: (u16, u16)) -> Self {
+        self.size_and_position.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets whether the widget is positioned relative to its parent's window.
+    fn with_relative_to_parent(mut self, relative: bool) -> Self {
+        self.size_and_position.position_mode = match relative {
+            true => PositionMode::RelativeToParent,
+            false => PositionMode::Absolute,
+        };
+        self
+    }
+
+    /// Sets whether the widget is clipped to its parent's window.
+    fn with_clip_to_parent(mut self, clip: bool) -> Self {
+        self.size_and_position.clip_to_parent = clip;
+        self
+    }
+
+    /// Slides the widget into place from the given offset when it's first drawn.
+    fn with_entrance_animation(mut self, from_offset: (i16, i16), duration: std::time::Duration) -> Self {
+        self.size_and_position.animate_from_offset(from_offset, duration, Easing::EaseOut);
+        self
+    }
+
+    /// Colors the border and title with a slot of the app's theme.
+    fn with_theme_slot(mut self, slot: &str) -> Self {
+        self.theme_slot = Some(slot.to_owned());
+        self
+    }
+
+    /// Sets the glyphs the border is drawn with.
+    fn with_border_style(mut self, style: crate::render::BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Sets the blank space between the border (or edge) and the content.
+    fn with_padding(mut self, padding: crate::render::Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets where the title sits along the top border.
+    fn with_title_alignment(mut self, alignment: crate::render::Alignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Sets the title drawn in the bottom border and its alignment.
+    fn with_bottom_title(mut self, title: String, alignment: crate::render::Alignment) -> Self {
+        self.bottom_title = Some((title, alignment));
+        self
+    }
+
     /// Sets the widget's update handler closure. This closure is called during event updates.
-    /// The closure receives references to the widget itself, the event parser, and mutable application data.
+    /// The closure receives references to the widget itself, mutable application data, the app, and the scene,
+    /// along with the button's state and whether it's toggled on (see `ButtonWidgetBuilder::with_toggle`).
     /// By default, there is no update handler, meaning the widget won't respond to events.
-    type FunctionType = Box<dyn Fn(&mut ButtonWidget<C>, &mut C, &mut crate::App<C>, &mut Scene<C>, &ButtonState)>;
+    type FunctionType = UpdateHandler<C>;
     fn with_update_handler(mut self, handler: Self::FunctionType) -> Self {
         self.update_handler = Some(handler);
         self
@@ -201,6 +353,36 @@ impl<C: 'static> WidgetBuilder<C> for ButtonWidgetBuilder<C> {
         self.parent = parent;
         self
     }
+
+    /// Tags the widget's window with the given keywords (see `Scene::widgets_by_keyword`).
+    fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Gives the widget a tooltip, shown once the mouse rests over it (see `Scene::set_tooltip_delay`).
+    fn with_tooltip(mut self, tooltip: impl Into<Tooltip<C>>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Paints the whole window with the colors and modifiers (see `Window::colorizes`).
+    fn with_colors(mut self, colors: Vec<crate::render::ColorType>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Sets whether the widget starts hidden (see `Scene::set_widget_visible`).
+    fn with_hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Sets the layer the widget's window is drawn in (see `Window::set_layer`).
+    fn with_layer(mut self, layer: crate::render::Layer) -> Self {
+        self.layer = layer;
+        self
+    }
     
     /// Builds the widget and adds it to the provided scene, returning the new widget's index in the scene graph.
     /// This method combines the `build` and `scene.add_widget` calls into one for convenience.
@@ -209,7 +391,8 @@ impl<C: 'static> WidgetBuilder<C> for ButtonWidgetBuilder<C> {
     /// ```
     /// use term_render::widget_impls::{ButtonWidgetBuilder, WidgetBuilder};
     /// use term_render::render::Rect;
-    /// let mut app = term_render::App::new().unwrap();
+    /// # struct AppData;
+    /// let mut app = term_render::App::<AppData>::new_headless(80, 24);
     /// let mut scene = term_render::widget::Scene::new();
     /// let widget_index = ButtonWidgetBuilder::<AppData>::builder(String::from("My Widget"))
     ///     .with_position((5, 5))
@@ -217,18 +400,200 @@ impl<C: 'static> WidgetBuilder<C> for ButtonWidgetBuilder<C> {
     ///     .add_to_scene(&mut app, &mut scene)
     ///     .expect("Failed to build and add widget to scene.");
     /// ```
-    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetErr> {
-        if let Ok((widget, window)) = self.build(&app.area.read()) {
-            scene.add_widget(widget, window, &mut *app.renderer.write())
-        } else {
-            Err(WidgetErr::new("Failed to build and add widget to scene."))
-        }
+    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetError> {
+        let (widget, window) = self.build(&app.area.read())?;
+        scene.add_widget(widget, window, &mut app.renderer.write())
+    }
+}
+
+impl<C> ButtonWidgetBuilder<C> {
+    /// Sets whether Return or Space press the button while the mouse is hovering over it (as well as
+    /// while it's focused), pressing it as a left click which is released on the following frame.
+    /// Without this, only Return presses the button, and only while it's focused.
+    pub fn with_keyboard_activation(mut self, keyboard_activation: bool) -> Self {
+        self.keyboard_activation = keyboard_activation;
+        self
+    }
+
+    /// Gives the button a context menu with the given items, which opens where the button is right clicked
+    /// (as the state becomes `Released(Right)`) before the update handler is called. The same items are
+    /// reused every time the menu opens (see `Scene::open_context_menu`).
+    pub fn with_context_menu(mut self, items: Vec<ContextMenuItem<C>>) -> Self {
+        self.context_menu = Some(std::rc::Rc::new(std::cell::RefCell::new(items)));
+        self
+    }
+
+    /// Sets whether the button is a toggle, which flips on and off each time it's left clicked (or pressed
+    /// through the keyboard). It starts off; whether it's on is passed to the renderer and update handler
+    /// (see `ButtonWidget::is_toggled`).
+    pub fn with_toggle(mut self, toggle: bool) -> Self {
+        self.toggle = toggle;
+        self
+    }
+
+    /// Sets whether the button starts enabled (which it does by default; see `ButtonWidget::set_enabled`).
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets a closure run once the widget is added to the scene, given the app data and the app, for
+    /// starting anything the widget owns (see `Widget::on_mount` for when it runs).
+    pub fn with_on_mount(mut self, on_mount: LifecycleHook<C>) -> Self {
+        self.on_mount = Some(on_mount);
+        self
+    }
+
+    /// Sets a closure run once the widget is removed from the scene, given the app data and the app, for
+    /// stopping anything started when it was mounted (see `Widget::on_unmount`).
+    pub fn with_on_unmount(mut self, on_unmount: LifecycleHook<C>) -> Self {
+        self.on_unmount = Some(on_unmount);
+        self
     }
 }
 
 /// Represents a button widget that can respond to user interactions such as clicks and hovers.
 /// Implements the `Widget` trait, allowing it to be integrated into a scene graph and rendered.
 /// Type C represents the application data type, which can be any type defined by the user.
+/// # Example:
+/// Stepping a button through each of its states with a headless app (see `App::new_headless`):
+/// ```
+/// use term_render::widget_impls::{ButtonState, ButtonWidgetBuilder, WidgetBuilder};
+/// use term_render::event_handler::{MouseEvent, MouseEventType, MouseState};
+/// use term_render::render::{Colored, Span};
+/// use term_render::widget::Scene;
+/// let mut app = term_render::App::<()>::new_headless(16, 3);
+/// let mut scene = Scene::new();
+/// ButtonWidgetBuilder::<()>::builder(String::from("button"))
+///     .with_border(true)
+///     .with_renderer(Box::new(|_size, _position, state, _toggled, _data| {
+///         let label = match state {
+///             ButtonState::Normal => "Normal",
+///             ButtonState::Hovered => "Hovered",
+///             ButtonState::Pressed(_) => "Pressed",
+///             ButtonState::Held(_) => "Held",
+///             ButtonState::Released(_) => "Released",
+///             ButtonState::Disabled => "Disabled",
+///         };
+///         Some(vec![Span::from_tokens(vec![Colored::new(String::from(label))])])
+///     }))
+///     .with_position((0, 0))
+///     .with_size((12, 3))
+///     .add_to_scene(&mut app, &mut scene)
+///     .unwrap();
+/// // runs a frame with the given mouse event over the button, returning its rendered row
+/// let mut frame = |mouse: Option<(MouseEventType, MouseState)>| {
+///     app.events.write().mouse_event = mouse.map(|(event_type, state)| {
+///         MouseEvent { event_type, position: (5, 2), state, click_count: 1 }
+///     });
+///     scene.update_all_widgets(&mut app, &mut ()).unwrap();
+///     app.events.write().clear_events();
+///     app.renderer.write().snapshot()
+/// };
+/// assert_eq!(frame(None), vec!["┌──────────┐", "│Normal    │", "└──────────┘"]);
+/// assert_eq!(frame(Some((MouseEventType::Null, MouseState::Hold)))[1], "│Hovered   │");
+/// assert_eq!(frame(Some((MouseEventType::Left, MouseState::Press)))[1], "│Pressed   │");
+/// assert_eq!(frame(None)[1], "│Held      │");
+/// assert_eq!(frame(Some((MouseEventType::Left, MouseState::Release)))[1], "│Released  │");
+/// // clicking gave the button focus, so it's still shown as hovered
+/// assert_eq!(frame(None)[1], "│Hovered   │");
+/// ```
+/// The other buttons go through the same states, and a press released outside the button is cancelled:
+/// ```
+/// use term_render::widget_impls::{ButtonState, ButtonWidgetBuilder, WidgetBuilder};
+/// use term_render::event_handler::{KeyCode, MouseEvent, MouseEventType, MouseState};
+/// use term_render::widget::Scene;
+/// let mut app = term_render::App::<Vec<ButtonState>>::new_headless(16, 3);
+/// let mut scene = Scene::new();
+/// ButtonWidgetBuilder::<Vec<ButtonState>>::builder(String::from("button"))
+///     .with_update_handler(Box::new(|_widget, states, _app, _scene, state, _toggled| states.push(state.clone())))
+///     .with_keyboard_activation(true)
+///     .with_position((0, 0))
+///     .with_size((12, 3))
+///     .add_to_scene(&mut app, &mut scene)
+///     .unwrap();
+/// let mut states = vec![];
+/// // runs a frame with the given mouse event (over the button, or at column 15 outside it)
+/// let mut frame = |mouse: Option<(MouseEventType, MouseState, u16)>, key: Option<KeyCode>| {
+///     app.events.write().mouse_event = mouse.map(|(event_type, state, x)| {
+///         MouseEvent { event_type, position: (x, 2), state, click_count: 1 }
+///     });
+///     if let Some(key) = key {  app.events.write().key_events.insert(key, true);  }
+///     scene.update_all_widgets(&mut app, &mut states).unwrap();
+///     app.events.write().clear_events();
+/// };
+/// use MouseEventType::{Left, Right, Null};
+/// use MouseState::{Press, Release, Hold};
+/// frame(Some((Null, Hold, 5)), None);
+/// frame(Some((Right, Press, 5)), None);
+/// frame(Some((Right, Hold, 6)), None);
+/// frame(Some((Right, Release, 6)), None);
+/// frame(Some((Null, Hold, 6)), None);
+/// // pressed over the button, but released outside it
+/// frame(Some((Left, Press, 5)), None);
+/// frame(Some((Left, Hold, 15)), None);
+/// frame(Some((Left, Release, 15)), None);
+/// // Return while hovered presses and releases it over two frames
+/// frame(Some((Null, Hold, 5)), None);
+/// frame(None, Some(KeyCode::Return));
+/// frame(None, None);
+/// assert_eq!(states, vec![
+///     ButtonState::Hovered,
+///     ButtonState::Pressed(Right), ButtonState::Held(Right), ButtonState::Released(Right), ButtonState::Hovered,
+///     // cancelled rather than released (the click focused the button, so Normal is reported as Hovered)
+///     ButtonState::Pressed(Left), ButtonState::Held(Left), ButtonState::Hovered,
+///     ButtonState::Hovered, ButtonState::Pressed(Left), ButtonState::Released(Left),
+/// ]);
+/// ```
+/// Toggles flip with each click, and a disabled button ignores clicks, letting them through to what's beneath it:
+/// ```
+/// use term_render::widget_impls::{ButtonState, ButtonWidget, ButtonWidgetBuilder, WidgetBuilder};
+/// use term_render::event_handler::{MouseEvent, MouseEventType, MouseState};
+/// use term_render::widget::Scene;
+/// let mut app = term_render::App::<Vec<&'static str>>::new_headless(20, 3);
+/// let mut scene = Scene::new();
+/// // a toolbar of toggles, with a disabled one drawn over a plain button
+/// for (name, x, depth) in [("bold", 0, 0), ("underneath", 10, 0), ("italic", 10, 1)] {
+///     ButtonWidgetBuilder::<Vec<&'static str>>::builder(String::from(name))
+///         .with_toggle(name != "underneath")
+///         .with_enabled(name != "italic")
+///         .with_update_handler(Box::new(move |_widget, log, _app, _scene, state, _toggled| {
+///             if let ButtonState::Pressed(_) = state {  log.push(name);  }
+///         }))
+///         .with_position((x, 0))
+///         .with_depth(depth)
+///         .with_size((10, 3))
+///         .add_to_scene(&mut app, &mut scene)
+///         .unwrap();
+/// }
+/// let mut log = vec![];
+/// // clicks (presses and releases) at the given column, followed by a frame without any mouse event
+/// let mut click = |scene: &mut Scene<Vec<&'static str>>, log: &mut Vec<&'static str>, x: u16| {
+///     for state in [Some(MouseState::Press), Some(MouseState::Release), None] {
+///         app.events.write().mouse_event = state.map(|state| MouseEvent { event_type: MouseEventType::Left, position: (x, 1), state, click_count: 1 });
+///         scene.update_all_widgets(&mut app, log).unwrap();
+///         app.events.write().clear_events();
+///     }
+/// };
+///
+/// // toggling twice goes back to where it started
+/// click(&mut scene, &mut log, 4);
+/// assert!(scene.widget_as::<ButtonWidget<Vec<&'static str>>>("bold").unwrap().is_toggled());
+/// click(&mut scene, &mut log, 4);
+/// assert!(!scene.widget_as::<ButtonWidget<Vec<&'static str>>>("bold").unwrap().is_toggled());
+///
+/// // the disabled toggle doesn't change, and the button beneath it gets the click instead
+/// click(&mut scene, &mut log, 14);
+/// let italic = scene.widget_as::<ButtonWidget<Vec<&'static str>>>("italic").unwrap();
+/// assert!(!italic.is_enabled() && !italic.is_toggled());
+/// assert_eq!(log, vec!["bold", "bold", "underneath"]);
+///
+/// // once it's enabled, it's clicked like any other
+/// scene.widget_as_mut::<ButtonWidget<Vec<&'static str>>>("italic").unwrap().set_enabled(true);
+/// click(&mut scene, &mut log, 14);
+/// assert!(scene.widget_as::<ButtonWidget<Vec<&'static str>>>("italic").unwrap().is_toggled());
+/// assert_eq!(log, vec!["bold", "bold", "underneath", "italic"]);
+/// ```
 pub struct ButtonWidget<C> {
     /// The indices of child widgets in the scene graph.
     children: Vec<usize>,
@@ -253,16 +618,92 @@ pub struct ButtonWidget<C> {
     pub render_function: Option<RenderFunction<C>>,
 
     /// Optional closure that handles updates to the widget's state.
-    pub update_handler: Option<Box<dyn Fn(&mut ButtonWidget<C>, &mut C, &mut crate::App<C>, &mut Scene<C>, &ButtonState)>>,
+    pub update_handler: Option<UpdateHandler<C>>,
 
 
     /// The current interaction state of the button.
     button_state: std::rc::Rc<ButtonState>,
 
+    /// The click count of the last press (2 for a double click, etc.).
+    click_count: u8,
+
+    /// Whether the button has the scene's focus (Return presses it).
+    focused: bool,
+    /// Whether the current press came from the keyboard (so it's released without the mouse).
+    keyboard_press: bool,
+    /// Whether Return/Space press the button while it's hovered (see `ButtonWidgetBuilder::with_keyboard_activation`).
+    pub keyboard_activation: bool,
+    /// The items of the context menu right clicking the button opens (see `ButtonWidgetBuilder::with_context_menu`).
+    context_menu: Option<SharedContextMenuItems<C>>,
+    /// Whether left clicking the button flips `toggled` (see `ButtonWidgetBuilder::with_toggle`).
+    toggle: bool,
+    /// Whether the toggle is on.
+    toggled: bool,
+    /// Whether the button responds to the mouse and keyboard (see `ButtonWidget::set_enabled`).
+    enabled: bool,
+
+    /// The keywords the widget's window is tagged with (see `Scene::widgets_by_keyword`).
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+
+    /// The closure run once the widget is added to the scene, if any (see `Widget::on_mount`).
+    on_mount: Option<LifecycleHook<C>>,
+    /// The closure run once the widget is removed from the scene, if any (see `Widget::on_unmount`).
+    on_unmount: Option<LifecycleHook<C>>,
+
     __phantom: std::marker::PhantomData<C>,
 }
 
 impl<C> ButtonWidget<C> {
+    /// Gets the number of consecutive clicks which made up the last press of the button
+    /// (1 for a single click, 2 for a double click, 3 for a triple click, etc.).
+    /// This is most useful when the state is `Pressed` or `Released`; it's 0 before the first press.
+    pub fn get_click_count(&self) -> u8 {
+        self.click_count
+    }
+
+    /// Returns whether the button has the scene's focus.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Returns whether the button's toggle is on (always false unless it was built `with_toggle`).
+    pub fn is_toggled(&self) -> bool {
+        self.toggled
+    }
+
+    /// Turns the button's toggle on or off, without calling the update handler.
+    pub fn set_toggled(&mut self, toggled: bool) {
+        self.toggled = toggled;
+    }
+
+    /// Returns whether the button is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Enables or disables the button. A disabled button ignores the mouse and keyboard (cancelling any
+    /// press in progress), is reported as `ButtonState::Disabled`, and doesn't block clicks to the widgets
+    /// beneath it. Its toggle keeps its value.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.button_state = std::rc::Rc::new(ButtonState::Normal);
+            self.keyboard_press = false;
+        }
+    }
+
+    /// Gets the state reported to the renderer and update handler. A focused button which
+    /// isn't being interacted with is reported as `Hovered`, so it's styled as such.
+    fn get_reported_state(&self) -> std::rc::Rc<ButtonState> {
+        match self.button_state.as_ref() {
+            _ if !self.enabled => std::rc::Rc::new(ButtonState::Disabled),
+            ButtonState::Normal if self.focused => std::rc::Rc::new(ButtonState::Hovered),
+            _ => std::rc::Rc::clone(&self.button_state),
+        }
+    }
+
     /// Creates a new ButtonWidget and its associated window.
     /// # Arguments
     /// * `name` - Unique identifier for the widget
@@ -280,10 +721,10 @@ impl<C> ButtonWidget<C> {
                render_function: Option<RenderFunction<C>>,
                depth: u16,
                display_area: &crate::render::Rect,
-    ) -> Result<(ButtonWidget<C>, crate::render::Window), WidgetErr> {
+    ) -> Result<(ButtonWidget<C>, crate::render::Window), WidgetError> {
         let (size, position) = size_and_position.get_size_and_position(display_area);
-        if size.0 == 0 || size.1 == 0 || position.0 == 0 || position.1 == 0 {
-            return Err(WidgetErr::new("Size or position cannot be zero"))
+        if size.0 == 0 || size.1 == 0 {
+            return Err(WidgetError::InvalidGeometry { size, position })
         }
         let window = crate::render::Window::new(position, depth, size);
         
@@ -295,6 +736,18 @@ impl<C> ButtonWidget<C> {
             render_function,
             update_handler: None,
             button_state: std::rc::Rc::new(ButtonState::Normal),
+            click_count: 0,
+            focused: false,
+            keyboard_press: false,
+            keyboard_activation: false,
+            context_menu: None,
+            toggle: false,
+            toggled: false,
+            enabled: true,
+            keywords: vec![],
+            tooltip: None,
+            on_mount: None,
+            on_unmount: None,
             __phantom: std::marker::PhantomData,
         };
         
@@ -303,13 +756,23 @@ impl<C> ButtonWidget<C> {
 }
 
 /// Implementation of the methods for ButtonWidget
-impl<C> Widget<C> for ButtonWidget<C> {
+impl<C: 'static> Widget<C> for ButtonWidget<C> {
     /// Returns the widget's name as an identifier. The rendering backend relies
     /// on `String` names instead of widgets.
     fn get_window_ref(&self) -> String {
         self.name.clone()
     }
     
+    /// Runs the mount closure, if any (see `with_on_mount`).
+    fn on_mount(&mut self, app: &mut crate::App<C>, data: &mut C) {
+        if let Some(on_mount) = &mut self.on_mount {  on_mount(data, app);  }
+    }
+    
+    /// Runs the unmount closure, if any (see `with_on_unmount`).
+    fn on_unmount(&mut self, app: &mut crate::App<C>, data: &mut C) {
+        if let Some(on_unmount) = &mut self.on_unmount {  on_unmount(data, app);  }
+    }
+    
     /// Handles event updates by invoking the user-provided update handler closure, if any.
     /// The closure receives references to the widget itself, the event parser, mutable application data,
     /// and also the button's state (`ButtonState`) because it's a Button widget.
@@ -318,95 +781,118 @@ impl<C> Widget<C> for ButtonWidget<C> {
     /// handles updating the button's state based on mouse events. The states are:
     /// - Normal: Default state when not interacted with.
     /// - Hovered: When the mouse is over the button.
-    /// - Pressed: When the button is clicked (mouse down).
+    /// - Pressed: When the button is clicked (mouse down) with the left, middle, or right button.
     /// - Held: When the button is held down after being pressed.
-    /// - Released: When the button is released (mouse up) after being pressed.
+    /// - Released: When the button is released (mouse up) over the button after being pressed.
+    ///   Releasing it anywhere else cancels the press, going straight back to Normal.
     /// 
+    /// While the button is focused (see `Scene::set_focus`), Return presses it as if it were
+    /// clicked, releasing it on the following frame (as do Return and Space while it's hovered,
+    /// with `ButtonWidgetBuilder::with_keyboard_activation`). Right clicking a button with a context menu
+    /// (see `ButtonWidgetBuilder::with_context_menu`) opens it as the button is released, and releasing
+    /// a left click on a toggle (see `ButtonWidgetBuilder::with_toggle`) flips it. A disabled button skips
+    /// all of this, only calling the update handler with `ButtonState::Disabled`.
+    ///
     /// The state transitions are managed internally based on mouse events. The entire
     /// widget as a whole represents the button's 'hit box'. The button **will** check
     /// for concealing widgets above it, and **cannot** be modified such as to only do so
     /// in certain circumstances.
     fn update_with_events(&mut self, data: &mut C, app: &mut crate::App<C>, scene: &mut Scene<C>) {
+        use crate::event_handler::{KeyCode, MouseEventType, MouseState};
+        if !self.enabled {
+            if let Some(update_handler) = self.update_handler.take() {
+                update_handler(self, data, app, scene, &ButtonState::Disabled, self.toggled);
+                self.update_handler = Some(update_handler);
+            }
+            return;
+        }
         // updating the button's state based on mouse events
         let (size, position) = self.size_and_position.get_size_and_position(&app.area.read());
+        let events = app.events.read();
+        let event = events.mouse_event.clone();
+        // whether the mouse is over the button (and not over something covering it)
+        let over = event.as_ref().map(|event| {
+            event.position.0 > position.0 && event.position.0 < position.0 + size.0 &&
+                event.position.1 > position.1 && event.position.1 < position.1 + size.1 &&
+                self.size_and_position.is_within_clip(event.position) &&
+                !scene.is_click_blocked_all(scene.get_widget_index(self.get_window_ref()).unwrap_or(0), event.position, app).unwrap_or(false)
+        });
+        let activation_key = events.contains_key_code(KeyCode::Return) ||
+            (self.keyboard_activation && events.contains_char(' '));
+        drop(events);
+        let keyboard_press = activation_key && (self.focused ||
+            (self.keyboard_activation && matches!(self.button_state.as_ref(), ButtonState::Hovered)));
         match self.button_state.as_ref() {
-            ButtonState::Normal => {
-                // checking if the mouse is now hovering, or clicking
-                if let Some(event) = &app.events.read().mouse_event {
-                    if event.position.0 > position.0 && event.position.0 < position.0 + size.0 &&
-                       event.position.1 > position.1 && event.position.1 < position.1 + size.1 {
-                        // mouse is over the button
-                        if scene.is_click_blocked(scene.get_widget_index(self.get_window_ref()).unwrap_or(0), event.position)
-                            .unwrap_or(false) {  return;  }
-                        if event.event_type == crate::event_handler::MouseEventType::Left &&
-                           event.state == crate::event_handler::MouseState::Press {
+            // pressing through the keyboard works from any state other than an ongoing press
+            ButtonState::Normal | ButtonState::Hovered | ButtonState::Released(_) if keyboard_press => {
+                self.button_state = std::rc::Rc::new(ButtonState::Pressed(MouseEventType::Left));
+                self.click_count = 1;
+                self.keyboard_press = true;
+            },
+            ButtonState::Pressed(state) if self.keyboard_press => {
+                // there's no mouse release to wait for
+                self.button_state = std::rc::Rc::new(ButtonState::Released(state.clone()));
+                self.keyboard_press = false;
+            },
+            ButtonState::Normal | ButtonState::Hovered => {
+                // checking if the mouse is now hovering, or clicking (with any of the buttons)
+                match (event, over) {
+                    (Some(event), Some(true)) => {
+                        if event.state == MouseState::Press &&
+                            matches!(event.event_type, MouseEventType::Left | MouseEventType::Middle | MouseEventType::Right)
+                        {
                             // the button was clicked
-                            self.button_state = std::rc::Rc::new(ButtonState::Pressed(event.event_type.clone()));
+                            self.button_state = std::rc::Rc::new(ButtonState::Pressed(event.event_type));
+                            self.click_count = event.click_count;
                         } else {
                             // just hovering
                             self.button_state = std::rc::Rc::new(ButtonState::Hovered);
                         }
-                    }
+                    },
+                    // the mouse moved away (or is over something covering the button)
+                    (Some(_), _) => self.button_state = std::rc::Rc::new(ButtonState::Normal),
+                    (None, _) => {},
                 }
             },
-            ButtonState::Pressed(state) => {
-                // transitioning to Held
-                self.button_state = std::rc::Rc::new(ButtonState::Held(state.clone()));
-            },
-            ButtonState::Held(state) => {
-                // checking if the mouse was released
-                if let Some(event) = &app.events.read().mouse_event {
-                    if &event.event_type == state &&
-                        event.state == crate::event_handler::MouseState::Release {
-                        // the button was released
-                        self.button_state = std::rc::Rc::new(ButtonState::Released(event.event_type.clone()));
-                    }
+            ButtonState::Pressed(state) | ButtonState::Held(state) => {
+                // checking if the mouse was released, which only counts over the button
+                // (releasing elsewhere cancels the press)
+                match event {
+                    Some(event) if &event.event_type == state && event.state == MouseState::Release => {
+                        self.button_state = match over {
+                            Some(true) => std::rc::Rc::new(ButtonState::Released(event.event_type)),
+                            _ => std::rc::Rc::new(ButtonState::Normal),
+                        };
+                    },
+                    // transitioning to Held
+                    _ => self.button_state = std::rc::Rc::new(ButtonState::Held(state.clone())),
                 }
             },
             ButtonState::Released(_) => {
                 // transitioning to Normal (or Hovered if still hovering)
-                let hovering = if let Some(event) = &app.events.read().mouse_event {
-                    if event.position.0 > position.0 && event.position.0 < position.0 + size.0 &&
-                       event.position.1 > position.1 && event.position.1 < position.1 + size.1 {
-                        // mouse is over the button
-                        true
-                    } else {  false  }
-                } else {  false  };
-                if hovering {
-                    self.button_state = std::rc::Rc::new(ButtonState::Hovered);
-                } else {
-                    self.button_state = std::rc::Rc::new(ButtonState::Normal);
-                }
-            },
-            ButtonState::Hovered => {
-                // checking if the mouse is still hovering and if it was clicked
-                // checking if the mouse is now hovering, or clicking
-                if let Some(event) = &app.events.read().mouse_event {
-                    if event.position.0 > position.0 && event.position.0 < position.0 + size.0 &&
-                       event.position.1 > position.1 && event.position.1 < position.1 + size.1 {
-                        // mouse is over the button
-                        if scene.is_click_blocked(scene.get_widget_index(self.get_window_ref()).unwrap_or(0), event.position)
-                            .unwrap_or(false) {
-                            self.button_state = std::rc::Rc::new(ButtonState::Normal);
-                            return;
-                        }
-                        
-                        if event.event_type == crate::event_handler::MouseEventType::Left &&
-                           event.state == crate::event_handler::MouseState::Press {
-                            // the button was clicked
-                            self.button_state = std::rc::Rc::new(ButtonState::Pressed(event.event_type.clone()));
-                        }
-                    } else {
-                        // mouse moved away
-                        self.button_state = std::rc::Rc::new(ButtonState::Normal);
-                    }
-                }
+                self.button_state = match over {
+                    Some(true) => std::rc::Rc::new(ButtonState::Hovered),
+                    _ => std::rc::Rc::new(ButtonState::Normal),
+                };
             },
+            // only ever reported, never stored
+            ButtonState::Disabled => self.button_state = std::rc::Rc::new(ButtonState::Normal),
+        }
+        if self.toggle && let ButtonState::Released(MouseEventType::Left) = self.button_state.as_ref() {
+            self.toggled = !self.toggled;
+        }
+        
+        // right clicking opens the context menu where the mouse was released
+        let released = app.events.read().mouse_event.as_ref().map(|event| event.position);
+        if let ButtonState::Released(MouseEventType::Right) = self.button_state.as_ref() &&
+            let Some(items) = &self.context_menu && let Some(position) = released
+        {
+            let _ = scene.open_shared_context_menu(position, std::rc::Rc::clone(items), app);
         }
         
         if let Some(update_handler) = self.update_handler.take() {
-            let button_state = std::rc::Rc::clone(&self.button_state);
-            update_handler(self, data, app, scene, &*button_state);
+            let button_state = self.get_reported_state();
+            update_handler(self, data, app, scene, &button_state, self.toggled);
             self.update_handler = Some(update_handler);
         }
     }
@@ -420,10 +906,9 @@ impl<C> Widget<C> for ButtonWidget<C> {
         let (size, position) = self.size_and_position.get_size_and_position(area);
         window.resize(size);
         window.r#move(position);
-        if let Some(render_function) = &self.render_function {
-            if let Some(render) = render_function(size, position, &self.button_state, app_state) {
-                return window.try_update_lines(render);
-            }
+        if let Some(render_function) = &self.render_function &&
+            let Some(render) = render_function(size, position, &self.get_reported_state(), self.toggled, app_state) {
+            return window.try_update_lines(render);
         } false
     }
     
@@ -457,9 +942,56 @@ impl<C> Widget<C> for ButtonWidget<C> {
         self.parent_index = index;
     }
     
+    /// Checks if a given position collides with the button. A disabled button never does, so
+    /// clicks pass through it to the widgets beneath.
     fn is_collided(&self, position: (u16, u16)) -> bool {
-        let (size, pos) = self.size_and_position.get_last();
-        position.0 >= pos.0 && position.0 < pos.0 + size.0 && position.1 >= pos.1 && position.1 < pos.1 + size.1
+        self.enabled && self.size_and_position.is_collided(position)
+    }
+
+    /// Returns the widget as `Any` for downcasting.
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget as a mutable `Any` for downcasting.
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget's size and position configuration.
+    fn get_size_and_position(&self) -> Option<&SizeAndPosition> {
+        Some(&self.size_and_position)
+    }
+
+    /// Returns the widget's size and position configuration mutably.
+    fn get_size_and_position_mut(&mut self) -> Option<&mut SizeAndPosition> {
+        Some(&mut self.size_and_position)
+    }
+
+    /// Returns the keywords the widget's window is tagged with.
+    fn get_keywords(&self) -> &[String] {
+        &self.keywords
+    }
+
+    /// Returns the tooltip shown while the mouse rests over the widget, if any.
+    fn get_tooltip(&self) -> Option<&Tooltip<C>> {
+        self.tooltip.as_ref()
+    }
+
+    /// Buttons take focus when clicked, or through Tab/Shift+Tab.
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    /// Return presses the button while it has the scene's focus.
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+    
+    /// Applies the offset and clip inherited from the parent widgets to the size and position.
+    fn set_parent_transform(&mut self, offset: (i16, i16), clip: Option<Region>) {
+        self.size_and_position.parent_offset = offset;
+        self.size_and_position.clip = clip;
     }
 }
 
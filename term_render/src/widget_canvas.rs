@@ -0,0 +1,799 @@
+use crate::widget_impls::*;
+use crate::widget::*;
+
+type RenderFunction<C> = Box<dyn Fn(&mut Canvas, &mut C)>;
+type UpdateHandler<C> = Box<dyn Fn(&mut dyn Widget<C>, &mut C, &mut crate::App<C>, &mut Scene<C>)>;
+/// The foreground and background colors of a cell.
+type CellColors = (Option<(u8, u8, u8)>, Option<(u8, u8, u8)>);
+
+/// How a `Canvas` turns its pixels into characters.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum CanvasMode {
+    /// Unicode braille characters, giving each cell 2x4 pixels. Each cell takes the (average) color of its pixels.
+    #[default]
+    Braille,
+    /// Upper and lower half blocks, giving each cell 1x2 pixels, each of which keeps its own color.
+    HalfBlock,
+}
+
+impl CanvasMode {
+    /// Gets the number of pixels (columns, rows) each cell holds.
+    pub fn get_cell_pixels(&self) -> (u16, u16) {
+        match self {
+            CanvasMode::Braille => (2, 4),
+            CanvasMode::HalfBlock => (1, 2),
+        }
+    }
+}
+
+// the braille dot for each pixel of a cell, indexed by [row][column]
+const BRAILLE_DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// A grid of pixels drawn with braille or half-block characters (see `CanvasMode`), for sparklines,
+/// plots, and other simple graphics. Each pixel is either on or off, and lit pixels can have a color
+/// (set with `set_color` before drawing). Only the rows of cells whose pixels changed are re-rendered.
+/// # Example:
+/// ```
+/// use term_render::widget_impls::{Canvas, CanvasMode};
+/// let mut canvas = Canvas::new(4, 2, CanvasMode::Braille);
+/// assert_eq!(canvas.get_resolution(), (8, 8));
+/// canvas.line(0, 0, 7, 7);
+/// assert!(canvas.get_pixel(3, 3) && !canvas.get_pixel(3, 4));
+/// let rows: Vec<String> = (0..2).map(|row| canvas.render_row(row).join().0.replace("\x1b[0m", "")).collect();
+/// assert_eq!(rows, vec!["⠑⢄  ", "  ⠑⢄"]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Canvas {
+    mode: CanvasMode,
+    /// The size in cells (columns, rows).
+    cells: (u16, u16),
+    /// Whether each pixel is lit (row by row).
+    pixels: Vec<bool>,
+    /// The color of each pixel, if it has one.
+    colors: Vec<Option<(u8, u8, u8)>>,
+    /// The color newly drawn pixels are given.
+    pen: Option<(u8, u8, u8)>,
+    /// The rows of cells whose pixels changed since they were last rendered.
+    dirty: Vec<bool>,
+}
+
+impl Canvas {
+    /// Creates a blank canvas covering the given number of cells (columns, rows).
+    pub fn new(columns: u16, rows: u16, mode: CanvasMode) -> Self {
+        let mut canvas = Canvas {  mode, ..Default::default()  };
+        canvas.resize(columns, rows);
+        canvas
+    }
+
+    /// Gets the mode the pixels are drawn with.
+    pub fn get_mode(&self) -> CanvasMode {
+        self.mode
+    }
+
+    /// Changes the mode the pixels are drawn with, clearing the canvas (the resolution changes).
+    pub fn set_mode(&mut self, mode: CanvasMode) {
+        if mode == self.mode {  return;  }
+        self.mode = mode;
+        let (columns, rows) = self.cells;
+        self.cells = (0, 0);
+        self.pixels.clear();
+        self.colors.clear();
+        self.resize(columns, rows);
+    }
+
+    /// Gets the size of the canvas in cells (columns, rows).
+    pub fn get_cell_size(&self) -> (u16, u16) {
+        self.cells
+    }
+
+    /// Gets the number of pixels (width, height): `(columns * 2, rows * 4)` for braille, or
+    /// `(columns, rows * 2)` for half blocks.
+    pub fn get_resolution(&self) -> (u16, u16) {
+        let (cell_width, cell_height) = self.mode.get_cell_pixels();
+        (self.cells.0.saturating_mul(cell_width), self.cells.1.saturating_mul(cell_height))
+    }
+
+    /// Resizes the canvas to the given number of cells, keeping the pixels which still fit.
+    /// Every row is re-rendered afterward.
+    pub fn resize(&mut self, columns: u16, rows: u16) {
+        if (columns, rows) == self.cells {  return;  }
+        let (old_width, old_height) = self.get_resolution();
+        self.cells = (columns, rows);
+        let (width, height) = self.get_resolution();
+        let mut pixels = vec![false; width as usize * height as usize];
+        let mut colors = vec![None; pixels.len()];
+        for y in 0..height.min(old_height) as usize {
+            for x in 0..width.min(old_width) as usize {
+                pixels[y * width as usize + x] = self.pixels[y * old_width as usize + x];
+                colors[y * width as usize + x] = self.colors[y * old_width as usize + x];
+            }
+        }
+        self.pixels = pixels;
+        self.colors = colors;
+        self.dirty = vec![true; rows as usize];
+    }
+
+    /// Sets the color pixels are drawn with from now on (None leaves them in the default color).
+    pub fn set_color(&mut self, color: Option<(u8, u8, u8)>) {
+        self.pen = color;
+    }
+
+    /// Gets whether the pixel at (x, y) is lit. Pixels outside the canvas are never lit.
+    pub fn get_pixel(&self, x: u16, y: u16) -> bool {
+        let (width, height) = self.get_resolution();
+        x < width && y < height && self.pixels[y as usize * width as usize + x as usize]
+    }
+
+    /// Lights (or clears) the pixel at (x, y) in the current color. Pixels outside the canvas are ignored.
+    pub fn set_pixel(&mut self, x: u16, y: u16, on: bool) {
+        let (width, height) = self.get_resolution();
+        if x >= width || y >= height {  return;  }
+        let index = y as usize * width as usize + x as usize;
+        let color = if on {  self.pen  } else {  None  };
+        if self.pixels[index] == on && self.colors[index] == color {  return;  }
+        self.pixels[index] = on;
+        self.colors[index] = color;
+        self.dirty[(y / self.mode.get_cell_pixels().1) as usize] = true;
+    }
+
+    /// Draws a line between two pixels (inclusive) in the current color. The ends can be outside
+    /// the canvas, in which case only the part inside is drawn.
+    pub fn line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32) {
+        // bresenham's line algorithm
+        let (delta_x, delta_y) = ((x1 - x0).abs(), -(y1 - y0).abs());
+        let (step_x, step_y) = ((x1 - x0).signum(), (y1 - y0).signum());
+        let (mut x, mut y) = (x0, y0);
+        let mut error = delta_x + delta_y;
+        loop {
+            if x >= 0 && y >= 0 && x <= u16::MAX as i32 && y <= u16::MAX as i32 {
+                self.set_pixel(x as u16, y as u16, true);
+            }
+            if x == x1 && y == y1 {  break;  }
+            let doubled = error * 2;
+            if doubled >= delta_y {
+                error += delta_y;
+                x += step_x;
+            }
+            if doubled <= delta_x {
+                error += delta_x;
+                y += step_y;
+            }
+        }
+    }
+
+    /// Clears every pixel.
+    pub fn clear(&mut self) {
+        let (width, cell_height) = (self.get_resolution().0 as usize, self.mode.get_cell_pixels().1 as usize);
+        for (row, dirty) in self.dirty.iter_mut().enumerate() {
+            let range = row * cell_height * width..(row + 1) * cell_height * width;
+            if self.pixels[range.clone()].iter().any(|pixel| *pixel) {  *dirty = true;  }
+            self.pixels[range.clone()].fill(false);
+            self.colors[range].fill(None);
+        }
+    }
+
+    /// Clears the canvas and plots the series as a line graph across its full width, scaled so the
+    /// smallest value is at the bottom and the largest at the top. Values which aren't finite are skipped.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{Canvas, CanvasMode};
+    /// let mut canvas = Canvas::new(5, 2, CanvasMode::HalfBlock);
+    /// canvas.plot(&[0.0, 1.0, 2.0, 3.0, 4.0]);
+    /// // the resolution is 5x4, so the values rise one pixel per column
+    /// assert!(canvas.get_pixel(0, 3) && canvas.get_pixel(3, 1) && canvas.get_pixel(4, 0));
+    /// assert!(!canvas.get_pixel(0, 0));
+    /// ```
+    pub fn plot(&mut self, series: &[f64]) {
+        self.clear();
+        let (width, height) = self.get_resolution();
+        let finite = series.iter().copied().filter(|value| value.is_finite());
+        let (min, max) = finite.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| (min.min(value), max.max(value)));
+        if width == 0 || height == 0 || min > max {  return;  }
+        let to_pixel = |index: usize, value: f64| {
+            let x = match series.len() {
+                1 => 0,
+                length => (index * (width as usize - 1) / (length - 1)) as i32,
+            };
+            // a flat series sits in the middle
+            let scale = if max > min {  (value - min) / (max - min)  } else {  0.5  };
+            (x, ((1.0 - scale) * (height - 1) as f64).round() as i32)
+        };
+        let mut last = None;
+        for (index, value) in series.iter().enumerate() {
+            if !value.is_finite() {
+                last = None;
+                continue;
+            }
+            let (x, y) = to_pixel(index, *value);
+            let (last_x, last_y) = last.unwrap_or((x, y));
+            self.line(last_x, last_y, x, y);
+            last = Some((x, y));
+        }
+    }
+
+    /// Renders a row of cells into a span, coloring each cell with the color of its pixels.
+    pub fn render_row(&self, row: u16) -> crate::render::Span {
+        let mut span = crate::render::Span::default();
+        if row >= self.cells.1 {  return span;  }
+        let (cell_width, cell_height) = self.mode.get_cell_pixels();
+        // consecutive cells of the same colors share a segment
+        let mut text = String::new();
+        let mut style: CellColors = (None, None);
+        for column in 0..self.cells.0 {
+            let pixel = |x: u16, y: u16| {
+                let (x, y) = (column * cell_width + x, row * cell_height + y);
+                match self.get_pixel(x, y) {
+                    true => Some(self.colors[y as usize * self.get_resolution().0 as usize + x as usize]),
+                    false => None,
+                }
+            };
+            let (chr, cell_style) = match self.mode {
+                CanvasMode::Braille => {
+                    let mut dots = 0;
+                    let mut lit_colors = vec![];
+                    for (y, row_dots) in BRAILLE_DOTS.iter().enumerate() {
+                        for (x, dot) in row_dots.iter().enumerate() {
+                            let Some(color) = pixel(x as u16, y as u16) else {  continue;  };
+                            dots |= dot;
+                            lit_colors.extend(color);
+                        }
+                    }
+                    match dots {
+                        0 => (' ', (None, None)),
+                        _ => (char::from_u32(0x2800 + dots).unwrap_or(' '), (Canvas::average(&lit_colors), None)),
+                    }
+                },
+                CanvasMode::HalfBlock => match (pixel(0, 0), pixel(0, 1)) {
+                    (None, None) => (' ', (None, None)),
+                    (Some(top), None) => ('▀', (top, None)),
+                    (None, Some(bottom)) => ('▄', (bottom, None)),
+                    (Some(top), Some(bottom)) if top == bottom => ('█', (top, None)),
+                    (Some(top), Some(Some(bottom))) => ('▀', (top, Some(bottom))),
+                    (Some(Some(top)), Some(None)) => ('▄', (None, Some(top))),
+                    (Some(None), Some(None)) => ('█', (None, None)),
+                },
+            };
+            // blank cells take on the current style (unless it has a background), so runs of them don't split segments
+            let cell_style = if chr == ' ' && style.1.is_none() {  style  } else {  cell_style  };
+            if cell_style != style && !text.is_empty() {
+                span.push(Canvas::get_colored(std::mem::take(&mut text), style));
+            }
+            style = cell_style;
+            text.push(chr);
+        }
+        if !text.is_empty() {  span.push(Canvas::get_colored(text, style));  }
+        span
+    }
+
+    // averages the colors of a cell's pixels
+    fn average(colors: &[(u8, u8, u8)]) -> Option<(u8, u8, u8)> {
+        if colors.is_empty() {  return None;  }
+        let sum = colors.iter().fold((0u32, 0u32, 0u32), |sum, color| {
+            (sum.0 + color.0 as u32, sum.1 + color.1 as u32, sum.2 + color.2 as u32)
+        });
+        let count = colors.len() as u32;
+        Some(((sum.0 / count) as u8, (sum.1 / count) as u8, (sum.2 / count) as u8))
+    }
+
+    fn get_colored(text: String, (foreground, background): CellColors) -> crate::render::Colored {
+        let mut colored = crate::render::Colored::new(text);
+        if let Some((r, g, b)) = foreground {  colored.add_color(crate::render::ColorType::Rgb(r, g, b));  }
+        if let Some((r, g, b)) = background {  colored.add_color(crate::render::ColorType::OnRGB(r, g, b));  }
+        colored
+    }
+
+    /// Takes the rows of cells which changed since this was last called (see `render_row`).
+    pub fn take_dirty_rows(&mut self) -> Vec<u16> {
+        let rows = self.dirty.iter().enumerate().filter(|(_row, dirty)| **dirty).map(|(row, _dirty)| row as u16).collect();
+        self.dirty.fill(false);
+        rows
+    }
+}
+
+/// Builder for creating CanvasWidget instances with a fluent interface.
+/// Maintains configuration state until build() is called to create the actual widget.
+pub struct CanvasWidgetBuilder<C> {
+    /// The unique name identifier for the widget.
+    name: String,
+    /// The z-index depth of the widget; higher values render on top of lower ones.
+    depth: Option<u16>,
+    /// Whether the widget should have a border.
+    border: bool,
+    /// The title of the widget, if any.
+    title: Option<String>,
+    /// The theme slot coloring the border and title, if any.
+    theme_slot: Option<String>,
+    /// The glyphs the border is drawn with.
+    border_style: crate::render::BorderStyle,
+    /// The blank space between the border (or edge) and the content.
+    padding: crate::render::Padding,
+    /// Where the title sits along the top border.
+    title_alignment: crate::render::Alignment,
+    /// The title drawn in the bottom border and its alignment, if any.
+    bottom_title: Option<(String, crate::render::Alignment)>,
+    /// The size and position configuration for the widget.
+    pub size_and_position: SizeAndPosition,
+    /// The closure drawing onto the canvas each update, if any.
+    pub render_function: Option<RenderFunction<C>>,
+    /// The update handler function for the widget, if any. This function is called during event updates.
+    update_handler: Option<UpdateHandler<C>>,
+    /// The index of the parent widget in the scene graph, if any.
+    parent: Option<usize>,
+    /// How the canvas's pixels are drawn.
+    mode: CanvasMode,
+
+    /// The keywords the widget's window is tagged with.
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+    /// The colors and modifiers the whole window is painted with (i.e. the border's color).
+    colors: Vec<crate::render::ColorType>,
+    /// Whether the window starts hidden.
+    hidden: bool,
+    /// The layer the window is drawn in.
+    layer: crate::render::Layer,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+impl<C> CanvasWidgetBuilder<C> {
+    /// Sets how the canvas's pixels are drawn (see `CanvasMode`). By default, braille is used.
+    pub fn with_mode(mut self, mode: CanvasMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+/// Implementations for the methods in `WidgetBuilder`.
+impl<C: 'static> WidgetBuilder<C> for CanvasWidgetBuilder<C> {
+    /// Constructs a `CanvasWidget`, an implementor of `Widget`, given the parameters.
+    /// Validates that the size is non-zero before creating the widget.
+    /// The method takes in a reference to the terminal's current area/size.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{CanvasMode, CanvasWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::Rect;
+    /// # struct AppData;
+    /// let (widget, window) = CanvasWidgetBuilder::<AppData>::builder(String::new())
+    ///     .with_mode(CanvasMode::HalfBlock)
+    ///     .with_size((40, 10))
+    ///     .build(&Rect { width: 80, height: 24 })  // replace &Rect with the actual terminal size (such as `&app.area.read()`)
+    ///     .expect("Invalid widget position or size.");
+    /// ```
+    fn build(mut self, display_area: &crate::render::Rect) -> Result<(Box<dyn Widget<C>>, crate::render::Window), WidgetBuilderError> {
+        let (size, position) = self.size_and_position.get_size_and_position(display_area);
+        if size.0 == 0 || size.1 == 0 {
+            return Err(WidgetBuilderError::InvalidGeometry { size, position })
+        }
+        let depth = self.depth.as_ref().unwrap_or(&0u16);
+        let mut window = crate::render::Window::new(position, *depth, size);
+        if self.border {  window.bordered();  }
+        window.set_theme_slot(self.theme_slot.clone());
+        window.set_border_style(self.border_style);
+        window.set_padding(self.padding);
+        if let Some(title) = &self.title {  window.titled(title.clone());  }
+        window.colorizes(std::mem::take(&mut self.colors));
+        if self.hidden {  window.hide();  }
+        window.set_layer(self.layer);
+        window.set_title_alignment(self.title_alignment);
+        if let Some((title, alignment)) = &self.bottom_title {
+            window.bottom_titled(crate::render::Span::from_tokens(vec![crate::render::Colored::new(title.clone())]));
+            window.set_bottom_title_alignment(*alignment);
+        }
+        let canvas = Canvas::new(window.get_content_width() as u16, window.get_content_height() as u16, self.mode);
+        Ok((Box::new(CanvasWidget::<C> {
+            children: vec![],
+            name: self.name,
+            parent_index: self.parent,
+            size_and_position: self.size_and_position,
+            render_function: self.render_function,
+            update_handler: self.update_handler,
+            canvas,
+            keywords: self.keywords,
+            tooltip: self.tooltip,
+            __phantom: std::marker::PhantomData,
+        }), window))
+    }
+
+    /// Sets the widget's fixed position (static layout).
+    /// Retrains the dynamic proportions of any dynamic positioning configuration already in place.
+    fn with_position(mut self, position: (u16, u16)) -> Self {
+        self.size_and_position.position_offset = (position.0 as i16, position.1 as i16);
+        self
+    }
+
+    /// Sets the widget's fixed position (static layout).
+    /// Retrains the dynamic proportions of any dynamic size configuration already in place.
+    fn with_size(mut self, size: (u16, u16)) -> Self {
+        self.size_and_position.size_offset = (size.0 as i16, size.1 as i16);
+        self
+    }
+
+    /// Configures dynamic positioning based on terminal size with a fixed offset.
+    fn with_dynamic_position(mut self, position_offset: (i16, i16), position_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.position_offset = position_offset;
+        self.size_and_position.position_area_percent = position_area_percent;
+        self
+    }
+
+    /// Configures dynamic sizing based on terminal size with a fixed offset.
+    fn with_dynamic_size(mut self, size_offset: (i16, i16), size_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.size_offset = size_offset;
+        self.size_and_position.size_area_percent = size_area_percent;
+        self
+    }
+
+    /// Sets whether the widget should have a border. By default, all widgets are borderless.
+    fn with_border(mut self, border: bool) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Sets the widget's title (displayed in border if enabled; invisible otherwise).
+    fn with_title(mut self, title: String) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Assigns a depth to the widget. Higher values represent UI elements that are stacked further
+    /// ontop of other elements. By default, the builder instance has a depth of None, which corresponds
+    /// to 0 (root depth).
+    fn with_depth(mut self, depth: u16) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// The type representing the renderer closure.
+    type RendererType = RenderFunction<C>;
+    /// Sets the closure which draws onto the canvas every update, receiving the canvas (already
+    /// resized to the widget) and the app's data. The canvas keeps its pixels between updates, so the
+    /// closure can either draw incrementally or clear it first (`Canvas::plot` clears it for you).
+    /// Only the rows whose pixels changed are re-rendered. By default, the canvas is left as it is,
+    /// to be drawn on directly (see `Scene::widget_as_mut`).
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{CanvasWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::Rect;
+    /// struct AppData {  samples: Vec<f64>  }
+    /// let (widget, window) = CanvasWidgetBuilder::<AppData>::builder(String::new())
+    ///     .with_renderer(Box::new(|canvas, data: &mut AppData| canvas.plot(&data.samples)))
+    ///     .with_size((40, 10))
+    ///     .build(&Rect { width: 80, height: 24 })
+    ///     .unwrap();
+    /// ```
+    fn with_renderer(mut self, renderer: Self::RendererType) -> Self {
+        self.render_function = Some(renderer);
+        self
+    }
+
+    /// Generates a new builder instance with a provided unique name identifier.
+    /// By default, size_and_position are no compatible and require the user to
+    /// provide them using the other `WidgetBuilder` trait functions.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{CanvasWidgetBuilder, WidgetBuilder};
+    /// # struct AppData;
+    /// let builder = CanvasWidgetBuilder::<AppData>::builder(String::from("Widget Name"));
+    /// ```
+    fn builder(name: String) -> Self {
+        Self {
+            name,
+            depth: None,
+            size_and_position: SizeAndPosition::default(),
+            render_function: None,
+            border: false,
+            title: None,
+            theme_slot: None,
+            border_style: crate::render::BorderStyle::Plain,
+            padding: crate::render::Padding::default(),
+            title_alignment: crate::render::Alignment::Center,
+            bottom_title: None,
+            update_handler: None,
+            parent: None,
+            mode: CanvasMode::default(),
+            keywords: vec![],
+            tooltip: None,
+            colors: vec![],
+            hidden: false,
+            layer: crate::render::Layer::Normal,
+            __phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the SizeAndPosition configuration directly.
+    fn with_sap(mut self, sap: SizeAndPosition) -> Self {
+        self.size_and_position = sap;
+        self
+    }
+
+    /// Sets which point of the widget is placed at its computed position.
+    fn with_anchor(mut self, anchor: Anchor) -> Self {
+        self.size_and_position.anchor = anchor;
+        self
+    }
+
+    /// Sets the smallest size the widget can shrink to.
+    fn with_min_size(mut self, min_size: (u16, u16)) -> Self {
+        self.size_and_position.min_size = Some(min_size);
+        self
+    }
+
+    /// Sets the largest size the widget can grow to.
+    fn with_max_size(mut self, max_size: (u16, u16)) -> Self {
+        self.size_and_position.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets whether the widget is positioned relative to its parent's window.
+    fn with_relative_to_parent(mut self, relative: bool) -> Self {
+        self.size_and_position.position_mode = match relative {
+            true => PositionMode::RelativeToParent,
+            false => PositionMode::Absolute,
+        };
+        self
+    }
+
+    /// Sets whether the widget is clipped to its parent's window.
+    fn with_clip_to_parent(mut self, clip: bool) -> Self {
+        self.size_and_position.clip_to_parent = clip;
+        self
+    }
+
+    /// Slides the widget into place from the given offset when it's first drawn.
+    fn with_entrance_animation(mut self, from_offset: (i16, i16), duration: std::time::Duration) -> Self {
+        self.size_and_position.animate_from_offset(from_offset, duration, Easing::EaseOut);
+        self
+    }
+
+    /// Colors the border and title with a slot of the app's theme.
+    fn with_theme_slot(mut self, slot: &str) -> Self {
+        self.theme_slot = Some(slot.to_owned());
+        self
+    }
+
+    /// Sets the glyphs the border is drawn with.
+    fn with_border_style(mut self, style: crate::render::BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Sets the blank space between the border (or edge) and the content.
+    fn with_padding(mut self, padding: crate::render::Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets where the title sits along the top border.
+    fn with_title_alignment(mut self, alignment: crate::render::Alignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Sets the title drawn in the bottom border and its alignment.
+    fn with_bottom_title(mut self, title: String, alignment: crate::render::Alignment) -> Self {
+        self.bottom_title = Some((title, alignment));
+        self
+    }
+
+    /// Sets the widget's update handler closure. This closure is called during event updates.
+    /// By default, there is no update handler, meaning the widget won't respond to events.
+    type FunctionType = UpdateHandler<C>;
+    fn with_update_handler(mut self, handler: Self::FunctionType) -> Self {
+        self.update_handler = Some(handler);
+        self
+    }
+
+    /// Sets the parent widget index for this widget, if any.
+    /// By default, the parent is None, indicating a root node.
+    fn with_parent(mut self, parent: Option<usize>) -> Self {
+        self.parent = parent;
+        self
+    }
+
+    /// Tags the widget's window with the given keywords (see `Scene::widgets_by_keyword`).
+    fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Gives the widget a tooltip, shown once the mouse rests over it (see `Scene::set_tooltip_delay`).
+    fn with_tooltip(mut self, tooltip: impl Into<Tooltip<C>>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Paints the whole window with the colors and modifiers (see `Window::colorizes`).
+    fn with_colors(mut self, colors: Vec<crate::render::ColorType>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Sets whether the widget starts hidden (see `Scene::set_widget_visible`).
+    fn with_hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Sets the layer the widget's window is drawn in (see `Window::set_layer`).
+    fn with_layer(mut self, layer: crate::render::Layer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Builds the widget and adds it to the provided scene, returning the new widget's index in the scene graph.
+    /// This method combines the `build` and `scene.add_widget` calls into one for convenience.
+    /// If building the widget fails, an error is returned instead.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{CanvasWidget, CanvasWidgetBuilder, WidgetBuilder};
+    /// # struct AppData;
+    /// let mut app = term_render::App::<AppData>::new_headless(20, 4);
+    /// let mut scene = term_render::widget::Scene::new();
+    /// CanvasWidgetBuilder::<AppData>::builder(String::from("Canvas"))
+    ///     .with_size((4, 2))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .unwrap();
+    /// // drawing directly onto the canvas
+    /// let canvas = &mut scene.widget_as_mut::<CanvasWidget<AppData>>("Canvas").unwrap().canvas;
+    /// assert_eq!(canvas.get_resolution(), (8, 8));
+    /// canvas.line(0, 7, 7, 7);
+    /// scene.update_all_widgets(&mut app, &mut AppData).unwrap();
+    /// assert_eq!(app.renderer.write().snapshot()[1].trim_end(), "⣀⣀⣀⣀");
+    /// ```
+    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetError> {
+        let (widget, window) = self.build(&app.area.read())?;
+        scene.add_widget(widget, window, &mut app.renderer.write())
+    }
+}
+
+/// A widget drawing a `Canvas` of pixels with braille or half-block characters, for sparklines, plots,
+/// and other simple graphics. The canvas always covers the widget's content area, adapting its resolution
+/// as the widget is resized. It can be drawn on by the render closure each update, or directly (through
+/// `Scene::widget_as_mut`). Only the rows of cells whose pixels changed are re-rendered.
+/// `CanvasWidgetBuilder` is the associated builder for creating instances of this widget.
+pub struct CanvasWidget<C> {
+    /// The indices of child widgets in the scene graph.
+    children: Vec<usize>,
+
+    /// The unique name identifier for the widget. The rendering backend
+    /// relies on `String` names instead of widgets.
+    name: String,
+
+    /// The index of the parent widget in the scene graph, if any (None would
+    /// indicate the root node).
+    parent_index: Option<usize>,
+
+    /// Configuration for the widget's size and position, supporting both static and dynamic layouts.
+    pub size_and_position: SizeAndPosition,
+
+    /// Optional closure drawing onto the canvas every update.
+    pub render_function: Option<RenderFunction<C>>,
+
+    /// Optional closure that handles updates to the widget's state.
+    pub update_handler: Option<UpdateHandler<C>>,
+
+    /// The pixels drawn by the widget.
+    pub canvas: Canvas,
+
+    /// The keywords the widget's window is tagged with (see `Scene::widgets_by_keyword`).
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+/// Implementation of the methods for CanvasWidget
+impl<C: 'static> Widget<C> for CanvasWidget<C> {
+    /// Returns the widget's name as an identifier. The rendering backend relies
+    /// on `String` names instead of widgets.
+    fn get_window_ref(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Handles event updates by invoking the user-provided update handler closure, if any.
+    fn update_with_events(&mut self, data: &mut C, app: &mut crate::App<C>, scene: &mut Scene<C>) {
+        if let Some(update_handler) = self.update_handler.take() {
+            update_handler(self, data, app, scene);
+            self.update_handler = Some(update_handler);
+        }
+    }
+
+    /// Without an update handler, there's nothing to do with events.
+    fn consumes_events(&self) -> bool {
+        self.update_handler.is_some()
+    }
+
+    /// Resizes the canvas to the content area, lets the render closure draw onto it, and then
+    /// re-renders the rows of cells whose pixels changed.
+    fn update_render(&mut self, window: &mut crate::render::Window, area: &crate::render::Rect, app_state: &mut C) -> bool {
+        let (size, position) = self.size_and_position.get_size_and_position(area);
+        window.resize(size);
+        window.r#move(position);
+        self.canvas.resize(window.get_content_width() as u16, window.get_content_height() as u16);
+        if let Some(render_function) = &self.render_function {
+            render_function(&mut self.canvas, app_state);
+        }
+
+        let rows = self.canvas.take_dirty_rows();
+        let row_count = self.canvas.get_cell_size().1;
+        if window.get_row_count() != row_count as usize {
+            return window.try_update_lines((0..row_count).map(|row| self.canvas.render_row(row)).collect());
+        }
+        for row in &rows {
+            window.update_line(*row as usize, self.canvas.render_row(*row));
+        }
+        !rows.is_empty()
+    }
+
+    /// Returns the indices of child widgets in the scene graph.
+    fn get_children_indexes(&self) -> Vec<usize> {
+        self.children.clone()
+    }
+
+    /// Adds a child widget index to this widget.
+    fn add_child_index(&mut self, index: usize) {
+        self.children.push(index);
+    }
+
+    /// Removes a child widget index from this widget
+    fn remove_child_index(&mut self, index: usize) {
+        self.children.remove(index);
+    }
+
+    /// Clears all child widget indices from this widget
+    fn clear_children_indexes(&mut self) {
+        self.children.clear();
+    }
+
+    /// Returns the parent widget index if one exists, otherwise None.
+    fn get_parent_index(&self) -> Option<usize> {
+        self.parent_index
+    }
+
+    /// Sets the parent widget index for this widget, or None for a root node.
+    fn set_parent_index(&mut self, index: Option<usize>) {
+        self.parent_index = index;
+    }
+
+    /// Determines if a given position collides with the widget's area.
+    fn is_collided(&self, position: (u16, u16)) -> bool {
+        self.size_and_position.is_collided(position)
+    }
+
+    /// Returns the widget as `Any` for downcasting.
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget as a mutable `Any` for downcasting.
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget's size and position configuration.
+    fn get_size_and_position(&self) -> Option<&SizeAndPosition> {
+        Some(&self.size_and_position)
+    }
+
+    /// Returns the widget's size and position configuration mutably.
+    fn get_size_and_position_mut(&mut self) -> Option<&mut SizeAndPosition> {
+        Some(&mut self.size_and_position)
+    }
+
+    /// Returns the keywords the widget's window is tagged with.
+    fn get_keywords(&self) -> &[String] {
+        &self.keywords
+    }
+
+    /// Returns the tooltip shown while the mouse rests over the widget, if any.
+    fn get_tooltip(&self) -> Option<&Tooltip<C>> {
+        self.tooltip.as_ref()
+    }
+
+    /// Applies the offset and clip inherited from the parent widgets to the size and position.
+    fn set_parent_transform(&mut self, offset: (i16, i16), clip: Option<Region>) {
+        self.size_and_position.parent_offset = offset;
+        self.size_and_position.clip = clip;
+    }
+}
@@ -0,0 +1,285 @@
+use crate::widget_impls::*;
+use crate::widget::*;
+
+/// The action a context menu item runs when it's chosen. It gets the same access to the app data and app
+/// as the main callback.
+pub type ContextMenuAction<C> = Box<dyn FnMut(&mut C, &mut crate::App<C>)>;
+/// An item of a context menu: the label it's drawn with and the action it runs (see `Scene::open_context_menu`).
+pub type ContextMenuItem<C> = (crate::render::Span, ContextMenuAction<C>);
+/// The items of a context menu, shared between the menu and whatever opens it (i.e. a button opening the
+/// same menu every time it's right clicked).
+pub(crate) type SharedContextMenuItems<C> = std::rc::Rc<std::cell::RefCell<Vec<ContextMenuItem<C>>>>;
+
+/// The depth context menus are drawn at, so they sit above the rest of the UI (including dialogs).
+pub const CONTEXT_MENU_DEPTH: u16 = 200;
+
+/// Counts the context menus opened, giving each one's window a unique name.
+static NEXT_CONTEXT_MENU: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+/// A bordered popup listing actions, opened at a position (usually where the mouse was right clicked)
+/// through `Scene::open_context_menu` rather than a builder. The item under the mouse is highlighted, and
+/// left clicking it runs its action and closes the menu. Escape, or a click anywhere outside the menu,
+/// closes it without running anything. Only one context menu is open at a time.
+///
+/// The menu is drawn at `CONTEXT_MENU_DEPTH`, above everything else, so it hit-tests against its own
+/// bounds rather than the scene (it can't be blocked, even by a modal dialog or the widget which opened it).
+/// # Example:
+/// ```
+/// use term_render::widget_impls::{ButtonWidgetBuilder, ContextMenu, WidgetBuilder};
+/// use term_render::event_handler::{KeyCode, MouseEvent, MouseEventType, MouseState};
+/// use term_render::render::{Colored, Span};
+/// use term_render::widget::Scene;
+/// type Log = Vec<&'static str>;
+/// let item = |label: &str, action: &'static str| -> (Span, Box<dyn FnMut(&mut Log, &mut term_render::App<Log>)>) {
+///     (Span::from_tokens(vec![Colored::new(String::from(label))]), Box::new(move |log: &mut Log, _app| log.push(action)))
+/// };
+/// let mut app = term_render::App::<Log>::new_headless(30, 10);
+/// let mut scene = Scene::new();
+/// ButtonWidgetBuilder::<Log>::builder(String::from("button"))
+///     .with_context_menu(vec![item("Copy", "copy"), item("Paste", "paste")])
+///     .with_position((0, 0))
+///     .with_size((10, 3))
+///     .add_to_scene(&mut app, &mut scene)
+///     .unwrap();
+/// let mut log = vec![];
+/// // runs a frame with the given mouse event and key
+/// let frame = |app: &mut term_render::App<Log>, scene: &mut Scene<Log>, log: &mut Log, mouse: Option<(MouseEventType, MouseState, (u16, u16))>, key: Option<KeyCode>| {
+///     app.events.write().mouse_event = mouse.map(|(event_type, state, position)| {
+///         MouseEvent { event_type, position, state, click_count: 1 }
+///     });
+///     if let Some(key) = key {  app.events.write().key_events.insert(key, true);  }
+///     scene.update_all_widgets(app, log).unwrap();
+///     app.events.write().clear_events();
+///     app.renderer.write().snapshot()
+/// };
+/// use MouseEventType::{Left, Right, Null};
+/// use MouseState::{Press, Release, Hold};
+///
+/// // right clicking the button opens the menu where it was released, sized to the longest item
+/// frame(&mut app, &mut scene, &mut log, Some((Right, Press, (2, 1))), None);
+/// let lines = frame(&mut app, &mut scene, &mut log, Some((Right, Release, (2, 1))), None);
+/// assert_eq!(lines[1..5], ["  ┌─────┐", "  │Copy │", "  │Paste│", "  └─────┘"]);
+///
+/// // hovering an item highlights it, and clicking it runs its action and closes the menu
+/// frame(&mut app, &mut scene, &mut log, Some((Null, Hold, (4, 3))), None);
+/// let menu = scene.get_context_menu().unwrap();
+/// let highlighted = scene.widget_as_ref(menu).unwrap().as_any().downcast_ref::<ContextMenu<Log>>().unwrap().get_highlighted();
+/// assert_eq!(highlighted, Some(1));
+/// frame(&mut app, &mut scene, &mut log, Some((Left, Press, (4, 3))), None);
+/// assert_eq!(scene.get_context_menu(), None);
+/// assert_eq!(frame(&mut app, &mut scene, &mut log, None, None)[2], "");
+///
+/// // a menu which would overflow the bottom right corner flips to the left and up
+/// let items = vec![item("Rename", "rename"), item("Delete", "delete")];
+/// scene.open_context_menu((27, 8), items, &mut app).unwrap();
+/// let lines = frame(&mut app, &mut scene, &mut log, None, None);
+/// assert_eq!(lines[5..9], ["                    ┌──────┐", "                    │Rename│", "                    │Delete│", "                    └──────┘"]);
+///
+/// // opening another menu closes the first, and so does a click outside of it (or Escape)
+/// scene.open_context_menu((12, 0), vec![item("Copy", "copy")], &mut app).unwrap();
+/// assert_eq!(frame(&mut app, &mut scene, &mut log, None, None)[6], "");
+/// frame(&mut app, &mut scene, &mut log, Some((Left, Press, (20, 8))), None);
+/// assert_eq!(scene.get_context_menu(), None);
+/// scene.open_context_menu((12, 0), vec![item("Copy", "copy")], &mut app).unwrap();
+/// frame(&mut app, &mut scene, &mut log, None, Some(KeyCode::Escape));
+/// assert_eq!(scene.get_context_menu(), None);
+/// assert_eq!(log, vec!["paste"]);
+/// ```
+pub struct ContextMenu<C> {
+    /// The indices of child widgets in the scene graph.
+    children: Vec<usize>,
+
+    /// The unique name identifier for the widget. The rendering backend
+    /// relies on `String` names instead of widgets.
+    name: String,
+
+    /// Configuration for the widget's size and position (fixed once the menu is opened).
+    pub size_and_position: SizeAndPosition,
+
+    /// The labels of the items, with the actions they run.
+    items: SharedContextMenuItems<C>,
+    /// The index of the item under the mouse, if any.
+    highlighted: Option<usize>,
+    /// Whether the menu was closed (it's removed once the scene finishes updating).
+    closed: bool,
+
+    /// The keywords the widget's window is tagged with (always empty).
+    keywords: Vec<String>,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+impl<C> ContextMenu<C> {
+    /// Creates a menu (and its window) for the items at the given position. The menu's top left corner is
+    /// placed at the position, unless it would overflow the terminal, in which case it flips to the left
+    /// (and/or up) so that its opposite corner is at the position instead.
+    pub(crate) fn new(position: (u16, u16), items: SharedContextMenuItems<C>, area: &crate::render::Rect) -> Result<(ContextMenu<C>, crate::render::Window), WidgetError> {
+        let width = items.borrow().iter().map(|(label, _action)| label.size()).max().ok_or(WidgetError::new("A context menu needs at least one item"))?;
+        let size = (width as u16 + 2, items.borrow().len() as u16 + 2);
+        let flip = |position: u16, size: u16, area: u16| {
+            if position + size > area {  (position + 1).saturating_sub(size)  } else {  position  }
+        };
+        let position = (flip(position.0, size.0, area.width), flip(position.1, size.1, area.height));
+        let mut window = crate::render::Window::new(position, CONTEXT_MENU_DEPTH, size);
+        window.bordered();
+        let menu = ContextMenu {
+            children: vec![],
+            name: format!("context menu {}", NEXT_CONTEXT_MENU.fetch_add(1, std::sync::atomic::Ordering::Relaxed)),
+            size_and_position: SizeAndPosition::new_static(size, position),
+            items,
+            highlighted: None,
+            closed: false,
+            keywords: vec![],
+            __phantom: std::marker::PhantomData,
+        };
+        // the scene doesn't update widgets added partway through an update until the next one, so the menu
+        // is drawn up front (otherwise, opening it from a button would leave it empty for a frame)
+        window.from_lines(menu.get_lines(width));
+        Ok((menu, window))
+    }
+
+    /// Gets the lines of the items, with the highlighted one reversed and padded out to the given width.
+    fn get_lines(&self, width: usize) -> Vec<crate::render::Span> {
+        self.items.borrow().iter().enumerate().map(|(index, (label, _action))| {
+            let mut line = label.clone();
+            if self.highlighted == Some(index) {
+                line.push(crate::render::Colored::new(" ".repeat(width.saturating_sub(line.size()))));
+                line.add_color(crate::render::ColorType::Reverse);
+            } line
+        }).collect()
+    }
+
+    /// Returns the index of the item under the mouse, if any.
+    pub fn get_highlighted(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    /// Returns the index of the item drawn at the given terminal position, if there is one.
+    pub fn get_item_at(&self, position: (u16, u16)) -> Option<usize> {
+        let (size, menu_position) = self.size_and_position.get_last();
+        // the items fill the inside of the border, one per row
+        if position.0 <= menu_position.0 || position.0 + 1 >= menu_position.0 + size.0 ||
+            position.1 <= menu_position.1 || position.1 + 1 >= menu_position.1 + size.1
+        {
+            return None;
+        }
+        Some((position.1 - menu_position.1 - 1) as usize)
+    }
+
+    /// Closes the menu, queueing it for removal. Nothing happens if it was already closed.
+    fn close(&mut self, scene: &mut Scene<C>) {
+        if self.closed {  return;  }
+        self.closed = true;
+        let Some(own_index) = scene.get_widget_index(self.name.clone()) else {  return;  };
+        scene.forget_context_menu(own_index);
+        scene.queue_removal(own_index);
+    }
+}
+
+/// Implementation of the methods for ContextMenu
+impl<C: 'static> Widget<C> for ContextMenu<C> {
+    /// Returns the widget's name as an identifier. The rendering backend relies
+    /// on `String` names instead of widgets.
+    fn get_window_ref(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Highlights the item under the mouse, runs the item which is left clicked, and closes the menu
+    /// on Escape or a click outside of it.
+    fn update_with_events(&mut self, data: &mut C, app: &mut crate::App<C>, scene: &mut Scene<C>) {
+        use crate::event_handler::{KeyCode, MouseEventType, MouseState};
+        if self.closed {  return;  }
+        let _ = self.size_and_position.get_size_and_position(&app.area.read());
+        let events = app.events.read();
+        if let Some(position) = events.mouse_event.as_ref().map(|event| event.position).or(events.last_mouse_position()) {
+            self.highlighted = self.get_item_at(position);
+        }
+        let escaped = events.contains_key_code(KeyCode::Escape);
+        let clicked = events.mouse_event.as_ref()
+            .filter(|event| event.state == MouseState::Press)
+            .map(|event| (event.event_type.clone(), event.position));
+        drop(events);
+
+        let chosen = match clicked {
+            Some((event_type, position)) if self.size_and_position.is_collided(position) => {
+                // clicking the border does nothing
+                let Some(index) = self.get_item_at(position) else {  return;  };
+                if event_type != MouseEventType::Left {  return;  }
+                Some(index)
+            },
+            Some(_) => None,
+            None if escaped => None,
+            None => return,
+        };
+        if let Some(index) = chosen && let Some((_label, action)) = self.items.borrow_mut().get_mut(index) {
+            action(data, app);
+        }
+        self.close(scene);
+    }
+
+    /// Draws the items.
+    fn update_render(&mut self, window: &mut crate::render::Window, area: &crate::render::Rect, _app_state: &mut C) -> bool {
+        let (size, position) = self.size_and_position.get_size_and_position(area);
+        window.resize(size);
+        window.r#move(position);
+        window.try_update_lines(self.get_lines(size.0.saturating_sub(2) as usize))
+    }
+
+    /// Returns the indices of child widgets in the scene graph.
+    fn get_children_indexes(&self) -> Vec<usize> {
+        self.children.clone()
+    }
+
+    /// Adds a child widget index to this widget.
+    fn add_child_index(&mut self, index: usize) {
+        self.children.push(index);
+    }
+
+    /// Removes a child widget index from this widget
+    fn remove_child_index(&mut self, index: usize) {
+        self.children.remove(index);
+    }
+
+    /// Clears all child widget indices from this widget
+    fn clear_children_indexes(&mut self) {
+        self.children.clear();
+    }
+
+    /// Context menus are always root widgets.
+    fn get_parent_index(&self) -> Option<usize> {
+        None
+    }
+
+    /// Context menus are always root widgets, so this does nothing.
+    fn set_parent_index(&mut self, _index: Option<usize>) {}
+
+    /// Determines if a given position collides with the widget's area.
+    fn is_collided(&self, position: (u16, u16)) -> bool {
+        self.size_and_position.is_collided(position)
+    }
+
+    /// Returns the widget as `Any` for downcasting.
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget as a mutable `Any` for downcasting.
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget's size and position configuration.
+    fn get_size_and_position(&self) -> Option<&SizeAndPosition> {
+        Some(&self.size_and_position)
+    }
+
+    /// Returns the widget's size and position configuration mutably.
+    fn get_size_and_position_mut(&mut self) -> Option<&mut SizeAndPosition> {
+        Some(&mut self.size_and_position)
+    }
+
+    /// Returns the keywords the widget's window is tagged with.
+    fn get_keywords(&self) -> &[String] {
+        &self.keywords
+    }
+}
@@ -0,0 +1,738 @@
+use crate::widget_impls::*;
+use crate::widget::*;
+
+type RenderFunction = Vec<crate::render::Span>;
+/// The callback a dialog reports the chosen button's index to, once, as it closes.
+pub type DialogCallback<C> = Box<dyn FnMut(&mut C, usize)>;
+
+/// The depth dialogs are drawn at unless another one is given, so they sit above the rest of the UI.
+pub const DIALOG_DEPTH: u16 = 100;
+
+/// The blank space between a dialog's border and its message, unless other padding is given.
+const DIALOG_PADDING: crate::render::Padding = crate::render::Padding { left: 2, right: 2, top: 1, bottom: 1 };
+
+/// Builder for creating DialogWidget instances with a fluent interface.
+/// Maintains configuration state until build() is called to create the actual widget.
+///
+/// Unlike the other builders, the defaults describe a finished dialog: it's bordered, centered on the
+/// terminal, drawn at `DIALOG_DEPTH`, and sized to fit its message and buttons. The message is set
+/// through `with_message` (or `with_renderer` for several lines), and the chosen button is reported
+/// through the closure given to `with_update_handler`.
+pub struct DialogBuilder<C> {
+    /// The unique name identifier for the widget.
+    name: String,
+    /// The z-index depth of the widget; higher values render on top of lower ones.
+    depth: Option<u16>,
+    /// Whether the widget should have a border.
+    border: bool,
+    /// The title of the widget, if any.
+    title: Option<String>,
+    /// The theme slot coloring the border and title, if any.
+    theme_slot: Option<String>,
+    /// The glyphs the border is drawn with.
+    border_style: crate::render::BorderStyle,
+    /// The blank space between the border (or edge) and the content.
+    padding: crate::render::Padding,
+    /// Where the title sits along the top border.
+    title_alignment: crate::render::Alignment,
+    /// The title drawn in the bottom border and its alignment, if any.
+    bottom_title: Option<(String, crate::render::Alignment)>,
+    /// The size and position configuration for the widget.
+    pub size_and_position: SizeAndPosition,
+    /// The lines of the message shown above the buttons.
+    pub message: Vec<crate::render::Span>,
+    /// The index of the parent widget in the scene graph, if any.
+    parent: Option<usize>,
+    /// The labels of the buttons, from left to right.
+    buttons: Vec<String>,
+    /// The button highlighted when the dialog opens.
+    default_button: usize,
+    /// The button chosen by Escape (the last one if None).
+    cancel_button: Option<usize>,
+    /// Called with the chosen button's index as the dialog closes.
+    callback: Option<DialogCallback<C>>,
+
+    /// The keywords the widget's window is tagged with.
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+    /// The colors and modifiers the whole window is painted with (i.e. the border's color).
+    colors: Vec<crate::render::ColorType>,
+    /// Whether the window starts hidden.
+    hidden: bool,
+    /// The layer the window is drawn in.
+    layer: crate::render::Layer,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+impl<C> DialogBuilder<C> {
+    /// Creates a message box: a dialog with the given message and a single "OK" button.
+    pub fn message_box(name: String, message: crate::render::Span) -> Self where C: 'static {
+        Self::builder(name)
+            .with_message(message)
+            .with_buttons(vec![String::from("OK")])
+    }
+
+    /// Creates a confirm prompt: a dialog with the given message and "Yes" and "No" buttons.
+    /// Choosing "Yes" reports 0, while "No" (or Escape) reports 1.
+    pub fn confirm(name: String, message: crate::render::Span) -> Self where C: 'static {
+        Self::builder(name)
+            .with_message(message)
+            .with_buttons(vec![String::from("Yes"), String::from("No")])
+    }
+
+    /// Sets the (single line) message shown above the buttons.
+    pub fn with_message(mut self, message: crate::render::Span) -> Self {
+        self.message = vec![message];
+        self
+    }
+
+    /// Sets the labels of the buttons, from left to right. By default, there's a single "OK" button.
+    pub fn with_buttons(mut self, buttons: Vec<String>) -> Self {
+        self.buttons = buttons;
+        self
+    }
+
+    /// Sets the button highlighted when the dialog opens (the first one by default).
+    pub fn with_default_button(mut self, index: usize) -> Self {
+        self.default_button = index;
+        self
+    }
+
+    /// Sets the button Escape chooses (the last one by default).
+    pub fn with_cancel_button(mut self, index: usize) -> Self {
+        self.cancel_button = Some(index);
+        self
+    }
+
+    /// Returns the size which fits the message and the row of buttons within the border and padding.
+    fn get_fitted_size(&self) -> (u16, u16) {
+        let buttons = DialogWidget::<C>::get_button_row(&self.buttons, None, 0).size();
+        let message = self.message.iter().map(|line| line.size()).max().unwrap_or(0);
+        let border = self.border as u16 * 2;
+        (
+            message.max(buttons) as u16 + border + self.padding.left + self.padding.right,
+            // a blank row separates the message from the buttons
+            self.message.len() as u16 + 2 + border + self.padding.top + self.padding.bottom,
+        )
+    }
+}
+
+/// Implementations for the methods in `WidgetBuilder`.
+impl<C: 'static> WidgetBuilder<C> for DialogBuilder<C> {
+    /// Constructs a `DialogWidget`, an implementor of `Widget`, given the parameters.
+    /// Unless a size was given, the dialog is sized to fit its message and buttons.
+    /// Validates that the size is non-zero before creating the widget.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{DialogBuilder, WidgetBuilder};
+    /// use term_render::render::{Rect, Span, Colored};
+    /// # struct AppData;
+    /// let (widget, window) = DialogBuilder::<AppData>::confirm(String::new(), Span::from_tokens(vec![Colored::new(String::from("Delete the file?"))]))
+    ///     .build(&Rect { width: 80, height: 24 })  // replace &Rect with the actual terminal size (such as `&app.area.read()`)
+    ///     .expect("Invalid widget position or size.");
+    /// // the border, the padding, the message, a blank row, and the buttons
+    /// assert_eq!(window.size, (22, 7));
+    /// ```
+    fn build(mut self, display_area: &crate::render::Rect) -> Result<(Box<dyn Widget<C>>, crate::render::Window), WidgetBuilderError> {
+        if self.buttons.is_empty() {
+            return Err(WidgetBuilderError::new("A dialog needs at least one button"))
+        }
+        if self.size_and_position.size_offset == (0, 0) && self.size_and_position.size_area_percent == (0.0, 0.0) {
+            let size = self.get_fitted_size();
+            self.size_and_position.size_offset = (size.0 as i16, size.1 as i16);
+        }
+        let (size, position) = self.size_and_position.get_size_and_position(display_area);
+        if size.0 == 0 || size.1 == 0 {
+            return Err(WidgetBuilderError::InvalidGeometry { size, position })
+        }
+        let depth = self.depth.as_ref().unwrap_or(&DIALOG_DEPTH);
+        let mut window = crate::render::Window::new(position, *depth, size);
+        if self.border {  window.bordered();  }
+        window.set_theme_slot(self.theme_slot.clone());
+        window.set_border_style(self.border_style);
+        window.set_padding(self.padding);
+        if let Some(title) = &self.title {  window.titled(title.clone());  }
+        window.colorizes(std::mem::take(&mut self.colors));
+        if self.hidden {  window.hide();  }
+        window.set_layer(self.layer);
+        window.set_title_alignment(self.title_alignment);
+        if let Some((title, alignment)) = &self.bottom_title {
+            window.bottom_titled(crate::render::Span::from_tokens(vec![crate::render::Colored::new(title.clone())]));
+            window.set_bottom_title_alignment(*alignment);
+        }
+        let last = self.buttons.len() - 1;
+        Ok((Box::new(DialogWidget::<C> {
+            children: vec![],
+            name: self.name,
+            parent_index: self.parent,
+            size_and_position: self.size_and_position,
+            border: self.border,
+            padding: self.padding,
+            message: self.message,
+            highlighted: self.default_button.min(last),
+            cancel_button: self.cancel_button.unwrap_or(last).min(last),
+            buttons: self.buttons,
+            callback: self.callback,
+            opened: None,
+            closed: false,
+            selected: false,
+            keywords: self.keywords,
+            tooltip: self.tooltip,
+            __phantom: std::marker::PhantomData,
+        }), window))
+    }
+
+    /// Sets the widget's fixed position (static layout).
+    /// This replaces the default centering, placing the dialog's center at the position.
+    fn with_position(mut self, position: (u16, u16)) -> Self {
+        self.size_and_position.position_offset = (position.0 as i16, position.1 as i16);
+        self.size_and_position.position_area_percent = (0.0, 0.0);
+        self
+    }
+
+    /// Sets the widget's fixed size, rather than fitting it to the message and buttons.
+    fn with_size(mut self, size: (u16, u16)) -> Self {
+        self.size_and_position.size_offset = (size.0 as i16, size.1 as i16);
+        self
+    }
+
+    /// Configures dynamic positioning based on terminal size with a fixed offset.
+    fn with_dynamic_position(mut self, position_offset: (i16, i16), position_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.position_offset = position_offset;
+        self.size_and_position.position_area_percent = position_area_percent;
+        self
+    }
+
+    /// Configures dynamic sizing based on terminal size with a fixed offset.
+    fn with_dynamic_size(mut self, size_offset: (i16, i16), size_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.size_offset = size_offset;
+        self.size_and_position.size_area_percent = size_area_percent;
+        self
+    }
+
+    /// Sets whether the widget should have a border. By default, dialogs are bordered.
+    fn with_border(mut self, border: bool) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Sets the widget's title (displayed in border if enabled; invisible otherwise).
+    fn with_title(mut self, title: String) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Assigns a depth to the widget. By default, dialogs are drawn at `DIALOG_DEPTH`.
+    fn with_depth(mut self, depth: u16) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// The type representing the renderer closure.
+    type RendererType = RenderFunction;
+    /// Sets the lines of the message shown above the buttons (see `with_message` for a single line).
+    fn with_renderer(mut self, renderer: Self::RendererType) -> Self {
+        self.message = renderer;
+        self
+    }
+
+    /// Generates a new builder instance with a provided unique name identifier.
+    /// The dialog starts out bordered, centered, and with a single "OK" button.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{DialogBuilder, WidgetBuilder};
+    /// # struct AppData;
+    /// let builder = DialogBuilder::<AppData>::builder(String::from("Widget Name"));
+    /// ```
+    fn builder(name: String) -> Self {
+        let mut size_and_position = SizeAndPosition::new_dynamic((0, 0), (0, 0), (0.0, 0.0), (0.5, 0.5));
+        size_and_position.anchor = Anchor::Center;
+        Self {
+            name,
+            depth: None,
+            size_and_position,
+            message: vec![],
+            border: true,
+            title: None,
+            theme_slot: None,
+            border_style: crate::render::BorderStyle::Plain,
+            padding: DIALOG_PADDING,
+            title_alignment: crate::render::Alignment::Center,
+            bottom_title: None,
+            parent: None,
+            buttons: vec![String::from("OK")],
+            default_button: 0,
+            cancel_button: None,
+            callback: None,
+            keywords: vec![],
+            tooltip: None,
+            colors: vec![],
+            hidden: false,
+            layer: crate::render::Layer::Normal,
+            __phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the SizeAndPosition configuration directly.
+    fn with_sap(mut self, sap: SizeAndPosition) -> Self {
+        self.size_and_position = sap;
+        self
+    }
+
+    /// Sets which point of the widget is placed at its computed position (the center by default).
+    fn with_anchor(mut self, anchor: Anchor) -> Self {
+        self.size_and_position.anchor = anchor;
+        self
+    }
+
+    /// Sets the smallest size the widget can shrink to.
+    fn with_min_size(mut self, min_size: (u16, u16)) -> Self {
+        self.size_and_position.min_size = Some(min_size);
+        self
+    }
+
+    /// Sets the largest size the widget can grow to.
+    fn with_max_size(mut self, max_size: (u16, u16)) -> Self {
+        self.size_and_position.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets whether the widget is positioned relative to its parent's window.
+    fn with_relative_to_parent(mut self, relative: bool) -> Self {
+        self.size_and_position.position_mode = match relative {
+            true => PositionMode::RelativeToParent,
+            false => PositionMode::Absolute,
+        };
+        self
+    }
+
+    /// Sets whether the widget is clipped to its parent's window.
+    fn with_clip_to_parent(mut self, clip: bool) -> Self {
+        self.size_and_position.clip_to_parent = clip;
+        self
+    }
+
+    /// Slides the widget into place from the given offset when it's first drawn.
+    fn with_entrance_animation(mut self, from_offset: (i16, i16), duration: std::time::Duration) -> Self {
+        self.size_and_position.animate_from_offset(from_offset, duration, Easing::EaseOut);
+        self
+    }
+
+    /// Colors the border and title with a slot of the app's theme.
+    fn with_theme_slot(mut self, slot: &str) -> Self {
+        self.theme_slot = Some(slot.to_owned());
+        self
+    }
+
+    /// Sets the glyphs the border is drawn with.
+    fn with_border_style(mut self, style: crate::render::BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Sets the blank space between the border (or edge) and the content.
+    /// By default, dialogs have two columns on either side and a row above and below.
+    fn with_padding(mut self, padding: crate::render::Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets where the title sits along the top border.
+    fn with_title_alignment(mut self, alignment: crate::render::Alignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Sets the title drawn in the bottom border and its alignment.
+    fn with_bottom_title(mut self, title: String, alignment: crate::render::Alignment) -> Self {
+        self.bottom_title = Some((title, alignment));
+        self
+    }
+
+    type FunctionType = Option<DialogCallback<C>>;
+    /// Sets the closure called with the index of the chosen button as the dialog closes.
+    fn with_update_handler(mut self, handler: Self::FunctionType) -> Self {
+        self.callback = handler;
+        self
+    }
+
+    /// Sets the parent widget index for this widget, if any.
+    /// By default, the parent is None, indicating a root node.
+    fn with_parent(mut self, parent: Option<usize>) -> Self {
+        self.parent = parent;
+        self
+    }
+
+    /// Tags the widget's window with the given keywords (see `Scene::widgets_by_keyword`).
+    fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Gives the widget a tooltip, shown once the mouse rests over it (see `Scene::set_tooltip_delay`).
+    fn with_tooltip(mut self, tooltip: impl Into<Tooltip<C>>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Paints the whole window with the colors and modifiers (see `Window::colorizes`).
+    fn with_colors(mut self, colors: Vec<crate::render::ColorType>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Sets whether the widget starts hidden (see `Scene::set_widget_visible`).
+    fn with_hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Sets the layer the widget's window is drawn in (see `Window::set_layer`).
+    fn with_layer(mut self, layer: crate::render::Layer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Builds the dialog and adds it to the provided scene, returning the new widget's index in the
+    /// scene graph. The dialog becomes the scene's modal widget (and takes the focus) immediately.
+    /// If building the widget fails, an error is returned instead.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{DialogBuilder, WidgetBuilder};
+    /// use term_render::render::{Span, Colored};
+    /// # struct AppData { quitting: bool }
+    /// let mut app = term_render::App::<AppData>::new_headless(80, 24);
+    /// let mut scene = term_render::widget::Scene::new();
+    /// let widget_index = DialogBuilder::<AppData>::confirm(String::from("quit"), Span::from_tokens(vec![Colored::new(String::from("Quit?"))]))
+    ///     .with_update_handler(Some(Box::new(|data: &mut AppData, choice| data.quitting = choice == 0)))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .expect("Failed to build and add widget to scene.");
+    /// ```
+    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetError> {
+        let name = self.name.clone();
+        let (widget, window) = self.build(&app.area.read())?;
+        let index = scene.add_widget(widget, window, &mut app.renderer.write())?;
+        let opened = (scene.get_modal(), scene.focused_widget());
+        scene.set_modal(Some(index));
+        if let Some(dialog) = scene.widget_as_mut::<DialogWidget<C>>(&name) {
+            dialog.opened = Some(opened);
+        } Ok(index)
+    }
+}
+
+/// A modal dialog: a message above a row of buttons, one of which is highlighted.
+///
+/// While it's open, the dialog is the scene's modal widget (see `Scene::set_modal`), so the widgets
+/// beneath it can't be clicked or focused. Left/Right and Tab/Shift+Tab move the highlight, Return
+/// chooses the highlighted button, Escape chooses the cancel button, and clicking a button chooses it.
+/// Once a button is chosen, its index is passed to the callback, the previous modal widget and focus
+/// are restored, and the dialog (along with any children) is removed from the scene.
+/// # Example
+/// ```
+/// use term_render::widget::Scene;
+/// use term_render::widget_impls::{DialogBuilder, StaticWidgetBuilder, WidgetBuilder};
+/// use term_render::event_handler::KeyCode;
+/// use term_render::render::{Colored, Span};
+///
+/// let mut app = term_render::App::<Vec<usize>>::new_headless(40, 12);
+/// let mut scene = Scene::new();
+/// let main = StaticWidgetBuilder::<Vec<usize>>::builder(String::from("main"))
+///     .with_dynamic_position((0, 0), (0.0, 0.0))
+///     .with_dynamic_size((0, 0), (1.0, 1.0))
+///     .with_renderer(Box::new(|_size, _position, _data| None))
+///     .add_to_scene(&mut app, &mut scene)
+///     .unwrap();
+/// let dialog = DialogBuilder::<Vec<usize>>::confirm(String::from("confirm"), Span::from_tokens(vec![Colored::new(String::from("Overwrite it?"))]))
+///     .with_parent(Some(main))
+///     .with_update_handler(Some(Box::new(|choices: &mut Vec<usize>, choice| choices.push(choice))))
+///     .add_to_scene(&mut app, &mut scene)
+///     .unwrap();
+/// let mut choices = vec![];
+/// let mut frame = |app: &mut term_render::App<Vec<usize>>, scene: &mut Scene<Vec<usize>>, choices: &mut Vec<usize>, key: Option<KeyCode>| {
+///     if let Some(key) = key {  app.events.write().key_events.insert(key, true);  }
+///     scene.update_all_widgets(app, choices).unwrap();
+///     app.events.write().clear_events();
+///     app.renderer.write().snapshot()
+/// };
+///
+/// // the dialog is centered, and everything beneath it is blocked
+/// let lines = frame(&mut app, &mut scene, &mut choices, None);
+/// assert_eq!(lines[6], "         │  [ Yes ]  [ No ]  │");
+/// assert_eq!(scene.focused_widget(), Some(dialog));
+/// assert_eq!(scene.is_click_blocked_all(main, (1, 1), &app), Some(true));
+///
+/// // moving to "No" and choosing it closes the dialog
+/// frame(&mut app, &mut scene, &mut choices, Some(KeyCode::Right));
+/// frame(&mut app, &mut scene, &mut choices, Some(KeyCode::Return));
+/// assert_eq!(choices, vec![1]);
+/// assert!(scene.get_widget_index(String::from("confirm")).is_none());
+/// assert_eq!(scene.get_modal(), None);
+/// assert_eq!(scene.is_click_blocked_all(main, (1, 1), &app), Some(false));
+/// ```
+pub struct DialogWidget<C> {
+    /// The indices of child widgets in the scene graph.
+    children: Vec<usize>,
+
+    /// The unique name identifier for the widget. The rendering backend
+    /// relies on `String` names instead of widgets.
+    name: String,
+
+    /// The index of the parent widget in the scene graph, if any (None would
+    /// indicate the root node).
+    parent_index: Option<usize>,
+
+    /// Configuration for the widget's size and position, supporting both static and dynamic layouts.
+    pub size_and_position: SizeAndPosition,
+
+    border: bool,
+    padding: crate::render::Padding,
+
+    /// The lines of the message shown above the buttons.
+    message: Vec<crate::render::Span>,
+    /// The labels of the buttons, from left to right.
+    buttons: Vec<String>,
+    /// The index of the highlighted button.
+    highlighted: usize,
+    /// The button chosen by Escape.
+    cancel_button: usize,
+    /// Called with the chosen button's index as the dialog closes.
+    callback: Option<DialogCallback<C>>,
+    /// The scene's modal widget and focus from before the dialog opened (None until it has).
+    opened: Option<(Option<usize>, Option<usize>)>,
+    /// Whether a button was chosen (the dialog is removed once the scene finishes updating).
+    closed: bool,
+
+    /// Indicates whether the widget is currently selected (has the scene's focus for keyboard navigation).
+    pub selected: bool,
+
+    /// The keywords the widget's window is tagged with (see `Scene::widgets_by_keyword`).
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+impl<C> DialogWidget<C> {
+    /// Returns the index of the highlighted button.
+    pub fn get_highlighted(&self) -> usize {
+        self.highlighted
+    }
+
+    /// Highlights the button at the given index (clamped to the buttons).
+    pub fn set_highlighted(&mut self, index: usize) {
+        self.highlighted = index.min(self.buttons.len().saturating_sub(1));
+    }
+
+    /// Returns the labels of the buttons, from left to right.
+    pub fn get_buttons(&self) -> &[String] {
+        &self.buttons
+    }
+
+    /// Builds the row of buttons (each as `[ label ]`, two columns apart) with the given one highlighted,
+    /// indented by the given number of columns.
+    fn get_button_row(buttons: &[String], highlighted: Option<usize>, indent: usize) -> crate::render::Span {
+        let mut tokens = vec![crate::render::Colored::new(" ".repeat(indent))];
+        for (index, label) in buttons.iter().enumerate() {
+            if index > 0 {  tokens.push(crate::render::Colored::new(String::from("  ")));  }
+            let mut colors = vec![];
+            if highlighted == Some(index) {  colors = vec![crate::render::ColorType::Reverse, crate::render::ColorType::Bold];  }
+            tokens.push(crate::render::Colored::get_from_color_types_str(&format!("[ {} ]", label), colors));
+        }
+        crate::render::Span::from_tokens(tokens)
+    }
+
+    /// Returns the width of the content area (within the border and padding).
+    fn get_content_width(&self) -> usize {
+        let (size, _) = self.size_and_position.get_last();
+        size.0.saturating_sub(self.border as u16 * 2 + self.padding.left + self.padding.right) as usize
+    }
+
+    /// Returns the columns the button row is indented by to center it.
+    fn get_button_indent(&self) -> usize {
+        let width = Self::get_button_row(&self.buttons, None, 0).size();
+        self.get_content_width().saturating_sub(width) / 2
+    }
+
+    /// Returns the index of the button drawn at the given terminal position, if there is one.
+    pub fn get_button_at(&self, position: (u16, u16)) -> Option<usize> {
+        if !self.size_and_position.is_collided(position) {  return None;  }
+        let (size, widget_position) = self.size_and_position.get_last();
+        // the buttons are on the last row of the content
+        let row = (widget_position.1 + size.1).saturating_sub(self.border as u16 + self.padding.bottom + 1);
+        if position.1 != row {  return None;  }
+        let mut left = (widget_position.0 + self.border as u16 + self.padding.left) as usize + self.get_button_indent();
+        for (index, label) in self.buttons.iter().enumerate() {
+            let width = crate::render::Span::from_tokens(vec![crate::render::Colored::new(format!("[ {} ]", label))]).size();
+            if (left..left + width).contains(&(position.0 as usize)) {  return Some(index);  }
+            left += width + 2;
+        } None
+    }
+
+    /// Chooses the button at the given index: the callback is called, the scene's previous modal widget and
+    /// focus are restored, and the dialog is queued for removal. Nothing happens if it was already closed.
+    fn choose(&mut self, index: usize, data: &mut C, scene: &mut Scene<C>) {
+        if self.closed {  return;  }
+        self.closed = true;
+        if let Some(callback) = &mut self.callback {
+            callback(data, index);
+        }
+        let Some(own_index) = scene.get_widget_index(self.name.clone()) else {  return;  };
+        if let Some((modal, focus)) = self.opened {
+            scene.set_modal(modal.filter(|modal| *modal != own_index));
+            match focus {
+                Some(focus) if scene.set_focus(focus).is_ok() => {},
+                _ => scene.clear_focus(),
+            }
+        }
+        scene.queue_removal(own_index);
+    }
+}
+
+/// Implementation of the methods for DialogWidget
+impl<C: 'static> Widget<C> for DialogWidget<C> {
+    /// Returns the widget's name as an identifier. The rendering backend relies
+    /// on `String` names instead of widgets.
+    fn get_window_ref(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Becomes the scene's modal widget the first time it's updated (if it wasn't added through
+    /// `add_to_scene`), then handles the highlight and choosing a button through the keyboard and mouse.
+    fn update_with_events(&mut self, data: &mut C, app: &mut crate::App<C>, scene: &mut Scene<C>) {
+        use crate::event_handler::{KeyCode, KeyModifiers, MouseEventType, MouseState};
+        let _ = self.size_and_position.get_size_and_position(&app.area.read());
+        if self.closed {  return;  }
+        let widget_index = scene.get_widget_index(self.get_window_ref()).unwrap_or(0);
+        if self.opened.is_none() {
+            self.opened = Some((scene.get_modal(), scene.focused_widget()));
+            scene.set_modal(Some(widget_index));
+        }
+
+        let events = app.events.read();
+        let mut chosen = None;
+        if let Some(event) = &events.mouse_event && !scene.is_click_blocked_all(widget_index, event.position, app).unwrap_or(false) &&
+            let Some(index) = self.get_button_at(event.position)
+        {
+            // hovering a button highlights it, and clicking it chooses it
+            self.highlighted = index;
+            if event.event_type == MouseEventType::Left && event.state == MouseState::Press {
+                chosen = Some(index);
+            }
+        }
+        if self.selected {
+            let last = self.buttons.len() - 1;
+            let shifted = events.contains_modifier(KeyModifiers::Shift);
+            if events.contains_key_code(KeyCode::Left) || (events.contains_key_code(KeyCode::Tab) && shifted) {
+                self.highlighted = self.highlighted.checked_sub(1).unwrap_or(last);
+            } else if events.contains_key_code(KeyCode::Right) || events.contains_key_code(KeyCode::Tab) {
+                self.highlighted = if self.highlighted == last {  0  } else {  self.highlighted + 1  };
+            }
+            if events.contains_key_code(KeyCode::Return) {
+                chosen = Some(self.highlighted);
+            } else if events.contains_key_code(KeyCode::Escape) {
+                chosen = Some(self.cancel_button);
+            }
+        }
+        drop(events);
+
+        if let Some(index) = chosen {
+            self.choose(index, data, scene);
+        }
+    }
+
+    /// Draws the message with the row of buttons (centered) on the last row.
+    fn update_render(&mut self, window: &mut crate::render::Window, area: &crate::render::Rect, _app_state: &mut C) -> bool {
+        let (size, position) = self.size_and_position.get_size_and_position(area);
+        window.resize(size);
+        window.r#move(position);
+        let rows = size.1.saturating_sub(self.border as u16 * 2 + self.padding.top + self.padding.bottom) as usize;
+        let mut lines = self.message.clone();
+        lines.resize(rows.saturating_sub(1).max(lines.len()), crate::render::Span::default());
+        lines.push(Self::get_button_row(&self.buttons, Some(self.highlighted), self.get_button_indent()));
+        window.try_update_lines(lines)
+    }
+
+    /// Returns the indices of child widgets in the scene graph.
+    fn get_children_indexes(&self) -> Vec<usize> {
+        self.children.clone()
+    }
+
+    /// Adds a child widget index to this widget.
+    fn add_child_index(&mut self, index: usize) {
+        self.children.push(index);
+    }
+
+    /// Removes a child widget index from this widget
+    fn remove_child_index(&mut self, index: usize) {
+        self.children.remove(index);
+    }
+
+    /// Clears all child widget indices from this widget
+    fn clear_children_indexes(&mut self) {
+        self.children.clear();
+    }
+
+    /// Returns the parent widget index if one exists, otherwise None.
+    fn get_parent_index(&self) -> Option<usize> {
+        self.parent_index
+    }
+
+    /// Sets the parent widget index for this widget, or None for a root node.
+    fn set_parent_index(&mut self, index: Option<usize>) {
+        self.parent_index = index;
+    }
+
+    /// Determines if a given position collides with the widget's area.
+    fn is_collided(&self, position: (u16, u16)) -> bool {
+        self.size_and_position.is_collided(position)
+    }
+
+    /// Returns the widget as `Any` for downcasting.
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget as a mutable `Any` for downcasting.
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget's size and position configuration.
+    fn get_size_and_position(&self) -> Option<&SizeAndPosition> {
+        Some(&self.size_and_position)
+    }
+
+    /// Returns the widget's size and position configuration mutably.
+    fn get_size_and_position_mut(&mut self) -> Option<&mut SizeAndPosition> {
+        Some(&mut self.size_and_position)
+    }
+
+    /// Returns the keywords the widget's window is tagged with.
+    fn get_keywords(&self) -> &[String] {
+        &self.keywords
+    }
+
+    /// Returns the tooltip shown while the mouse rests over the widget, if any.
+    fn get_tooltip(&self) -> Option<&Tooltip<C>> {
+        self.tooltip.as_ref()
+    }
+
+    /// Dialogs take the focus as they open, so the keyboard drives them straight away.
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    /// The dialog is selected (responds to the keyboard) while it has the scene's focus.
+    fn set_focused(&mut self, focused: bool) {
+        self.selected = focused;
+    }
+
+    /// Applies the offset and clip inherited from the parent widgets to the size and position.
+    fn set_parent_transform(&mut self, offset: (i16, i16), clip: Option<Region>) {
+        self.size_and_position.parent_offset = offset;
+        self.size_and_position.clip = clip;
+    }
+}
@@ -4,6 +4,10 @@ use crate::widget_impls::*;
 use crate::widget::*;
 
 type RenderFunction<C> = Box<dyn Fn((u16, u16), (u16, u16), &mut C) -> Option<Vec<crate::render::Span>>>;
+type RenderKeyFunction<C> = Box<dyn Fn(&C) -> u64>;
+type UpdateHandler<C> = Box<dyn Fn(&mut dyn Widget<C>, &mut C, &mut crate::App<C>, &mut Scene<C>)>;
+// when the render closure last ran, along with the geometry and key it ran with
+type RenderRecord = (std::time::Instant, ((u16, u16), (u16, u16)), Option<u64>);
 
 /// Builder for creating DynamicWidget instances with a fluent interface.
 /// Maintains configuration state until build() is called to create the actual widget.
@@ -19,6 +23,16 @@ pub struct DynamicWidgetBuilder<C> {
     border: bool,
     /// The title of the widget, if any.
     title: Option<String>,
+    /// The theme slot coloring the border and title, if any.
+    theme_slot: Option<String>,
+    /// The glyphs the border is drawn with.
+    border_style: crate::render::BorderStyle,
+    /// The blank space between the border (or edge) and the content.
+    padding: crate::render::Padding,
+    /// Where the title sits along the top border.
+    title_alignment: crate::render::Alignment,
+    /// The title drawn in the bottom border and its alignment, if any.
+    bottom_title: Option<(String, crate::render::Alignment)>,
     /// The size and position configuration for the widget.
     pub size_and_position: SizeAndPosition,
     /// The custom render function for the widget, if any.
@@ -26,35 +40,128 @@ pub struct DynamicWidgetBuilder<C> {
     /// The update handler function for the widget, if any. This function is called during event updates.
     /// The closure receives references to the widget itself, the event parser, and mutable application data.
     /// In responce, the closure can react to events and modify the widget's state as needed.
-    update_handler: Option<Box<dyn Fn(&mut dyn Widget<C>, &mut C, &mut crate::App<C>, &mut Scene<C>)>>,
+    update_handler: Option<UpdateHandler<C>>,
     /// The index of the parent widget in the scene graph, if any.
     parent: Option<usize>,
 
+    /// The keywords the widget's window is tagged with.
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+    /// The colors and modifiers the whole window is painted with (i.e. the border's color).
+    colors: Vec<crate::render::ColorType>,
+    /// Whether the window starts hidden.
+    hidden: bool,
+    /// The layer the window is drawn in.
+    layer: crate::render::Layer,
+
+    /// The minimum time between calls to the render closure, if any.
+    update_interval: Option<std::time::Duration>,
+    /// The closure returning the version key the render closure is gated on, if any.
+    render_when: Option<RenderKeyFunction<C>>,
+
+    /// The closure run once the widget is added to the scene, if any (see `Widget::on_mount`).
+    on_mount: Option<LifecycleHook<C>>,
+    /// The closure run once the widget is removed from the scene, if any (see `Widget::on_unmount`).
+    on_unmount: Option<LifecycleHook<C>>,
+
     __phantom: std::marker::PhantomData<C>,
 }
 
+impl<C> DynamicWidgetBuilder<C> {
+    /// Limits how often the render closure is called: after rendering, the closure is skipped until
+    /// the interval has elapsed (unless the widget is resized or moved). Useful for content like clocks
+    /// or stats which don't need redrawing every frame. By default, the closure is called every update.
+    pub fn with_update_interval(mut self, interval: std::time::Duration) -> Self {
+        self.update_interval = Some(interval);
+        self
+    }
+
+    /// Sets a "version key" closure gating the render closure, which is then only called when the
+    /// returned key differs from the one it was last rendered with (or the widget is resized or moved).
+    /// The key should change whenever anything the render closure reads does, such as a counter bumped
+    /// alongside the state, or a hash of it.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{DynamicWidgetBuilder, WidgetBuilder};
+    /// use term_render::widget::Scene;
+    ///
+    /// struct AppData {  version: u64, renders: usize  }
+    ///
+    /// let mut app = term_render::App::<AppData>::new_headless(20, 5);
+    /// let mut scene = Scene::new();
+    /// DynamicWidgetBuilder::<AppData>::builder(String::from("Counter"))
+    ///     .with_size((10, 1))
+    ///     .with_renderer(Box::new(|_size, _position, data: &mut AppData| {
+    ///         data.renders += 1;
+    ///         Some(vec![format!("v{}", data.version).as_str().into()])
+    ///     }))
+    ///     .with_render_when(Box::new(|data: &AppData| data.version))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .unwrap();
+    ///
+    /// let mut data = AppData {  version: 0, renders: 0  };
+    /// for _ in 0..100 {  scene.update_all_widgets(&mut app, &mut data).unwrap();  }
+    /// assert_eq!(data.renders, 1);  // nothing changed after the first frame
+    /// data.version += 1;
+    /// scene.update_all_widgets(&mut app, &mut data).unwrap();
+    /// assert_eq!(data.renders, 2);
+    /// ```
+    pub fn with_render_when(mut self, key: Box<dyn Fn(&C) -> u64>) -> Self {
+        self.render_when = Some(key);
+        self
+    }
+
+    /// Sets a closure run once the widget is added to the scene, given the app data and the app, for
+    /// starting anything the widget owns (see `Widget::on_mount` for when it runs).
+    pub fn with_on_mount(mut self, on_mount: LifecycleHook<C>) -> Self {
+        self.on_mount = Some(on_mount);
+        self
+    }
+
+    /// Sets a closure run once the widget is removed from the scene, given the app data and the app, for
+    /// stopping anything started when it was mounted (see `Widget::on_unmount`).
+    pub fn with_on_unmount(mut self, on_unmount: LifecycleHook<C>) -> Self {
+        self.on_unmount = Some(on_unmount);
+        self
+    }
+}
+
 /// Implementations for the methods in `WidgetBuilder`.
 impl<C: 'static> WidgetBuilder<C> for DynamicWidgetBuilder<C> {
     /// Constructs a `DynamicWidget`, an implementor of `Widget`, given the parameters.
-    /// Validates that size and position are non-zero before creating the widget.
+    /// Validates that the size is non-zero before creating the widget.
     /// The method takes in a reference to the terminal's current area/size.
     /// # Example:
     /// ```
     /// use term_render::widget_impls::{DynamicWidgetBuilder, WidgetBuilder};
     /// use term_render::render::Rect;
+    /// # struct AppData;
     /// let (widget, window) = DynamicWidgetBuilder::<AppData>::builder(String::new())
-    ///     .build(&Rect::default())  // replace &Rect with the actual terminal size (such as `&app.area.read()`)
+    ///     .with_size((20, 10))
+    ///     .build(&Rect { width: 80, height: 24 })  // replace &Rect with the actual terminal size (such as `&app.area.read()`)
     ///     .expect("Invalid widget position or size.");
     /// ```
     fn build(mut self, display_area: &crate::render::Rect) -> Result<(Box<dyn Widget<C>>, crate::render::Window), WidgetBuilderError> {
-        let (position, size) = self.size_and_position.get_size_and_position(display_area);
-        if size.0 == 0 || size.1 == 0 || position.0 == 0 || position.1 == 0 {
-            return Err(WidgetBuilderError { details: String::from("Position and/or size cannot be zero when building a new widget or window.") })
+        let (size, position) = self.size_and_position.get_size_and_position(display_area);
+        if size.0 == 0 || size.1 == 0 {
+            return Err(WidgetBuilderError::InvalidGeometry { size, position })
         }
         let depth = self.depth.as_ref().unwrap_or(&0u16);
         let mut window = crate::render::Window::new(position, *depth, size);
         if self.border {  window.bordered();  }
+        window.set_theme_slot(self.theme_slot.clone());
+        window.set_border_style(self.border_style);
+        window.set_padding(self.padding);
         if let Some(title) = &self.title {  window.titled(title.clone());  }
+        window.colorizes(std::mem::take(&mut self.colors));
+        if self.hidden {  window.hide();  }
+        window.set_layer(self.layer);
+        window.set_title_alignment(self.title_alignment);
+        if let Some((title, alignment)) = &self.bottom_title {
+            window.bottom_titled(crate::render::Span::from_tokens(vec![crate::render::Colored::new(title.clone())]));
+            window.set_bottom_title_alignment(*alignment);
+        }
         Ok((Box::new(DynamicWidget::<C> {
             children: vec![],
             name: self.name,
@@ -62,6 +169,13 @@ impl<C: 'static> WidgetBuilder<C> for DynamicWidgetBuilder<C> {
             size_and_position: self.size_and_position,
             render_function: self.render_function,
             update_handler: self.update_handler,
+            keywords: self.keywords,
+            tooltip: self.tooltip,
+            update_interval: self.update_interval,
+            render_when: self.render_when,
+            last_render: None,
+            on_mount: self.on_mount,
+            on_unmount: self.on_unmount,
             __phantom: std::marker::PhantomData,
         }), window))
     }
@@ -126,14 +240,15 @@ impl<C: 'static> WidgetBuilder<C> for DynamicWidgetBuilder<C> {
     /// ```
     /// use term_render::widget_impls::{DynamicWidgetBuilder, WidgetBuilder};
     /// use term_render::render::Rect;
+    /// # struct AppData;
     ///
     /// // the closure can capture local variables to reduce boilerplate
-    /// let closure = Box::new(move |size, position| {
-    ///     None  // this will leave the widget un-updated
-    /// });
     /// let (widget, window) = DynamicWidgetBuilder::<AppData>::builder(String::new())
-    ///     .with_renderer(closure)
-    ///     .build(&Rect::default())
+    ///     .with_renderer(Box::new(move |size, position, data: &mut AppData| {
+    ///         None  // this will leave the widget un-updated
+    ///     }))
+    ///     .with_size((20, 10))
+    ///     .build(&Rect { width: 80, height: 24 })
     ///     .unwrap();
     /// ```
     fn with_renderer(mut self, renderer: Self::RendererType) -> Self {
@@ -147,6 +262,7 @@ impl<C: 'static> WidgetBuilder<C> for DynamicWidgetBuilder<C> {
     /// # Example:
     /// ```
     /// use term_render::widget_impls::{DynamicWidgetBuilder, WidgetBuilder};
+    /// # struct AppData;
     /// let builder = DynamicWidgetBuilder::<AppData>::builder(String::from("Widget Name"));
     /// ```
     fn builder(name: String) -> Self {
@@ -157,8 +273,22 @@ impl<C: 'static> WidgetBuilder<C> for DynamicWidgetBuilder<C> {
             render_function: None,
             border: false,
             title: None,
+            theme_slot: None,
+            border_style: crate::render::BorderStyle::Plain,
+            padding: crate::render::Padding::default(),
+            title_alignment: crate::render::Alignment::Center,
+            bottom_title: None,
             update_handler: None,
             parent: None,
+            keywords: vec![],
+            tooltip: None,
+            colors: vec![],
+            hidden: false,
+            layer: crate::render::Layer::Normal,
+            update_interval: None,
+            render_when: None,
+            on_mount: None,
+            on_unmount: None,
             __phantom: std::marker::PhantomData,
         }
     }
@@ -169,6 +299,75 @@ impl<C: 'static> WidgetBuilder<C> for DynamicWidgetBuilder<C> {
         self
     }
 
+    /// Sets which point of the widget is placed at its computed position.
+    fn with_anchor(mut self, anchor: Anchor) -> Self {
+        self.size_and_position.anchor = anchor;
+        self
+    }
+
+    /// Sets the smallest size the widget can shrink to.
+    fn with_min_size(mut self, min_size: (u16, u16)) -> Self {
+        self.size_and_position.min_size = Some(min_size);
+        self
+    }
+
+    /// Sets the largest size the widget can grow to.
+    fn with_max_size(mut self, max_size: (u16, u16)) -> Self {
+        self.size_and_position.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets whether the widget is positioned relative to its parent's window.
+    fn with_relative_to_parent(mut self, relative: bool) -> Self {
+        self.size_and_position.position_mode = match relative {
+            true => PositionMode::RelativeToParent,
+            false => PositionMode::Absolute,
+        };
+        self
+    }
+
+    /// Sets whether the widget is clipped to its parent's window.
+    fn with_clip_to_parent(mut self, clip: bool) -> Self {
+        self.size_and_position.clip_to_parent = clip;
+        self
+    }
+
+    /// Slides the widget into place from the given offset when it's first drawn.
+    fn with_entrance_animation(mut self, from_offset: (i16, i16), duration: std::time::Duration) -> Self {
+        self.size_and_position.animate_from_offset(from_offset, duration, Easing::EaseOut);
+        self
+    }
+
+    /// Colors the border and title with a slot of the app's theme.
+    fn with_theme_slot(mut self, slot: &str) -> Self {
+        self.theme_slot = Some(slot.to_owned());
+        self
+    }
+
+    /// Sets the glyphs the border is drawn with.
+    fn with_border_style(mut self, style: crate::render::BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Sets the blank space between the border (or edge) and the content.
+    fn with_padding(mut self, padding: crate::render::Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets where the title sits along the top border.
+    fn with_title_alignment(mut self, alignment: crate::render::Alignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Sets the title drawn in the bottom border and its alignment.
+    fn with_bottom_title(mut self, title: String, alignment: crate::render::Alignment) -> Self {
+        self.bottom_title = Some((title, alignment));
+        self
+    }
+
     /// Sets the widget's update handler closure. This closure is called during event updates.
     /// The closure receives references to the widget itself, the event parser, and mutable application data.
     /// By default, there is no update handler, meaning the widget won't respond to events.
@@ -184,6 +383,36 @@ impl<C: 'static> WidgetBuilder<C> for DynamicWidgetBuilder<C> {
         self.parent = parent;
         self
     }
+
+    /// Tags the widget's window with the given keywords (see `Scene::widgets_by_keyword`).
+    fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Gives the widget a tooltip, shown once the mouse rests over it (see `Scene::set_tooltip_delay`).
+    fn with_tooltip(mut self, tooltip: impl Into<Tooltip<C>>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Paints the whole window with the colors and modifiers (see `Window::colorizes`).
+    fn with_colors(mut self, colors: Vec<crate::render::ColorType>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Sets whether the widget starts hidden (see `Scene::set_widget_visible`).
+    fn with_hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Sets the layer the widget's window is drawn in (see `Window::set_layer`).
+    fn with_layer(mut self, layer: crate::render::Layer) -> Self {
+        self.layer = layer;
+        self
+    }
     
     /// Builds the widget and adds it to the provided scene, returning the new widget's index in the scene graph.
     /// This method combines the `build` and `scene.add_widget` calls into one for convenience.
@@ -192,7 +421,8 @@ impl<C: 'static> WidgetBuilder<C> for DynamicWidgetBuilder<C> {
     /// ```
     /// use term_render::widget_impls::{DynamicWidgetBuilder, WidgetBuilder};
     /// use term_render::render::Rect;
-    /// let mut app = term_render::App::new().unwrap();
+    /// # struct AppData;
+    /// let mut app = term_render::App::<AppData>::new_headless(80, 24);
     /// let mut scene = term_render::widget::Scene::new();
     /// let widget_index = DynamicWidgetBuilder::<AppData>::builder(String::from("My Widget"))
     ///     .with_position((5, 5))
@@ -200,12 +430,9 @@ impl<C: 'static> WidgetBuilder<C> for DynamicWidgetBuilder<C> {
     ///     .add_to_scene(&mut app, &mut scene)
     ///     .expect("Failed to build and add widget to scene.");
     /// ```
-    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetErr> {
-        if let Ok((widget, window)) = self.build(&app.area.read()) {
-            scene.add_widget(widget, window, &mut *app.renderer.write())
-        } else {
-            Err(WidgetErr::new("Failed to build and add widget to scene."))
-        }
+    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetError> {
+        let (widget, window) = self.build(&app.area.read())?;
+        scene.add_widget(widget, window, &mut app.renderer.write())
     }
 }
 
@@ -239,7 +466,26 @@ pub struct DynamicWidget<C> {
     pub render_function: Option<RenderFunction<C>>,
 
     /// Optional closure that handles updates to the widget's state.
-    pub update_handler: Option<Box<dyn Fn(&mut dyn Widget<C>, &mut C, &mut crate::App<C>, &mut Scene<C>)>>,
+    pub update_handler: Option<UpdateHandler<C>>,
+
+    /// The keywords the widget's window is tagged with (see `Scene::widgets_by_keyword`).
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+
+    /// The minimum time between calls to the render closure, if any.
+    pub update_interval: Option<std::time::Duration>,
+
+    /// Optional closure returning a version key; the render closure only runs when the key changes.
+    pub render_when: Option<RenderKeyFunction<C>>,
+
+    /// When the render closure last ran, along with the geometry and key it ran with.
+    last_render: Option<RenderRecord>,
+
+    /// The closure run once the widget is added to the scene, if any (see `Widget::on_mount`).
+    on_mount: Option<LifecycleHook<C>>,
+    /// The closure run once the widget is removed from the scene, if any (see `Widget::on_unmount`).
+    on_unmount: Option<LifecycleHook<C>>,
 
     __phantom: std::marker::PhantomData<C>,
 }
@@ -262,10 +508,10 @@ impl<C> DynamicWidget<C> {
                render_function: Option<RenderFunction<C>>,
                depth: u16,
                display_area: &crate::render::Rect,
-    ) -> Result<(DynamicWidget<C>, crate::render::Window), WidgetErr> {
+    ) -> Result<(DynamicWidget<C>, crate::render::Window), WidgetError> {
         let (size, position) = size_and_position.get_size_and_position(display_area);
-        if size.0 == 0 || size.1 == 0 || position.0 == 0 || position.1 == 0 {
-            return Err(WidgetErr::new("Size or position cannot be zero"))
+        if size.0 == 0 || size.1 == 0 {
+            return Err(WidgetError::InvalidGeometry { size, position })
         }
         let window = crate::render::Window::new(position, depth, size);
         
@@ -276,6 +522,13 @@ impl<C> DynamicWidget<C> {
             size_and_position,
             render_function,
             update_handler: None,
+            keywords: vec![],
+            tooltip: None,
+            update_interval: None,
+            render_when: None,
+            last_render: None,
+            on_mount: None,
+            on_unmount: None,
             __phantom: std::marker::PhantomData,
         };
         
@@ -284,13 +537,23 @@ impl<C> DynamicWidget<C> {
 }
 
 /// Implementation of the methods for DynamicWidget
-impl<C> Widget<C> for DynamicWidget<C> {
+impl<C: 'static> Widget<C> for DynamicWidget<C> {
     /// Returns the widget's name as an identifier. The rendering backend relies
     /// on `String` names instead of widgets.
     fn get_window_ref(&self) -> String {
         self.name.clone()
     }
     
+    /// Runs the mount closure, if any (see `with_on_mount`).
+    fn on_mount(&mut self, app: &mut crate::App<C>, data: &mut C) {
+        if let Some(on_mount) = &mut self.on_mount {  on_mount(data, app);  }
+    }
+    
+    /// Runs the unmount closure, if any (see `with_on_unmount`).
+    fn on_unmount(&mut self, app: &mut crate::App<C>, data: &mut C) {
+        if let Some(on_unmount) = &mut self.on_unmount {  on_unmount(data, app);  }
+    }
+    
     /// Handles event updates by invoking the user-provided update handler closure, if any.
     /// The closure receives references to the widget itself, the event parser, and mutable application data.
     /// If no update handler is set, this method performs no action.
@@ -305,18 +568,31 @@ impl<C> Widget<C> for DynamicWidget<C> {
     /// Called automatically during render passes.
     /// If `Some(render_closure)` is provided, that closure will be called.
     /// If the closure returns `Some(Vec<Span>)`, then the rendered content will be set as such.
+    /// While the geometry is unchanged, the closure is skipped until the update interval elapses
+    /// and/or the `render_when` key changes (when either is set).
     fn update_render(&mut self, window: &mut crate::render::Window, area: &crate::render::Rect, app_state: &mut C) -> bool {
         // only needs to change with size
         let (size, position) = self.size_and_position.get_size_and_position(area);
         window.resize(size);
         window.r#move(position);
+        let key = self.render_when.as_ref().map(|render_when| render_when(app_state));
+        if let Some((time, geometry, last_key)) = &self.last_render && *geometry == (size, position) {
+            let waiting = self.update_interval.is_some_and(|interval| time.elapsed() < interval);
+            if waiting || (key.is_some() && key == *last_key) {  return false;  }
+        }
         if let Some(render_function) = &self.render_function {
+            self.last_render = Some((std::time::Instant::now(), (size, position), key));
             if let Some(render) = render_function(size, position, app_state) {
                 return window.try_update_lines(render);
             }
         } false
     }
     
+    /// Without an update handler, there's nothing to do with events.
+    fn consumes_events(&self) -> bool {
+        self.update_handler.is_some()
+    }
+    
     /// Returns the indices of child widgets in the scene graph.
     fn get_children_indexes(&self) -> Vec<usize> {
         self.children.clone()
@@ -349,8 +625,43 @@ impl<C> Widget<C> for DynamicWidget<C> {
     
     /// Determines if a given position collides with the widget's area.
     fn is_collided(&self, position: (u16, u16)) -> bool {
-        let (size, pos) = self.size_and_position.get_last();
-        position.0 >= pos.0 && position.0 < pos.0 + size.0 && position.1 >= pos.1 && position.1 < pos.1 + size.1
+        self.size_and_position.is_collided(position)
+    }
+
+    /// Returns the widget as `Any` for downcasting.
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget as a mutable `Any` for downcasting.
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget's size and position configuration.
+    fn get_size_and_position(&self) -> Option<&SizeAndPosition> {
+        Some(&self.size_and_position)
+    }
+
+    /// Returns the widget's size and position configuration mutably.
+    fn get_size_and_position_mut(&mut self) -> Option<&mut SizeAndPosition> {
+        Some(&mut self.size_and_position)
+    }
+
+    /// Returns the keywords the widget's window is tagged with.
+    fn get_keywords(&self) -> &[String] {
+        &self.keywords
+    }
+
+    /// Returns the tooltip shown while the mouse rests over the widget, if any.
+    fn get_tooltip(&self) -> Option<&Tooltip<C>> {
+        self.tooltip.as_ref()
+    }
+    
+    /// Applies the offset and clip inherited from the parent widgets to the size and position.
+    fn set_parent_transform(&mut self, offset: (i16, i16), clip: Option<Region>) {
+        self.size_and_position.parent_offset = offset;
+        self.size_and_position.clip = clip;
     }
 }
 
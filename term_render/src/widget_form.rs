@@ -0,0 +1,320 @@
+use crate::widget_impls::*;
+use crate::widget::*;
+use crate::layout::{Constraint, Layout};
+
+/// The callback a form's buttons report to when they're clicked, with the clicked button's index
+/// (counted across all of the form's `Buttons` rows) and the typed text of every input by name.
+pub type FormSubmitHandler<C> = Box<dyn Fn(&mut C, usize, &std::collections::HashMap<String, String>)>;
+
+/// A row of a form (see `FormBuilder`), laid out top to bottom in the order they're given.
+#[derive(Clone)]
+pub enum FormRow {
+    /// A line of text, one row tall.
+    Label(crate::render::Span),
+    /// A bordered, three row tall text field. The name is the name of its `TypingWidget` in the scene, and
+    /// the key of its text in `FormBuilder::values`. The placeholder shows until something's typed.
+    Input { name: String, placeholder: crate::render::Span },
+    /// A horizontal line (see `SeparatorWidget`), one row tall.
+    Separator,
+    /// Bordered buttons sharing a three row tall row evenly, with the given labels.
+    Buttons(Vec<crate::render::Span>),
+}
+
+impl FormRow {
+    /// Gets the number of rows the row takes up.
+    fn get_height(&self) -> u16 {
+        match self {
+            FormRow::Label(_) | FormRow::Separator => 1,
+            FormRow::Input { .. } | FormRow::Buttons(_) => 3,
+        }
+    }
+}
+
+/// Builds a form out of existing widgets: a container (a `StaticWidget`) holding a `StaticTextWidget` for
+/// each label, a `TypingWidget` for each input, a `SeparatorWidget` for each separator, and a `ButtonWidget`
+/// for each button, all parented to the container. The rows are stacked inside the container (within its
+/// border, if it has one) following its size and position, so the form can be dynamically sized or moved
+/// like any other widget. Tab moves through the inputs and buttons in the order they're declared
+/// (see `Scene::set_tab_order`).
+///
+/// Unlike the other builders, `add_to_scene` doesn't consume the builder, so it can be kept to read the
+/// inputs back through `values`. The widgets besides the inputs are named after the form, i.e.
+/// `"<form> label 0"`, `"<form> separator 2"`, or `"<form> button 1"` (with the index of the row, or of
+/// the button for buttons).
+/// # Example:
+/// ```
+/// use term_render::widget_impls::{FormBuilder, FormRow, TypingWidget};
+/// use term_render::event_handler::{MouseEvent, MouseEventType, MouseState};
+/// use term_render::render::{Colored, Span};
+/// use term_render::widget::Scene;
+/// let text = |text: &str| Span::from_tokens(vec![Colored::new(String::from(text))]);
+/// let mut app = term_render::App::<Vec<String>>::new_headless(24, 14);
+/// let mut scene = Scene::new();
+/// let form = FormBuilder::<Vec<String>>::new(String::from("signup"))
+///     .with_rows(vec![
+///         FormRow::Label(text("Username")),
+///         FormRow::Input { name: String::from("username"), placeholder: text("...") },
+///         FormRow::Input { name: String::from("email"), placeholder: text("email") },
+///         FormRow::Separator,
+///         FormRow::Buttons(vec![text("Ok"), text("Cancel")]),
+///     ])
+///     .with_border(true)
+///     .with_position((0, 0))
+///     .with_size((24, 13))
+///     .with_submit_handler(Box::new(|log, button, values| {
+///         log.push(format!("{}: {}", button, values["username"]));
+///     }));
+/// form.add_to_scene(&mut app, &mut scene).unwrap();
+/// scene.update_all_widgets(&mut app, &mut vec![]).unwrap();
+/// assert_eq!(app.renderer.write().snapshot(), vec![
+///     "┌──────────────────────┐",
+///     "│Username              │",
+///     "│┌────────────────────┐│",
+///     "││...                 ││",
+///     "│└────────────────────┘│",
+///     "│┌────────────────────┐│",
+///     "││email               ││",
+///     "│└────────────────────┘│",
+///     "│──────────────────────│",
+///     "│┌─────────┐┌─────────┐│",
+///     "││   Ok    ││ Cancel  ││",
+///     "│└─────────┘└─────────┘│",
+///     "└──────────────────────┘",
+///     "",
+/// ]);
+///
+/// // Tab visits the inputs and then the buttons, in the order they were declared
+/// let focusable: Vec<String> = scene.get_tab_order().iter().map(|index| {
+///     scene.widget_as_ref(*index).unwrap().get_window_ref()
+/// }).collect();
+/// assert_eq!(focusable, vec!["username", "email", "signup button 0", "signup button 1"]);
+///
+/// // the typed text is collected by the inputs' names
+/// scene.widget_as_mut::<TypingWidget<Vec<String>>>("username").unwrap().set_text("ferris");
+/// let values = form.values(&scene);
+/// assert_eq!((values["username"].as_str(), values["email"].as_str()), ("ferris", ""));
+///
+/// // clicking a button reports its index along with the values
+/// let mut log = vec![];
+/// for state in [MouseState::Press, MouseState::Release] {
+///     app.events.write().mouse_event = Some(MouseEvent { event_type: MouseEventType::Left, position: (15, 10), state, click_count: 1 });
+///     scene.update_all_widgets(&mut app, &mut log).unwrap();
+///     app.events.write().clear_events();
+/// }
+/// assert_eq!(log, vec!["1: ferris"]);
+/// ```
+pub struct FormBuilder<C> {
+    /// The unique name identifier for the form's container (and the prefix of its widgets' names).
+    name: String,
+    /// The rows of the form, from top to bottom.
+    rows: Vec<FormRow>,
+    /// The size and position configuration for the container.
+    pub size_and_position: SizeAndPosition,
+    /// The z-index depth of the form's widgets; higher values render on top of lower ones.
+    depth: Option<u16>,
+    /// Whether the container has a border.
+    border: bool,
+    /// The title of the container, if any.
+    title: Option<String>,
+    /// The index of the container's parent widget in the scene graph, if any.
+    parent: Option<usize>,
+    /// Called whenever one of the buttons is clicked.
+    submit_handler: Option<std::rc::Rc<FormSubmitHandler<C>>>,
+}
+
+impl<C: 'static> FormBuilder<C> {
+    /// Creates a builder for an empty form with the given unique name.
+    pub fn new(name: String) -> Self {
+        FormBuilder {
+            name,
+            rows: vec![],
+            size_and_position: SizeAndPosition::default(),
+            depth: None,
+            border: false,
+            title: None,
+            parent: None,
+            submit_handler: None,
+        }
+    }
+
+    /// Sets the rows of the form, from top to bottom.
+    pub fn with_rows(mut self, rows: Vec<FormRow>) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Adds a row beneath the others.
+    pub fn with_row(mut self, row: FormRow) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    /// Sets the container's fixed position (static layout).
+    pub fn with_position(mut self, position: (u16, u16)) -> Self {
+        self.size_and_position.position_offset = (position.0 as i16, position.1 as i16);
+        self
+    }
+
+    /// Sets the container's fixed size (static layout).
+    pub fn with_size(mut self, size: (u16, u16)) -> Self {
+        self.size_and_position.size_offset = (size.0 as i16, size.1 as i16);
+        self
+    }
+
+    /// Sets the container's SizeAndPosition configuration directly.
+    pub fn with_sap(mut self, sap: SizeAndPosition) -> Self {
+        self.size_and_position = sap;
+        self
+    }
+
+    /// Sets whether the container has a border. By default, forms are borderless.
+    pub fn with_border(mut self, border: bool) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Sets the container's title (displayed in the border if enabled; invisible otherwise).
+    pub fn with_title(mut self, title: String) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the depth of the form's widgets (0 by default).
+    pub fn with_depth(mut self, depth: u16) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// Sets the parent widget index for the container, if any.
+    pub fn with_parent(mut self, parent: Option<usize>) -> Self {
+        self.parent = parent;
+        self
+    }
+
+    /// Sets the callback the buttons report to as they're clicked (released over, with the left mouse
+    /// button or through the keyboard).
+    pub fn with_submit_handler(mut self, handler: FormSubmitHandler<C>) -> Self {
+        self.submit_handler = Some(std::rc::Rc::new(handler));
+        self
+    }
+
+    /// Gets the names of the inputs, in the order they're declared.
+    pub fn get_input_names(&self) -> Vec<String> {
+        get_input_names(&self.rows)
+    }
+
+    /// Collects the typed text of every input by its name. Inputs which are no longer in the scene are left out.
+    pub fn values(&self, scene: &Scene<C>) -> std::collections::HashMap<String, String> {
+        collect_values(scene, &self.get_input_names())
+    }
+
+    /// Builds the container and the widgets of every row, adding them to the scene (and appending the inputs
+    /// and buttons to its tab order). Returns the index of the container, whose removal removes the whole form.
+    pub fn add_to_scene(&self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetError> {
+        use crate::render::{Colored, ColorType, Span};
+        let depth = self.depth.unwrap_or(0);
+        let mut container = StaticWidgetBuilder::<C>::builder(self.name.clone())
+            .with_sap(self.size_and_position.clone())
+            .with_border(self.border)
+            .with_depth(depth)
+            .with_parent(self.parent);
+        if let Some(title) = &self.title {  container = container.with_title(title.clone());  }
+        let container = container.add_to_scene(app, scene)?;
+
+        // the rows follow the container's own geometry as it's recalculated
+        let region = std::cell::RefCell::new(self.size_and_position.clone());
+        let rows = Layout::vertical(self.rows.iter().map(|row| Constraint::Length(row.get_height())).collect::<Vec<_>>())
+            .with_margin(self.border as u16)
+            .within(std::rc::Rc::new(move |area| region.borrow_mut().get_size_and_position(area)));
+        let names = std::rc::Rc::new(self.get_input_names());
+        let mut focusable = vec![];
+        let mut button_count = 0;
+        for (index, row) in self.rows.iter().enumerate() {
+            match row {
+                FormRow::Label(label) => {
+                    StaticTextWidgetBuilder::<C>::builder(format!("{} label {}", self.name, index))
+                        .with_renderer(vec![label.clone()])
+                        .with_sap(rows.cell(index))
+                        .with_depth(depth)
+                        .with_parent(Some(container))
+                        .add_to_scene(app, scene)?;
+                },
+                FormRow::Input { name, placeholder } => {
+                    focusable.push(TypingWidgetBuilder::<C>::builder(name.clone())
+                        .with_border(true)
+                        .with_placeholder(placeholder.clone())
+                        .with_renderer(Box::new(|size, _position, typed, _selection, _selected, _data| {
+                            // the end of the text stays in view (the terminal's cursor marks where typing goes)
+                            let text = typed.concat();
+                            let width = size.0.saturating_sub(2) as usize;
+                            let skip = text.chars().count().saturating_sub(width.saturating_sub(1));
+                            Some(vec![Span::from_tokens(vec![Colored::new(text.chars().skip(skip).collect())])])
+                        }))
+                        .with_sap(rows.cell(index))
+                        .with_depth(depth)
+                        .with_parent(Some(container))
+                        .add_to_scene(app, scene)?);
+                },
+                FormRow::Separator => {
+                    SeparatorWidgetBuilder::<C>::builder(format!("{} separator {}", self.name, index))
+                        .with_sap(rows.cell(index))
+                        .with_depth(depth)
+                        .with_parent(Some(container))
+                        .add_to_scene(app, scene)?;
+                },
+                FormRow::Buttons(labels) => {
+                    let columns = Layout::horizontal(vec![Constraint::Fill; labels.len()]).within(rows.region(index));
+                    for (column, label) in labels.iter().enumerate() {
+                        let button = button_count;
+                        button_count += 1;
+                        let label = label.clone();
+                        let (handler, names) = (self.submit_handler.clone(), names.clone());
+                        focusable.push(ButtonWidgetBuilder::<C>::builder(format!("{} button {}", self.name, button))
+                            .with_border(true)
+                            .with_renderer(Box::new(move |size, _position, state, _toggled, _data| {
+                                // centered within the border, and reversed while hovered or pressed
+                                let padding = (size.0.saturating_sub(2) as usize).saturating_sub(label.size()) / 2;
+                                let mut line = Span::from_tokens(vec![Colored::new(" ".repeat(padding))]);
+                                let mut label = label.clone();
+                                if matches!(state, ButtonState::Hovered | ButtonState::Pressed(_) | ButtonState::Held(_)) {
+                                    label.add_color(ColorType::Reverse);
+                                }
+                                line.append(label);
+                                Some(vec![line])
+                            }))
+                            .with_update_handler(Box::new(move |_button, data, _app, scene, state, _toggled| {
+                                if let ButtonState::Released(crate::event_handler::MouseEventType::Left) = state &&
+                                    let Some(handler) = &handler
+                                {
+                                    handler(data, button, &collect_values(scene, &names));
+                                }
+                            }))
+                            .with_sap(columns.cell(column))
+                            .with_depth(depth)
+                            .with_parent(Some(container))
+                            .add_to_scene(app, scene)?);
+                    }
+                },
+            }
+        }
+        let mut order = scene.get_tab_order().to_vec();
+        order.extend(focusable);
+        scene.set_tab_order(order);
+        Ok(container)
+    }
+}
+
+/// Gets the names of the inputs among the rows, in order.
+fn get_input_names(rows: &[FormRow]) -> Vec<String> {
+    rows.iter().filter_map(|row| match row {
+        FormRow::Input { name, .. } => Some(name.clone()),
+        _ => None,
+    }).collect()
+}
+
+/// Collects the typed text of the named inputs which are in the scene.
+fn collect_values<C: 'static>(scene: &Scene<C>, names: &[String]) -> std::collections::HashMap<String, String> {
+    names.iter().filter_map(|name| {
+        scene.widget_as::<TypingWidget<C>>(name).map(|input| (name.clone(), input.typed_text.clone()))
+    }).collect()
+}
@@ -0,0 +1,571 @@
+use crate::render::{Image, ImageProtocol};
+use crate::widget_impls::*;
+use crate::widget::*;
+
+type RenderFunction<C> = Box<dyn Fn(&mut C) -> Option<Image>>;
+type UpdateHandler<C> = Box<dyn Fn(&mut dyn Widget<C>, &mut C, &mut crate::App<C>, &mut Scene<C>)>;
+
+/// Builder for creating ImageWidget instances with a fluent interface.
+/// Maintains configuration state until build() is called to create the actual widget.
+pub struct ImageWidgetBuilder<C> {
+    /// The unique name identifier for the widget.
+    name: String,
+    /// The z-index depth of the widget; higher values render on top of lower ones.
+    depth: Option<u16>,
+    /// Whether the widget should have a border.
+    border: bool,
+    /// The title of the widget, if any.
+    title: Option<String>,
+    /// The theme slot coloring the border and title, if any.
+    theme_slot: Option<String>,
+    /// The glyphs the border is drawn with.
+    border_style: crate::render::BorderStyle,
+    /// The blank space between the border (or edge) and the content.
+    padding: crate::render::Padding,
+    /// Where the title sits along the top border.
+    title_alignment: crate::render::Alignment,
+    /// The title drawn in the bottom border and its alignment, if any.
+    bottom_title: Option<(String, crate::render::Alignment)>,
+    /// The size and position configuration for the widget.
+    pub size_and_position: SizeAndPosition,
+    /// The closure supplying new images each update, if any.
+    pub render_function: Option<RenderFunction<C>>,
+    /// The update handler function for the widget, if any. This function is called during event updates.
+    update_handler: Option<UpdateHandler<C>>,
+    /// The index of the parent widget in the scene graph, if any.
+    parent: Option<usize>,
+    /// The image initially shown, if any.
+    image: Option<Image>,
+    /// The protocol the image is shown with, if it was chosen rather than detected.
+    protocol: Option<ImageProtocol>,
+
+    /// The keywords the widget's window is tagged with.
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+    /// The colors and modifiers the whole window is painted with (i.e. the border's color).
+    colors: Vec<crate::render::ColorType>,
+    /// Whether the window starts hidden.
+    hidden: bool,
+    /// The layer the window is drawn in.
+    layer: crate::render::Layer,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+impl<C> ImageWidgetBuilder<C> {
+    /// Sets the image initially shown.
+    pub fn with_image(mut self, image: Image) -> Self {
+        self.image = Some(image);
+        self
+    }
+
+    /// Sets the protocol the image is shown with, overriding the one detected from the environment
+    /// (see `ImageProtocol::detect`). `ImageProtocol::Blocks` always draws it with half blocks.
+    pub fn with_protocol(mut self, protocol: ImageProtocol) -> Self {
+        self.protocol = Some(protocol);
+        self
+    }
+}
+
+/// Implementations for the methods in `WidgetBuilder`.
+impl<C: 'static> WidgetBuilder<C> for ImageWidgetBuilder<C> {
+    /// Constructs a `ImageWidget`, an implementor of `Widget`, given the parameters.
+    /// Validates that the size is non-zero before creating the widget.
+    /// The method takes in a reference to the terminal's current area/size.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{ImageWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::{Image, ImageProtocol, Rect};
+    /// # struct AppData;
+    /// let thumbnail = Image::new(2, 2, vec![255; 16]).unwrap();
+    /// let (widget, window) = ImageWidgetBuilder::<AppData>::builder(String::new())
+    ///     .with_image(thumbnail)
+    ///     .with_protocol(ImageProtocol::Kitty)
+    ///     .with_size((40, 10))
+    ///     .build(&Rect { width: 80, height: 24 })  // replace &Rect with the actual terminal size (such as `&app.area.read()`)
+    ///     .expect("Invalid widget position or size.");
+    /// ```
+    fn build(mut self, display_area: &crate::render::Rect) -> Result<(Box<dyn Widget<C>>, crate::render::Window), WidgetBuilderError> {
+        let (size, position) = self.size_and_position.get_size_and_position(display_area);
+        if size.0 == 0 || size.1 == 0 {
+            return Err(WidgetBuilderError::InvalidGeometry { size, position })
+        }
+        let depth = self.depth.as_ref().unwrap_or(&0u16);
+        let mut window = crate::render::Window::new(position, *depth, size);
+        if self.border {  window.bordered();  }
+        window.set_theme_slot(self.theme_slot.clone());
+        window.set_border_style(self.border_style);
+        window.set_padding(self.padding);
+        if let Some(title) = &self.title {  window.titled(title.clone());  }
+        window.colorizes(std::mem::take(&mut self.colors));
+        if self.hidden {  window.hide();  }
+        window.set_layer(self.layer);
+        window.set_title_alignment(self.title_alignment);
+        if let Some((title, alignment)) = &self.bottom_title {
+            window.bottom_titled(crate::render::Span::from_tokens(vec![crate::render::Colored::new(title.clone())]));
+            window.set_bottom_title_alignment(*alignment);
+        }
+        Ok((Box::new(ImageWidget::<C> {
+            children: vec![],
+            name: self.name,
+            parent_index: self.parent,
+            size_and_position: self.size_and_position,
+            render_function: self.render_function,
+            update_handler: self.update_handler,
+            image: self.image,
+            protocol: self.protocol.unwrap_or_else(ImageProtocol::detect),
+            fitted: None,
+            keywords: self.keywords,
+            tooltip: self.tooltip,
+            __phantom: std::marker::PhantomData,
+        }), window))
+    }
+
+    /// Sets the widget's fixed position (static layout).
+    /// Retrains the dynamic proportions of any dynamic positioning configuration already in place.
+    fn with_position(mut self, position: (u16, u16)) -> Self {
+        self.size_and_position.position_offset = (position.0 as i16, position.1 as i16);
+        self
+    }
+
+    /// Sets the widget's fixed position (static layout).
+    /// Retrains the dynamic proportions of any dynamic size configuration already in place.
+    fn with_size(mut self, size: (u16, u16)) -> Self {
+        self.size_and_position.size_offset = (size.0 as i16, size.1 as i16);
+        self
+    }
+
+    /// Configures dynamic positioning based on terminal size with a fixed offset.
+    fn with_dynamic_position(mut self, position_offset: (i16, i16), position_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.position_offset = position_offset;
+        self.size_and_position.position_area_percent = position_area_percent;
+        self
+    }
+
+    /// Configures dynamic sizing based on terminal size with a fixed offset.
+    fn with_dynamic_size(mut self, size_offset: (i16, i16), size_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.size_offset = size_offset;
+        self.size_and_position.size_area_percent = size_area_percent;
+        self
+    }
+
+    /// Sets whether the widget should have a border. By default, all widgets are borderless.
+    fn with_border(mut self, border: bool) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Sets the widget's title (displayed in border if enabled; invisible otherwise).
+    fn with_title(mut self, title: String) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Assigns a depth to the widget. Higher values represent UI elements that are stacked further
+    /// ontop of other elements. By default, the builder instance has a depth of None, which corresponds
+    /// to 0 (root depth).
+    fn with_depth(mut self, depth: u16) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// The type representing the renderer closure.
+    type RendererType = RenderFunction<C>;
+    /// Sets the closure supplying the image each update, given the app's data. Returning an image replaces
+    /// the one shown (images are compared by id, so returning the same one again costs nothing), while
+    /// None keeps the current one. By default, the image only changes through `ImageWidget::set_image`.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{ImageWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::{Image, Rect};
+    /// struct AppData {  thumbnails: Vec<Image>, selected: usize  }
+    /// let (widget, window) = ImageWidgetBuilder::<AppData>::builder(String::new())
+    ///     .with_renderer(Box::new(|data: &mut AppData| data.thumbnails.get(data.selected).cloned()))
+    ///     .with_size((20, 10))
+    ///     .build(&Rect { width: 80, height: 24 })
+    ///     .unwrap();
+    /// ```
+    fn with_renderer(mut self, renderer: Self::RendererType) -> Self {
+        self.render_function = Some(renderer);
+        self
+    }
+
+    /// Generates a new builder instance with a provided unique name identifier.
+    /// By default, size_and_position are no compatible and require the user to
+    /// provide them using the other `WidgetBuilder` trait functions.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{ImageWidgetBuilder, WidgetBuilder};
+    /// # struct AppData;
+    /// let builder = ImageWidgetBuilder::<AppData>::builder(String::from("Widget Name"));
+    /// ```
+    fn builder(name: String) -> Self {
+        Self {
+            name,
+            depth: None,
+            size_and_position: SizeAndPosition::default(),
+            render_function: None,
+            border: false,
+            title: None,
+            theme_slot: None,
+            border_style: crate::render::BorderStyle::Plain,
+            padding: crate::render::Padding::default(),
+            title_alignment: crate::render::Alignment::Center,
+            bottom_title: None,
+            update_handler: None,
+            parent: None,
+            image: None,
+            protocol: None,
+            keywords: vec![],
+            tooltip: None,
+            colors: vec![],
+            hidden: false,
+            layer: crate::render::Layer::Normal,
+            __phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the SizeAndPosition configuration directly.
+    fn with_sap(mut self, sap: SizeAndPosition) -> Self {
+        self.size_and_position = sap;
+        self
+    }
+
+    /// Sets which point of the widget is placed at its computed position.
+    fn with_anchor(mut self, anchor: Anchor) -> Self {
+        self.size_and_position.anchor = anchor;
+        self
+    }
+
+    /// Sets the smallest size the widget can shrink to.
+    fn with_min_size(mut self, min_size: (u16, u16)) -> Self {
+        self.size_and_position.min_size = Some(min_size);
+        self
+    }
+
+    /// Sets the largest size the widget can grow to.
+    fn with_max_size(mut self, max_size: (u16, u16)) -> Self {
+        self.size_and_position.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets whether the widget is positioned relative to its parent's window.
+    fn with_relative_to_parent(mut self, relative: bool) -> Self {
+        self.size_and_position.position_mode = match relative {
+            true => PositionMode::RelativeToParent,
+            false => PositionMode::Absolute,
+        };
+        self
+    }
+
+    /// Sets whether the widget is clipped to its parent's window.
+    fn with_clip_to_parent(mut self, clip: bool) -> Self {
+        self.size_and_position.clip_to_parent = clip;
+        self
+    }
+
+    /// Slides the widget into place from the given offset when it's first drawn.
+    fn with_entrance_animation(mut self, from_offset: (i16, i16), duration: std::time::Duration) -> Self {
+        self.size_and_position.animate_from_offset(from_offset, duration, Easing::EaseOut);
+        self
+    }
+
+    /// Colors the border and title with a slot of the app's theme.
+    fn with_theme_slot(mut self, slot: &str) -> Self {
+        self.theme_slot = Some(slot.to_owned());
+        self
+    }
+
+    /// Sets the glyphs the border is drawn with.
+    fn with_border_style(mut self, style: crate::render::BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Sets the blank space between the border (or edge) and the content.
+    fn with_padding(mut self, padding: crate::render::Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets where the title sits along the top border.
+    fn with_title_alignment(mut self, alignment: crate::render::Alignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Sets the title drawn in the bottom border and its alignment.
+    fn with_bottom_title(mut self, title: String, alignment: crate::render::Alignment) -> Self {
+        self.bottom_title = Some((title, alignment));
+        self
+    }
+
+    /// Sets the widget's update handler closure. This closure is called during event updates.
+    /// By default, there is no update handler, meaning the widget won't respond to events.
+    type FunctionType = UpdateHandler<C>;
+    fn with_update_handler(mut self, handler: Self::FunctionType) -> Self {
+        self.update_handler = Some(handler);
+        self
+    }
+
+    /// Sets the parent widget index for this widget, if any.
+    /// By default, the parent is None, indicating a root node.
+    fn with_parent(mut self, parent: Option<usize>) -> Self {
+        self.parent = parent;
+        self
+    }
+
+    /// Tags the widget's window with the given keywords (see `Scene::widgets_by_keyword`).
+    fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Gives the widget a tooltip, shown once the mouse rests over it (see `Scene::set_tooltip_delay`).
+    fn with_tooltip(mut self, tooltip: impl Into<Tooltip<C>>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Paints the whole window with the colors and modifiers (see `Window::colorizes`).
+    fn with_colors(mut self, colors: Vec<crate::render::ColorType>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Sets whether the widget starts hidden (see `Scene::set_widget_visible`).
+    fn with_hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Sets the layer the widget's window is drawn in (see `Window::set_layer`).
+    fn with_layer(mut self, layer: crate::render::Layer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Builds the widget and adds it to the provided scene, returning the new widget's index in the scene graph.
+    /// This method combines the `build` and `scene.add_widget` calls into one for convenience.
+    /// If building the widget fails, an error is returned instead.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{ImageWidget, ImageWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::ImageProtocol;
+    /// # struct AppData;
+    /// let mut app = term_render::App::<AppData>::new_headless(20, 4);
+    /// let mut scene = term_render::widget::Scene::new();
+    /// ImageWidgetBuilder::<AppData>::builder(String::from("Image"))
+    ///     .with_size((4, 2))
+    ///     .with_protocol(ImageProtocol::Blocks)
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .unwrap();
+    /// // a 4x4 image: white on top, black below
+    /// let pixels = (0..16).flat_map(|pixel| if pixel < 8 {  [255; 4]  } else {  [0, 0, 0, 255]  }).collect();
+    /// let widget = scene.widget_as_mut::<ImageWidget<AppData>>("Image").unwrap();
+    /// widget.set_image(4, 4, pixels).unwrap();
+    /// scene.update_all_widgets(&mut app, &mut AppData).unwrap();
+    /// assert_eq!(app.renderer.write().snapshot()[0], "▀▀▀▀");
+    /// ```
+    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetError> {
+        let (widget, window) = self.build(&app.area.read())?;
+        scene.add_widget(widget, window, &mut app.renderer.write())
+    }
+}
+
+/// A widget showing a raster image through the terminal's graphics protocol (kitty, iTerm2, or sixel),
+/// detected from the environment unless one is chosen (see `ImageProtocol`). The image is scaled to fit
+/// the content area (keeping its aspect ratio) and placed at its top left. The image is also drawn with
+/// half blocks beneath, which is what's shown without a protocol, or while the image can't be shown whole
+/// (when it's clipped, partially off screen, or a window above overlaps it).
+/// `ImageWidgetBuilder` is the associated builder for creating instances of this widget.
+pub struct ImageWidget<C> {
+    /// The indices of child widgets in the scene graph.
+    children: Vec<usize>,
+
+    /// The unique name identifier for the widget. The rendering backend
+    /// relies on `String` names instead of widgets.
+    name: String,
+
+    /// The index of the parent widget in the scene graph, if any (None would
+    /// indicate the root node).
+    parent_index: Option<usize>,
+
+    /// Configuration for the widget's size and position, supporting both static and dynamic layouts.
+    pub size_and_position: SizeAndPosition,
+
+    /// Optional closure supplying new images every update.
+    pub render_function: Option<RenderFunction<C>>,
+
+    /// Optional closure that handles updates to the widget's state.
+    pub update_handler: Option<UpdateHandler<C>>,
+
+    /// The image shown, if any.
+    image: Option<Image>,
+
+    /// The protocol the image is shown with.
+    protocol: ImageProtocol,
+
+    /// The image and the size (in cells) it was last drawn at, to know when it has to be drawn again.
+    fitted: Option<(Image, (u16, u16))>,
+
+    /// The keywords the widget's window is tagged with (see `Scene::widgets_by_keyword`).
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+impl<C> ImageWidget<C> {
+    /// Replaces the image with RGBA pixels (four bytes per pixel, row by row), returning an error if the
+    /// number of bytes doesn't match the size (see `Image::new`).
+    pub fn set_image(&mut self, width: u32, height: u32, pixels: Vec<u8>) -> Result<(), String> {
+        self.image = Some(Image::new(width, height, pixels)?);
+        Ok(())
+    }
+
+    /// Gets the image shown, if any.
+    pub fn get_image(&self) -> Option<&Image> {
+        self.image.as_ref()
+    }
+
+    /// Removes the image, leaving the widget blank.
+    pub fn clear_image(&mut self) {
+        self.image = None;
+    }
+
+    /// Gets the protocol the image is shown with.
+    pub fn get_protocol(&self) -> ImageProtocol {
+        self.protocol
+    }
+
+    /// Changes the protocol the image is shown with (see `ImageProtocol`).
+    pub fn set_protocol(&mut self, protocol: ImageProtocol) {
+        self.protocol = protocol;
+        self.fitted = None;  // placing the image again
+    }
+}
+
+/// Implementation of the methods for ImageWidget
+impl<C: 'static> Widget<C> for ImageWidget<C> {
+    /// Returns the widget's name as an identifier. The rendering backend relies
+    /// on `String` names instead of widgets.
+    fn get_window_ref(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Handles event updates by invoking the user-provided update handler closure, if any.
+    fn update_with_events(&mut self, data: &mut C, app: &mut crate::App<C>, scene: &mut Scene<C>) {
+        if let Some(update_handler) = self.update_handler.take() {
+            update_handler(self, data, app, scene);
+            self.update_handler = Some(update_handler);
+        }
+    }
+
+    /// Without an update handler, there's nothing to do with events.
+    fn consumes_events(&self) -> bool {
+        self.update_handler.is_some()
+    }
+
+    /// Takes any new image from the render closure, then redraws the half blocks (and places the image
+    /// over them) if the image or the space for it changed.
+    fn update_render(&mut self, window: &mut crate::render::Window, area: &crate::render::Rect, app_state: &mut C) -> bool {
+        let (size, position) = self.size_and_position.get_size_and_position(area);
+        window.resize(size);
+        window.r#move(position);
+        if let Some(render_function) = &self.render_function &&
+            let Some(image) = render_function(app_state)
+        {
+            self.image = Some(image);
+        }
+
+        let content = (window.get_content_width() as u16, window.get_content_height() as u16);
+        let fitted = self.image.as_ref().map(|image| (image.clone(), image.fit(content.0, content.1)));
+        if fitted == self.fitted {  return false;  }
+        self.fitted = fitted;
+        match &self.fitted {
+            Some((image, (columns, rows))) => {
+                window.from_lines(image.to_half_blocks(*columns, *rows));
+                window.set_image(Some((image.clone(), self.protocol)));
+            },
+            None => {
+                window.clear_lines();
+                window.set_image(None);
+            },
+        }
+        true
+    }
+
+    /// Returns the indices of child widgets in the scene graph.
+    fn get_children_indexes(&self) -> Vec<usize> {
+        self.children.clone()
+    }
+
+    /// Adds a child widget index to this widget.
+    fn add_child_index(&mut self, index: usize) {
+        self.children.push(index);
+    }
+
+    /// Removes a child widget index from this widget
+    fn remove_child_index(&mut self, index: usize) {
+        self.children.remove(index);
+    }
+
+    /// Clears all child widget indices from this widget
+    fn clear_children_indexes(&mut self) {
+        self.children.clear();
+    }
+
+    /// Returns the parent widget index if one exists, otherwise None.
+    fn get_parent_index(&self) -> Option<usize> {
+        self.parent_index
+    }
+
+    /// Sets the parent widget index for this widget, or None for a root node.
+    fn set_parent_index(&mut self, index: Option<usize>) {
+        self.parent_index = index;
+    }
+
+    /// Determines if a given position collides with the widget's area.
+    fn is_collided(&self, position: (u16, u16)) -> bool {
+        self.size_and_position.is_collided(position)
+    }
+
+    /// Returns the widget as `Any` for downcasting.
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget as a mutable `Any` for downcasting.
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget's size and position configuration.
+    fn get_size_and_position(&self) -> Option<&SizeAndPosition> {
+        Some(&self.size_and_position)
+    }
+
+    /// Returns the widget's size and position configuration mutably.
+    fn get_size_and_position_mut(&mut self) -> Option<&mut SizeAndPosition> {
+        Some(&mut self.size_and_position)
+    }
+
+    /// Returns the keywords the widget's window is tagged with.
+    fn get_keywords(&self) -> &[String] {
+        &self.keywords
+    }
+
+    /// Returns the tooltip shown while the mouse rests over the widget, if any.
+    fn get_tooltip(&self) -> Option<&Tooltip<C>> {
+        self.tooltip.as_ref()
+    }
+
+    /// Applies the offset and clip inherited from the parent widgets to the size and position.
+    fn set_parent_transform(&mut self, offset: (i16, i16), clip: Option<Region>) {
+        self.size_and_position.parent_offset = offset;
+        self.size_and_position.clip = clip;
+    }
+}
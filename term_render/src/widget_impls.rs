@@ -6,6 +6,22 @@ pub use crate::widget_dynamic::*;
 pub use crate::widget_typing::*;
 pub use crate::widget_static::*;
 pub use crate::widget_button::*;
+pub use crate::widget_scroll_view::*;
+pub use crate::widget_separator::*;
+pub use crate::widget_form::*;
+pub use crate::widget_list::*;
+pub use crate::widget_log::*;
+pub use crate::widget_table::*;
+pub use crate::widget_checkbox::*;
+pub use crate::widget_radio::*;
+pub use crate::widget_dialog::*;
+pub use crate::widget_context_menu::*;
+pub use crate::widget_tab::*;
+pub use crate::widget_status_bar::*;
+pub use crate::widget_animation::*;
+pub use crate::widget_canvas::*;
+#[cfg(feature = "images")]
+pub use crate::widget_image::*;
 use crate::widget::*;
 
 /// A builder trait for constructing widgets with a fluent interface.
@@ -41,21 +57,177 @@ pub trait WidgetBuilder<C> {
     fn builder(name: String) -> Self;
     /// Sets the widget's SizeAndPosition configuration directly.
     fn with_sap(self, sap: SizeAndPosition) -> Self;
+    /// Sets which point of the widget is placed at its position (i.e. `Anchor::Center` to center it on
+    /// the position rather than placing its top left corner there). The default is `Anchor::TopLeft`.
+    fn with_anchor(self, anchor: Anchor) -> Self;
+    /// Sets the smallest size (width, height) the widget can shrink to when the terminal is resized.
+    fn with_min_size(self, min_size: (u16, u16)) -> Self;
+    /// Sets the largest size (width, height) the widget can grow to when the terminal is resized.
+    fn with_max_size(self, max_size: (u16, u16)) -> Self;
+    /// Sets whether the widget's offsets and percentages are measured against its parent's window rather than
+    /// the terminal (see `PositionMode::RelativeToParent`), so it moves and resizes along with the parent.
+    /// By default, widgets are positioned absolutely.
+    fn with_relative_to_parent(self, relative: bool) -> Self;
+    /// Sets whether the widget is clipped to its parent's window, hiding (and ignoring clicks on) whatever
+    /// hangs outside of it. By default, widgets aren't clipped to their parent.
+    fn with_clip_to_parent(self, clip: bool) -> Self;
+    /// Slides the widget into place from the given offset away from it (i.e. `(0, 10)` slides it up from
+    /// 10 rows below) over the given duration, starting the first time it's drawn (see
+    /// `SizeAndPosition::animate_from_offset`). By default, widgets appear in place immediately.
+    fn with_entrance_animation(self, from_offset: (i16, i16), duration: std::time::Duration) -> Self;
+    /// Colors the widget's border and title with a slot of the app's theme (i.e. `"accent"`), following
+    /// the theme as it changes. By default, widgets don't use the theme.
+    fn with_theme_slot(self, slot: &str) -> Self;
+    /// Sets the glyphs the widget's border is drawn with (see `BorderStyle`), such as rounded or double lines.
+    /// By default, borders are drawn with plain lines. This has no effect unless the widget has a border.
+    fn with_border_style(self, style: crate::render::BorderStyle) -> Self;
+    /// Sets the blank space between the widget's border (or edge, when borderless) and its content.
+    /// The padding takes away from the space the content has. By default, there's no padding.
+    fn with_padding(self, padding: crate::render::Padding) -> Self;
+    /// Sets where the title sits along the top border (centered by default).
+    fn with_title_alignment(self, alignment: crate::render::Alignment) -> Self;
+    /// Sets a secondary title drawn in the bottom border (i.e. a hint like "press q to quit") at the
+    /// given alignment. Like the main title, it's invisible unless the widget has a border.
+    fn with_bottom_title(self, title: String, alignment: crate::render::Alignment) -> Self;
     /// The type for the update handler closure.
     type FunctionType;
     /// Sets the widget's update handler closure. This closure is called during event updates.
     fn with_update_handler(self, _handler: Self::FunctionType) -> Self;
     /// Sets the widget's parent by its index in the scene, or `None` for no parent (root level).
     fn with_parent(self, parent: Option<usize>) -> Self;
+    /// Tags the widget's window with keywords (i.e. `"dialog"`), so groups of widgets can be found or
+    /// removed together (see `Scene::widgets_by_keyword` and `Scene::remove_widgets_by_keyword`).
+    /// By default, widgets have no keywords.
+    fn with_keywords(self, keywords: Vec<String>) -> Self;
+    /// Gives the widget a tooltip: a `Span`, or a `TooltipFunction` producing one from the app data. The scene
+    /// shows it beside the cursor once the mouse rests over the widget (see `Scene::set_tooltip_delay`), and
+    /// hides it as soon as the mouse moves. By default, widgets have no tooltip.
+    fn with_tooltip(self, tooltip: impl Into<Tooltip<C>>) -> Self;
+    /// Paints the whole window with the colors and modifiers (i.e. `ColorType::Red` for a red border), applied
+    /// when it's built so the first render already has them. By default, windows aren't colored.
+    /// # Example
+    /// ```
+    /// use term_render::widget_impls::{StaticWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::{ColorType, Rect};
+    /// let (_widget, window) = StaticWidgetBuilder::<()>::builder(String::from("popup"))
+    ///     .with_border(true)
+    ///     .with_colors(vec![ColorType::Red, ColorType::Bold])
+    ///     .with_hidden(true)
+    ///     .with_position((0, 0))
+    ///     .with_size((10, 5))
+    ///     .build(&Rect { width: 20, height: 10 })
+    ///     .unwrap();
+    /// assert!(window.has_color(&ColorType::Red) && window.has_color(&ColorType::Bold));
+    /// assert!(!window.has_color(&ColorType::Blue));
+    /// assert!(window.hidden);
+    /// ```
+    fn with_colors(self, colors: Vec<crate::render::ColorType>) -> Self;
+    /// Paints the whole window with a single color or modifier (see `with_colors`).
+    fn with_color(self, color: crate::render::ColorType) -> Self where Self: Sized {
+        self.with_colors(vec![color])
+    }
+    /// Sets whether the widget starts hidden, such as a dialog shown later (see `Scene::set_widget_visible`).
+    /// Hidden widgets don't process events or block clicks until they're shown. By default, widgets are visible.
+    fn with_hidden(self, hidden: bool) -> Self;
+    /// Sets the layer the widget's window is drawn in (see `Window::set_layer`), such as `Layer::Overlay` to
+    /// draw it over every other widget whatever their depths. Clicks follow the same order. By default, widgets
+    /// are in `Layer::Normal`.
+    fn with_layer(self, layer: crate::render::Layer) -> Self;
     /// Builds and adds the widget to the scene, removing the boilerplate of calling `build` and then adding it to the scene.
-    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetErr>;
+    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetError>;
+}
+
+/// The point on a widget which is placed at its computed position.
+/// For example, `Center` with a position of 50% of the terminal centers the widget on the screen,
+/// regardless of its size.
+/// # Example:
+/// ```
+/// use term_render::widget_impls::{Anchor, SizeAndPosition};
+/// use term_render::render::Rect;
+/// // a 40x10 dialog centered on the terminal
+/// let mut sap = SizeAndPosition::new_dynamic((40, 10), (0, 0), (0.0, 0.0), (0.5, 0.5));
+/// sap.anchor = Anchor::Center;
+/// assert_eq!(sap.get_size_and_position(&Rect { width: 80, height: 24 }), ((40, 10), (20, 7)));
+/// // the position never goes below (0, 0), even if the terminal is smaller than the widget
+/// assert_eq!(sap.get_size_and_position(&Rect { width: 20, height: 6 }), ((40, 10), (0, 0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Anchor {
+    /// The widget's top left corner is at the position (the default).
+    #[default]
+    TopLeft,
+    TopCenter,
+    TopRight,
+    CenterLeft,
+    Center,
+    CenterRight,
+    BottomLeft,
+    BottomCenter,
+    BottomRight,
+}
+
+impl Anchor {
+    /// Returns the fraction (x, y) of the widget's size which the position is shifted back by.
+    pub fn get_fraction(&self) -> (f32, f32) {
+        match self {
+            Anchor::TopLeft => (0.0, 0.0),
+            Anchor::TopCenter => (0.5, 0.0),
+            Anchor::TopRight => (1.0, 0.0),
+            Anchor::CenterLeft => (0.0, 0.5),
+            Anchor::Center => (0.5, 0.5),
+            Anchor::CenterRight => (1.0, 0.5),
+            Anchor::BottomLeft => (0.0, 1.0),
+            Anchor::BottomCenter => (0.5, 1.0),
+            Anchor::BottomRight => (1.0, 1.0),
+        }
+    }
+}
+
+/// What a widget's offsets and percentages are measured against.
+/// # Example:
+/// ```
+/// use term_render::widget_impls::{StaticTextWidgetBuilder, WidgetBuilder};
+/// use term_render::render::Span;
+/// let mut app = term_render::App::<()>::new_headless(12, 4);
+/// let mut scene = term_render::widget::Scene::new();
+/// let panel = StaticTextWidgetBuilder::<()>::builder(String::from("panel"))
+///     .with_renderer(vec![Span::from("......")])
+///     .with_position((1, 1)).with_size((6, 1))
+///     .add_to_scene(&mut app, &mut scene).unwrap();
+/// for (name, x) in [("a", 0), ("b", 5)] {
+///     StaticTextWidgetBuilder::<()>::builder(String::from(name))
+///         .with_renderer(vec![Span::from(name.repeat(3).as_str())])
+///         .with_position((x, 0)).with_size((3, 1)).with_depth(1)
+///         .with_parent(Some(panel)).with_relative_to_parent(true).with_clip_to_parent(true)
+///         .add_to_scene(&mut app, &mut scene).unwrap();
+/// }
+/// scene.update_all_widgets(&mut app, &mut ()).unwrap();
+/// // "b" hangs off the right of the panel, so it's clipped
+/// assert_eq!(app.renderer.write().snapshot()[1], " aaa..b");
+///
+/// // moving the panel takes its children along with it
+/// let sap = scene.widget_as_dyn_mut(panel).unwrap().get_size_and_position_mut().unwrap();
+/// sap.position_offset = (4, 2);
+/// scene.update_all_widgets(&mut app, &mut ()).unwrap();
+/// assert_eq!(app.renderer.write().snapshot(), vec!["", "", "    aaa..b", ""]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PositionMode {
+    /// The terminal's area, with the position measured from its top left corner (the default).
+    #[default]
+    Absolute,
+    /// The parent widget's window, with the position measured from its top left corner, so the widget
+    /// follows the parent as it moves or resizes. Widgets without a parent are positioned absolutely.
+    RelativeToParent,
 }
 
 /// Represents a widget's size and position configuration, supporting both static and dynamic layouts.
 /// Dynamic layouts are calculated relative to the terminal area with optional fixed offsets.
 /// Static layouts are solely based on the provided constant, and as such won't change if the terminal
 /// is resized.
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct SizeAndPosition {
     /// Fixed size adjustment (width, height) in characters
     pub size_offset: (i16, i16),
@@ -73,6 +245,37 @@ pub struct SizeAndPosition {
     
     /// Caches the last calculated size and position to avoid redundant calculations.
     pub last_size_pos: ((u16, u16), (u16, u16)), // caching the last calculated size and position
+    
+    /// An offset inherited from the parent widgets (i.e. the scroll offset of a `ScrollViewWidget`).
+    /// This is applied on top of the calculated position; it's set by the scene, not the user.
+    pub parent_offset: (i16, i16),
+    /// The region (size, position) inherited from the parent widgets that the widget is visible within.
+    /// Positions outside of it never collide with the widget. This is also set by the scene.
+    pub clip: Option<Region>,
+    
+    /// Computes the size and position directly from the terminal area, replacing the offsets and
+    /// percentages (i.e. a cell of a `Layout`; see `new_computed`).
+    pub computed: Option<crate::layout::RegionFunction>,
+    
+    /// The point of the widget placed at the calculated position (`Anchor::TopLeft` by default).
+    pub anchor: Anchor,
+    
+    /// The smallest size (width, height) the widget can have; this wins over `max_size`.
+    /// If the widget no longer fits the terminal, its position is moved back so it stays on-screen.
+    pub min_size: Option<(u16, u16)>,
+    /// The largest size (width, height) the widget can have.
+    pub max_size: Option<(u16, u16)>,
+    
+    /// The transition towards the current offsets and percentages, if one is in flight (see `animate_to`).
+    pub animation: Option<Animation>,    
+    /// What the offsets and percentages are measured against (`PositionMode::Absolute` by default).
+    pub position_mode: PositionMode,
+    /// The region (size, position) of the parent's window, as of its last update. When positioned
+    /// relative to the parent, this replaces the terminal area. It's set by the scene, not the user.
+    pub parent_rect: Option<Region>,
+    /// Clips the widget to the parent's window, so the parts hanging outside of it aren't drawn (or
+    /// clicked). This is set by the user, and applied by the scene.
+    pub clip_to_parent: bool,
 }
 
 impl SizeAndPosition {
@@ -89,6 +292,16 @@ impl SizeAndPosition {
             size_area_percent: (0.0, 0.0),
             position_area_percent: (0.0, 0.0),
             last_size_pos: ((0, 0), (0, 0)),
+            parent_offset: (0, 0),
+            clip: None,
+            computed: None,
+            anchor: Anchor::TopLeft,
+            min_size: None,
+            max_size: None,
+            animation: None,
+            position_mode: PositionMode::Absolute,
+            parent_rect: None,
+            clip_to_parent: false,
         }
     }
     
@@ -105,6 +318,26 @@ impl SizeAndPosition {
             size_area_percent,
             position_area_percent,
             last_size_pos: ((0, 0), (0, 0)),
+            parent_offset: (0, 0),
+            clip: None,
+            computed: None,
+            anchor: Anchor::TopLeft,
+            min_size: None,
+            max_size: None,
+            animation: None,
+            position_mode: PositionMode::Absolute,
+            parent_rect: None,
+            clip_to_parent: false,
+        }
+    }
+    
+    /// Creates a SizeAndPosition computed from the terminal area by the given function every time
+    /// it's recalculated (such as a cell from `layout::Layout::region`, or `layout::Layout::cell`).
+    /// The function returns the (size, position) of the widget.
+    pub fn new_computed(region: crate::layout::RegionFunction) -> SizeAndPosition {
+        SizeAndPosition {
+            computed: Some(region),
+            ..Default::default()
         }
     }
     
@@ -114,31 +347,163 @@ impl SizeAndPosition {
     /// # Returns
     /// Tuple containing ((width, height), (x, y)) coordinates
     ///  - *Note: static layout configurations will always return the same values regardless of the inputted area.*
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::SizeAndPosition;
+    /// use term_render::render::Rect;
+    /// // half of the terminal, but never smaller than 30x10
+    /// let mut sap = SizeAndPosition::new_dynamic((0, 0), (0, 0), (0.5, 0.5), (0.5, 0.5));
+    /// sap.min_size = Some((30, 10));
+    /// assert_eq!(sap.get_size_and_position(&Rect { width: 80, height: 24 }), ((40, 12), (40, 12)));
+    /// // shrinking the terminal keeps the minimum size, and moves the widget back on-screen
+    /// assert_eq!(sap.get_size_and_position(&Rect { width: 40, height: 12 }), ((30, 10), (10, 2)));
+    /// // if it can't fit at all, it stays at the top left corner rather than going off-screen
+    /// assert_eq!(sap.get_size_and_position(&Rect { width: 20, height: 5 }), ((30, 10), (0, 0)));
+    /// 
+    /// sap.max_size = Some((50, 15));
+    /// assert_eq!(sap.get_size_and_position(&Rect { width: 200, height: 60 }), ((50, 15), (100, 30)));
+    /// ```
     pub fn get_size_and_position(&mut self, area: &crate::render::Rect) -> ((u16, u16), (u16, u16)) {
-        let width = (((area.width as f32) * self.size_area_percent.0) as i16 + self.size_offset.0) as u16;
-        let height = (((area.height as f32) * self.size_area_percent.1) as i16 + self.size_offset.1) as u16;
-        
-        let x = (((area.width as f32) * self.position_area_percent.0) as i16 + self.position_offset.0) as u16;
-        let y = (((area.height as f32) * self.position_area_percent.1) as i16 + self.position_offset.1) as u16;
+        let target = self.get_geometry();
+        let (size_offset, position_offset, size_area_percent, position_area_percent) = match &mut self.animation {
+            Some(animation) => {
+                animation.start();
+                animation.interpolate(target)
+            },
+            None => target,
+        };
+        if self.animation.is_some_and(|animation| animation.is_finished()) {  self.animation = None;  }
+        // relative to the parent, its window takes the place of the terminal, and its corner is the origin
+        let (area, origin) = match (self.position_mode, self.parent_rect) {
+            (PositionMode::RelativeToParent, Some((size, position))) => (&crate::render::Rect { width: size.0, height: size.1 }, position),
+            _ => (area, (0, 0)),
+        };
+        let ((width, height), (x, y)) = match &self.computed {
+            Some(computed) => {
+                let (size, position) = computed(area);
+                (self.constrain_size(size), position)
+            },
+            None => {
+                let width = ((area.width as f32) * size_area_percent.0) as i32 + size_offset.0.round() as i32;
+                let height = ((area.height as f32) * size_area_percent.1) as i32 + size_offset.1.round() as i32;
+                let size = self.constrain_size((width.clamp(0, u16::MAX as i32) as u16, height.clamp(0, u16::MAX as i32) as u16));
+                // shifting back by part of the widget's own size for the anchor
+                let fraction = self.anchor.get_fraction();
+                let x = ((area.width as f32) * position_area_percent.0) as i32 + position_offset.0.round() as i32 -
+                    (size.0 as f32 * fraction.0).round() as i32;
+                let y = ((area.height as f32) * position_area_percent.1) as i32 + position_offset.1.round() as i32 -
+                    (size.1 as f32 * fraction.1).round() as i32;
+                // anchored widgets which don't fit stop at the top left corner
+                (size, (x.clamp(0, u16::MAX as i32) as u16, y.clamp(0, u16::MAX as i32) as u16))
+            },
+        };
+        // with a size constraint, the widget is kept on-screen rather than overflowing off the right/bottom
+        let (x, y) = match self.min_size.is_some() || self.max_size.is_some() {
+            true => (
+                x.min(area.width.saturating_sub(width)),
+                y.min(area.height.saturating_sub(height)),
+            ),
+            false => (x, y),
+        };
+        // the parent offset can push the widget off the top/left of the terminal, so it's clamped
+        let x = (x as i32 + origin.0 as i32 + self.parent_offset.0 as i32).clamp(0, u16::MAX as i32) as u16;
+        let y = (y as i32 + origin.1 as i32 + self.parent_offset.1 as i32).clamp(0, u16::MAX as i32) as u16;
         
         self.last_size_pos = ((width, height), (x, y));
         
         ((width, height), (x, y))
     }
     
+    /// Smoothly transitions the offsets and percentages to those of the target over the given duration,
+    /// following the easing curve. The fields are set to the target immediately, and each call to
+    /// `get_size_and_position` interpolates between where the widget was and the target (starting the
+    /// clock on the first call). Retargeting an animation in flight starts from wherever it currently is.
+    /// The anchor and size constraints aren't animated, and computed layouts (see `new_computed`) ignore
+    /// the animation.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{Easing, SizeAndPosition};
+    /// use term_render::render::Rect;
+    /// let area = Rect { width: 80, height: 24 };
+    /// let mut sap = SizeAndPosition::new_static((20, 5), (10, 20));
+    /// sap.animate_to(SizeAndPosition::new_static((20, 5), (10, 10)), std::time::Duration::from_millis(100), Easing::EaseInOut);
+    /// assert_eq!(sap.position_offset, (10, 10));  // the target is set right away
+    /// assert_eq!(sap.get_size_and_position(&area), ((20, 5), (10, 20)));  // but it starts where it was
+    /// assert!(sap.is_animating());
+    /// std::thread::sleep(std::time::Duration::from_millis(150));
+    /// assert_eq!(sap.get_size_and_position(&area), ((20, 5), (10, 10)));
+    /// assert!(!sap.is_animating());
+    /// ```
+    pub fn animate_to(&mut self, target: SizeAndPosition, duration: std::time::Duration, easing: Easing) {
+        let start = match &self.animation {
+            Some(animation) => animation.interpolate(self.get_geometry()),
+            None => self.get_geometry(),
+        };
+        self.size_offset = target.size_offset;
+        self.position_offset = target.position_offset;
+        self.size_area_percent = target.size_area_percent;
+        self.position_area_percent = target.position_area_percent;
+        self.animation = Some(Animation::new(start, self.get_geometry(), duration, easing));
+    }
+    
+    /// Animates the widget into its current place from the given offset away from it (i.e. `(0, 10)`
+    /// slides it up from 10 rows below). As with `animate_to`, the clock starts on the next calculation,
+    /// and the place can still be changed afterwards (the animation arrives wherever it ends up).
+    pub fn animate_from_offset(&mut self, from_offset: (i16, i16), duration: std::time::Duration, easing: Easing) {
+        let target = self.get_geometry();
+        let start = (target.0, (target.1.0 + from_offset.0 as f32, target.1.1 + from_offset.1 as f32), target.2, target.3);
+        self.animation = Some(Animation::new(start, target, duration, easing));
+    }
+    
+    /// Returns whether an animation is in flight (or waiting to start). While animating, the scene
+    /// treats the widget as updated every frame (see `Scene::update_all_widgets`), so anything
+    /// depending on it stays refreshed. Custom widgets which don't expose their `SizeAndPosition`
+    /// (see `Widget::get_size_and_position`) should return this from `update_render` instead.
+    pub fn is_animating(&self) -> bool {
+        self.animation.is_some_and(|animation| !animation.is_finished())
+    }
+    
+    /// The offsets and percentages (the target of any animation) as floats.
+    fn get_geometry(&self) -> Geometry {
+        let float = |pair: (i16, i16)| (pair.0 as f32, pair.1 as f32);
+        (float(self.size_offset), float(self.position_offset), self.size_area_percent, self.position_area_percent)
+    }
+    
+    /// Clamps a size between the minimum and maximum sizes (the minimum wins if they overlap).
+    fn constrain_size(&self, size: (u16, u16)) -> (u16, u16) {
+        let (mut width, mut height) = size;
+        if let Some(max_size) = self.max_size {
+            width = width.min(max_size.0);
+            height = height.min(max_size.1);
+        }
+        if let Some(min_size) = self.min_size {
+            width = width.max(min_size.0);
+            height = height.max(min_size.1);
+        } (width, height)
+    }
+    
     pub fn get_last(&self) -> ((u16, u16), (u16, u16)) {
         self.last_size_pos
     }
-}
-
-/// Error type for widget building operations, containing details about what went wrong.
-#[derive(Debug)]
-pub struct WidgetBuilderError {
-    pub details: String,
-}
-
-impl std::fmt::Display for WidgetBuilderError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "WidgetBuilderError: {}", self.details)
+    
+    /// Checks if a position is within the last calculated size and position, and within the inherited clip region.
+    pub fn is_collided(&self, position: (u16, u16)) -> bool {
+        let (size, pos) = self.last_size_pos;
+        position.0 >= pos.0 && position.0 < pos.0.saturating_add(size.0) &&
+            position.1 >= pos.1 && position.1 < pos.1.saturating_add(size.1) &&
+            self.is_within_clip(position)
+    }
+    
+    /// Checks if a position is within the inherited clip region (always true if there's no clip).
+    pub fn is_within_clip(&self, position: (u16, u16)) -> bool {
+        match self.clip {
+            None => true,
+            Some((size, pos)) => position.0 >= pos.0 && position.0 < pos.0.saturating_add(size.0) &&
+                position.1 >= pos.1 && position.1 < pos.1.saturating_add(size.1),
+        }
     }
 }
+
+/// Error type for widget building operations (the same as `WidgetError`, so `?` works between building and
+/// adding widgets).
+pub type WidgetBuilderError = crate::widget::WidgetError;
@@ -0,0 +1,800 @@
+use crate::widget_impls::*;
+use crate::widget::*;
+
+type RenderFunction<C> = Box<dyn Fn((u16, u16), (u16, u16), &[crate::render::Span], Option<usize>, &mut C) -> Option<Vec<crate::render::Span>>>;
+type UpdateHandler<C> = Box<dyn Fn(&mut ListWidget<C>, &mut C, &mut crate::App<C>, &mut Scene<C>, &[ListEvent])>;
+
+/// The interactions a list widget reports to its update handler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListEvent {
+    /// The selection moved to the item at the given index (by the keyboard or a click).
+    SelectionChanged(usize),
+    /// The item at the given index was activated (by Return or a double click).
+    Activated(usize),
+}
+
+/// Builder for creating ListWidget instances with a fluent interface.
+/// Maintains configuration state until build() is called to create the actual widget.
+pub struct ListWidgetBuilder<C> {
+    /// The unique name identifier for the widget.
+    name: String,
+    /// The z-index depth of the widget; higher values render on top of lower ones.
+    depth: Option<u16>,
+    /// Whether the widget should have a border.
+    border: bool,
+    /// The title of the widget, if any.
+    title: Option<String>,
+    /// The theme slot coloring the border and title, if any.
+    theme_slot: Option<String>,
+    /// The glyphs the border is drawn with.
+    border_style: crate::render::BorderStyle,
+    /// The blank space between the border (or edge) and the content.
+    padding: crate::render::Padding,
+    /// Where the title sits along the top border.
+    title_alignment: crate::render::Alignment,
+    /// The title drawn in the bottom border and its alignment, if any.
+    bottom_title: Option<(String, crate::render::Alignment)>,
+    /// The size and position configuration for the widget.
+    pub size_and_position: SizeAndPosition,
+    /// The custom render function for the widget, if any.
+    pub render_function: Option<RenderFunction<C>>,
+    /// The index of the parent widget in the scene graph, if any.
+    parent: Option<usize>,
+    /// The items initially in the list.
+    items: Vec<crate::render::Span>,
+    /// The backgrounds of the even and odd items, if the list is striped.
+    row_striping: Option<(Option<crate::render::ColorType>, Option<crate::render::ColorType>)>,
+
+    update_handler: Option<UpdateHandler<C>>,
+
+    /// The keywords the widget's window is tagged with.
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+    /// The colors and modifiers the whole window is painted with (i.e. the border's color).
+    colors: Vec<crate::render::ColorType>,
+    /// Whether the window starts hidden.
+    hidden: bool,
+    /// The layer the window is drawn in.
+    layer: crate::render::Layer,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+impl<C> ListWidgetBuilder<C> {
+    /// Sets the items initially in the list. The first item starts out selected.
+    pub fn with_items(mut self, items: Vec<crate::render::Span>) -> Self {
+        self.items = items;
+        self
+    }
+
+    /// Stripes the items with alternating backgrounds (see `Window::set_row_striping`). The stripes
+    /// stay with the items as the list scrolls, and the selection highlight is drawn over them.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{ListWidget, ListWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::{CellColor, Colored, ColorType, Frame, Rect, Span};
+    /// # struct AppData;
+    /// let mut app = term_render::App::<AppData>::new_headless(8, 3);
+    /// let mut scene = term_render::widget::Scene::new();
+    /// let items = (0..6).map(|index| Span::from_tokens(vec![Colored::new(format!("item {}", index))])).collect();
+    /// let list = ListWidgetBuilder::<AppData>::builder(String::from("list"))
+    ///     .with_items(items)
+    ///     .with_row_striping(Some(ColorType::OnBlue), Some(ColorType::OnRed))
+    ///     .with_position((0, 0))
+    ///     .with_size((8, 3))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .unwrap();
+    /// let area = Rect { width: 8, height: 3 };
+    /// let mut render = |scene: &mut term_render::widget::Scene<AppData>| {
+    ///     scene.update_widget_renderer(list, &mut app.renderer.write(), &area, &mut AppData).unwrap();
+    ///     let mut frame = Frame::new(8, 3);
+    ///     for (y, line) in app.renderer.write().snapshot_styled().iter().enumerate() {
+    ///         frame.write_str(0, y as u16, line);
+    ///     }
+    ///     frame
+    /// };
+    ///
+    /// let frame = render(&mut scene);
+    /// assert_eq!(frame.get(7, 0).unwrap().style.bg, Some(CellColor::Indexed(4)));
+    /// assert!(frame.get(0, 0).unwrap().style.reverse);  // the first item starts out selected
+    /// assert_eq!(frame.get(7, 1).unwrap().style.bg, Some(CellColor::Indexed(1)));
+    ///
+    /// // scrolled down by one, so the top row is now the odd item
+    /// scene.widget_as_mut::<ListWidget<AppData>>("list").unwrap().set_selected_index(3);
+    /// let frame = render(&mut scene);
+    /// assert_eq!(frame.to_lines()[0], "item 1");
+    /// assert_eq!(frame.get(7, 0).unwrap().style.bg, Some(CellColor::Indexed(1)));
+    /// assert_eq!(frame.get(7, 1).unwrap().style.bg, Some(CellColor::Indexed(4)));
+    /// assert!(frame.get(0, 2).unwrap().style.reverse && !frame.get(0, 1).unwrap().style.reverse);
+    /// ```
+    pub fn with_row_striping(mut self, even: Option<crate::render::ColorType>, odd: Option<crate::render::ColorType>) -> Self {
+        self.row_striping = Some((even, odd));
+        self
+    }
+}
+
+/// Implementations for the methods in `WidgetBuilder`.
+impl<C: 'static> WidgetBuilder<C> for ListWidgetBuilder<C> {
+    /// Constructs a `ListWidget`, an implementor of `Widget`, given the parameters.
+    /// Validates that the size is non-zero before creating the widget.
+    /// The method takes in a reference to the terminal's current area/size.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{ListWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::{Rect, Span, Colored};
+    /// # struct AppData;
+    /// let (widget, window) = ListWidgetBuilder::<AppData>::builder(String::new())
+    ///     .with_items(vec![Span::from_tokens(vec![Colored::new(String::from("First"))])])
+    ///     .with_position((0, 0))
+    ///     .with_size((20, 10))
+    ///     .build(&Rect { width: 80, height: 24 })  // replace &Rect with the actual terminal size (such as `&app.area.read()`)
+    ///     .expect("Invalid widget position or size.");
+    /// ```
+    fn build(mut self, display_area: &crate::render::Rect) -> Result<(Box<dyn Widget<C>>, crate::render::Window), WidgetBuilderError> {
+        let (size, position) = self.size_and_position.get_size_and_position(display_area);
+        if size.0 == 0 || size.1 == 0 {
+            return Err(WidgetBuilderError::InvalidGeometry { size, position })
+        }
+        let depth = self.depth.as_ref().unwrap_or(&0u16);
+        let mut window = crate::render::Window::new(position, *depth, size);
+        if self.border {  window.bordered();  }
+        window.set_theme_slot(self.theme_slot.clone());
+        window.set_border_style(self.border_style);
+        window.set_padding(self.padding);
+        if let Some(title) = &self.title {  window.titled(title.clone());  }
+        window.colorizes(std::mem::take(&mut self.colors));
+        if self.hidden {  window.hide();  }
+        window.set_layer(self.layer);
+        window.set_title_alignment(self.title_alignment);
+        if let Some((title, alignment)) = &self.bottom_title {
+            window.bottom_titled(crate::render::Span::from_tokens(vec![crate::render::Colored::new(title.clone())]));
+            window.set_bottom_title_alignment(*alignment);
+        }
+        Ok((Box::new(ListWidget::<C> {
+            children: vec![],
+            name: self.name,
+            parent_index: self.parent,
+            size_and_position: self.size_and_position,
+            render_function: self.render_function,
+            update_handler: self.update_handler,
+            border: self.border,
+            padding: self.padding,
+            selected_index: (!self.items.is_empty()).then_some(0),
+            items: self.items,
+            row_striping: self.row_striping,
+            scroll_offset: 0,
+            scroll_remainder: 0.0,
+            selected: false,
+            keywords: self.keywords,
+            tooltip: self.tooltip,
+            __phantom: std::marker::PhantomData,
+        }), window))
+    }
+
+    /// Sets the widget's fixed position (static layout).
+    /// Retrains the dynamic proportions of any dynamic positioning configuration already in place.
+    fn with_position(mut self, position: (u16, u16)) -> Self {
+        self.size_and_position.position_offset = (position.0 as i16, position.1 as i16);
+        self
+    }
+
+    /// Sets the widget's fixed position (static layout).
+    /// Retrains the dynamic proportions of any dynamic size configuration already in place.
+    fn with_size(mut self, size: (u16, u16)) -> Self {
+        self.size_and_position.size_offset = (size.0 as i16, size.1 as i16);
+        self
+    }
+
+    /// Configures dynamic positioning based on terminal size with a fixed offset.
+    fn with_dynamic_position(mut self, position_offset: (i16, i16), position_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.position_offset = position_offset;
+        self.size_and_position.position_area_percent = position_area_percent;
+        self
+    }
+
+    /// Configures dynamic sizing based on terminal size with a fixed offset.
+    fn with_dynamic_size(mut self, size_offset: (i16, i16), size_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.size_offset = size_offset;
+        self.size_and_position.size_area_percent = size_area_percent;
+        self
+    }
+
+    /// Sets whether the widget should have a border. By default, all widgets are borderless.
+    fn with_border(mut self, border: bool) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Sets the widget's title (displayed in border if enabled; invisible otherwise).
+    fn with_title(mut self, title: String) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Assigns a depth to the widget. Higher values represent UI elements that are stacked further
+    /// ontop of other elements. By default, the builder instance has a depth of None, which corresponds
+    /// to 0 (root depth).
+    fn with_depth(mut self, depth: u16) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// The type representing the renderer closure.
+    type RendererType = RenderFunction<C>;
+    /// Sets the rendering closure that generates content for the widget.
+    /// The closure receives the size, position, the visible slice of items, and the index of the
+    /// selected item within that slice (None if the selection is scrolled out of view or the list is empty).
+    /// By default, the items are drawn as they are, with the selected item highlighted (reversed).
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{ListWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::{Rect, ColorType};
+    /// # struct AppData;
+    /// // underlining the selected item instead of reversing it
+    /// let closure = Box::new(|_size, _position, items: &[term_render::render::Span], selected: Option<usize>, _data: &mut AppData| {
+    ///     let mut items = items.to_vec();
+    ///     if let Some(item) = selected.and_then(|selected| items.get_mut(selected)) {
+    ///         item.add_color(ColorType::Underline);
+    ///     }
+    ///     Some(items)
+    /// });
+    /// let (widget, window) = ListWidgetBuilder::<AppData>::builder(String::new())
+    ///     .with_renderer(closure)
+    ///     .with_position((0, 0))
+    ///     .with_size((20, 10))
+    ///     .build(&Rect { width: 80, height: 24 })
+    ///     .unwrap();
+    /// ```
+    fn with_renderer(mut self, renderer: Self::RendererType) -> Self {
+        self.render_function = Some(renderer);
+        self
+    }
+
+    /// Generates a new builder instance with a provided unique name identifier.
+    /// By default, size_and_position are no compatible and require the user to
+    /// provide them using the other `WidgetBuilder` trait functions.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{ListWidgetBuilder, WidgetBuilder};
+    /// # struct AppData;
+    /// let builder = ListWidgetBuilder::<AppData>::builder(String::from("Widget Name"));
+    /// ```
+    fn builder(name: String) -> Self {
+        Self {
+            name,
+            depth: None,
+            size_and_position: SizeAndPosition::default(),
+            render_function: None,
+            border: false,
+            title: None,
+            theme_slot: None,
+            border_style: crate::render::BorderStyle::Plain,
+            padding: crate::render::Padding::default(),
+            title_alignment: crate::render::Alignment::Center,
+            bottom_title: None,
+            parent: None,
+            items: vec![],
+            row_striping: None,
+            update_handler: None,
+            keywords: vec![],
+            tooltip: None,
+            colors: vec![],
+            hidden: false,
+            layer: crate::render::Layer::Normal,
+            __phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the SizeAndPosition configuration directly.
+    fn with_sap(mut self, sap: SizeAndPosition) -> Self {
+        self.size_and_position = sap;
+        self
+    }
+
+    /// Sets which point of the widget is placed at its computed position.
+    fn with_anchor(mut self, anchor: Anchor) -> Self {
+        self.size_and_position.anchor = anchor;
+        self
+    }
+
+    /// Sets the smallest size the widget can shrink to.
+    fn with_min_size(mut self, min_size: (u16, u16)) -> Self {
+        self.size_and_position.min_size = Some(min_size);
+        self
+    }
+
+    /// Sets the largest size the widget can grow to.
+    fn with_max_size(mut self, max_size: (u16, u16)) -> Self {
+        self.size_and_position.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets whether the widget is positioned relative to its parent's window.
+    fn with_relative_to_parent(mut self, relative: bool) -> Self {
+        self.size_and_position.position_mode = match relative {
+            true => PositionMode::RelativeToParent,
+            false => PositionMode::Absolute,
+        };
+        self
+    }
+
+    /// Sets whether the widget is clipped to its parent's window.
+    fn with_clip_to_parent(mut self, clip: bool) -> Self {
+        self.size_and_position.clip_to_parent = clip;
+        self
+    }
+
+    /// Slides the widget into place from the given offset when it's first drawn.
+    fn with_entrance_animation(mut self, from_offset: (i16, i16), duration: std::time::Duration) -> Self {
+        self.size_and_position.animate_from_offset(from_offset, duration, Easing::EaseOut);
+        self
+    }
+
+    /// Colors the border and title with a slot of the app's theme.
+    fn with_theme_slot(mut self, slot: &str) -> Self {
+        self.theme_slot = Some(slot.to_owned());
+        self
+    }
+
+    /// Sets the glyphs the border is drawn with.
+    fn with_border_style(mut self, style: crate::render::BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Sets the blank space between the border (or edge) and the content.
+    fn with_padding(mut self, padding: crate::render::Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets where the title sits along the top border.
+    fn with_title_alignment(mut self, alignment: crate::render::Alignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Sets the title drawn in the bottom border and its alignment.
+    fn with_bottom_title(mut self, title: String, alignment: crate::render::Alignment) -> Self {
+        self.bottom_title = Some((title, alignment));
+        self
+    }
+
+    type FunctionType = Option<UpdateHandler<C>>;
+    /// Sets a closure which is called after the list handles its events each frame.
+    /// The closure receives the `ListEvent`s which occurred this frame (usually none).
+    fn with_update_handler(mut self, handler: Self::FunctionType) -> Self {
+        self.update_handler = handler;
+        self
+    }
+
+    /// Sets the parent widget index for this widget, if any.
+    /// By default, the parent is None, indicating a root node.
+    fn with_parent(mut self, parent: Option<usize>) -> Self {
+        self.parent = parent;
+        self
+    }
+
+    /// Tags the widget's window with the given keywords (see `Scene::widgets_by_keyword`).
+    fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Gives the widget a tooltip, shown once the mouse rests over it (see `Scene::set_tooltip_delay`).
+    fn with_tooltip(mut self, tooltip: impl Into<Tooltip<C>>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Paints the whole window with the colors and modifiers (see `Window::colorizes`).
+    fn with_colors(mut self, colors: Vec<crate::render::ColorType>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Sets whether the widget starts hidden (see `Scene::set_widget_visible`).
+    fn with_hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Sets the layer the widget's window is drawn in (see `Window::set_layer`).
+    fn with_layer(mut self, layer: crate::render::Layer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Builds the widget and adds it to the provided scene, returning the new widget's index in the scene graph.
+    /// This method combines the `build` and `scene.add_widget` calls into one for convenience.
+    /// If building the widget fails, an error is returned instead.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{ListWidgetBuilder, WidgetBuilder};
+    /// # struct AppData;
+    /// let mut app = term_render::App::<AppData>::new_headless(80, 24);
+    /// let mut scene = term_render::widget::Scene::new();
+    /// let widget_index = ListWidgetBuilder::<AppData>::builder(String::from("My Widget"))
+    ///     .with_position((5, 5))
+    ///     .with_size((20, 10))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .expect("Failed to build and add widget to scene.");
+    /// ```
+    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetError> {
+        let (widget, window) = self.build(&app.area.read())?;
+        scene.add_widget(widget, window, &mut app.renderer.write())
+    }
+}
+
+/// A list of items (one `Span` per row) with a selection, scrolled to fit within the widget.
+///
+/// Clicking an item selects it (and the list), after which Up/Down move the selection,
+/// PageUp/PageDown move it by the visible height, Home/End jump to the first/last item, and Return
+/// activates the selected item. Double-clicking an item also activates it. The mouse wheel
+/// scrolls the list while over it without moving the selection.
+pub struct ListWidget<C> {
+    /// The indices of child widgets in the scene graph.
+    children: Vec<usize>,
+
+    /// The unique name identifier for the widget. The rendering backend
+    /// relies on `String` names instead of widgets.
+    name: String,
+
+    /// The index of the parent widget in the scene graph, if any (None would
+    /// indicate the root node).
+    parent_index: Option<usize>,
+
+    /// Configuration for the widget's size and position, supporting both static and dynamic layouts.
+    pub size_and_position: SizeAndPosition,
+
+    /// Optional closure that generates the widget's rendered content from the visible items.
+    pub render_function: Option<RenderFunction<C>>,
+
+    /// Optional closure that handles updates to the widget during event processing.
+    update_handler: Option<UpdateHandler<C>>,
+
+    border: bool,
+    padding: crate::render::Padding,
+
+    /// The items in the list.
+    items: Vec<crate::render::Span>,
+    /// The backgrounds of the even and odd items, if the list is striped.
+    pub row_striping: Option<(Option<crate::render::ColorType>, Option<crate::render::ColorType>)>,
+    /// The index of the selected item (None only when the list is empty).
+    selected_index: Option<usize>,
+    /// The index of the first visible item.
+    scroll_offset: usize,
+    // the part of a row scrolled by the mouse wheel which hasn't been applied yet
+    scroll_remainder: f64,
+
+    /// Indicates whether the widget is currently selected (has the scene's focus for keyboard navigation).
+    pub selected: bool,
+
+    /// The keywords the widget's window is tagged with (see `Scene::widgets_by_keyword`).
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+impl<C> ListWidget<C> {
+    /// Returns the items in the list.
+    pub fn get_items(&self) -> &[crate::render::Span] {
+        &self.items
+    }
+
+    /// Replaces the items in the list, clamping the selection to the new items.
+    pub fn set_items(&mut self, items: Vec<crate::render::Span>) {
+        self.items = items;
+        self.clamp_selection();
+    }
+
+    /// Adds an item to the end of the list (selecting it if the list was empty).
+    pub fn push_item(&mut self, item: crate::render::Span) {
+        self.items.push(item);
+        self.clamp_selection();
+    }
+
+    /// Inserts an item at the given index, keeping the same item selected.
+    /// The index is clamped to the length of the list.
+    pub fn insert_item(&mut self, index: usize, item: crate::render::Span) {
+        let index = index.min(self.items.len());
+        self.items.insert(index, item);
+        if let Some(selected) = self.selected_index && selected >= index {
+            self.selected_index = Some(selected + 1);
+        }
+        self.clamp_selection();
+    }
+
+    /// Removes the item at the given index, returning it if it existed. The selection stays on
+    /// the same item if possible, otherwise it's clamped to the remaining items.
+    pub fn remove_item(&mut self, index: usize) -> Option<crate::render::Span> {
+        if index >= self.items.len() {  return None;  }
+        let item = self.items.remove(index);
+        if let Some(selected) = self.selected_index && selected > index {
+            self.selected_index = Some(selected - 1);
+        }
+        self.clamp_selection();
+        Some(item)
+    }
+
+    /// Returns the index of the selected item (None if the list is empty).
+    pub fn get_selected_index(&self) -> Option<usize> {
+        self.selected_index
+    }
+
+    /// Returns the selected item, if any.
+    pub fn get_selected_item(&self) -> Option<&crate::render::Span> {
+        self.selected_index.and_then(|index| self.items.get(index))
+    }
+
+    /// Selects the item at the given index (clamped to the list), scrolling it into view.
+    /// Returns true if the selection changed.
+    pub fn set_selected_index(&mut self, index: usize) -> bool {
+        if self.items.is_empty() {  return false;  }
+        let index = index.min(self.items.len() - 1);
+        let changed = self.selected_index != Some(index);
+        self.selected_index = Some(index);
+        self.scroll_into_view(index);
+        changed
+    }
+
+    /// Returns the index of the first visible item.
+    pub fn get_scroll_offset(&self) -> usize {
+        self.scroll_offset
+    }
+
+    /// Scrolls by the given number of rows (negative scrolls up), clamped to the items.
+    /// Returns true if the offset changed.
+    pub fn scroll_by(&mut self, rows: i32) -> bool {
+        let max_scroll = self.items.len().saturating_sub(self.get_visible_rows());
+        let offset = (self.scroll_offset as i64 + rows as i64).clamp(0, max_scroll as i64) as usize;
+        let changed = offset != self.scroll_offset;
+        self.scroll_offset = offset;
+        changed
+    }
+
+    /// Returns the number of items which fit within the widget (excluding the border).
+    pub fn get_visible_rows(&self) -> usize {
+        let (size, _) = self.size_and_position.get_last();
+        size.1.saturating_sub(self.border as u16 * 2 + self.padding.top + self.padding.bottom) as usize
+    }
+
+    /// Scrolls the minimum amount needed for the item at the given index to be visible.
+    pub fn scroll_into_view(&mut self, index: usize) {
+        let rows = self.get_visible_rows().max(1);
+        if index < self.scroll_offset {
+            self.scroll_offset = index;
+        } else if index >= self.scroll_offset + rows {
+            self.scroll_offset = index + 1 - rows;
+        }
+    }
+
+    /// Returns the index of the item drawn at the given terminal position, if there is one.
+    pub fn get_item_at(&self, position: (u16, u16)) -> Option<usize> {
+        if !self.size_and_position.is_collided(position) {  return None;  }
+        let (size, widget_position) = self.size_and_position.get_last();
+        let top = widget_position.1 + self.border as u16 + self.padding.top;
+        let bottom = (widget_position.1 + size.1).saturating_sub(self.border as u16 + self.padding.bottom);
+        if position.1 < top || position.1 >= bottom {  return None;  }
+        let index = self.scroll_offset + (position.1 - top) as usize;
+        (index < self.items.len()).then_some(index)
+    }
+
+    /// Keeps the selection and scroll offset within the items.
+    fn clamp_selection(&mut self) {
+        self.selected_index = match self.items.len() {
+            0 => None,
+            len => Some(self.selected_index.unwrap_or(0).min(len - 1)),
+        };
+        self.scroll_by(0);
+    }
+
+    /// Moves the selection by the given number of items, returning the event if it changed.
+    fn move_selection(&mut self, rows: i64) -> Option<ListEvent> {
+        let selected = self.selected_index? as i64;
+        let index = (selected + rows).clamp(0, self.items.len() as i64 - 1) as usize;
+        self.set_selected_index(index).then_some(ListEvent::SelectionChanged(index))
+    }
+}
+
+/// Implementation of the methods for ListWidget
+impl<C: 'static> Widget<C> for ListWidget<C> {
+    /// Returns the widget's name as an identifier. The rendering backend relies
+    /// on `String` names instead of widgets.
+    fn get_window_ref(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Handles selecting items through the mouse and keyboard (while selected), and scrolling
+    /// through the mouse wheel (while over the list). The update handler is then called with the
+    /// events which occurred.
+    fn update_with_events(&mut self, data: &mut C, app: &mut crate::App<C>, scene: &mut Scene<C>) {
+        let _ = self.size_and_position.get_size_and_position(&app.area.read());
+        self.scroll_by(0);  // the list may have been resized
+        let widget_index = scene.get_widget_index(self.get_window_ref()).unwrap_or(0);
+        let mut list_events = vec![];
+
+        let events = app.events.read();
+        let mut wheel_sign = 0.0;
+        if let Some(event) = &events.mouse_event && event.state == crate::event_handler::MouseState::Press {
+            match event.event_type {
+                // the scene focuses the list when it receives the click
+                crate::event_handler::MouseEventType::Left => {
+                    if self.selected && let Some(index) = self.get_item_at(event.position) {
+                        if self.set_selected_index(index) {
+                            list_events.push(ListEvent::SelectionChanged(index));
+                        }
+                        if event.click_count == 2 {
+                            list_events.push(ListEvent::Activated(index));
+                        }
+                    }
+                },
+                crate::event_handler::MouseEventType::Up => wheel_sign = -1.0,
+                crate::event_handler::MouseEventType::Down => wheel_sign = 1.0,
+                _ => {},
+            }
+        }
+        // the wheel scrolls by the accumulated (smoothed) amount of only the scrolls over the list
+        let over = |position: (u16, u16)| self.is_collided(position) &&
+            !scene.is_click_blocked_all(widget_index, position, app).unwrap_or(false);
+        let delta = events.scroll_at(over);
+        if !events.mouse_event.as_ref().is_some_and(|event| over(event.position)) {  wheel_sign = 0.0;  }
+        if delta != 0.0 || wheel_sign != 0.0 {
+            self.scroll_remainder += delta;
+            // making sure each notch of the wheel responds immediately
+            if wheel_sign != 0.0 && self.scroll_remainder.abs() < 1.0 {  self.scroll_remainder = wheel_sign;  }
+            let rows = self.scroll_remainder.trunc();
+            self.scroll_remainder -= rows;
+            self.scroll_by(rows as i32);
+        } else {
+            self.scroll_remainder = 0.0;
+        }
+
+        if self.selected {
+            let page = self.get_visible_rows().max(1) as i64;
+            let len = self.items.len() as i64;
+            let moves = [
+                (crate::event_handler::KeyCode::Up, -1),
+                (crate::event_handler::KeyCode::Down, 1),
+                (crate::event_handler::KeyCode::PageUp, -page),
+                (crate::event_handler::KeyCode::PageDown, page),
+                (crate::event_handler::KeyCode::Home, -len),
+                (crate::event_handler::KeyCode::End, len),
+            ];
+            for (key, rows) in moves {
+                if events.contains_key_code(key) && let Some(event) = self.move_selection(rows) {
+                    list_events.push(event);
+                }
+            }
+            if events.contains_key_code(crate::event_handler::KeyCode::Return) && let Some(index) = self.selected_index {
+                list_events.push(ListEvent::Activated(index));
+            }
+        }
+        drop(events);
+
+        if let Some(handler) = self.update_handler.take() {
+            handler(self, data, app, scene, &list_events);
+            self.update_handler = Some(handler);
+        }
+    }
+
+    /// Draws the visible items, either through the render function or with the selected
+    /// item reversed. Rows past the end of the list are left empty.
+    fn update_render(&mut self, window: &mut crate::render::Window, area: &crate::render::Rect, app_state: &mut C) -> bool {
+        let (size, position) = self.size_and_position.get_size_and_position(area);
+        window.resize(size);
+        window.r#move(position);
+        let rows = self.get_visible_rows();
+        let end = (self.scroll_offset + rows).min(self.items.len());
+        let visible = &self.items[self.scroll_offset.min(end)..end];
+        let selected = self.selected_index
+            .filter(|index| (self.scroll_offset..end).contains(index))
+            .map(|index| index - self.scroll_offset);
+        let mut lines = match &self.render_function {
+            Some(render_function) => match render_function(size, position, visible, selected, app_state) {
+                Some(lines) => lines,
+                None => return false,
+            },
+            None => {
+                let width = size.0.saturating_sub(self.border as u16 * 2 + self.padding.left + self.padding.right) as usize;
+                let mut lines = visible.to_vec();
+                if let Some(line) = selected.and_then(|selected| lines.get_mut(selected)) {
+                    // padding the highlight out to the full width of the list
+                    let padding = width.saturating_sub(line.size());
+                    line.push(crate::render::Colored::new(" ".repeat(padding)));
+                    line.add_color(crate::render::ColorType::Reverse);
+                } lines
+            },
+        };
+        lines.resize(lines.len().max(rows), crate::render::Span::default());
+        // the stripes are anchored to the items, so they don't flip as the list scrolls
+        window.set_striping(self.row_striping.map(|(even, odd)| {
+            crate::render::RowStriping { even, odd, start_row: self.scroll_offset, header_rows: 0 }
+        }));
+        window.try_update_lines(lines)
+    }
+
+    /// Returns the indices of child widgets in the scene graph.
+    fn get_children_indexes(&self) -> Vec<usize> {
+        self.children.clone()
+    }
+
+    /// Adds a child widget index to this widget.
+    fn add_child_index(&mut self, index: usize) {
+        self.children.push(index);
+    }
+
+    /// Removes a child widget index from this widget
+    fn remove_child_index(&mut self, index: usize) {
+        self.children.remove(index);
+    }
+
+    /// Clears all child widget indices from this widget
+    fn clear_children_indexes(&mut self) {
+        self.children.clear();
+    }
+
+    /// Returns the parent widget index if one exists, otherwise None.
+    fn get_parent_index(&self) -> Option<usize> {
+        self.parent_index
+    }
+
+    /// Sets the parent widget index for this widget, or None for a root node.
+    fn set_parent_index(&mut self, index: Option<usize>) {
+        self.parent_index = index;
+    }
+
+    /// Determines if a given position collides with the widget's area.
+    fn is_collided(&self, position: (u16, u16)) -> bool {
+        self.size_and_position.is_collided(position)
+    }
+
+    /// Returns the widget as `Any` for downcasting.
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget as a mutable `Any` for downcasting.
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget's size and position configuration.
+    fn get_size_and_position(&self) -> Option<&SizeAndPosition> {
+        Some(&self.size_and_position)
+    }
+
+    /// Returns the widget's size and position configuration mutably.
+    fn get_size_and_position_mut(&mut self) -> Option<&mut SizeAndPosition> {
+        Some(&mut self.size_and_position)
+    }
+
+    /// Returns the keywords the widget's window is tagged with.
+    fn get_keywords(&self) -> &[String] {
+        &self.keywords
+    }
+
+    /// Returns the tooltip shown while the mouse rests over the widget, if any.
+    fn get_tooltip(&self) -> Option<&Tooltip<C>> {
+        self.tooltip.as_ref()
+    }
+
+    /// Lists take focus when clicked, or through Tab/Shift+Tab.
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    /// The list is selected (responds to the keyboard) while it has the scene's focus.
+    fn set_focused(&mut self, focused: bool) {
+        self.selected = focused;
+    }
+
+    /// Applies the offset and clip inherited from the parent widgets to the size and position.
+    fn set_parent_transform(&mut self, offset: (i16, i16), clip: Option<Region>) {
+        self.size_and_position.parent_offset = offset;
+        self.size_and_position.clip = clip;
+    }
+}
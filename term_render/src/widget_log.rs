@@ -0,0 +1,775 @@
+use crate::widget_impls::*;
+use crate::widget::*;
+
+type RenderFunction<C> = Box<dyn Fn((u16, u16), (u16, u16), &[crate::render::Span], &mut C) -> Option<Vec<crate::render::Span>>>;
+type UpdateHandler<C> = Box<dyn Fn(&mut LogWidget<C>, &mut C, &mut crate::App<C>, &mut Scene<C>)>;
+
+/// The number of lines a log keeps by default before dropping the oldest ones.
+pub const DEFAULT_LOG_HISTORY: usize = 10_000;
+
+/// Builder for creating LogWidget instances with a fluent interface.
+/// Maintains configuration state until build() is called to create the actual widget.
+pub struct LogWidgetBuilder<C> {
+    /// The unique name identifier for the widget.
+    name: String,
+    /// The z-index depth of the widget; higher values render on top of lower ones.
+    depth: Option<u16>,
+    /// Whether the widget should have a border.
+    border: bool,
+    /// The title of the widget, if any.
+    title: Option<String>,
+    /// The theme slot coloring the border and title, if any.
+    theme_slot: Option<String>,
+    /// The glyphs the border is drawn with.
+    border_style: crate::render::BorderStyle,
+    /// The blank space between the border (or edge) and the content.
+    padding: crate::render::Padding,
+    /// Where the title sits along the top border.
+    title_alignment: crate::render::Alignment,
+    /// The title drawn in the bottom border and its alignment, if any.
+    bottom_title: Option<(String, crate::render::Alignment)>,
+    /// The size and position configuration for the widget.
+    pub size_and_position: SizeAndPosition,
+    /// The custom render function for the widget, if any.
+    pub render_function: Option<RenderFunction<C>>,
+    /// The index of the parent widget in the scene graph, if any.
+    parent: Option<usize>,
+    /// The lines initially in the log.
+    lines: Vec<crate::render::Span>,
+    /// The most lines the log keeps.
+    max_lines: usize,
+    /// Whether the log starts out following (stuck to) the newest line.
+    follow: bool,
+
+    update_handler: Option<UpdateHandler<C>>,
+
+    /// The keywords the widget's window is tagged with.
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+    /// The colors and modifiers the whole window is painted with (i.e. the border's color).
+    colors: Vec<crate::render::ColorType>,
+    /// Whether the window starts hidden.
+    hidden: bool,
+    /// The layer the window is drawn in.
+    layer: crate::render::Layer,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+impl<C> LogWidgetBuilder<C> {
+    /// Sets the lines initially in the log (only the newest `max_lines` are kept).
+    pub fn with_lines(mut self, lines: Vec<crate::render::Span>) -> Self {
+        self.lines = lines;
+        self
+    }
+
+    /// Sets the most lines the log keeps; pushing past it drops the oldest lines.
+    /// By default, `DEFAULT_LOG_HISTORY` lines are kept. The limit is at least one line.
+    pub fn with_max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = max_lines.max(1);
+        self
+    }
+
+    /// Sets whether the log starts out following the newest line. By default, it does.
+    pub fn with_follow(mut self, follow: bool) -> Self {
+        self.follow = follow;
+        self
+    }
+}
+
+/// Implementations for the methods in `WidgetBuilder`.
+impl<C: 'static> WidgetBuilder<C> for LogWidgetBuilder<C> {
+    /// Constructs a `LogWidget`, an implementor of `Widget`, given the parameters.
+    /// Validates that the size is non-zero before creating the widget.
+    /// The method takes in a reference to the terminal's current area/size.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{LogWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::{Rect, Span, Colored};
+    /// # struct AppData;
+    /// let (widget, window) = LogWidgetBuilder::<AppData>::builder(String::new())
+    ///     .with_lines(vec![Span::from_tokens(vec![Colored::new(String::from("Started"))])])
+    ///     .with_max_lines(1000)
+    ///     .with_position((0, 0))
+    ///     .with_size((40, 10))
+    ///     .build(&Rect { width: 80, height: 24 })  // replace &Rect with the actual terminal size (such as `&app.area.read()`)
+    ///     .expect("Invalid widget position or size.");
+    /// ```
+    fn build(mut self, display_area: &crate::render::Rect) -> Result<(Box<dyn Widget<C>>, crate::render::Window), WidgetBuilderError> {
+        let (size, position) = self.size_and_position.get_size_and_position(display_area);
+        if size.0 == 0 || size.1 == 0 {
+            return Err(WidgetBuilderError::InvalidGeometry { size, position })
+        }
+        let depth = self.depth.as_ref().unwrap_or(&0u16);
+        let mut window = crate::render::Window::new(position, *depth, size);
+        if self.border {  window.bordered();  }
+        window.set_theme_slot(self.theme_slot.clone());
+        window.set_border_style(self.border_style);
+        window.set_padding(self.padding);
+        if let Some(title) = &self.title {  window.titled(title.clone());  }
+        window.colorizes(std::mem::take(&mut self.colors));
+        if self.hidden {  window.hide();  }
+        window.set_layer(self.layer);
+        window.set_title_alignment(self.title_alignment);
+        if let Some((title, alignment)) = &self.bottom_title {
+            window.bottom_titled(crate::render::Span::from_tokens(vec![crate::render::Colored::new(title.clone())]));
+            window.set_bottom_title_alignment(*alignment);
+        }
+        let mut widget = LogWidget::<C> {
+            children: vec![],
+            name: self.name,
+            parent_index: self.parent,
+            size_and_position: self.size_and_position,
+            render_function: self.render_function,
+            update_handler: self.update_handler,
+            border: self.border,
+            padding: self.padding,
+            lines: std::collections::VecDeque::new(),
+            max_lines: self.max_lines,
+            scroll_offset: 0,
+            scroll_remainder: 0.0,
+            follow: self.follow,
+            selected: false,
+            keywords: self.keywords,
+            tooltip: self.tooltip,
+            __phantom: std::marker::PhantomData,
+        };
+        for line in self.lines {
+            widget.push_line(line);
+        }
+        Ok((Box::new(widget), window))
+    }
+
+    /// Sets the widget's fixed position (static layout).
+    /// Retrains the dynamic proportions of any dynamic positioning configuration already in place.
+    fn with_position(mut self, position: (u16, u16)) -> Self {
+        self.size_and_position.position_offset = (position.0 as i16, position.1 as i16);
+        self
+    }
+
+    /// Sets the widget's fixed position (static layout).
+    /// Retrains the dynamic proportions of any dynamic size configuration already in place.
+    fn with_size(mut self, size: (u16, u16)) -> Self {
+        self.size_and_position.size_offset = (size.0 as i16, size.1 as i16);
+        self
+    }
+
+    /// Configures dynamic positioning based on terminal size with a fixed offset.
+    fn with_dynamic_position(mut self, position_offset: (i16, i16), position_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.position_offset = position_offset;
+        self.size_and_position.position_area_percent = position_area_percent;
+        self
+    }
+
+    /// Configures dynamic sizing based on terminal size with a fixed offset.
+    fn with_dynamic_size(mut self, size_offset: (i16, i16), size_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.size_offset = size_offset;
+        self.size_and_position.size_area_percent = size_area_percent;
+        self
+    }
+
+    /// Sets whether the widget should have a border. By default, all widgets are borderless.
+    fn with_border(mut self, border: bool) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Sets the widget's title (displayed in border if enabled; invisible otherwise).
+    fn with_title(mut self, title: String) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Assigns a depth to the widget. Higher values represent UI elements that are stacked further
+    /// ontop of other elements. By default, the builder instance has a depth of None, which corresponds
+    /// to 0 (root depth).
+    fn with_depth(mut self, depth: u16) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// The type representing the renderer closure.
+    type RendererType = RenderFunction<C>;
+    /// Sets the rendering closure that generates content for the widget.
+    /// The closure receives the size, position, and the visible lines of the log (oldest first).
+    /// By default, the lines are drawn as they are.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{LogWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::{Rect, ColorType};
+    /// # struct AppData;
+    /// // dimming every line but the newest one visible
+    /// let closure = Box::new(|_size, _position, lines: &[term_render::render::Span], _data: &mut AppData| {
+    ///     let mut lines = lines.to_vec();
+    ///     let newest = lines.len().saturating_sub(1);
+    ///     for line in &mut lines[..newest] {
+    ///         line.add_color(ColorType::Dim);
+    ///     }
+    ///     Some(lines)
+    /// });
+    /// let (widget, window) = LogWidgetBuilder::<AppData>::builder(String::new())
+    ///     .with_renderer(closure)
+    ///     .with_position((0, 0))
+    ///     .with_size((40, 10))
+    ///     .build(&Rect { width: 80, height: 24 })
+    ///     .unwrap();
+    /// ```
+    fn with_renderer(mut self, renderer: Self::RendererType) -> Self {
+        self.render_function = Some(renderer);
+        self
+    }
+
+    /// Generates a new builder instance with a provided unique name identifier.
+    /// By default, size_and_position are no compatible and require the user to
+    /// provide them using the other `WidgetBuilder` trait functions.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{LogWidgetBuilder, WidgetBuilder};
+    /// # struct AppData;
+    /// let builder = LogWidgetBuilder::<AppData>::builder(String::from("Widget Name"));
+    /// ```
+    fn builder(name: String) -> Self {
+        Self {
+            name,
+            depth: None,
+            size_and_position: SizeAndPosition::default(),
+            render_function: None,
+            border: false,
+            title: None,
+            theme_slot: None,
+            border_style: crate::render::BorderStyle::Plain,
+            padding: crate::render::Padding::default(),
+            title_alignment: crate::render::Alignment::Center,
+            bottom_title: None,
+            parent: None,
+            lines: vec![],
+            max_lines: DEFAULT_LOG_HISTORY,
+            follow: true,
+            update_handler: None,
+            keywords: vec![],
+            tooltip: None,
+            colors: vec![],
+            hidden: false,
+            layer: crate::render::Layer::Normal,
+            __phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the SizeAndPosition configuration directly.
+    fn with_sap(mut self, sap: SizeAndPosition) -> Self {
+        self.size_and_position = sap;
+        self
+    }
+
+    /// Sets which point of the widget is placed at its computed position.
+    fn with_anchor(mut self, anchor: Anchor) -> Self {
+        self.size_and_position.anchor = anchor;
+        self
+    }
+
+    /// Sets the smallest size the widget can shrink to.
+    fn with_min_size(mut self, min_size: (u16, u16)) -> Self {
+        self.size_and_position.min_size = Some(min_size);
+        self
+    }
+
+    /// Sets the largest size the widget can grow to.
+    fn with_max_size(mut self, max_size: (u16, u16)) -> Self {
+        self.size_and_position.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets whether the widget is positioned relative to its parent's window.
+    fn with_relative_to_parent(mut self, relative: bool) -> Self {
+        self.size_and_position.position_mode = match relative {
+            true => PositionMode::RelativeToParent,
+            false => PositionMode::Absolute,
+        };
+        self
+    }
+
+    /// Sets whether the widget is clipped to its parent's window.
+    fn with_clip_to_parent(mut self, clip: bool) -> Self {
+        self.size_and_position.clip_to_parent = clip;
+        self
+    }
+
+    /// Slides the widget into place from the given offset when it's first drawn.
+    fn with_entrance_animation(mut self, from_offset: (i16, i16), duration: std::time::Duration) -> Self {
+        self.size_and_position.animate_from_offset(from_offset, duration, Easing::EaseOut);
+        self
+    }
+
+    /// Colors the border and title with a slot of the app's theme.
+    fn with_theme_slot(mut self, slot: &str) -> Self {
+        self.theme_slot = Some(slot.to_owned());
+        self
+    }
+
+    /// Sets the glyphs the border is drawn with.
+    fn with_border_style(mut self, style: crate::render::BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Sets the blank space between the border (or edge) and the content.
+    fn with_padding(mut self, padding: crate::render::Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets where the title sits along the top border.
+    fn with_title_alignment(mut self, alignment: crate::render::Alignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Sets the title drawn in the bottom border and its alignment.
+    fn with_bottom_title(mut self, title: String, alignment: crate::render::Alignment) -> Self {
+        self.bottom_title = Some((title, alignment));
+        self
+    }
+
+    type FunctionType = Option<UpdateHandler<C>>;
+    /// Sets a closure which is called after the log handles its events each frame.
+    /// This is the natural place to push the lines which arrived since the last frame
+    /// (i.e. by draining a channel stored in the app's data).
+    fn with_update_handler(mut self, handler: Self::FunctionType) -> Self {
+        self.update_handler = handler;
+        self
+    }
+
+    /// Sets the parent widget index for this widget, if any.
+    /// By default, the parent is None, indicating a root node.
+    fn with_parent(mut self, parent: Option<usize>) -> Self {
+        self.parent = parent;
+        self
+    }
+
+    /// Tags the widget's window with the given keywords (see `Scene::widgets_by_keyword`).
+    fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Gives the widget a tooltip, shown once the mouse rests over it (see `Scene::set_tooltip_delay`).
+    fn with_tooltip(mut self, tooltip: impl Into<Tooltip<C>>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Paints the whole window with the colors and modifiers (see `Window::colorizes`).
+    fn with_colors(mut self, colors: Vec<crate::render::ColorType>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Sets whether the widget starts hidden (see `Scene::set_widget_visible`).
+    fn with_hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Sets the layer the widget's window is drawn in (see `Window::set_layer`).
+    fn with_layer(mut self, layer: crate::render::Layer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Builds the widget and adds it to the provided scene, returning the new widget's index in the scene graph.
+    /// This method combines the `build` and `scene.add_widget` calls into one for convenience.
+    /// If building the widget fails, an error is returned instead.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{LogWidgetBuilder, WidgetBuilder};
+    /// # struct AppData;
+    /// let mut app = term_render::App::<AppData>::new_headless(80, 24);
+    /// let mut scene = term_render::widget::Scene::new();
+    /// let widget_index = LogWidgetBuilder::<AppData>::builder(String::from("My Widget"))
+    ///     .with_position((5, 5))
+    ///     .with_size((40, 10))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .expect("Failed to build and add widget to scene.");
+    /// ```
+    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetError> {
+        let (widget, window) = self.build(&app.area.read())?;
+        scene.add_widget(widget, window, &mut app.renderer.write())
+    }
+}
+
+/// A tail-style log: lines are appended to the bottom, and the oldest are dropped once the
+/// history is full. The history is a ring buffer, and only the visible lines are handed to the
+/// window each frame, so the window's per-row dirty flags only re-render rows whose content
+/// changed (a scroll shifts the rows without redrawing the border or the rest of the screen).
+///
+/// While following, the log sticks to the newest line. Scrolling up (the mouse wheel while over
+/// the log, or Up/PageUp/Home while it's selected) stops following, and the view stays on the same
+/// lines as new ones arrive. Scrolling back to the bottom (or pressing End) follows again.
+/// # Example
+/// ```
+/// use term_render::widget::Scene;
+/// use term_render::widget_impls::{LogWidget, LogWidgetBuilder, WidgetBuilder};
+/// use term_render::render::{Colored, Span};
+///
+/// let mut app = term_render::App::<()>::new_headless(60, 20);
+/// let mut scene = Scene::new();
+/// LogWidgetBuilder::<()>::builder(String::from("log"))
+///     .with_border(true)
+///     .with_max_lines(1000)
+///     .with_position((0, 0))
+///     .with_size((40, 12))
+///     .add_to_scene(&mut app, &mut scene)
+///     .unwrap();
+/// let line = |index: usize| Span::from_tokens(vec![Colored::new(format!("line {}", index))]);
+/// // the number of rows which need to be drawn this frame
+/// let dirty_rows = |app: &mut term_render::App<()>| {
+///     let mut renderer = app.renderer.write();
+///     let area = renderer.get_window_area().clone();
+///     renderer.get_window_reference_mut(String::from("log")).get_render_closure(&area).len()
+/// };
+///
+/// // pushing 50k lines (a few per frame) only ever redraws up to the window's height
+/// let mut pushed = 0;
+/// while pushed < 50_000 {
+///     let log = scene.widget_as_mut::<LogWidget<()>>("log").unwrap();
+///     for _ in 0..5 {
+///         log.push_line(line(pushed));
+///         pushed += 1;
+///     }
+///     scene.update_all_widgets(&mut app, &mut ()).unwrap();
+///     assert!(dirty_rows(&mut app) <= 12);
+/// }
+/// let log = scene.widget_as_mut::<LogWidget<()>>("log").unwrap();
+/// assert_eq!(log.len(), 1000);
+/// assert_eq!(log.get_visible_lines().last(), Some(&line(49_999)));
+///
+/// // after scrolling up, new lines don't move the view (and so don't redraw anything)
+/// log.scroll_by(-100);
+/// assert!(!log.is_following());
+/// scene.update_all_widgets(&mut app, &mut ()).unwrap();
+/// let _ = dirty_rows(&mut app);
+/// let log = scene.widget_as_mut::<LogWidget<()>>("log").unwrap();
+/// let first = log.get_visible_lines()[0].clone();
+/// log.push_line(line(50_000));
+/// scene.update_all_widgets(&mut app, &mut ()).unwrap();
+/// assert_eq!(dirty_rows(&mut app), 0);
+/// let log = scene.widget_as_mut::<LogWidget<()>>("log").unwrap();
+/// assert_eq!(log.get_visible_lines()[0], first);
+///
+/// // and scrolling back to the bottom follows again
+/// log.scroll_by(1000);
+/// assert!(log.is_following());
+/// ```
+pub struct LogWidget<C> {
+    /// The indices of child widgets in the scene graph.
+    children: Vec<usize>,
+
+    /// The unique name identifier for the widget. The rendering backend
+    /// relies on `String` names instead of widgets.
+    name: String,
+
+    /// The index of the parent widget in the scene graph, if any (None would
+    /// indicate the root node).
+    parent_index: Option<usize>,
+
+    /// Configuration for the widget's size and position, supporting both static and dynamic layouts.
+    pub size_and_position: SizeAndPosition,
+
+    /// Optional closure that generates the widget's rendered content from the visible lines.
+    pub render_function: Option<RenderFunction<C>>,
+
+    /// Optional closure that handles updates to the widget during event processing.
+    update_handler: Option<UpdateHandler<C>>,
+
+    border: bool,
+    padding: crate::render::Padding,
+
+    /// The retained lines, oldest first.
+    lines: std::collections::VecDeque<crate::render::Span>,
+    /// The most lines which are retained.
+    max_lines: usize,
+    /// The index of the first visible line (ignored while following).
+    scroll_offset: usize,
+    // the part of a row scrolled by the mouse wheel which hasn't been applied yet
+    scroll_remainder: f64,
+    /// Whether the view sticks to the newest line.
+    follow: bool,
+
+    /// Indicates whether the widget is currently selected (has the scene's focus for keyboard navigation).
+    pub selected: bool,
+
+    /// The keywords the widget's window is tagged with (see `Scene::widgets_by_keyword`).
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+impl<C> LogWidget<C> {
+    /// Appends a line to the bottom of the log, dropping the oldest line if the history is full.
+    /// While not following, the view stays on the same lines (unless they were dropped).
+    pub fn push_line(&mut self, line: crate::render::Span) {
+        self.lines.push_back(line);
+        if self.lines.len() > self.max_lines {
+            self.lines.pop_front();
+            self.scroll_offset = self.scroll_offset.saturating_sub(1);
+        }
+    }
+
+    /// Appends each of the lines in order (see `push_line`).
+    pub fn extend_lines(&mut self, lines: impl IntoIterator<Item = crate::render::Span>) {
+        for line in lines {
+            self.push_line(line);
+        }
+    }
+
+    /// Removes every line from the log.
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.scroll_offset = 0;
+    }
+
+    /// Returns the number of lines retained.
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns whether the log has no lines.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Returns the retained lines, oldest first.
+    pub fn get_lines(&self) -> &std::collections::VecDeque<crate::render::Span> {
+        &self.lines
+    }
+
+    /// Returns the most lines the log retains.
+    pub fn get_max_lines(&self) -> usize {
+        self.max_lines
+    }
+
+    /// Sets the most lines the log retains (at least one), dropping the oldest lines past it.
+    pub fn set_max_lines(&mut self, max_lines: usize) {
+        self.max_lines = max_lines.max(1);
+        let excess = self.lines.len().saturating_sub(self.max_lines);
+        self.lines.drain(..excess);
+        self.scroll_offset = self.scroll_offset.saturating_sub(excess);
+    }
+
+    /// Returns whether the view sticks to the newest line.
+    pub fn is_following(&self) -> bool {
+        self.follow
+    }
+
+    /// Sets whether the view sticks to the newest line. Stopping keeps the view where it is.
+    pub fn set_follow(&mut self, follow: bool) {
+        self.scroll_offset = self.get_scroll_offset();
+        self.follow = follow;
+    }
+
+    /// Returns the index of the first visible line.
+    pub fn get_scroll_offset(&self) -> usize {
+        match self.follow {
+            true => self.get_max_scroll(),
+            false => self.scroll_offset.min(self.get_max_scroll()),
+        }
+    }
+
+    /// Scrolls so the given line is the first visible one, clamped to `get_max_scroll`.
+    /// Scrolling to the bottom follows the newest line, and scrolling anywhere else stops following.
+    pub fn set_scroll_offset(&mut self, offset: usize) {
+        self.scroll_offset = offset.min(self.get_max_scroll());
+        self.follow = self.scroll_offset == self.get_max_scroll();
+    }
+
+    /// Scrolls by the given number of lines (negative scrolls up), clamping at either end.
+    /// Returns true if the offset changed.
+    pub fn scroll_by(&mut self, rows: i32) -> bool {
+        let offset = self.get_scroll_offset();
+        self.set_scroll_offset((offset as i64 + rows as i64).max(0) as usize);
+        offset != self.get_scroll_offset()
+    }
+
+    /// Returns the largest scroll offset, where the newest line is at the bottom of the view.
+    pub fn get_max_scroll(&self) -> usize {
+        self.lines.len().saturating_sub(self.get_visible_rows())
+    }
+
+    /// Returns the number of lines which fit within the widget (excluding the border).
+    pub fn get_visible_rows(&self) -> usize {
+        let (size, _) = self.size_and_position.get_last();
+        size.1.saturating_sub(self.border as u16 * 2 + self.padding.top + self.padding.bottom) as usize
+    }
+
+    /// Returns the lines currently in view, oldest first.
+    pub fn get_visible_lines(&self) -> Vec<crate::render::Span> {
+        let offset = self.get_scroll_offset();
+        self.lines.range(offset..(offset + self.get_visible_rows()).min(self.lines.len())).cloned().collect()
+    }
+}
+
+/// Implementation of the methods for LogWidget
+impl<C: 'static> Widget<C> for LogWidget<C> {
+    /// Returns the widget's name as an identifier. The rendering backend relies
+    /// on `String` names instead of widgets.
+    fn get_window_ref(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Handles scrolling through the mouse wheel (while over the log) and the keyboard (while
+    /// selected). The update handler is then called, usually to push new lines.
+    fn update_with_events(&mut self, data: &mut C, app: &mut crate::App<C>, scene: &mut Scene<C>) {
+        let _ = self.size_and_position.get_size_and_position(&app.area.read());
+        let widget_index = scene.get_widget_index(self.get_window_ref()).unwrap_or(0);
+
+        let events = app.events.read();
+        let mut wheel_sign = 0.0;
+        if let Some(event) = &events.mouse_event && event.state == crate::event_handler::MouseState::Press {
+            match event.event_type {
+                crate::event_handler::MouseEventType::Up => wheel_sign = -1.0,
+                crate::event_handler::MouseEventType::Down => wheel_sign = 1.0,
+                _ => {},
+            }
+        }
+        // the wheel scrolls by the accumulated (smoothed) amount of only the scrolls over the log
+        let over = |position: (u16, u16)| self.is_collided(position) &&
+            !scene.is_click_blocked_all(widget_index, position, app).unwrap_or(false);
+        let delta = events.scroll_at(over);
+        if !events.mouse_event.as_ref().is_some_and(|event| over(event.position)) {  wheel_sign = 0.0;  }
+        if delta != 0.0 || wheel_sign != 0.0 {
+            self.scroll_remainder += delta;
+            // making sure each notch of the wheel responds immediately
+            if wheel_sign != 0.0 && self.scroll_remainder.abs() < 1.0 {  self.scroll_remainder = wheel_sign;  }
+            let rows = self.scroll_remainder.trunc();
+            self.scroll_remainder -= rows;
+            if rows != 0.0 {  self.scroll_by(rows as i32);  }
+        } else {
+            self.scroll_remainder = 0.0;
+        }
+
+        if self.selected {
+            let page = self.get_visible_rows().max(1) as i32;
+            let moves = [
+                (crate::event_handler::KeyCode::Up, -1),
+                (crate::event_handler::KeyCode::Down, 1),
+                (crate::event_handler::KeyCode::PageUp, -page),
+                (crate::event_handler::KeyCode::PageDown, page),
+            ];
+            for (key, rows) in moves {
+                if events.contains_key_code(key) {  self.scroll_by(rows);  }
+            }
+            if events.contains_key_code(crate::event_handler::KeyCode::Home) {  self.set_scroll_offset(0);  }
+            if events.contains_key_code(crate::event_handler::KeyCode::End) {  self.set_follow(true);  }
+        }
+        drop(events);
+
+        if let Some(handler) = self.update_handler.take() {
+            handler(self, data, app, scene);
+            self.update_handler = Some(handler);
+        }
+    }
+
+    /// Draws the visible lines, either through the render function or as they are. Only the
+    /// visible lines are compared against the window's, so the work is bounded by the height.
+    fn update_render(&mut self, window: &mut crate::render::Window, area: &crate::render::Rect, app_state: &mut C) -> bool {
+        let (size, position) = self.size_and_position.get_size_and_position(area);
+        window.resize(size);
+        window.r#move(position);
+        let rows = self.get_visible_rows();
+        let visible = self.get_visible_lines();
+        let mut lines = match &self.render_function {
+            Some(render_function) => match render_function(size, position, &visible, app_state) {
+                Some(lines) => lines,
+                None => return false,
+            },
+            None => visible,
+        };
+        // keeping the line count fixed, so the window only re-renders the rows which changed
+        lines.resize(lines.len().max(rows), crate::render::Span::default());
+        window.try_update_lines(lines)
+    }
+
+    /// Returns the indices of child widgets in the scene graph.
+    fn get_children_indexes(&self) -> Vec<usize> {
+        self.children.clone()
+    }
+
+    /// Adds a child widget index to this widget.
+    fn add_child_index(&mut self, index: usize) {
+        self.children.push(index);
+    }
+
+    /// Removes a child widget index from this widget
+    fn remove_child_index(&mut self, index: usize) {
+        self.children.remove(index);
+    }
+
+    /// Clears all child widget indices from this widget
+    fn clear_children_indexes(&mut self) {
+        self.children.clear();
+    }
+
+    /// Returns the parent widget index if one exists, otherwise None.
+    fn get_parent_index(&self) -> Option<usize> {
+        self.parent_index
+    }
+
+    /// Sets the parent widget index for this widget, or None for a root node.
+    fn set_parent_index(&mut self, index: Option<usize>) {
+        self.parent_index = index;
+    }
+
+    /// Determines if a given position collides with the widget's area.
+    fn is_collided(&self, position: (u16, u16)) -> bool {
+        self.size_and_position.is_collided(position)
+    }
+
+    /// Returns the widget as `Any` for downcasting.
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget as a mutable `Any` for downcasting.
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget's size and position configuration.
+    fn get_size_and_position(&self) -> Option<&SizeAndPosition> {
+        Some(&self.size_and_position)
+    }
+
+    /// Returns the widget's size and position configuration mutably.
+    fn get_size_and_position_mut(&mut self) -> Option<&mut SizeAndPosition> {
+        Some(&mut self.size_and_position)
+    }
+
+    /// Returns the keywords the widget's window is tagged with.
+    fn get_keywords(&self) -> &[String] {
+        &self.keywords
+    }
+
+    /// Returns the tooltip shown while the mouse rests over the widget, if any.
+    fn get_tooltip(&self) -> Option<&Tooltip<C>> {
+        self.tooltip.as_ref()
+    }
+
+    /// Logs take focus when clicked, or through Tab/Shift+Tab.
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    /// The log is selected (responds to the keyboard) while it has the scene's focus.
+    fn set_focused(&mut self, focused: bool) {
+        self.selected = focused;
+    }
+
+    /// Applies the offset and clip inherited from the parent widgets to the size and position.
+    fn set_parent_transform(&mut self, offset: (i16, i16), clip: Option<Region>) {
+        self.size_and_position.parent_offset = offset;
+        self.size_and_position.clip = clip;
+    }
+}
@@ -0,0 +1,697 @@
+use crate::widget_impls::*;
+use crate::widget::*;
+
+type UpdateHandler<C> = Box<dyn Fn(&mut dyn Widget<C>, &mut C, &mut crate::App<C>, &mut Scene<C>)>;
+
+/// The number of lines a single mouse wheel event scrolls.
+const WHEEL_SCROLL_LINES: u16 = 1;
+
+/// Builder for creating ScrollViewWidget instances with a fluent interface.
+/// Maintains configuration state until build() is called to create the actual widget.
+pub struct ScrollViewWidgetBuilder<C> {
+    /// The unique name identifier for the widget.
+    name: String,
+    /// The z-index depth of the widget; higher values render on top of lower ones.
+    depth: Option<u16>,
+    /// Whether the widget should have a border.
+    border: bool,
+    /// The title of the widget, if any.
+    title: Option<String>,
+    /// The theme slot coloring the border and title, if any.
+    theme_slot: Option<String>,
+    /// The glyphs the border is drawn with.
+    border_style: crate::render::BorderStyle,
+    /// The blank space between the border (or edge) and the content.
+    padding: crate::render::Padding,
+    /// Where the title sits along the top border.
+    title_alignment: crate::render::Alignment,
+    /// The title drawn in the bottom border and its alignment, if any.
+    bottom_title: Option<(String, crate::render::Alignment)>,
+    /// The size and position configuration for the widget.
+    pub size_and_position: SizeAndPosition,
+    /// The index of the parent widget in the scene graph, if any.
+    parent: Option<usize>,
+    /// Whether a scrollbar is drawn along the right edge of the viewport.
+    scrollbar: bool,
+
+    update_handler: Option<UpdateHandler<C>>,
+
+    /// The keywords the widget's window is tagged with.
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+    /// The colors and modifiers the whole window is painted with (i.e. the border's color).
+    colors: Vec<crate::render::ColorType>,
+    /// Whether the window starts hidden.
+    hidden: bool,
+    /// The layer the window is drawn in.
+    layer: crate::render::Layer,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+impl<C> ScrollViewWidgetBuilder<C> {
+    /// Sets whether a scrollbar reflecting the scroll offset and virtual extent is drawn
+    /// along the right edge of the viewport. By default, there is no scrollbar.
+    /// The scrollbar takes up the last column of the viewport, so children are clipped before it.
+    pub fn with_scrollbar(mut self, scrollbar: bool) -> Self {
+        self.scrollbar = scrollbar;
+        self
+    }
+}
+
+/// Implementations for the methods in `WidgetBuilder`.
+impl<C: 'static> WidgetBuilder<C> for ScrollViewWidgetBuilder<C> {
+    /// Constructs a `ScrollViewWidget`, an implementor of `Widget`, given the parameters.
+    /// Validates that the size is non-zero before creating the widget.
+    /// The method takes in a reference to the terminal's current area/size.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{ScrollViewWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::Rect;
+    /// # struct AppData;
+    /// let (widget, window) = ScrollViewWidgetBuilder::<AppData>::builder(String::new())
+    ///     .with_scrollbar(true)
+    ///     .with_position((0, 0))
+    ///     .with_size((20, 10))
+    ///     .build(&Rect { width: 80, height: 24 })  // replace &Rect with the actual terminal size (such as `&app.area.read()`)
+    ///     .expect("Invalid widget position or size.");
+    /// ```
+    fn build(mut self, display_area: &crate::render::Rect) -> Result<(Box<dyn Widget<C>>, crate::render::Window), WidgetBuilderError> {
+        let (size, position) = self.size_and_position.get_size_and_position(display_area);
+        if size.0 == 0 || size.1 == 0 {
+            return Err(WidgetBuilderError::InvalidGeometry { size, position })
+        }
+        let depth = self.depth.as_ref().unwrap_or(&0u16);
+        let mut window = crate::render::Window::new(position, *depth, size);
+        if self.border {  window.bordered();  }
+        window.set_theme_slot(self.theme_slot.clone());
+        window.set_border_style(self.border_style);
+        window.set_padding(self.padding);
+        if let Some(title) = &self.title {  window.titled(title.clone());  }
+        window.colorizes(std::mem::take(&mut self.colors));
+        if self.hidden {  window.hide();  }
+        window.set_layer(self.layer);
+        window.set_title_alignment(self.title_alignment);
+        if let Some((title, alignment)) = &self.bottom_title {
+            window.bottom_titled(crate::render::Span::from_tokens(vec![crate::render::Colored::new(title.clone())]));
+            window.set_bottom_title_alignment(*alignment);
+        }
+        Ok((Box::new(ScrollViewWidget::<C> {
+            children: vec![],
+            name: self.name,
+            parent_index: self.parent,
+            size_and_position: self.size_and_position,
+            update_handler: self.update_handler,
+            border: self.border,
+            padding: self.padding,
+            scrollbar: self.scrollbar,
+            scroll_offset: 0,
+            applied_offset: 0,
+            rendered_offset: 0,
+            virtual_height: 0,
+            selected: false,
+            keywords: self.keywords,
+            tooltip: self.tooltip,
+            __phantom: std::marker::PhantomData,
+        }), window))
+    }
+
+    /// Sets the widget's fixed position (static layout).
+    /// Retrains the dynamic proportions of any dynamic positioning configuration already in place.
+    fn with_position(mut self, position: (u16, u16)) -> Self {
+        self.size_and_position.position_offset = (position.0 as i16, position.1 as i16);
+        self
+    }
+
+    /// Sets the widget's fixed position (static layout).
+    /// Retrains the dynamic proportions of any dynamic size configuration already in place.
+    fn with_size(mut self, size: (u16, u16)) -> Self {
+        self.size_and_position.size_offset = (size.0 as i16, size.1 as i16);
+        self
+    }
+
+    /// Configures dynamic positioning based on terminal size with a fixed offset.
+    fn with_dynamic_position(mut self, position_offset: (i16, i16), position_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.position_offset = position_offset;
+        self.size_and_position.position_area_percent = position_area_percent;
+        self
+    }
+
+    /// Configures dynamic sizing based on terminal size with a fixed offset.
+    fn with_dynamic_size(mut self, size_offset: (i16, i16), size_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.size_offset = size_offset;
+        self.size_and_position.size_area_percent = size_area_percent;
+        self
+    }
+
+    /// Sets whether the widget should have a border. By default, all widgets are borderless.
+    /// The border isn't part of the viewport, so children are clipped within it.
+    fn with_border(mut self, border: bool) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Sets the widget's title (displayed in border if enabled; invisible otherwise).
+    fn with_title(mut self, title: String) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Assigns a depth to the widget. Children of the scroll view need a higher depth than
+    /// the scroll view itself, otherwise they'll be drawn underneath it.
+    fn with_depth(mut self, depth: u16) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// The scroll view only draws its border and scrollbar; the content comes from its children.
+    type RendererType = ();
+    /// Scroll views don't have a custom renderer, so this is a no-op that returns self.
+    fn with_renderer(self, _renderer: Self::RendererType) -> Self {
+        self
+    }
+
+    /// Generates a new builder instance with a provided unique name identifier.
+    /// By default, size_and_position are no compatible and require the user to
+    /// provide them using the other `WidgetBuilder` trait functions.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{ScrollViewWidgetBuilder, WidgetBuilder};
+    /// # struct AppData;
+    /// let builder = ScrollViewWidgetBuilder::<AppData>::builder(String::from("Widget Name"));
+    /// ```
+    fn builder(name: String) -> Self {
+        Self {
+            name,
+            depth: None,
+            size_and_position: SizeAndPosition::default(),
+            border: false,
+            title: None,
+            theme_slot: None,
+            border_style: crate::render::BorderStyle::Plain,
+            padding: crate::render::Padding::default(),
+            title_alignment: crate::render::Alignment::Center,
+            bottom_title: None,
+            parent: None,
+            scrollbar: false,
+            update_handler: None,
+            keywords: vec![],
+            tooltip: None,
+            colors: vec![],
+            hidden: false,
+            layer: crate::render::Layer::Normal,
+            __phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the SizeAndPosition configuration directly.
+    fn with_sap(mut self, sap: SizeAndPosition) -> Self {
+        self.size_and_position = sap;
+        self
+    }
+
+    /// Sets which point of the widget is placed at its computed position.
+    fn with_anchor(mut self, anchor: Anchor) -> Self {
+        self.size_and_position.anchor = anchor;
+        self
+    }
+
+    /// Sets the smallest size the widget can shrink to.
+    fn with_min_size(mut self, min_size: (u16, u16)) -> Self {
+        self.size_and_position.min_size = Some(min_size);
+        self
+    }
+
+    /// Sets the largest size the widget can grow to.
+    fn with_max_size(mut self, max_size: (u16, u16)) -> Self {
+        self.size_and_position.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets whether the widget is positioned relative to its parent's window.
+    fn with_relative_to_parent(mut self, relative: bool) -> Self {
+        self.size_and_position.position_mode = match relative {
+            true => PositionMode::RelativeToParent,
+            false => PositionMode::Absolute,
+        };
+        self
+    }
+
+    /// Sets whether the widget is clipped to its parent's window.
+    fn with_clip_to_parent(mut self, clip: bool) -> Self {
+        self.size_and_position.clip_to_parent = clip;
+        self
+    }
+
+    /// Slides the widget into place from the given offset when it's first drawn.
+    fn with_entrance_animation(mut self, from_offset: (i16, i16), duration: std::time::Duration) -> Self {
+        self.size_and_position.animate_from_offset(from_offset, duration, Easing::EaseOut);
+        self
+    }
+
+    /// Colors the border and title with a slot of the app's theme.
+    fn with_theme_slot(mut self, slot: &str) -> Self {
+        self.theme_slot = Some(slot.to_owned());
+        self
+    }
+
+    /// Sets the glyphs the border is drawn with.
+    fn with_border_style(mut self, style: crate::render::BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Sets the blank space between the border (or edge) and the content.
+    fn with_padding(mut self, padding: crate::render::Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets where the title sits along the top border.
+    fn with_title_alignment(mut self, alignment: crate::render::Alignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Sets the title drawn in the bottom border and its alignment.
+    fn with_bottom_title(mut self, title: String, alignment: crate::render::Alignment) -> Self {
+        self.bottom_title = Some((title, alignment));
+        self
+    }
+
+    type FunctionType = Option<UpdateHandler<C>>;
+    /// Sets a closure which is called after the scroll view handles its events each frame.
+    fn with_update_handler(mut self, handler: Self::FunctionType) -> Self {
+        self.update_handler = handler;
+        self
+    }
+
+    /// Sets the parent widget index for this widget, if any.
+    /// By default, the parent is None, indicating a root node.
+    fn with_parent(mut self, parent: Option<usize>) -> Self {
+        self.parent = parent;
+        self
+    }
+
+    /// Tags the widget's window with the given keywords (see `Scene::widgets_by_keyword`).
+    fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Gives the widget a tooltip, shown once the mouse rests over it (see `Scene::set_tooltip_delay`).
+    fn with_tooltip(mut self, tooltip: impl Into<Tooltip<C>>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Paints the whole window with the colors and modifiers (see `Window::colorizes`).
+    fn with_colors(mut self, colors: Vec<crate::render::ColorType>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Sets whether the widget starts hidden (see `Scene::set_widget_visible`).
+    fn with_hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Sets the layer the widget's window is drawn in (see `Window::set_layer`).
+    fn with_layer(mut self, layer: crate::render::Layer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Builds the widget and adds it to the provided scene, returning the new widget's index in the scene graph.
+    /// This method combines the `build` and `scene.add_widget` calls into one for convenience.
+    /// If building the widget fails, an error is returned instead.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{ScrollViewWidgetBuilder, WidgetBuilder};
+    /// # struct AppData;
+    /// let mut app = term_render::App::<AppData>::new_headless(80, 24);
+    /// let mut scene = term_render::widget::Scene::new();
+    /// let widget_index = ScrollViewWidgetBuilder::<AppData>::builder(String::from("My Widget"))
+    ///     .with_position((5, 5))
+    ///     .with_size((20, 10))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .expect("Failed to build and add widget to scene.");
+    /// ```
+    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetError> {
+        let (widget, window) = self.build(&app.area.read())?;
+        scene.add_widget(widget, window, &mut app.renderer.write())
+    }
+}
+
+/// A container which scrolls its children within a viewport.
+/// Children are positioned as if the scroll view wasn't scrolled (a virtual canvas starting at
+/// the top of the viewport, which can extend past the bottom of it). Each frame, the children are
+/// offset by the scroll amount and clipped to the viewport; children entirely outside the viewport
+/// produce no draw calls. The virtual extent is recalculated every frame from the children's windows,
+/// so resizing children is handled automatically.
+///
+/// The mouse wheel scrolls the view while over it, and PageUp/PageDown scroll by a page while the
+/// view is selected (by clicking within it). `Scene::scroll_into_view` scrolls a child into view.
+///
+/// Children should have a higher depth than the scroll view. Children scrolled past the top of
+/// the terminal itself are clamped to the first row, so viewports should generally leave a row of
+/// space above them if children are taller than a single line.
+/// # Example
+/// ```
+/// use term_render::widget_impls::{ScrollViewWidget, ScrollViewWidgetBuilder, StaticTextWidgetBuilder, WidgetBuilder};
+/// use term_render::widget::Scene;
+/// use term_render::render::Span;
+/// let mut app = term_render::App::<()>::new_headless(10, 5);
+/// let mut scene = Scene::new();
+/// let view = ScrollViewWidgetBuilder::<()>::builder(String::from("view"))
+///     .with_position((0, 1))
+///     .with_size((10, 3))
+///     .add_to_scene(&mut app, &mut scene)
+///     .unwrap();
+/// // a column of six rows, twice as tall as the viewport (starting at its top)
+/// for row in 0..6 {
+///     StaticTextWidgetBuilder::<()>::builder(format!("row {}", row))
+///         .with_renderer(vec![Span::from(format!("row {}", row).as_str())])
+///         .with_parent(Some(view))
+///         .with_depth(1)
+///         .with_position((0, 1 + row))
+///         .with_size((10, 1))
+///         .add_to_scene(&mut app, &mut scene)
+///         .unwrap();
+/// }
+/// let mut parser = vte::Parser::new();
+/// let mut frame = |scene: &mut Scene<()>, bytes: &[u8]| {
+///     app.events.write().clear_events();
+///     app.events.write().parse(&mut parser, bytes);
+///     scene.update_all_widgets(&mut app, &mut ()).unwrap();
+///     app.renderer.write().snapshot()
+/// };
+/// assert_eq!(frame(&mut scene, b""), vec!["", "row 0", "row 1", "row 2", ""]);
+/// assert_eq!(scene.widget_as::<ScrollViewWidget<()>>("view").unwrap().get_virtual_height(), 6);
+/// // the wheel over the view scrolls it, and the rows outside the viewport aren't drawn
+/// assert_eq!(frame(&mut scene, b"\x1b[<65;2;2M"), vec!["", "row 1", "row 2", "row 3", ""]);
+/// // but not while the mouse is elsewhere
+/// assert_eq!(frame(&mut scene, b"\x1b[<65;2;5M"), vec!["", "row 1", "row 2", "row 3", ""]);
+/// // page down scrolls by a page once the view is focused, stopping at the last row
+/// scene.set_focus(view).unwrap();
+/// assert_eq!(frame(&mut scene, b"\x1b[6~"), vec!["", "row 3", "row 4", "row 5", ""]);
+/// assert_eq!(frame(&mut scene, b"\x1b[6~"), vec!["", "row 3", "row 4", "row 5", ""]);
+/// assert_eq!(frame(&mut scene, b"\x1b[5~"), vec!["", "row 0", "row 1", "row 2", ""]);
+/// ```
+pub struct ScrollViewWidget<C> {
+    /// The indices of child widgets in the scene graph.
+    children: Vec<usize>,
+
+    /// The unique name identifier for the widget. The rendering backend
+    /// relies on `String` names instead of widgets.
+    name: String,
+
+    /// The index of the parent widget in the scene graph, if any (None would
+    /// indicate the root node).
+    parent_index: Option<usize>,
+
+    /// Configuration for the widget's size and position, supporting both static and dynamic layouts.
+    pub size_and_position: SizeAndPosition,
+
+    /// Optional closure that handles updates to the widget during event processing.
+    update_handler: Option<UpdateHandler<C>>,
+
+    border: bool,
+    padding: crate::render::Padding,
+    scrollbar: bool,
+
+    /// The number of rows the content is scrolled down by.
+    scroll_offset: u16,
+    // the offset the children were last positioned with (used to un-offset their windows)
+    applied_offset: u16,
+    // the offset the scroll view window was last rendered with
+    rendered_offset: u16,
+    /// The height of the content, from the top of the viewport to the bottom of the lowest child.
+    virtual_height: u16,
+
+    /// Indicates whether the widget has the scene's focus (PageUp/PageDown scroll it).
+    pub selected: bool,
+
+    /// The keywords the widget's window is tagged with (see `Scene::widgets_by_keyword`).
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+impl<C> ScrollViewWidget<C> {
+    /// Returns the region (size, position) children are visible within, excluding the border, padding, and scrollbar.
+    pub fn get_viewport(&self) -> Region {
+        let (mut size, mut position) = self.size_and_position.get_last();
+        if self.border {
+            size = (size.0.saturating_sub(2), size.1.saturating_sub(2));
+            position = (position.0 + 1, position.1 + 1);
+        }
+        let padding = self.padding;
+        size = (
+            size.0.saturating_sub(padding.left + padding.right),
+            size.1.saturating_sub(padding.top + padding.bottom),
+        );
+        position = (position.0 + padding.left, position.1 + padding.top);
+        if self.scrollbar {  size.0 = size.0.saturating_sub(1);  }
+        (size, position)
+    }
+
+    /// Returns the current scroll offset (the number of rows the content is scrolled by).
+    pub fn get_scroll_offset(&self) -> u16 {
+        self.scroll_offset
+    }
+
+    /// Returns the height of the content as of the last update.
+    pub fn get_virtual_height(&self) -> u16 {
+        self.virtual_height
+    }
+
+    /// Returns the largest valid scroll offset for the current content and viewport.
+    pub fn get_max_scroll(&self) -> u16 {
+        self.virtual_height.saturating_sub(self.get_viewport().0.1)
+    }
+
+    /// Sets the scroll offset, clamped to the content. Returns true if the offset changed.
+    pub fn set_scroll_offset(&mut self, offset: u16) -> bool {
+        let offset = offset.min(self.get_max_scroll());
+        if offset == self.scroll_offset {  return false;  }
+        self.scroll_offset = offset;
+        true
+    }
+
+    /// Scrolls by the given number of rows (negative scrolls up). Returns true if the offset changed.
+    pub fn scroll_by(&mut self, rows: i32) -> bool {
+        let offset = (self.scroll_offset as i32 + rows).clamp(0, u16::MAX as i32) as u16;
+        self.set_scroll_offset(offset)
+    }
+
+    /// Recalculates the virtual height from the children's windows (undoing the offset they were positioned with).
+    fn update_virtual_height(&mut self, scene: &Scene<C>, renderer: &crate::render::App) {
+        let viewport_top = self.get_viewport().1.1;
+        let mut bottom = viewport_top;
+        for child in &self.children {
+            let Ok(widget) = scene.widget_as_ref(*child) else {  continue;  };
+            let name = widget.get_window_ref();
+            if !renderer.contains_window(name.clone()) {  continue;  }
+            let window = renderer.get_window_reference(name);
+            if window.hidden {  continue;  }
+            let child_bottom = window.position.1.saturating_add(self.applied_offset).saturating_add(window.size.1);
+            bottom = bottom.max(child_bottom);
+        }
+        self.virtual_height = bottom - viewport_top;
+        // the content may have shrunk
+        self.scroll_offset = self.scroll_offset.min(self.get_max_scroll());
+    }
+
+    /// Builds the scrollbar column for the given number of rows.
+    fn get_scrollbar(&self, rows: u16) -> Vec<char> {
+        let mut bar = vec!['│'; rows as usize];
+        if self.virtual_height <= rows || rows == 0 {  return bar;  }
+        let thumb = ((rows as u32 * rows as u32) / self.virtual_height as u32).max(1) as u16;
+        let max_scroll = self.get_max_scroll().max(1);
+        let start = ((self.scroll_offset as u32 * (rows - thumb) as u32) / max_scroll as u32) as u16;
+        for row in start..(start + thumb).min(rows) {
+            bar[row as usize] = '█';
+        } bar
+    }
+}
+
+/// Implementation of the methods for ScrollViewWidget
+impl<C: 'static> Widget<C> for ScrollViewWidget<C> {
+    /// Returns the widget's name as an identifier. The rendering backend relies
+    /// on `String` names instead of widgets.
+    fn get_window_ref(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Handles scrolling through the mouse wheel (while over the viewport) and PageUp/PageDown
+    /// (while selected), keeping the virtual extent up to date with the children.
+    fn update_with_events(&mut self, data: &mut C, app: &mut crate::App<C>, scene: &mut Scene<C>) {
+        let _ = self.size_and_position.get_size_and_position(&app.area.read());
+        self.update_virtual_height(scene, &app.renderer.read());
+
+        let events = app.events.read();
+        if let Some(event) = &events.mouse_event && event.state == crate::event_handler::MouseState::Press {
+            match event.event_type {
+                crate::event_handler::MouseEventType::Up if self.is_collided(event.position) => {
+                    self.scroll_by(-(WHEEL_SCROLL_LINES as i32));
+                },
+                crate::event_handler::MouseEventType::Down if self.is_collided(event.position) => {
+                    self.scroll_by(WHEEL_SCROLL_LINES as i32);
+                },
+                _ => {},
+            }
+        }
+        if self.selected {
+            let page = self.get_viewport().0.1.max(1) as i32;
+            if events.contains_key_code(crate::event_handler::KeyCode::PageUp) {  self.scroll_by(-page);  }
+            if events.contains_key_code(crate::event_handler::KeyCode::PageDown) {  self.scroll_by(page);  }
+        }
+        drop(events);
+
+        if let Some(handler) = self.update_handler.take() {
+            handler(self, data, app, scene);
+            self.update_handler = Some(handler);
+        }
+        // the children are updated after this with the new offset
+        self.applied_offset = self.scroll_offset;
+    }
+
+    /// Updates the border and scrollbar. The whole window is redrawn whenever the scroll offset
+    /// changes so the old positions of the children get cleared.
+    fn update_render(&mut self, window: &mut crate::render::Window, area: &crate::render::Rect, _app_state: &mut C) -> bool {
+        let (size, position) = self.size_and_position.get_size_and_position(area);
+        window.resize(size);
+        window.r#move(position);
+        let mut changed = false;
+        if self.rendered_offset != self.scroll_offset {
+            self.rendered_offset = self.scroll_offset;
+            window.update_all();
+            changed = true;
+        }
+        if !self.scrollbar {  return changed;  }
+
+        let (viewport_size, _) = self.get_viewport();
+        let bar = self.get_scrollbar(viewport_size.1);
+        let lines = bar.into_iter().map(|chr| {
+            crate::render::Span::from_tokens(vec![
+                crate::render::Colored::get_from_color_types_str(&" ".repeat(viewport_size.0 as usize), vec![]),
+                crate::render::Colored::get_from_color_types_str(&chr.to_string(), vec![crate::render::ColorType::BrightBlack]),
+            ])
+        }).collect();
+        window.try_update_lines(lines) || changed
+    }
+
+    /// Returns the indices of child widgets in the scene graph.
+    fn get_children_indexes(&self) -> Vec<usize> {
+        self.children.clone()
+    }
+
+    /// Adds a child widget index to this widget.
+    fn add_child_index(&mut self, index: usize) {
+        self.children.push(index);
+    }
+
+    /// Removes a child widget index from this widget
+    fn remove_child_index(&mut self, index: usize) {
+        self.children.remove(index);
+    }
+
+    /// Clears all child widget indices from this widget
+    fn clear_children_indexes(&mut self) {
+        self.children.clear();
+    }
+
+    /// Returns the parent widget index if one exists, otherwise None.
+    fn get_parent_index(&self) -> Option<usize> {
+        self.parent_index
+    }
+
+    /// Sets the parent widget index for this widget, or None for a root node.
+    fn set_parent_index(&mut self, index: Option<usize>) {
+        self.parent_index = index;
+    }
+
+    /// Determines if a given position collides with the widget's area.
+    fn is_collided(&self, position: (u16, u16)) -> bool {
+        self.size_and_position.is_collided(position)
+    }
+
+    /// Returns the widget as `Any` for downcasting.
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget as a mutable `Any` for downcasting.
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget's size and position configuration.
+    fn get_size_and_position(&self) -> Option<&SizeAndPosition> {
+        Some(&self.size_and_position)
+    }
+
+    /// Returns the widget's size and position configuration mutably.
+    fn get_size_and_position_mut(&mut self) -> Option<&mut SizeAndPosition> {
+        Some(&mut self.size_and_position)
+    }
+
+    /// Returns the keywords the widget's window is tagged with.
+    fn get_keywords(&self) -> &[String] {
+        &self.keywords
+    }
+
+    /// Returns the tooltip shown while the mouse rests over the widget, if any.
+    fn get_tooltip(&self) -> Option<&Tooltip<C>> {
+        self.tooltip.as_ref()
+    }
+
+    /// Scroll views take focus when clicked (outside of any focusable children), or through Tab/Shift+Tab.
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    /// PageUp/PageDown scroll the view while it has the scene's focus.
+    fn set_focused(&mut self, focused: bool) {
+        self.selected = focused;
+    }
+
+    /// Applies the offset and clip inherited from the parent widgets to the size and position.
+    fn set_parent_transform(&mut self, offset: (i16, i16), clip: Option<Region>) {
+        self.size_and_position.parent_offset = offset;
+        self.size_and_position.clip = clip;
+    }
+
+    /// Children are shifted up by the scroll offset.
+    fn get_child_offset(&self) -> (i16, i16) {
+        (0, -(self.scroll_offset.min(i16::MAX as u16) as i16))
+    }
+
+    /// Children are clipped to the viewport.
+    fn get_child_clip(&self) -> Option<Region> {
+        Some(self.get_viewport())
+    }
+
+    /// Scrolls the minimum amount needed to fit the given region within the viewport
+    /// (the top of the region is preferred if it's taller than the viewport).
+    fn scroll_child_into_view(&mut self, rect: Region) -> bool {
+        let (viewport_size, viewport_position) = self.get_viewport();
+        let (top, bottom) = (rect.1.1 as i32, rect.1.1 as i32 + rect.0.1 as i32);
+        let (view_top, view_bottom) = (viewport_position.1 as i32, viewport_position.1 as i32 + viewport_size.1 as i32);
+        let rows = if top < view_top || rect.0.1 > viewport_size.1 {
+            top - view_top
+        } else if bottom > view_bottom {
+            bottom - view_bottom
+        } else {  0  };
+        if rows == 0 {  return false;  }
+        // the content can be taller than the last calculated extent if the child was just added
+        let offset = (self.scroll_offset as i32 + rows).clamp(0, u16::MAX as i32) as u16;
+        self.virtual_height = self.virtual_height.max(offset.saturating_add(viewport_size.1));
+        self.set_scroll_offset(offset)
+    }
+}
@@ -0,0 +1,523 @@
+use crate::widget_impls::*;
+use crate::widget::*;
+use crate::layout::Direction;
+
+/// Builder for creating SeparatorWidget instances with a fluent interface.
+/// Maintains configuration state until build() is called to create the actual widget.
+/// `SeparatorWidgetBuilder` is an example of an implementation of `WidgetBuilder`, where
+/// the struct doesn't implement `Widget`.
+pub struct SeparatorWidgetBuilder<C> {
+    /// The unique name identifier for the widget.
+    name: String,
+    /// The z-index depth of the widget; higher values render on top of lower ones.
+    depth: Option<u16>,
+    /// Whether the widget should have a border.
+    border: bool,
+    /// The title of the widget, if any.
+    title: Option<String>,
+    /// The theme slot coloring the border and title, if any.
+    theme_slot: Option<String>,
+    /// The glyphs the border (and the line) are drawn with.
+    border_style: crate::render::BorderStyle,
+    /// The blank space between the border (or edge) and the content.
+    padding: crate::render::Padding,
+    /// Where the title sits along the top border.
+    title_alignment: crate::render::Alignment,
+    /// The title drawn in the bottom border and its alignment, if any.
+    bottom_title: Option<(String, crate::render::Alignment)>,
+    /// The size and position configuration for the widget.
+    pub size_and_position: SizeAndPosition,
+    /// The label drawn inline with the line, if any.
+    pub label: Option<crate::render::Span>,
+    /// Whether the line runs across (horizontal) or down (vertical).
+    direction: Direction,
+    /// The index of the parent widget in the scene graph, if any.
+    parent: Option<usize>,
+    
+    /// The keywords the widget's window is tagged with.
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+    /// The colors and modifiers the whole window is painted with (i.e. the border's color).
+    colors: Vec<crate::render::ColorType>,
+    /// Whether the window starts hidden.
+    hidden: bool,
+    /// The layer the window is drawn in.
+    layer: crate::render::Layer,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+impl<C> SeparatorWidgetBuilder<C> {
+    /// Sets whether the line runs across the widget (`Direction::Horizontal`, the default) or down it
+    /// (`Direction::Vertical`).
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the label drawn inline with the line (the same as `with_renderer`). A horizontal line has it
+    /// near its start, and a vertical one has it written downwards, a character per row.
+    pub fn with_label(mut self, label: crate::render::Span) -> Self {
+        self.label = Some(label);
+        self
+    }
+}
+
+/// Implementations for the methods in `WidgetBuilder`.
+impl<C: 'static> WidgetBuilder<C> for SeparatorWidgetBuilder<C> {
+    /// Constructs a `SeparatorWidget`, an implementor of `Widget`, given the parameters.
+    /// Validates that the size is non-zero before creating the widget.
+    /// The method takes in a reference to the terminal's current area/size.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{SeparatorWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::Rect;
+    /// let (widget, window) = SeparatorWidgetBuilder::<()>::builder(String::new())
+    ///     .with_size((20, 1))
+    ///     .build(&Rect::default())  // replace &Rect with the actual terminal size (such as `&app.area.read()`)
+    ///     .expect("Invalid widget position or size.");
+    /// ```
+    fn build(mut self, display_area: &crate::render::Rect) -> Result<(Box<dyn Widget<C>>, crate::render::Window), WidgetBuilderError> {
+        let (size, position) = self.size_and_position.get_size_and_position(display_area);
+        if size.0 == 0 || size.1 == 0 {
+            return Err(WidgetBuilderError::InvalidGeometry { size, position })
+        }
+        let depth = self.depth.as_ref().unwrap_or(&0u16);
+        let mut window = crate::render::Window::new(position, *depth, size);
+        if self.border {  window.bordered();  }
+        window.set_theme_slot(self.theme_slot.clone());
+        window.set_border_style(self.border_style);
+        window.set_padding(self.padding);
+        if let Some(title) = &self.title {  window.titled(title.clone());  }
+        window.colorizes(std::mem::take(&mut self.colors));
+        if self.hidden {  window.hide();  }
+        window.set_layer(self.layer);
+        window.set_title_alignment(self.title_alignment);
+        if let Some((title, alignment)) = &self.bottom_title {
+            window.bottom_titled(crate::render::Span::from_tokens(vec![crate::render::Colored::new(title.clone())]));
+            window.set_bottom_title_alignment(*alignment);
+        }
+        Ok((Box::new(SeparatorWidget::<C> {
+            children: vec![],
+            name: self.name,
+            parent_index: self.parent,
+            size_and_position: self.size_and_position,
+            label: self.label,
+            direction: self.direction,
+            line_style: self.border_style,
+            keywords: self.keywords,
+            tooltip: self.tooltip,
+            __phantom: std::marker::PhantomData,
+        }), window))
+    }
+    
+    /// Sets the widget's fixed position (static layout).
+    /// Retrains the dynamic proportions of any dynamic positioning configuration already in place.
+    fn with_position(mut self, position: (u16, u16)) -> Self {
+        self.size_and_position.position_offset = (position.0 as i16, position.1 as i16);
+        self
+    }
+    
+    /// Sets the widget's fixed position (static layout).
+    /// Retrains the dynamic proportions of any dynamic size configuration already in place.
+    fn with_size(mut self, size: (u16, u16)) -> Self {
+        self.size_and_position.size_offset = (size.0 as i16, size.1 as i16);
+        self
+    }
+    
+    /// Configures dynamic positioning based on terminal size with a fixed offset.
+    fn with_dynamic_position(mut self, position_offset: (i16, i16), position_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.position_offset = position_offset;
+        self.size_and_position.position_area_percent = position_area_percent;
+        self
+    }
+    
+    /// Configures dynamic sizing based on terminal size with a fixed offset.
+    fn with_dynamic_size(mut self, size_offset: (i16, i16), size_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.size_offset = size_offset;
+        self.size_and_position.size_area_percent = size_area_percent;
+        self
+    }
+    
+    /// Sets whether the widget should have a border. By default, all widgets are borderless.
+    fn with_border(mut self, border: bool) -> Self {
+        self.border = border;
+        self
+    }
+    
+    /// Sets the widget's title (displayed in border if enabled; invisible otherwise).
+    fn with_title(mut self, title: String) -> Self {
+        self.title = Some(title);
+        self
+    }
+    
+    /// Assigns a depth to the widget. Higher values represent UI elements that are stacked further
+    /// ontop of other elements. By default, the builder instance has a depth of None, which corresponds
+    /// to 0 (root depth). This is fine as long as it isn't trying to appear ontop of other elements, such
+    /// as for a pop-up.
+    fn with_depth(mut self, depth: u16) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+    
+    /// The type representing the renderer content. Like `StaticTextWidget`, this is the content
+    /// itself rather than a closure: the label drawn inline with the line.
+    type RendererType = crate::render::Span;
+    /// Sets the label drawn inline with the line (see `SeparatorWidgetBuilder::with_label`).
+    fn with_renderer(mut self, renderer: Self::RendererType) -> Self {
+        self.label = Some(renderer);
+        self
+    }
+    
+    /// Generates a new builder instance with a provided unique name identifier.
+    /// By default, size_and_position are no compatible and require the user to
+    /// provide them using the other `WidgetBuilder` trait functions.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{SeparatorWidgetBuilder, WidgetBuilder};
+    /// let builder = SeparatorWidgetBuilder::<()>::builder(String::from("Widget Name"));
+    /// ```
+    fn builder(name: String) -> Self {
+        Self {
+            name,
+            depth: None,
+            size_and_position: SizeAndPosition::default(),
+            label: None,
+            direction: Direction::Horizontal,
+            border: false,
+            title: None,
+            theme_slot: None,
+            border_style: crate::render::BorderStyle::Plain,
+            padding: crate::render::Padding::default(),
+            title_alignment: crate::render::Alignment::Center,
+            bottom_title: None,
+            parent: None,
+            keywords: vec![],
+            tooltip: None,
+            colors: vec![],
+            hidden: false,
+            layer: crate::render::Layer::Normal,
+            __phantom: std::marker::PhantomData,
+        }
+    }
+    
+    /// Sets the SizeAndPosition configuration directly.
+    fn with_sap(mut self, sap: SizeAndPosition) -> Self {
+        self.size_and_position = sap;
+        self
+    }
+
+    /// Sets which point of the widget is placed at its computed position.
+    fn with_anchor(mut self, anchor: Anchor) -> Self {
+        self.size_and_position.anchor = anchor;
+        self
+    }
+
+    /// Sets the smallest size the widget can shrink to.
+    fn with_min_size(mut self, min_size: (u16, u16)) -> Self {
+        self.size_and_position.min_size = Some(min_size);
+        self
+    }
+
+    /// Sets the largest size the widget can grow to.
+    fn with_max_size(mut self, max_size: (u16, u16)) -> Self {
+        self.size_and_position.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets whether the widget is positioned relative to its parent's window.
+    fn with_relative_to_parent(mut self, relative: bool) -> Self {
+        self.size_and_position.position_mode = match relative {
+            true => PositionMode::RelativeToParent,
+            false => PositionMode::Absolute,
+        };
+        self
+    }
+
+    /// Sets whether the widget is clipped to its parent's window.
+    fn with_clip_to_parent(mut self, clip: bool) -> Self {
+        self.size_and_position.clip_to_parent = clip;
+        self
+    }
+
+    /// Slides the widget into place from the given offset when it's first drawn.
+    fn with_entrance_animation(mut self, from_offset: (i16, i16), duration: std::time::Duration) -> Self {
+        self.size_and_position.animate_from_offset(from_offset, duration, Easing::EaseOut);
+        self
+    }
+
+    /// Colors the border and title with a slot of the app's theme.
+    fn with_theme_slot(mut self, slot: &str) -> Self {
+        self.theme_slot = Some(slot.to_owned());
+        self
+    }
+
+    /// Sets the glyphs the border is drawn with.
+    fn with_border_style(mut self, style: crate::render::BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Sets the blank space between the border (or edge) and the content.
+    fn with_padding(mut self, padding: crate::render::Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets where the title sits along the top border.
+    fn with_title_alignment(mut self, alignment: crate::render::Alignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Sets the title drawn in the bottom border and its alignment.
+    fn with_bottom_title(mut self, title: String, alignment: crate::render::Alignment) -> Self {
+        self.bottom_title = Some((title, alignment));
+        self
+    }
+    
+    type FunctionType = Box<dyn Fn(&mut dyn Widget<C>, &mut crate::App<C>, &mut Scene<C>, &mut C)>;
+    /// Separators do not respond to events, so this is a no-op that returns self.
+    fn with_update_handler(self, _handler: Self::FunctionType) -> Self {
+        self
+    }
+    
+    /// Sets the parent widget index for this widget, if any.
+    /// By default, the parent is None, indicating a root node.
+    fn with_parent(mut self, parent: Option<usize>) -> Self {
+        self.parent = parent;
+        self
+    }
+
+    /// Tags the widget's window with the given keywords (see `Scene::widgets_by_keyword`).
+    fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Gives the widget a tooltip, shown once the mouse rests over it (see `Scene::set_tooltip_delay`).
+    fn with_tooltip(mut self, tooltip: impl Into<Tooltip<C>>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Paints the whole window with the colors and modifiers (see `Window::colorizes`).
+    fn with_colors(mut self, colors: Vec<crate::render::ColorType>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Sets whether the widget starts hidden (see `Scene::set_widget_visible`).
+    fn with_hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Sets the layer the widget's window is drawn in (see `Window::set_layer`).
+    fn with_layer(mut self, layer: crate::render::Layer) -> Self {
+        self.layer = layer;
+        self
+    }
+    
+    /// Builds the widget and adds it to the provided scene, returning the new widget's index in the scene graph.
+    /// This method combines the `build` and `scene.add_widget` calls into one for convenience.
+    /// If building the widget fails, an error is returned instead.
+    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetError> {
+        let (widget, window) = self.build(&app.area.read())?;
+        scene.add_widget(widget, window, &mut app.renderer.write())
+    }
+}
+
+/// A line dividing parts of the UI (i.e. sections of a form or panes of a sidebar), drawn with the
+/// box-drawing characters of its border style, with an optional label inline with it.
+/// `SeparatorWidgetBuilder` is the associated builder for creating instances of this widget.
+/// The generic parameter C represents the application data type, which can be any type defined by the user.
+/// # Example:
+/// ```
+/// use term_render::widget_impls::{SeparatorWidgetBuilder, WidgetBuilder};
+/// use term_render::layout::Direction;
+/// use term_render::render::{BorderStyle, Colored, Span};
+/// use term_render::widget::Scene;
+/// let mut app = term_render::App::<()>::new_headless(16, 4);
+/// let mut scene = Scene::new();
+/// SeparatorWidgetBuilder::<()>::builder(String::from("section"))
+///     .with_label(Span::from_tokens(vec![Colored::new(String::from("Account"))]))
+///     .with_position((0, 0))
+///     .with_size((15, 1))
+///     .add_to_scene(&mut app, &mut scene)
+///     .unwrap();
+/// SeparatorWidgetBuilder::<()>::builder(String::from("divider"))
+///     .with_direction(Direction::Vertical)
+///     .with_border_style(BorderStyle::Double)
+///     .with_label(Span::from_tokens(vec![Colored::new(String::from("ab"))]))
+///     .with_position((15, 0))
+///     .with_size((1, 4))
+///     .add_to_scene(&mut app, &mut scene)
+///     .unwrap();
+/// scene.update_all_widgets(&mut app, &mut ()).unwrap();
+/// assert_eq!(app.renderer.write().snapshot(), vec![
+///     "── Account ────║",
+///     "               a",
+///     "               b",
+///     "               ║",
+/// ]);
+/// ```
+pub struct SeparatorWidget<C> {
+    /// The indices of child widgets in the scene graph.
+    children: Vec<usize>,
+    
+    /// The unique name identifier for the widget. The rendering backend
+    /// relies on `String` names instead of widgets.
+    name: String,
+    
+    /// The index of the parent widget in the scene graph, if any (None would
+    /// indicate the root node).
+    parent_index: Option<usize>,
+    
+    /// Configuration for the widget's size and position, supporting both static and dynamic layouts.
+    pub size_and_position: SizeAndPosition,
+    
+    /// The label drawn inline with the line, if any.
+    pub label: Option<crate::render::Span>,
+    /// Whether the line runs across (horizontal) or down (vertical).
+    pub direction: Direction,
+    /// The style whose glyphs the line is drawn with.
+    pub line_style: crate::render::BorderStyle,
+    
+    /// The keywords the widget's window is tagged with (see `Scene::widgets_by_keyword`).
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+impl<C> SeparatorWidget<C> {
+    /// Gets the lines drawing the separator at the given size.
+    fn get_lines(&self, size: (u16, u16)) -> Vec<crate::render::Span> {
+        use crate::render::{Colored, Span};
+        let chars = self.line_style.get_chars();
+        match self.direction {
+            Direction::Horizontal => {
+                // the label sits a couple of cells in, padded by a space on either side
+                let width = size.0 as usize;
+                let mut line = Span::from_tokens(vec![]);
+                if let Some(label) = self.label.as_ref().filter(|label| label.size() > 0 && label.size() + 4 <= width) {
+                    line.push(Colored::new(format!("{}{} ", chars.top, chars.top)));
+                    line.append(label.clone());
+                    line.push(Colored::new(String::from(" ")));
+                }
+                line.push(Colored::new(chars.top.to_string().repeat(width - line.size())));
+                vec![line]
+            },
+            Direction::Vertical => {
+                // the label is written downwards, a row below the top
+                let mut rows: Vec<Span> = match &self.label {
+                    Some(label) if label.size() + 2 <= size.1 as usize => label.wrap(1, crate::render::WrapMode::Char),
+                    _ => vec![],
+                };
+                let line = || Span::from_tokens(vec![Colored::new(chars.left.to_string())]);
+                if !rows.is_empty() {  rows.insert(0, line());  }
+                while rows.len() < size.1 as usize {  rows.push(line());  }
+                rows
+            },
+        }
+    }
+}
+
+/// Implementation of the methods for SeparatorWidget
+impl<C: 'static> Widget<C> for SeparatorWidget<C> {
+    /// Returns the widget's name as an identifier. The rendering backend relies
+    /// on `String` names instead of widgets.
+    fn get_window_ref(&self) -> String {
+        self.name.clone()
+    }
+    
+    /// Handles event updates (no-op, as separators don't respond to events)
+    fn update_with_events(&mut self, _data: &mut C, _app: &mut crate::App<C>, _scene: &mut Scene<C>) {}
+    
+    /// Separators never react to events, so the scene can skip updating them with events.
+    fn consumes_events(&self) -> bool {
+        false
+    }
+    
+    /// Draws the line across (or down) the widget's current size.
+    fn update_render(&mut self, window: &mut crate::render::Window, area: &crate::render::Rect, _app_state: &mut C) -> bool {
+        let (size, position) = self.size_and_position.get_size_and_position(area);
+        window.resize(size);
+        window.r#move(position);
+        window.try_update_lines(self.get_lines(size))
+    }
+    
+    /// Returns the indices of child widgets in the scene graph.
+    fn get_children_indexes(&self) -> Vec<usize> {
+        self.children.clone()
+    }
+    
+    /// Adds a child widget index to this widget.
+    fn add_child_index(&mut self, index: usize) {
+        self.children.push(index);
+    }
+    
+    /// Removes a child widget index from this widget
+    fn remove_child_index(&mut self, index: usize) {
+        self.children.remove(index);
+    }
+    
+    /// Clears all child widget indices from this widget
+    fn clear_children_indexes(&mut self) {
+        self.children.clear();
+    }
+    
+    /// Returns the parent widget index if one exists, otherwise None.
+    fn get_parent_index(&self) -> Option<usize> {
+        self.parent_index
+    }
+    
+    /// Sets the parent widget index for this widget, or None for a root node.
+    fn set_parent_index(&mut self, index: Option<usize>) {
+        self.parent_index = index;
+    }
+    
+    /// Determines if a given position collides with the widget's area.
+    fn is_collided(&self, position: (u16, u16)) -> bool {
+        self.size_and_position.is_collided(position)
+    }
+
+    /// Returns the widget as `Any` for downcasting.
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget as a mutable `Any` for downcasting.
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget's size and position configuration.
+    fn get_size_and_position(&self) -> Option<&SizeAndPosition> {
+        Some(&self.size_and_position)
+    }
+
+    /// Returns the widget's size and position configuration mutably.
+    fn get_size_and_position_mut(&mut self) -> Option<&mut SizeAndPosition> {
+        Some(&mut self.size_and_position)
+    }
+
+    /// Returns the keywords the widget's window is tagged with.
+    fn get_keywords(&self) -> &[String] {
+        &self.keywords
+    }
+
+    /// Returns the tooltip shown while the mouse rests over the widget, if any.
+    fn get_tooltip(&self) -> Option<&Tooltip<C>> {
+        self.tooltip.as_ref()
+    }
+    
+    /// Applies the offset and clip inherited from the parent widgets to the size and position.
+    fn set_parent_transform(&mut self, offset: (i16, i16), clip: Option<Region>) {
+        self.size_and_position.parent_offset = offset;
+        self.size_and_position.clip = clip;
+    }
+}
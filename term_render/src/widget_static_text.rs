@@ -16,44 +16,115 @@ pub struct StaticTextWidgetBuilder<C> {
     border: bool,
     /// The title of the widget, if any.
     title: Option<String>,
+    /// The theme slot coloring the border and title, if any.
+    theme_slot: Option<String>,
+    /// The glyphs the border is drawn with.
+    border_style: crate::render::BorderStyle,
+    /// The blank space between the border (or edge) and the content.
+    padding: crate::render::Padding,
+    /// Where the title sits along the top border.
+    title_alignment: crate::render::Alignment,
+    /// The title drawn in the bottom border and its alignment, if any.
+    bottom_title: Option<(String, crate::render::Alignment)>,
     /// The size and position configuration for the widget.
     pub size_and_position: SizeAndPosition,
     /// The custom render function for the widget, if any.
     pub render_text: Vec<crate::render::Span>,
     /// The index of the parent widget in the scene graph, if any.
     parent: Option<usize>,
+    /// The horizontal alignment of the text within the widget.
+    alignment: crate::render::Alignment,
     
+    /// The keywords the widget's window is tagged with.
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+    /// The colors and modifiers the whole window is painted with (i.e. the border's color).
+    colors: Vec<crate::render::ColorType>,
+    /// Whether the window starts hidden.
+    hidden: bool,
+    /// The layer the window is drawn in.
+    layer: crate::render::Layer,
+
     __phantom: std::marker::PhantomData<C>,
 }
 
+impl<C> StaticTextWidgetBuilder<C> {
+    /// Sets the horizontal alignment of the text (left by default). The alignment is applied by
+    /// the window, so centered or right aligned text stays aligned when a dynamic size changes.
+    /// # Example
+    /// ```
+    /// use term_render::widget_impls::{StaticTextWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::{Alignment, ColorType, Colorize, Rect, Span};
+    /// let mut app = term_render::App::<()>::new_headless(10, 3);
+    /// let mut scene = term_render::widget::Scene::new();
+    /// StaticTextWidgetBuilder::<()>::builder(String::from("title"))
+    ///     .with_alignment(Alignment::Center)
+    ///     .with_border(true)
+    ///     // the color's escape codes don't count towards the width being centered
+    ///     .with_renderer(vec![Span::from_tokens(vec!["Hi".colorize(ColorType::Red)])])
+    ///     .with_position((0, 0))
+    ///     .with_dynamic_size((0, 3), (1.0, 0.0))  // the full width of the terminal
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .unwrap();
+    /// scene.update_all_widgets(&mut app, &mut ()).unwrap();
+    /// assert_eq!(app.renderer.write().snapshot()[1], "│   Hi   │");
+    /// // still centered within the border once the terminal is resized
+    /// app.renderer.write().set_headless_size(16, 3);
+    /// *app.area.write() = Rect { width: 16, height: 3 };
+    /// scene.update_all_widgets(&mut app, &mut ()).unwrap();
+    /// assert_eq!(app.renderer.write().snapshot()[1], "│      Hi      │");
+    /// ```
+    pub fn with_alignment(mut self, alignment: crate::render::Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+}
+
 /// Implementations for the methods in `WidgetBuilder`.
 impl<C: 'static> WidgetBuilder<C> for StaticTextWidgetBuilder<C> {
     /// Constructs a `StaticTextWidget`, an implementor of `Widget`, given the parameters.
-    /// Validates that size and position are non-zero before creating the widget.
+    /// Validates that the size is non-zero before creating the widget.
     /// The method takes in a reference to the terminal's current area/size.
     /// # Example:
     /// ```
     /// use term_render::widget_impls::{StaticTextWidgetBuilder, WidgetBuilder};
     /// use term_render::render::Rect;
+    /// # struct AppData;
     /// let (widget, window) = StaticTextWidgetBuilder::<AppData>::builder(String::new())
-    ///     .build(&Rect::default())  // replace &Rect with the actual terminal size (such as `&app.area.read()`)
+    ///     .with_size((20, 10))
+    ///     .build(&Rect { width: 80, height: 24 })  // replace &Rect with the actual terminal size (such as `&app.area.read()`)
     ///     .expect("Invalid widget position or size.");
     /// ```
     fn build(mut self, display_area: &crate::render::Rect) -> Result<(Box<dyn Widget<C>>, crate::render::Window), WidgetBuilderError> {
-        let (position, size) = self.size_and_position.get_size_and_position(display_area);
-        if size.0 == 0 || size.1 == 0 || position.0 == 0 || position.1 == 0 {
-            return Err(WidgetBuilderError { details: String::from("Position and/or size cannot be zero when building a new widget or window.") })
+        let (size, position) = self.size_and_position.get_size_and_position(display_area);
+        if size.0 == 0 || size.1 == 0 {
+            return Err(WidgetBuilderError::InvalidGeometry { size, position })
         }
         let depth = self.depth.as_ref().unwrap_or(&0u16);
         let mut window = crate::render::Window::new(position, *depth, size);
         if self.border {  window.bordered();  }
+        window.set_theme_slot(self.theme_slot.clone());
+        window.set_border_style(self.border_style);
+        window.set_padding(self.padding);
         if let Some(title) = &self.title {  window.titled(title.clone());  }
+        window.colorizes(std::mem::take(&mut self.colors));
+        if self.hidden {  window.hide();  }
+        window.set_layer(self.layer);
+        window.set_title_alignment(self.title_alignment);
+        if let Some((title, alignment)) = &self.bottom_title {
+            window.bottom_titled(crate::render::Span::from_tokens(vec![crate::render::Colored::new(title.clone())]));
+            window.set_bottom_title_alignment(*alignment);
+        }
+        window.set_alignment(self.alignment);
         Ok((Box::new(StaticTextWidget::<C> {
             children: vec![],
             name: self.name,
             parent_index: self.parent,
             size_and_position: self.size_and_position,
             render_text: self.render_text,
+            keywords: self.keywords,
+            tooltip: self.tooltip,
             __phantom: std::marker::PhantomData,
         }), window))
     }
@@ -109,7 +180,7 @@ impl<C: 'static> WidgetBuilder<C> for StaticTextWidgetBuilder<C> {
     
     /// The type representing the renderer content. This is different from other widgets
     /// as it is not a closure, but rather the actual content to render.
-    type RendererType = Vec<crate::render::Span>;
+    type RendererType = RenderFunction;
     /// This renderer is unique, as instead of providing a render closure, the user provides
     /// the actual rendered content directly. This is because static text widgets don't need to
     /// change their content dynamically, and as such a closure is unnecessary overhead.
@@ -122,6 +193,7 @@ impl<C: 'static> WidgetBuilder<C> for StaticTextWidgetBuilder<C> {
     /// use term_render::widget_impls::{StaticTextWidgetBuilder, WidgetBuilder};
     /// use term_render::render::{Rect, Colorize, ColorType};
     /// use term_render::color;
+    /// # struct AppData;
     ///
     /// let (widget, window) = StaticTextWidgetBuilder::<AppData>::builder(String::new())
     ///     .with_renderer(vec![
@@ -129,7 +201,8 @@ impl<C: 'static> WidgetBuilder<C> for StaticTextWidgetBuilder<C> {
     ///             color!["Hello World!", Blue, Bold],
     ///         ])
     ///     ])
-    ///     .build(&Rect::default())
+    ///     .with_size((20, 10))
+    ///     .build(&Rect { width: 80, height: 24 })
     ///     .unwrap();
     /// ```
     fn with_renderer(mut self, renderer: Self::RendererType) -> Self {
@@ -143,6 +216,7 @@ impl<C: 'static> WidgetBuilder<C> for StaticTextWidgetBuilder<C> {
     /// # Example:
     /// ```
     /// use term_render::widget_impls::{StaticTextWidgetBuilder, WidgetBuilder};
+    /// # struct AppData;
     /// let builder = StaticTextWidgetBuilder::<AppData>::builder(String::from("Widget Name"));
     /// ```
     fn builder(name: String) -> Self {
@@ -153,7 +227,18 @@ impl<C: 'static> WidgetBuilder<C> for StaticTextWidgetBuilder<C> {
             render_text: vec![],
             border: false,
             title: None,
+            theme_slot: None,
+            border_style: crate::render::BorderStyle::Plain,
+            padding: crate::render::Padding::default(),
+            title_alignment: crate::render::Alignment::Center,
+            bottom_title: None,
             parent: None,
+            alignment: crate::render::Alignment::Left,
+            keywords: vec![],
+            tooltip: None,
+            colors: vec![],
+            hidden: false,
+            layer: crate::render::Layer::Normal,
             __phantom: std::marker::PhantomData,
         }
     }
@@ -163,6 +248,75 @@ impl<C: 'static> WidgetBuilder<C> for StaticTextWidgetBuilder<C> {
         self.size_and_position = sap;
         self
     }
+
+    /// Sets which point of the widget is placed at its computed position.
+    fn with_anchor(mut self, anchor: Anchor) -> Self {
+        self.size_and_position.anchor = anchor;
+        self
+    }
+
+    /// Sets the smallest size the widget can shrink to.
+    fn with_min_size(mut self, min_size: (u16, u16)) -> Self {
+        self.size_and_position.min_size = Some(min_size);
+        self
+    }
+
+    /// Sets the largest size the widget can grow to.
+    fn with_max_size(mut self, max_size: (u16, u16)) -> Self {
+        self.size_and_position.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets whether the widget is positioned relative to its parent's window.
+    fn with_relative_to_parent(mut self, relative: bool) -> Self {
+        self.size_and_position.position_mode = match relative {
+            true => PositionMode::RelativeToParent,
+            false => PositionMode::Absolute,
+        };
+        self
+    }
+
+    /// Sets whether the widget is clipped to its parent's window.
+    fn with_clip_to_parent(mut self, clip: bool) -> Self {
+        self.size_and_position.clip_to_parent = clip;
+        self
+    }
+
+    /// Slides the widget into place from the given offset when it's first drawn.
+    fn with_entrance_animation(mut self, from_offset: (i16, i16), duration: std::time::Duration) -> Self {
+        self.size_and_position.animate_from_offset(from_offset, duration, Easing::EaseOut);
+        self
+    }
+
+    /// Colors the border and title with a slot of the app's theme.
+    fn with_theme_slot(mut self, slot: &str) -> Self {
+        self.theme_slot = Some(slot.to_owned());
+        self
+    }
+
+    /// Sets the glyphs the border is drawn with.
+    fn with_border_style(mut self, style: crate::render::BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Sets the blank space between the border (or edge) and the content.
+    fn with_padding(mut self, padding: crate::render::Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets where the title sits along the top border.
+    fn with_title_alignment(mut self, alignment: crate::render::Alignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Sets the title drawn in the bottom border and its alignment.
+    fn with_bottom_title(mut self, title: String, alignment: crate::render::Alignment) -> Self {
+        self.bottom_title = Some((title, alignment));
+        self
+    }
     
     type FunctionType = Box<dyn Fn(&mut dyn Widget<C>, &mut crate::App<C>, &mut Scene<C>, &mut C)>;
     /// Static widgets do not respond to events, so this is a no-op that returns self.
@@ -178,6 +332,36 @@ impl<C: 'static> WidgetBuilder<C> for StaticTextWidgetBuilder<C> {
         self.parent = parent;
         self
     }
+
+    /// Tags the widget's window with the given keywords (see `Scene::widgets_by_keyword`).
+    fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Gives the widget a tooltip, shown once the mouse rests over it (see `Scene::set_tooltip_delay`).
+    fn with_tooltip(mut self, tooltip: impl Into<Tooltip<C>>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Paints the whole window with the colors and modifiers (see `Window::colorizes`).
+    fn with_colors(mut self, colors: Vec<crate::render::ColorType>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Sets whether the widget starts hidden (see `Scene::set_widget_visible`).
+    fn with_hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Sets the layer the widget's window is drawn in (see `Window::set_layer`).
+    fn with_layer(mut self, layer: crate::render::Layer) -> Self {
+        self.layer = layer;
+        self
+    }
     
     /// Builds the widget and adds it to the provided scene, returning the new widget's index in the scene graph.
     /// This method combines the `build` and `scene.add_widget` calls into one for convenience.
@@ -186,7 +370,8 @@ impl<C: 'static> WidgetBuilder<C> for StaticTextWidgetBuilder<C> {
     /// ```
     /// use term_render::widget_impls::{StaticTextWidgetBuilder, WidgetBuilder};
     /// use term_render::render::Rect;
-    /// let mut app = term_render::App::new().unwrap();
+    /// # struct AppData;
+    /// let mut app = term_render::App::<AppData>::new_headless(80, 24);
     /// let mut scene = term_render::widget::Scene::new();
     /// let widget_index = StaticTextWidgetBuilder::<AppData>::builder(String::from("My Widget"))
     ///     .with_position((5, 5))
@@ -194,12 +379,9 @@ impl<C: 'static> WidgetBuilder<C> for StaticTextWidgetBuilder<C> {
     ///     .add_to_scene(&mut app, &mut scene)
     ///     .expect("Failed to build and add widget to scene.");
     /// ```
-    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetErr> {
-        if let Ok((widget, window)) = self.build(&app.area.read()) {
-            scene.add_widget(widget, window, &mut *app.renderer.write())
-        } else {
-            Err(WidgetErr::new("Failed to build and add widget to scene."))
-        }
+    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetError> {
+        let (widget, window) = self.build(&app.area.read())?;
+        scene.add_widget(widget, window, &mut app.renderer.write())
     }
 }
 
@@ -231,6 +413,11 @@ pub struct StaticTextWidget<C> {
     /// Optional closure that generates the widget's rendered content based on size and position.
     pub render_text: Vec<crate::render::Span>,
     
+    /// The keywords the widget's window is tagged with (see `Scene::widgets_by_keyword`).
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+
     __phantom: std::marker::PhantomData<C>,
 }
 
@@ -252,10 +439,10 @@ impl<C> StaticTextWidget<C> {
                render_text: Vec<crate::render::Span>,
                depth: u16,
                display_area: &crate::render::Rect,
-    ) -> Result<(StaticTextWidget<C>, crate::render::Window), WidgetErr> {
+    ) -> Result<(StaticTextWidget<C>, crate::render::Window), WidgetError> {
         let (size, position) = size_and_position.get_size_and_position(display_area);
-        if size.0 == 0 || size.1 == 0 || position.0 == 0 || position.1 == 0 {
-            return Err(WidgetErr::new("Size or position cannot be zero"))
+        if size.0 == 0 || size.1 == 0 {
+            return Err(WidgetError::InvalidGeometry { size, position })
         }
         let window = crate::render::Window::new(position, depth, size);
         
@@ -265,6 +452,8 @@ impl<C> StaticTextWidget<C> {
             parent_index: None,
             size_and_position,
             render_text,
+            keywords: vec![],
+            tooltip: None,
             __phantom: std::marker::PhantomData,
         };
         
@@ -273,7 +462,7 @@ impl<C> StaticTextWidget<C> {
 }
 
 /// Implementation of the methods for StaticTextWidget
-impl<C> Widget<C> for StaticTextWidget<C> {
+impl<C: 'static> Widget<C> for StaticTextWidget<C> {
     /// Returns the widget's name as an identifier. The rendering backend relies
     /// on `String` names instead of widgets.
     fn get_window_ref(&self) -> String {
@@ -286,6 +475,11 @@ impl<C> Widget<C> for StaticTextWidget<C> {
         // the static widget doesn't need to change
     }
     
+    /// Static widgets never react to events, so the scene can skip updating them with events.
+    fn consumes_events(&self) -> bool {
+        false
+    }
+    
     /// Updates the widget's rendering based on current size and position.
     /// Called automatically during render passes.
     /// If `Some(render_closure)` is provided, that closure will be called.
@@ -332,7 +526,42 @@ impl<C> Widget<C> for StaticTextWidget<C> {
     
     /// Determines if a given position collides with the widget's area.
     fn is_collided(&self, position: (u16, u16)) -> bool {
-        let (size, pos) = self.size_and_position.get_last();
-        position.0 >= pos.0 && position.0 < pos.0 + size.0 && position.1 >= pos.1 && position.1 < pos.1 + size.1
+        self.size_and_position.is_collided(position)
+    }
+
+    /// Returns the widget as `Any` for downcasting.
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget as a mutable `Any` for downcasting.
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget's size and position configuration.
+    fn get_size_and_position(&self) -> Option<&SizeAndPosition> {
+        Some(&self.size_and_position)
+    }
+
+    /// Returns the widget's size and position configuration mutably.
+    fn get_size_and_position_mut(&mut self) -> Option<&mut SizeAndPosition> {
+        Some(&mut self.size_and_position)
+    }
+
+    /// Returns the keywords the widget's window is tagged with.
+    fn get_keywords(&self) -> &[String] {
+        &self.keywords
+    }
+
+    /// Returns the tooltip shown while the mouse rests over the widget, if any.
+    fn get_tooltip(&self) -> Option<&Tooltip<C>> {
+        self.tooltip.as_ref()
+    }
+    
+    /// Applies the offset and clip inherited from the parent widgets to the size and position.
+    fn set_parent_transform(&mut self, offset: (i16, i16), clip: Option<Region>) {
+        self.size_and_position.parent_offset = offset;
+        self.size_and_position.clip = clip;
     }
 }
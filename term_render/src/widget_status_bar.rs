@@ -0,0 +1,536 @@
+use crate::widget_impls::*;
+use crate::widget::*;
+
+type HintRenderer = Box<dyn Fn(&crate::KeyChord, &str) -> crate::render::Span>;
+
+/// Builder for creating StatusBarWidget instances with a fluent interface.
+/// Maintains configuration state until build() is called to create the actual widget.
+/// By default, the bar is a single borderless row pinned to the bottom of the terminal, spanning its width.
+pub struct StatusBarWidgetBuilder<C> {
+    /// The unique name identifier for the widget.
+    name: String,
+    /// The z-index depth of the widget; higher values render on top of lower ones.
+    depth: Option<u16>,
+    /// Whether the widget should have a border.
+    border: bool,
+    /// The title of the widget, if any.
+    title: Option<String>,
+    /// The theme slot coloring the border and title, if any.
+    theme_slot: Option<String>,
+    /// The glyphs the border is drawn with.
+    border_style: crate::render::BorderStyle,
+    /// The blank space between the border (or edge) and the content.
+    padding: crate::render::Padding,
+    /// Where the title sits along the top border.
+    title_alignment: crate::render::Alignment,
+    /// The title drawn in the bottom border and its alignment, if any.
+    bottom_title: Option<(String, crate::render::Alignment)>,
+    /// The size and position configuration for the widget.
+    pub size_and_position: SizeAndPosition,
+    /// The index of the parent widget in the scene graph, if any.
+    parent: Option<usize>,
+    /// Renders a single hint, if the default style is replaced.
+    hint_renderer: Option<HintRenderer>,
+
+    /// The keywords the widget's window is tagged with.
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+    /// The colors and modifiers the whole window is painted with (i.e. the border's color).
+    colors: Vec<crate::render::ColorType>,
+    /// Whether the window starts hidden.
+    hidden: bool,
+    /// The layer the window is drawn in.
+    layer: crate::render::Layer,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+/// Implementations for the methods in `WidgetBuilder`.
+impl<C: 'static> WidgetBuilder<C> for StatusBarWidgetBuilder<C> {
+    /// Constructs a `StatusBarWidget`, an implementor of `Widget`, given the parameters.
+    /// Validates that the size is non-zero before creating the widget.
+    /// The method takes in a reference to the terminal's current area/size.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{StatusBarWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::Rect;
+    /// # struct AppData;
+    /// let (widget, window) = StatusBarWidgetBuilder::<AppData>::builder(String::new())
+    ///     .build(&Rect { width: 80, height: 24 })  // replace &Rect with the actual terminal size (such as `&app.area.read()`)
+    ///     .expect("Invalid widget position or size.");
+    /// ```
+    fn build(mut self, display_area: &crate::render::Rect) -> Result<(Box<dyn Widget<C>>, crate::render::Window), WidgetBuilderError> {
+        let (size, position) = self.size_and_position.get_size_and_position(display_area);
+        if size.0 == 0 || size.1 == 0 {
+            return Err(WidgetBuilderError::InvalidGeometry { size, position })
+        }
+        let depth = self.depth.as_ref().unwrap_or(&0u16);
+        let mut window = crate::render::Window::new(position, *depth, size);
+        if self.border {  window.bordered();  }
+        window.set_theme_slot(self.theme_slot.clone());
+        window.set_border_style(self.border_style);
+        window.set_padding(self.padding);
+        if let Some(title) = &self.title {  window.titled(title.clone());  }
+        window.colorizes(std::mem::take(&mut self.colors));
+        if self.hidden {  window.hide();  }
+        window.set_layer(self.layer);
+        window.set_title_alignment(self.title_alignment);
+        if let Some((title, alignment)) = &self.bottom_title {
+            window.bottom_titled(crate::render::Span::from_tokens(vec![crate::render::Colored::new(title.clone())]));
+            window.set_bottom_title_alignment(*alignment);
+        }
+        Ok((Box::new(StatusBarWidget::<C> {
+            children: vec![],
+            name: self.name,
+            parent_index: self.parent,
+            size_and_position: self.size_and_position,
+            border: self.border,
+            padding: self.padding,
+            hint_renderer: self.hint_renderer,
+            mode: String::new(),
+            hints: vec![],
+            keywords: self.keywords,
+            tooltip: self.tooltip,
+            __phantom: std::marker::PhantomData,
+        }), window))
+    }
+
+    /// Sets the widget's fixed position (static layout).
+    /// Retrains the dynamic proportions of any dynamic positioning configuration already in place.
+    fn with_position(mut self, position: (u16, u16)) -> Self {
+        self.size_and_position.position_offset = (position.0 as i16, position.1 as i16);
+        self
+    }
+
+    /// Sets the widget's fixed position (static layout).
+    /// Retrains the dynamic proportions of any dynamic size configuration already in place.
+    fn with_size(mut self, size: (u16, u16)) -> Self {
+        self.size_and_position.size_offset = (size.0 as i16, size.1 as i16);
+        self
+    }
+
+    /// Configures dynamic positioning based on terminal size with a fixed offset.
+    fn with_dynamic_position(mut self, position_offset: (i16, i16), position_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.position_offset = position_offset;
+        self.size_and_position.position_area_percent = position_area_percent;
+        self
+    }
+
+    /// Configures dynamic sizing based on terminal size with a fixed offset.
+    fn with_dynamic_size(mut self, size_offset: (i16, i16), size_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.size_offset = size_offset;
+        self.size_and_position.size_area_percent = size_area_percent;
+        self
+    }
+
+    /// Sets whether the widget should have a border. By default, all widgets are borderless.
+    fn with_border(mut self, border: bool) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Sets the widget's title (displayed in border if enabled; invisible otherwise).
+    fn with_title(mut self, title: String) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Assigns a depth to the widget. Higher values represent UI elements that are stacked further
+    /// ontop of other elements. By default, the builder instance has a depth of None, which corresponds
+    /// to 0 (root depth).
+    fn with_depth(mut self, depth: u16) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// The type representing the renderer closure.
+    type RendererType = HintRenderer;
+    /// Sets how a single hint is drawn from its chord and name (by default, the chord in bold followed
+    /// by the name). The hints are separated by two spaces.
+    fn with_renderer(mut self, renderer: Self::RendererType) -> Self {
+        self.hint_renderer = Some(renderer);
+        self
+    }
+
+    /// Generates a new builder instance with a provided unique name identifier.
+    /// Unlike other widgets, the size and position default to a single row along the bottom of the terminal.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{StatusBarWidgetBuilder, WidgetBuilder};
+    /// # struct AppData;
+    /// let builder = StatusBarWidgetBuilder::<AppData>::builder(String::from("Widget Name"));
+    /// ```
+    fn builder(name: String) -> Self {
+        let size_and_position = SizeAndPosition {
+            size_offset: (0, 1),
+            size_area_percent: (1.0, 0.0),
+            position_offset: (0, -1),
+            position_area_percent: (0.0, 1.0),
+            ..SizeAndPosition::default()
+        };
+        Self {
+            name,
+            depth: None,
+            size_and_position,
+            border: false,
+            title: None,
+            theme_slot: None,
+            border_style: crate::render::BorderStyle::Plain,
+            padding: crate::render::Padding::default(),
+            title_alignment: crate::render::Alignment::Center,
+            bottom_title: None,
+            parent: None,
+            hint_renderer: None,
+            keywords: vec![],
+            tooltip: None,
+            colors: vec![],
+            hidden: false,
+            layer: crate::render::Layer::Normal,
+            __phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the SizeAndPosition configuration directly.
+    fn with_sap(mut self, sap: SizeAndPosition) -> Self {
+        self.size_and_position = sap;
+        self
+    }
+
+    /// Sets which point of the widget is placed at its computed position.
+    fn with_anchor(mut self, anchor: Anchor) -> Self {
+        self.size_and_position.anchor = anchor;
+        self
+    }
+
+    /// Sets the smallest size the widget can shrink to.
+    fn with_min_size(mut self, min_size: (u16, u16)) -> Self {
+        self.size_and_position.min_size = Some(min_size);
+        self
+    }
+
+    /// Sets the largest size the widget can grow to.
+    fn with_max_size(mut self, max_size: (u16, u16)) -> Self {
+        self.size_and_position.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets whether the widget is positioned relative to its parent's window.
+    fn with_relative_to_parent(mut self, relative: bool) -> Self {
+        self.size_and_position.position_mode = match relative {
+            true => PositionMode::RelativeToParent,
+            false => PositionMode::Absolute,
+        };
+        self
+    }
+
+    /// Sets whether the widget is clipped to its parent's window.
+    fn with_clip_to_parent(mut self, clip: bool) -> Self {
+        self.size_and_position.clip_to_parent = clip;
+        self
+    }
+
+    /// Slides the widget into place from the given offset when it's first drawn.
+    fn with_entrance_animation(mut self, from_offset: (i16, i16), duration: std::time::Duration) -> Self {
+        self.size_and_position.animate_from_offset(from_offset, duration, Easing::EaseOut);
+        self
+    }
+
+    /// Colors the border and title with a slot of the app's theme.
+    fn with_theme_slot(mut self, slot: &str) -> Self {
+        self.theme_slot = Some(slot.to_owned());
+        self
+    }
+
+    /// Sets the glyphs the border is drawn with.
+    fn with_border_style(mut self, style: crate::render::BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Sets the blank space between the border (or edge) and the content.
+    fn with_padding(mut self, padding: crate::render::Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets where the title sits along the top border.
+    fn with_title_alignment(mut self, alignment: crate::render::Alignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Sets the title drawn in the bottom border and its alignment.
+    fn with_bottom_title(mut self, title: String, alignment: crate::render::Alignment) -> Self {
+        self.bottom_title = Some((title, alignment));
+        self
+    }
+
+    type FunctionType = Box<dyn Fn(&mut dyn Widget<C>, &mut crate::App<C>, &mut Scene<C>, &mut C)>;
+    /// The status bar follows the App's keymap, so this is a no-op that returns self.
+    fn with_update_handler(self, _handler: Self::FunctionType) -> Self {
+        self
+    }
+
+    /// Sets the parent widget index for this widget, if any.
+    /// By default, the parent is None, indicating a root node.
+    fn with_parent(mut self, parent: Option<usize>) -> Self {
+        self.parent = parent;
+        self
+    }
+
+    /// Tags the widget's window with the given keywords (see `Scene::widgets_by_keyword`).
+    fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Gives the widget a tooltip, shown once the mouse rests over it (see `Scene::set_tooltip_delay`).
+    fn with_tooltip(mut self, tooltip: impl Into<Tooltip<C>>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Paints the whole window with the colors and modifiers (see `Window::colorizes`).
+    fn with_colors(mut self, colors: Vec<crate::render::ColorType>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Sets whether the widget starts hidden (see `Scene::set_widget_visible`).
+    fn with_hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Sets the layer the widget's window is drawn in (see `Window::set_layer`).
+    fn with_layer(mut self, layer: crate::render::Layer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Builds the widget and adds it to the provided scene, returning the new widget's index in the scene graph.
+    /// This method combines the `build` and `scene.add_widget` calls into one for convenience.
+    /// If building the widget fails, an error is returned instead.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{StatusBarWidgetBuilder, WidgetBuilder};
+    /// # struct AppData;
+    /// let mut app = term_render::App::<AppData>::new_headless(80, 24);
+    /// let mut scene = term_render::widget::Scene::new();
+    /// let widget_index = StatusBarWidgetBuilder::<AppData>::builder(String::from("status bar"))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .expect("Failed to build and add widget to scene.");
+    /// ```
+    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetError> {
+        let (widget, window) = self.build(&app.area.read())?;
+        scene.add_widget(widget, window, &mut app.renderer.write())
+    }
+}
+
+/// A single row of key hints for the App's attached keymap (see `App::attach_keymap`), which follows the
+/// keymap as it's swapped. The keymap's name (if it has one) is shown first, highlighted, so modes can be
+/// told apart. When the terminal is too narrow for every hint, the row is cut off with an ellipsis.
+/// # Example
+/// ```
+/// use term_render::{KeyChord, KeyMap};
+/// use term_render::event_handler::KeyCode;
+/// use term_render::widget::Scene;
+/// use term_render::widget_impls::{StatusBarWidgetBuilder, WidgetBuilder};
+///
+/// let mut app = term_render::App::<()>::new_headless(40, 3);
+/// let mut scene = Scene::new();
+/// StatusBarWidgetBuilder::<()>::builder(String::from("status bar"))
+///     .add_to_scene(&mut app, &mut scene)
+///     .unwrap();
+/// app.attach_keymap(KeyMap::new("NORMAL")
+///     .with_binding(KeyChord::ctrl('s'), "Save", Box::new(|_, _| {}))
+///     .with_binding(KeyChord::char('q'), "Quit", Box::new(|_, _| {})));
+/// scene.update_all_widgets(&mut app, &mut ()).unwrap();
+/// assert_eq!(app.renderer.write().snapshot()[2], " NORMAL  ^S Save  q Quit");
+///
+/// // swapping the keymap swaps the hints
+/// app.attach_keymap(KeyMap::new("").with_binding(KeyChord::key(KeyCode::Escape), "Back", Box::new(|_, _| {})));
+/// scene.update_all_widgets(&mut app, &mut ()).unwrap();
+/// assert_eq!(app.renderer.write().snapshot()[2], "Esc Back");
+///
+/// // a narrow terminal cuts the hints off
+/// let mut app = term_render::App::<()>::new_headless(12, 1);
+/// let mut scene = Scene::new();
+/// StatusBarWidgetBuilder::<()>::builder(String::from("status bar"))
+///     .add_to_scene(&mut app, &mut scene)
+///     .unwrap();
+/// app.attach_keymap(KeyMap::new("")
+///     .with_binding(KeyChord::ctrl('s'), "Save", Box::new(|_, _| {}))
+///     .with_binding(KeyChord::char('q'), "Quit", Box::new(|_, _| {})));
+/// scene.update_all_widgets(&mut app, &mut ()).unwrap();
+/// assert_eq!(app.renderer.write().snapshot()[0], "^S Save  q …");
+/// ```
+pub struct StatusBarWidget<C> {
+    /// The indices of child widgets in the scene graph.
+    children: Vec<usize>,
+
+    /// The unique name identifier for the widget. The rendering backend
+    /// relies on `String` names instead of widgets.
+    name: String,
+
+    /// The index of the parent widget in the scene graph, if any (None would
+    /// indicate the root node).
+    parent_index: Option<usize>,
+
+    /// Configuration for the widget's size and position, supporting both static and dynamic layouts.
+    pub size_and_position: SizeAndPosition,
+
+    border: bool,
+    padding: crate::render::Padding,
+
+    /// Renders a single hint, if the default style is replaced.
+    hint_renderer: Option<HintRenderer>,
+    /// The name of the keymap being shown.
+    mode: String,
+    /// The chords and names of the keymap being shown.
+    hints: Vec<(crate::KeyChord, String)>,
+
+    /// The keywords the widget's window is tagged with (see `Scene::widgets_by_keyword`).
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+impl<C> StatusBarWidget<C> {
+    /// Returns the name of the keymap being shown, and its chords and names.
+    pub fn get_hints(&self) -> (&str, &[(crate::KeyChord, String)]) {
+        (&self.mode, &self.hints)
+    }
+
+    /// Builds the row of hints, cut off with an ellipsis if it's wider than the given width.
+    fn get_line(&self, width: usize) -> crate::render::Span {
+        let mut line = crate::render::Span::default();
+        if !self.mode.is_empty() {
+            line.push(crate::render::Colored::get_from_color_types_str(&format!(" {} ", self.mode), vec![
+                crate::render::ColorType::Reverse,
+                crate::render::ColorType::Bold,
+            ]));
+            line.push(crate::render::Colored::new(String::from(" ")));
+        }
+        for (index, (chord, name)) in self.hints.iter().enumerate() {
+            if index > 0 {  line.push(crate::render::Colored::new(String::from("  ")));  }
+            match &self.hint_renderer {
+                Some(renderer) => line.append(renderer(chord, name)),
+                None => {
+                    line.push(crate::render::Colored::get_from_color_types_str(&chord.to_string(), vec![crate::render::ColorType::Bold]));
+                    line.push(crate::render::Colored::new(format!(" {}", name)));
+                },
+            }
+        }
+        if line.size() <= width {  return line;  }
+        let mut clamped = line.clamp(width.saturating_sub(1));
+        clamped.push(crate::render::Colored::new(String::from("…")));
+        clamped
+    }
+}
+
+/// Implementation of the methods for StatusBarWidget
+impl<C: 'static> Widget<C> for StatusBarWidget<C> {
+    /// Returns the widget's name as an identifier. The rendering backend relies
+    /// on `String` names instead of widgets.
+    fn get_window_ref(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Copies the hints of the App's attached keymap (if they've changed).
+    fn update_with_events(&mut self, _data: &mut C, app: &mut crate::App<C>, _scene: &mut Scene<C>) {
+        let Some(keymap) = app.get_keymap() else {
+            self.mode.clear();
+            self.hints.clear();
+            return;
+        };
+        let unchanged = self.mode == keymap.get_name() && self.hints.len() == keymap.len() &&
+            self.hints.iter().zip(keymap.get_hints()).all(|((chord, name), hint)| (chord, name.as_str()) == hint);
+        if unchanged {  return;  }
+        self.mode = keymap.get_name().to_owned();
+        self.hints = keymap.get_hints().map(|(chord, name)| (chord.clone(), name.to_owned())).collect();
+    }
+
+    /// Draws the hints along the row, cut off to the width of the content.
+    fn update_render(&mut self, window: &mut crate::render::Window, area: &crate::render::Rect, _app_state: &mut C) -> bool {
+        let (size, position) = self.size_and_position.get_size_and_position(area);
+        window.resize(size);
+        window.r#move(position);
+        let width = size.0.saturating_sub(self.border as u16 * 2 + self.padding.left + self.padding.right);
+        window.try_update_lines(vec![self.get_line(width as usize)])
+    }
+
+    /// Returns the indices of child widgets in the scene graph.
+    fn get_children_indexes(&self) -> Vec<usize> {
+        self.children.clone()
+    }
+
+    /// Adds a child widget index to this widget.
+    fn add_child_index(&mut self, index: usize) {
+        self.children.push(index);
+    }
+
+    /// Removes a child widget index from this widget
+    fn remove_child_index(&mut self, index: usize) {
+        self.children.remove(index);
+    }
+
+    /// Clears all child widget indices from this widget
+    fn clear_children_indexes(&mut self) {
+        self.children.clear();
+    }
+
+    /// Returns the parent widget index if one exists, otherwise None.
+    fn get_parent_index(&self) -> Option<usize> {
+        self.parent_index
+    }
+
+    /// Sets the parent widget index for this widget, or None for a root node.
+    fn set_parent_index(&mut self, index: Option<usize>) {
+        self.parent_index = index;
+    }
+
+    /// Determines if a given position collides with the widget's area.
+    fn is_collided(&self, position: (u16, u16)) -> bool {
+        self.size_and_position.is_collided(position)
+    }
+
+    /// Returns the widget as `Any` for downcasting.
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget as a mutable `Any` for downcasting.
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget's size and position configuration.
+    fn get_size_and_position(&self) -> Option<&SizeAndPosition> {
+        Some(&self.size_and_position)
+    }
+
+    /// Returns the widget's size and position configuration mutably.
+    fn get_size_and_position_mut(&mut self) -> Option<&mut SizeAndPosition> {
+        Some(&mut self.size_and_position)
+    }
+
+    /// Returns the keywords the widget's window is tagged with.
+    fn get_keywords(&self) -> &[String] {
+        &self.keywords
+    }
+
+    /// Returns the tooltip shown while the mouse rests over the widget, if any.
+    fn get_tooltip(&self) -> Option<&Tooltip<C>> {
+        self.tooltip.as_ref()
+    }
+
+    /// Applies the offset and clip inherited from the parent widgets to the size and position.
+    fn set_parent_transform(&mut self, offset: (i16, i16), clip: Option<Region>) {
+        self.size_and_position.parent_offset = offset;
+        self.size_and_position.clip = clip;
+    }
+}
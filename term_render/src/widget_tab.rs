@@ -0,0 +1,721 @@
+use crate::widget_impls::*;
+use crate::widget::*;
+
+type UpdateHandler<C> = Box<dyn Fn(&mut TabWidget<C>, &mut C, &mut crate::App<C>, &mut Scene<C>)>;
+
+/// Identifies one of a tab widget's tabs (see `TabWidgetBuilder::add_tab` and `Scene::set_widget_tab`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TabHandle(usize);
+
+impl TabHandle {
+    /// Returns the position of the tab in the label bar (0 for the leftmost tab).
+    pub fn get_index(&self) -> usize {
+        self.0
+    }
+}
+
+/// Builder for creating TabWidget instances with a fluent interface.
+/// Maintains configuration state until build() is called to create the actual widget.
+pub struct TabWidgetBuilder<C> {
+    /// The unique name identifier for the widget.
+    name: String,
+    /// The z-index depth of the widget; higher values render on top of lower ones.
+    depth: Option<u16>,
+    /// Whether the widget should have a border.
+    border: bool,
+    /// The title of the widget, if any.
+    title: Option<String>,
+    /// The theme slot coloring the border and title, if any.
+    theme_slot: Option<String>,
+    /// The glyphs the border is drawn with.
+    border_style: crate::render::BorderStyle,
+    /// The blank space between the border (or edge) and the content.
+    padding: crate::render::Padding,
+    /// Where the title sits along the top border.
+    title_alignment: crate::render::Alignment,
+    /// The title drawn in the bottom border and its alignment, if any.
+    bottom_title: Option<(String, crate::render::Alignment)>,
+    /// The size and position configuration for the widget.
+    pub size_and_position: SizeAndPosition,
+    /// The index of the parent widget in the scene graph, if any.
+    parent: Option<usize>,
+    /// The labels of the tabs, from left to right.
+    tabs: Vec<crate::render::Span>,
+    /// The tab shown initially.
+    active_tab: usize,
+
+    update_handler: Option<UpdateHandler<C>>,
+
+    /// The keywords the widget's window is tagged with.
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+    /// The colors and modifiers the whole window is painted with (i.e. the border's color).
+    colors: Vec<crate::render::ColorType>,
+    /// Whether the window starts hidden.
+    hidden: bool,
+    /// The layer the window is drawn in.
+    layer: crate::render::Layer,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+impl<C> TabWidgetBuilder<C> {
+    /// Adds a tab with the given label to the right of the others, returning the handle children
+    /// are assigned to it with (see `Scene::set_widget_tab`).
+    pub fn add_tab(&mut self, label: crate::render::Span) -> TabHandle {
+        self.tabs.push(label);
+        TabHandle(self.tabs.len() - 1)
+    }
+
+    /// Adds a tab with the given label to the right of the others (see `add_tab` to get its handle).
+    pub fn with_tab(mut self, label: crate::render::Span) -> Self {
+        self.add_tab(label);
+        self
+    }
+
+    /// Sets the tab shown initially (the first one by default).
+    pub fn with_active_tab(mut self, tab: TabHandle) -> Self {
+        self.active_tab = tab.0;
+        self
+    }
+}
+
+/// Implementations for the methods in `WidgetBuilder`.
+impl<C: 'static> WidgetBuilder<C> for TabWidgetBuilder<C> {
+    /// Constructs a `TabWidget`, an implementor of `Widget`, given the parameters.
+    /// Validates that the size is non-zero before creating the widget.
+    /// The method takes in a reference to the terminal's current area/size.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{TabWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::{Rect, Span, Colored};
+    /// # struct AppData;
+    /// let mut builder = TabWidgetBuilder::<AppData>::builder(String::new());
+    /// let first = builder.add_tab(Span::from_tokens(vec![Colored::new(String::from("First"))]));
+    /// let second = builder.add_tab(Span::from_tokens(vec![Colored::new(String::from("Second"))]));
+    /// let (widget, window) = builder
+    ///     .with_active_tab(second)
+    ///     .with_position((0, 0))
+    ///     .with_size((40, 10))
+    ///     .build(&Rect { width: 80, height: 24 })  // replace &Rect with the actual terminal size (such as `&app.area.read()`)
+    ///     .expect("Invalid widget position or size.");
+    /// ```
+    fn build(mut self, display_area: &crate::render::Rect) -> Result<(Box<dyn Widget<C>>, crate::render::Window), WidgetBuilderError> {
+        let (size, position) = self.size_and_position.get_size_and_position(display_area);
+        if size.0 == 0 || size.1 == 0 {
+            return Err(WidgetBuilderError::InvalidGeometry { size, position })
+        }
+        let depth = self.depth.as_ref().unwrap_or(&0u16);
+        let mut window = crate::render::Window::new(position, *depth, size);
+        if self.border {  window.bordered();  }
+        window.set_theme_slot(self.theme_slot.clone());
+        window.set_border_style(self.border_style);
+        window.set_padding(self.padding);
+        if let Some(title) = &self.title {  window.titled(title.clone());  }
+        window.colorizes(std::mem::take(&mut self.colors));
+        if self.hidden {  window.hide();  }
+        window.set_layer(self.layer);
+        window.set_title_alignment(self.title_alignment);
+        if let Some((title, alignment)) = &self.bottom_title {
+            window.bottom_titled(crate::render::Span::from_tokens(vec![crate::render::Colored::new(title.clone())]));
+            window.set_bottom_title_alignment(*alignment);
+        }
+        Ok((Box::new(TabWidget::<C> {
+            children: vec![],
+            name: self.name,
+            parent_index: self.parent,
+            size_and_position: self.size_and_position,
+            update_handler: self.update_handler,
+            border: self.border,
+            padding: self.padding,
+            active_tab: self.active_tab.min(self.tabs.len().saturating_sub(1)),
+            tabs: self.tabs,
+            assignments: vec![],
+            selected: false,
+            keywords: self.keywords,
+            tooltip: self.tooltip,
+            __phantom: std::marker::PhantomData,
+        }), window))
+    }
+
+    /// Sets the widget's fixed position (static layout).
+    /// Retrains the dynamic proportions of any dynamic positioning configuration already in place.
+    fn with_position(mut self, position: (u16, u16)) -> Self {
+        self.size_and_position.position_offset = (position.0 as i16, position.1 as i16);
+        self
+    }
+
+    /// Sets the widget's fixed position (static layout).
+    /// Retrains the dynamic proportions of any dynamic size configuration already in place.
+    fn with_size(mut self, size: (u16, u16)) -> Self {
+        self.size_and_position.size_offset = (size.0 as i16, size.1 as i16);
+        self
+    }
+
+    /// Configures dynamic positioning based on terminal size with a fixed offset.
+    fn with_dynamic_position(mut self, position_offset: (i16, i16), position_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.position_offset = position_offset;
+        self.size_and_position.position_area_percent = position_area_percent;
+        self
+    }
+
+    /// Configures dynamic sizing based on terminal size with a fixed offset.
+    fn with_dynamic_size(mut self, size_offset: (i16, i16), size_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.size_offset = size_offset;
+        self.size_and_position.size_area_percent = size_area_percent;
+        self
+    }
+
+    /// Sets whether the widget should have a border. By default, all widgets are borderless.
+    fn with_border(mut self, border: bool) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Sets the widget's title (displayed in border if enabled; invisible otherwise).
+    fn with_title(mut self, title: String) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Assigns a depth to the widget. Higher values represent UI elements that are stacked further
+    /// ontop of other elements. By default, the builder instance has a depth of None, which corresponds
+    /// to 0 (root depth).
+    fn with_depth(mut self, depth: u16) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// The type representing the renderer closure.
+    type RendererType = Vec<crate::render::Span>;
+    /// Sets the labels of the tabs, from left to right (replacing any added so far). The handle of each
+    /// tab is its position in the list (see `add_tab` for getting the handles directly).
+    fn with_renderer(mut self, renderer: Self::RendererType) -> Self {
+        self.tabs = renderer;
+        self
+    }
+
+    /// Generates a new builder instance with a provided unique name identifier.
+    /// By default, size_and_position are no compatible and require the user to
+    /// provide them using the other `WidgetBuilder` trait functions.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{TabWidgetBuilder, WidgetBuilder};
+    /// # struct AppData;
+    /// let builder = TabWidgetBuilder::<AppData>::builder(String::from("Widget Name"));
+    /// ```
+    fn builder(name: String) -> Self {
+        Self {
+            name,
+            depth: None,
+            size_and_position: SizeAndPosition::default(),
+            border: false,
+            title: None,
+            theme_slot: None,
+            border_style: crate::render::BorderStyle::Plain,
+            padding: crate::render::Padding::default(),
+            title_alignment: crate::render::Alignment::Center,
+            bottom_title: None,
+            parent: None,
+            tabs: vec![],
+            active_tab: 0,
+            update_handler: None,
+            keywords: vec![],
+            tooltip: None,
+            colors: vec![],
+            hidden: false,
+            layer: crate::render::Layer::Normal,
+            __phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the SizeAndPosition configuration directly.
+    fn with_sap(mut self, sap: SizeAndPosition) -> Self {
+        self.size_and_position = sap;
+        self
+    }
+
+    /// Sets which point of the widget is placed at its computed position.
+    fn with_anchor(mut self, anchor: Anchor) -> Self {
+        self.size_and_position.anchor = anchor;
+        self
+    }
+
+    /// Sets the smallest size the widget can shrink to.
+    fn with_min_size(mut self, min_size: (u16, u16)) -> Self {
+        self.size_and_position.min_size = Some(min_size);
+        self
+    }
+
+    /// Sets the largest size the widget can grow to.
+    fn with_max_size(mut self, max_size: (u16, u16)) -> Self {
+        self.size_and_position.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets whether the widget is positioned relative to its parent's window.
+    fn with_relative_to_parent(mut self, relative: bool) -> Self {
+        self.size_and_position.position_mode = match relative {
+            true => PositionMode::RelativeToParent,
+            false => PositionMode::Absolute,
+        };
+        self
+    }
+
+    /// Sets whether the widget is clipped to its parent's window.
+    fn with_clip_to_parent(mut self, clip: bool) -> Self {
+        self.size_and_position.clip_to_parent = clip;
+        self
+    }
+
+    /// Slides the widget into place from the given offset when it's first drawn.
+    fn with_entrance_animation(mut self, from_offset: (i16, i16), duration: std::time::Duration) -> Self {
+        self.size_and_position.animate_from_offset(from_offset, duration, Easing::EaseOut);
+        self
+    }
+
+    /// Colors the border and title with a slot of the app's theme.
+    fn with_theme_slot(mut self, slot: &str) -> Self {
+        self.theme_slot = Some(slot.to_owned());
+        self
+    }
+
+    /// Sets the glyphs the border is drawn with.
+    fn with_border_style(mut self, style: crate::render::BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Sets the blank space between the border (or edge) and the content.
+    fn with_padding(mut self, padding: crate::render::Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets where the title sits along the top border.
+    fn with_title_alignment(mut self, alignment: crate::render::Alignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Sets the title drawn in the bottom border and its alignment.
+    fn with_bottom_title(mut self, title: String, alignment: crate::render::Alignment) -> Self {
+        self.bottom_title = Some((title, alignment));
+        self
+    }
+
+    type FunctionType = Option<UpdateHandler<C>>;
+    /// Sets a closure which is called after the tabs handle their events each frame
+    /// (`TabWidget::get_active_tab` gives the tab being shown).
+    fn with_update_handler(mut self, handler: Self::FunctionType) -> Self {
+        self.update_handler = handler;
+        self
+    }
+
+    /// Sets the parent widget index for this widget, if any.
+    /// By default, the parent is None, indicating a root node.
+    fn with_parent(mut self, parent: Option<usize>) -> Self {
+        self.parent = parent;
+        self
+    }
+
+    /// Tags the widget's window with the given keywords (see `Scene::widgets_by_keyword`).
+    fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Gives the widget a tooltip, shown once the mouse rests over it (see `Scene::set_tooltip_delay`).
+    fn with_tooltip(mut self, tooltip: impl Into<Tooltip<C>>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Paints the whole window with the colors and modifiers (see `Window::colorizes`).
+    fn with_colors(mut self, colors: Vec<crate::render::ColorType>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Sets whether the widget starts hidden (see `Scene::set_widget_visible`).
+    fn with_hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Sets the layer the widget's window is drawn in (see `Window::set_layer`).
+    fn with_layer(mut self, layer: crate::render::Layer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Builds the widget and adds it to the provided scene, returning the new widget's index in the scene graph.
+    /// This method combines the `build` and `scene.add_widget` calls into one for convenience.
+    /// If building the widget fails, an error is returned instead.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{TabWidgetBuilder, WidgetBuilder};
+    /// # struct AppData;
+    /// let mut app = term_render::App::<AppData>::new_headless(80, 24);
+    /// let mut scene = term_render::widget::Scene::new();
+    /// let widget_index = TabWidgetBuilder::<AppData>::builder(String::from("My Widget"))
+    ///     .with_position((5, 5))
+    ///     .with_size((40, 10))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .expect("Failed to build and add widget to scene.");
+    /// ```
+    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetError> {
+        let (widget, window) = self.build(&app.area.read())?;
+        scene.add_widget(widget, window, &mut app.renderer.write())
+    }
+}
+
+/// A container with a bar of tab labels along its top, which only shows the children assigned to the
+/// active tab (see `Scene::set_widget_tab`). Children which aren't assigned to a tab are always shown.
+///
+/// Clicking a label switches to its tab, as do Left/Right while the widget is selected. The children of
+/// the other tabs are hidden through the scene (see `Scene::set_widget_visible`), so they don't process
+/// events, can't be clicked or focused, and are re-rendered in full as their tab is shown again.
+/// Children are positioned in terminal coordinates (like any other widget), and are clipped to the
+/// area below the label bar (see `get_body`).
+/// # Example
+/// ```
+/// use term_render::widget::Scene;
+/// use term_render::widget_impls::{StaticTextWidgetBuilder, TabWidget, TabWidgetBuilder, WidgetBuilder};
+/// use term_render::event_handler::{MouseEvent, MouseEventType, MouseState};
+/// use term_render::render::{Colored, Span};
+///
+/// let text = |text: &str| Span::from_tokens(vec![Colored::new(String::from(text))]);
+/// let mut app = term_render::App::<()>::new_headless(30, 8);
+/// let mut scene = Scene::new();
+/// let mut builder = TabWidgetBuilder::<()>::builder(String::from("tabs"));
+/// let (first, second) = (builder.add_tab(text("One")), builder.add_tab(text("Two")));
+/// let tabs = builder
+///     .with_border(true)
+///     .with_position((0, 0))
+///     .with_size((30, 8))
+///     .add_to_scene(&mut app, &mut scene)
+///     .unwrap();
+/// for (name, tab) in [("first", first), ("second", second)] {
+///     let child = StaticTextWidgetBuilder::<()>::builder(String::from(name))
+///         .with_renderer(vec![text(&format!("The {} tab", name))])
+///         .with_position((1, 3))
+///         .with_size((20, 1))
+///         .with_parent(Some(tabs))
+///         .add_to_scene(&mut app, &mut scene)
+///         .unwrap();
+///     scene.set_widget_tab(child, tab, &mut app.renderer.write()).unwrap();
+/// }
+/// let mut frame = |app: &mut term_render::App<()>, scene: &mut Scene<()>, click: Option<(u16, u16)>| {
+///     app.events.write().mouse_event = click.map(|position| {
+///         MouseEvent { event_type: MouseEventType::Left, position, state: MouseState::Press, click_count: 1 }
+///     });
+///     scene.update_all_widgets(app, &mut ()).unwrap();
+///     app.events.write().clear_events();
+///     app.renderer.write().snapshot()
+/// };
+///
+/// let lines = frame(&mut app, &mut scene, None);
+/// assert_eq!(lines[1], "│ One │ Two                  │");
+/// assert_eq!(lines[3], "│The first tab               │");
+///
+/// // clicking the second label shows the second tab's children instead
+/// frame(&mut app, &mut scene, Some((8, 1)));
+/// let lines = frame(&mut app, &mut scene, None);
+/// assert_eq!(lines[3], "│The second tab              │");
+/// assert_eq!(scene.widget_as::<TabWidget<()>>("tabs").unwrap().get_active_tab(), second);
+/// // the hidden tab's children can't be clicked
+/// assert!(!scene.is_widget_visible(scene.get_widget_index(String::from("first")).unwrap()));
+/// assert_eq!(scene.hit_test((2, 3), &app), scene.get_widget_index(String::from("second")));
+/// ```
+pub struct TabWidget<C> {
+    /// The indices of child widgets in the scene graph.
+    children: Vec<usize>,
+
+    /// The unique name identifier for the widget. The rendering backend
+    /// relies on `String` names instead of widgets.
+    name: String,
+
+    /// The index of the parent widget in the scene graph, if any (None would
+    /// indicate the root node).
+    parent_index: Option<usize>,
+
+    /// Configuration for the widget's size and position, supporting both static and dynamic layouts.
+    pub size_and_position: SizeAndPosition,
+
+    /// Optional closure that handles updates to the widget during event processing.
+    update_handler: Option<UpdateHandler<C>>,
+
+    border: bool,
+    padding: crate::render::Padding,
+
+    /// The labels of the tabs, from left to right.
+    tabs: Vec<crate::render::Span>,
+    /// The index of the tab being shown.
+    active_tab: usize,
+    /// The tab each assigned child belongs to, as (child index, tab index).
+    assignments: Vec<(usize, usize)>,
+
+    /// Indicates whether the widget is currently selected (has the scene's focus for keyboard navigation).
+    pub selected: bool,
+
+    /// The keywords the widget's window is tagged with (see `Scene::widgets_by_keyword`).
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+impl<C> TabWidget<C> {
+    /// Adds a tab with the given label to the right of the others, returning its handle.
+    pub fn add_tab(&mut self, label: crate::render::Span) -> TabHandle {
+        self.tabs.push(label);
+        TabHandle(self.tabs.len() - 1)
+    }
+
+    /// Returns the handle of the tab being shown.
+    pub fn get_active_tab(&self) -> TabHandle {
+        TabHandle(self.active_tab)
+    }
+
+    /// Switches to the given tab (the children are shown and hidden as the widget next updates).
+    /// Returns true if the tab changed, and false if it was already active or doesn't exist.
+    pub fn set_active_tab(&mut self, tab: TabHandle) -> bool {
+        if tab.0 >= self.tabs.len() || tab.0 == self.active_tab {  return false;  }
+        self.active_tab = tab.0;
+        true
+    }
+
+    /// Returns the labels of the tabs, from left to right.
+    pub fn get_tabs(&self) -> &[crate::render::Span] {
+        &self.tabs
+    }
+
+    /// Returns the tab the child at the given index is assigned to, if any.
+    pub fn get_child_tab(&self, child: usize) -> Option<TabHandle> {
+        self.assignments.iter().find(|(index, _)| *index == child).map(|(_, tab)| TabHandle(*tab))
+    }
+
+    /// Returns the region (size, position) below the label bar, within the border and padding,
+    /// which the children are clipped to.
+    pub fn get_body(&self) -> Region {
+        let (size, position) = self.size_and_position.get_last();
+        let border = self.border as u16;
+        let top = border + self.padding.top + 1;
+        let left = border + self.padding.left;
+        (
+            (size.0.saturating_sub(left + border + self.padding.right), size.1.saturating_sub(top + border + self.padding.bottom)),
+            (position.0 + left, position.1 + top),
+        )
+    }
+
+    /// Returns the index of the tab whose label is at the given terminal position, if there is one.
+    pub fn get_tab_at(&self, position: (u16, u16)) -> Option<usize> {
+        if !self.size_and_position.is_collided(position) {  return None;  }
+        let (_, widget_position) = self.size_and_position.get_last();
+        let row = widget_position.1 + self.border as u16 + self.padding.top;
+        if position.1 != row {  return None;  }
+        let mut left = (widget_position.0 + self.border as u16 + self.padding.left) as usize;
+        for (index, label) in self.tabs.iter().enumerate() {
+            // each label is padded by a space on either side, and followed by a separator
+            let width = label.size() + 2;
+            if (left..left + width).contains(&(position.0 as usize)) {  return Some(index);  }
+            left += width + 1;
+        } None
+    }
+
+    /// Assigns the child at the given index to a tab, replacing any previous assignment.
+    fn assign(&mut self, child: usize, tab: usize) {
+        self.assignments.retain(|(index, _)| *index != child);
+        self.assignments.push((child, tab));
+    }
+
+    /// Builds the label bar with the active tab highlighted.
+    fn get_label_bar(&self) -> crate::render::Span {
+        let mut tokens = vec![];
+        for (index, label) in self.tabs.iter().enumerate() {
+            if index > 0 {  tokens.push(crate::render::Colored::new(String::from("│")));  }
+            let mut label_tokens = crate::render::Span::from_tokens(vec![crate::render::Colored::new(String::from(" "))]);
+            label_tokens.append(label.clone());
+            label_tokens.push(crate::render::Colored::new(String::from(" ")));
+            let mut label = label_tokens;
+            if index == self.active_tab {
+                label.add_color(crate::render::ColorType::Reverse);
+                label.add_color(crate::render::ColorType::Bold);
+            }
+            tokens.extend(label.get_tokens().iter().cloned());
+        }
+        crate::render::Span::from_tokens(tokens)
+    }
+
+    /// Shows the children assigned to the active tab and hides the rest (leaving any which already
+    /// match alone). Nothing is shown while the tab widget itself is hidden.
+    fn sync_visibility(&self, own_index: Option<usize>, scene: &mut Scene<C>, renderer: &mut crate::render::App) {
+        let shown = own_index.is_none_or(|index| scene.is_widget_visible(index));
+        for (child, tab) in &self.assignments {
+            let visible = shown && *tab == self.active_tab;
+            if scene.is_widget_visible(*child) != visible {
+                let _ = scene.set_widget_visible(*child, visible, renderer);
+            }
+        }
+    }
+}
+
+impl<C: 'static> Scene<C> {
+    /// Assigns the widget at the given index to a tab of its parent, which has to be a `TabWidget`
+    /// (see `WidgetBuilder::with_parent`). The widget is shown or hidden right away to match the
+    /// active tab, along with its children. Returns an error if the index is invalid, the parent
+    /// isn't a tab widget, or the tab doesn't exist.
+    pub fn set_widget_tab(&mut self, index: usize, tab: TabHandle, app: &mut crate::render::App) -> Result<(), WidgetError> {
+        let parent = self.widget_as_ref(index)?.get_parent_index().ok_or(WidgetError::NoParent(index))?;
+        let tabs = self.widget_as_dyn_mut(parent)?.as_any_mut().downcast_mut::<TabWidget<C>>()
+            .ok_or(WidgetError::WrongWidgetType { index: parent, expected: "TabWidget" })?;
+        if tab.0 >= tabs.tabs.len() {  return Err(WidgetError::new("The tab doesn't exist"));  }
+        tabs.assign(index, tab.0);
+        let visible = tab.0 == tabs.active_tab && self.is_widget_visible(parent);
+        if self.is_widget_visible(index) != visible {
+            self.set_widget_visible(index, visible, app)?;
+        } Ok(())
+    }
+}
+
+/// Implementation of the methods for TabWidget
+impl<C: 'static> Widget<C> for TabWidget<C> {
+    /// Returns the widget's name as an identifier. The rendering backend relies
+    /// on `String` names instead of widgets.
+    fn get_window_ref(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Switches tabs through clicking a label or Left/Right (while selected), then shows and hides
+    /// the children to match the active tab before calling the update handler.
+    fn update_with_events(&mut self, data: &mut C, app: &mut crate::App<C>, scene: &mut Scene<C>) {
+        let _ = self.size_and_position.get_size_and_position(&app.area.read());
+        let widget_index = scene.get_widget_index(self.get_window_ref());
+
+        let events = app.events.read();
+        if let Some(event) = &events.mouse_event &&
+            event.event_type == crate::event_handler::MouseEventType::Left &&
+            event.state == crate::event_handler::MouseState::Press &&
+            !scene.is_click_blocked_all(widget_index.unwrap_or(0), event.position, app).unwrap_or(false) &&
+            let Some(tab) = self.get_tab_at(event.position)
+        {
+            self.active_tab = tab;
+        }
+        if self.selected && !self.tabs.is_empty() {
+            let last = self.tabs.len() - 1;
+            if events.contains_key_code(crate::event_handler::KeyCode::Left) {
+                self.active_tab = self.active_tab.checked_sub(1).unwrap_or(last);
+            } else if events.contains_key_code(crate::event_handler::KeyCode::Right) {
+                self.active_tab = if self.active_tab >= last {  0  } else {  self.active_tab + 1  };
+            }
+        }
+        drop(events);
+
+        // the children come after the tabs in the scene, so they're already shown or hidden by the time they update
+        self.sync_visibility(widget_index, scene, &mut app.renderer.write());
+        if let Some(handler) = self.update_handler.take() {
+            handler(self, data, app, scene);
+            self.update_handler = Some(handler);
+            self.sync_visibility(widget_index, scene, &mut app.renderer.write());
+        }
+    }
+
+    /// Draws the label bar along the top of the content (the rest is left empty for the children).
+    fn update_render(&mut self, window: &mut crate::render::Window, area: &crate::render::Rect, _app_state: &mut C) -> bool {
+        let (size, position) = self.size_and_position.get_size_and_position(area);
+        window.resize(size);
+        window.r#move(position);
+        window.try_update_lines(vec![self.get_label_bar()])
+    }
+
+    /// Returns the indices of child widgets in the scene graph.
+    fn get_children_indexes(&self) -> Vec<usize> {
+        self.children.clone()
+    }
+
+    /// Adds a child widget index to this widget.
+    fn add_child_index(&mut self, index: usize) {
+        self.children.push(index);
+    }
+
+    /// Removes a child widget index from this widget (along with its tab assignment).
+    fn remove_child_index(&mut self, index: usize) {
+        let child = self.children.remove(index);
+        self.assignments.retain(|(assigned, _)| *assigned != child);
+    }
+
+    /// Clears all child widget indices from this widget
+    fn clear_children_indexes(&mut self) {
+        self.children.clear();
+        self.assignments.clear();
+    }
+
+    /// Returns the parent widget index if one exists, otherwise None.
+    fn get_parent_index(&self) -> Option<usize> {
+        self.parent_index
+    }
+
+    /// Sets the parent widget index for this widget, or None for a root node.
+    fn set_parent_index(&mut self, index: Option<usize>) {
+        self.parent_index = index;
+    }
+
+    /// Determines if a given position collides with the widget's area.
+    fn is_collided(&self, position: (u16, u16)) -> bool {
+        self.size_and_position.is_collided(position)
+    }
+
+    /// Returns the widget as `Any` for downcasting.
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget as a mutable `Any` for downcasting.
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget's size and position configuration.
+    fn get_size_and_position(&self) -> Option<&SizeAndPosition> {
+        Some(&self.size_and_position)
+    }
+
+    /// Returns the widget's size and position configuration mutably.
+    fn get_size_and_position_mut(&mut self) -> Option<&mut SizeAndPosition> {
+        Some(&mut self.size_and_position)
+    }
+
+    /// Returns the keywords the widget's window is tagged with.
+    fn get_keywords(&self) -> &[String] {
+        &self.keywords
+    }
+
+    /// Returns the tooltip shown while the mouse rests over the widget, if any.
+    fn get_tooltip(&self) -> Option<&Tooltip<C>> {
+        self.tooltip.as_ref()
+    }
+
+    /// Tab widgets take focus when clicked, or through Tab/Shift+Tab.
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    /// The tabs are selected (respond to the keyboard) while they have the scene's focus.
+    fn set_focused(&mut self, focused: bool) {
+        self.selected = focused;
+    }
+
+    /// Applies the offset and clip inherited from the parent widgets to the size and position.
+    fn set_parent_transform(&mut self, offset: (i16, i16), clip: Option<Region>) {
+        self.size_and_position.parent_offset = offset;
+        self.size_and_position.clip = clip;
+    }
+
+    /// Children are clipped to the area below the label bar.
+    fn get_child_clip(&self) -> Option<Region> {
+        Some(self.get_body())
+    }
+}
@@ -0,0 +1,830 @@
+use crate::widget_impls::*;
+use crate::widget::*;
+
+type UpdateHandler<C> = Box<dyn Fn(&mut TableWidget<C>, &mut C, &mut crate::App<C>, &mut Scene<C>, Option<usize>)>;
+
+/// The number of blank columns between each column of the table.
+const COLUMN_SPACING: u16 = 1;
+
+/// How the width of a table column is decided.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColumnWidth {
+    /// A fixed number of characters.
+    Fixed(u16),
+    /// A percentage of the table's width in the range [0, 1].
+    Percent(f32),
+    /// An even share of whatever width is left over after the fixed and percentage columns.
+    Fill,
+}
+
+impl ColumnWidth {
+    /// Resolves the width of each column given the available width (excluding any spacing between
+    /// the columns). Fixed columns are resolved first, then percentages, and finally fill columns
+    /// split what's left. When there isn't enough room, the columns are shrunk from the right, so
+    /// later columns may end up with a width of zero.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::ColumnWidth;
+    /// let widths = [ColumnWidth::Fixed(10), ColumnWidth::Percent(0.5), ColumnWidth::Fill, ColumnWidth::Fill];
+    /// assert_eq!(ColumnWidth::resolve(&widths, 40), vec![10, 20, 5, 5]);
+    ///
+    /// // narrower than the fixed widths combined
+    /// let widths = [ColumnWidth::Fixed(10), ColumnWidth::Fixed(10), ColumnWidth::Fill];
+    /// assert_eq!(ColumnWidth::resolve(&widths, 15), vec![10, 5, 0]);
+    /// assert_eq!(ColumnWidth::resolve(&widths, 0), vec![0, 0, 0]);
+    /// ```
+    pub fn resolve(widths: &[ColumnWidth], available: u16) -> Vec<u16> {
+        let mut remaining = available;
+        let mut resolved = vec![0u16; widths.len()];
+        for (column, width) in widths.iter().enumerate() {
+            if let ColumnWidth::Fixed(width) = width {
+                resolved[column] = (*width).min(remaining);
+                remaining -= resolved[column];
+            }
+        }
+        for (column, width) in widths.iter().enumerate() {
+            if let ColumnWidth::Percent(percent) = width {
+                resolved[column] = ((available as f32 * percent.clamp(0.0, 1.0)) as u16).min(remaining);
+                remaining -= resolved[column];
+            }
+        }
+        let fill_count = widths.iter().filter(|width| **width == ColumnWidth::Fill).count() as u16;
+        if fill_count == 0 {  return resolved;  }
+        // the leftover columns go to the first fill columns
+        let (share, mut leftover) = (remaining / fill_count, remaining % fill_count);
+        for (column, width) in widths.iter().enumerate() {
+            if *width != ColumnWidth::Fill {  continue;  }
+            resolved[column] = share + (leftover > 0) as u16;
+            leftover = leftover.saturating_sub(1);
+        } resolved
+    }
+}
+
+/// The definition of a single table column.
+#[derive(Debug, Clone)]
+pub struct ColumnDef {
+    /// The title shown in the header row.
+    pub title: crate::render::Span,
+    /// How the width of the column is decided.
+    pub width: ColumnWidth,
+}
+
+/// Builder for creating TableWidget instances with a fluent interface.
+/// Maintains configuration state until build() is called to create the actual widget.
+pub struct TableWidgetBuilder<C> {
+    /// The unique name identifier for the widget.
+    name: String,
+    /// The z-index depth of the widget; higher values render on top of lower ones.
+    depth: Option<u16>,
+    /// Whether the widget should have a border.
+    border: bool,
+    /// The title of the widget, if any.
+    title: Option<String>,
+    /// The theme slot coloring the border and title, if any.
+    theme_slot: Option<String>,
+    /// The glyphs the border is drawn with.
+    border_style: crate::render::BorderStyle,
+    /// The blank space between the border (or edge) and the content.
+    padding: crate::render::Padding,
+    /// Where the title sits along the top border.
+    title_alignment: crate::render::Alignment,
+    /// The title drawn in the bottom border and its alignment, if any.
+    bottom_title: Option<(String, crate::render::Alignment)>,
+    /// The size and position configuration for the widget.
+    pub size_and_position: SizeAndPosition,
+    /// The index of the parent widget in the scene graph, if any.
+    parent: Option<usize>,
+    /// The columns of the table.
+    columns: Vec<ColumnDef>,
+    /// The rows initially in the table.
+    rows: Vec<Vec<crate::render::Span>>,
+    /// The styling added to the header row.
+    header_style: Vec<crate::render::ColorType>,
+    /// The backgrounds of the even and odd rows of the body, if the table is striped.
+    row_striping: Option<(Option<crate::render::ColorType>, Option<crate::render::ColorType>)>,
+
+    update_handler: Option<UpdateHandler<C>>,
+
+    /// The keywords the widget's window is tagged with.
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+    /// The colors and modifiers the whole window is painted with (i.e. the border's color).
+    colors: Vec<crate::render::ColorType>,
+    /// Whether the window starts hidden.
+    hidden: bool,
+    /// The layer the window is drawn in.
+    layer: crate::render::Layer,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+impl<C> TableWidgetBuilder<C> {
+    /// Sets the columns of the table.
+    pub fn with_columns(mut self, columns: Vec<ColumnDef>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Sets the rows initially in the table (one `Span` per cell). The first row starts out selected.
+    pub fn with_rows(mut self, rows: Vec<Vec<crate::render::Span>>) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Sets the styling added to the header row. By default, the header is bold and underlined.
+    pub fn with_header_style(mut self, style: Vec<crate::render::ColorType>) -> Self {
+        self.header_style = style;
+        self
+    }
+
+    /// Stripes the rows of the body with alternating backgrounds (see `Window::set_row_striping`).
+    /// The pinned header isn't striped, the stripes stay with the rows as the body scrolls, and the
+    /// selection highlight is drawn over them.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{ColumnDef, ColumnWidth, TableWidget, TableWidgetBuilder, WidgetBuilder};
+    /// use term_render::render::{CellColor, Colored, ColorType, Frame, Rect, Span};
+    /// # struct AppData;
+    /// let span = |text: String| Span::from_tokens(vec![Colored::new(text)]);
+    /// let mut app = term_render::App::<AppData>::new_headless(10, 4);
+    /// let mut scene = term_render::widget::Scene::new();
+    /// let table = TableWidgetBuilder::<AppData>::builder(String::from("table"))
+    ///     .with_columns(vec![ColumnDef { title: span(String::from("Name")), width: ColumnWidth::Fill }])
+    ///     .with_rows((0..6).map(|index| vec![span(format!("row {}", index))]).collect())
+    ///     .with_row_striping(Some(ColorType::OnBlue), Some(ColorType::OnRed))
+    ///     .with_position((0, 0))
+    ///     .with_size((10, 4))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .unwrap();
+    /// let area = Rect { width: 10, height: 4 };
+    /// let mut render = |scene: &mut term_render::widget::Scene<AppData>| {
+    ///     scene.update_widget_renderer(table, &mut app.renderer.write(), &area, &mut AppData).unwrap();
+    ///     let mut frame = Frame::new(10, 4);
+    ///     for (y, line) in app.renderer.write().snapshot_styled().iter().enumerate() {
+    ///         frame.write_str(0, y as u16, line);
+    ///     }
+    ///     frame
+    /// };
+    ///
+    /// // the header isn't striped, and the first row of the body is even
+    /// let frame = render(&mut scene);
+    /// assert_eq!(frame.to_lines(), vec!["Name", "row 0", "row 1", "row 2"]);
+    /// assert_eq!(frame.get(9, 0).unwrap().style.bg, None);
+    /// assert!(frame.get(0, 0).unwrap().style.underline);
+    /// assert_eq!(frame.get(9, 1).unwrap().style.bg, Some(CellColor::Indexed(4)));
+    /// assert_eq!(frame.get(9, 2).unwrap().style.bg, Some(CellColor::Indexed(1)));
+    /// assert!(frame.get(0, 1).unwrap().style.reverse);  // the first row starts out selected
+    ///
+    /// // selecting the last row scrolls the body by three, so the top row of the body is odd
+    /// scene.widget_as_mut::<TableWidget<AppData>>("table").unwrap().set_selected_row(5);
+    /// let frame = render(&mut scene);
+    /// assert_eq!(frame.to_lines(), vec!["Name", "row 3", "row 4", "row 5"]);
+    /// assert_eq!(frame.get(9, 0).unwrap().style.bg, None);
+    /// assert_eq!(frame.get(9, 1).unwrap().style.bg, Some(CellColor::Indexed(1)));
+    /// assert_eq!(frame.get(9, 2).unwrap().style.bg, Some(CellColor::Indexed(4)));
+    /// assert_eq!(frame.get(9, 3).unwrap().style.bg, Some(CellColor::Indexed(1)));
+    /// assert!(frame.get(0, 3).unwrap().style.reverse && !frame.get(0, 1).unwrap().style.reverse);
+    /// ```
+    pub fn with_row_striping(mut self, even: Option<crate::render::ColorType>, odd: Option<crate::render::ColorType>) -> Self {
+        self.row_striping = Some((even, odd));
+        self
+    }
+}
+
+/// Implementations for the methods in `WidgetBuilder`.
+impl<C: 'static> WidgetBuilder<C> for TableWidgetBuilder<C> {
+    /// Constructs a `TableWidget`, an implementor of `Widget`, given the parameters.
+    /// Validates that the size is non-zero before creating the widget.
+    /// The method takes in a reference to the terminal's current area/size.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{TableWidgetBuilder, WidgetBuilder, ColumnDef, ColumnWidth};
+    /// use term_render::render::{Rect, Span, Colored};
+    /// # struct AppData;
+    /// let span = |text: &str| Span::from_tokens(vec![Colored::new(text.to_string())]);
+    /// let (widget, window) = TableWidgetBuilder::<AppData>::builder(String::new())
+    ///     .with_columns(vec![
+    ///         ColumnDef { title: span("Name"), width: ColumnWidth::Fill },
+    ///         ColumnDef { title: span("Size"), width: ColumnWidth::Fixed(8) },
+    ///     ])
+    ///     .with_rows(vec![vec![span("notes.txt"), span("2 KB")]])
+    ///     .with_position((0, 0))
+    ///     .with_size((30, 10))
+    ///     .build(&Rect { width: 80, height: 24 })  // replace &Rect with the actual terminal size (such as `&app.area.read()`)
+    ///     .expect("Invalid widget position or size.");
+    /// ```
+    fn build(mut self, display_area: &crate::render::Rect) -> Result<(Box<dyn Widget<C>>, crate::render::Window), WidgetBuilderError> {
+        let (size, position) = self.size_and_position.get_size_and_position(display_area);
+        if size.0 == 0 || size.1 == 0 {
+            return Err(WidgetBuilderError::InvalidGeometry { size, position })
+        }
+        let depth = self.depth.as_ref().unwrap_or(&0u16);
+        let mut window = crate::render::Window::new(position, *depth, size);
+        if self.border {  window.bordered();  }
+        window.set_theme_slot(self.theme_slot.clone());
+        window.set_border_style(self.border_style);
+        window.set_padding(self.padding);
+        if let Some(title) = &self.title {  window.titled(title.clone());  }
+        window.colorizes(std::mem::take(&mut self.colors));
+        if self.hidden {  window.hide();  }
+        window.set_layer(self.layer);
+        window.set_title_alignment(self.title_alignment);
+        if let Some((title, alignment)) = &self.bottom_title {
+            window.bottom_titled(crate::render::Span::from_tokens(vec![crate::render::Colored::new(title.clone())]));
+            window.set_bottom_title_alignment(*alignment);
+        }
+        Ok((Box::new(TableWidget::<C> {
+            children: vec![],
+            name: self.name,
+            parent_index: self.parent,
+            size_and_position: self.size_and_position,
+            update_handler: self.update_handler,
+            border: self.border,
+            padding: self.padding,
+            columns: self.columns,
+            selected_row: (!self.rows.is_empty()).then_some(0),
+            rows: self.rows,
+            header_style: self.header_style,
+            row_striping: self.row_striping,
+            scroll_offset: 0,
+            selected: false,
+            keywords: self.keywords,
+            tooltip: self.tooltip,
+            __phantom: std::marker::PhantomData,
+        }), window))
+    }
+
+    /// Sets the widget's fixed position (static layout).
+    /// Retrains the dynamic proportions of any dynamic positioning configuration already in place.
+    fn with_position(mut self, position: (u16, u16)) -> Self {
+        self.size_and_position.position_offset = (position.0 as i16, position.1 as i16);
+        self
+    }
+
+    /// Sets the widget's fixed position (static layout).
+    /// Retrains the dynamic proportions of any dynamic size configuration already in place.
+    fn with_size(mut self, size: (u16, u16)) -> Self {
+        self.size_and_position.size_offset = (size.0 as i16, size.1 as i16);
+        self
+    }
+
+    /// Configures dynamic positioning based on terminal size with a fixed offset.
+    fn with_dynamic_position(mut self, position_offset: (i16, i16), position_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.position_offset = position_offset;
+        self.size_and_position.position_area_percent = position_area_percent;
+        self
+    }
+
+    /// Configures dynamic sizing based on terminal size with a fixed offset.
+    fn with_dynamic_size(mut self, size_offset: (i16, i16), size_area_percent: (f32, f32)) -> Self {
+        self.size_and_position.size_offset = size_offset;
+        self.size_and_position.size_area_percent = size_area_percent;
+        self
+    }
+
+    /// Sets whether the widget should have a border. By default, all widgets are borderless.
+    fn with_border(mut self, border: bool) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Sets the widget's title (displayed in border if enabled; invisible otherwise).
+    fn with_title(mut self, title: String) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Assigns a depth to the widget. Higher values represent UI elements that are stacked further
+    /// ontop of other elements. By default, the builder instance has a depth of None, which corresponds
+    /// to 0 (root depth).
+    fn with_depth(mut self, depth: u16) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// The table lays out its own cells from the columns and rows, so there's no custom renderer.
+    type RendererType = ();
+    /// Tables don't have a custom renderer, so this is a no-op that returns self.
+    fn with_renderer(self, _renderer: Self::RendererType) -> Self {
+        self
+    }
+
+    /// Generates a new builder instance with a provided unique name identifier.
+    /// By default, size_and_position are no compatible and require the user to
+    /// provide them using the other `WidgetBuilder` trait functions.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{TableWidgetBuilder, WidgetBuilder};
+    /// # struct AppData;
+    /// let builder = TableWidgetBuilder::<AppData>::builder(String::from("Widget Name"));
+    /// ```
+    fn builder(name: String) -> Self {
+        Self {
+            name,
+            depth: None,
+            size_and_position: SizeAndPosition::default(),
+            border: false,
+            title: None,
+            theme_slot: None,
+            border_style: crate::render::BorderStyle::Plain,
+            padding: crate::render::Padding::default(),
+            title_alignment: crate::render::Alignment::Center,
+            bottom_title: None,
+            parent: None,
+            columns: vec![],
+            rows: vec![],
+            header_style: vec![crate::render::ColorType::Bold, crate::render::ColorType::Underline],
+            row_striping: None,
+            update_handler: None,
+            keywords: vec![],
+            tooltip: None,
+            colors: vec![],
+            hidden: false,
+            layer: crate::render::Layer::Normal,
+            __phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the SizeAndPosition configuration directly.
+    fn with_sap(mut self, sap: SizeAndPosition) -> Self {
+        self.size_and_position = sap;
+        self
+    }
+
+    /// Sets which point of the widget is placed at its computed position.
+    fn with_anchor(mut self, anchor: Anchor) -> Self {
+        self.size_and_position.anchor = anchor;
+        self
+    }
+
+    /// Sets the smallest size the widget can shrink to.
+    fn with_min_size(mut self, min_size: (u16, u16)) -> Self {
+        self.size_and_position.min_size = Some(min_size);
+        self
+    }
+
+    /// Sets the largest size the widget can grow to.
+    fn with_max_size(mut self, max_size: (u16, u16)) -> Self {
+        self.size_and_position.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets whether the widget is positioned relative to its parent's window.
+    fn with_relative_to_parent(mut self, relative: bool) -> Self {
+        self.size_and_position.position_mode = match relative {
+            true => PositionMode::RelativeToParent,
+            false => PositionMode::Absolute,
+        };
+        self
+    }
+
+    /// Sets whether the widget is clipped to its parent's window.
+    fn with_clip_to_parent(mut self, clip: bool) -> Self {
+        self.size_and_position.clip_to_parent = clip;
+        self
+    }
+
+    /// Slides the widget into place from the given offset when it's first drawn.
+    fn with_entrance_animation(mut self, from_offset: (i16, i16), duration: std::time::Duration) -> Self {
+        self.size_and_position.animate_from_offset(from_offset, duration, Easing::EaseOut);
+        self
+    }
+
+    /// Colors the border and title with a slot of the app's theme.
+    fn with_theme_slot(mut self, slot: &str) -> Self {
+        self.theme_slot = Some(slot.to_owned());
+        self
+    }
+
+    /// Sets the glyphs the border is drawn with.
+    fn with_border_style(mut self, style: crate::render::BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Sets the blank space between the border (or edge) and the content.
+    fn with_padding(mut self, padding: crate::render::Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets where the title sits along the top border.
+    fn with_title_alignment(mut self, alignment: crate::render::Alignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Sets the title drawn in the bottom border and its alignment.
+    fn with_bottom_title(mut self, title: String, alignment: crate::render::Alignment) -> Self {
+        self.bottom_title = Some((title, alignment));
+        self
+    }
+
+    type FunctionType = Option<UpdateHandler<C>>;
+    /// Sets a closure which is called whenever the selected row changes (by the keyboard or a click).
+    /// The closure receives the newly selected row (None once the table is emptied).
+    fn with_update_handler(mut self, handler: Self::FunctionType) -> Self {
+        self.update_handler = handler;
+        self
+    }
+
+    /// Sets the parent widget index for this widget, if any.
+    /// By default, the parent is None, indicating a root node.
+    fn with_parent(mut self, parent: Option<usize>) -> Self {
+        self.parent = parent;
+        self
+    }
+
+    /// Tags the widget's window with the given keywords (see `Scene::widgets_by_keyword`).
+    fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Gives the widget a tooltip, shown once the mouse rests over it (see `Scene::set_tooltip_delay`).
+    fn with_tooltip(mut self, tooltip: impl Into<Tooltip<C>>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Paints the whole window with the colors and modifiers (see `Window::colorizes`).
+    fn with_colors(mut self, colors: Vec<crate::render::ColorType>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Sets whether the widget starts hidden (see `Scene::set_widget_visible`).
+    fn with_hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Sets the layer the widget's window is drawn in (see `Window::set_layer`).
+    fn with_layer(mut self, layer: crate::render::Layer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Builds the widget and adds it to the provided scene, returning the new widget's index in the scene graph.
+    /// This method combines the `build` and `scene.add_widget` calls into one for convenience.
+    /// If building the widget fails, an error is returned instead.
+    /// # Example:
+    /// ```
+    /// use term_render::widget_impls::{TableWidgetBuilder, WidgetBuilder};
+    /// # struct AppData;
+    /// let mut app = term_render::App::<AppData>::new_headless(80, 24);
+    /// let mut scene = term_render::widget::Scene::new();
+    /// let widget_index = TableWidgetBuilder::<AppData>::builder(String::from("My Widget"))
+    ///     .with_position((5, 5))
+    ///     .with_size((20, 10))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .expect("Failed to build and add widget to scene.");
+    /// ```
+    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetError> {
+        let (widget, window) = self.build(&app.area.read())?;
+        scene.add_widget(widget, window, &mut app.renderer.write())
+    }
+}
+
+/// A table of rows laid out in columns, with a header row that stays pinned while the body scrolls.
+///
+/// Cells are clamped to the width of their column and padded out to it. Clicking a row selects it
+/// (and the table), after which Up/Down move the selection, PageUp/PageDown move it by the visible
+/// height, and Home/End jump to the first/last row. The mouse wheel scrolls the body while over it.
+pub struct TableWidget<C> {
+    /// The indices of child widgets in the scene graph.
+    children: Vec<usize>,
+
+    /// The unique name identifier for the widget. The rendering backend
+    /// relies on `String` names instead of widgets.
+    name: String,
+
+    /// The index of the parent widget in the scene graph, if any (None would
+    /// indicate the root node).
+    parent_index: Option<usize>,
+
+    /// Configuration for the widget's size and position, supporting both static and dynamic layouts.
+    pub size_and_position: SizeAndPosition,
+
+    /// Optional closure which is called when the selected row changes.
+    update_handler: Option<UpdateHandler<C>>,
+
+    border: bool,
+    padding: crate::render::Padding,
+
+    /// The columns of the table.
+    pub columns: Vec<ColumnDef>,
+    /// The rows of the table (one `Span` per cell).
+    rows: Vec<Vec<crate::render::Span>>,
+    /// The styling added to the header row.
+    pub header_style: Vec<crate::render::ColorType>,
+    /// The backgrounds of the even and odd rows of the body, if the table is striped.
+    pub row_striping: Option<(Option<crate::render::ColorType>, Option<crate::render::ColorType>)>,
+    /// The index of the selected row (None only when the table is empty).
+    selected_row: Option<usize>,
+    /// The index of the first visible row of the body.
+    scroll_offset: usize,
+
+    /// Indicates whether the widget is currently selected (has the scene's focus for keyboard navigation).
+    pub selected: bool,
+
+    /// The keywords the widget's window is tagged with (see `Scene::widgets_by_keyword`).
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+
+    __phantom: std::marker::PhantomData<C>,
+}
+
+impl<C> TableWidget<C> {
+    /// Returns the rows of the table.
+    pub fn get_rows(&self) -> &[Vec<crate::render::Span>] {
+        &self.rows
+    }
+
+    /// Replaces the rows of the table, clamping the selection to the new rows.
+    pub fn set_rows(&mut self, rows: Vec<Vec<crate::render::Span>>) {
+        self.rows = rows;
+        self.clamp_selection();
+    }
+
+    /// Adds a row to the end of the table (selecting it if the table was empty).
+    pub fn push_row(&mut self, row: Vec<crate::render::Span>) {
+        self.rows.push(row);
+        self.clamp_selection();
+    }
+
+    /// Removes the row at the given index, returning it if it existed. The selection stays on
+    /// the same row if possible, otherwise it's clamped to the remaining rows.
+    pub fn remove_row(&mut self, index: usize) -> Option<Vec<crate::render::Span>> {
+        if index >= self.rows.len() {  return None;  }
+        let row = self.rows.remove(index);
+        if let Some(selected) = self.selected_row && selected > index {
+            self.selected_row = Some(selected - 1);
+        }
+        self.clamp_selection();
+        Some(row)
+    }
+
+    /// Returns the index of the selected row (None if the table is empty).
+    pub fn selected_row(&self) -> Option<usize> {
+        self.selected_row
+    }
+
+    /// Selects the row at the given index (clamped to the table), scrolling it into view.
+    /// Returns true if the selection changed.
+    pub fn set_selected_row(&mut self, index: usize) -> bool {
+        if self.rows.is_empty() {  return false;  }
+        let index = index.min(self.rows.len() - 1);
+        let changed = self.selected_row != Some(index);
+        self.selected_row = Some(index);
+        self.scroll_into_view(index);
+        changed
+    }
+
+    /// Returns the index of the first visible row of the body.
+    pub fn get_scroll_offset(&self) -> usize {
+        self.scroll_offset
+    }
+
+    /// Scrolls the body by the given number of rows (negative scrolls up), clamped to the rows.
+    /// Returns true if the offset changed.
+    pub fn scroll_by(&mut self, rows: i32) -> bool {
+        let max_scroll = self.rows.len().saturating_sub(self.get_visible_rows());
+        let offset = (self.scroll_offset as i64 + rows as i64).clamp(0, max_scroll as i64) as usize;
+        let changed = offset != self.scroll_offset;
+        self.scroll_offset = offset;
+        changed
+    }
+
+    /// Returns the number of body rows which fit within the widget (excluding the border and header).
+    pub fn get_visible_rows(&self) -> usize {
+        let (size, _) = self.size_and_position.get_last();
+        size.1.saturating_sub(self.border as u16 * 2 + self.padding.top + self.padding.bottom + 1) as usize
+    }
+
+    /// Returns the resolved width of each column for the table's current size.
+    pub fn get_column_widths(&self) -> Vec<u16> {
+        let (size, _) = self.size_and_position.get_last();
+        let spacing = COLUMN_SPACING * self.columns.len().saturating_sub(1) as u16;
+        let available = size.0
+            .saturating_sub(self.border as u16 * 2 + self.padding.left + self.padding.right)
+            .saturating_sub(spacing);
+        let widths: Vec<ColumnWidth> = self.columns.iter().map(|column| column.width).collect();
+        ColumnWidth::resolve(&widths, available)
+    }
+
+    /// Scrolls the minimum amount needed for the row at the given index to be visible.
+    pub fn scroll_into_view(&mut self, index: usize) {
+        let rows = self.get_visible_rows().max(1);
+        if index < self.scroll_offset {
+            self.scroll_offset = index;
+        } else if index >= self.scroll_offset + rows {
+            self.scroll_offset = index + 1 - rows;
+        }
+    }
+
+    /// Returns the index of the body row drawn at the given terminal position, if there is one.
+    pub fn get_row_at(&self, position: (u16, u16)) -> Option<usize> {
+        if !self.size_and_position.is_collided(position) {  return None;  }
+        let (size, widget_position) = self.size_and_position.get_last();
+        let top = widget_position.1 + self.border as u16 + self.padding.top + 1;  // below the header
+        let bottom = (widget_position.1 + size.1).saturating_sub(self.border as u16 + self.padding.bottom);
+        if position.1 < top || position.1 >= bottom {  return None;  }
+        let index = self.scroll_offset + (position.1 - top) as usize;
+        (index < self.rows.len()).then_some(index)
+    }
+
+    /// Keeps the selection and scroll offset within the rows.
+    fn clamp_selection(&mut self) {
+        self.selected_row = match self.rows.len() {
+            0 => None,
+            len => Some(self.selected_row.unwrap_or(0).min(len - 1)),
+        };
+        self.scroll_by(0);
+    }
+
+    /// Lays out a row of cells, clamping and padding each one to the width of its column.
+    fn layout_row(cells: &[crate::render::Span], widths: &[u16]) -> crate::render::Span {
+        let mut line = crate::render::Span::default();
+        for (column, width) in widths.iter().enumerate() {
+            if column > 0 {
+                line.push(crate::render::Colored::new(" ".repeat(COLUMN_SPACING as usize)));
+            }
+            let cell = cells.get(column).map(|cell| cell.clamp(*width as usize)).unwrap_or_default();
+            let padding = (*width as usize).saturating_sub(cell.size());
+            line.append(cell);
+            line.push(crate::render::Colored::new(" ".repeat(padding)));
+        } line
+    }
+}
+
+/// Implementation of the methods for TableWidget
+impl<C: 'static> Widget<C> for TableWidget<C> {
+    /// Returns the widget's name as an identifier. The rendering backend relies
+    /// on `String` names instead of widgets.
+    fn get_window_ref(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Handles selecting rows through the mouse and keyboard (while selected), and scrolling
+    /// through the mouse wheel (while over the table). The update handler is called if the
+    /// selected row changed.
+    fn update_with_events(&mut self, data: &mut C, app: &mut crate::App<C>, scene: &mut Scene<C>) {
+        let _ = self.size_and_position.get_size_and_position(&app.area.read());
+        self.scroll_by(0);  // the table may have been resized
+        let previous_row = self.selected_row;
+
+        let events = app.events.read();
+        if let Some(event) = &events.mouse_event && event.state == crate::event_handler::MouseState::Press {
+            match event.event_type {
+                crate::event_handler::MouseEventType::Up if self.is_collided(event.position) => {
+                    self.scroll_by(-1);
+                },
+                crate::event_handler::MouseEventType::Down if self.is_collided(event.position) => {
+                    self.scroll_by(1);
+                },
+                // the scene focuses the table when it receives the click
+                crate::event_handler::MouseEventType::Left => {
+                    if self.selected && let Some(row) = self.get_row_at(event.position) {
+                        self.set_selected_row(row);
+                    }
+                },
+                _ => {},
+            }
+        }
+        if self.selected && let Some(selected) = self.selected_row {
+            let page = self.get_visible_rows().max(1);
+            let moves = [
+                (crate::event_handler::KeyCode::Up, selected.saturating_sub(1)),
+                (crate::event_handler::KeyCode::Down, selected + 1),
+                (crate::event_handler::KeyCode::PageUp, selected.saturating_sub(page)),
+                (crate::event_handler::KeyCode::PageDown, selected + page),
+                (crate::event_handler::KeyCode::Home, 0),
+                (crate::event_handler::KeyCode::End, self.rows.len()),
+            ];
+            for (key, row) in moves {
+                if events.contains_key_code(key) {  self.set_selected_row(row);  }
+            }
+        }
+        drop(events);
+
+        if self.selected_row != previous_row && let Some(handler) = self.update_handler.take() {
+            let selected_row = self.selected_row;
+            handler(self, data, app, scene, selected_row);
+            self.update_handler = Some(handler);
+        }
+    }
+
+    /// Draws the header row followed by the visible rows of the body, with the selected row reversed.
+    fn update_render(&mut self, window: &mut crate::render::Window, area: &crate::render::Rect, _app_state: &mut C) -> bool {
+        let (size, position) = self.size_and_position.get_size_and_position(area);
+        window.resize(size);
+        window.r#move(position);
+        let widths = self.get_column_widths();
+        let titles: Vec<crate::render::Span> = self.columns.iter().map(|column| column.title.clone()).collect();
+        let mut header = Self::layout_row(&titles, &widths);
+        for color in &self.header_style {
+            header.add_color(*color);
+        }
+
+        let rows = self.get_visible_rows();
+        let end = (self.scroll_offset + rows).min(self.rows.len());
+        let mut lines = vec![header];
+        for index in self.scroll_offset.min(end)..end {
+            let mut line = Self::layout_row(&self.rows[index], &widths);
+            if self.selected_row == Some(index) {
+                line.add_color(crate::render::ColorType::Reverse);
+            }
+            lines.push(line);
+        }
+        lines.resize(rows + 1, crate::render::Span::default());
+        // the stripes are anchored to the rows of the body (below the header), so they don't flip as it scrolls
+        window.set_striping(self.row_striping.map(|(even, odd)| {
+            crate::render::RowStriping { even, odd, start_row: self.scroll_offset, header_rows: 1 }
+        }));
+        window.try_update_lines(lines)
+    }
+
+    /// Returns the indices of child widgets in the scene graph.
+    fn get_children_indexes(&self) -> Vec<usize> {
+        self.children.clone()
+    }
+
+    /// Adds a child widget index to this widget.
+    fn add_child_index(&mut self, index: usize) {
+        self.children.push(index);
+    }
+
+    /// Removes a child widget index from this widget
+    fn remove_child_index(&mut self, index: usize) {
+        self.children.remove(index);
+    }
+
+    /// Clears all child widget indices from this widget
+    fn clear_children_indexes(&mut self) {
+        self.children.clear();
+    }
+
+    /// Returns the parent widget index if one exists, otherwise None.
+    fn get_parent_index(&self) -> Option<usize> {
+        self.parent_index
+    }
+
+    /// Sets the parent widget index for this widget, or None for a root node.
+    fn set_parent_index(&mut self, index: Option<usize>) {
+        self.parent_index = index;
+    }
+
+    /// Determines if a given position collides with the widget's area.
+    fn is_collided(&self, position: (u16, u16)) -> bool {
+        self.size_and_position.is_collided(position)
+    }
+
+    /// Returns the widget as `Any` for downcasting.
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget as a mutable `Any` for downcasting.
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget's size and position configuration.
+    fn get_size_and_position(&self) -> Option<&SizeAndPosition> {
+        Some(&self.size_and_position)
+    }
+
+    /// Returns the widget's size and position configuration mutably.
+    fn get_size_and_position_mut(&mut self) -> Option<&mut SizeAndPosition> {
+        Some(&mut self.size_and_position)
+    }
+
+    /// Returns the keywords the widget's window is tagged with.
+    fn get_keywords(&self) -> &[String] {
+        &self.keywords
+    }
+
+    /// Returns the tooltip shown while the mouse rests over the widget, if any.
+    fn get_tooltip(&self) -> Option<&Tooltip<C>> {
+        self.tooltip.as_ref()
+    }
+
+    /// Tables take focus when clicked, or through Tab/Shift+Tab.
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    /// The table is selected (responds to the keyboard) while it has the scene's focus.
+    fn set_focused(&mut self, focused: bool) {
+        self.selected = focused;
+    }
+
+    /// Applies the offset and clip inherited from the parent widgets to the size and position.
+    fn set_parent_transform(&mut self, offset: (i16, i16), clip: Option<Region>) {
+        self.size_and_position.parent_offset = offset;
+        self.size_and_position.clip = clip;
+    }
+}
@@ -1,7 +1,25 @@
 use crate::widget_impls::*;
 use crate::widget::*;
+use crate::event_handler::{InputEvent, KeyCode, KeyModifiers};
 
-type RenderFunction<C> = Box<dyn Fn((u16, u16), (u16, u16), &[&str; 2], bool, &mut C) -> Option<Vec<crate::render::Span>>>;
+type RenderFunction<C> = Box<dyn Fn((u16, u16), (u16, u16), &[&str; 3], Option<(usize, usize)>, bool, &mut C) -> Option<Vec<crate::render::Span>>>;
+type UpdateHandler<C> = Box<dyn Fn(&mut TypingWidget<C>, &mut C, &mut crate::App<C>, &mut Scene<C>)>;
+/// How long a paste waits for the terminal to answer the clipboard request before giving up.
+const CLIPBOARD_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(1);
+
+type MultilineRenderFunction<C> = Box<dyn Fn((u16, u16), (u16, u16), &MultilineText, bool, &mut C) -> Option<Vec<crate::render::Span>>>;
+
+/// The typed text of a multi-line `TypingWidget`, as handed to its multi-line renderer.
+pub struct MultilineText<'a> {
+    /// The typed text split at each newline (there's always at least one line).
+    pub lines: Vec<&'a str>,
+    /// The line and column (in chars) of the cursor.
+    pub cursor: (usize, usize),
+    /// The in-progress input method composition at the cursor (empty if there isn't one).
+    pub preedit: &'a str,
+    /// The start and end (line, column) of the selection, if there is one. The end is exclusive.
+    pub selection: Option<((usize, usize), (usize, usize))>,
+}
 
 /// Builder for creating StaticWidget instances with a fluent interface.
 /// Maintains configuration state until build() is called to create the actual widget.
@@ -16,6 +34,16 @@ pub struct TypingWidgetBuilder<C> {
     border: bool,
     /// The title of the widget, if any.
     title: Option<String>,
+    /// The theme slot coloring the border and title, if any.
+    theme_slot: Option<String>,
+    /// The glyphs the border is drawn with.
+    border_style: crate::render::BorderStyle,
+    /// The blank space between the border (or edge) and the content.
+    padding: crate::render::Padding,
+    /// Where the title sits along the top border.
+    title_alignment: crate::render::Alignment,
+    /// The title drawn in the bottom border and its alignment, if any.
+    bottom_title: Option<(String, crate::render::Alignment)>,
     /// The size and position configuration for the widget.
     pub size_and_position: SizeAndPosition,
     /// The custom render function for the widget, if any.
@@ -23,33 +51,244 @@ pub struct TypingWidgetBuilder<C> {
     /// The index of the parent widget in the scene graph, if any.
     parent: Option<usize>,
     
-    update_handler: Option<Box<dyn Fn(&mut dyn Widget<C>, &mut C, &mut crate::App<C>, &mut Scene<C>)>>,
+    update_handler: Option<UpdateHandler<C>>,
+    
+    /// Whether Return inserts newlines and Up/Down move between lines.
+    multiline: bool,
+    /// The renderer used instead of `render_function` in multi-line mode, if any.
+    multiline_render_function: Option<MultilineRenderFunction<C>>,
+    /// Decides which characters can be typed, if any filter is set.
+    input_filter: Option<Box<dyn Fn(char) -> bool>>,
+    /// The maximum number of characters which can be typed, if any.
+    max_length: Option<usize>,
+    /// The line shown while the text is empty and the widget isn't selected, if any.
+    placeholder: Option<crate::render::Span>,
+    /// The character shown in place of each typed character (i.e. for passwords), if any.
+    mask_char: Option<char>,
     
+    /// The keywords the widget's window is tagged with.
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+    /// The colors and modifiers the whole window is painted with (i.e. the border's color).
+    colors: Vec<crate::render::ColorType>,
+    /// Whether the window starts hidden.
+    hidden: bool,
+    /// The layer the window is drawn in.
+    layer: crate::render::Layer,
+
+    /// The closure run once the widget is added to the scene, if any (see `Widget::on_mount`).
+    on_mount: Option<LifecycleHook<C>>,
+    /// The closure run once the widget is removed from the scene, if any (see `Widget::on_unmount`).
+    on_unmount: Option<LifecycleHook<C>>,
+
     __phantom: std::marker::PhantomData<C>,
 }
 
+impl<C> TypingWidgetBuilder<C> {
+    /// Sets whether the widget edits multiple lines of text. In multi-line mode, Return inserts a newline,
+    /// Up/Down move the cursor between lines (keeping the column it started at where possible),
+    /// and deleting at the start of a line joins it with the previous one. By default, widgets are single-line.
+    pub fn with_multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self
+    }
+
+    /// Sets the renderer for multi-line mode (this also enables multi-line mode). Instead of the text
+    /// split around the cursor, the closure receives the text split into lines along with the cursor's line and column.
+    /// # Example
+    /// ```
+    /// use term_render::widget_impls::{TypingWidget, TypingWidgetBuilder, WidgetBuilder};
+    /// use term_render::widget::Scene;
+    /// use term_render::render::{Colored, Span};
+    /// let mut app = term_render::App::<()>::new_headless(10, 3);
+    /// let mut scene = Scene::new();
+    /// let editor = TypingWidgetBuilder::<()>::builder(String::from("editor"))
+    ///     // one row per line, with the cursor drawn as a `|`
+    ///     .with_multiline_renderer(Box::new(|_size, _position, text, _selected, _data| {
+    ///         Some(text.lines.iter().enumerate().map(|(index, line)| {
+    ///             let mut line = line.to_string();
+    ///             if index == text.cursor.0 {
+    ///                 let split = line.char_indices().nth(text.cursor.1).map(|(at, _)| at).unwrap_or(line.len());
+    ///                 line.insert(split, '|');
+    ///             }
+    ///             Span::from_tokens(vec![Colored::new(line)])
+    ///         }).collect())
+    ///     }))
+    ///     .with_position((0, 0))
+    ///     .with_size((10, 3))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .unwrap();
+    /// scene.set_focus(editor).unwrap();
+    /// let mut parser = vte::Parser::new();
+    /// let mut frame = |scene: &mut Scene<()>, keys: &[&[u8]]| {
+    ///     app.events.write().clear_events();
+    ///     for key in keys {
+    ///         app.events.write().parse(&mut parser, key);
+    ///     }
+    ///     scene.update_all_widgets(&mut app, &mut ()).unwrap();
+    ///     app.renderer.write().snapshot()
+    /// };
+    /// let (up, down) = (&b"\x1b[A"[..], &b"\x1b[B"[..]);
+    /// // Return starts a new line
+    /// assert_eq!(frame(&mut scene, &[b"a", "ü".as_bytes(), b"c", b"d", b"\r", b"e"]), vec!["aücd", "e|", ""]);
+    /// // up keeps the column (in chars, not bytes) where it can, and down goes back to where it started
+    /// assert_eq!(frame(&mut scene, &[up]), vec!["a|ücd", "e", ""]);
+    /// assert_eq!(frame(&mut scene, &[b"\x1b[C", b"\x1b[C", down, up]), vec!["aüc|d", "e", ""]);
+    /// // deleting at the start of a line joins it onto the previous one
+    /// assert_eq!(frame(&mut scene, &[down, b"\x1b[D", b"\x7f"]), vec!["aücd|e", "", ""]);
+    /// assert_eq!(scene.widget_as::<TypingWidget<()>>("editor").unwrap().typed_text, "aücde");
+    /// ```
+    pub fn with_multiline_renderer(mut self, renderer: MultilineRenderFunction<C>) -> Self {
+        self.multiline = true;
+        self.multiline_render_function = Some(renderer);
+        self
+    }
+
+    /// Sets a filter deciding which characters can be typed (or pasted); any others are dropped.
+    /// For example, `Box::new(|chr| chr.is_ascii_digit())` for a numeric field.
+    /// Newlines from Return in multi-line mode go through the filter as well.
+    pub fn with_input_filter(mut self, filter: Box<dyn Fn(char) -> bool>) -> Self {
+        self.input_filter = Some(filter);
+        self
+    }
+
+    /// Sets the maximum number of characters the text can hold. Once it's reached, further input is
+    /// ignored, but deleting and moving the cursor still work.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets a placeholder (i.e. "Type here...") which is rendered in place of the render function's
+    /// output while the text is empty and the widget isn't selected.
+    /// # Example
+    /// ```
+    /// use term_render::widget_impls::{TypingWidgetBuilder, WidgetBuilder};
+    /// use term_render::widget::Scene;
+    /// use term_render::render::{Colored, Span};
+    /// let mut app = term_render::App::<()>::new_headless(12, 1);
+    /// let mut scene = Scene::new();
+    /// let field = TypingWidgetBuilder::<()>::builder(String::from("name"))
+    ///     .with_placeholder(Span::from_tokens(vec![Colored::new(String::from("Type here..."))]))
+    ///     .with_renderer(Box::new(|_size, _position, typed, _selection, _selected, _data| {
+    ///         Some(vec![Span::from_tokens(vec![Colored::new(typed.concat())])])
+    ///     }))
+    ///     .with_position((0, 0))
+    ///     .with_size((12, 1))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .unwrap();
+    /// let mut parser = vte::Parser::new();
+    /// let mut frame = |scene: &mut Scene<()>, keys: &[u8]| {
+    ///     app.events.write().clear_events();
+    ///     for key in keys {
+    ///         app.events.write().parse(&mut parser, &[*key]);
+    ///     }
+    ///     scene.update_all_widgets(&mut app, &mut ()).unwrap();
+    ///     app.renderer.write().snapshot()[0].clone()
+    /// };
+    /// assert_eq!(frame(&mut scene, b""), "Type here...");
+    /// // hidden once the field is focused, even before anything is typed
+    /// scene.set_focus(field).unwrap();
+    /// assert_eq!(frame(&mut scene, b""), "");
+    /// assert_eq!(frame(&mut scene, b"Al"), "Al");
+    /// // and only shown again once the text is empty and the field has lost focus
+    /// scene.clear_focus();
+    /// assert_eq!(frame(&mut scene, b""), "Al");
+    /// scene.set_focus(field).unwrap();
+    /// assert_eq!(frame(&mut scene, b"\x7f\x7f"), "");
+    /// scene.clear_focus();
+    /// assert_eq!(frame(&mut scene, b""), "Type here...");
+    /// ```
+    pub fn with_placeholder(mut self, placeholder: crate::render::Span) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Masks the text passed to the render function, repeating `mask_char` once per typed character
+    /// (i.e. `'•'` for a password field). `typed_text` still holds the real value.
+    /// # Example
+    /// ```
+    /// use term_render::widget_impls::{TypingWidget, TypingWidgetBuilder, WidgetBuilder};
+    /// use term_render::widget::Scene;
+    /// use term_render::render::{Colored, Span};
+    /// let mut app = term_render::App::<()>::new_headless(12, 1);
+    /// let mut scene = Scene::new();
+    /// let field = TypingWidgetBuilder::<()>::builder(String::from("password"))
+    ///     .with_mask_char('•')
+    ///     // marking the cursor between the two halves of the text
+    ///     .with_renderer(Box::new(|_size, _position, typed, _selection, _selected, _data| {
+    ///         Some(vec![Span::from_tokens(vec![Colored::new(format!("{}|{}{}", typed[0], typed[1], typed[2]))])])
+    ///     }))
+    ///     .with_position((0, 0))
+    ///     .with_size((12, 1))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .unwrap();
+    /// scene.set_focus(field).unwrap();
+    /// let mut parser = vte::Parser::new();
+    /// // "pässwd", and then left twice (each key as its own read)
+    /// for key in [&b"p"[..], "ä".as_bytes(), b"s", b"s", b"w", b"d", b"\x1b[D", b"\x1b[D"] {
+    ///     app.events.write().parse(&mut parser, key);
+    /// }
+    /// scene.update_all_widgets(&mut app, &mut ()).unwrap();
+    /// assert_eq!(app.renderer.write().snapshot()[0], "••••|••");
+    /// assert_eq!(scene.widget_as::<TypingWidget<()>>("password").unwrap().typed_text, "pässwd");
+    /// ```
+    pub fn with_mask_char(mut self, mask_char: char) -> Self {
+        self.mask_char = Some(mask_char);
+        self
+    }
+
+    /// Sets a closure run once the widget is added to the scene, given the app data and the app, for
+    /// starting anything the widget owns (see `Widget::on_mount` for when it runs).
+    pub fn with_on_mount(mut self, on_mount: LifecycleHook<C>) -> Self {
+        self.on_mount = Some(on_mount);
+        self
+    }
+
+    /// Sets a closure run once the widget is removed from the scene, given the app data and the app, for
+    /// stopping anything started when it was mounted (see `Widget::on_unmount`).
+    pub fn with_on_unmount(mut self, on_unmount: LifecycleHook<C>) -> Self {
+        self.on_unmount = Some(on_unmount);
+        self
+    }
+}
+
 /// Implementations for the methods in `WidgetBuilder`.
 impl<C: 'static> WidgetBuilder<C> for TypingWidgetBuilder<C> {
     /// Constructs a `TypingWidget`, an implementor of `Widget`, given the parameters.
-    /// Validates that size and position are non-zero before creating the widget.
+    /// Validates that the size is non-zero before creating the widget.
     /// The method takes in a reference to the terminal's current area/size.
     /// # Example:
     /// ```
     /// use term_render::widget_impls::{TypingWidgetBuilder, WidgetBuilder};
     /// use term_render::render::Rect;
+    /// # struct AppData;
     /// let (widget, window) = TypingWidgetBuilder::<AppData>::builder(String::new())
-    ///     .build(&Rect::default())  // replace &Rect with the actual terminal size (such as `&app.area.read()`)
+    ///     .with_size((20, 10))
+    ///     .build(&Rect { width: 80, height: 24 })  // replace &Rect with the actual terminal size (such as `&app.area.read()`)
     ///     .expect("Invalid widget position or size.");
     /// ```
     fn build(mut self, display_area: &crate::render::Rect) -> Result<(Box<dyn Widget<C>>, crate::render::Window), WidgetBuilderError> {
-        let (position, size) = self.size_and_position.get_size_and_position(display_area);
-        if size.0 == 0 || size.1 == 0 || position.0 == 0 || position.1 == 0 {
-            return Err(WidgetBuilderError { details: String::from("Position and/or size cannot be zero when building a new widget or window.") })
+        let (size, position) = self.size_and_position.get_size_and_position(display_area);
+        if size.0 == 0 || size.1 == 0 {
+            return Err(WidgetBuilderError::InvalidGeometry { size, position })
         }
         let depth = self.depth.as_ref().unwrap_or(&0u16);
         let mut window = crate::render::Window::new(position, *depth, size);
         if self.border {  window.bordered();  }
+        window.set_theme_slot(self.theme_slot.clone());
+        window.set_border_style(self.border_style);
+        window.set_padding(self.padding);
         if let Some(title) = &self.title {  window.titled(title.clone());  }
+        window.colorizes(std::mem::take(&mut self.colors));
+        if self.hidden {  window.hide();  }
+        window.set_layer(self.layer);
+        window.set_title_alignment(self.title_alignment);
+        if let Some((title, alignment)) = &self.bottom_title {
+            window.bottom_titled(crate::render::Span::from_tokens(vec![crate::render::Colored::new(title.clone())]));
+            window.set_bottom_title_alignment(*alignment);
+        }
         Ok((Box::new(TypingWidget::<C> {
             children: vec![],
             name: self.name,
@@ -60,6 +299,23 @@ impl<C: 'static> WidgetBuilder<C> for TypingWidgetBuilder<C> {
             typed_text: String::new(),
             selected: false,
             cursor_pos: 0,
+            preedit: None,
+            cursor_position: None,
+            border: self.border,
+            padding: self.padding,
+            multiline: self.multiline,
+            multiline_render_function: self.multiline_render_function,
+            input_filter: self.input_filter,
+            max_length: self.max_length,
+            placeholder: self.placeholder,
+            mask_char: self.mask_char,
+            preferred_column: None,
+            selection_anchor: None,
+            clipboard_requested: None,
+            keywords: self.keywords,
+            tooltip: self.tooltip,
+            on_mount: self.on_mount,
+            on_unmount: self.on_unmount,
             __phantom: std::marker::PhantomData,
         }), window))
     }
@@ -118,20 +374,25 @@ impl<C: 'static> WidgetBuilder<C> for TypingWidgetBuilder<C> {
     /// Sets the rendering closure that generates content for the widget.
     /// The closure receives size and position parameters and returns an optional vector of type `Span`.
     /// By default, there is no renderer, leaving the widget empty (apart from stylization like a border or title).
-    /// The closure is a boxed closure that takes in `(size: (u16, u16), position: (u16, u16), typed_text: &[&str; 2])`. This closure
-    /// can capture local context to allow for easier dynamic variations between widgets with minimal boilerplate.
+    /// The closure is a boxed closure that takes in
+    /// `(size: (u16, u16), position: (u16, u16), typed_text: &[&str; 3], selection: Option<(usize, usize)>, selected: bool, data: &mut C)`.
+    /// The typed text is split into the text before the cursor, the in-progress input method composition (preedit),
+    /// and the text after the cursor. The preedit isn't part of the typed text yet, so it's usually drawn underlined.
+    /// The selection is the start and (exclusive) end char index of the selected text within the typed text (not counting the preedit).
+    /// This closure can capture local context to allow for easier dynamic variations between widgets with minimal boilerplate.
     /// #Example:
     /// ```
     /// use term_render::widget_impls::{TypingWidgetBuilder, WidgetBuilder};
     /// use term_render::render::Rect;
+    /// # struct AppData;
     ///
     /// // the closure can capture local variables to reduce boilerplate
-    /// let closure = Box::new(move |size, position, typed_text| {
-    ///     None  // this will leave the widget un-updated (it will default to its cache and assume no updates are necessary unless other events occur)
-    /// });
     /// let (widget, window) = TypingWidgetBuilder::<AppData>::builder(String::new())
-    ///     .with_renderer(closure)
-    ///     .build(&Rect::default())
+    ///     .with_renderer(Box::new(move |size, position, typed_text: &_, selection, selected, data: &mut AppData| {
+    ///         None  // this will leave the widget un-updated (it will default to its cache and assume no updates are necessary unless other events occur)
+    ///     }))
+    ///     .with_size((20, 10))
+    ///     .build(&Rect { width: 80, height: 24 })
     ///     .unwrap();
     /// ```
     fn with_renderer(mut self, renderer: Self::RendererType) -> Self {
@@ -145,6 +406,7 @@ impl<C: 'static> WidgetBuilder<C> for TypingWidgetBuilder<C> {
     /// # Example:
     /// ```
     /// use term_render::widget_impls::{TypingWidgetBuilder, WidgetBuilder};
+    /// # struct AppData;
     /// let builder = TypingWidgetBuilder::<AppData>::builder(String::from("Widget Name"));
     /// ```
     fn builder(name: String) -> Self {
@@ -155,8 +417,26 @@ impl<C: 'static> WidgetBuilder<C> for TypingWidgetBuilder<C> {
             render_function: None,
             border: false,
             title: None,
+            theme_slot: None,
+            border_style: crate::render::BorderStyle::Plain,
+            padding: crate::render::Padding::default(),
+            title_alignment: crate::render::Alignment::Center,
+            bottom_title: None,
             parent: None,
             update_handler: None,
+            multiline: false,
+            multiline_render_function: None,
+            input_filter: None,
+            max_length: None,
+            placeholder: None,
+            mask_char: None,
+            keywords: vec![],
+            tooltip: None,
+            colors: vec![],
+            hidden: false,
+            layer: crate::render::Layer::Normal,
+            on_mount: None,
+            on_unmount: None,
             __phantom: std::marker::PhantomData,
         }
     }
@@ -167,9 +447,79 @@ impl<C: 'static> WidgetBuilder<C> for TypingWidgetBuilder<C> {
         self
     }
 
-    type FunctionType = Option<Box<dyn Fn(&mut dyn Widget<C>, &mut C, &mut crate::App<C>, &mut Scene<C>)>>;
+    /// Sets which point of the widget is placed at its computed position.
+    fn with_anchor(mut self, anchor: Anchor) -> Self {
+        self.size_and_position.anchor = anchor;
+        self
+    }
+
+    /// Sets the smallest size the widget can shrink to.
+    fn with_min_size(mut self, min_size: (u16, u16)) -> Self {
+        self.size_and_position.min_size = Some(min_size);
+        self
+    }
+
+    /// Sets the largest size the widget can grow to.
+    fn with_max_size(mut self, max_size: (u16, u16)) -> Self {
+        self.size_and_position.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets whether the widget is positioned relative to its parent's window.
+    fn with_relative_to_parent(mut self, relative: bool) -> Self {
+        self.size_and_position.position_mode = match relative {
+            true => PositionMode::RelativeToParent,
+            false => PositionMode::Absolute,
+        };
+        self
+    }
+
+    /// Sets whether the widget is clipped to its parent's window.
+    fn with_clip_to_parent(mut self, clip: bool) -> Self {
+        self.size_and_position.clip_to_parent = clip;
+        self
+    }
+
+    /// Slides the widget into place from the given offset when it's first drawn.
+    fn with_entrance_animation(mut self, from_offset: (i16, i16), duration: std::time::Duration) -> Self {
+        self.size_and_position.animate_from_offset(from_offset, duration, Easing::EaseOut);
+        self
+    }
+
+    /// Colors the border and title with a slot of the app's theme.
+    fn with_theme_slot(mut self, slot: &str) -> Self {
+        self.theme_slot = Some(slot.to_owned());
+        self
+    }
+
+    /// Sets the glyphs the border is drawn with.
+    fn with_border_style(mut self, style: crate::render::BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Sets the blank space between the border (or edge) and the content.
+    fn with_padding(mut self, padding: crate::render::Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets where the title sits along the top border.
+    fn with_title_alignment(mut self, alignment: crate::render::Alignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Sets the title drawn in the bottom border and its alignment.
+    fn with_bottom_title(mut self, title: String, alignment: crate::render::Alignment) -> Self {
+        self.bottom_title = Some((title, alignment));
+        self
+    }
+
+    type FunctionType = Option<UpdateHandler<C>>;
     /// The box itself is basically static, however the text being typed is dynamic and will call the
-    /// callback closure to allow for state changes and other actions.
+    /// callback closure to allow for state changes and other actions. The closure receives the widget
+    /// itself, so it can read the typed or selected text (i.e. to wire copy and cut to a clipboard).
     fn with_update_handler(mut self, handler: Self::FunctionType) -> Self {
         // static widgets don't need an update handler
         self.update_handler = handler;
@@ -182,6 +532,36 @@ impl<C: 'static> WidgetBuilder<C> for TypingWidgetBuilder<C> {
         self.parent = parent;
         self
     }
+
+    /// Tags the widget's window with the given keywords (see `Scene::widgets_by_keyword`).
+    fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Gives the widget a tooltip, shown once the mouse rests over it (see `Scene::set_tooltip_delay`).
+    fn with_tooltip(mut self, tooltip: impl Into<Tooltip<C>>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Paints the whole window with the colors and modifiers (see `Window::colorizes`).
+    fn with_colors(mut self, colors: Vec<crate::render::ColorType>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Sets whether the widget starts hidden (see `Scene::set_widget_visible`).
+    fn with_hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Sets the layer the widget's window is drawn in (see `Window::set_layer`).
+    fn with_layer(mut self, layer: crate::render::Layer) -> Self {
+        self.layer = layer;
+        self
+    }
     
     /// Builds the widget and adds it to the provided scene, returning the new widget's index in the scene graph.
     /// This method combines the `build` and `scene.add_widget` calls into one for convenience.
@@ -190,7 +570,8 @@ impl<C: 'static> WidgetBuilder<C> for TypingWidgetBuilder<C> {
     /// ```
     /// use term_render::widget_impls::{TypingWidgetBuilder, WidgetBuilder};
     /// use term_render::render::Rect;
-    /// let mut app = term_render::App::new().unwrap();
+    /// # struct AppData;
+    /// let mut app = term_render::App::<AppData>::new_headless(80, 24);
     /// let mut scene = term_render::widget::Scene::new();
     /// let widget_index = TypingWidgetBuilder::<AppData>::builder(String::from("My Widget"))
     ///     .with_position((5, 5))
@@ -198,12 +579,9 @@ impl<C: 'static> WidgetBuilder<C> for TypingWidgetBuilder<C> {
     ///     .add_to_scene(&mut app, &mut scene)
     ///     .expect("Failed to build and add widget to scene.");
     /// ```
-    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetErr> {
-        if let Ok((widget, window)) = self.build(&app.area.read()) {
-            scene.add_widget(widget, window, &mut *app.renderer.write())
-        } else {
-            Err(WidgetErr::new("Failed to build and add widget to scene."))
-        }
+    fn add_to_scene(self, app: &mut crate::App<C>, scene: &mut Scene<C>) -> Result<usize, WidgetError> {
+        let (widget, window) = self.build(&app.area.read())?;
+        scene.add_widget(widget, window, &mut app.renderer.write())
     }
 }
 
@@ -212,6 +590,57 @@ impl<C: 'static> WidgetBuilder<C> for TypingWidgetBuilder<C> {
 /// Suitable for content that doesn't change frequently or in response to events.
 /// `TypingWidgetBuilder` is the associated builder for creating instances of this widget.
 /// The generic parameter C represents the application data type, which can be any type defined by the user.
+/// Input is applied in the order it was typed, even when several keys arrive within a frame.
+/// # Example
+/// ```
+/// use term_render::widget_impls::{TypingWidget, TypingWidgetBuilder, WidgetBuilder};
+/// use term_render::widget::Scene;
+/// let mut app = term_render::App::<()>::new_headless(20, 3);
+/// let mut scene = Scene::new();
+/// let field = TypingWidgetBuilder::<()>::builder(String::from("field"))
+///     .with_position((0, 0))
+///     .with_size((20, 3))
+///     .add_to_scene(&mut app, &mut scene)
+///     .unwrap();
+/// scene.set_focus(field).unwrap();
+///
+/// // "a", "b", left, and then "c", all in one frame
+/// let mut parser = vte::Parser::new();
+/// for bytes in [&b"a"[..], b"b", b"\x1b[D", b"c"] {
+///     let mut events = app.events.write();
+///     events.bytes = bytes.len();
+///     parser.advance(&mut *events, bytes);
+/// }
+/// scene.update_all_widgets(&mut app, &mut ()).unwrap();
+/// assert_eq!(scene.widget_as::<TypingWidget<()>>("field").unwrap().typed_text, "acb");
+/// ```
+///
+/// A bracketed paste is inserted as it was pasted, even into a single line field, and its newline
+/// isn't read as Return:
+/// ```
+/// use term_render::event_handler::{InputEvent, KeyCode};
+/// use term_render::widget_impls::{TypingWidget, TypingWidgetBuilder, WidgetBuilder};
+/// use term_render::widget::Scene;
+/// let mut app = term_render::App::<()>::new_headless(20, 3);
+/// let mut scene = Scene::new();
+/// let field = TypingWidgetBuilder::<()>::builder(String::from("field"))
+///     .with_position((0, 0))
+///     .with_size((20, 3))
+///     .add_to_scene(&mut app, &mut scene)
+///     .unwrap();
+/// scene.set_focus(field).unwrap();
+///
+/// let mut parser = vte::Parser::new();
+/// app.events.write().parse(&mut parser, b"\x1b[200~a\nb\tc\x1b[201~");
+/// let events = app.events.read();
+/// assert_eq!(events.peek_events(), &[InputEvent::Paste(String::from("a\nb\tc"))]);
+/// assert!(!events.contains_key_code(KeyCode::Return));
+/// drop(events);
+/// scene.update_all_widgets(&mut app, &mut ()).unwrap();
+/// let widget = scene.widget_as::<TypingWidget<()>>("field").unwrap();
+/// assert!(!widget.multiline);
+/// assert_eq!(widget.typed_text, "a\nb\tc");
+/// ```
 pub struct TypingWidget<C> {
     /// The indices of child widgets in the scene graph.
     children: Vec<usize>,
@@ -237,7 +666,7 @@ pub struct TypingWidget<C> {
     
     /// Optional closure that handles updates to the widget during event processing.
     /// This closure can modify the widget or application state as needed.
-    update_handler: Option<Box<dyn Fn(&mut dyn Widget<C>, &mut C, &mut crate::App<C>, &mut Scene<C>)>>,
+    update_handler: Option<UpdateHandler<C>>,
     
     /// The text that has been typed into the widget so far.
     pub typed_text: String,
@@ -245,8 +674,59 @@ pub struct TypingWidget<C> {
     /// Indicates whether the widget is currently selected (focused for input).
     pub selected: bool,
     
+    /// The byte index of the cursor within the typed text (always on a char boundary).
     pub cursor_pos: usize,
 
+    /// The in-progress input method composition shown at the cursor. It isn't inserted into
+    /// the typed text until the composition is committed.
+    pub preedit: Option<String>,
+
+    /// Whether the window is bordered (used for placing the cursor for input methods).
+    border: bool,
+
+    /// The window's padding (also used for placing the cursor).
+    padding: crate::render::Padding,
+
+    /// Where the terminal's cursor is shown while selected (the insertion point), as of the last update.
+    cursor_position: Option<(u16, u16)>,
+
+    /// Whether Return inserts newlines and Up/Down move between lines.
+    pub multiline: bool,
+
+    /// The renderer used instead of `render_function` in multi-line mode, if any.
+    pub multiline_render_function: Option<MultilineRenderFunction<C>>,
+
+    /// Decides which characters can be typed, if any filter is set (others are dropped).
+    pub input_filter: Option<Box<dyn Fn(char) -> bool>>,
+
+    /// The maximum number of characters the text can hold, if any.
+    pub max_length: Option<usize>,
+
+    /// The line shown while the text is empty and the widget isn't selected, if any.
+    pub placeholder: Option<crate::render::Span>,
+
+    /// The character passed to the render function in place of each typed character, if any.
+    pub mask_char: Option<char>,
+
+    /// The column (in chars) Up/Down try to keep, so moving through shorter lines doesn't lose it.
+    preferred_column: Option<usize>,
+
+    /// The byte index where the selection started (the other end is the cursor), if there's a selection.
+    pub selection_anchor: Option<usize>,
+
+    /// When the clipboard was requested for pasting, while waiting for the terminal to answer.
+    clipboard_requested: Option<std::time::Instant>,
+
+    /// The keywords the widget's window is tagged with (see `Scene::widgets_by_keyword`).
+    keywords: Vec<String>,
+    /// The tooltip shown while the mouse rests over the widget, if any.
+    tooltip: Option<Tooltip<C>>,
+
+    /// The closure run once the widget is added to the scene, if any (see `Widget::on_mount`).
+    on_mount: Option<LifecycleHook<C>>,
+    /// The closure run once the widget is removed from the scene, if any (see `Widget::on_unmount`).
+    on_unmount: Option<LifecycleHook<C>>,
+
     __phantom: std::marker::PhantomData<C>,
 }
 
@@ -268,10 +748,10 @@ impl<C> TypingWidget<C> {
                render_function: Option<RenderFunction<C>>,
                depth: u16,
                display_area: &crate::render::Rect,
-    ) -> Result<(TypingWidget<C>, crate::render::Window), WidgetErr> {
+    ) -> Result<(TypingWidget<C>, crate::render::Window), WidgetError> {
         let (size, position) = size_and_position.get_size_and_position(display_area);
-        if size.0 == 0 || size.1 == 0 || position.0 == 0 || position.1 == 0 {
-            return Err(WidgetErr::new("Size or position cannot be zero"))
+        if size.0 == 0 || size.1 == 0 {
+            return Err(WidgetError::InvalidGeometry { size, position })
         }
         let window = crate::render::Window::new(position, depth, size);
         
@@ -285,6 +765,23 @@ impl<C> TypingWidget<C> {
             typed_text: String::new(),
             selected: false,
             cursor_pos: 0,
+            preedit: None,
+            cursor_position: None,
+            border: false,
+            padding: crate::render::Padding::default(),
+            multiline: false,
+            multiline_render_function: None,
+            input_filter: None,
+            max_length: None,
+            placeholder: None,
+            mask_char: None,
+            preferred_column: None,
+            selection_anchor: None,
+            clipboard_requested: None,
+            keywords: vec![],
+            tooltip: None,
+            on_mount: None,
+            on_unmount: None,
             __phantom: std::marker::PhantomData,
         };
         
@@ -292,49 +789,353 @@ impl<C> TypingWidget<C> {
     }
 }
 
+impl<C> TypingWidget<C> {
+    /// Gets the byte index of the character before the cursor (or 0 at the start).
+    fn previous_boundary(&self) -> usize {
+        self.typed_text[..self.cursor_pos].char_indices().next_back().map(|(index, _)| index).unwrap_or(0)
+    }
+
+    /// Gets the byte index of the start of the line containing the given byte index.
+    fn line_start(&self, index: usize) -> usize {
+        self.typed_text[..index].rfind('\n').map(|newline| newline + 1).unwrap_or(0)
+    }
+
+    /// Gets the byte index of the end of the line (the newline, or the end of the text) containing the given byte index.
+    fn line_end(&self, index: usize) -> usize {
+        self.typed_text[index..].find('\n').map(|newline| index + newline).unwrap_or(self.typed_text.len())
+    }
+
+    /// Gets the selected byte range of the typed text (start, end), if anything is selected.
+    /// # Example
+    /// ```
+    /// use term_render::widget_impls::{TypingWidget, TypingWidgetBuilder, WidgetBuilder};
+    /// use term_render::widget::Scene;
+    /// use term_render::render::{Colored, Span};
+    /// let mut app = term_render::App::<()>::new_headless(10, 1);
+    /// let mut scene = Scene::new();
+    /// let field = TypingWidgetBuilder::<()>::builder(String::from("field"))
+    ///     // drawing the selection in brackets
+    ///     .with_renderer(Box::new(|_size, _position, typed, selection, _selected, _data| {
+    ///         let mut text: Vec<char> = typed.concat().chars().collect();
+    ///         if let Some((start, end)) = selection {
+    ///             text.insert(end, ']');
+    ///             text.insert(start, '[');
+    ///         }
+    ///         Some(vec![Span::from_tokens(vec![Colored::new(text.into_iter().collect())])])
+    ///     }))
+    ///     .with_position((0, 0))
+    ///     .with_size((10, 1))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .unwrap();
+    /// scene.set_focus(field).unwrap();
+    /// let mut parser = vte::Parser::new();
+    /// let mut frame = |scene: &mut Scene<()>, keys: &[&[u8]]| {
+    ///     app.events.write().clear_events();
+    ///     for key in keys {
+    ///         app.events.write().parse(&mut parser, key);
+    ///     }
+    ///     scene.update_all_widgets(&mut app, &mut ()).unwrap();
+    ///     let widget = scene.widget_as::<TypingWidget<()>>("field").unwrap();
+    ///     (widget.get_selection(), app.renderer.write().snapshot()[0].clone())
+    /// };
+    /// // "héllo", and then shift + left twice
+    /// let shift_left: &[u8] = b"\x1b[1;2D";
+    /// frame(&mut scene, &[b"h", "é".as_bytes(), b"l", b"l", b"o"]);
+    /// assert_eq!(frame(&mut scene, &[shift_left, shift_left]), (Some((4, 6)), String::from("hél[lo]")));
+    /// assert_eq!(scene.widget_as::<TypingWidget<()>>("field").unwrap().selected_text(), Some("lo"));
+    /// // extending it back over the multi-byte character
+    /// assert_eq!(frame(&mut scene, &[shift_left, shift_left]), (Some((1, 6)), String::from("h[éllo]")));
+    /// // typing replaces the selection
+    /// assert_eq!(frame(&mut scene, &[b"a"]), (None, String::from("ha")));
+    /// // selecting everything, and then moving without shift drops the selection
+    /// assert_eq!(frame(&mut scene, &[shift_left, shift_left]), (Some((0, 2)), String::from("[ha]")));
+    /// assert_eq!(frame(&mut scene, &[b"\x1b[C"]), (None, String::from("ha")));
+    /// // and deleting a selection removes just the selected text (the "h" left of the cursor)
+    /// assert_eq!(frame(&mut scene, &[shift_left, b"\x7f"]), (None, String::from("a")));
+    /// ```
+    pub fn get_selection(&self) -> Option<(usize, usize)> {
+        let anchor = self.selection_anchor?;
+        if anchor == self.cursor_pos {  return None;  }
+        Some((usize::min(anchor, self.cursor_pos), usize::max(anchor, self.cursor_pos)))
+    }
+
+    /// Gets the selected text, if anything is selected (i.e. for copying to a clipboard).
+    pub fn selected_text(&self) -> Option<&str> {
+        let (start, end) = self.get_selection()?;
+        self.typed_text.get(start..end)
+    }
+
+    /// Removes the selected text, leaving the cursor where it was (i.e. for cutting to a clipboard).
+    /// Returns whether there was a selection to remove.
+    pub fn delete_selection(&mut self) -> bool {
+        let Some((start, end)) = self.get_selection() else {
+            self.selection_anchor = None;
+            return false;
+        };
+        self.typed_text.replace_range(start..end, "");
+        self.cursor_pos = start;
+        self.selection_anchor = None;
+        self.preferred_column = None;
+        true
+    }
+
+    /// Inserts text at the cursor, replacing the selection if there is one. Characters rejected by the
+    /// input filter are dropped, and the text is cut short once the max length is reached.
+    /// # Example
+    /// ```
+    /// use term_render::widget_impls::{TypingWidget, TypingWidgetBuilder, WidgetBuilder};
+    /// use term_render::widget::Scene;
+    /// let mut app = term_render::App::<()>::new_headless(20, 1);
+    /// let mut scene = Scene::new();
+    /// let port = TypingWidgetBuilder::<()>::builder(String::from("port"))
+    ///     .with_input_filter(Box::new(|chr| chr.is_ascii_digit()))
+    ///     .with_max_length(5)
+    ///     .with_position((0, 0))
+    ///     .with_size((10, 1))
+    ///     .add_to_scene(&mut app, &mut scene)
+    ///     .unwrap();
+    /// scene.set_focus(port).unwrap();
+    /// let mut parser = vte::Parser::new();
+    /// // each key arrives as its own read, like it would from the terminal
+    /// let mut type_frame = |scene: &mut Scene<()>, keys: &[u8]| {
+    ///     app.events.write().clear_events();
+    ///     for key in keys {
+    ///         app.events.write().parse(&mut parser, &[*key]);
+    ///     }
+    ///     scene.update_all_widgets(&mut app, &mut ()).unwrap();
+    ///     scene.widget_as::<TypingWidget<()>>("port").unwrap().typed_text.clone()
+    /// };
+    /// // the letters are dropped, and the input stops at five characters
+    /// assert_eq!(type_frame(&mut scene, b"80a80b8080"), "80808");
+    /// // but deleting still works, making room again
+    /// assert_eq!(type_frame(&mut scene, b"\x7f1"), "80801");
+    /// ```
+    pub fn insert_text(&mut self, text: &str) {
+        let selection_length = self.selected_text().map(|text| text.chars().count()).unwrap_or(0);
+        let available = match self.max_length {
+            Some(max_length) => max_length.saturating_sub(self.typed_text.chars().count() - selection_length),
+            None => usize::MAX,
+        };
+        let text: String = text.chars()
+            .filter(|chr| self.input_filter.as_ref().is_none_or(|filter| filter(*chr)))
+            .take(available)
+            .collect();
+        // a rejected input shouldn't delete the selection
+        if text.is_empty() {  return;  }
+        self.delete_selection();
+        self.typed_text.insert_str(self.cursor_pos, &text);
+        self.cursor_pos += text.len();
+        self.preferred_column = None;
+    }
+
+    /// Replaces the typed text (i.e. to pre-populate a field), moving the cursor to the end and clearing
+    /// the selection. The text isn't checked against the input filter or max length.
+    pub fn set_text(&mut self, text: &str) {
+        self.typed_text = text.to_string();
+        self.cursor_pos = self.typed_text.len();
+        self.selection_anchor = None;
+        self.preferred_column = None;
+    }
+
+    /// Converts a byte index of the typed text into its char index.
+    fn char_index(&self, index: usize) -> usize {
+        self.typed_text[..index].chars().count()
+    }
+
+    /// Converts a byte index of the typed text into its line and column (in chars).
+    fn line_col(&self, index: usize) -> (usize, usize) {
+        let line_start = self.line_start(index);
+        (self.typed_text[..index].matches('\n').count(), self.typed_text[line_start..index].chars().count())
+    }
+
+    /// Gets the line and column (in chars) of the cursor.
+    pub fn get_cursor_line_col(&self) -> (usize, usize) {
+        self.line_col(self.cursor_pos)
+    }
+
+    /// Moves the cursor up (negative) or down (positive) a line, keeping the preferred column where possible.
+    fn move_vertically(&mut self, direction: i8) {
+        let column = self.preferred_column.unwrap_or(self.get_cursor_line_col().1);
+        let line_start = self.line_start(self.cursor_pos);
+        let target_start = match direction < 0 {
+            true if line_start == 0 => return,
+            true => self.line_start(line_start - 1),
+            false => {
+                let line_end = self.line_end(self.cursor_pos);
+                if line_end == self.typed_text.len() {  return;  }
+                line_end + 1
+            },
+        };
+        let target_end = self.line_end(target_start);
+        self.cursor_pos = self.typed_text[target_start..target_end].char_indices().nth(column)
+            .map(|(index, _)| target_start + index)
+            .unwrap_or(target_end);
+        self.preferred_column = Some(column);
+    }
+
+    /// Gets the terminal position of the insertion point (after any preedit text), assuming the typed
+    /// text is drawn from the top left of the widget's content. None if it's outside the visible region.
+    pub fn get_cursor_position(&mut self, area: &crate::render::Rect) -> Option<(u16, u16)> {
+        let (size, position) = self.size_and_position.get_size_and_position(area);
+        let border = self.border as u16;
+        let padding = self.padding;
+        let line_start = self.line_start(self.cursor_pos);
+        let line = self.get_cursor_line_col().0 as u16;
+        let width = crate::render::get_text_width(&self.mask(&self.typed_text[line_start..self.cursor_pos])) +
+            crate::render::get_text_width(&self.mask(self.preedit.as_deref().unwrap_or("")));
+        let max_x = position.0 + size.0.saturating_sub(border + padding.right + 1);
+        let max_y = position.1 + size.1.saturating_sub(border + padding.bottom + 1);
+        let cursor = (
+            u16::min(position.0 + border + padding.left + width as u16, max_x),
+            u16::min(position.1 + border + padding.top + line, max_y),
+        );
+        match self.size_and_position.is_within_clip(cursor) {
+            true => Some(cursor),
+            false => None,
+        }
+    }
+
+    /// Replaces each character (other than newlines) with the mask character, if one is set.
+    fn mask<'a>(&self, text: &'a str) -> std::borrow::Cow<'a, str> {
+        match self.mask_char {
+            Some(mask_char) => std::borrow::Cow::Owned(text.chars()
+                .map(|chr| if chr == '\n' { chr } else { mask_char })
+                .collect()),
+            None => std::borrow::Cow::Borrowed(text),
+        }
+    }
+
+    /// Handles left, right, up, down, and backspace (moving by whole characters). Deleting a newline
+    /// joins the line with the previous one, and holding shift while moving extends the selection from
+    /// where the cursor was.
+    fn handle_key(&mut self, key: KeyCode, shifted: bool) {
+        if key == KeyCode::Delete && !self.delete_selection() && self.cursor_pos > 0 {
+            self.cursor_pos = self.previous_boundary();
+            self.typed_text.remove(self.cursor_pos);
+            self.preferred_column = None;
+        }
+        if !matches!(key, KeyCode::Left | KeyCode::Right | KeyCode::Up | KeyCode::Down) {  return;  }
+        match shifted {
+            true if self.selection_anchor.is_none() => self.selection_anchor = Some(self.cursor_pos),
+            true => {},
+            false => self.selection_anchor = None,
+        }
+        match key {
+            KeyCode::Left => {
+                self.cursor_pos = self.previous_boundary();
+                self.preferred_column = None;
+            },
+            KeyCode::Right => {
+                self.cursor_pos = self.typed_text[self.cursor_pos..].chars().next()
+                    .map(|chr| self.cursor_pos + chr.len_utf8())
+                    .unwrap_or(self.cursor_pos);
+                self.preferred_column = None;
+            },
+            KeyCode::Up if self.multiline => self.move_vertically(-1),
+            KeyCode::Down if self.multiline => self.move_vertically(1),
+            _ => {},
+        }
+        // an empty selection collapses
+        if self.selection_anchor == Some(self.cursor_pos) {
+            self.selection_anchor = None;
+        }
+    }
+
+    /// Checks if the given ime position lies within this widget (i.e. it was set by this widget).
+    fn is_collided_ime(&self, position: Option<(u16, u16)>) -> bool {
+        position.is_some_and(|position| self.size_and_position.is_collided(position))
+    }
+}
+
 /// Implementation of the methods for TypingWidget
-impl<C> Widget<C> for TypingWidget<C> {
+impl<C: 'static> Widget<C> for TypingWidget<C> {
     /// Returns the widget's name as an identifier. The rendering backend relies
     /// on `String` names instead of widgets.
     fn get_window_ref(&self) -> String {
         self.name.clone()
     }
     
+    /// Runs the mount closure, if any (see `with_on_mount`).
+    fn on_mount(&mut self, app: &mut crate::App<C>, data: &mut C) {
+        if let Some(on_mount) = &mut self.on_mount {  on_mount(data, app);  }
+    }
+    
+    /// Runs the unmount closure, if any (see `with_on_unmount`).
+    fn on_unmount(&mut self, app: &mut crate::App<C>, data: &mut C) {
+        if let Some(on_unmount) = &mut self.on_unmount {  on_unmount(data, app);  }
+    }
+    
     // for handling updates (a static widget would just have this empty)
     /// Handles event updates. However, compared to the other widgets, this one
     /// doesn't directly act to modify the widget, but rather to respond to changes in text input.
     fn update_with_events(&mut self, data: &mut C, app: &mut crate::App<C>, scene: &mut Scene<C>) {
-        // checking if the box is being selected, or unselected
-        if let Some(event) = &app.events.read().mouse_event {
-            if event.event_type == crate::event_handler::MouseEventType::Left {
-                self.selected = self.is_collided(event.position) &&
-                    !scene.is_click_blocked_all(scene.get_widget_index(self.get_window_ref())
-                    .unwrap_or(0), event.position, &*app).unwrap_or(false);
+        // actually handling text input if selected (focused through the scene)
+        let events = app.events.read();
+        if self.selected {
+            // the keys belong to the input method while it's composing
+            self.preedit = events.composition.clone();
+            // replaying the frame's input in the order it was typed (typing "ab", moving left, and then typing "c" gives "acb")
+            for event in events.peek_events() {
+                match event {
+                    // shortcuts aren't typed
+                    InputEvent::Key(_, held) | InputEvent::Char(_, held)
+                        if held.contains(&KeyModifiers::Control) || held.contains(&KeyModifiers::Command) => {},
+                    // typing or pasting over a selection replaces it
+                    InputEvent::Char(chr, _) => self.insert_text(&chr.to_string()),
+                    InputEvent::Key(KeyCode::Return, _) if self.multiline => self.insert_text("\n"),
+                    // pastes are inserted all at once (newlines and tabs included)
+                    InputEvent::Paste(paste) => self.insert_text(paste),
+                    InputEvent::Key(key, held) if self.preedit.is_none() => {
+                        self.handle_key(*key, held.contains(&KeyModifiers::Shift));
+                    },
+                    _ => {},
+                }
             }
+        } else {
+            self.preedit = None;
         }
         
-        // actually handling text input if selected
-        let events = app.events.read();
-        if self.selected && !events.contains_modifier(crate::event_handler::KeyModifiers::Control) &&
-            !events.contains_modifier(crate::event_handler::KeyModifiers::Command)
-        {
-            for char in &events.char_events {
-                self.typed_text.insert(self.cursor_pos, *char);
-                self.cursor_pos += 1;
-            }
-            
-            // handling left, right and backspace
-            if events.contains_key_code(crate::event_handler::KeyCode::Delete) && self.cursor_pos > 0 {
-                self.cursor_pos = self.cursor_pos.saturating_sub(1);
-                self.typed_text.remove(self.cursor_pos);
+        // copying and pasting through the terminal's clipboard: Cmd+C/Cmd+V, or Ctrl+C while there's
+        // a selection (otherwise it exits the app) and Ctrl+V (masked text can't be copied)
+        let shortcut = events.contains_modifier(crate::event_handler::KeyModifiers::Control) ||
+            events.contains_modifier(crate::event_handler::KeyModifiers::Command);
+        let copy = match self.selected && shortcut && events.contains_char('c') && self.mask_char.is_none() {
+            true => self.selected_text().map(String::from),
+            false => None,
+        };
+        let paste = self.selected && shortcut && events.contains_char('v');
+        // the terminal's answer is only waited on for a moment (some terminals never answer)
+        if let Some(requested) = self.clipboard_requested {
+            if self.selected && let Some(content) = &events.clipboard_content {
+                self.insert_text(content);
+                self.clipboard_requested = None;
+            } else if !self.selected || requested.elapsed() > CLIPBOARD_TIMEOUT {
+                self.clipboard_requested = None;
             }
-            if events.contains_key_code(crate::event_handler::KeyCode::Left) {
-                self.cursor_pos = self.cursor_pos.saturating_sub(1);
+        }
+        drop(events);  // making sure there isn't a deadlock
+        if copy.is_some() || paste {
+            let mut renderer = app.renderer.write();
+            if let Some(text) = copy {
+                renderer.set_clipboard(&text);
             }
-            if events.contains_key_code(crate::event_handler::KeyCode::Right) {
-                self.cursor_pos = usize::min(self.cursor_pos + 1, self.typed_text.len());
+            if paste {
+                renderer.request_clipboard();
+                self.clipboard_requested = Some(std::time::Instant::now());
             }
-        } drop(events);  // making sure there isn't a deadlock
+        }
+        
+        let ime_position = match self.selected {
+            true => self.get_cursor_position(&app.area.read()),
+            false => None,
+        };
+        // only touching the renderer when this widget owns (or owned) the ime position
+        let mut renderer = app.renderer.write();
+        if ime_position.is_some() || self.is_collided_ime(renderer.get_ime_position()) {
+            renderer.set_ime_position(ime_position);
+        } drop(renderer);
+        // the scene shows the terminal's cursor there too
+        self.cursor_position = ime_position;
         
         if let Some(update_handler) = self.update_handler.take() {
             update_handler(self, data, app, scene);
@@ -351,10 +1152,31 @@ impl<C> Widget<C> for TypingWidget<C> {
         let (size, position) = self.size_and_position.get_size_and_position(area);
         window.resize(size);
         window.r#move(position);
+        if self.typed_text.is_empty() && self.preedit.is_none() && !self.selected &&
+            let Some(placeholder) = &self.placeholder {
+            return window.try_update_lines(vec![placeholder.clone()]);
+        }
+        let typed_text = self.mask(&self.typed_text);
+        let preedit = self.mask(self.preedit.as_deref().unwrap_or(""));
+        if self.multiline && let Some(render_function) = &self.multiline_render_function {
+            let text = MultilineText {
+                lines: typed_text.split('\n').collect(),
+                cursor: self.get_cursor_line_col(),
+                preedit: &preedit,
+                selection: self.get_selection().map(|(start, end)| (self.line_col(start), self.line_col(end))),
+            };
+            if let Some(render) = render_function(size, position, &text, self.selected, app_state) {
+                return window.try_update_lines(render);
+            }
+            return false;
+        }
         if let Some(render_function) = &self.render_function {
-            let typed = &[self.typed_text.get(0..self.cursor_pos).unwrap_or(""),
-                self.typed_text.get(self.cursor_pos..).unwrap_or("")];
-            if let Some(render) = render_function(size, position, &typed, self.selected, app_state) {
+            // the masked text has different byte offsets, so it's split at the cursor's char index
+            let split = typed_text.char_indices().nth(self.char_index(self.cursor_pos))
+                .map(|(index, _)| index).unwrap_or(typed_text.len());
+            let typed = &[&typed_text[..split], &*preedit, &typed_text[split..]];
+            let selection = self.get_selection().map(|(start, end)| (self.char_index(start), self.char_index(end)));
+            if let Some(render) = render_function(size, position, typed, selection, self.selected, app_state) {
                 return window.try_update_lines(render);
             }
         } false
@@ -392,7 +1214,62 @@ impl<C> Widget<C> for TypingWidget<C> {
     
     /// Determines if a given position collides with the widget's area.
     fn is_collided(&self, position: (u16, u16)) -> bool {
-        let (size, pos) = self.size_and_position.get_last();
-        position.0 >= pos.0 && position.0 < pos.0 + size.0 && position.1 >= pos.1 && position.1 < pos.1 + size.1
+        self.size_and_position.is_collided(position)
+    }
+
+    /// Returns the widget as `Any` for downcasting.
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget as a mutable `Any` for downcasting.
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    /// Returns the widget's size and position configuration.
+    fn get_size_and_position(&self) -> Option<&SizeAndPosition> {
+        Some(&self.size_and_position)
+    }
+
+    /// Returns the widget's size and position configuration mutably.
+    fn get_size_and_position_mut(&mut self) -> Option<&mut SizeAndPosition> {
+        Some(&mut self.size_and_position)
+    }
+
+    /// Returns the keywords the widget's window is tagged with.
+    fn get_keywords(&self) -> &[String] {
+        &self.keywords
+    }
+
+    /// Returns the tooltip shown while the mouse rests over the widget, if any.
+    fn get_tooltip(&self) -> Option<&Tooltip<C>> {
+        self.tooltip.as_ref()
+    }
+    
+    /// Typing widgets take focus when clicked, or through Tab/Shift+Tab.
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    /// Selected text can be copied (unless it's masked).
+    fn has_selection(&self) -> bool {
+        self.mask_char.is_none() && self.get_selection().is_some()
+    }
+
+    /// The widget is selected (receives typed text) while it has the scene's focus.
+    fn set_focused(&mut self, focused: bool) {
+        self.selected = focused;
+    }
+
+    /// While selected, the terminal's cursor is shown at the insertion point.
+    fn cursor_position(&self) -> Option<(u16, u16)> {
+        self.cursor_position
+    }
+
+    /// Applies the offset and clip inherited from the parent widgets to the size and position.
+    fn set_parent_transform(&mut self, offset: (i16, i16), clip: Option<Region>) {
+        self.size_and_position.parent_offset = offset;
+        self.size_and_position.clip = clip;
     }
 }
@@ -16,7 +16,7 @@ use syn::punctuated::Punctuated;
 /// - value of type T with trait Colorize
 /// - n ColorType variants where n is [0, ∞)
 /// # Example
-/// ```
+/// ```ignore
 /// term_render::color!("Hello World", White, Bold, Underline);
 /// term_render::color!("Hello World");  // converts to Colored without applying modifiers
 /// ```
@@ -43,7 +43,7 @@ pub fn color (input: TokenStream) -> TokenStream {
 /// # Parameters
 /// - value of type T
 /// # Example
-/// ```
+/// ```ignore
 /// let data = send_sync!(MyStruct { field1: 10, field2: String::from("Hello") });
 /// // instead of
 /// let data = std::sync::Arc::new(parking_lot::RwLock::new(MyStruct { field1: 10, field2: String::from("Hello") }));